@@ -277,6 +277,7 @@ fn to_pending_def<'a>(
 
         Type(TypeDef::Opaque { .. }) => internal_error!("opaques not implemented"),
         Type(TypeDef::Ability { .. }) => todo_abilities!(),
+        Type(TypeDef::BuiltinImpls { .. }) => todo_abilities!(),
 
         Value(AstValueDef::Expect { .. }) => todo!(),
         Value(AstValueDef::ExpectFx { .. }) => todo!(),
@@ -7,7 +7,7 @@ use roc_can::operator::desugar_expr;
 use roc_collections::all::MutSet;
 use roc_module::symbol::Symbol;
 use roc_parse::{ast::Expr, pattern::PatternType};
-use roc_problem::can::{Problem, RuntimeError};
+use roc_problem::can::{ArgumentBinding, Problem, RuntimeError};
 use roc_region::all::{Loc, Region};
 
 use super::{expr2::Expr2, output::Output};
@@ -453,11 +453,14 @@ pub fn expr_to_expr2<'a>(
                 if !original_scope.contains_symbol(sub_symbol) {
                     if !output.references.has_lookup(sub_symbol) {
                         // The body never referenced this argument we declared. It's an unused argument!
+                        // The editor doesn't apply suggested edits, so how the argument
+                        // is bound doesn't matter here.
                         env.problem(Problem::UnusedArgument(
                             symbol,
                             is_anonymous,
                             sub_symbol,
                             region,
+                            ArgumentBinding::Identifier,
                         ));
                     }
 
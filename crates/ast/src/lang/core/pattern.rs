@@ -9,6 +9,7 @@ use roc_can::num::{
 };
 use roc_collections::all::BumpMap;
 use roc_error_macros::internal_error;
+use roc_module::ident::Lowercase;
 use roc_module::symbol::{Interns, Symbol};
 use roc_parse::ast::{StrLiteral, StrSegment};
 use roc_parse::pattern::PatternType;
@@ -440,6 +441,55 @@ pub fn to_pattern2<'a>(
             })
         }
 
+        Tuple(patterns) => {
+            // a tuple pattern destructures the record its elements are the fields of
+            let ext_var = env.var_store.fresh();
+            let whole_var = env.var_store.fresh();
+            let destructs = PoolVec::with_capacity(patterns.len() as u32, env.pool);
+
+            for (index, (node_id, loc_pattern)) in destructs
+                .iter_node_ids()
+                .zip((*patterns).iter())
+                .enumerate()
+            {
+                let label = Lowercase::tuple_elem(index);
+                let symbol = scope.ignore(label.as_str().into(), &mut env.ident_ids);
+                let (new_output, can_guard) = to_pattern_id(
+                    env,
+                    scope,
+                    pattern_type,
+                    &loc_pattern.value,
+                    loc_pattern.region,
+                );
+
+                let destruct = RecordDestruct {
+                    var: env.var_store.fresh(),
+                    label: PoolStr::new(label.as_str(), env.pool),
+                    symbol,
+                    typ: env
+                        .pool
+                        .add(DestructType::Guard(env.var_store.fresh(), can_guard)),
+                };
+
+                output.union(new_output);
+
+                env.pool[node_id] = destruct;
+                env.set_region(node_id, loc_pattern.region);
+            }
+
+            Pattern2::RecordDestructure {
+                whole_var,
+                ext_var,
+                destructs,
+            }
+        }
+
+        List(_) => todo!("list patterns in the editor"),
+        As(_, _) => todo!("as patterns in the editor"),
+        ListRest(_) => {
+            unreachable!("should have been handled in List");
+        }
+
         RequiredField(_name, _loc_pattern) => {
             unreachable!("should have been handled in RecordDestructure");
         }
@@ -442,6 +442,24 @@ pub fn to_type2<'a>(
 
             Type2::Record(field_types, ext_type)
         }
+        Tuple(elems) => {
+            // a tuple type is the closed record type whose fields are its elements
+            let field_types = PoolVec::with_capacity(elems.len() as u32, env.pool);
+
+            for (index, (node_id, elem)) in
+                field_types.iter_node_ids().zip(elems.iter()).enumerate()
+            {
+                let label = Lowercase::tuple_elem(index);
+                let poolstr = PoolStr::new(label.as_str(), env.pool);
+                let field_id = to_type_id(env, scope, references, &elem.value, elem.region);
+
+                env.pool[node_id] = (poolstr, RecordField::Required(field_id));
+            }
+
+            let ext_type = env.add(Type2::EmptyRec, region);
+
+            Type2::Record(field_types, ext_type)
+        }
         TagUnion { tags, ext, .. } => {
             let tag_types_vec = can_tags(env, scope, references, tags.items, region);
 
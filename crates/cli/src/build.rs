@@ -5,6 +5,7 @@ use roc_build::{
 };
 use roc_builtins::bitcode;
 use roc_collections::VecMap;
+use roc_error_macros::user_error;
 use roc_load::{
     EntryPoint, ExecutionMode, Expectations, LoadConfig, LoadMonomorphizedError, LoadedModule,
     LoadingProblem, Threading,
@@ -30,6 +31,19 @@ fn report_timing(buf: &mut String, label: &str, duration: Duration) {
     .unwrap()
 }
 
+/// An optimized build is one that gets shipped, so a `dbg` left in it is an error rather than
+/// something that prints from the released program.
+pub fn reject_dbg_in_optimized_build(
+    opt_level: OptLevel,
+    expectations: &VecMap<ModuleId, Expectations>,
+) {
+    let optimized = matches!(opt_level, OptLevel::Optimize | OptLevel::Size);
+
+    if optimized && expectations.values().any(|data| !data.dbgs.is_empty()) {
+        user_error!("`dbg` cannot be used in an optimized build. Remove the `dbg` or build without --optimize or --opt-size.");
+    }
+}
+
 pub struct BuiltFile {
     pub binary_path: PathBuf,
     pub problems: Problems,
@@ -250,6 +264,8 @@ pub fn build_file<'a>(
     let expectations = std::mem::take(&mut loaded.expectations);
     let loaded = loaded;
 
+    reject_dbg_in_optimized_build(opt_level, &expectations);
+
     let interns = loaded.interns.clone();
 
     enum HostRebuildTiming {
@@ -461,6 +477,7 @@ pub fn check_file(
     arena: &Bump,
     roc_file_path: PathBuf,
     emit_timings: bool,
+    show_holes: bool,
     threading: Threading,
 ) -> Result<(program::Problems, Duration), LoadingProblem> {
     let compilation_start = Instant::now();
@@ -525,6 +542,10 @@ pub fn check_file(
         println!("Finished checking in {} ms\n", compilation_end.as_millis(),);
     }
 
+    if show_holes {
+        loaded.add_inferred_hole_problems();
+    }
+
     Ok((
         program::report_problems_typechecked(&mut loaded),
         compilation_end,
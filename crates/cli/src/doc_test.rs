@@ -0,0 +1,380 @@
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use bumpalo::Bump;
+use roc_gen_llvm::llvm::build::LlvmBackendMode;
+use roc_load::docs::{doc_examples, DocEntry, DocExample};
+use roc_load::{ExecutionMode, LoadConfig, LoadingProblem, Threading};
+use roc_module::symbol::ModuleId;
+use roc_mono::ir::OptLevel;
+use roc_parse::parser::Parser;
+use roc_parse::state::State;
+use roc_problem::can::Problem;
+use roc_region::all::LineInfo;
+use roc_repl_expect::run::{expect_mono_module_to_dylib, run_expects, ExpectFunctions};
+use roc_reporting::report::RenderTarget;
+use roc_target::TargetInfo;
+use target_lexicon::Triple;
+
+/// The name of the module that the doc examples get compiled in
+const DOC_TESTS_MODULE: &str = "DocTests";
+
+/// A `>>>` example from the docs of one of the project's modules
+struct DocTest {
+    module_name: String,
+    path: PathBuf,
+    /// The line of the module's file that the example starts on, counting from 1
+    line: usize,
+    example: DocExample,
+}
+
+impl DocTest {
+    fn location(&self) -> String {
+        format!("{}:{}", self.path.display(), self.line)
+    }
+
+    /// The example as a top-level `expect`. If the example has an expected value, the
+    /// expect compares against it; otherwise, the example just has to run without crashing.
+    fn to_expect(&self) -> String {
+        let mut buf = String::new();
+
+        buf.push_str("# ");
+        buf.push_str(&self.location());
+        buf.push_str("\nexpect\n");
+
+        match &self.example.expected {
+            Some(_) => buf.push_str("    docTestActual =\n"),
+            None => buf.push_str("    _ =\n"),
+        }
+
+        for line in self.example.code.lines() {
+            if !line.is_empty() {
+                buf.push_str("        ");
+                buf.push_str(line);
+            }
+
+            buf.push('\n');
+        }
+
+        match &self.example.expected {
+            Some(expected) => {
+                buf.push_str("    docTestActual == (");
+                buf.push_str(expected);
+                buf.push_str(")\n");
+            }
+            None => buf.push_str("    True\n"),
+        }
+
+        buf
+    }
+}
+
+/// Run the `>>>` examples in the doc comments of the modules that the given .roc file imports
+/// (and of the file itself, if it's an interface module), as if each of them was an `expect`.
+///
+/// Like `roc fix`, this only looks at modules in the same directory as the given file (or
+/// below it), so that the docs of platforms and packages from elsewhere are left alone.
+/// That includes the builtins when their files are in that directory, e.g. when running
+/// `roc test --doc crates/compiler/builtins/roc/List.roc` in the compiler's repository.
+pub fn test_docs(
+    roc_file_path: &Path,
+    triple: &Triple,
+    opt_level: OptLevel,
+    threading: Threading,
+) -> io::Result<i32> {
+    let start_time = Instant::now();
+    let arena = Bump::new();
+    let arena = &arena;
+    let target_info = TargetInfo::from(triple);
+
+    let project_dir = roc_file_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    // Step 1: find the examples in the docs
+    let load_config = LoadConfig {
+        target_info,
+        render: RenderTarget::ColorTerminal,
+        threading,
+        exec_mode: ExecutionMode::Check,
+    };
+    let doc_tests = match roc_load::load_and_typecheck(
+        arena,
+        roc_file_path.to_path_buf(),
+        Default::default(),
+        load_config,
+    ) {
+        Ok(loaded) => find_doc_tests(&loaded, &project_dir),
+        Err(LoadingProblem::FormattedReport(report)) => {
+            print!("{}", report);
+
+            return Ok(1);
+        }
+        Err(other) => panic!("{:?}", other),
+    };
+
+    if doc_tests.is_empty() {
+        println!("No doc examples were found.");
+
+        return Ok(2);
+    }
+
+    // Step 2: turn the examples into the expects of a module that imports the documented ones
+    let mut module_names: Vec<&str> = doc_tests
+        .iter()
+        .map(|doc_test| doc_test.module_name.as_str())
+        .collect();
+
+    module_names.sort_unstable();
+    module_names.dedup();
+
+    let mut src = format!(
+        "interface {}\n    exposes []\n    imports [{}]\n",
+        DOC_TESTS_MODULE,
+        module_names.join(", ")
+    );
+
+    // Where each example's expect starts in the module, so we can tell which one failed
+    let mut expect_offsets: Vec<(u32, &DocTest)> = Vec::with_capacity(doc_tests.len());
+    let mut failed = 0;
+    let mut passed = 0;
+
+    for doc_test in doc_tests.iter() {
+        let expect = doc_test.to_expect();
+
+        // Check that the example parses on its own, so that one broken example
+        // doesn't stop all the others from running.
+        if parses(&expect) {
+            src.push('\n');
+            expect_offsets.push((src.len() as u32, doc_test));
+            src.push_str(&expect);
+        } else {
+            failed += 1;
+
+            println!(
+                "\n── DOC EXAMPLE DOES NOT PARSE ── {}\n\n{}\n",
+                doc_test.location(),
+                indented(&doc_test.example.code)
+            );
+        }
+    }
+
+    // Step 3: compile the module and run its expects
+    if !expect_offsets.is_empty() {
+        let src_dir = tempfile::tempdir()?;
+        let filename = src_dir.path().join(format!("{}.roc", DOC_TESTS_MODULE));
+
+        // Failed expects get reported by reading their source from this file
+        std::fs::write(&filename, &src)?;
+
+        let load_config = LoadConfig {
+            target_info,
+            render: RenderTarget::ColorTerminal,
+            threading,
+            exec_mode: ExecutionMode::Test,
+        };
+        let mut loaded = match roc_load::load_and_monomorphize_from_str(
+            arena,
+            filename,
+            arena.alloc_str(&src),
+            project_dir,
+            Default::default(),
+            load_config,
+        ) {
+            Ok(loaded) => loaded,
+            Err(LoadingProblem::FormattedReport(report)) => {
+                print!("{}", report);
+
+                return Ok(1);
+            }
+            Err(other) => panic!("{:?}", other),
+        };
+
+        // Problems in the documented modules are for `roc check` to report, and the examples
+        // don't have to use everything they import.
+        let home = loaded.module_id;
+
+        loaded
+            .can_problems
+            .retain(|module_id, _| *module_id == home);
+        loaded
+            .type_problems
+            .retain(|module_id, _| *module_id == home);
+
+        for problems in loaded.can_problems.values_mut() {
+            problems.retain(|problem| {
+                !matches!(
+                    problem,
+                    Problem::UnusedImport(..)
+                        | Problem::UnusedExposedImport(..)
+                        | Problem::UnusedDef(..)
+                )
+            });
+        }
+
+        roc_build::program::report_problems_monomorphized(&mut loaded);
+
+        let mut expectations = std::mem::take(&mut loaded.expectations);
+        let interns = loaded.interns.clone();
+
+        let (lib, expects, layout_interner) = expect_mono_module_to_dylib(
+            arena,
+            triple.clone(),
+            loaded,
+            opt_level,
+            LlvmBackendMode::CliTest,
+        )
+        .unwrap();
+
+        let arena = &Bump::new();
+        let interns = arena.alloc(interns);
+        let layout_interner = layout_interner.into_global();
+
+        let mut writer = std::io::stdout();
+
+        // Run the expects one at a time, so that we know which example each failure is for
+        let all_expects = expects
+            .pure
+            .iter()
+            .map(|expect| (*expect, false))
+            .chain(expects.fx.iter().map(|expect| (*expect, true)));
+
+        for (expect, is_fx) in all_expects {
+            let mut output = Vec::new();
+            let mut pure = bumpalo::collections::Vec::new_in(arena);
+            let mut fx = bumpalo::collections::Vec::new_in(arena);
+
+            if is_fx {
+                fx.push(expect);
+            } else {
+                pure.push(expect);
+            }
+
+            let (expect_failed, _) = run_expects(
+                &mut output,
+                RenderTarget::ColorTerminal,
+                arena,
+                interns,
+                &layout_interner,
+                &lib,
+                &mut expectations,
+                ExpectFunctions { pure, fx },
+            )?;
+
+            if expect_failed > 0 {
+                failed += 1;
+
+                let expect_offset = expect.region.start().offset;
+                let doc_test = expect_offsets
+                    .iter()
+                    .rev()
+                    .find(|(offset, _)| *offset <= expect_offset)
+                    .map(|(_, doc_test)| doc_test);
+
+                if let Some(doc_test) = doc_test {
+                    writeln!(
+                        writer,
+                        "\nThis doc example failed: {}\n\n{}",
+                        doc_test.location(),
+                        indented(&doc_test.example.code)
+                    )?;
+                }
+
+                writer.write_all(&output)?;
+            } else {
+                passed += 1;
+            }
+        }
+    }
+
+    let total_time = start_time.elapsed();
+
+    let failed_color = if failed == 0 {
+        32 // green
+    } else {
+        31 // red
+    };
+
+    println!(
+        "\n\x1B[{failed_color}m{failed}\x1B[39m failed and \x1B[32m{passed}\x1B[39m passed in {} ms.\n",
+        total_time.as_millis(),
+    );
+
+    Ok((failed > 0) as i32)
+}
+
+/// Find the examples in the docs of the modules in the project directory, in the order they
+/// appear in their files.
+fn find_doc_tests(loaded: &roc_load::LoadedModule, project_dir: &Path) -> Vec<DocTest> {
+    let mut doc_tests = Vec::new();
+
+    for (module_id, module_docs) in loaded.documentation.iter() {
+        let (path, src) = match loaded.sources.get(module_id) {
+            Some(source) => source,
+            None => continue,
+        };
+
+        let path = builtin_path(*module_id, path, project_dir).unwrap_or_else(|| path.clone());
+
+        if !path.starts_with(project_dir) || !path.is_file() {
+            continue;
+        }
+
+        let line_info = LineInfo::new(src);
+
+        for entry in module_docs.entries.iter() {
+            let (docs, region) = match entry {
+                DocEntry::DocDef(doc_def) => match &doc_def.docs {
+                    Some(docs) => (docs, doc_def.region),
+                    None => continue,
+                },
+                DocEntry::DetachedDoc(_) => continue,
+            };
+
+            // The doc comment is on the lines right above the definition
+            let def_line = line_info.convert_pos(region.start()).line as usize;
+            let docs_line = def_line.saturating_sub(docs.lines().count());
+
+            for example in doc_examples(docs) {
+                doc_tests.push(DocTest {
+                    module_name: module_docs.name.clone(),
+                    path: path.clone(),
+                    line: docs_line + example.line + 1,
+                    example,
+                });
+            }
+        }
+    }
+
+    doc_tests.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+
+    doc_tests
+}
+
+/// Builtins that aren't the module being tested are loaded from the source compiled into roc,
+/// under just their file name (e.g. `List.roc`). That source is the file of the same name in
+/// the builtins' directory, so when that's the project directory, this is where it came from.
+fn builtin_path(module_id: ModuleId, path: &Path, project_dir: &Path) -> Option<PathBuf> {
+    if module_id.is_builtin() && path.components().count() == 1 {
+        Some(project_dir.join(path))
+    } else {
+        None
+    }
+}
+
+fn parses(defs: &str) -> bool {
+    let arena = Bump::new();
+
+    roc_parse::module::module_defs()
+        .parse(&arena, State::new(defs.as_bytes()))
+        .is_ok()
+}
+
+fn indented(code: &str) -> String {
+    code.lines()
+        .map(|line| format!("    {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,281 @@
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::{format, FormatMode};
+use bumpalo::Bump;
+use roc_can::expr::Declarations;
+use roc_can::traverse::{find_def_region, find_shadowed_lookups};
+use roc_load::{ExecutionMode, LoadConfig, LoadingProblem, Threading};
+use roc_problem::can::SuggestedEdit;
+use roc_reporting::report::RenderTarget;
+use roc_target::TargetInfo;
+
+/// Check the given .roc file and the modules it imports, apply the edits suggested for the
+/// problems found in them, and format the files that changed.
+///
+/// Only modules in the same directory as the given file (or below it) get changed, so that
+/// platforms and packages from elsewhere are left alone. Returns the files that changed,
+/// along with the number of edits applied to each.
+pub fn fix(
+    arena: &Bump,
+    roc_file_path: PathBuf,
+    threading: Threading,
+) -> Result<Vec<(PathBuf, usize)>, LoadingProblem> {
+    let project_dir = roc_file_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let load_config = LoadConfig {
+        // Only used for generating errors, like in `roc check`
+        target_info: TargetInfo::default_x86_64(),
+        render: RenderTarget::ColorTerminal,
+        threading,
+        exec_mode: ExecutionMode::Check,
+    };
+    let loaded =
+        roc_load::load_and_typecheck(arena, roc_file_path, Default::default(), load_config)?;
+
+    let mut fixed_files = Vec::new();
+    let no_decls = Declarations::new();
+
+    for (module_id, problems) in loaded.can_problems.iter() {
+        if module_id.is_builtin() {
+            continue;
+        }
+
+        let (path, src) = match loaded.sources.get(module_id) {
+            Some(source) => source,
+            None => continue,
+        };
+
+        if !path.starts_with(&project_dir) {
+            continue;
+        }
+
+        let edits: Vec<SuggestedEdit> = problems
+            .iter()
+            .flat_map(|problem| problem.suggested_edits())
+            .collect();
+
+        let decls = loaded
+            .declarations_by_id
+            .get(module_id)
+            .unwrap_or(&no_decls);
+        let (fixed_src, num_edits) = apply_edits(src, decls, edits);
+
+        if num_edits == 0 {
+            continue;
+        }
+
+        std::fs::write(path, fixed_src).map_err(|error| LoadingProblem::FileProblem {
+            filename: path.clone(),
+            error: error.kind(),
+        })?;
+
+        fixed_files.push((path.clone(), num_edits));
+    }
+
+    // Removing entries from lists and defs leaves their formatting behind, so clean that up
+    let paths = fixed_files.iter().map(|(path, _)| path.clone()).collect();
+
+    if let Err(message) = format(paths, FormatMode::Format, None) {
+        eprintln!("{}", message);
+    }
+
+    Ok(fixed_files)
+}
+
+/// Apply the edits to the source code they were suggested for, which `decls` were
+/// canonicalized from. Returns the fixed source code, and how many of the edits were applied.
+fn apply_edits(src: &str, decls: &Declarations, mut edits: Vec<SuggestedEdit>) -> (String, usize) {
+    edits.sort_by_key(|edit| edit.region().start().offset);
+    edits.dedup();
+
+    let mut changes: Vec<(usize, Range<usize>, String)> = edits
+        .iter()
+        .enumerate()
+        .flat_map(|(index, edit)| {
+            replacements(src, decls, *edit)
+                .into_iter()
+                .map(move |(range, text)| (index, range, text))
+        })
+        .collect();
+
+    // A change inside one that came before it, like prefixing an argument of a def that gets
+    // removed, has nothing left to change.
+    changes.sort_by_key(|(_, range, _)| (range.start, std::cmp::Reverse(range.end)));
+
+    let mut kept: Vec<(usize, Range<usize>, String)> = Vec::with_capacity(changes.len());
+
+    for change in changes {
+        match kept.last() {
+            Some((_, last, _)) if change.1.start < last.end => {}
+            _ => kept.push(change),
+        }
+    }
+
+    let mut applied: Vec<usize> = kept.iter().map(|(index, _, _)| *index).collect();
+    applied.sort_unstable();
+    applied.dedup();
+
+    // Starting from the end of the file keeps the ranges of the other changes valid
+    let mut src = src.to_string();
+
+    for (_, range, text) in kept.into_iter().rev() {
+        src.replace_range(range, &text);
+    }
+
+    (src, applied.len())
+}
+
+/// The ranges of the source code an edit replaces, and what it replaces each of them with.
+fn replacements(
+    src: &str,
+    decls: &Declarations,
+    edit: SuggestedEdit,
+) -> Vec<(Range<usize>, String)> {
+    let region = edit.region();
+    let start = region.start().offset as usize;
+    let end = region.end().offset as usize;
+
+    match edit {
+        SuggestedEdit::RemoveListEntry(_) => {
+            let (start, end) = with_separator(src, start, end);
+
+            vec![(start..end, String::new())]
+        }
+        SuggestedEdit::PrefixWithUnderscore(_) => vec![(start..start, "_".to_string())],
+        SuggestedEdit::IgnoreRecordField(_) => {
+            let name = &src[start..end];
+
+            vec![(start..end, format!("{}: _{}", name, name))]
+        }
+        SuggestedEdit::RemoveDef(_) => match find_def_region(region, decls) {
+            Some(def_region) => {
+                let def_start = def_region.start().offset as usize;
+                let def_end = def_region.end().offset as usize;
+
+                vec![(whole_lines(src, def_start, def_end), String::new())]
+            }
+            None => vec![],
+        },
+        SuggestedEdit::RenameShadow {
+            original_region,
+            shadow,
+        } => match find_shadowed_lookups(original_region, shadow, decls) {
+            Some(lookups) => {
+                let new_name = unused_name(src, &src[start..end]);
+
+                std::iter::once(shadow)
+                    .chain(lookups)
+                    .map(|region| {
+                        let range = region.start().offset as usize..region.end().offset as usize;
+
+                        (range, new_name.clone())
+                    })
+                    .collect()
+            }
+            None => vec![],
+        },
+    }
+}
+
+/// Extend the range of a list entry to include the comma separating it from the next entry,
+/// or from the previous one if it's the last entry.
+fn with_separator(src: &str, start: usize, end: usize) -> (usize, usize) {
+    let after = &src[end..];
+    let after_trimmed = after.trim_start();
+
+    if let Some(rest) = after_trimmed.strip_prefix(',') {
+        let whitespace = rest.len() - rest.trim_start().len();
+
+        return (start, src.len() - rest.len() + whitespace);
+    }
+
+    let before = src[..start].trim_end();
+
+    match before.strip_suffix(',') {
+        Some(before_comma) => (before_comma.len(), end),
+        None => (start, end),
+    }
+}
+
+/// Extend the range of a def to the whole lines it's on, along with the doc comment right above
+/// it, so that removing it doesn't leave an empty line or a comment about nothing behind.
+///
+/// A def always begins a line, but its region can begin after that, since the region of an
+/// annotation only covers the type.
+fn whole_lines(src: &str, start: usize, end: usize) -> Range<usize> {
+    let line_start = |offset: usize| src[..offset].rfind('\n').map_or(0, |index| index + 1);
+
+    let mut start = line_start(start);
+
+    while start > 0 {
+        let previous_line = line_start(start - 1);
+
+        if src[previous_line..start].trim_start().starts_with("##") {
+            start = previous_line;
+        } else {
+            break;
+        }
+    }
+
+    let end = match src[end..].find('\n') {
+        Some(newline) if src[end..end + newline].trim().is_empty() => end + newline + 1,
+        None if src[end..].trim().is_empty() => src.len(),
+        _ => end,
+    };
+
+    start..end
+}
+
+/// A name like `name2`, that isn't used anywhere in the source code yet.
+fn unused_name(src: &str, name: &str) -> String {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+
+    (2..)
+        .map(|number| format!("{}{}", name, number))
+        .find(|candidate| {
+            !src.match_indices(candidate.as_str()).any(|(index, _)| {
+                !src[..index].ends_with(is_ident_char)
+                    && !src[index + candidate.len()..].starts_with(is_ident_char)
+            })
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unused_name, whole_lines};
+
+    #[test]
+    fn whole_lines_of_a_def() {
+        let src = "x = 1\n\n## The answer\ny : Num *\ny = 42\n\nz = 3\n";
+        // The region of an annotated def begins at its type
+        let start = src.find("Num *").unwrap();
+        let end = src.find("42").unwrap() + 2;
+
+        let range = whole_lines(src, start, end);
+
+        assert_eq!(&src[range.clone()], "## The answer\ny : Num *\ny = 42\n");
+        assert_eq!(
+            format!("{}{}", &src[..range.start], &src[range.end..]),
+            "x = 1\n\n\nz = 3\n"
+        );
+    }
+
+    #[test]
+    fn whole_lines_of_an_indented_def_at_the_end() {
+        let src = "f = \\n ->\n    y = n\n    n";
+        let start = src.find("y =").unwrap();
+
+        assert_eq!(&src[whole_lines(src, start, start + 5)], "    y = n\n");
+    }
+
+    #[test]
+    fn unused_name_skips_names_in_use() {
+        assert_eq!(unused_name("x = 1\nx2 = 2\nxx3 = x", "x"), "x3");
+        assert_eq!(unused_name("count = count2_total", "count"), "count2");
+    }
+}
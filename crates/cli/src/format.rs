@@ -1,18 +1,24 @@
 use std::ffi::OsStr;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use crate::FormatMode;
 use bumpalo::Bump;
-use roc_error_macros::{internal_error, user_error};
+use roc_error_macros::internal_error;
 use roc_fmt::def::fmt_defs;
 use roc_fmt::module::fmt_module;
+use roc_fmt::reflow::reflow_defs;
 use roc_fmt::spaces::RemoveSpaces;
 use roc_fmt::{Ast, Buf};
+use roc_module::symbol::{Interns, ModuleIds};
 use roc_parse::{
     module::{self, module_defs},
-    parser::{Parser, SyntaxError},
+    parser::{FileError, Parser, SourceError, SyntaxError},
     state::State,
 };
+use roc_region::all::LineInfo;
+use roc_reporting::report::{parse_problem, RenderTarget, RocDocAllocator, DEFAULT_PALETTE};
+use similar::TextDiff;
 
 fn flatten_directories(files: std::vec::Vec<PathBuf>) -> std::vec::Vec<PathBuf> {
     let mut to_flatten = files;
@@ -58,101 +64,221 @@ fn is_roc_file(path: &Path) -> bool {
     matches!(path.extension().and_then(OsStr::to_str), Some("roc"))
 }
 
-pub fn format(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), String> {
+pub fn format(
+    files: std::vec::Vec<PathBuf>,
+    mode: FormatMode,
+    max_width: Option<u16>,
+) -> Result<(), String> {
     let files = flatten_directories(files);
+    let mut unparsable_files = 0;
+    let mut unformatted_files = 0;
 
     for file in files {
         let arena = Bump::new();
 
         let src = std::fs::read_to_string(&file).unwrap();
 
-        let ast = arena.alloc(parse_all(&arena, &src).unwrap_or_else(|e| {
-            user_error!("Unexpected parse failure when parsing this formatting:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
-        }));
-        let mut buf = Buf::new_in(&arena);
-        fmt_all(&mut buf, ast);
-
-        let reparsed_ast = arena.alloc(parse_all(&arena, buf.as_str()).unwrap_or_else(|e| {
-            let mut fail_file = file.clone();
-            fail_file.set_extension("roc-format-failed");
-            std::fs::write(&fail_file, buf.as_str()).unwrap();
-            internal_error!(
-                "Formatting bug; formatted code isn't valid\n\n\
-                I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
-                Parse error was: {:?}\n\n",
-                fail_file.display(),
-                e
-            );
-        }));
-
-        let ast_normalized = ast.remove_spaces(&arena);
-        let reparsed_ast_normalized = reparsed_ast.remove_spaces(&arena);
-
-        // HACK!
-        // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
-        // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
-        // I don't have the patience to debug this right now, so let's leave it for another day...
-        // TODO: fix PartialEq impl on ast types
-        if format!("{:?}", ast_normalized) != format!("{:?}", reparsed_ast_normalized) {
-            let mut fail_file = file.clone();
-            fail_file.set_extension("roc-format-failed");
-            std::fs::write(&fail_file, buf.as_str()).unwrap();
-
-            let mut before_file = file.clone();
-            before_file.set_extension("roc-format-failed-ast-before");
-            std::fs::write(&before_file, &format!("{:#?}\n", ast_normalized)).unwrap();
-
-            let mut after_file = file.clone();
-            after_file.set_extension("roc-format-failed-ast-after");
-            std::fs::write(&after_file, &format!("{:#?}\n", reparsed_ast_normalized)).unwrap();
-
-            internal_error!(
-                "Formatting bug; formatting didn't reparse as the same tree\n\n\
-                I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
-                I wrote the tree before and after formatting to these files for debugging purposes:\n{}\n{}\n\n",
-                fail_file.display(),
-                before_file.display(),
-                after_file.display());
-        }
-
-        // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
-        let mut reformatted_buf = Buf::new_in(&arena);
-        fmt_all(&mut reformatted_buf, reparsed_ast);
-        if buf.as_str() != reformatted_buf.as_str() {
-            let mut unstable_1_file = file.clone();
-            unstable_1_file.set_extension("roc-format-unstable-1");
-            std::fs::write(&unstable_1_file, buf.as_str()).unwrap();
-
-            let mut unstable_2_file = file.clone();
-            unstable_2_file.set_extension("roc-format-unstable-2");
-            std::fs::write(&unstable_2_file, reformatted_buf.as_str()).unwrap();
-
-            internal_error!(
-                "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
-                I wrote the result of formatting to this file for debugging purposes:\n{}\n\n\
-                I wrote the result of double-formatting here:\n{}\n\n",
-                unstable_1_file.display(),
-                unstable_2_file.display());
-        }
+        let formatted_src = match format_src(&arena, &src, &file, max_width) {
+            Ok(formatted_src) => formatted_src,
+            Err(problem) => {
+                // Keep going, so that all the problems get reported in one run
+                eprintln!("{}", parse_problem_report(&file, &src, problem));
+                unparsable_files += 1;
+                continue;
+            }
+        };
 
         match mode {
             FormatMode::CheckOnly => {
-                // If we notice that this file needs to be formatted, return early
-                if buf.as_str() != src {
-                    return Err("One or more files need to be reformatted.".to_string());
+                // If this file needs to be formatted, show how, then move on to the next one
+                if formatted_src != src {
+                    print!("{}", unified_diff(&file, &src, &formatted_src));
+                    unformatted_files += 1;
                 }
             }
 
             FormatMode::Format => {
-                // If all the checks above passed, actually write out the new file.
-                std::fs::write(&file, buf.as_str()).unwrap();
+                // If all the checks passed, actually write out the new file.
+                std::fs::write(&file, &formatted_src).unwrap();
+            }
+        }
+    }
+
+    if unparsable_files > 0 {
+        Err(format!(
+            "{} file(s) could not be formatted, because they have syntax errors.",
+            unparsable_files
+        ))
+    } else if unformatted_files > 0 {
+        Err("One or more files need to be reformatted.".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Format the source code read from stdin, printing the result to stdout.
+/// This is how editors integrate with the formatter.
+pub fn format_stdin(mode: FormatMode, max_width: Option<u16>) -> Result<(), String> {
+    let arena = Bump::new();
+
+    let mut src = String::new();
+    std::io::stdin()
+        .read_to_string(&mut src)
+        .map_err(|error| format!("I couldn't read from stdin: {}", error))?;
+
+    // Files written to help debug formatter bugs go in the temp dir, not the editor's cwd
+    let debug_path = std::env::temp_dir().join("stdin.roc");
+
+    let formatted_src = format_src(&arena, &src, &debug_path, max_width)
+        .map_err(|problem| parse_problem_report(Path::new(STDIN_FILENAME), &src, problem))?;
+
+    match mode {
+        FormatMode::CheckOnly => {
+            if formatted_src != src {
+                print!(
+                    "{}",
+                    unified_diff(Path::new(STDIN_FILENAME), &src, &formatted_src)
+                );
+
+                return Err("The code from stdin needs to be reformatted.".to_string());
             }
         }
+
+        FormatMode::Format => {
+            print!("{}", formatted_src);
+        }
     }
 
     Ok(())
 }
 
+const STDIN_FILENAME: &str = "stdin";
+
+/// Format the given source code, verifying that the result means the same thing and is stable.
+/// A formatter bug is an internal error, with details written next to `debug_path`.
+/// With a `max_width`, lines are broken and joined to fit within that many columns.
+fn format_src<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    debug_path: &Path,
+    max_width: Option<u16>,
+) -> Result<String, SyntaxError<'a>> {
+    let ast = arena.alloc(parse_all(arena, src)?);
+    let mut buf = Buf::new_in(arena);
+    fmt_all(arena, &mut buf, ast, max_width);
+
+    let reparsed_ast = arena.alloc(parse_all(arena, buf.as_str()).unwrap_or_else(|e| {
+        let mut fail_file = debug_path.to_path_buf();
+        fail_file.set_extension("roc-format-failed");
+        std::fs::write(&fail_file, buf.as_str()).unwrap();
+        internal_error!(
+            "Formatting bug; formatted code isn't valid\n\n\
+            I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
+            Parse error was: {:?}\n\n",
+            fail_file.display(),
+            e
+        );
+    }));
+
+    let ast_normalized = ast.remove_spaces(arena);
+    let reparsed_ast_normalized = reparsed_ast.remove_spaces(arena);
+
+    // HACK!
+    // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
+    // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
+    // I don't have the patience to debug this right now, so let's leave it for another day...
+    // TODO: fix PartialEq impl on ast types
+    if format!("{:?}", ast_normalized) != format!("{:?}", reparsed_ast_normalized) {
+        let mut fail_file = debug_path.to_path_buf();
+        fail_file.set_extension("roc-format-failed");
+        std::fs::write(&fail_file, buf.as_str()).unwrap();
+
+        let mut before_file = debug_path.to_path_buf();
+        before_file.set_extension("roc-format-failed-ast-before");
+        std::fs::write(&before_file, &format!("{:#?}\n", ast_normalized)).unwrap();
+
+        let mut after_file = debug_path.to_path_buf();
+        after_file.set_extension("roc-format-failed-ast-after");
+        std::fs::write(&after_file, &format!("{:#?}\n", reparsed_ast_normalized)).unwrap();
+
+        internal_error!(
+            "Formatting bug; formatting didn't reparse as the same tree\n\n\
+            I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
+            I wrote the tree before and after formatting to these files for debugging purposes:\n{}\n{}\n\n",
+            fail_file.display(),
+            before_file.display(),
+            after_file.display());
+    }
+
+    // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
+    let mut reformatted_buf = Buf::new_in(arena);
+    fmt_all(arena, &mut reformatted_buf, reparsed_ast, max_width);
+    if buf.as_str() != reformatted_buf.as_str() {
+        let mut unstable_1_file = debug_path.to_path_buf();
+        unstable_1_file.set_extension("roc-format-unstable-1");
+        std::fs::write(&unstable_1_file, buf.as_str()).unwrap();
+
+        let mut unstable_2_file = debug_path.to_path_buf();
+        unstable_2_file.set_extension("roc-format-unstable-2");
+        std::fs::write(&unstable_2_file, reformatted_buf.as_str()).unwrap();
+
+        internal_error!(
+            "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
+            I wrote the result of formatting to this file for debugging purposes:\n{}\n\n\
+            I wrote the result of double-formatting here:\n{}\n\n",
+            unstable_1_file.display(),
+            unstable_2_file.display());
+    }
+
+    Ok(buf.as_str().to_string())
+}
+
+/// Render a syntax error the same way the compiler does.
+fn parse_problem_report(filename: &Path, src: &str, problem: SyntaxError) -> String {
+    let src_lines: Vec<&str> = src.split('\n').collect();
+    let lines = LineInfo::new(src);
+
+    let mut module_ids = ModuleIds::default();
+    let home = module_ids.get_or_insert(&"Format".into());
+    let interns = Interns {
+        module_ids,
+        ..Default::default()
+    };
+
+    let alloc = RocDocAllocator::new(&src_lines, home, &interns);
+    let file_error = FileError {
+        problem: SourceError {
+            problem,
+            bytes: src.as_bytes(),
+        },
+        filename: filename.to_path_buf(),
+    };
+    let report = parse_problem(&alloc, &lines, filename.to_path_buf(), 0, file_error);
+
+    // Like clap's errors, only use colors when the report is going to a terminal
+    let render = if atty::is(atty::Stream::Stderr) {
+        RenderTarget::ColorTerminal
+    } else {
+        RenderTarget::Generic
+    };
+
+    let mut buf = String::new();
+    report.render(render, &mut buf, &alloc, &DEFAULT_PALETTE);
+
+    buf
+}
+
+/// A unified diff from the original source to the formatted source, like `diff -u` prints.
+fn unified_diff(filename: &Path, src: &str, formatted_src: &str) -> String {
+    let original_name = filename.display().to_string();
+    let formatted_name = format!("{} (formatted)", original_name);
+
+    TextDiff::from_lines(src, formatted_src)
+        .unified_diff()
+        .header(&original_name, &formatted_name)
+        .to_string()
+}
+
 fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
     let (module, state) = module::parse_header(arena, State::new(src.as_bytes()))
         .map_err(|e| SyntaxError::Header(e.problem))?;
@@ -162,10 +288,13 @@ fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'
     Ok(Ast { module, defs })
 }
 
-fn fmt_all<'a>(buf: &mut Buf<'a>, ast: &'a Ast) {
+fn fmt_all<'a>(arena: &'a Bump, buf: &mut Buf<'a>, ast: &'a Ast<'a>, max_width: Option<u16>) {
     fmt_module(buf, &ast.module);
 
-    fmt_defs(buf, &ast.defs, 0);
+    match max_width {
+        Some(max_width) => fmt_defs(buf, &reflow_defs(arena, &ast.defs, max_width), 0),
+        None => fmt_defs(buf, &ast.defs, 0),
+    }
 
     buf.fmt_end_of_file();
 }
@@ -25,12 +25,18 @@ use target_lexicon::{
 use tempfile::TempDir;
 
 pub mod build;
+#[cfg(not(windows))]
+mod doc_test;
+mod fix;
 mod format;
-pub use format::format;
+pub use fix::fix;
+pub use format::{format, format_stdin};
 
 use crate::build::{BuildFileError, BuildOrdering};
 
 const DEFAULT_ROC_FILENAME: &str = "main.roc";
+const DEFAULT_DOCS_OUTPUT_DIR: &str = "generated-docs";
+const DEFAULT_BUILTINS_DOCS_URL: &str = "https://www.roc-lang.org/builtins";
 
 pub const CMD_BUILD: &str = "build";
 pub const CMD_RUN: &str = "run";
@@ -39,10 +45,12 @@ pub const CMD_REPL: &str = "repl";
 pub const CMD_EDIT: &str = "edit";
 pub const CMD_DOCS: &str = "docs";
 pub const CMD_CHECK: &str = "check";
+pub const CMD_FIX: &str = "fix";
 pub const CMD_VERSION: &str = "version";
 pub const CMD_FORMAT: &str = "format";
 pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
+pub const CMD_EXPLAIN: &str = "explain";
 
 pub const FLAG_DEBUG: &str = "debug";
 pub const FLAG_DEV: &str = "dev";
@@ -56,12 +64,22 @@ pub const FLAG_TIME: &str = "time";
 pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_PREBUILT: &str = "prebuilt-platform";
 pub const FLAG_CHECK: &str = "check";
+pub const FLAG_STDIN: &str = "stdin";
+pub const FLAG_MAX_WIDTH: &str = "max-width";
+pub const FLAG_OUTPUT: &str = "output";
+pub const FLAG_BASE_URL: &str = "base-url";
+pub const FLAG_BUILTINS_URL: &str = "builtins-url";
+pub const FLAG_PACKAGE_URL: &str = "package-url";
+pub const FLAG_BACKEND: &str = "backend";
+pub const FLAG_DOC: &str = "doc";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
+pub const FLAG_SHOW_HOLES: &str = "show-holes";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
 pub const GLUE_FILE: &str = "GLUE_FILE";
 pub const DIRECTORY_OR_FILES: &str = "DIRECTORY_OR_FILES";
 pub const ARGS_FOR_APP: &str = "ARGS_FOR_APP";
+pub const ERROR_CODE: &str = "ERROR_CODE";
 
 const VERSION: &str = include_str!("../../../version.txt");
 
@@ -90,7 +108,7 @@ pub fn build_app<'a>() -> Command<'a> {
 
     let flag_debug = Arg::new(FLAG_DEBUG)
         .long(FLAG_DEBUG)
-        .help("Store LLVM debug information in the generated program")
+        .help("Store debug information in the generated program (LLVM debug info, or DWARF and a source map for --dev wasm32 builds)")
         .required(false);
 
     let flag_time = Arg::new(FLAG_TIME)
@@ -183,6 +201,12 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(
+                Arg::new(FLAG_DOC)
+                    .long(FLAG_DOC)
+                    .help("Run the `>>>` examples in doc comments instead of the top-level `expect`s\n(A line right after an example is the value it should evaluate to.)")
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -194,6 +218,14 @@ pub fn build_app<'a>() -> Command<'a> {
         )
         .subcommand(Command::new(CMD_REPL)
             .about("Launch the interactive Read Eval Print Loop (REPL)")
+            .arg(
+                Arg::new(FLAG_BACKEND)
+                    .long(FLAG_BACKEND)
+                    .help("Choose the code generator for evaluating expressions\n(The dev backend falls back to LLVM for anything it doesn't support yet, unless dev-only is chosen.)")
+                    .possible_values(["dev", "dev-only", "llvm"])
+                    .default_value("dev")
+                    .required(false),
+            )
         )
         .subcommand(Command::new(CMD_RUN)
             .about("Run a .roc file even if it has build errors")
@@ -232,7 +264,22 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(
                 Arg::new(FLAG_CHECK)
                     .long(FLAG_CHECK)
-                    .help("Checks that specified files are formatted\n(If formatting is needed, return a non-zero exit code.)")
+                    .help("Checks that specified files are formatted\n(If formatting is needed, print a diff of the changes and return a non-zero exit code.)")
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_STDIN)
+                    .long(FLAG_STDIN)
+                    .help("Format code read from stdin, and print the result to stdout\n(Useful for editor integrations.)")
+                    .conflicts_with(DIRECTORY_OR_FILES)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_MAX_WIDTH)
+                    .long(FLAG_MAX_WIDTH)
+                    .help("Break and join lines so that code fits within this many columns\n(Without this, code keeps the line breaks it already has.)")
+                    .takes_value(true)
+                    .validator(|s| s.parse::<u16>())
                     .required(false),
             )
         )
@@ -242,6 +289,12 @@ pub fn build_app<'a>() -> Command<'a> {
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
             .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(FLAG_SHOW_HOLES)
+                    .long(FLAG_SHOW_HOLES)
+                    .help("Report the inferred type of every `_` in an annotation as a warning")
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -250,15 +303,67 @@ pub fn build_app<'a>() -> Command<'a> {
                     .default_value(DEFAULT_ROC_FILENAME),
             )
             )
+        .subcommand(Command::new(CMD_EXPLAIN)
+            .about("Explain an error code from the header of a report, like E3010")
+            .arg(
+                Arg::new(ERROR_CODE)
+                    .help("The code to explain")
+                    .required(true),
+            )
+            )
+        .subcommand(Command::new(CMD_FIX)
+            .about("Check the code for problems, and fix the ones that have an automatic fix\n(Like removing unused imports and definitions, or marking unused arguments with an underscore.)")
+            .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of an app to fix, along with the modules it imports")
+                    .allow_invalid_utf8(true)
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+            )
         .subcommand(
             Command::new(CMD_DOCS)
                 .about("Generate documentation for Roc modules (Work In Progress)")
                 .arg(Arg::new(DIRECTORY_OR_FILES)
                     .multiple_values(true)
                     .required(false)
-                    .help("The directory or files to build documentation for")
+                    .help("The directory or files to build documentation for\n(If one of them has a `package` or `platform` header, only the modules it exposes get documented.)")
                     .allow_invalid_utf8(true)
                 )
+                .arg(
+                    Arg::new(FLAG_OUTPUT)
+                        .long(FLAG_OUTPUT)
+                        .help("The directory to write the generated docs to")
+                        .allow_invalid_utf8(true)
+                        .default_value(DEFAULT_DOCS_OUTPUT_DIR)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(FLAG_BASE_URL)
+                        .long(FLAG_BASE_URL)
+                        .help("The URL path the docs will be served from, e.g. /packages/json\n(Links between pages of the docs start with this.)")
+                        .takes_value(true)
+                        .default_value("/")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(FLAG_BUILTINS_URL)
+                        .long(FLAG_BUILTINS_URL)
+                        .help("Where the docs for the builtins are served from\n(Builtin types and values in signatures and docs link there.)")
+                        .takes_value(true)
+                        .default_value(DEFAULT_BUILTINS_DOCS_URL)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(FLAG_PACKAGE_URL)
+                        .long(FLAG_PACKAGE_URL)
+                        .help("Where the docs for the package imported with the given shorthand are served from, e.g. json=/packages/json\n(Types and values from that package link there. Can be given once per package.)")
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .validator(|value| parse_package_url(value).map(|_| ()))
+                        .required(false),
+                )
         )
         .subcommand(Command::new(CMD_GLUE)
             .about("Generate glue code between a platform's Roc API and its host language")
@@ -315,6 +420,18 @@ pub enum FormatMode {
     CheckOnly,
 }
 
+/// Split a `--package-url` value like `json=/packages/json` into the package's shorthand and the
+/// URL its docs are served from.
+pub fn parse_package_url(value: &str) -> Result<(&str, &str), String> {
+    match value.split_once('=') {
+        Some((shorthand, url)) if !shorthand.is_empty() && !url.is_empty() => Ok((shorthand, url)),
+        _ => Err(format!(
+            "`{}` should be a package shorthand and a URL, e.g. json=/packages/json",
+            value
+        )),
+    }
+}
+
 #[cfg(windows)]
 pub fn test(_matches: &ArgMatches, _triple: Triple) -> io::Result<i32> {
     todo!("running tests does not work on windows right now")
@@ -372,6 +489,10 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
         process::exit(1);
     }
 
+    if matches.is_present(FLAG_DOC) {
+        return doc_test::test_docs(path, &triple, opt_level, threading);
+    }
+
     let arena = &arena;
     let target = &triple;
     let opt_level = opt_level;
@@ -395,6 +516,8 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     let mut expectations = std::mem::take(&mut loaded.expectations);
     let loaded = loaded;
 
+    build::reject_dbg_in_optimized_build(opt_level, &expectations);
+
     let interns = loaded.interns.clone();
 
     let (lib, expects, layout_interner) = roc_repl_expect::run::expect_mono_module_to_dylib(
@@ -1106,6 +1229,7 @@ pub enum Target {
     Linux64,
     Windows64,
     Wasm32,
+    Wasm32Wasi,
 }
 
 impl Default for Target {
@@ -1124,6 +1248,7 @@ impl Target {
             Linux64 => "linux64",
             Windows64 => "windows64",
             Wasm32 => "wasm32",
+            Wasm32Wasi => "wasm32-wasi",
         }
     }
 
@@ -1134,6 +1259,7 @@ impl Target {
         Target::Linux64.as_str(),
         Target::Windows64.as_str(),
         Target::Wasm32.as_str(),
+        Target::Wasm32Wasi.as_str(),
     ];
 
     pub fn to_triple(self) -> Triple {
@@ -1169,6 +1295,13 @@ impl Target {
                 environment: Environment::Unknown,
                 binary_format: BinaryFormat::Wasm,
             },
+            Wasm32Wasi => Triple {
+                architecture: Architecture::Wasm32,
+                vendor: Vendor::Unknown,
+                operating_system: OperatingSystem::Wasi,
+                environment: Environment::Musl,
+                binary_format: BinaryFormat::Wasm,
+            },
         }
     }
 }
@@ -1195,6 +1328,7 @@ impl std::str::FromStr for Target {
             "linux64" => Ok(Target::Linux64),
             "windows64" => Ok(Target::Windows64),
             "wasm32" => Ok(Target::Wasm32),
+            "wasm32-wasi" => Ok(Target::Wasm32Wasi),
             _ => Err(format!("Roc does not know how to compile to {}", string)),
         }
     }
@@ -1,9 +1,11 @@
 use roc_build::link::LinkType;
 use roc_cli::build::check_file;
 use roc_cli::{
-    build_app, format, test, BuildConfig, FormatMode, Target, CMD_BUILD, CMD_CHECK, CMD_DEV,
-    CMD_DOCS, CMD_EDIT, CMD_FORMAT, CMD_GLUE, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION,
-    DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_LIB, FLAG_NO_LINK, FLAG_TARGET, FLAG_TIME, GLUE_FILE,
+    build_app, fix, format, format_stdin, parse_package_url, test, BuildConfig, FormatMode, Target,
+    CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_DOCS, CMD_EDIT, CMD_EXPLAIN, CMD_FIX, CMD_FORMAT, CMD_GLUE,
+    CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, ERROR_CODE, FLAG_BACKEND,
+    FLAG_BASE_URL, FLAG_BUILTINS_URL, FLAG_CHECK, FLAG_LIB, FLAG_MAX_WIDTH, FLAG_NO_LINK,
+    FLAG_OUTPUT, FLAG_PACKAGE_URL, FLAG_SHOW_HOLES, FLAG_STDIN, FLAG_TARGET, FLAG_TIME, GLUE_FILE,
     ROC_FILE,
 };
 use roc_docs::generate_docs_html;
@@ -117,6 +119,7 @@ fn main() -> io::Result<()> {
             let arena = bumpalo::Bump::new();
 
             let emit_timings = matches.is_present(FLAG_TIME);
+            let show_holes = matches.is_present(FLAG_SHOW_HOLES);
             let filename = matches.value_of_os(ROC_FILE).unwrap();
             let roc_file_path = PathBuf::from(filename);
             let threading = match matches
@@ -129,7 +132,7 @@ fn main() -> io::Result<()> {
                 Some(n) => Threading::AtMost(n),
             };
 
-            match check_file(&arena, roc_file_path, emit_timings, threading) {
+            match check_file(&arena, roc_file_path, emit_timings, show_holes, threading) {
                 Ok((problems, total_time)) => {
                     println!(
                         "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.",
@@ -171,9 +174,77 @@ fn main() -> io::Result<()> {
                 }
             }
         }
-        Some((CMD_REPL, _)) => {
+        Some((CMD_EXPLAIN, matches)) => {
+            let code = matches.value_of(ERROR_CODE).unwrap();
+
+            match roc_reporting::error::explain::explain(code) {
+                Some(explanation) => {
+                    print!("{}", explanation);
+
+                    Ok(0)
+                }
+                None => {
+                    eprintln!(
+                        "`{}` is not an error code I know about. Codes look like E3010, and are shown in the header of each report.",
+                        code
+                    );
+
+                    Ok(1)
+                }
+            }
+        }
+        Some((CMD_FIX, matches)) => {
+            let arena = bumpalo::Bump::new();
+
+            let filename = matches.value_of_os(ROC_FILE).unwrap();
+            let roc_file_path = PathBuf::from(filename);
+            let threading = match matches
+                .value_of(roc_cli::FLAG_MAX_THREADS)
+                .and_then(|s| s.parse::<usize>().ok())
+            {
+                None => Threading::AllAvailable,
+                Some(0) => user_error!("cannot build with at most 0 threads"),
+                Some(1) => Threading::Single,
+                Some(n) => Threading::AtMost(n),
+            };
+
+            match fix(&arena, roc_file_path, threading) {
+                Ok(fixed_files) => {
+                    if fixed_files.is_empty() {
+                        println!("There was nothing that `roc fix` knows how to fix.");
+                    }
+
+                    for (path, num_edits) in fixed_files {
+                        println!(
+                            "Applied {} {} to {}",
+                            num_edits,
+                            if num_edits == 1 { "fix" } else { "fixes" },
+                            path.display()
+                        );
+                    }
+
+                    Ok(0)
+                }
+
+                Err(LoadingProblem::FormattedReport(report)) => {
+                    print!("{}", report);
+
+                    Ok(1)
+                }
+                Err(other) => {
+                    panic!("fix failed with error:\n{:?}", other);
+                }
+            }
+        }
+        Some((CMD_REPL, matches)) => {
             {
-                roc_repl_cli::main()?;
+                let backend = match matches.value_of(FLAG_BACKEND) {
+                    Some("llvm") => roc_repl_cli::Backend::Llvm,
+                    Some("dev-only") => roc_repl_cli::Backend::DevOnly,
+                    _ => roc_repl_cli::Backend::Dev,
+                };
+
+                roc_repl_cli::main(backend)?;
 
                 // Exit 0 if the repl exited normally
                 Ok(0)
@@ -226,47 +297,67 @@ fn main() -> io::Result<()> {
                 roc_files_recursive(os_str.as_os_str(), metadata.file_type(), &mut roc_files)?;
             }
 
-            generate_docs_html(roc_files);
+            let output_dir = Path::new(matches.value_of_os(FLAG_OUTPUT).unwrap());
+            let base_url = matches.value_of(FLAG_BASE_URL).unwrap();
+            let builtins_url = matches.value_of(FLAG_BUILTINS_URL).unwrap();
+            let package_urls: Vec<(&str, &str)> = matches
+                .values_of(FLAG_PACKAGE_URL)
+                .into_iter()
+                .flatten()
+                .map(|value| parse_package_url(value).unwrap())
+                .collect();
+
+            generate_docs_html(roc_files, output_dir, base_url, builtins_url, &package_urls);
 
             Ok(0)
         }
         Some((CMD_FORMAT, matches)) => {
-            let maybe_values = matches.values_of_os(DIRECTORY_OR_FILES);
+            let format_mode = match matches.is_present(FLAG_CHECK) {
+                true => FormatMode::CheckOnly,
+                false => FormatMode::Format,
+            };
 
-            let mut values: Vec<OsString> = Vec::new();
+            let max_width = matches
+                .value_of(FLAG_MAX_WIDTH)
+                .and_then(|s| s.parse::<u16>().ok());
 
-            match maybe_values {
-                None => {
-                    let mut os_string_values: Vec<OsString> = Vec::new();
-                    read_all_roc_files(
-                        &std::env::current_dir()?.as_os_str().to_os_string(),
-                        &mut os_string_values,
-                    )?;
-                    for os_string in os_string_values {
-                        values.push(os_string);
+            let format_result = if matches.is_present(FLAG_STDIN) {
+                format_stdin(format_mode, max_width)
+            } else {
+                let maybe_values = matches.values_of_os(DIRECTORY_OR_FILES);
+
+                let mut values: Vec<OsString> = Vec::new();
+
+                match maybe_values {
+                    None => {
+                        let mut os_string_values: Vec<OsString> = Vec::new();
+                        read_all_roc_files(
+                            &std::env::current_dir()?.as_os_str().to_os_string(),
+                            &mut os_string_values,
+                        )?;
+                        for os_string in os_string_values {
+                            values.push(os_string);
+                        }
                     }
-                }
-                Some(os_values) => {
-                    for os_str in os_values {
-                        values.push(os_str.to_os_string());
+                    Some(os_values) => {
+                        for os_str in os_values {
+                            values.push(os_str.to_os_string());
+                        }
                     }
                 }
-            }
 
-            let mut roc_files = Vec::new();
+                let mut roc_files = Vec::new();
 
-            // Populate roc_files
-            for os_str in values {
-                let metadata = fs::metadata(os_str.clone())?;
-                roc_files_recursive(os_str.as_os_str(), metadata.file_type(), &mut roc_files)?;
-            }
+                // Populate roc_files
+                for os_str in values {
+                    let metadata = fs::metadata(os_str.clone())?;
+                    roc_files_recursive(os_str.as_os_str(), metadata.file_type(), &mut roc_files)?;
+                }
 
-            let format_mode = match matches.is_present(FLAG_CHECK) {
-                true => FormatMode::CheckOnly,
-                false => FormatMode::Format,
+                format(roc_files, format_mode, max_width)
             };
 
-            let format_exit_code = match format(roc_files, format_mode) {
+            let format_exit_code = match format_result {
                 Ok(_) => 0,
                 Err(message) => {
                     eprintln!("{}", message);
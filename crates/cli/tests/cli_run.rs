@@ -11,12 +11,15 @@ extern crate roc_module;
 mod cli_run {
     use cli_utils::helpers::{
         example_file, examples_dir, extract_valgrind_errors, fixture_file, fixtures_dir,
-        known_bad_file, run_cmd, run_roc, run_with_valgrind, strip_colors, Out, ValgrindError,
-        ValgrindErrorXWhat,
+        known_bad_file, root_dir, run_cmd, run_roc, run_with_valgrind, strip_colors, Out,
+        ValgrindError, ValgrindErrorXWhat,
     };
     use const_format::concatcp;
     use indoc::indoc;
-    use roc_cli::{CMD_BUILD, CMD_CHECK, CMD_FORMAT, CMD_RUN};
+    use roc_cli::{
+        CMD_BUILD, CMD_CHECK, CMD_DOCS, CMD_FIX, CMD_FORMAT, CMD_RUN, CMD_TEST, FLAG_BASE_URL,
+        FLAG_DOC, FLAG_OUTPUT,
+    };
     use roc_test_utils::assert_multiline_str_eq;
     use serial_test::serial;
     use std::iter;
@@ -27,8 +30,8 @@ mod cli_run {
     const OPTIMIZE_FLAG: &str = concatcp!("--", roc_cli::FLAG_OPTIMIZE);
     const LINKER_FLAG: &str = concatcp!("--", roc_cli::FLAG_LINKER);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
+    const STDIN_FLAG: &str = concatcp!("--", roc_cli::FLAG_STDIN);
     const PREBUILT_PLATFORM: &str = concatcp!("--", roc_cli::FLAG_PREBUILT, "=true");
-    #[allow(dead_code)]
     const TARGET_FLAG: &str = concatcp!("--", roc_cli::FLAG_TARGET);
 
     use std::sync::Once;
@@ -223,6 +226,41 @@ mod cli_run {
         }
     }
 
+    /// Builds the file for the wasm32-wasi target, and runs the resulting
+    /// standalone .wasm file with wasmer's WASI runtime.
+    #[cfg(feature = "wasm32-cli-run")]
+    fn check_wasi_output_with_stdin(
+        file: &Path,
+        stdin: &[&str],
+        executable_filename: &str,
+        flags: &[&str],
+        expected_ending: &str,
+    ) {
+        let mut flags = flags.to_vec();
+        flags.push(concatcp!(TARGET_FLAG, "=wasm32-wasi"));
+
+        let compile_out = run_roc(
+            [CMD_BUILD, file.to_str().unwrap()]
+                .iter()
+                .chain(flags.as_slice()),
+            &[],
+        );
+
+        assert!(compile_out.status.success(), "bad status {:?}", compile_out);
+
+        let path = file
+            .with_file_name(executable_filename)
+            .with_extension("wasm");
+        let stdout = crate::run_with_wasmer(&path, stdin);
+
+        if !stdout.ends_with(expected_ending) {
+            panic!(
+                "expected output to end with {:?} but instead got {:#?}",
+                expected_ending, stdout
+            );
+        }
+    }
+
     #[cfg(feature = "wasm32-cli-run")]
     fn check_wasm_output_with_stdin(
         file: &Path,
@@ -312,6 +350,32 @@ mod cli_run {
                             eprintln!("WARNING: skipping testing example {} because it only works in a browser!", example.filename);
                             return;
                         }
+                        "rocLovesWasi" => {
+                            // this runs in a WASI runtime rather than natively, so check it with wasmer
+                            #[cfg(feature = "wasm32-cli-run")]
+                            {
+                                check_wasi_output_with_stdin(
+                                    &file_name,
+                                    example.stdin,
+                                    example.executable_filename,
+                                    &[],
+                                    example.expected_ending,
+                                );
+
+                                check_wasi_output_with_stdin(
+                                    &file_name,
+                                    example.stdin,
+                                    example.executable_filename,
+                                    &[OPTIMIZE_FLAG],
+                                    example.expected_ending,
+                                );
+                            }
+
+                            #[cfg(not(feature = "wasm32-cli-run"))]
+                            eprintln!("WARNING: skipping testing example {} because it needs the wasm32-cli-run feature!", example.filename);
+
+                            return;
+                        }
                         "args" => {
                             custom_flags = vec![LINKER_FLAG, "legacy"];
                         }
@@ -435,6 +499,14 @@ mod cli_run {
             expected_ending:"Roc <3 Web Assembly!\n",
             use_valgrind: true,
         },
+        platformSwitchingWasi:"platform-switching" => Example {
+            filename: "rocLovesWasi.roc",
+            executable_filename: "rocLovesWasi",
+            stdin: &[],
+            arguments: &[],
+            expected_ending:"Roc <3 WASI!\n",
+            use_valgrind: false,
+        },
         platformSwitchingZig:"platform-switching" => Example {
             filename: "rocLovesZig.roc",
             executable_filename: "rocLovesZig",
@@ -967,7 +1039,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── UNRECOGNIZED NAME ─────────────────────────── tests/known_bad/TypeError.roc ─
+                ── UNRECOGNIZED NAME [E2051] ─────────────────── tests/known_bad/TypeError.roc ─
 
                 Nothing is named `d` in this scope.
 
@@ -995,7 +1067,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── MISSING DEFINITION ────────────────── tests/known_bad/ExposedNotDefined.roc ─
+                ── MISSING DEFINITION [E2025] ────────── tests/known_bad/ExposedNotDefined.roc ─
 
                 bar is listed as exposed, but it isn't defined in this module.
 
@@ -1016,7 +1088,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── UNUSED IMPORT ──────────────────────────── tests/known_bad/UnusedImport.roc ─
+                ── UNUSED IMPORT [E2054] ──────────────────── tests/known_bad/UnusedImport.roc ─
 
                 Nothing from Symbol is used in this module.
 
@@ -1039,7 +1111,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── UNKNOWN GENERATES FUNCTION ─────── tests/known_bad/UnknownGeneratesWith.roc ─
+                ── UNKNOWN GENERATES FUNCTION [E2048] ─ .../known_bad/UnknownGeneratesWith.roc ─
 
                 I don't know how to generate the foobar function.
 
@@ -1056,6 +1128,139 @@ mod cli_run {
         );
     }
 
+    #[test]
+    fn fix_unused_imports_and_arguments() {
+        // Fix a copy, so that the fixtures stay unfixed
+        let dir = std::env::temp_dir().join("roc_cli_fix");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        for file_name in ["NeedsFixes.roc", "Unused.roc", "Used.roc"] {
+            std::fs::copy(fixture_file("fix", file_name), dir.join(file_name)).unwrap();
+        }
+
+        let file = dir.join("NeedsFixes.roc");
+        let out = run_roc([CMD_FIX, file.to_str().unwrap()], &[]);
+
+        assert!(out.status.success(), "{}", out.stderr);
+        assert!(out.stdout.contains("Applied 4 fixes"), "{}", out.stdout);
+
+        assert_multiline_str_eq!(
+            std::fs::read_to_string(&file).unwrap().as_str(),
+            indoc!(
+                r#"
+                interface NeedsFixes
+                    exposes [describe]
+                    imports [Used.{ twice }]
+
+                describe = \_count, { label, verbose: _verbose } ->
+                    twice label
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn fix_unused_defs_and_shadowing() {
+        let dir = std::env::temp_dir().join("roc_cli_fix_defs");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let file = dir.join("Tidy.roc");
+        std::fs::copy(fixture_file("fix", "Tidy.roc"), &file).unwrap();
+
+        let out = run_roc([CMD_FIX, file.to_str().unwrap()], &[]);
+
+        assert!(out.status.success(), "{}", out.stderr);
+        assert!(out.stdout.contains("Applied 4 fixes"), "{}", out.stdout);
+
+        assert_multiline_str_eq!(
+            std::fs::read_to_string(&file).unwrap().as_str(),
+            indoc!(
+                r#"
+                interface Tidy
+                    exposes [area, describe, firstOr]
+                    imports []
+
+                area = \width, height ->
+                    width * height
+
+                describe = \name ->
+                    greet = \name2 -> Str.concat "Hi, " name2
+
+                    greet name
+
+                firstOr = \list, default ->
+                    when List.first list is
+                        Ok default2 -> default2
+                        Err _ -> default
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn docs_output_dir_and_base_url() {
+        let output_dir = std::env::temp_dir().join("roc_cli_docs");
+        let _ = std::fs::remove_dir_all(&output_dir);
+
+        let file = fixture_file("doc-tests", "Arith.roc");
+        let out = run_roc(
+            [
+                CMD_DOCS,
+                file.to_str().unwrap(),
+                &format!("--{}", FLAG_OUTPUT),
+                output_dir.to_str().unwrap(),
+                &format!("--{}", FLAG_BASE_URL),
+                "packages/arith",
+            ],
+            &[],
+        );
+
+        assert!(out.status.success(), "{}", out.stderr);
+
+        let html = std::fs::read_to_string(output_dir.join("Arith").join("index.html")).unwrap();
+        assert!(html.contains(r#"href="/packages/arith/Arith""#), "{}", html);
+        assert!(
+            html.contains(r#"src="/packages/arith/search.js""#),
+            "{}",
+            html
+        );
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn doc_examples_are_tested() {
+        let file = fixture_file("doc-tests", "Arith.roc");
+        let out = run_roc(
+            [CMD_TEST, &format!("--{}", FLAG_DOC), file.to_str().unwrap()],
+            &[],
+        );
+        let stdout = strip_colors(&out.stdout);
+
+        assert!(!out.status.success(), "{}", stdout);
+        assert!(stdout.contains("1 failed and 1 passed"), "{}", stdout);
+        assert!(
+            stdout.contains("This doc example failed: ") && stdout.contains("Arith.roc:14"),
+            "{}",
+            stdout
+        );
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn builtin_doc_examples_are_tested() {
+        let mut file = root_dir();
+        file.extend(["crates", "compiler", "builtins", "roc", "Bool.roc"]);
+
+        let out = run_roc(
+            [CMD_TEST, &format!("--{}", FLAG_DOC), file.to_str().unwrap()],
+            &[],
+        );
+        let stdout = strip_colors(&out.stdout);
+
+        assert!(out.status.success(), "{}", stdout);
+        assert!(stdout.contains("0 failed and 8 passed"), "{}", stdout);
+    }
+
     #[test]
     fn format_check_good() {
         check_format_check_as_expected(&fixture_file("format", "Formatted.roc"), true);
@@ -1074,6 +1279,71 @@ mod cli_run {
         // This doesn't fail, since only "Formatted.roc" and non-roc files are present in this folder
         check_format_check_as_expected(&fixtures_dir("format/formatted_directory"), true);
     }
+
+    #[test]
+    fn format_check_prints_diff() {
+        let file = fixture_file("format", "NotFormatted.roc");
+        let out = run_roc([CMD_FORMAT, file.to_str().unwrap(), CHECK_FLAG], &[]);
+
+        assert!(!out.status.success());
+        assert!(out.stdout.starts_with(&format!(
+            "--- {}\n+++ {} (formatted)\n@@ ",
+            file.display(),
+            file.display()
+        )));
+    }
+
+    #[test]
+    fn format_stdin() {
+        let formatted = std::fs::read_to_string(fixture_file("format", "Formatted.roc")).unwrap();
+        let out = run_roc([CMD_FORMAT, STDIN_FLAG], &[&formatted]);
+
+        assert!(out.status.success());
+        assert_eq!(out.stdout, formatted);
+
+        let not_formatted =
+            std::fs::read_to_string(fixture_file("format", "NotFormatted.roc")).unwrap();
+        let out = run_roc([CMD_FORMAT, STDIN_FLAG], &[&not_formatted]);
+
+        assert!(out.status.success());
+        assert_ne!(out.stdout, not_formatted);
+    }
+
+    #[test]
+    fn format_syntax_error() {
+        let out = run_roc(
+            [
+                CMD_FORMAT,
+                known_bad_file("SyntaxError.roc").to_str().unwrap(),
+            ],
+            &[],
+        );
+        let stderr = strip_colors(&out.stderr);
+
+        assert!(!out.status.success());
+        assert!(stderr.contains("SyntaxError.roc"), "{}", stderr);
+        assert!(stderr.contains("could not be formatted"), "{}", stderr);
+
+        // stderr isn't a terminal here, so the parse report shouldn't have any colors
+        assert_eq!(stderr, out.stderr);
+
+        let expected_report = indoc!(
+            r#"
+            I am partway through parsing an record, but I got stuck here:
+
+            1│  app "syntaxError"
+            2│      packages { pf: "platform/main.roc" }
+            3│      imports []
+            4│      provides [main] to pf
+            5│
+            6│  main = { a: 1
+                       ^
+            "#
+        );
+
+        assert!(stderr.contains("RECORD PARSE PROBLEM"), "{}", stderr);
+        assert!(stderr.contains(expected_report), "{}", stderr);
+    }
 }
 
 #[allow(dead_code)]
@@ -0,0 +1,17 @@
+interface Arith
+    exposes [double, half]
+    imports []
+
+## Double a number.
+##
+## >>> Arith.double 4
+## 8
+double : I64 -> I64
+double = \n -> n * 2
+
+## Halve a number, rounding down.
+##
+## >>> Arith.half 9
+## 5
+half : I64 -> I64
+half = \n -> n // 2
@@ -0,0 +1,6 @@
+interface NeedsFixes
+    exposes [describe]
+    imports [Unused, Used.{ twice, thrice }]
+
+describe = \count, { label, verbose } ->
+    twice label
@@ -0,0 +1,20 @@
+interface Tidy
+    exposes [area, describe, firstOr]
+    imports []
+
+## Not used anywhere
+unused : Num *
+unused = 42
+
+area = \width, height ->
+    scale = 2
+    width * height
+
+describe = \name ->
+    greet = \name -> Str.concat "Hi, " name
+    greet name
+
+firstOr = \list, default ->
+    when List.first list is
+        Ok default -> default
+        Err _ -> default
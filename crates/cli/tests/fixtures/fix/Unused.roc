@@ -0,0 +1,5 @@
+interface Unused
+    exposes [unused]
+    imports []
+
+unused = 1
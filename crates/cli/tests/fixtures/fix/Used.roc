@@ -0,0 +1,7 @@
+interface Used
+    exposes [twice, thrice]
+    imports []
+
+twice = \n -> n * 2
+
+thrice = \n -> n * 3
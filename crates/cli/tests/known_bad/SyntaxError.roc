@@ -0,0 +1,6 @@
+app "syntaxError"
+    packages { pf: "platform/main.roc" }
+    imports []
+    provides [main] to pf
+
+main = { a: 1
@@ -494,6 +494,7 @@ fn stmt_spec<'a>(
         }
         Expect { remainder, .. } => stmt_spec(builder, interner, env, block, layout, remainder),
         ExpectFx { remainder, .. } => stmt_spec(builder, interner, env, block, layout, remainder),
+        Dbg { remainder, .. } => stmt_spec(builder, interner, env, block, layout, remainder),
         Ret(symbol) => Ok(env.symbols[symbol]),
         Refcounting(modify_rc, continuation) => match modify_rc {
             ModifyRc::Inc(symbol, _) => {
@@ -585,6 +586,13 @@ fn stmt_spec<'a>(
             let jpid = env.join_points[id];
             builder.add_jump(block, jpid, argument, ret_type_id)
         }
+        Crash(msg, _, _) => {
+            // Model this as an unknown call on the message rather than a bare
+            // TERMINATE, so the message counts as used.
+            let result_type = layout_spec(builder, interner, layout, &WhenRecursive::Unreachable)?;
+
+            builder.add_unknown_with(block, &[env.symbols[msg]], result_type)
+        }
         RuntimeError(_) => {
             let type_id = layout_spec(builder, interner, layout, &WhenRecursive::Unreachable)?;
 
@@ -1269,8 +1269,11 @@ pub fn llvm_module_to_dylib(
 
     child.wait().unwrap();
 
-    // Load the dylib
-    let path = dylib_path.as_path().to_str().unwrap();
+    load_dylib(&dylib_path, target)
+}
+
+fn load_dylib(dylib_path: &Path, target: &Triple) -> Result<Library, Error> {
+    let path = dylib_path.to_str().unwrap();
 
     if matches!(target.architecture, Architecture::Aarch64(_)) {
         // On AArch64 darwin machines, calling `ldopen` on Roc-generated libs from multiple threads
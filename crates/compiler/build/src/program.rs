@@ -183,6 +183,7 @@ pub fn gen_from_mono_module(
             loaded,
             target,
             app_o_file,
+            emit_debug_info,
             preprocessed_host_path,
             wasm_dev_stack_bytes,
         ),
@@ -422,6 +423,7 @@ pub fn gen_from_mono_module_dev(
     loaded: MonomorphizedModule,
     target: &target_lexicon::Triple,
     app_o_file: &Path,
+    emit_debug_info: bool,
     preprocessed_host_path: &Path,
     wasm_dev_stack_bytes: Option<u32>,
 ) -> CodeGenTiming {
@@ -431,7 +433,9 @@ pub fn gen_from_mono_module_dev(
         Architecture::Wasm32 => gen_from_mono_module_dev_wasm32(
             arena,
             loaded,
+            target,
             app_o_file,
+            emit_debug_info,
             preprocessed_host_path,
             wasm_dev_stack_bytes,
         ),
@@ -448,6 +452,7 @@ pub fn gen_from_mono_module_dev(
     loaded: MonomorphizedModule,
     target: &target_lexicon::Triple,
     app_o_file: &Path,
+    _emit_debug_info: bool,
     _host_input_path: &Path,
     _wasm_dev_stack_bytes: Option<u32>,
 ) -> CodeGenTiming {
@@ -465,7 +470,9 @@ pub fn gen_from_mono_module_dev(
 fn gen_from_mono_module_dev_wasm32(
     arena: &bumpalo::Bump,
     loaded: MonomorphizedModule,
+    target: &target_lexicon::Triple,
     app_o_file: &Path,
+    emit_debug_info: bool,
     preprocessed_host_path: &Path,
     wasm_dev_stack_bytes: Option<u32>,
 ) -> CodeGenTiming {
@@ -475,9 +482,17 @@ fn gen_from_mono_module_dev_wasm32(
         procedures,
         mut interns,
         layout_interner,
+        sources,
+        symbol_regions,
         ..
     } = loaded;
 
+    let debug_sources = if emit_debug_info {
+        Some(roc_gen_wasm::DebugSources::new(&sources, &symbol_regions))
+    } else {
+        None
+    };
+
     let exposed_to_host = loaded
         .exposed_to_host
         .values
@@ -491,6 +506,7 @@ fn gen_from_mono_module_dev_wasm32(
         module_id,
         exposed_to_host,
         stack_bytes: wasm_dev_stack_bytes.unwrap_or(roc_gen_wasm::Env::DEFAULT_STACK_BYTES),
+        debug_sources: debug_sources.as_ref(),
     };
 
     let host_bytes = std::fs::read(preprocessed_host_path).unwrap_or_else(|_| {
@@ -509,8 +525,20 @@ fn gen_from_mono_module_dev_wasm32(
         )
     });
 
-    let final_binary_bytes =
-        roc_gen_wasm::build_app_binary(&env, &mut interns, host_module, procedures);
+    let final_binary_bytes = match target.operating_system {
+        target_lexicon::OperatingSystem::Wasi => {
+            roc_gen_wasm::build_wasi_app_binary(&env, &mut interns, host_module, procedures)
+                .unwrap_or_else(|unresolved| {
+                    panic!(
+                        "The host {} can't run in a WASI runtime, because it doesn't define these functions: {}\nA WASI host must not import anything from the `{}` module.",
+                        preprocessed_host_path.display(),
+                        unresolved.join(", "),
+                        roc_gen_wasm::BUILTINS_IMPORT_MODULE_NAME
+                    )
+                })
+        }
+        _ => roc_gen_wasm::build_app_binary(&env, &mut interns, host_module, procedures),
+    };
 
     let code_gen = code_gen_start.elapsed();
     let emit_o_file_start = Instant::now();
@@ -1,4 +1,6 @@
 const std = @import("std");
+const builtin = @import("builtin");
+const RocStr = @import("str.zig").RocStr;
 
 const SIGUSR1: c_int = 10;
 
@@ -21,3 +23,18 @@ pub fn setSharedBuffer(ptr: [*]u8, length: usize) callconv(.C) usize {
 pub fn expectFailedStart() callconv(.C) [*]u8 {
     return SHARED_BUFFER.ptr;
 }
+
+extern fn roc_dbg(location: *const RocStr, message: *const RocStr) callconv(.C) void;
+
+// Reports the location and rendered value of a `dbg` through `roc_dbg`
+pub fn dbg(location: RocStr, message: RocStr) callconv(.C) void {
+    roc_dbg(&location, &message);
+}
+
+// The `roc_dbg` used when the host does not provide its own
+pub fn defaultDbg(location: *const RocStr, message: *const RocStr) callconv(.C) void {
+    // there is no stderr to print to in wasm
+    if (builtin.target.cpu.arch != .wasm32) {
+        std.debug.print("[{s}] {s}\n", .{ location.asSlice(), message.asSlice() });
+    }
+}
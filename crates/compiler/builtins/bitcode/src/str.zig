@@ -476,6 +476,18 @@ pub fn init(bytes_ptr: [*]const u8, length: usize) callconv(.C) RocStr {
     return @call(.{ .modifier = always_inline }, RocStr.init, .{ bytes_ptr, length });
 }
 
+// crash
+pub fn crash(message: RocStr, tag_id: u32) callconv(.C) void {
+    // roc_panic expects a null-terminated string
+    const length = message.len();
+    const buffer = utils.alloc(length + 1, @alignOf(u8)) orelse unreachable;
+
+    @memcpy(buffer, message.asU8ptr(), length);
+    buffer[length] = 0;
+
+    utils.panic(@ptrCast(*const anyopaque, buffer), tag_id);
+}
+
 // Str.equal
 pub fn strEqual(self: RocStr, other: RocStr) callconv(.C) bool {
     return self.eq(other);
@@ -21,6 +21,7 @@ interface Encode
         string,
         list,
         record,
+        tuple,
         tag,
         custom,
         appendWith,
@@ -52,6 +53,7 @@ EncoderFormatting has
     string : Str -> Encoder fmt | fmt has EncoderFormatting
     list : List elem, (elem -> Encoder fmt) -> Encoder fmt | fmt has EncoderFormatting
     record : List { key : Str, value : Encoder fmt } -> Encoder fmt | fmt has EncoderFormatting
+    tuple : List (Encoder fmt) -> Encoder fmt | fmt has EncoderFormatting
     tag : Str, List (Encoder fmt) -> Encoder fmt | fmt has EncoderFormatting
 
 custom : (List U8, fmt -> List U8) -> Encoder fmt | fmt has EncoderFormatting
@@ -106,13 +106,70 @@ static_assertions::assert_eq_size!(SpecializationId, Option<SpecializationId>);
 
 pub enum SpecializationLambdaSetError {}
 
-/// A key into a particular implementation of an ability member for an opaque type.
+/// A key into a particular implementation of an ability member for an opaque type, or for a
+/// builtin type or type alias implementing an ability in the module defining the ability.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct ImplKey {
     pub opaque: Symbol,
     pub ability_member: Symbol,
 }
 
+impl ImplKey {
+    /// The module declaring this implementation. Opaque types declare their implementations
+    /// alongside their definition, while implementations for builtin types are declared in the
+    /// module defining the ability. (So are those for type aliases, which must be defined in
+    /// that module as well.)
+    pub fn home(&self) -> ModuleId {
+        let type_home = self.opaque.module_id();
+        let ability_home = self.ability_member.module_id();
+
+        if type_home.is_builtin() && !ability_home.is_builtin() {
+            ability_home
+        } else {
+            type_home
+        }
+    }
+}
+
+/// Whether `symbol` is a builtin type that abilities defined outside of the builtins may be
+/// implemented for, as in `Str has [Pretty { pretty: prettyStr }]` or
+/// `U64 has [Pretty { pretty: prettyU64 }]`.
+pub fn is_implementable_builtin(symbol: Symbol) -> bool {
+    matches!(
+        symbol,
+        Symbol::STR_STR
+            | Symbol::LIST_LIST
+            | Symbol::BOX_BOX_TYPE
+            | Symbol::DICT_DICT
+            | Symbol::SET_SET
+            | Symbol::RESULT_RESULT
+    ) || builtin_number_type(symbol) == Some(symbol)
+}
+
+/// The number type (e.g. `U64`) that `symbol` stands for, if it's one of the builtin number types
+/// or the type marking its range (e.g. `Unsigned64` in `U64 : Num (Integer Unsigned64)`).
+pub fn builtin_number_type(symbol: Symbol) -> Option<Symbol> {
+    let number = match symbol {
+        Symbol::NUM_U8 | Symbol::NUM_UNSIGNED8 => Symbol::NUM_U8,
+        Symbol::NUM_U16 | Symbol::NUM_UNSIGNED16 => Symbol::NUM_U16,
+        Symbol::NUM_U32 | Symbol::NUM_UNSIGNED32 => Symbol::NUM_U32,
+        Symbol::NUM_U64 | Symbol::NUM_UNSIGNED64 => Symbol::NUM_U64,
+        Symbol::NUM_U128 | Symbol::NUM_UNSIGNED128 => Symbol::NUM_U128,
+        Symbol::NUM_I8 | Symbol::NUM_SIGNED8 => Symbol::NUM_I8,
+        Symbol::NUM_I16 | Symbol::NUM_SIGNED16 => Symbol::NUM_I16,
+        Symbol::NUM_I32 | Symbol::NUM_SIGNED32 => Symbol::NUM_I32,
+        Symbol::NUM_I64 | Symbol::NUM_SIGNED64 => Symbol::NUM_I64,
+        Symbol::NUM_I128 | Symbol::NUM_SIGNED128 => Symbol::NUM_I128,
+        Symbol::NUM_NAT | Symbol::NUM_NATURAL => Symbol::NUM_NAT,
+        Symbol::NUM_F32 | Symbol::NUM_BINARY32 => Symbol::NUM_F32,
+        Symbol::NUM_F64 | Symbol::NUM_BINARY64 => Symbol::NUM_F64,
+        Symbol::NUM_DEC | Symbol::NUM_DECIMAL => Symbol::NUM_DEC,
+        _ => return None,
+    };
+
+    Some(number)
+}
+
 /// Fully-resolved implementation of an ability member for an opaque type.
 /// This is only fully known after type solving of the owning module.
 #[derive(Clone, Debug)]
@@ -159,6 +216,14 @@ pub struct IAbilitiesStore<Phase: ResolvePhase> {
     /// Resolved specializations for a symbol. These might be ephemeral (known due to type solving),
     /// or resolved on-the-fly during mono.
     resolved_specializations: MutMap<SpecializationId, Symbol>,
+
+    /// Maps an ability to the abilities it requires of the types implementing it. For example,
+    /// for `Ord has Eq`, we keep Ord->[Eq].
+    required_abilities: MutMap<Symbol, Vec<Symbol>>,
+
+    /// Maps an ability member to the symbol of its default implementation, if the ability gives
+    /// one. Opaque types that do not implement the member themselves use the default instead.
+    default_implementations: MutMap<Symbol, Symbol>,
 }
 
 impl<Phase: ResolvePhase> Default for IAbilitiesStore<Phase> {
@@ -173,6 +238,8 @@ impl<Phase: ResolvePhase> Default for IAbilitiesStore<Phase> {
                 // Safety: 1 != 0
                 unsafe { NonZeroU32::new_unchecked(1) },
             resolved_specializations: Default::default(),
+            required_abilities: Default::default(),
+            default_implementations: Default::default(),
          }
     }
 }
@@ -201,6 +268,59 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
         );
     }
 
+    /// Records the abilities an ability requires of the types implementing it.
+    pub fn register_required_abilities(&mut self, ability: Symbol, required: Vec<Symbol>) {
+        let old_required = self.required_abilities.insert(ability, required);
+        debug_assert!(
+            old_required.is_none(),
+            "Replacing existing required abilities"
+        );
+    }
+
+    /// The abilities an ability directly requires of the types implementing it.
+    pub fn required_abilities(&self, ability: Symbol) -> &[Symbol] {
+        self.required_abilities
+            .get(&ability)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Iterator over all abilities that require other abilities, and the abilities they require.
+    pub fn iter_required_abilities(&self) -> impl Iterator<Item = (Symbol, &[Symbol])> {
+        self.required_abilities
+            .iter()
+            .map(|(k, v)| (*k, v.as_slice()))
+    }
+
+    /// Records that `default` implements the ability member `member` for types that do not
+    /// implement it themselves.
+    pub fn register_default_implementation(&mut self, member: Symbol, default: Symbol) {
+        let old_default = self.default_implementations.insert(member, default);
+        debug_assert!(old_default.is_none(), "Replacing existing default");
+    }
+
+    /// The default implementation of an ability member, if it has one.
+    pub fn default_implementation(&self, member: Symbol) -> Option<Symbol> {
+        self.default_implementations.get(&member).copied()
+    }
+
+    /// If `symbol` is the default implementation of an ability member, returns that member.
+    pub fn member_of_default_implementation(&self, symbol: Symbol) -> Option<Symbol> {
+        self.default_implementations
+            .iter()
+            .find(|(_, default)| **default == symbol)
+            .map(|(member, _)| *member)
+    }
+
+    /// Marks all types using the default implementation `default` as erroring for that member.
+    fn mark_default_implementation_errored(&mut self, default: Symbol) {
+        for member_impl in self.declared_implementations.values_mut() {
+            if *member_impl == MemberImpl::Impl(default) {
+                *member_impl = MemberImpl::Error;
+            }
+        }
+    }
+
     /// Checks if `name` is a root ability member symbol name.
     /// Note that this will return `false` for specializations of an ability member, which have
     /// different symbols from the root.
@@ -227,8 +347,12 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
     #[inline(always)]
     fn register_one_declared_impl(&mut self, impl_key: ImplKey, member_impl: MemberImpl) {
         if let MemberImpl::Impl(specialization_symbol) = member_impl {
-            self.specialization_to_root
-                .insert(specialization_symbol, impl_key);
+            // A default implementation is shared by all types using it, so it does not specialize
+            // the member for any one of them.
+            if self.default_implementation(impl_key.ability_member) != Some(specialization_symbol) {
+                self.specialization_to_root
+                    .insert(specialization_symbol, impl_key);
+            }
         }
         self.declared_implementations.insert(impl_key, member_impl);
     }
@@ -316,6 +440,9 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
             // Taking closure for a new module, so specialization IDs can be fresh
             next_specialization_id: _,
             resolved_specializations: _,
+
+            required_abilities,
+            default_implementations,
         } = self;
 
         let mut new = PendingAbilitiesStore::default();
@@ -332,6 +459,20 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
             }
         });
 
+        // Types implementing an ability must also implement the abilities it requires, so we
+        // need to capture those too.
+        let mut required_stack: Vec<Symbol> = abilities_to_introduce.iter().copied().collect();
+        while let Some(ability) = required_stack.pop() {
+            for required in required_abilities.get(&ability).into_iter().flatten() {
+                if members_of_ability.contains_key(required)
+                    && !abilities_to_introduce.contains(required)
+                {
+                    abilities_to_introduce.insert(*required);
+                    required_stack.push(*required);
+                }
+            }
+        }
+
         // 2. Add each ability, and any specializations of its members we know about.
         for ability in abilities_to_introduce.into_iter() {
             let members = members_of_ability.get(&ability).unwrap();
@@ -352,10 +493,18 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
                 };
 
                 imported_member_data.push((*member, imported_data));
+
+                if let Some(default) = default_implementations.get(member) {
+                    new.register_default_implementation(*member, *default);
+                }
             }
 
             new.register_ability(ability, imported_member_data);
 
+            if let Some(required) = required_abilities.get(&ability) {
+                new.register_required_abilities(ability, required.clone());
+            }
+
             // Add any specializations of the ability's members we know about.
             declared_implementations
                 .iter()
@@ -449,6 +598,24 @@ impl IAbilitiesStore<Resolved> {
         }
     }
 
+    /// Marks the default implementation of an ability member as either properly implementing the
+    /// member, or as erroring. The types using an erroring default are marked as erroring too.
+    pub fn mark_default_implementation(
+        &mut self,
+        default: Symbol,
+        mark: Result<MemberSpecializationInfo<Resolved>, ()>,
+    ) {
+        debug_assert!(self.member_of_default_implementation(default).is_some());
+        debug_assert!(!self.specializations.contains_key(&default));
+
+        match mark {
+            Ok(specialization_info) => {
+                self.specializations.insert(default, specialization_info);
+            }
+            Err(()) => self.mark_default_implementation_errored(default),
+        }
+    }
+
     pub fn specialization_info(
         &self,
         specialization_symbol: Symbol,
@@ -475,7 +642,11 @@ impl IAbilitiesStore<Pending> {
     pub fn import_implementation(&mut self, impl_key: ImplKey, resolved_impl: &ResolvedImpl) {
         let member_impl = match resolved_impl {
             ResolvedImpl::Impl(specialization) => {
-                self.import_specialization(specialization);
+                // A default implementation from another module is imported with the rest of that
+                // module's default implementations; see [`Self::import_default_implementation`].
+                if specialization.symbol.module_id() == impl_key.home() {
+                    self.import_specialization(specialization);
+                }
                 MemberImpl::Impl(specialization.symbol)
             }
             ResolvedImpl::Derived => MemberImpl::Derived,
@@ -489,6 +660,16 @@ impl IAbilitiesStore<Pending> {
         );
     }
 
+    /// Imports the default implementation `default` of an ability member, as solved by the module
+    /// defining the ability.
+    pub fn import_default_implementation(&mut self, default: Symbol, resolved: &ResolvedImpl) {
+        match resolved {
+            ResolvedImpl::Impl(specialization) => self.import_specialization(specialization),
+            ResolvedImpl::Error => self.mark_default_implementation_errored(default),
+            ResolvedImpl::Derived => internal_error!("default implementations are never derived"),
+        }
+    }
+
     fn import_specialization(
         &mut self,
         specialization: &MemberSpecializationInfo<impl ResolvePhase>,
@@ -499,7 +680,13 @@ impl IAbilitiesStore<Pending> {
             specialization_lambda_sets,
         } = specialization;
 
-        let old_spec = self.specializations.insert(
+        if self.specializations.contains_key(symbol) {
+            // A default implementation, already imported for another type using it.
+            debug_assert!(self.member_of_default_implementation(*symbol).is_some());
+            return;
+        }
+
+        self.specializations.insert(
             *symbol,
             MemberSpecializationInfo {
                 _phase: Default::default(),
@@ -507,7 +694,6 @@ impl IAbilitiesStore<Pending> {
                 specialization_lambda_sets: specialization_lambda_sets.clone(),
             },
         );
-        debug_assert!(old_spec.is_none(), "Replacing existing specialization");
     }
 
     pub fn union(&mut self, other: Self) {
@@ -519,6 +705,8 @@ impl IAbilitiesStore<Pending> {
             next_specialization_id,
             resolved_specializations,
             specializations,
+            required_abilities,
+            default_implementations,
         } = other;
 
         for (ability, members) in other_members_of_ability.into_iter() {
@@ -554,6 +742,16 @@ impl IAbilitiesStore<Pending> {
             );
         }
 
+        for (ability, required) in required_abilities.into_iter() {
+            let old_required = self.required_abilities.insert(ability, required.clone());
+            debug_assert!(old_required.is_none() || old_required.unwrap() == required);
+        }
+
+        for (member, default) in default_implementations.into_iter() {
+            let old_default = self.default_implementations.insert(member, default);
+            debug_assert!(old_default.is_none() || old_default.unwrap() == default);
+        }
+
         debug_assert_eq!(next_specialization_id.get(), 1);
         debug_assert_eq!(self.next_specialization_id.get(), 1);
         debug_assert!(resolved_specializations.is_empty());
@@ -579,6 +777,8 @@ impl IAbilitiesStore<Pending> {
             next_specialization_id,
             resolved_specializations,
             specializations,
+            required_abilities,
+            default_implementations,
         } = self;
 
         let ability_members = ability_members
@@ -657,6 +857,8 @@ impl IAbilitiesStore<Pending> {
             next_specialization_id,
             resolved_specializations,
             specializations,
+            required_abilities,
+            default_implementations,
         }
     }
 }
@@ -418,6 +418,11 @@ pub fn find_type_def_symbols(
                     stack.push(&t.value);
                 }
             }
+            Tuple(elems) => {
+                for t in elems.iter() {
+                    stack.push(&t.value);
+                }
+            }
             TagUnion { ext, tags } => {
                 let mut inner_stack = Vec::with_capacity(tags.items.len());
 
@@ -812,6 +817,31 @@ fn can_annotation_help(
                 Type::Record(field_types, TypeExtension::from_type(ext_type))
             }
         }
+        Tuple(elems) => {
+            // A tuple type is the closed record type whose fields are its elements,
+            // e.g. `(Str, U8)` is `{ 0 : Str, 1 : U8 }`.
+            let mut field_types = SendMap::default();
+
+            for (index, elem) in elems.iter().enumerate() {
+                let elem_type = can_annotation_help(
+                    env,
+                    &elem.value,
+                    elem.region,
+                    scope,
+                    var_store,
+                    introduced_variables,
+                    local_aliases,
+                    references,
+                );
+
+                field_types.insert(
+                    Lowercase::tuple_elem(index),
+                    RecordField::Required(elem_type),
+                );
+            }
+
+            Type::Record(field_types, TypeExtension::Closed)
+        }
         TagUnion { tags, ext, .. } => {
             let ext_type = can_extension_type(
                 env,
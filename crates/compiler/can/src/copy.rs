@@ -1,7 +1,7 @@
 use crate::{
     def::Def,
     expr::{AccessorData, ClosureData, Expr, Field, OpaqueWrapFunctionData, WhenBranchPattern},
-    pattern::{DestructType, Pattern, RecordDestruct},
+    pattern::{DestructType, ListPatterns, Pattern, RecordDestruct},
 };
 use roc_module::{
     ident::{Lowercase, TagName},
@@ -623,6 +623,23 @@ fn deep_copy_expr_help<C: CopyEnv>(env: &mut C, copied: &mut Vec<Variable>, expr
             lookups_in_cond: lookups_in_cond.to_vec(),
         },
 
+        Dbg {
+            loc_message,
+            loc_continuation,
+            variable,
+            location,
+        } => Dbg {
+            loc_message: Box::new(loc_message.map(|e| go_help!(e))),
+            loc_continuation: Box::new(loc_continuation.map(|e| go_help!(e))),
+            variable: sub!(*variable),
+            location: location.clone(),
+        },
+
+        Crash { msg, ret_var } => Crash {
+            msg: Box::new(msg.map(|m| go_help!(m))),
+            ret_var: sub!(*ret_var),
+        },
+
         TypedHole(v) => TypedHole(sub!(*v)),
 
         RuntimeError(err) => RuntimeError(err.clone()),
@@ -650,6 +667,7 @@ fn deep_copy_pattern_help<C: CopyEnv>(
 
     match pat {
         Identifier(s) => Identifier(*s),
+        As(subpattern, s) => As(Box::new(subpattern.map(|p| go_help!(p))), *s),
         AppliedTag {
             whole_var,
             ext_var,
@@ -719,6 +737,18 @@ fn deep_copy_pattern_help<C: CopyEnv>(
                 })
                 .collect(),
         },
+        List {
+            list_var,
+            elem_var,
+            patterns: ListPatterns { patterns, opt_rest },
+        } => List {
+            list_var: sub!(*list_var),
+            elem_var: sub!(*elem_var),
+            patterns: ListPatterns {
+                patterns: patterns.iter().map(|lp| lp.map(|p| go_help!(p))).collect(),
+                opt_rest: *opt_rest,
+            },
+        },
         NumLiteral(var, s, n, bound) => NumLiteral(sub!(*var), s.clone(), *n, *bound),
         IntLiteral(v1, v2, s, n, bound) => IntLiteral(sub!(*v1), sub!(*v2), s.clone(), *n, *bound),
         FloatLiteral(v1, v2, s, n, bound) => {
@@ -1,3 +1,4 @@
+use crate::abilities::is_implementable_builtin;
 use crate::abilities::AbilityMemberData;
 use crate::abilities::ImplKey;
 use crate::abilities::MemberVariables;
@@ -155,6 +156,17 @@ impl PendingValueDef<'_> {
 struct PendingAbilityMember<'a> {
     name: Loc<Symbol>,
     typ: Loc<ast::TypeAnnotation<'a>>,
+    default: Option<PendingAbilityMemberDefault<'a>>,
+}
+
+/// The default implementation of an ability member, e.g. `neq = \a, b -> !(eq a b)`.
+/// It is canonicalized as a top-level def annotated with the member's signature.
+#[derive(Debug, Clone)]
+struct PendingAbilityMemberDefault<'a> {
+    ast_pattern: &'a Loc<ast::Pattern<'a>>,
+    symbol: Loc<Symbol>,
+    typ: &'a Loc<ast::TypeAnnotation<'a>>,
+    body: &'a Loc<ast::Expr<'a>>,
 }
 
 #[derive(Debug, Clone)]
@@ -176,6 +188,9 @@ enum PendingTypeDef<'a> {
 
     Ability {
         name: Loc<Symbol>,
+        /// Abilities the types implementing this ability must also implement, e.g. `Eq` in
+        /// `Ord has Eq`
+        required: &'a [Loc<ast::TypeAnnotation<'a>>],
         members: Vec<PendingAbilityMember<'a>>,
     },
 
@@ -199,6 +214,13 @@ enum PendingTypeDef<'a> {
         region: Region,
     },
 
+    /// Implementations of abilities for a builtin type, e.g.
+    /// `Str has [Pretty { pretty: prettyStr }]`
+    BuiltinImpls {
+        name: &'a Loc<&'a str>,
+        has_abilities: &'a Loc<ast::HasAbilities<'a>>,
+    },
+
     AbilityNotOnToplevel,
     AbilityShadows,
 }
@@ -226,6 +248,7 @@ impl PendingTypeDef<'_> {
             PendingTypeDef::InvalidAlias { symbol, region, .. } => Some((*symbol, *region)),
             PendingTypeDef::ShadowedAlias { .. } => None,
             PendingTypeDef::InvalidAbility { symbol, region } => Some((*symbol, *region)),
+            PendingTypeDef::BuiltinImpls { .. } => None,
             PendingTypeDef::AbilityNotOnToplevel => None,
             PendingTypeDef::AbilityShadows => None,
         }
@@ -597,6 +620,162 @@ fn separate_implemented_and_required_members(
     }
 }
 
+/// Resolves an ability a type claims to implement in a `has` clause, along with the ability's
+/// members.
+fn resolve_claimed_ability<'a>(
+    env: &mut Env<'a>,
+    scope: &mut Scope,
+    pending_abilities_in_scope: &PendingAbilitiesInScope,
+    region: Region,
+    ability: &Loc<ast::TypeAnnotation<'a>>,
+) -> Option<(Symbol, VecSet<Symbol>)> {
+    let ability_region = ability.region;
+
+    match ability.value {
+        ast::TypeAnnotation::Apply(module_name, ident, []) => {
+            match make_apply_symbol(env, region, scope, module_name, ident) {
+                Ok(ability) => {
+                    let opt_members = scope
+                        .abilities_store
+                        .members_of_ability(ability)
+                        .map(|members| members.iter().copied().collect())
+                        .or_else(|| pending_abilities_in_scope.get(&ability).cloned());
+
+                    if let Some(members) = opt_members {
+                        // This is an ability we already imported into the scope,
+                        // or which is also undergoing canonicalization at the moment.
+                        Some((ability, members))
+                    } else {
+                        env.problem(Problem::NotAnAbility(ability_region));
+                        None
+                    }
+                }
+                Err(_) => {
+                    // This is bad apply; an error will have been reported for it
+                    // already.
+                    None
+                }
+            }
+        }
+        _ => {
+            // Register the problem but keep going.
+            env.problem(Problem::NotAnAbility(ability_region));
+            None
+        }
+    }
+}
+
+/// Canonicalizes the implementations claimed for the members of `ability`, as in
+/// `Hash { hash: myHash }`, reporting members that are missing or not part of the ability.
+fn canonicalize_claimed_ability_impls<'a>(
+    env: &mut Env<'a>,
+    scope: &mut Scope,
+    region: Region,
+    ability: Symbol,
+    members: VecSet<Symbol>,
+    impls: Loc<ast::HasImpls<'a>>,
+) -> impl Iterator<Item = (Symbol, MemberImpl)> {
+    let mut impl_map: VecMap<Symbol, Loc<MemberImpl>> = VecMap::default();
+
+    // First up canonicalize all the claimed implementations, building a map of ability
+    // member -> implementation.
+    for loc_impl in impls.extract_spaces().item.items {
+        let (member, impl_symbol) =
+            match canonicalize_claimed_ability_impl(env, scope, ability, loc_impl) {
+                Ok((member, impl_symbol)) => (member, impl_symbol),
+                Err(()) => continue,
+            };
+
+        // Did the user claim this implementation for a specialization of a different
+        // type? e.g.
+        //
+        //   A has [Hash {hash: myHash}]
+        //   B has [Hash {hash: myHash}]
+        //
+        // If so, that's an error and we drop the impl for this type.
+        let member_impl = match scope.abilities_store.impl_key(impl_symbol) {
+            Some(ImplKey {
+                opaque,
+                ability_member,
+            }) => {
+                env.problem(Problem::OverloadedSpecialization {
+                    overload: loc_impl.region,
+                    original_opaque: *opaque,
+                    ability_member: *ability_member,
+                });
+                MemberImpl::Error
+            }
+            None => MemberImpl::Impl(impl_symbol),
+        };
+
+        // Did the user already claim an implementation for the ability member for this
+        // type previously? (e.g. Hash {hash: hash1, hash: hash2})
+        let opt_old_impl_symbol = impl_map.insert(member, Loc::at(loc_impl.region, member_impl));
+
+        if let Some(old_impl_symbol) = opt_old_impl_symbol {
+            env.problem(Problem::DuplicateImpl {
+                original: old_impl_symbol.region,
+                duplicate: loc_impl.region,
+            });
+        }
+    }
+
+    // Check that the members this type claims to implement corresponds 1-to-1 with
+    // the members the ability offers.
+    let SeparatedMembers {
+        not_required,
+        mut not_implemented,
+    } = separate_implemented_and_required_members(
+        impl_map.iter().map(|(member, _)| *member).collect(),
+        members,
+    );
+
+    if !not_required.is_empty() {
+        // Implementing something that's not required is a recoverable error, we don't
+        // need to skip association of the implemented abilities. Just remove the
+        // unneeded members.
+        for sym in not_required.iter() {
+            impl_map.remove(sym);
+        }
+
+        env.problem(Problem::ImplementsNonRequired {
+            region,
+            ability,
+            not_required,
+        });
+    }
+
+    // Members the type does not implement itself use the ability's default
+    // implementation, if there is one.
+    not_implemented.retain(
+        |member| match scope.abilities_store.default_implementation(*member) {
+            Some(default) => {
+                impl_map.insert(*member, Loc::at_zero(MemberImpl::Impl(default)));
+                false
+            }
+            None => true,
+        },
+    );
+
+    if !not_implemented.is_empty() {
+        // We'll generate runtime errors for the members that are needed but
+        // unspecified.
+        for sym in not_implemented.iter() {
+            impl_map.insert(*sym, Loc::at_zero(MemberImpl::Error));
+        }
+
+        env.problem(Problem::DoesNotImplementAbility {
+            region,
+            ability,
+            not_implemented,
+        });
+    }
+
+    impl_map
+        .into_iter()
+        .map(|(member, def)| (member, def.value))
+}
+
 #[inline(always)]
 #[allow(clippy::too_many_arguments)]
 fn canonicalize_opaque<'a>(
@@ -627,6 +806,7 @@ fn canonicalize_opaque<'a>(
         let has_abilities = has_abilities.value.collection();
 
         let mut derived_abilities = vec![];
+        let mut implemented_abilities: Vec<Loc<Symbol>> = vec![];
 
         for has_ability in has_abilities.items {
             let region = has_ability.region;
@@ -637,132 +817,28 @@ fn canonicalize_opaque<'a>(
 
             let ability_region = ability.region;
 
-            let (ability, members) = match ability.value {
-                ast::TypeAnnotation::Apply(module_name, ident, []) => {
-                    match make_apply_symbol(env, region, scope, module_name, ident) {
-                        Ok(ability) => {
-                            let opt_members = scope
-                                .abilities_store
-                                .members_of_ability(ability)
-                                .map(|members| members.iter().copied().collect())
-                                .or_else(|| pending_abilities_in_scope.get(&ability).cloned());
-
-                            if let Some(members) = opt_members {
-                                // This is an ability we already imported into the scope,
-                                // or which is also undergoing canonicalization at the moment.
-                                (ability, members)
-                            } else {
-                                env.problem(Problem::NotAnAbility(ability_region));
-                                continue;
-                            }
-                        }
-                        Err(_) => {
-                            // This is bad apply; an error will have been reported for it
-                            // already.
-                            continue;
-                        }
-                    }
-                }
-                _ => {
-                    // Register the problem but keep going.
-                    env.problem(Problem::NotAnAbility(ability_region));
-                    continue;
-                }
+            let (ability, members) = match resolve_claimed_ability(
+                env,
+                scope,
+                pending_abilities_in_scope,
+                region,
+                &ability,
+            ) {
+                Some(ability_and_members) => ability_and_members,
+                None => continue,
             };
 
-            if let Some(impls) = opt_impls {
-                let mut impl_map: VecMap<Symbol, Loc<MemberImpl>> = VecMap::default();
-
-                // First up canonicalize all the claimed implementations, building a map of ability
-                // member -> implementation.
-                for loc_impl in impls.extract_spaces().item.items {
-                    let (member, impl_symbol) =
-                        match canonicalize_claimed_ability_impl(env, scope, ability, loc_impl) {
-                            Ok((member, impl_symbol)) => (member, impl_symbol),
-                            Err(()) => continue,
-                        };
-
-                    // Did the user claim this implementation for a specialization of a different
-                    // type? e.g.
-                    //
-                    //   A has [Hash {hash: myHash}]
-                    //   B has [Hash {hash: myHash}]
-                    //
-                    // If so, that's an error and we drop the impl for this opaque type.
-                    let member_impl = match scope.abilities_store.impl_key(impl_symbol) {
-                        Some(ImplKey {
-                            opaque,
-                            ability_member,
-                        }) => {
-                            env.problem(Problem::OverloadedSpecialization {
-                                overload: loc_impl.region,
-                                original_opaque: *opaque,
-                                ability_member: *ability_member,
-                            });
-                            MemberImpl::Error
-                        }
-                        None => MemberImpl::Impl(impl_symbol),
-                    };
-
-                    // Did the user already claim an implementation for the ability member for this
-                    // type previously? (e.g. Hash {hash: hash1, hash: hash2})
-                    let opt_old_impl_symbol =
-                        impl_map.insert(member, Loc::at(loc_impl.region, member_impl));
-
-                    if let Some(old_impl_symbol) = opt_old_impl_symbol {
-                        env.problem(Problem::DuplicateImpl {
-                            original: old_impl_symbol.region,
-                            duplicate: loc_impl.region,
-                        });
-                    }
-                }
+            output.references.insert_type_lookup(ability);
 
-                // Check that the members this opaque claims to implement corresponds 1-to-1 with
-                // the members the ability offers.
-                let SeparatedMembers {
-                    not_required,
-                    not_implemented,
-                } = separate_implemented_and_required_members(
-                    impl_map.iter().map(|(member, _)| *member).collect(),
-                    members,
-                );
-
-                if !not_required.is_empty() {
-                    // Implementing something that's not required is a recoverable error, we don't
-                    // need to skip association of the implemented abilities. Just remove the
-                    // unneeded members.
-                    for sym in not_required.iter() {
-                        impl_map.remove(sym);
-                    }
-
-                    env.problem(Problem::ImplementsNonRequired {
-                        region,
-                        ability,
-                        not_required,
-                    });
-                }
-
-                if !not_implemented.is_empty() {
-                    // We'll generate runtime errors for the members that are needed but
-                    // unspecified.
-                    for sym in not_implemented.iter() {
-                        impl_map.insert(*sym, Loc::at_zero(MemberImpl::Error));
-                    }
-
-                    env.problem(Problem::DoesNotImplementAbility {
-                        region,
-                        ability,
-                        not_implemented,
-                    });
-                }
-
-                let impls = impl_map
-                    .into_iter()
-                    .map(|(member, def)| (member, def.value));
+            if let Some(impls) = opt_impls {
+                let impls =
+                    canonicalize_claimed_ability_impls(env, scope, region, ability, members, impls);
 
                 scope
                     .abilities_store
                     .register_declared_implementations(name.value, impls);
+
+                implemented_abilities.push(Loc::at(region, ability));
             } else if let Some((_, members)) = ability.derivable_ability() {
                 let impls = members.iter().map(|member| (*member, MemberImpl::Derived));
                 scope
@@ -770,6 +846,7 @@ fn canonicalize_opaque<'a>(
                     .register_declared_implementations(name.value, impls);
 
                 derived_abilities.push(Loc::at(ability_region, ability));
+                implemented_abilities.push(Loc::at(region, ability));
             } else {
                 // There was no record specified of functions to use for
                 // members, but also this isn't a builtin ability, so we don't
@@ -778,6 +855,29 @@ fn canonicalize_opaque<'a>(
             }
         }
 
+        // Check that the opaque also implements the abilities required by the ones it implements.
+        for Loc {
+            region,
+            value: ability,
+        } in implemented_abilities.iter()
+        {
+            let missing: Vec<_> = scope
+                .abilities_store
+                .required_abilities(*ability)
+                .iter()
+                .filter(|required| !implemented_abilities.iter().any(|a| a.value == **required))
+                .copied()
+                .collect();
+
+            if !missing.is_empty() {
+                env.problem(Problem::MissingRequiredAbilities {
+                    region: *region,
+                    ability: *ability,
+                    missing,
+                });
+            }
+        }
+
         if !derived_abilities.is_empty() {
             // Fresh instance of this opaque to be checked for derivability during solving.
             let fresh_inst = Type::DelayedAlias(AliasCommon {
@@ -844,7 +944,7 @@ pub(crate) fn canonicalize_defs<'a>(
         if let Ok(type_index) = either_index.split() {
             let type_def = &loc_defs.type_defs[type_index.index()];
             let pending_type_def = to_pending_type_def(env, type_def, scope, pattern_type);
-            if let PendingTypeDef::Ability { name, members } = &pending_type_def {
+            if let PendingTypeDef::Ability { name, members, .. } = &pending_type_def {
                 pending_abilities_in_scope.insert(
                     name.value,
                     members.iter().map(|mem| mem.name.value).collect(),
@@ -873,6 +973,43 @@ pub(crate) fn canonicalize_defs<'a>(
         }
     }
 
+    for pending_type_def in pending_type_defs.iter() {
+        if let PendingTypeDef::Ability {
+            name,
+            required,
+            members,
+        } = pending_type_def
+        {
+            register_required_abilities(
+                env,
+                scope,
+                &pending_abilities_in_scope,
+                name.value,
+                required,
+            );
+
+            for member in members.iter() {
+                if let Some(default) = &member.default {
+                    scope
+                        .abilities_store
+                        .register_default_implementation(member.name.value, default.symbol.value);
+
+                    // The default implementation is a def of its own, annotated with the member's
+                    // signature.
+                    let pending_def = PendingValueDef::TypedBody(
+                        default.ast_pattern,
+                        default.symbol.map(|sym| Pattern::Identifier(*sym)),
+                        default.typ,
+                        default.body,
+                    );
+                    let region = Region::span_across(&default.symbol.region, &default.body.region);
+
+                    pending_value_defs.push(Loc::at(region, PendingValue::Def(pending_def)));
+                }
+            }
+        }
+    }
+
     if cfg!(debug_assertions) {
         scope.register_debug_idents();
     }
@@ -900,6 +1037,46 @@ pub(crate) fn canonicalize_defs<'a>(
     )
 }
 
+/// Resolves the abilities an ability requires, like `Eq` in `Ord has Eq`, and records them in the
+/// abilities store.
+fn register_required_abilities<'a>(
+    env: &mut Env<'a>,
+    scope: &mut Scope,
+    pending_abilities_in_scope: &PendingAbilitiesInScope,
+    ability: Symbol,
+    required: &'a [Loc<ast::TypeAnnotation<'a>>],
+) {
+    let mut required_abilities = Vec::with_capacity(required.len());
+
+    for loc_required in required.iter() {
+        let region = loc_required.region;
+
+        match loc_required.value.extract_spaces().item {
+            ast::TypeAnnotation::Apply(module_name, ident, []) => {
+                match make_apply_symbol(env, region, scope, module_name, ident) {
+                    Ok(symbol)
+                        if scope.abilities_store.is_ability(symbol)
+                            || pending_abilities_in_scope.contains_key(&symbol) =>
+                    {
+                        required_abilities.push(symbol);
+                    }
+                    Ok(_) => env.problem(Problem::NotAnAbility(region)),
+                    Err(_) => {
+                        // This is bad apply; an error will have been reported for it already.
+                    }
+                }
+            }
+            _ => env.problem(Problem::NotAnAbility(region)),
+        }
+    }
+
+    if !required_abilities.is_empty() {
+        scope
+            .abilities_store
+            .register_required_abilities(ability, required_abilities);
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn canonicalize_value_defs<'a>(
     env: &mut Env<'a>,
@@ -1059,6 +1236,7 @@ fn canonicalize_type_defs<'a>(
 
     let mut type_defs = MutMap::default();
     let mut referenced_type_symbols = VecMap::default();
+    let mut builtin_impls = Vec::new();
 
     // Determine which idents we introduced in the course of this process.
     let mut symbols_introduced = MutMap::default();
@@ -1091,7 +1269,7 @@ fn canonicalize_type_defs<'a>(
 
                 type_defs.insert(name.value, TypeDef::Opaque(name, vars, ann, derived));
             }
-            PendingTypeDef::Ability { name, members } => {
+            PendingTypeDef::Ability { name, members, .. } => {
                 let mut referenced_symbols = Vec::with_capacity(2);
 
                 for member in members.iter() {
@@ -1104,6 +1282,12 @@ fn canonicalize_type_defs<'a>(
                 referenced_type_symbols.insert(name.value, referenced_symbols);
                 type_defs.insert(name.value, TypeDef::Ability(name, members));
             }
+            PendingTypeDef::BuiltinImpls {
+                name,
+                has_abilities,
+            } => {
+                builtin_impls.push((name, has_abilities));
+            }
             PendingTypeDef::InvalidAlias { .. }
             | PendingTypeDef::InvalidAbility { .. }
             | PendingTypeDef::AbilityShadows
@@ -1186,9 +1370,140 @@ fn canonicalize_type_defs<'a>(
         pending_abilities_in_scope,
     );
 
+    // With the abilities in scope, we can now resolve their implementations for builtin types.
+    canonicalize_builtin_impls(env, scope, pending_abilities_in_scope, builtin_impls);
+
     (aliases, symbols_introduced)
 }
 
+/// Canonicalizes implementations of abilities for builtin types, like
+/// `Str has [Pretty { pretty: prettyStr }]`, and for structural types through a type alias
+/// defined in the same module, like `Point has [Pretty { pretty: prettyPoint }]` for
+/// `Point : { x : I64, y : I64 }`. Since these types can't declare the abilities they implement
+/// themselves, we make sure there is only ever one implementation of an ability for such a type
+/// by only admitting implementations from the module defining the ability.
+fn canonicalize_builtin_impls<'a>(
+    env: &mut Env<'a>,
+    scope: &mut Scope,
+    pending_abilities_in_scope: &PendingAbilitiesInScope,
+    builtin_impls: Vec<(&'a Loc<&'a str>, &'a Loc<ast::HasAbilities<'a>>)>,
+) {
+    // (builtin type, ability) -> region of the implementation
+    let mut declared: VecMap<(Symbol, Symbol), Region> = VecMap::default();
+
+    for (name, has_abilities) in builtin_impls {
+        let typ = match make_apply_symbol(env, name.region, scope, "", name.value) {
+            Ok(typ)
+                if is_implementable_builtin(typ) || is_home_structural_alias(env, scope, typ) =>
+            {
+                typ
+            }
+            Ok(typ) => {
+                env.problem(Problem::IllegalBuiltinImplementation {
+                    typ,
+                    region: name.region,
+                });
+                continue;
+            }
+            Err(_) => {
+                // This is bad apply; an error will have been reported for it already.
+                continue;
+            }
+        };
+
+        for has_ability in has_abilities.value.collection().items {
+            let region = has_ability.region;
+            let (ability, opt_impls) = match has_ability.value.extract_spaces().item {
+                ast::HasAbility::HasAbility { ability, impls } => (ability, impls),
+                _ => internal_error!("spaces not extracted"),
+            };
+
+            let (ability, members) = match resolve_claimed_ability(
+                env,
+                scope,
+                pending_abilities_in_scope,
+                region,
+                &ability,
+            ) {
+                Some(ability_and_members) => ability_and_members,
+                None => continue,
+            };
+
+            if ability.module_id() != env.home {
+                env.problem(Problem::ForeignBuiltinImplementation {
+                    typ,
+                    ability,
+                    region,
+                });
+                continue;
+            }
+
+            let impls = match opt_impls {
+                Some(impls) => impls,
+                None => {
+                    // Builtin types derive builtin abilities on their own, and there is no way to
+                    // derive any other ability.
+                    env.problem(Problem::IllegalDerivedAbility(region));
+                    continue;
+                }
+            };
+
+            if let Some(original) = declared.get(&(typ, ability)) {
+                env.problem(Problem::DuplicateBuiltinImplementation {
+                    typ,
+                    ability,
+                    original: *original,
+                    duplicate: region,
+                });
+                continue;
+            }
+
+            let impls =
+                canonicalize_claimed_ability_impls(env, scope, region, ability, members, impls);
+
+            scope
+                .abilities_store
+                .register_declared_implementations(typ, impls);
+
+            declared.insert((typ, ability), region);
+        }
+    }
+
+    // Check that the builtin types also implement the abilities required by the ones they
+    // implement. Builtin abilities are derived for builtin types whenever possible.
+    for ((typ, ability), region) in declared.into_iter() {
+        let missing: Vec<_> = scope
+            .abilities_store
+            .required_abilities(ability)
+            .iter()
+            .filter(|required| {
+                required.derivable_ability().is_none()
+                    && !scope
+                        .abilities_store
+                        .has_declared_implementation(typ, **required)
+            })
+            .copied()
+            .collect();
+
+        if !missing.is_empty() {
+            env.problem(Problem::MissingRequiredAbilities {
+                region,
+                ability,
+                missing,
+            });
+        }
+    }
+}
+
+/// Whether `symbol` is a structural type alias defined in the module being canonicalized.
+fn is_home_structural_alias(env: &Env, scope: &Scope, symbol: Symbol) -> bool {
+    symbol.module_id() == env.home
+        && matches!(
+            scope.lookup_alias(symbol),
+            Some(alias) if alias.kind == AliasKind::Structural
+        )
+}
+
 /// Resolve all pending abilities, to add them to scope.
 #[allow(clippy::too_many_arguments)]
 fn resolve_abilities<'a>(
@@ -1209,6 +1524,7 @@ fn resolve_abilities<'a>(
                     region: member_name_region,
                 },
             typ,
+            default: _,
         } in members
         {
             let member_annot = canonicalize_annotation(
@@ -1784,6 +2100,11 @@ fn pattern_to_vars_by_symbol(
             vars_by_symbol.insert(*symbol, expr_var);
         }
 
+        As(subpattern, symbol) => {
+            vars_by_symbol.insert(symbol.value, expr_var);
+            pattern_to_vars_by_symbol(vars_by_symbol, &subpattern.value, expr_var);
+        }
+
         AbilityMemberSpecialization {
             ident,
             specializes: _,
@@ -1811,6 +2132,20 @@ fn pattern_to_vars_by_symbol(
             }
         }
 
+        List {
+            patterns,
+            list_var,
+            elem_var,
+        } => {
+            for pat in patterns.patterns.iter() {
+                pattern_to_vars_by_symbol(vars_by_symbol, &pat.value, *elem_var);
+            }
+
+            if let Some((_, Some(rest))) = &patterns.opt_rest {
+                vars_by_symbol.insert(rest.value, *list_var);
+            }
+        }
+
         NumLiteral(..)
         | IntLiteral(..)
         | FloatLiteral(..)
@@ -2356,6 +2691,23 @@ fn to_pending_type_def<'a>(
             AliasKind::Opaque,
         ),
 
+        BuiltinImpls {
+            header,
+            has_abilities,
+        } if pattern_type != PatternType::TopLevelDef => {
+            let region = Region::span_across(&header.region(), &has_abilities.region);
+            env.problem(Problem::BuiltinImplementationNotOnToplevel { region });
+
+            PendingTypeDef::AbilityNotOnToplevel
+        }
+        BuiltinImpls {
+            header: TypeHeader { name, vars: _ },
+            has_abilities,
+        } => PendingTypeDef::BuiltinImpls {
+            name,
+            has_abilities,
+        },
+
         Ability {
             header, members, ..
         } if pattern_type != PatternType::TopLevelDef => {
@@ -2371,8 +2723,9 @@ fn to_pending_type_def<'a>(
 
         Ability {
             header: TypeHeader { name, vars },
-            members,
             loc_has: _,
+            required_abilities,
+            members,
         } => {
             let name = match scope
                 .introduce_without_shadow_symbol(&Ident::from(name.value), name.region)
@@ -2421,9 +2774,32 @@ fn to_pending_type_def<'a>(
                     }
                 };
 
+                let default = member.default.as_ref().map(|default| {
+                    let region = default.name.region;
+                    let default_name = default.name.extract_spaces().item;
+
+                    if default_name != member_name {
+                        // The parser only accepts a default named after its member.
+                        internal_error!("default implementation does not match its member");
+                    }
+
+                    // Not introduced into scope; the default is only reachable through the member.
+                    let symbol = scope.scopeless_symbol(&member_name.into(), region);
+
+                    PendingAbilityMemberDefault {
+                        ast_pattern: env
+                            .arena
+                            .alloc(Loc::at(region, ast::Pattern::Identifier(member_name))),
+                        symbol: Loc::at(region, symbol),
+                        typ: &member.typ,
+                        body: default.body,
+                    }
+                });
+
                 named_members.push(PendingAbilityMember {
                     name: Loc::at(name_region, member_sym),
                     typ: member.typ,
+                    default,
                 });
 
                 if pattern_type == PatternType::TopLevelDef {
@@ -2433,6 +2809,7 @@ fn to_pending_type_def<'a>(
 
             PendingTypeDef::Ability {
                 name,
+                required: required_abilities,
                 members: named_members,
             }
         }
@@ -5,7 +5,8 @@ use roc_collections::{MutMap, VecSet};
 use roc_module::ident::{Ident, Lowercase, ModuleName};
 use roc_module::symbol::{IdentIdsByModule, ModuleId, ModuleIds, Symbol};
 use roc_problem::can::{Problem, RuntimeError};
-use roc_region::all::{Loc, Region};
+use roc_region::all::{LineInfo, Loc, Region};
+use std::path::Path;
 
 /// The canonicalization environment for a particular module.
 pub struct Env<'a> {
@@ -34,6 +35,10 @@ pub struct Env<'a> {
 
     pub top_level_symbols: VecSet<Symbol>,
 
+    /// The module's source file and where its lines start, so that a `dbg` can report where
+    /// it is at runtime. Not available when canonicalizing a lone expression.
+    pub src_location: Option<(&'a Path, &'a LineInfo)>,
+
     pub arena: &'a Bump,
 }
 
@@ -55,6 +60,19 @@ impl<'a> Env<'a> {
             qualified_type_lookups: VecSet::default(),
             tailcallable_symbol: None,
             top_level_symbols: VecSet::default(),
+            src_location: None,
+        }
+    }
+
+    /// Where `region` is in the source, like `Main.roc:12`
+    pub fn location(&self, region: Region) -> String {
+        match self.src_location {
+            Some((path, line_info)) => {
+                let line = line_info.convert_pos(region.start()).line + 1;
+
+                format!("{}:{}", path.display(), line)
+            }
+            None => format!("{:?}", region),
         }
     }
 
@@ -3,7 +3,7 @@ use crate::pattern::DestructType;
 use roc_collections::all::HumanIndex;
 use roc_error_macros::internal_error;
 use roc_exhaustive::{
-    is_useful, Ctor, CtorName, Error, Guard, Literal, Pattern, RenderAs, TagId, Union,
+    is_useful, Ctor, CtorName, Error, Guard, ListArity, Literal, Pattern, RenderAs, TagId, Union,
 };
 use roc_module::ident::{TagIdIntType, TagName};
 use roc_region::all::{Loc, Region};
@@ -57,6 +57,7 @@ enum SketchedPattern {
     Literal(Literal),
     Ctor(Variable, TagName, Vec<SketchedPattern>),
     KnownCtor(Union, TagId, Vec<SketchedPattern>),
+    List(ListArity, Vec<SketchedPattern>),
 }
 
 impl SketchedPattern {
@@ -69,6 +70,10 @@ impl SketchedPattern {
                 tag_id,
                 patterns.into_iter().map(|pat| pat.reify(subs)).collect(),
             ),
+            Self::List(arity, patterns) => Pattern::List(
+                arity,
+                patterns.into_iter().map(|pat| pat.reify(subs)).collect(),
+            ),
             Self::Ctor(var, tag_name, patterns) => {
                 let (union, tag_id) = convert_tag(subs, var, &tag_name);
                 Pattern::Ctor(
@@ -114,6 +119,7 @@ fn sketch_pattern(var: Variable, pattern: &crate::pattern::Pattern) -> SketchedP
         }
         &FloatLiteral(_, _, _, f, _) => SP::Literal(Literal::Float(f64::to_bits(f))),
         StrLiteral(v) => SP::Literal(Literal::Str(v.clone())),
+        As(subpattern, _) => sketch_pattern(var, &subpattern.value),
         &SingleQuote(c) => SP::Literal(Literal::Byte(c as u8)),
         RecordDestructure { destructs, .. } => {
             let tag_id = TagId(0);
@@ -185,6 +191,20 @@ fn sketch_pattern(var: Variable, pattern: &crate::pattern::Pattern) -> SketchedP
             )
         }
 
+        List {
+            patterns, elem_var, ..
+        } => {
+            let arity = patterns.arity();
+
+            let sketched_elem_patterns = patterns
+                .patterns
+                .iter()
+                .map(|p| sketch_pattern(*elem_var, &p.value))
+                .collect();
+
+            SP::List(arity, sketched_elem_patterns)
+        }
+
         // Treat this like a literal so we mark it as non-exhaustive
         MalformedPattern(..) => SP::Literal(Literal::Byte(1)),
 
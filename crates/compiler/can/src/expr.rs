@@ -7,7 +7,9 @@ use crate::num::{
     finish_parsing_base, finish_parsing_float, finish_parsing_num, float_expr_from_result,
     int_expr_from_result, num_expr_from_result, FloatBound, IntBound, NumBound,
 };
-use crate::pattern::{canonicalize_pattern, BindingsFromPattern, Pattern, PermitShadows};
+use crate::pattern::{
+    argument_binding, canonicalize_pattern, BindingsFromPattern, Pattern, PermitShadows,
+};
 use crate::procedure::References;
 use crate::scope::Scope;
 use crate::traverse::{walk_expr, Visitor};
@@ -15,12 +17,12 @@ use roc_collections::soa::Index;
 use roc_collections::{SendMap, VecMap, VecSet};
 use roc_error_macros::internal_error;
 use roc_module::called_via::CalledVia;
-use roc_module::ident::{ForeignSymbol, Lowercase, TagName};
+use roc_module::ident::{tuple_arity, ForeignSymbol, Lowercase, TagName};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::Symbol;
 use roc_parse::ast::{self, Defs, EscapedChar, StrLiteral};
 use roc_parse::pattern::PatternType::*;
-use roc_problem::can::{PrecedenceProblem, Problem, RuntimeError};
+use roc_problem::can::{ArgumentBinding, PrecedenceProblem, Problem, RuntimeError};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{ExhaustiveMark, IllegalCycleMark, RedundantMark, VarStore, Variable};
 use roc_types::types::{Alias, Category, LambdaSet, OptAbleVar, Type};
@@ -238,6 +240,21 @@ pub enum Expr {
         lookups_in_cond: Vec<(Symbol, Variable)>,
     },
 
+    /// Reports the value of `loc_message` with its location, then evaluates `loc_continuation`
+    Dbg {
+        loc_message: Box<Loc<Expr>>,
+        loc_continuation: Box<Loc<Expr>>,
+        variable: Variable,
+        /// where `loc_message` is, as reported at runtime
+        location: Box<str>,
+    },
+
+    /// Stops the program with a message, e.g. `crash "unreachable"`
+    Crash {
+        msg: Box<Loc<Expr>>,
+        ret_var: Variable,
+    },
+
     /// Rendered as empty box in editor
     TypedHole(Variable),
 
@@ -264,6 +281,7 @@ impl Expr {
             &Self::RunLowLevel { op, .. } => Category::LowLevelOpResult(op),
             Self::ForeignCall { .. } => Category::ForeignCall,
             Self::Closure(..) => Category::Lambda,
+            Self::Record { fields, .. } if tuple_arity(fields.keys()).is_some() => Category::Tuple,
             Self::Record { .. } => Category::Record,
             Self::EmptyRecord => Category::Record,
             Self::Access { field, .. } => Category::Access(field.clone()),
@@ -285,6 +303,10 @@ impl Expr {
             }
             Self::Expect { .. } => Category::Expect,
             Self::ExpectFx { .. } => Category::Expect,
+            Self::Dbg {
+                loc_continuation, ..
+            } => loc_continuation.value.category(),
+            Self::Crash { .. } => Category::Crash,
 
             // these nodes place no constraints on the expression's type
             Self::TypedHole(_) | Self::RuntimeError(..) => Category::Unknown,
@@ -557,6 +579,35 @@ pub fn canonicalize_expr<'a>(
 
             (answer, Output::default())
         }
+        ast::Expr::Tuple(elems) => {
+            // A tuple is the record whose fields are its elements, e.g. `(a, b)` is `{ 0: a, 1: b }`
+            let mut can_fields = SendMap::default();
+            let mut output = Output::default();
+
+            for (index, loc_elem) in elems.iter().enumerate() {
+                let (loc_can_expr, elem_output) =
+                    canonicalize_expr(env, var_store, scope, loc_elem.region, &loc_elem.value);
+
+                output.references.union_mut(&elem_output.references);
+
+                can_fields.insert(
+                    Lowercase::tuple_elem(index),
+                    Field {
+                        var: var_store.fresh(),
+                        region: loc_elem.region,
+                        loc_expr: Box::new(loc_can_expr),
+                    },
+                );
+            }
+
+            (
+                Record {
+                    record_var: var_store.fresh(),
+                    fields: can_fields,
+                },
+                output,
+            )
+        }
         ast::Expr::Record(fields) => {
             if fields.is_empty() {
                 (EmptyRecord, Output::default())
@@ -708,7 +759,27 @@ pub fn canonicalize_expr<'a>(
                 output.references.union_mut(&arg_out.references);
             }
 
-            if let ast::Expr::OpaqueRef(name) = loc_fn.value {
+            if let ast::Expr::Crash = loc_fn.value {
+                // We treat crash specially, since it always takes exactly one message.
+
+                debug_assert!(!args.is_empty());
+
+                if args.len() > 1 {
+                    let extra_args = loc_args[1..].iter().map(|loc_arg| &loc_arg.region);
+                    env.problem(Problem::OverAppliedCrash {
+                        region: Region::across_all(extra_args),
+                    });
+                }
+
+                let (_, msg) = args.swap_remove(0);
+
+                let crash = Crash {
+                    msg: Box::new(msg),
+                    ret_var: var_store.fresh(),
+                };
+
+                (crash, output)
+            } else if let ast::Expr::OpaqueRef(name) = loc_fn.value {
                 // We treat opaques specially, since an opaque can wrap exactly one argument.
 
                 debug_assert!(!args.is_empty());
@@ -822,6 +893,15 @@ pub fn canonicalize_expr<'a>(
         ast::Expr::Var { module_name, ident } => {
             canonicalize_var_lookup(env, var_store, scope, module_name, ident, region)
         }
+        ast::Expr::Crash => {
+            // `crash` needs a message, so a bare `crash` can't be used as a value
+            env.problem(Problem::UnappliedCrash { region });
+
+            (
+                RuntimeError(roc_problem::can::RuntimeError::NoImplementation),
+                Output::default(),
+            )
+        }
         ast::Expr::Underscore(name) => {
             // we parse underscores, but they are not valid expression syntax
             let problem = roc_problem::can::RuntimeError::MalformedIdentifier(
@@ -1006,6 +1086,33 @@ pub fn canonicalize_expr<'a>(
                 output,
             )
         }
+        ast::Expr::Dbg(message, continuation) => {
+            let mut output = Output::default();
+
+            let (loc_message, output1) =
+                canonicalize_expr(env, var_store, scope, message.region, &message.value);
+
+            let (loc_continuation, output2) = canonicalize_expr(
+                env,
+                var_store,
+                scope,
+                continuation.region,
+                &continuation.value,
+            );
+
+            output.union(output1);
+            output.union(output2);
+
+            (
+                Dbg {
+                    location: env.location(message.region).into_boxed_str(),
+                    loc_message: Box::new(loc_message),
+                    loc_continuation: Box::new(loc_continuation),
+                    variable: var_store.fresh(),
+                },
+                output,
+            )
+        }
         ast::Expr::If(if_thens, final_else_branch) => {
             let mut branches = Vec::with_capacity(if_thens.len());
             let mut output = Output::default();
@@ -1256,11 +1363,17 @@ fn canonicalize_closure_body<'a>(
     for (sub_symbol, region) in bound_by_argument_patterns {
         if !output.references.has_value_lookup(sub_symbol) {
             // The body never referenced this argument we declared. It's an unused argument!
+            let binding = can_args
+                .iter()
+                .find_map(|(_, _, loc_pattern)| argument_binding(loc_pattern, sub_symbol))
+                .unwrap_or(ArgumentBinding::Identifier);
+
             env.problem(Problem::UnusedArgument(
                 symbol,
                 is_anonymous,
                 sub_symbol,
                 region,
+                binding,
             ));
         } else {
             // We shouldn't ultimately count arguments as referenced locals. Otherwise,
@@ -1802,6 +1915,42 @@ pub fn inline_calls(var_store: &mut VarStore, scope: &mut Scope, expr: Expr) ->
             }
         }
 
+        Dbg {
+            loc_message,
+            loc_continuation,
+            variable,
+            location,
+        } => {
+            let loc_message = Loc {
+                region: loc_message.region,
+                value: inline_calls(var_store, scope, loc_message.value),
+            };
+
+            let loc_continuation = Loc {
+                region: loc_continuation.region,
+                value: inline_calls(var_store, scope, loc_continuation.value),
+            };
+
+            Dbg {
+                loc_message: Box::new(loc_message),
+                loc_continuation: Box::new(loc_continuation),
+                variable,
+                location,
+            }
+        }
+
+        Crash { msg, ret_var } => {
+            let msg = Loc {
+                region: msg.region,
+                value: inline_calls(var_store, scope, msg.value),
+            };
+
+            Crash {
+                msg: Box::new(msg),
+                ret_var,
+            }
+        }
+
         LetRec(defs, loc_expr, mark) => {
             let mut new_defs = Vec::with_capacity(defs.len());
 
@@ -2521,9 +2670,10 @@ impl Declarations {
             })
     }
 
-    pub fn expects(&self) -> VecMap<Region, Vec<(Symbol, Variable)>> {
+    pub fn expects(&self) -> ExpectLookups {
         let mut collector = ExpectCollector {
             expects: VecMap::default(),
+            dbgs: VecMap::default(),
         };
 
         let var = Variable::EMPTY_RECORD;
@@ -2556,10 +2706,20 @@ impl Declarations {
             }
         }
 
-        collector.expects
+        ExpectLookups {
+            expects: collector.expects,
+            dbgs: collector.dbgs,
+        }
     }
 }
 
+/// The values that `expect` failures and `dbg`s report at runtime, keyed by source region
+#[derive(Debug, Default)]
+pub struct ExpectLookups {
+    pub expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+    pub dbgs: VecMap<Region, Variable>,
+}
+
 roc_error_macros::assert_sizeof_default!(DeclarationTag, 8);
 
 #[derive(Clone, Copy, Debug)]
@@ -2697,6 +2857,16 @@ fn get_lookup_symbols(expr: &Expr, var_store: &mut VarStore) -> Vec<(Symbol, Var
                 // Intentionally ignore the lookups in the nested `expect` condition itself,
                 // because they couldn't possibly influence the outcome of this `expect`!
             }
+            Expr::Dbg {
+                loc_continuation, ..
+            } => {
+                stack.push(&(*loc_continuation).value);
+
+                // Like with a nested `expect`, what a `dbg` reports can't influence the outcome
+            }
+            Expr::Crash { msg, .. } => {
+                stack.push(&msg.value);
+            }
             Expr::Num(_, _, _, _)
             | Expr::Float(_, _, _, _, _)
             | Expr::Int(_, _, _, _, _)
@@ -2809,6 +2979,7 @@ fn toplevel_expect_to_inline_expect_help(mut loc_expr: Loc<Expr>, has_effects: b
 
 struct ExpectCollector {
     expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+    dbgs: VecMap<Region, Variable>,
 }
 
 impl crate::traverse::Visitor for ExpectCollector {
@@ -2827,6 +2998,13 @@ impl crate::traverse::Visitor for ExpectCollector {
                 self.expects
                     .insert(loc_condition.region, lookups_in_cond.to_vec());
             }
+            Expr::Dbg {
+                loc_message,
+                variable,
+                ..
+            } => {
+                self.dbgs.insert(loc_message.region, *variable);
+            }
             _ => (),
         }
 
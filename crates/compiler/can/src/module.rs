@@ -3,7 +3,7 @@ use crate::annotation::canonicalize_annotation;
 use crate::def::{canonicalize_defs, Def};
 use crate::effect_module::HostedGeneratedFunctions;
 use crate::env::Env;
-use crate::expr::{ClosureData, Declarations, Expr, Output, PendingDerives};
+use crate::expr::{ClosureData, Declarations, ExpectLookups, Expr, Output, PendingDerives};
 use crate::pattern::{BindingsFromPattern, Pattern};
 use crate::scope::Scope;
 use bumpalo::Bump;
@@ -16,7 +16,7 @@ use roc_parse::ast::{Defs, TypeAnnotation};
 use roc_parse::header::HeaderFor;
 use roc_parse::pattern::PatternType;
 use roc_problem::can::{Problem, RuntimeError};
-use roc_region::all::{Loc, Region};
+use roc_region::all::{LineInfo, Loc, Region};
 use roc_types::subs::{ExposedTypesStorageSubs, VarStore, Variable};
 use roc_types::types::{Alias, AliasKind, AliasVar, Type};
 
@@ -111,12 +111,18 @@ impl ExposedForModule {
 /// which is the job of this structure.
 pub type ResolvedImplementations = VecMap<ImplKey, ResolvedImpl>;
 
+/// How the default implementations of an ability's members are resolved, keyed by the symbol of
+/// the default implementation. Modules using a default implementation for their own opaque types
+/// need this to restore its specialization lambda sets.
+pub type ResolvedDefaultImplementations = VecMap<Symbol, ResolvedImpl>;
+
 /// The types of all exposed values/functions of a module. This includes ability member
 /// specializations.
 #[derive(Clone, Debug)]
 pub struct ExposedModuleTypes {
     pub exposed_types_storage_subs: ExposedTypesStorageSubs,
     pub resolved_implementations: ResolvedImplementations,
+    pub resolved_default_implementations: ResolvedDefaultImplementations,
 }
 
 #[derive(Debug)]
@@ -131,6 +137,7 @@ pub struct Module {
     pub rigid_variables: RigidVariables,
     pub abilities_store: PendingAbilitiesStore,
     pub loc_expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+    pub loc_dbgs: VecMap<Region, Variable>,
 }
 
 #[derive(Debug, Default)]
@@ -154,6 +161,7 @@ pub struct ModuleOutput {
     pub pending_derives: PendingDerives,
     pub scope: Scope,
     pub loc_expects: VecMap<Region, Vec<(Symbol, Variable)>>,
+    pub loc_dbgs: VecMap<Region, Variable>,
 }
 
 fn validate_generate_with<'a>(
@@ -272,11 +280,13 @@ pub fn canonicalize_module_defs<'a>(
     exposed_imports: MutMap<Ident, (Symbol, Region)>,
     exposed_symbols: &VecSet<Symbol>,
     symbols_from_requires: &[(Loc<Symbol>, Loc<TypeAnnotation<'a>>)],
+    src_location: (&'a std::path::Path, &'a LineInfo),
     var_store: &mut VarStore,
 ) -> ModuleOutput {
     let mut can_exposed_imports = MutMap::default();
     let mut scope = Scope::new(home, exposed_ident_ids, imported_abilities_state);
     let mut env = Env::new(arena, home, dep_idents, module_ids);
+    env.src_location = Some(src_location);
     let num_deps = dep_idents.len();
 
     for (name, alias) in aliases.into_iter() {
@@ -315,6 +325,20 @@ pub fn canonicalize_module_defs<'a>(
     //
     // Here we essentially add those "defs" to "the beginning of the module"
     // by canonicalizing them right before we canonicalize the actual ast::Def nodes.
+    // Names imported with `imports [Foo.{ bar }]`, as opposed to the ones every module gets.
+    // Platforms and hosted modules are left out, since the names they import can be used
+    // in ways that don't show up as references, like in the `requires` signature.
+    let explicit_exposed_imports: Vec<(Symbol, Region)> =
+        if matches!(header_for, HeaderFor::App { .. } | HeaderFor::Interface) {
+            exposed_imports
+                .values()
+                .filter(|(_, region)| *region != Region::zero())
+                .copied()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
     for (ident, (symbol, region)) in exposed_imports {
         let first_char = ident.as_inline_str().as_str().chars().next().unwrap();
 
@@ -384,6 +408,11 @@ pub fn canonicalize_module_defs<'a>(
         if !output.references.has_type_or_value_lookup(symbol)
             && !exposed_symbols.contains(&symbol)
             && !scope.abilities_store.is_specialization_name(symbol)
+            // Default implementations are used through their member, never by name.
+            && scope
+                .abilities_store
+                .member_of_default_implementation(symbol)
+                .is_none()
         {
             env.problem(Problem::UnusedDef(symbol, region));
         }
@@ -696,6 +725,12 @@ pub fn canonicalize_module_defs<'a>(
     referenced_values.extend(env.qualified_value_lookups.iter().copied());
     referenced_types.extend(env.qualified_type_lookups.iter().copied());
 
+    for (symbol, region) in explicit_exposed_imports {
+        if !referenced_values.contains(&symbol) && !referenced_types.contains(&symbol) {
+            env.problem(Problem::UnusedExposedImport(symbol, region));
+        }
+    }
+
     let mut fix_closures_no_capture_symbols = VecSet::default();
     let mut fix_closures_closure_captures = VecMap::default();
     for index in 0..declarations.len() {
@@ -782,7 +817,10 @@ pub fn canonicalize_module_defs<'a>(
         }
     }
 
-    let loc_expects = declarations.expects();
+    let ExpectLookups {
+        expects: loc_expects,
+        dbgs: loc_dbgs,
+    } = declarations.expects();
 
     ModuleOutput {
         scope,
@@ -797,6 +835,7 @@ pub fn canonicalize_module_defs<'a>(
         pending_derives,
         lookups,
         loc_expects,
+        loc_dbgs,
     }
 }
 
@@ -905,6 +944,22 @@ fn fix_values_captured_in_closure_pattern(
                 }
             }
         }
+        List { patterns, .. } => {
+            for loc_pat in patterns.patterns.iter_mut() {
+                fix_values_captured_in_closure_pattern(
+                    &mut loc_pat.value,
+                    no_capture_symbols,
+                    closure_captures,
+                );
+            }
+        }
+        As(subpattern, _) => {
+            fix_values_captured_in_closure_pattern(
+                &mut subpattern.value,
+                no_capture_symbols,
+                closure_captures,
+            );
+        }
         Identifier(_)
         | NumLiteral(..)
         | IntLiteral(..)
@@ -981,6 +1036,31 @@ fn fix_values_captured_in_closure_expr(
             );
         }
 
+        Dbg {
+            loc_message,
+            loc_continuation,
+            ..
+        } => {
+            fix_values_captured_in_closure_expr(
+                &mut loc_message.value,
+                no_capture_symbols,
+                closure_captures,
+            );
+            fix_values_captured_in_closure_expr(
+                &mut loc_continuation.value,
+                no_capture_symbols,
+                closure_captures,
+            );
+        }
+
+        Crash { msg, ret_var: _ } => {
+            fix_values_captured_in_closure_expr(
+                &mut msg.value,
+                no_capture_symbols,
+                closure_captures,
+            );
+        }
+
         Closure(ClosureData {
             captured_symbols,
             name,
@@ -6,7 +6,9 @@ use roc_module::called_via::BinOp::Pizza;
 use roc_module::called_via::{BinOp, CalledVia};
 use roc_module::ident::ModuleName;
 use roc_parse::ast::Expr::{self, *};
-use roc_parse::ast::{AssignedField, ValueDef, WhenBranch};
+use roc_parse::ast::{
+    AbilityMember, AbilityMemberDefault, AssignedField, TypeDef, ValueDef, WhenBranch,
+};
 use roc_region::all::{Loc, Region};
 
 // BinOp precedence logic adapted from Gluon by Markus Westerlind
@@ -105,7 +107,40 @@ fn desugar_value_def<'a>(arena: &'a Bump, def: &'a ValueDef<'a>) -> ValueDef<'a>
     }
 }
 
+fn desugar_type_def<'a>(arena: &'a Bump, def: &'a TypeDef<'a>) -> TypeDef<'a> {
+    match def {
+        TypeDef::Ability {
+            header,
+            loc_has,
+            required_abilities,
+            members,
+        } if members.iter().any(|member| member.default.is_some()) => {
+            // Default member implementations are expressions, so they need desugaring too.
+            let members = arena.alloc_slice_fill_iter(members.iter().map(|member| {
+                let default = member.default.map(|default| AbilityMemberDefault {
+                    name: default.name,
+                    body: desugar_expr(arena, default.body),
+                });
+
+                AbilityMember { default, ..*member }
+            }));
+
+            TypeDef::Ability {
+                header: *header,
+                loc_has: *loc_has,
+                required_abilities,
+                members,
+            }
+        }
+        _ => *def,
+    }
+}
+
 pub fn desugar_defs<'a>(arena: &'a Bump, defs: &mut roc_parse::ast::Defs<'a>) {
+    for type_def in defs.type_defs.iter_mut() {
+        *type_def = desugar_type_def(arena, arena.alloc(*type_def));
+    }
+
     for value_def in defs.value_defs.iter_mut() {
         *value_def = desugar_value_def(arena, arena.alloc(*value_def));
     }
@@ -123,6 +158,7 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
         | AccessorFunction(_)
         | Var { .. }
         | Underscore { .. }
+        | Crash
         | MalformedIdent(_, _)
         | MalformedClosure
         | PrecedenceConflict { .. }
@@ -153,6 +189,20 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
                 value,
             })
         }
+        Tuple(items) => {
+            let mut new_items = Vec::with_capacity_in(items.len(), arena);
+
+            for item in items.iter() {
+                new_items.push(desugar_expr(arena, item));
+            }
+            let new_items = new_items.into_bump_slice();
+            let value: Expr<'a> = Tuple(items.replace_items(new_items));
+
+            arena.alloc(Loc {
+                region: loc_expr.region,
+                value,
+            })
+        }
         Record(fields) => arena.alloc(Loc {
             region: loc_expr.region,
             value: Record(fields.map_items(arena, |field| {
@@ -344,6 +394,14 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
                 region: loc_expr.region,
             })
         }
+        Dbg(message, continuation) => {
+            let desugared_message = &*arena.alloc(desugar_expr(arena, message));
+            let desugared_continuation = &*arena.alloc(desugar_expr(arena, continuation));
+            arena.alloc(Loc {
+                value: Dbg(desugared_message, desugared_continuation),
+                region: loc_expr.region,
+            })
+        }
     }
 }
 
@@ -6,11 +6,14 @@ use crate::num::{
     ParsedNumResult,
 };
 use crate::scope::{PendingAbilitiesInScope, Scope};
+use roc_exhaustive::ListArity;
 use roc_module::ident::{Ident, Lowercase, TagName};
 use roc_module::symbol::Symbol;
 use roc_parse::ast::{self, StrLiteral, StrSegment};
 use roc_parse::pattern::PatternType;
-use roc_problem::can::{MalformedPatternProblem, Problem, RuntimeError, ShadowKind};
+use roc_problem::can::{
+    ArgumentBinding, MalformedPatternProblem, Problem, RuntimeError, ShadowKind,
+};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{VarStore, Variable};
 use roc_types::types::{LambdaSet, OptAbleVar, PatternCategory, Type};
@@ -20,6 +23,8 @@ use roc_types::types::{LambdaSet, OptAbleVar, PatternCategory, Type};
 #[derive(Clone, Debug)]
 pub enum Pattern {
     Identifier(Symbol),
+    /// A pattern that also binds the whole matched value, e.g. `Ok x as result`
+    As(Box<Loc<Pattern>>, Loc<Symbol>),
     AppliedTag {
         whole_var: Variable,
         ext_var: Variable,
@@ -55,6 +60,11 @@ pub enum Pattern {
         ext_var: Variable,
         destructs: Vec<Loc<RecordDestruct>>,
     },
+    List {
+        list_var: Variable,
+        elem_var: Variable,
+        patterns: ListPatterns,
+    },
     NumLiteral(Variable, Box<str>, IntValue, NumBound),
     IntLiteral(Variable, Variable, Box<str>, IntValue, IntBound),
     FloatLiteral(Variable, Variable, Box<str>, f64, FloatBound),
@@ -87,10 +97,12 @@ impl Pattern {
         use Pattern::*;
         match self {
             Identifier(_) => None,
+            As(pattern, _) => pattern.value.opt_var(),
 
             AppliedTag { whole_var, .. } => Some(*whole_var),
             UnwrappedOpaque { whole_var, .. } => Some(*whole_var),
             RecordDestructure { whole_var, .. } => Some(*whole_var),
+            List { list_var, .. } => Some(*list_var),
             NumLiteral(var, ..) => Some(*var),
             IntLiteral(var, ..) => Some(*var),
             FloatLiteral(var, ..) => Some(*var),
@@ -117,7 +129,9 @@ impl Pattern {
             | UnsupportedPattern(..)
             | MalformedPattern(..)
             | AbilityMemberSpecialization { .. } => true,
+            As(pattern, _) => pattern.value.surely_exhaustive(),
             RecordDestructure { destructs, .. } => destructs.is_empty(),
+            List { patterns, .. } => patterns.surely_exhaustive(),
             AppliedTag { .. }
             | NumLiteral(..)
             | IntLiteral(..)
@@ -139,11 +153,13 @@ impl Pattern {
 
         match self {
             Identifier(_) => C::PatternDefault,
+            As(pattern, _) => pattern.value.category(),
 
             AppliedTag { tag_name, .. } => C::Ctor(tag_name.clone()),
             UnwrappedOpaque { opaque, .. } => C::Opaque(*opaque),
             RecordDestructure { destructs, .. } if destructs.is_empty() => C::EmptyRecord,
             RecordDestructure { .. } => C::Record,
+            List { .. } => C::List,
             NumLiteral(..) => C::Num,
             IntLiteral(..) => C::Int,
             FloatLiteral(..) => C::Float,
@@ -160,6 +176,33 @@ impl Pattern {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct ListPatterns {
+    pub patterns: Vec<Loc<Pattern>>,
+    /// Where a rest pattern splits patterns before and after it, if it does at all,
+    /// and the symbol the rest of the list is bound to, if any.
+    /// If present, patterns at index >= the rest index appear after the rest pattern.
+    /// For example:
+    ///   [ .., A, B ] -> patterns = [A, B], rest = 0
+    ///   [ A, .., B ] -> patterns = [A, B], rest = 1
+    ///   [ A, B, .. ] -> patterns = [A, B], rest = 2
+    pub opt_rest: Option<(usize, Option<Loc<Symbol>>)>,
+}
+
+impl ListPatterns {
+    pub fn arity(&self) -> ListArity {
+        match self.opt_rest {
+            Some((before, _)) => ListArity::Slice(before, self.patterns.len() - before),
+            None => ListArity::Exact(self.patterns.len()),
+        }
+    }
+
+    /// Is this list pattern the trivially-exhaustive pattern `[..]`?
+    fn surely_exhaustive(&self) -> bool {
+        self.patterns.is_empty() && matches!(self.opt_rest, Some((0, _)))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RecordDestruct {
     pub var: Variable,
@@ -608,6 +651,150 @@ pub fn canonicalize_pattern<'a>(
             })
         }
 
+        Tuple(patterns) => {
+            // A tuple pattern destructures the record its elements are the fields of,
+            // e.g. `(x, _)` is `{ 0: x, 1: _ }`.
+            let ext_var = var_store.fresh();
+            let whole_var = var_store.fresh();
+            let mut destructs = Vec::with_capacity(patterns.len());
+
+            for (index, loc_pattern) in patterns.iter().enumerate() {
+                let label = Lowercase::tuple_elem(index);
+                let symbol =
+                    scope.scopeless_symbol(&Ident::from(label.as_str()), loc_pattern.region);
+                let can_guard = canonicalize_pattern(
+                    env,
+                    var_store,
+                    scope,
+                    output,
+                    pattern_type,
+                    &loc_pattern.value,
+                    loc_pattern.region,
+                    permit_shadows,
+                );
+
+                destructs.push(Loc {
+                    region: loc_pattern.region,
+                    value: RecordDestruct {
+                        var: var_store.fresh(),
+                        label,
+                        symbol,
+                        typ: DestructType::Guard(var_store.fresh(), can_guard),
+                    },
+                });
+            }
+
+            Pattern::RecordDestructure {
+                whole_var,
+                ext_var,
+                destructs,
+            }
+        }
+
+        List(patterns) => {
+            let mut can_pats = Vec::with_capacity(patterns.len());
+            let mut opt_rest = None;
+
+            for loc_pattern in patterns.iter() {
+                let mut pattern = &loc_pattern.value;
+                while let SpaceBefore(sub_pattern, _) | SpaceAfter(sub_pattern, _) = pattern {
+                    pattern = sub_pattern;
+                }
+
+                match pattern {
+                    ListRest(opt_name) => {
+                        if opt_rest.is_some() {
+                            env.problem(Problem::MultipleListRestPattern { region });
+                            continue;
+                        }
+
+                        let opt_rest_symbol = opt_name.as_ref().map(|(_, loc_name)| {
+                            let can_rest = canonicalize_pattern(
+                                env,
+                                var_store,
+                                scope,
+                                output,
+                                pattern_type,
+                                &Identifier(loc_name.value),
+                                loc_name.region,
+                                permit_shadows,
+                            );
+
+                            match can_rest.value {
+                                Pattern::Identifier(symbol) | Pattern::Shadowed(_, _, symbol) => {
+                                    Loc::at(loc_name.region, symbol)
+                                }
+                                _ => unreachable!("an identifier pattern always binds a symbol"),
+                            }
+                        });
+
+                        opt_rest = Some((can_pats.len(), opt_rest_symbol));
+                    }
+                    _ => {
+                        let can_pat = canonicalize_pattern(
+                            env,
+                            var_store,
+                            scope,
+                            output,
+                            pattern_type,
+                            &loc_pattern.value,
+                            loc_pattern.region,
+                            permit_shadows,
+                        );
+
+                        can_pats.push(can_pat);
+                    }
+                }
+            }
+
+            Pattern::List {
+                list_var: var_store.fresh(),
+                elem_var: var_store.fresh(),
+                patterns: ListPatterns {
+                    patterns: can_pats,
+                    opt_rest,
+                },
+            }
+        }
+
+        ListRest(_) => {
+            unreachable!("should have been handled in List");
+        }
+
+        As(loc_pattern, pattern_as) => {
+            // canonicalize the inner pattern first, so that e.g. `x as x` reports shadowing
+            // on the `as` name
+            let can_subpattern = canonicalize_pattern(
+                env,
+                var_store,
+                scope,
+                output,
+                pattern_type,
+                &loc_pattern.value,
+                loc_pattern.region,
+                permit_shadows,
+            );
+
+            let can_identifier = canonicalize_pattern(
+                env,
+                var_store,
+                scope,
+                output,
+                pattern_type,
+                &Identifier(pattern_as.identifier.value),
+                pattern_as.identifier.region,
+                permit_shadows,
+            );
+
+            match can_identifier.value {
+                Pattern::Identifier(symbol) | Pattern::Shadowed(_, _, symbol) => Pattern::As(
+                    Box::new(can_subpattern),
+                    Loc::at(pattern_as.identifier.region, symbol),
+                ),
+                _ => unreachable!("an identifier pattern always binds a symbol"),
+            }
+        }
+
         RequiredField(_name, _loc_pattern) => {
             unreachable!("should have been handled in RecordDestructure");
         }
@@ -659,6 +846,58 @@ fn malformed_pattern(env: &mut Env, problem: MalformedPatternProblem, region: Re
     Pattern::MalformedPattern(problem, region)
 }
 
+/// How a pattern binds the given symbol, if it does.
+pub fn argument_binding(loc_pattern: &Loc<Pattern>, symbol: Symbol) -> Option<ArgumentBinding> {
+    use Pattern::*;
+
+    match &loc_pattern.value {
+        Identifier(bound) | AbilityMemberSpecialization { ident: bound, .. }
+            if *bound == symbol =>
+        {
+            Some(ArgumentBinding::Identifier)
+        }
+        As(_, bound) if bound.value == symbol => Some(ArgumentBinding::Identifier),
+        As(pattern, _) => argument_binding(pattern, symbol),
+        AppliedTag { arguments, .. } => arguments
+            .iter()
+            .find_map(|(_, loc_arg)| argument_binding(loc_arg, symbol)),
+        UnwrappedOpaque { argument, .. } => argument_binding(&argument.1, symbol),
+        RecordDestructure { destructs, .. } => {
+            destructs
+                .iter()
+                .find_map(|loc_destruct| match &loc_destruct.value.typ {
+                    DestructType::Required if loc_destruct.value.symbol == symbol => {
+                        Some(ArgumentBinding::RecordField)
+                    }
+                    DestructType::Optional(..) if loc_destruct.value.symbol == symbol => {
+                        Some(ArgumentBinding::OptionalRecordField)
+                    }
+                    DestructType::Required | DestructType::Optional(..) => None,
+                    DestructType::Guard(_, inner) => argument_binding(inner, symbol),
+                })
+        }
+        List { patterns, .. } => match &patterns.opt_rest {
+            Some((_, Some(rest))) if rest.value == symbol => Some(ArgumentBinding::Identifier),
+            _ => patterns
+                .patterns
+                .iter()
+                .find_map(|loc_pat| argument_binding(loc_pat, symbol)),
+        },
+        Identifier(_)
+        | AbilityMemberSpecialization { .. }
+        | NumLiteral(..)
+        | IntLiteral(..)
+        | FloatLiteral(..)
+        | StrLiteral(_)
+        | SingleQuote(_)
+        | Underscore
+        | Shadowed(_, _, _)
+        | MalformedPattern(_, _)
+        | UnsupportedPattern(_)
+        | OpaqueNotInScope(..) => None,
+    }
+}
+
 /// An iterator over the bindings made by a pattern.
 ///
 /// We attempt to make no allocations when we can.
@@ -705,6 +944,11 @@ impl<'a> BindingsFromPattern<'a> {
                         } => {
                             return Some((*symbol, loc_pattern.region));
                         }
+                        As(pattern, symbol) => {
+                            stack.push(Pattern(pattern));
+
+                            return Some((symbol.value, symbol.region));
+                        }
                         AppliedTag {
                             arguments: loc_args,
                             ..
@@ -720,6 +964,17 @@ impl<'a> BindingsFromPattern<'a> {
                             let it = destructs.iter().rev().map(Destruct);
                             stack.extend(it);
                         }
+                        List {
+                            patterns: ListPatterns { patterns, opt_rest },
+                            ..
+                        } => {
+                            let it = patterns.iter().rev().map(Pattern);
+                            stack.extend(it);
+
+                            if let Some((_, Some(rest))) = opt_rest {
+                                return Some((rest.value, rest.region));
+                            }
+                        }
                         NumLiteral(..)
                         | IntLiteral(..)
                         | FloatLiteral(..)
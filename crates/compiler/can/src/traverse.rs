@@ -81,7 +81,11 @@ pub fn walk_decls<V: Visitor>(visitor: &mut V, decls: &Declarations) {
                     &function_def.value.arguments,
                     loc_body,
                     function_def.value.return_type,
-                )
+                );
+
+                if let Some(annot) = &decls.annotations[index] {
+                    visitor.visit_annotation(annot);
+                }
             }
             Destructure(destructure_index) => {
                 let destructure = &decls.destructs[destructure_index.index() as usize];
@@ -289,6 +293,22 @@ pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr, var: Variable) {
                 Variable::NULL,
             );
         }
+        Expr::Dbg {
+            loc_message,
+            loc_continuation,
+            variable,
+            ..
+        } => {
+            visitor.visit_expr(&loc_message.value, loc_message.region, *variable);
+            visitor.visit_expr(
+                &loc_continuation.value,
+                loc_continuation.region,
+                Variable::NULL,
+            );
+        }
+        Expr::Crash { msg, ret_var: _ } => {
+            visitor.visit_expr(&msg.value, msg.region, Variable::STR);
+        }
         Expr::TypedHole(_) => { /* terminal */ }
         Expr::RuntimeError(..) => { /* terminal */ }
     }
@@ -462,6 +482,9 @@ pub fn walk_pattern<V: Visitor>(visitor: &mut V, pattern: &Pattern) {
 
     match pattern {
         Identifier(..) => { /* terminal */ }
+        As(pattern, _) => {
+            visitor.visit_pattern(&pattern.value, pattern.region, pattern.value.opt_var())
+        }
         AppliedTag { arguments, .. } => arguments
             .iter()
             .for_each(|(v, lp)| visitor.visit_pattern(&lp.value, lp.region, Some(*v))),
@@ -472,6 +495,12 @@ pub fn walk_pattern<V: Visitor>(visitor: &mut V, pattern: &Pattern) {
         RecordDestructure { destructs, .. } => destructs
             .iter()
             .for_each(|d| visitor.visit_record_destruct(&d.value, d.region)),
+        List {
+            patterns, elem_var, ..
+        } => patterns
+            .patterns
+            .iter()
+            .for_each(|p| visitor.visit_pattern(&p.value, p.region, Some(*elem_var))),
         NumLiteral(..) => { /* terminal */ }
         IntLiteral(..) => { /* terminal */ }
         FloatLiteral(..) => { /* terminal */ }
@@ -533,6 +562,273 @@ pub fn find_type_at(region: Region, decls: &Declarations) -> Option<Variable> {
     visitor.typ
 }
 
+/// Finds the variables of all `_` placeholders in annotations, in source order.
+pub fn find_inferred_holes(decls: &Declarations) -> Vec<Loc<Variable>> {
+    let mut visitor = Collector { holes: Vec::new() };
+    visitor.visit_decls(decls);
+    visitor.holes.sort_by_key(|hole| hole.region);
+    return visitor.holes;
+
+    struct Collector {
+        holes: Vec<Loc<Variable>>,
+    }
+
+    impl Visitor for Collector {
+        fn visit_annotation(&mut self, annotation: &Annotation) {
+            let inferred = &annotation.introduced_variables.inferred;
+            self.holes.extend(inferred.iter().copied());
+        }
+    }
+}
+
+/// Finds where every symbol bound in the declarations is introduced, in source order.
+pub fn find_symbol_regions(decls: &Declarations) -> Vec<Loc<Symbol>> {
+    let mut visitor = Collector {
+        symbols: Vec::new(),
+    };
+    visitor.visit_decls(decls);
+    visitor.symbols.sort_by_key(|symbol| symbol.region);
+    return visitor.symbols;
+
+    struct Collector {
+        symbols: Vec<Loc<Symbol>>,
+    }
+
+    impl Visitor for Collector {
+        fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+            if let Expr::Closure(ClosureData { name, .. }) = expr {
+                self.symbols.push(Loc::at(region, *name));
+            }
+
+            walk_expr(self, expr, var);
+        }
+
+        fn visit_pattern(&mut self, pattern: &Pattern, region: Region, _opt_var: Option<Variable>) {
+            use Pattern::*;
+            match pattern {
+                Identifier(symbol)
+                | Shadowed(_, _, symbol)
+                | AbilityMemberSpecialization { ident: symbol, .. } => {
+                    self.symbols.push(Loc::at(region, *symbol));
+                }
+                As(_, loc_symbol) => self.symbols.push(*loc_symbol),
+                _ => {}
+            }
+
+            walk_pattern(self, pattern);
+        }
+
+        fn visit_record_destruct(&mut self, destruct: &RecordDestruct, region: Region) {
+            if !matches!(destruct.typ, DestructType::Guard(..)) {
+                self.symbols.push(Loc::at(region, destruct.symbol));
+            }
+
+            walk_record_destruct(self, destruct);
+        }
+    }
+}
+
+/// The region of the whole definition of the symbol bound at `region`, from its annotation to
+/// the end of its body. [None] if the symbol isn't defined on its own, like one of the fields
+/// a definition destructures.
+pub fn find_def_region(region: Region, decls: &Declarations) -> Option<Region> {
+    use crate::expr::DeclarationTag::*;
+
+    for (index, tag) in decls.declarations.iter().enumerate() {
+        if let Value | Function(_) | Recursive(_) | TailRecursive(_) = tag {
+            if decls.symbols[index].region == region {
+                let head_region = match &decls.annotations[index] {
+                    Some(annotation) if annotation.region.start() < region.start() => {
+                        annotation.region
+                    }
+                    _ => region,
+                };
+
+                return Some(Region::span_across(
+                    &head_region,
+                    &decls.expressions[index].region,
+                ));
+            }
+        }
+    }
+
+    let mut visitor = Finder {
+        region,
+        def_region: None,
+    };
+    visitor.visit_decls(decls);
+    return visitor.def_region;
+
+    struct Finder {
+        region: Region,
+        def_region: Option<Region>,
+    }
+
+    impl Visitor for Finder {
+        fn should_visit(&mut self, region: Region) -> bool {
+            self.def_region.is_none() && region.contains(&self.region)
+        }
+
+        fn visit_def(&mut self, def: &Def) {
+            if !self.should_visit(def.region()) {
+                return;
+            }
+
+            match def.loc_pattern.value {
+                Pattern::Identifier(_) if def.loc_pattern.region == self.region => {
+                    self.def_region = Some(def.region());
+                }
+                _ => walk_def(self, def),
+            }
+        }
+    }
+}
+
+/// The lookups meant for a binding at `shadow_region` that shadows the symbol defined at
+/// `original_region`: the lookups of that symbol that follow the binding in the scope it
+/// introduces, like the body of a closure or the rest of a `when` branch. [None] if the binding
+/// doesn't introduce a scope, like a top-level definition.
+pub fn find_shadowed_lookups(
+    original_region: Region,
+    shadow_region: Region,
+    decls: &Declarations,
+) -> Option<Vec<Region>> {
+    let original = find_symbol_regions(decls)
+        .into_iter()
+        .find(|loc_symbol| loc_symbol.region == original_region)?
+        .value;
+
+    let mut scope_finder = ScopeFinder {
+        shadow_region,
+        scope: None,
+    };
+    scope_finder.visit_decls(decls);
+
+    let mut collector = Collector {
+        symbol: original,
+        shadow_region,
+        scope: scope_finder.scope?,
+        lookups: Vec::new(),
+    };
+    collector.visit_decls(decls);
+    return Some(collector.lookups);
+
+    struct ScopeFinder {
+        shadow_region: Region,
+        scope: Option<Region>,
+    }
+
+    impl ScopeFinder {
+        fn check<'p>(
+            &mut self,
+            mut patterns: impl Iterator<Item = &'p Loc<Pattern>>,
+            scope: Region,
+        ) {
+            let shadow_region = self.shadow_region;
+
+            if patterns.any(|pattern| {
+                symbols_introduced_from_pattern(pattern)
+                    .any(|loc_symbol| loc_symbol.region == shadow_region)
+            }) {
+                self.scope = Some(scope);
+            }
+        }
+    }
+
+    impl Visitor for ScopeFinder {
+        fn should_visit(&mut self, region: Region) -> bool {
+            self.scope.is_none() && region.contains(&self.shadow_region)
+        }
+
+        fn visit_decls(&mut self, decls: &Declarations) {
+            use crate::expr::DeclarationTag::*;
+
+            // The arguments of top-level functions aren't part of a closure expression
+            for (index, tag) in decls.declarations.iter().enumerate() {
+                if let Function(function_index)
+                | Recursive(function_index)
+                | TailRecursive(function_index) = tag
+                {
+                    let function_def = &decls.function_bodies[function_index.index()];
+                    let arguments = function_def.value.arguments.iter();
+
+                    self.check(
+                        arguments.map(|(_, _, pattern)| pattern),
+                        decls.expressions[index].region,
+                    );
+                }
+            }
+
+            walk_decls(self, decls);
+        }
+
+        fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+            if !self.should_visit(region) {
+                return;
+            }
+
+            match expr {
+                Expr::Closure(closure_data) => self.check(
+                    closure_data.arguments.iter().map(|(_, _, pattern)| pattern),
+                    closure_data.loc_body.region,
+                ),
+                Expr::LetNonRec(def, body) => {
+                    self.check(std::iter::once(&def.loc_pattern), body.region)
+                }
+                Expr::LetRec(defs, body, _) => {
+                    self.check(defs.iter().map(|def| &def.loc_pattern), body.region)
+                }
+                Expr::When { branches, .. } => {
+                    for branch in branches {
+                        let scope = match &branch.guard {
+                            Some(guard) => Region::span_across(&guard.region, &branch.value.region),
+                            None => branch.value.region,
+                        };
+
+                        self.check(
+                            branch.patterns.iter().map(|pattern| &pattern.pattern),
+                            scope,
+                        );
+                    }
+                }
+                _ => {}
+            }
+
+            walk_expr(self, expr, var);
+        }
+    }
+
+    struct Collector {
+        symbol: Symbol,
+        shadow_region: Region,
+        scope: Region,
+        lookups: Vec<Region>,
+    }
+
+    impl Visitor for Collector {
+        fn should_visit(&mut self, region: Region) -> bool {
+            region.contains(&self.scope) || self.scope.contains(&region)
+        }
+
+        fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+            if !self.should_visit(region) {
+                return;
+            }
+
+            match expr {
+                Expr::Var(symbol)
+                    if *symbol == self.symbol
+                        && self.scope.contains(&region)
+                        && region.start() > self.shadow_region.end() =>
+                {
+                    self.lookups.push(region);
+                }
+                _ => walk_expr(self, expr, var),
+            }
+        }
+    }
+}
+
 /// Given an ability Foo has foo : ..., returns (T, foo1) if the symbol at the given region is a
 /// symbol foo1 that specializes foo for T. Otherwise if the symbol is foo but the specialization
 /// is unknown, (Foo, foo) is returned. Otherwise [None] is returned.
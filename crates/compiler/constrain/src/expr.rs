@@ -20,7 +20,7 @@ use roc_can::traverse::symbols_introduced_from_pattern;
 use roc_collections::all::{HumanIndex, MutMap, SendMap};
 use roc_collections::soa::Index;
 use roc_collections::VecMap;
-use roc_module::ident::Lowercase;
+use roc_module::ident::{tuple_arity, Lowercase};
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{IllegalCycleMark, Variable};
@@ -210,12 +210,17 @@ pub fn constrain_expr(
                     rec_constraints.push(field_con);
                 }
 
+                let category = if tuple_arity(field_types.keys()).is_some() {
+                    Category::Tuple
+                } else {
+                    Category::Record
+                };
                 let record_type = Type::Record(field_types, TypeExtension::Closed);
 
                 let record_con = constraints.equal_types_with_storage(
                     record_type,
                     expected,
-                    Category::Record,
+                    category,
                     region,
                     *record_var,
                 );
@@ -567,6 +572,44 @@ pub fn constrain_expr(
             constraints.exists_many(vars, all_constraints)
         }
 
+        Dbg {
+            loc_message,
+            loc_continuation,
+            variable,
+            ..
+        } => {
+            let message_con = constrain_expr(
+                constraints,
+                env,
+                loc_message.region,
+                &loc_message.value,
+                NoExpectation(Type::Variable(*variable)),
+            );
+
+            let continuation_con = constrain_expr(
+                constraints,
+                env,
+                loc_continuation.region,
+                &loc_continuation.value,
+                expected,
+            );
+
+            constraints.exists_many([*variable], [message_con, continuation_con])
+        }
+
+        Crash { msg, ret_var } => {
+            let expected_msg = Expected::ForReason(Reason::CrashArg, str_type(), msg.region);
+
+            let msg_is_str = constrain_expr(constraints, env, msg.region, &msg.value, expected_msg);
+
+            // a crash never returns, so it can stand in for a value of any type
+            let ret_con = constraints.equal_types_var(*ret_var, expected, Category::Crash, region);
+
+            let and = constraints.and_constraint([msg_is_str, ret_con]);
+
+            constraints.exists([*ret_var], and)
+        }
+
         If {
             cond_var,
             branch_var,
@@ -3,10 +3,10 @@ use crate::expr::{constrain_expr, Env};
 use roc_can::constraint::{Constraint, Constraints};
 use roc_can::expected::{Expected, PExpected};
 use roc_can::pattern::Pattern::{self, *};
-use roc_can::pattern::{DestructType, RecordDestruct};
+use roc_can::pattern::{DestructType, ListPatterns, RecordDestruct};
 use roc_collections::all::{HumanIndex, SendMap};
 use roc_collections::VecMap;
-use roc_module::ident::Lowercase;
+use roc_module::ident::{tuple_arity, Lowercase};
 use roc_module::symbol::Symbol;
 use roc_region::all::{Loc, Region};
 use roc_types::subs::Variable;
@@ -64,6 +64,12 @@ fn headers_from_annotation_help(
             headers.insert(*symbol, typ);
             true
         }
+        As(subpattern, symbol) => {
+            let typ = Loc::at(annotation.region, annotation.value.clone());
+            headers.insert(symbol.value, typ);
+
+            headers_from_annotation_help(&subpattern.value, annotation, headers)
+        }
         Underscore
         | MalformedPattern(_, _)
         | UnsupportedPattern(_)
@@ -101,6 +107,27 @@ fn headers_from_annotation_help(
             _ => false,
         },
 
+        List {
+            patterns: ListPatterns { patterns, opt_rest },
+            ..
+        } => match annotation.value.shallow_dealias() {
+            Type::Apply(Symbol::LIST_LIST, type_arguments, _) if type_arguments.len() == 1 => {
+                if let Some((_, Some(rest))) = opt_rest {
+                    headers.insert(
+                        rest.value,
+                        Loc::at(annotation.region, annotation.value.clone()),
+                    );
+                }
+
+                let elem_type = Loc::at(annotation.region, &type_arguments[0]);
+
+                patterns
+                    .iter()
+                    .all(|loc_pat| headers_from_annotation_help(&loc_pat.value, &elem_type, headers))
+            }
+            _ => false,
+        },
+
         AppliedTag {
             tag_name,
             arguments,
@@ -207,6 +234,26 @@ pub fn constrain_pattern(
             );
         }
 
+        As(subpattern, symbol) => {
+            // the name binds the whole matched value, so it has the same type as the pattern
+            state.headers.insert(
+                symbol.value,
+                Loc {
+                    region: symbol.region,
+                    value: expected.get_type_ref().clone(),
+                },
+            );
+
+            constrain_pattern(
+                constraints,
+                env,
+                &subpattern.value,
+                subpattern.region,
+                expected,
+                state,
+            );
+        }
+
         AbilityMemberSpecialization {
             ident: symbol,
             specializes: _,
@@ -336,7 +383,15 @@ pub fn constrain_pattern(
         } => {
             state.vars.push(*whole_var);
             state.vars.push(*ext_var);
-            let ext_type = Type::Variable(*ext_var);
+
+            // A record pattern matches records with more fields than it mentions, but a tuple
+            // pattern only matches tuples with exactly as many elements as it has.
+            let labels = destructs.iter().map(|destruct| &destruct.value.label);
+            let (ext_type, category) = if tuple_arity(labels).is_some() {
+                (Type::EmptyRec, PatternCategory::Tuple)
+            } else {
+                (Type::Variable(*ext_var), PatternCategory::Record)
+            };
 
             let mut field_types: SendMap<Lowercase, RecordField<Type>> = SendMap::default();
 
@@ -439,13 +494,58 @@ pub fn constrain_pattern(
             let record_con = constraints.pattern_presence(
                 Type::Variable(*whole_var),
                 expected,
-                PatternCategory::Record,
+                category,
                 region,
             );
 
             state.constraints.push(whole_con);
             state.constraints.push(record_con);
         }
+        List {
+            list_var,
+            elem_var,
+            patterns: ListPatterns { patterns, opt_rest },
+        } => {
+            state.vars.push(*list_var);
+            state.vars.push(*elem_var);
+
+            let elem_type = Type::Variable(*elem_var);
+            let list_type = builtins::list_type(elem_type.clone());
+
+            if let Some((_, Some(rest))) = opt_rest {
+                state
+                    .headers
+                    .insert(rest.value, Loc::at(rest.region, list_type.clone()));
+            }
+
+            for loc_pat in patterns {
+                constrain_pattern(
+                    constraints,
+                    env,
+                    &loc_pat.value,
+                    loc_pat.region,
+                    PExpected::NoExpectation(elem_type.clone()),
+                    state,
+                );
+            }
+
+            let whole_con = constraints.equal_types(
+                Type::Variable(*list_var),
+                Expected::NoExpectation(list_type),
+                Category::Storage(std::file!(), std::line!()),
+                region,
+            );
+
+            let list_con = constraints.pattern_presence(
+                Type::Variable(*list_var),
+                expected,
+                PatternCategory::List,
+                region,
+            );
+
+            state.constraints.push(whole_con);
+            state.constraints.push(list_con);
+        }
         AppliedTag {
             whole_var,
             ext_var,
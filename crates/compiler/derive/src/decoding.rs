@@ -1,7 +1,7 @@
 //! Derivers for the `Decoding` ability.
 
 use roc_can::expr::{
-    AnnotatedMark, ClosureData, Expr, Field, Recursive, WhenBranch, WhenBranchPattern,
+    AnnotatedMark, ClosureData, Expr, Field, IntValue, Recursive, WhenBranch, WhenBranchPattern,
 };
 use roc_can::pattern::Pattern;
 use roc_collections::SendMap;
@@ -11,6 +11,7 @@ use roc_module::called_via::CalledVia;
 use roc_module::ident::Lowercase;
 use roc_module::symbol::Symbol;
 use roc_region::all::{Loc, Region};
+use roc_types::num::{IntBound, IntLitWidth};
 use roc_types::subs::{
     Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
     RedundantMark, SubsSlice, UnionLambdas, UnionTags, Variable,
@@ -28,6 +29,7 @@ pub(crate) fn derive_decoder(
     let (body, body_type) = match key {
         FlatDecodableKey::List() => decoder_list(env, def_symbol),
         FlatDecodableKey::Record(fields) => decoder_record(env, def_symbol, fields),
+        FlatDecodableKey::Tuple(arity) => decoder_tuple(env, def_symbol, arity),
     };
 
     let specialization_lambda_sets =
@@ -75,6 +77,108 @@ pub(crate) fn derive_decoder(
 //
 //     Decode.custom \bytes, fmt -> Decode.decodeWith bytes (Decode.record initialState stepField finalizer) fmt
 fn decoder_record(env: &mut Env, _def_symbol: Symbol, fields: Vec<Lowercase>) -> (Expr, Variable) {
+    decoder_record_or_tuple(env, fields, Stepping::Record)
+}
+
+// Implements decoding of a tuple, whose elements are the fields "0", "1" and so on of a record.
+// For example, for
+//
+//   (a, b)
+//
+// we'd like to generate an impl like
+//
+// decoder : Decoder (a, b) fmt | a has Decoding, b has Decoding, fmt has DecoderFormatting
+// decoder =
+//     initialState : {0: Result a [NoField], 1: Result b [NoField]}
+//     initialState = {0: Err NoField, 1: Err NoField}
+//
+//     stepElem = \state, index ->
+//         when index is
+//             0 ->
+//                 Next (Decode.custom \bytes, fmt ->
+//                     when Decode.decodeWith bytes Decode.decoder fmt is
+//                         {result, rest} ->
+//                             {result: Result.map result \val -> {state & 0: Ok val}, rest})
+//             1 ->
+//                 Next (Decode.custom \bytes, fmt ->
+//                     when Decode.decodeWith bytes Decode.decoder fmt is
+//                         {result, rest} ->
+//                             {result: Result.map result \val -> {state & 1: Ok val}, rest})
+//             _ -> TooLong
+//
+//     finalizer = \{0: e0, 1: e1} ->
+//         when e0 is
+//             Ok e0 ->
+//                 when e1 is
+//                     Ok e1 -> Ok (e0, e1)
+//                     Err NoField -> Err TooShort
+//             Err NoField -> Err TooShort
+//
+//     Decode.custom \bytes, fmt -> Decode.decodeWith bytes (Decode.tuple initialState stepElem finalizer) fmt
+fn decoder_tuple(env: &mut Env, _def_symbol: Symbol, arity: usize) -> (Expr, Variable) {
+    let elems = (0..arity).map(|index| index.to_string().into()).collect();
+
+    decoder_record_or_tuple(env, elems, Stepping::Tuple)
+}
+
+/// How the decoding state of a record or tuple steps from one of its fields to the next.
+#[derive(Clone, Copy)]
+enum Stepping {
+    /// By the name of the field, with `Decode.record`
+    Record,
+    /// By the position of the element, with `Decode.tuple`
+    Tuple,
+}
+
+impl Stepping {
+    fn decode_symbol(self) -> Symbol {
+        match self {
+            Stepping::Record => Symbol::DECODE_RECORD,
+            Stepping::Tuple => Symbol::DECODE_TUPLE,
+        }
+    }
+
+    /// The type of what the step function gets to pick a field with
+    fn field_var(self) -> Variable {
+        match self {
+            Stepping::Record => Variable::STR,
+            Stepping::Tuple => Variable::NAT,
+        }
+    }
+
+    /// The tags for decoding a field, and for a field that isn't decoded
+    fn tag_names(self) -> (&'static str, &'static str) {
+        match self {
+            Stepping::Record => ("Keep", "Skip"),
+            Stepping::Tuple => ("Next", "TooLong"),
+        }
+    }
+
+    fn field_pattern(self, field_name: Lowercase) -> Pattern {
+        match self {
+            Stepping::Record => Pattern::StrLiteral(field_name.into()),
+            Stepping::Tuple => {
+                let index = field_name
+                    .as_tuple_elem()
+                    .expect("the field of a tuple is a position");
+
+                Pattern::IntLiteral(
+                    Variable::NAT,
+                    Variable::NATURAL,
+                    field_name.as_str().into(),
+                    IntValue::I128((index as i128).to_ne_bytes()),
+                    IntBound::Exact(IntLitWidth::Nat),
+                )
+            }
+        }
+    }
+}
+
+fn decoder_record_or_tuple(
+    env: &mut Env,
+    fields: Vec<Lowercase>,
+    stepping: Stepping,
+) -> (Expr, Variable) {
     // The decoded type of each field in the record, e.g. {first: a, second: b}.
     let mut field_vars = Vec::with_capacity(fields.len());
     // The type of each field in the decoding state, e.g. {first: Result a [NoField], second: Result b [NoField]}
@@ -101,12 +205,13 @@ fn decoder_record(env: &mut Env, _def_symbol: Symbol, fields: Vec<Lowercase>) ->
         &result_field_vars,
         initial_state_var,
         decode_err_var,
+        stepping,
     );
 
-    // Build up the type of `Decode.record` we expect
+    // Build up the type of `Decode.record` (or `Decode.tuple`) we expect
     let record_decoder_var = env.subs.fresh_unnamed_flex_var();
     let decode_record_lambda_set = env.subs.fresh_unnamed_flex_var();
-    let decode_record_var = env.import_builtin_symbol_var(Symbol::DECODE_RECORD);
+    let decode_record_var = env.import_builtin_symbol_var(stepping.decode_symbol());
     let this_decode_record_var = {
         let flat_type = FlatType::Func(
             SubsSlice::insert_into_subs(env.subs, [initial_state_var, step_var, finalizer_var]),
@@ -124,7 +229,7 @@ fn decoder_record(env: &mut Env, _def_symbol: Symbol, fields: Vec<Lowercase>) ->
         Box::new((
             this_decode_record_var,
             Loc::at_zero(Expr::AbilityMember(
-                Symbol::DECODE_RECORD,
+                stepping.decode_symbol(),
                 None,
                 this_decode_record_var,
             )),
@@ -193,9 +298,12 @@ fn decoder_record_step_field(
     result_field_vars: &[Variable],
     state_record_var: Variable,
     decode_err_var: Variable,
+    stepping: Stepping,
 ) -> (Expr, Variable) {
     let state_arg_symbol = env.new_symbol("stateRecord");
     let field_arg_symbol = env.new_symbol("field");
+    let field_arg_var = stepping.field_var();
+    let (keep_tag, skip_tag) = stepping.tag_names();
 
     // +1 because of the default branch.
     let mut branches = Vec::with_capacity(fields.len() + 1);
@@ -206,8 +314,8 @@ fn decoder_record_step_field(
             UnionTags::insert_slices_into_subs(
                 env.subs,
                 [
-                    ("Keep".into(), keep_payload_subs_slice),
-                    ("Skip".into(), Default::default()),
+                    (keep_tag.into(), keep_payload_subs_slice),
+                    (skip_tag.into(), Default::default()),
                 ],
             ),
             Variable::EMPTY_TAG_UNION,
@@ -625,7 +733,7 @@ fn decoder_record_step_field(
             Expr::Tag {
                 tag_union_var: keep_or_skip_var,
                 ext_var: env.new_ext_var(ExtensionKind::TagUnion),
-                name: "Keep".into(),
+                name: keep_tag.into(),
                 arguments: vec![(decode_custom_ret_var, Loc::at_zero(decode_custom))],
             }
         };
@@ -644,7 +752,7 @@ fn decoder_record_step_field(
             //     )
             WhenBranch {
                 patterns: vec![WhenBranchPattern {
-                    pattern: Loc::at_zero(Pattern::StrLiteral(field_name.into())),
+                    pattern: Loc::at_zero(stepping.field_pattern(field_name)),
                     degenerate: false,
                 }],
                 value: Loc::at_zero(keep),
@@ -665,7 +773,7 @@ fn decoder_record_step_field(
         value: Loc::at_zero(Expr::Tag {
             tag_union_var: keep_or_skip_var,
             ext_var: env.new_ext_var(ExtensionKind::TagUnion),
-            name: "Skip".into(),
+            name: skip_tag.into(),
             arguments: Vec::new(),
         }),
         guard: None,
@@ -677,11 +785,11 @@ fn decoder_record_step_field(
     // when field is
     let body = Expr::When {
         loc_cond: Box::new(Loc::at_zero(Expr::Var(field_arg_symbol))),
-        cond_var: Variable::STR,
+        cond_var: field_arg_var,
         expr_var: keep_or_skip_var,
         region: Region::zero(),
         branches,
-        branches_cond_var: Variable::STR,
+        branches_cond_var: field_arg_var,
         exhaustive: ExhaustiveMark::known_exhaustive(),
     };
 
@@ -699,7 +807,7 @@ fn decoder_record_step_field(
     };
 
     {
-        let args_slice = SubsSlice::insert_into_subs(env.subs, [state_record_var, Variable::STR]);
+        let args_slice = SubsSlice::insert_into_subs(env.subs, [state_record_var, field_arg_var]);
 
         env.subs.set_content(
             function_type,
@@ -721,7 +829,7 @@ fn decoder_record_step_field(
                 Loc::at_zero(Pattern::Identifier(state_arg_symbol)),
             ),
             (
-                Variable::STR,
+                field_arg_var,
                 AnnotatedMark::known_exhaustive(),
                 Loc::at_zero(Pattern::Identifier(field_arg_symbol)),
             ),
@@ -760,7 +868,10 @@ fn decoder_record_finalizer(
     };
 
     for (field_name, &field_var) in fields.iter().zip(field_vars.iter()) {
-        let symbol = env.new_symbol(field_name.as_str());
+        let symbol = match field_name.as_tuple_elem() {
+            Some(index) => env.new_symbol(&format!("e{}", index)),
+            None => env.new_symbol(field_name.as_str()),
+        };
 
         pattern_symbols.push(symbol);
 
@@ -1,8 +1,11 @@
-use roc_module::{ident::Lowercase, symbol::Symbol};
+use roc_module::{
+    ident::{tuple_arity, Lowercase},
+    symbol::Symbol,
+};
 use roc_types::subs::{Content, FlatType, Subs, Variable};
 
 use crate::{
-    util::{check_derivable_ext_var, debug_name_record},
+    util::{check_derivable_ext_var, debug_name_record, debug_name_tuple},
     DeriveError,
 };
 
@@ -18,6 +21,8 @@ pub enum FlatDecodableKey {
 
     // Unfortunate that we must allocate here, c'est la vie
     Record(Vec<Lowercase>),
+    /// A tuple, by its number of elements
+    Tuple(usize),
 }
 
 impl FlatDecodableKey {
@@ -25,6 +30,7 @@ impl FlatDecodableKey {
         match self {
             FlatDecodableKey::List() => "list".to_string(),
             FlatDecodableKey::Record(fields) => debug_name_record(fields),
+            FlatDecodableKey::Tuple(arity) => debug_name_tuple(*arity),
         }
     }
 }
@@ -57,6 +63,10 @@ impl FlatDecodable {
                         field_names.push(field_name.clone());
                     }
 
+                    if let Some(arity) = tuple_arity(&field_names) {
+                        return Ok(Key(FlatDecodableKey::Tuple(arity)));
+                    }
+
                     field_names.sort();
 
                     Ok(Key(FlatDecodableKey::Record(field_names)))
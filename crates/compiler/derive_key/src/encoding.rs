@@ -1,11 +1,11 @@
 use roc_module::{
-    ident::{Lowercase, TagName},
+    ident::{tuple_arity, Lowercase, TagName},
     symbol::Symbol,
 };
 use roc_types::subs::{Content, FlatType, Subs, Variable};
 
 use crate::{
-    util::{check_derivable_ext_var, debug_name_record},
+    util::{check_derivable_ext_var, debug_name_record, debug_name_tuple},
     DeriveError,
 };
 
@@ -22,6 +22,8 @@ pub enum FlatEncodableKey {
     Dict(/* takes two variables */),
     // Unfortunate that we must allocate here, c'est la vie
     Record(Vec<Lowercase>),
+    /// A tuple, by its number of elements
+    Tuple(usize),
     TagUnion(Vec<(TagName, u16)>),
 }
 
@@ -32,6 +34,7 @@ impl FlatEncodableKey {
             FlatEncodableKey::Set() => "set".to_string(),
             FlatEncodableKey::Dict() => "dict".to_string(),
             FlatEncodableKey::Record(fields) => debug_name_record(fields),
+            FlatEncodableKey::Tuple(arity) => debug_name_tuple(*arity),
             FlatEncodableKey::TagUnion(tags) => {
                 let mut str = String::from('[');
                 tags.iter().enumerate().for_each(|(i, (tag, arity))| {
@@ -74,6 +77,10 @@ impl FlatEncodable {
                         field_names.push(field_name.clone());
                     }
 
+                    if let Some(arity) = tuple_arity(&field_names) {
+                        return Ok(Key(FlatEncodableKey::Tuple(arity)));
+                    }
+
                     field_names.sort();
 
                     Ok(Key(FlatEncodableKey::Record(field_names)))
@@ -42,3 +42,7 @@ pub(crate) fn debug_name_record(fields: &[Lowercase]) -> String {
     str.push('}');
     str
 }
+
+pub(crate) fn debug_name_tuple(arity: usize) -> String {
+    format!("(arity:{})", arity)
+}
@@ -69,6 +69,39 @@ pub enum Pattern {
     Anything,
     Literal(Literal),
     Ctor(Union, TagId, std::vec::Vec<Pattern>),
+    /// A list pattern; the patterns are the elements before and after the rest, in order
+    List(ListArity, std::vec::Vec<Pattern>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ListArity {
+    /// A list of exactly this many elements, e.g. `[a, b]`
+    Exact(usize),
+    /// A list with this many elements before and after a rest pattern, e.g. `[a, .., b]`
+    Slice(usize, usize),
+}
+
+impl ListArity {
+    /// The number of element patterns in a list pattern of this arity
+    pub fn min_len(&self) -> usize {
+        match self {
+            ListArity::Exact(n) => *n,
+            ListArity::Slice(before, after) => before + after,
+        }
+    }
+
+    /// Does a list pattern of this arity match all lists of the shape `ctor`?
+    /// Only meaningful for the list shapes built by [list_ctors_at_head].
+    fn covers(&self, ctor: &ListArity) -> bool {
+        match (self, ctor) {
+            (ListArity::Exact(n), ListArity::Exact(m)) => n == m,
+            (ListArity::Exact(_), ListArity::Slice(..)) => false,
+            (ListArity::Slice(before, after), ListArity::Exact(m)) => before + after <= *m,
+            (ListArity::Slice(before, after), ListArity::Slice(other_before, other_after)) => {
+                before <= other_before && after <= other_after
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -140,6 +173,31 @@ fn is_exhaustive(matrix: &RefPatternMatrix, n: usize) -> PatternMatrix {
         vec![std::iter::repeat(Anything).take(n).collect()]
     } else if n == 0 {
         vec![]
+    } else if let Some(list_ctors) = list_ctors_at_head(matrix, None) {
+        let is_list_ctor_exhaustive = |arity: ListArity| {
+            let new_matrix: Vec<_> = matrix
+                .iter()
+                .filter_map(|r| specialize_row_by_list(arity, r))
+                .collect();
+            let rest: Vec<Vec<Pattern>> = is_exhaustive(&new_matrix, arity.min_len() + n - 1);
+
+            let mut result = Vec::with_capacity(rest.len());
+            for mut row in rest {
+                let rest_of_row = row.split_off(arity.min_len());
+                let elements = row;
+
+                let mut recovered = rest_of_row;
+                recovered.push(List(arity, elements));
+                result.push(recovered);
+            }
+
+            result
+        };
+
+        list_ctors
+            .into_iter()
+            .flat_map(is_list_ctor_exhaustive)
+            .collect()
     } else {
         let ctors = collect_ctors(matrix);
         let num_seen = ctors.len();
@@ -255,6 +313,32 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                 // NOTE: if there are bugs in this code, look at the ordering of the row/matrix
 
                 match first_pattern {
+                    List(arity, args) => {
+                        // A list pattern can match lists of many lengths, so check whether it
+                        // is useful for any of the list shapes the other rows distinguish.
+                        let list_ctors = list_ctors_at_head(&old_matrix, Some(arity))
+                            .expect("the vector has a list pattern");
+
+                        for list_ctor in list_ctors {
+                            if !arity.covers(&list_ctor) {
+                                continue;
+                            }
+
+                            let mut old_matrix = old_matrix.clone();
+                            let mut matrix = vec![];
+                            specialize_matrix_by_list(list_ctor, &mut old_matrix, &mut matrix);
+
+                            let mut vector = vector.clone();
+                            vector.extend(list_elements_at_arity(arity, args.clone(), list_ctor));
+
+                            if is_useful(matrix, vector) {
+                                break 'outer true;
+                            }
+                        }
+
+                        break false;
+                    }
+
                     // keep checking rows that start with this Ctor or Anything
                     Ctor(_, id, args) => {
                         specialize_row_by_ctor2(id, args.len(), &mut old_matrix, &mut matrix);
@@ -265,6 +349,25 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                     }
 
                     Anything => {
+                        if let Some(list_ctors) = list_ctors_at_head(&old_matrix, None) {
+                            // Lists of all shapes are covered only if each of them is
+                            for list_ctor in list_ctors {
+                                let mut old_matrix = old_matrix.clone();
+                                let mut matrix = vec![];
+                                specialize_matrix_by_list(list_ctor, &mut old_matrix, &mut matrix);
+
+                                let mut vector = vector.clone();
+                                vector
+                                    .extend(std::iter::repeat(Anything).take(list_ctor.min_len()));
+
+                                if is_useful(matrix, vector) {
+                                    break 'outer true;
+                                }
+                            }
+
+                            break false;
+                        }
+
                         // check if all alternatives appear in matrix
                         match is_complete(&old_matrix) {
                             Complete::No => {
@@ -325,7 +428,7 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                                 }
                                 Some(Anything) => matrix.push(patterns),
 
-                                Some(Ctor(_, _, _)) => panic!(
+                                Some(Ctor(_, _, _) | List(..)) => panic!(
                                     r#"Compiler bug! After type checking, constructors and literals should never align in pattern match exhaustiveness checks."#
                                 ),
 
@@ -366,7 +469,7 @@ fn specialize_row_by_ctor2(
             patterns.extend(std::iter::repeat(Anything).take(arity));
             matrix.push(patterns);
             }
-        Some(Literal(_)) => panic!( "Compiler bug! After type checking, constructors and literal should never align in pattern match exhaustiveness checks."),
+        Some(Literal(_) | List(..)) => panic!( "Compiler bug! After type checking, constructors and literal should never align in pattern match exhaustiveness checks."),
         None => panic!("Compiler error! Empty matrices should not get specialized."),
     }
     }
@@ -399,7 +502,7 @@ fn specialize_row_by_ctor(tag_id: TagId, arity: usize, row: &RefRow) -> Option<R
                 .collect();
             Some(new_patterns)
         }
-        Some(Literal(_)) => unreachable!(
+        Some(Literal(_) | List(..)) => unreachable!(
             r#"Compiler bug! After type checking, a constructor can never align with a literal: that should be a type error!"#
         ),
         None => panic!("Compiler error! Empty matrices should not get specialized."),
@@ -458,3 +561,135 @@ fn collect_ctors(matrix: &RefPatternMatrix) -> MutMap<TagId, Union> {
 
     ctors
 }
+
+/// LIST PATTERNS
+///
+/// Lists don't have a fixed set of constructors, so we make one up from the list patterns that
+/// appear in a column: lists of each length up to some `n`, and the lists of length `n` or more.
+/// Every list pattern in the column either matches all lists of one of these shapes, or none
+/// of them, so they can be treated like the constructors of a union.
+
+/// The list shapes distinguished by the list patterns at the head of the rows, if there are any,
+/// together with `extra_arity` (the arity of a list pattern not in the matrix).
+fn list_ctors_at_head(
+    matrix: &RefPatternMatrix,
+    extra_arity: Option<ListArity>,
+) -> Option<Vec<ListArity>> {
+    let mut arities = matrix
+        .iter()
+        .filter_map(|row| match row.last() {
+            Some(List(arity, _)) => Some(*arity),
+            _ => None,
+        })
+        .chain(extra_arity)
+        .peekable();
+
+    arities.peek()?;
+
+    let mut max_exact_len = None;
+    let mut max_before = 0;
+    let mut max_after = 0;
+
+    for arity in arities {
+        match arity {
+            ListArity::Exact(n) => max_exact_len = max_exact_len.max(Some(n)),
+            ListArity::Slice(before, after) => {
+                max_before = max_before.max(before);
+                max_after = max_after.max(after);
+            }
+        }
+    }
+
+    // Lists longer than every exact-length pattern, and long enough to hold the elements before
+    // and after any rest pattern, are all matched the same way.
+    let min_open_len = max_exact_len
+        .map_or(0, |n| n + 1)
+        .max(max_before + max_after);
+
+    let ctors = (0..min_open_len)
+        .map(ListArity::Exact)
+        .chain(std::iter::once(ListArity::Slice(
+            min_open_len - max_after,
+            max_after,
+        )))
+        .collect();
+
+    Some(ctors)
+}
+
+/// The element patterns that a list pattern of `arity` uses to match lists of the shape `ctor`,
+/// which `arity` must cover.
+fn list_elements_at_arity(arity: ListArity, mut elements: Vec<Pattern>, ctor: ListArity) -> Row {
+    debug_assert!(arity.covers(&ctor));
+
+    match arity {
+        ListArity::Exact(_) => elements,
+        ListArity::Slice(before, _) => {
+            let after = elements.split_off(before);
+            let num_wildcards = ctor.min_len() - arity.min_len();
+
+            elements.extend(std::iter::repeat(Anything).take(num_wildcards));
+            elements.extend(after);
+
+            elements
+        }
+    }
+}
+
+/// INVARIANT: (length row == N) ==> (length result == ctor.min_len() + N - 1)
+fn specialize_row_by_list(ctor: ListArity, row: &RefRow) -> Option<Row> {
+    let mut row = row.to_vec();
+
+    let head = row.pop();
+    let patterns = row;
+
+    match head {
+        Some(List(arity, elements)) => {
+            if arity.covers(&ctor) {
+                let mut new_patterns = list_elements_at_arity(arity, elements, ctor);
+                new_patterns.extend(patterns);
+                Some(new_patterns)
+            } else {
+                None
+            }
+        }
+        Some(Anything) => {
+            let new_patterns = std::iter::repeat(Anything)
+                .take(ctor.min_len())
+                .chain(patterns)
+                .collect();
+            Some(new_patterns)
+        }
+        Some(Literal(_) | Ctor(..)) => unreachable!(
+            r#"Compiler bug! After type checking, a list can never align with a literal or constructor: that should be a type error!"#
+        ),
+        None => panic!("Compiler error! Empty matrices should not get specialized."),
+    }
+}
+
+/// INVARIANT: (length row == N) ==> (length result == ctor.min_len() + N - 1)
+fn specialize_matrix_by_list(
+    ctor: ListArity,
+    old_matrix: &mut PatternMatrix,
+    matrix: &mut PatternMatrix,
+) {
+    for mut row in old_matrix.drain(..) {
+        let head = row.pop();
+        let mut patterns = row;
+
+        match head {
+            Some(List(arity, elements)) => {
+                if arity.covers(&ctor) {
+                    patterns.extend(list_elements_at_arity(arity, elements, ctor));
+                    matrix.push(patterns);
+                }
+            }
+            Some(Anything) => {
+                patterns.extend(std::iter::repeat(Anything).take(ctor.min_len()));
+                matrix.push(patterns);
+            }
+            Some(Literal(_) | Ctor(..)) => panic!("Compiler bug! After type checking, a list can never align with a literal or constructor: that should be a type error!"),
+            None => panic!("Compiler error! Empty matrices should not get specialized."),
+        }
+    }
+}
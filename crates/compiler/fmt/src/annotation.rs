@@ -193,6 +193,8 @@ impl<'a> Formattable for TypeAnnotation<'a> {
 
                 tags.iter().any(|tag| tag.value.is_multiline())
             }
+
+            Tuple(elems) => elems.iter().any(|elem| elem.value.is_multiline()),
         }
     }
 
@@ -224,6 +226,7 @@ impl<'a> Formattable for TypeAnnotation<'a> {
 
                     if !is_first && !is_multiline && should_add_newlines {
                         buf.newline();
+                        buf.indent(indent);
                     }
 
                     (&argument.value).format_with_options(
@@ -307,6 +310,8 @@ impl<'a> Formattable for TypeAnnotation<'a> {
                 }
             }
 
+            Tuple(elems) => fmt_collection(buf, indent, Braces::Round, *elems, newlines),
+
             As(lhs, _spaces, TypeHeader { name, vars }) => {
                 // TODO use _spaces?
                 lhs.value
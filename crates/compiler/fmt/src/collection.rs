@@ -1,13 +1,14 @@
 use roc_parse::ast::{Collection, CommentOrNewline, ExtractSpaces};
 
 use crate::{
-    annotation::{Formattable, Newlines},
+    annotation::{Formattable, Newlines, Parens},
     spaces::{fmt_comments_only, NewlineAt, INDENT},
     Buf,
 };
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Braces {
+    Round,
     Square,
     Curly,
 }
@@ -22,15 +23,23 @@ pub fn fmt_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable>(
     <T as ExtractSpaces<'a>>::Item: Formattable,
 {
     let start = match braces {
+        Braces::Round => '(',
         Braces::Curly => '{',
         Braces::Square => '[',
     };
 
     let end = match braces {
+        Braces::Round => ')',
         Braces::Curly => '}',
         Braces::Square => ']',
     };
 
+    // The elements of a tuple are separated by commas, so a function type in one needs parens
+    let item_parens = match braces {
+        Braces::Round => Parens::InFunctionType,
+        Braces::Square | Braces::Curly => Parens::NotNeeded,
+    };
+
     if items.is_multiline() {
         let braces_indent = indent;
         let item_indent = braces_indent + INDENT;
@@ -83,7 +92,8 @@ pub fn fmt_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable>(
             }
 
             buf.indent(item_indent);
-            item.item.format(buf, item_indent);
+            item.item
+                .format_with_options(buf, item_parens, Newlines::No, item_indent);
 
             buf.push(',');
 
@@ -127,7 +137,7 @@ pub fn fmt_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable>(
                 buf.spaces(1);
             }
 
-            item.format(buf, indent);
+            item.format_with_options(buf, item_parens, Newlines::No, indent);
             if iter.peek().is_some() {
                 buf.push(',');
             }
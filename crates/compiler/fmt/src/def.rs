@@ -46,6 +46,7 @@ impl<'a> Formattable for TypeDef<'a> {
             Alias { ann, .. } => ann.is_multiline(),
             Opaque { typ, .. } => typ.is_multiline(),
             Ability { members, .. } => members.iter().any(|d| d.is_multiline()),
+            BuiltinImpls { has_abilities, .. } => has_abilities.is_multiline(),
         }
     }
 
@@ -118,9 +119,26 @@ impl<'a> Formattable for TypeDef<'a> {
                     );
                 }
             }
+            BuiltinImpls {
+                header: TypeHeader { name, vars },
+                has_abilities,
+            } => {
+                buf.indent(indent);
+                buf.push_str(name.value);
+
+                for var in *vars {
+                    buf.spaces(1);
+                    fmt_pattern(buf, &var.value, indent, Parens::NotNeeded);
+                }
+
+                buf.spaces(1);
+
+                has_abilities.format_with_options(buf, Parens::NotNeeded, Newlines::No, indent);
+            }
             Ability {
                 header: TypeHeader { name, vars },
                 loc_has: _,
+                required_abilities,
                 members,
             } => {
                 buf.indent(indent);
@@ -132,7 +150,15 @@ impl<'a> Formattable for TypeDef<'a> {
 
                 buf.push_str(" has");
 
-                if !self.is_multiline() {
+                for (index, required) in required_abilities.iter().enumerate() {
+                    if index > 0 {
+                        buf.push(',');
+                    }
+                    buf.spaces(1);
+                    required.value.extract_spaces().item.format(buf, indent);
+                }
+
+                if !self.is_multiline() && required_abilities.is_empty() {
                     debug_assert_eq!(members.len(), 1);
                     buf.push_str(" ");
                     members[0].format(buf, indent + INDENT);
@@ -274,6 +300,7 @@ impl<'a> Formattable for ValueDef<'a> {
                 }
 
                 if let Some(comment_str) = comment {
+                    buf.note_comment();
                     buf.push_str(" #");
                     buf.spaces(1);
                     buf.push_str(comment_str.trim());
@@ -405,14 +432,22 @@ pub fn fmt_body<'a, 'buf>(
 
 impl<'a> Formattable for AbilityMember<'a> {
     fn is_multiline(&self) -> bool {
-        self.name.value.is_multiline() || self.typ.is_multiline()
+        self.name.value.is_multiline() || self.typ.is_multiline() || self.default.is_some()
     }
 
     fn format<'buf>(&self, buf: &mut Buf<'buf>, indent: u16) {
-        buf.push_str(self.name.value.extract_spaces().item);
+        let name = self.name.value.extract_spaces().item;
+
+        buf.push_str(name);
         buf.spaces(1);
         buf.push(':');
         buf.spaces(1);
         self.typ.value.format(buf, indent + INDENT);
+
+        if let Some(default) = &self.default {
+            buf.newline();
+            buf.indent(indent);
+            fmt_body(buf, &Pattern::Identifier(name), &default.body.value, indent);
+        }
     }
 }
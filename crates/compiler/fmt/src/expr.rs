@@ -38,6 +38,7 @@ impl<'a> Formattable for Expr<'a> {
             | AccessorFunction(_)
             | Var { .. }
             | Underscore { .. }
+            | Crash
             | MalformedIdent(_, _)
             | MalformedClosure
             | Tag(_)
@@ -46,7 +47,7 @@ impl<'a> Formattable for Expr<'a> {
             // These expressions always have newlines
             Defs(_, _) | When(_, _) => true,
 
-            List(items) => items.iter().any(|loc_expr| loc_expr.is_multiline()),
+            List(items) | Tuple(items) => items.iter().any(|loc_expr| loc_expr.is_multiline()),
 
             Str(literal) => {
                 use roc_parse::ast::StrLiteral::*;
@@ -66,7 +67,7 @@ impl<'a> Formattable for Expr<'a> {
                 loc_expr.is_multiline() || args.iter().any(|loc_arg| loc_arg.is_multiline())
             }
 
-            Expect(condition, continuation) => {
+            Expect(condition, continuation) | Dbg(condition, continuation) => {
                 condition.is_multiline() || continuation.is_multiline()
             }
 
@@ -187,6 +188,10 @@ impl<'a> Formattable for Expr<'a> {
                 buf.push('_');
                 buf.push_str(name);
             }
+            Crash => {
+                buf.indent(indent);
+                buf.push_str("crash");
+            }
             Apply(loc_expr, loc_args, _) => {
                 buf.indent(indent);
                 if apply_needs_parens && !loc_args.is_empty() {
@@ -367,11 +372,21 @@ impl<'a> Formattable for Expr<'a> {
             Expect(condition, continuation) => {
                 fmt_expect(buf, condition, continuation, self.is_multiline(), indent);
             }
+            Dbg(condition, continuation) => {
+                fmt_dbg(
+                    buf,
+                    condition,
+                    continuation,
+                    condition.is_multiline(),
+                    indent,
+                );
+            }
             If(branches, final_else) => {
                 fmt_if(buf, branches, final_else, self.is_multiline(), indent);
             }
             When(loc_condition, branches) => fmt_when(buf, loc_condition, branches, indent),
             List(items) => fmt_collection(buf, indent, Braces::Square, *items, Newlines::No),
+            Tuple(items) => fmt_collection(buf, indent, Braces::Round, *items, Newlines::No),
             BinOps(lefts, right) => fmt_binops(buf, lefts, right, false, parens, indent),
             UnaryOp(sub_expr, unary_op) => {
                 buf.indent(indent);
@@ -839,6 +854,32 @@ fn fmt_expect<'a, 'buf>(
     continuation.format(buf, indent);
 }
 
+fn fmt_dbg<'a, 'buf>(
+    buf: &mut Buf<'buf>,
+    condition: &'a Loc<Expr<'a>>,
+    continuation: &'a Loc<Expr<'a>>,
+    is_multiline: bool,
+    indent: u16,
+) {
+    buf.indent(indent);
+    buf.push_str("dbg");
+
+    let return_indent = if is_multiline {
+        buf.newline();
+        indent + INDENT
+    } else {
+        buf.spaces(1);
+        indent
+    };
+
+    condition.format(buf, return_indent);
+
+    // unlike `expect`, a `dbg` usually sits right above the code it is debugging
+    buf.ensure_ends_with_newline();
+
+    continuation.format(buf, indent);
+}
+
 fn fmt_if<'a, 'buf>(
     buf: &mut Buf<'buf>,
     branches: &'a [(Loc<Expr<'a>>, Loc<Expr<'a>>)],
@@ -7,6 +7,7 @@ pub mod def;
 pub mod expr;
 pub mod module;
 pub mod pattern;
+pub mod reflow;
 pub mod spaces;
 
 use bumpalo::{collections::String, Bump};
@@ -23,6 +24,7 @@ pub struct Buf<'a> {
     text: String<'a>,
     spaces_to_flush: usize,
     beginning_of_line: bool,
+    wrote_comment: bool,
 }
 
 impl<'a> Buf<'a> {
@@ -31,6 +33,7 @@ impl<'a> Buf<'a> {
             text: String::new_in(arena),
             spaces_to_flush: 0,
             beginning_of_line: true,
+            wrote_comment: false,
         }
     }
 
@@ -134,6 +137,17 @@ impl<'a> Buf<'a> {
         fmt_text_eof(&mut self.text)
     }
 
+    /// Records that a comment was written. Code containing comments can't be
+    /// re-flowed freely, since that could separate a comment from what it describes.
+    pub(crate) fn note_comment(&mut self) {
+        self.wrote_comment = true;
+    }
+
+    /// Whether any comments have been written to this buffer.
+    pub fn wrote_comment(&self) -> bool {
+        self.wrote_comment
+    }
+
     pub fn ends_with_space(&self) -> bool {
         self.spaces_to_flush > 0 || self.text.ends_with(' ')
     }
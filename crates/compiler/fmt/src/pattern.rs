@@ -23,7 +23,16 @@ impl<'a> Formattable for Pattern<'a> {
                 spaces.iter().any(|s| s.is_comment())
             }
 
-            Pattern::RecordDestructure(fields) => fields.iter().any(|f| f.is_multiline()),
+            Pattern::RecordDestructure(fields) | Pattern::Tuple(fields) | Pattern::List(fields) => {
+                fields.iter().any(|f| f.is_multiline())
+            }
+            Pattern::ListRest(opt_rest) => match opt_rest {
+                Some((spaces, _)) => spaces.iter().any(|s| s.is_comment()),
+                None => false,
+            },
+            Pattern::As(pattern, pattern_as) => {
+                pattern.is_multiline() || pattern_as.spaces_before.iter().any(|s| s.is_comment())
+            }
             Pattern::RequiredField(_, subpattern) => subpattern.is_multiline(),
 
             Pattern::OptionalField(_, expr) => expr.is_multiline(),
@@ -104,6 +113,83 @@ impl<'a> Formattable for Pattern<'a> {
                 buf.push_str("}");
             }
 
+            Tuple(loc_patterns) => {
+                buf.indent(indent);
+                buf.push_str("(");
+
+                let mut it = loc_patterns.iter().peekable();
+                while let Some(loc_pattern) = it.next() {
+                    loc_pattern.format(buf, indent);
+
+                    if it.peek().is_some() {
+                        buf.push_str(",");
+                        buf.spaces(1);
+                    }
+                }
+
+                buf.push_str(")");
+            }
+
+            List(loc_patterns) => {
+                buf.indent(indent);
+                buf.push_str("[");
+
+                let mut it = loc_patterns.iter().peekable();
+                while let Some(loc_pattern) = it.next() {
+                    loc_pattern.format(buf, indent);
+
+                    if it.peek().is_some() {
+                        buf.push_str(",");
+                        buf.spaces(1);
+                    }
+                }
+
+                buf.push_str("]");
+            }
+
+            ListRest(opt_rest) => {
+                buf.indent(indent);
+                buf.push_str("..");
+
+                if let Some((list_rest_spaces, name)) = opt_rest {
+                    buf.spaces(1);
+                    fmt_comments_only(buf, list_rest_spaces.iter(), NewlineAt::Bottom, indent);
+                    buf.indent(indent);
+                    buf.push_str("as");
+                    buf.spaces(1);
+                    buf.push_str(name.value);
+                }
+            }
+
+            As(loc_pattern, pattern_as) => {
+                // e.g. `Foo (Bar x as y)`
+                let parens = parens == Parens::InApply;
+
+                if parens {
+                    buf.indent(indent);
+                    buf.push('(');
+                }
+
+                loc_pattern.format(buf, indent);
+
+                buf.spaces(1);
+                buf.indent(indent);
+                buf.push_str("as");
+                buf.spaces(1);
+                fmt_comments_only(
+                    buf,
+                    pattern_as.spaces_before.iter(),
+                    NewlineAt::Bottom,
+                    indent,
+                );
+                buf.indent(indent);
+                buf.push_str(pattern_as.identifier.value);
+
+                if parens {
+                    buf.push(')');
+                }
+            }
+
             RequiredField(name, loc_pattern) => {
                 buf.indent(indent);
                 buf.push_str(name);
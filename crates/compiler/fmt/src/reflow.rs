@@ -0,0 +1,1332 @@
+//! Width-aware layout.
+//!
+//! On its own, the formatter puts a function application, operator chain, collection or
+//! type annotation on several lines exactly when the source code did (see
+//! `Formattable::is_multiline`). Re-flowing a tree before formatting it makes that decision
+//! based on a maximum line width instead: each of those is put on one line if it fits,
+//! and is otherwise spread over several lines, by rewriting the line breaks in the tree.
+//!
+//! Anything containing a comment keeps its line breaks, since moving those around could
+//! separate a comment from the code it describes. Blank lines between definitions, and
+//! whether a definition's body starts on its own line, are also kept as they were.
+
+use std::cell::{Cell, RefCell};
+
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_collections::all::MutMap;
+use roc_parse::ast::{
+    AbilityMember, AbilityMemberDefault, AssignedField, Collection, CommentOrNewline, Defs, Expr,
+    ExtractSpaces, Pattern, StrLiteral, Tag, TypeAnnotation, TypeDef, ValueDef, WhenBranch,
+};
+use roc_region::all::Loc;
+
+use crate::annotation::{Formattable, Newlines, Parens};
+use crate::spaces::INDENT;
+use crate::Buf;
+
+const NEWLINE: &[CommentOrNewline<'static>] = &[CommentOrNewline::Newline];
+
+/// Re-flows top-level definitions so that, once formatted, their lines fit within
+/// `max_width` columns wherever possible.
+pub fn reflow_defs<'a>(arena: &'a Bump, defs: &Defs<'a>, max_width: u16) -> Defs<'a> {
+    Reflow::new(arena, max_width).defs(defs, 0)
+}
+
+/// Re-flows an expression, formatted starting at column 0, so that its lines fit within
+/// `max_width` columns wherever possible.
+pub fn reflow_expr<'a>(arena: &'a Bump, expr: &'a Expr<'a>, max_width: u16) -> Expr<'a> {
+    Reflow::new(arena, max_width).expr(expr, 0, 0)
+}
+
+/// An expression's address, whether it is re-flowed unbroken, its column and its indent.
+type LayoutKey<'a> = (*const Expr<'a>, bool, u16, u16);
+
+/// Column positions here are estimates of where the formatter will put things; `indent`
+/// is the indentation the formatter will use for the node being re-flowed.
+struct Reflow<'a> {
+    arena: &'a Bump,
+    max_width: u16,
+    /// Re-flowed expressions, by where they were re-flowed. Closures and `when` branches lay
+    /// out their body both on the line of the `->` and on the next one (as does `body` for a
+    /// multiline operator chain or `if`), so without these, the time spent on nested ones
+    /// would double with each level of nesting.
+    layouts: RefCell<MutMap<LayoutKey<'a>, Expr<'a>>>,
+    /// The width of each expression's flat version, or `None` if it never fits on one line.
+    flat_widths: RefCell<MutMap<*const Expr<'a>, Option<u16>>>,
+    /// Set while re-flowing the inside of a node known to have no comments.
+    comment_free: Cell<bool>,
+}
+
+impl<'a> Reflow<'a> {
+    fn new(arena: &'a Bump, max_width: u16) -> Self {
+        Reflow {
+            arena,
+            max_width,
+            layouts: RefCell::new(MutMap::default()),
+            flat_widths: RefCell::new(MutMap::default()),
+            comment_free: Cell::new(false),
+        }
+    }
+
+    fn defs(&self, defs: &Defs<'a>, indent: u16) -> Defs<'a> {
+        let mut defs = defs.clone();
+
+        for type_def in defs.type_defs.iter_mut() {
+            *type_def = self.type_def(type_def, indent);
+        }
+
+        for value_def in defs.value_defs.iter_mut() {
+            *value_def = self.value_def(value_def, indent);
+        }
+
+        defs
+    }
+
+    fn type_def(&self, type_def: &TypeDef<'a>, indent: u16) -> TypeDef<'a> {
+        use roc_parse::ast::TypeDef::*;
+
+        match *type_def {
+            Alias { header, ann } => {
+                let col =
+                    indent + self.header_width(header.name.value, header.vars) + " : ".len() as u16;
+
+                Alias {
+                    header,
+                    ann: self.loc_ann(self.arena.alloc(ann), col, indent),
+                }
+            }
+            Opaque {
+                header,
+                typ,
+                derived,
+            } => {
+                let col = indent
+                    + self.header_width(header.name.value, header.vars)
+                    + " := ".len() as u16;
+
+                Opaque {
+                    header,
+                    typ: self.loc_ann(self.arena.alloc(typ), col, indent),
+                    derived,
+                }
+            }
+            // Ability lists are short and don't need reflowing.
+            BuiltinImpls { .. } => *type_def,
+            Ability {
+                header,
+                loc_has,
+                required_abilities,
+                members,
+            } => {
+                let member_indent = indent + INDENT;
+                let members = self
+                    .arena
+                    .alloc_slice_fill_iter(members.iter().map(|member| {
+                        let name = member.name.value.extract_spaces().item;
+                        let col = member_indent + name.len() as u16 + " : ".len() as u16;
+
+                        let default = member.default.map(|default| {
+                            let pattern = self
+                                .arena
+                                .alloc(Loc::at(default.name.region, Pattern::Identifier(name)));
+
+                            AbilityMemberDefault {
+                                name: default.name,
+                                body: self.body(pattern, default.body, member_indent),
+                            }
+                        });
+
+                        AbilityMember {
+                            name: member.name,
+                            typ: self.loc_ann(&member.typ, col, member_indent),
+                            default,
+                        }
+                    }));
+
+                Ability {
+                    header,
+                    loc_has,
+                    required_abilities,
+                    members,
+                }
+            }
+        }
+    }
+
+    fn value_def(&self, value_def: &ValueDef<'a>, indent: u16) -> ValueDef<'a> {
+        use roc_parse::ast::ValueDef::*;
+
+        match *value_def {
+            Annotation(loc_pattern, loc_ann) => {
+                let col = indent + self.width(&loc_pattern.value) + " : ".len() as u16;
+
+                Annotation(
+                    loc_pattern,
+                    self.value_ann(self.arena.alloc(loc_ann), col, indent),
+                )
+            }
+            Body(loc_pattern, loc_expr) => {
+                Body(loc_pattern, self.body(loc_pattern, loc_expr, indent))
+            }
+            AnnotatedBody {
+                ann_pattern,
+                ann_type,
+                comment,
+                body_pattern,
+                body_expr,
+            } => {
+                let col = indent + self.width(&ann_pattern.value) + " : ".len() as u16;
+
+                AnnotatedBody {
+                    ann_pattern,
+                    ann_type: self.arena.alloc(self.value_ann(ann_type, col, indent)),
+                    comment,
+                    body_pattern,
+                    body_expr: self.body(body_pattern, body_expr, indent),
+                }
+            }
+            Expect {
+                condition,
+                preceding_comment,
+            } => Expect {
+                condition: self.unbroken_loc_expr(
+                    condition,
+                    indent + "expect ".len() as u16,
+                    indent,
+                ),
+                preceding_comment,
+            },
+            ExpectFx {
+                condition,
+                preceding_comment,
+            } => ExpectFx {
+                condition: self.unbroken_loc_expr(
+                    condition,
+                    indent + "expect-fx ".len() as u16,
+                    indent,
+                ),
+                preceding_comment,
+            },
+        }
+    }
+
+    fn body(
+        &self,
+        loc_pattern: &'a Loc<Pattern<'a>>,
+        loc_expr: &'a Loc<Expr<'a>>,
+        indent: u16,
+    ) -> &'a Loc<Expr<'a>> {
+        let body_indent = indent + INDENT;
+
+        if let Expr::SpaceBefore(..) = loc_expr.value {
+            // The body already begins on the line after the `=`
+            return self.loc_expr(loc_expr, body_indent, body_indent);
+        }
+
+        let col = indent + self.width(&loc_pattern.value) + " = ".len() as u16;
+        let reflowed = self.loc_expr(loc_expr, col, indent);
+
+        match reflowed.value {
+            // Multiline binop chains always begin on the line after the `=`
+            Expr::BinOps(..) if reflowed.is_multiline() => {
+                self.loc_expr(loc_expr, body_indent, body_indent)
+            }
+            // A multiline `if` has to begin on a line of its own, so that its `else` lines up
+            Expr::If(..) if reflowed.is_multiline() => {
+                let body = self.expr(&loc_expr.value, body_indent, body_indent);
+
+                self.arena.alloc(Loc::at(
+                    loc_expr.region,
+                    Expr::SpaceBefore(self.arena.alloc(body), NEWLINE),
+                ))
+            }
+            _ => reflowed,
+        }
+    }
+
+    fn loc_expr(&self, loc_expr: &'a Loc<Expr<'a>>, col: u16, indent: u16) -> &'a Loc<Expr<'a>> {
+        self.arena.alloc(Loc::at(
+            loc_expr.region,
+            self.expr(&loc_expr.value, col, indent),
+        ))
+    }
+
+    fn expr(&self, expr: &'a Expr<'a>, col: u16, indent: u16) -> Expr<'a> {
+        self.layout(expr, false, col, indent)
+    }
+
+    /// Re-flows an expression, or reuses how it was re-flowed at the same position before.
+    fn layout(&self, expr: &'a Expr<'a>, unbroken: bool, col: u16, indent: u16) -> Expr<'a> {
+        // Nothing fits once past the maximum width, however far past it that is
+        let col = col.min(self.max_width.saturating_add(1));
+        let key = (expr as *const Expr<'a>, unbroken, col, indent);
+
+        if let Some(layout) = self.layouts.borrow().get(&key) {
+            return *layout;
+        }
+
+        let layout = if unbroken {
+            self.reflow_unbroken_expr(expr, col, indent)
+        } else {
+            self.reflow_expr(expr, col, indent)
+        };
+
+        self.layouts.borrow_mut().insert(key, layout);
+
+        layout
+    }
+
+    fn reflow_expr(&self, expr: &'a Expr<'a>, col: u16, indent: u16) -> Expr<'a> {
+        use roc_parse::ast::Expr::*;
+
+        match *expr {
+            List(_)
+            | Tuple(_)
+            | Record(_)
+            | RecordUpdate { .. }
+            | Apply(..)
+            | BinOps(..)
+            | If(..) => {
+                if self.has_comments(expr) {
+                    return self.spread(expr, col, indent, false);
+                }
+
+                self.without_comments(|| {
+                    if self.flat_fits(expr, col) {
+                        self.flat_expr(expr)
+                    } else {
+                        self.spread(expr, col, indent, true)
+                    }
+                })
+            }
+            SpaceBefore(sub_expr, spaces) => {
+                let col = if spaces.iter().any(|space| space.is_newline()) {
+                    indent
+                } else {
+                    col
+                };
+
+                SpaceBefore(self.arena.alloc(self.expr(sub_expr, col, indent)), spaces)
+            }
+            SpaceAfter(sub_expr, spaces) => {
+                SpaceAfter(self.arena.alloc(self.expr(sub_expr, col, indent)), spaces)
+            }
+            ParensAround(sub_expr) => ParensAround(self.arena.alloc(self.unbroken_expr(
+                sub_expr,
+                col + 1,
+                indent,
+            ))),
+            Access(sub_expr, field) => Access(
+                self.arena.alloc(self.unbroken_expr(sub_expr, col, indent)),
+                field,
+            ),
+            UnaryOp(sub_expr, op) => UnaryOp(self.unbroken_loc_expr(sub_expr, col + 1, indent), op),
+            Closure(loc_patterns, loc_body) => self.closure(loc_patterns, loc_body, col, indent),
+            Defs(defs, final_expr) => Defs(
+                self.arena.alloc(self.defs(defs, indent)),
+                self.loc_expr(final_expr, indent, indent),
+            ),
+            Backpassing(loc_patterns, loc_call, loc_body) => {
+                let col = indent + self.patterns_width(loc_patterns, ", ") + " <- ".len() as u16;
+
+                Backpassing(
+                    loc_patterns,
+                    self.unbroken_loc_expr(loc_call, col, indent),
+                    self.loc_expr(loc_body, indent, indent),
+                )
+            }
+            Expect(condition, continuation) => Expect(
+                self.unbroken_loc_expr(condition, indent + "expect ".len() as u16, indent),
+                self.loc_expr(continuation, indent, indent),
+            ),
+            Dbg(condition, continuation) => Dbg(
+                self.unbroken_loc_expr(condition, indent + "dbg ".len() as u16, indent),
+                self.loc_expr(continuation, indent, indent),
+            ),
+            When(condition, branches) => {
+                let branches = self.arena.alloc_slice_fill_iter(
+                    branches
+                        .iter()
+                        .map(|branch| &*self.arena.alloc(self.when_branch(branch, indent))),
+                );
+
+                When(
+                    self.unbroken_loc_expr(condition, col + "when ".len() as u16, indent),
+                    branches,
+                )
+            }
+            Float(_)
+            | Num(_)
+            | NonBase10Int { .. }
+            | Str(_)
+            | AccessorFunction(_)
+            | SingleQuote(_)
+            | Var { .. }
+            | Underscore(_)
+            | Crash
+            | Tag(_)
+            | OpaqueRef(_)
+            | MalformedIdent(..)
+            | MalformedClosure
+            | PrecedenceConflict(_) => *expr,
+        }
+    }
+
+    /// Re-flows an expression in a position where the formatter can't break it over several
+    /// lines, like an operand, a `when` condition or the contents of parentheses. It is joined
+    /// onto one line if it fits, and otherwise keeps the line breaks it had.
+    fn unbroken_expr(&self, expr: &'a Expr<'a>, col: u16, indent: u16) -> Expr<'a> {
+        self.layout(expr, true, col, indent)
+    }
+
+    fn reflow_unbroken_expr(&self, expr: &'a Expr<'a>, col: u16, indent: u16) -> Expr<'a> {
+        use roc_parse::ast::Expr::*;
+
+        match *expr {
+            List(_)
+            | Tuple(_)
+            | Record(_)
+            | RecordUpdate { .. }
+            | Apply(..)
+            | BinOps(..)
+            | If(..) => {
+                if self.has_comments(expr) {
+                    return self.spread(expr, col, indent, false);
+                }
+
+                self.without_comments(|| {
+                    if self.flat_fits(expr, col) {
+                        self.flat_expr(expr)
+                    } else {
+                        self.spread(expr, col, indent, false)
+                    }
+                })
+            }
+            SpaceBefore(sub_expr, spaces) => SpaceBefore(
+                self.arena.alloc(self.unbroken_expr(sub_expr, col, indent)),
+                spaces,
+            ),
+            SpaceAfter(sub_expr, spaces) => SpaceAfter(
+                self.arena.alloc(self.unbroken_expr(sub_expr, col, indent)),
+                spaces,
+            ),
+            _ => self.expr(expr, col, indent),
+        }
+    }
+
+    fn unbroken_loc_expr(
+        &self,
+        loc_expr: &'a Loc<Expr<'a>>,
+        col: u16,
+        indent: u16,
+    ) -> &'a Loc<Expr<'a>> {
+        self.arena.alloc(Loc::at(
+            loc_expr.region,
+            self.unbroken_expr(&loc_expr.value, col, indent),
+        ))
+    }
+
+    fn when_branch(&self, branch: &'a WhenBranch<'a>, indent: u16) -> WhenBranch<'a> {
+        let col =
+            indent + INDENT + self.patterns_width(branch.patterns, " | ") + " -> ".len() as u16;
+        let value = &branch.value;
+
+        WhenBranch {
+            patterns: branch.patterns,
+            value: Loc::at(
+                value.region,
+                self.after_arrow(&value.value, col, indent + 2 * INDENT),
+            ),
+            guard: branch.guard,
+        }
+    }
+
+    fn closure(
+        &self,
+        loc_patterns: &'a [Loc<Pattern<'a>>],
+        loc_body: &'a Loc<Expr<'a>>,
+        col: u16,
+        indent: u16,
+    ) -> Expr<'a> {
+        let body_col =
+            col + "\\".len() as u16 + self.patterns_width(loc_patterns, ", ") + " -> ".len() as u16;
+        let body = self.after_arrow(&loc_body.value, body_col, indent + INDENT);
+
+        Expr::Closure(
+            loc_patterns,
+            self.arena.alloc(Loc::at(loc_body.region, body)),
+        )
+    }
+
+    /// What follows the `->` of a closure or `when` branch stays on that line if it fits there
+    /// in its entirety, and otherwise begins on the next line.
+    fn after_arrow(&self, expr: &'a Expr<'a>, col: u16, indent: u16) -> Expr<'a> {
+        if self.has_comments(expr) {
+            return self.expr(expr, col, indent);
+        }
+
+        self.without_comments(|| {
+            let expr = strip_spaces(expr);
+            let same_line = self.expr(expr, col, indent);
+
+            if same_line.is_multiline() {
+                let next_line = self.expr(expr, indent, indent);
+
+                Expr::SpaceBefore(self.arena.alloc(next_line), NEWLINE)
+            } else {
+                same_line
+            }
+        })
+    }
+
+    /// Lays out the parts of a function application, operator chain or collection.
+    /// With `break_lines`, each part gets its own line; otherwise the parts keep the line
+    /// breaks they had.
+    fn spread(&self, expr: &'a Expr<'a>, col: u16, indent: u16, break_lines: bool) -> Expr<'a> {
+        use roc_parse::ast::Expr::*;
+
+        let item_indent = indent + INDENT;
+
+        match *expr {
+            List(items) => {
+                let new_items = self.arena.alloc_slice_fill_iter(
+                    items
+                        .items
+                        .iter()
+                        .map(|item| self.item(item, item_indent, break_lines)),
+                );
+
+                List(self.collection(items, new_items, break_lines))
+            }
+            Tuple(items) => {
+                let new_items = self.arena.alloc_slice_fill_iter(
+                    items
+                        .items
+                        .iter()
+                        .map(|item| self.item(item, item_indent, break_lines)),
+                );
+
+                Tuple(self.collection(items, new_items, break_lines))
+            }
+            Record(fields) => Record(self.expr_fields(fields, item_indent, break_lines)),
+            RecordUpdate { update, fields } => RecordUpdate {
+                update,
+                fields: self.expr_fields(fields, item_indent, break_lines),
+            },
+            Apply(loc_function, loc_args, called_via) if break_lines => {
+                if let Some(apply) =
+                    self.apply_with_trailing_block(loc_function, loc_args, called_via, col, indent)
+                {
+                    return apply;
+                }
+
+                let loc_args = self.arena.alloc_slice_fill_iter(
+                    loc_args.iter().map(|arg| self.item(arg, item_indent, true)),
+                );
+
+                Apply(self.flat_loc_expr(loc_function), loc_args, called_via)
+            }
+            Apply(loc_function, loc_args, called_via) => {
+                let loc_args = self.arena.alloc_slice_fill_iter(
+                    loc_args
+                        .iter()
+                        .map(|arg| self.unbroken_loc_expr(arg, item_indent, item_indent)),
+                );
+
+                Apply(loc_function, loc_args, called_via)
+            }
+            BinOps(lefts, loc_right) => {
+                // Every operand after the first one follows an operator, e.g. `|> `
+                let operand_col = indent + 3;
+
+                let lefts = self
+                    .arena
+                    .alloc_slice_fill_iter(lefts.iter().enumerate().map(
+                        |(index, (loc_left, loc_binop))| {
+                            let col = if index == 0 { col } else { operand_col };
+
+                            let left = if break_lines {
+                                let left =
+                                    self.unbroken_expr(strip_spaces(&loc_left.value), col, indent);
+
+                                SpaceAfter(self.arena.alloc(left), NEWLINE)
+                            } else {
+                                self.unbroken_expr(&loc_left.value, col, indent)
+                            };
+
+                            (Loc::at(loc_left.region, left), *loc_binop)
+                        },
+                    ));
+
+                let right = if break_lines {
+                    strip_spaces(&loc_right.value)
+                } else {
+                    &loc_right.value
+                };
+
+                BinOps(
+                    lefts,
+                    self.arena.alloc(Loc::at(
+                        loc_right.region,
+                        self.unbroken_expr(right, operand_col, indent),
+                    )),
+                )
+            }
+            If(branches, final_else) => {
+                // Keeping the layout of an `if` that is all on one line keeps its branches there
+                let reflow_branch = |branch: &'a Expr<'a>| {
+                    if break_lines || expr.is_multiline() {
+                        self.expr(branch, item_indent, item_indent)
+                    } else {
+                        self.unbroken_expr(branch, item_indent, item_indent)
+                    }
+                };
+
+                let branches = self
+                    .arena
+                    .alloc_slice_fill_iter(branches.iter().enumerate().map(
+                        |(index, (loc_condition, loc_then))| {
+                            let condition_col = if index == 0 {
+                                col + "if ".len() as u16
+                            } else {
+                                indent + "else if ".len() as u16
+                            };
+                            let condition = if break_lines {
+                                strip_spaces(&loc_condition.value)
+                            } else {
+                                &loc_condition.value
+                            };
+                            let condition = Loc::at(
+                                loc_condition.region,
+                                self.unbroken_expr(condition, condition_col, indent),
+                            );
+
+                            let then = if break_lines {
+                                let then = reflow_branch(strip_spaces(&loc_then.value));
+                                let then = SpaceAfter(self.arena.alloc(then), NEWLINE);
+
+                                SpaceBefore(self.arena.alloc(then), NEWLINE)
+                            } else {
+                                reflow_branch(&loc_then.value)
+                            };
+
+                            (condition, Loc::at(loc_then.region, then))
+                        },
+                    ));
+
+                let final_else_expr = if break_lines {
+                    strip_spaces(&final_else.value)
+                } else {
+                    &final_else.value
+                };
+
+                If(
+                    branches,
+                    self.arena
+                        .alloc(Loc::at(final_else.region, reflow_branch(final_else_expr))),
+                )
+            }
+            _ => *expr,
+        }
+    }
+
+    /// Formatting `f a b \x -> ...` (or a trailing list or record instead of a closure) keeps
+    /// everything but the last argument on the first line. This uses that layout if that
+    /// first line fits, spreading only the last argument over several lines.
+    fn apply_with_trailing_block(
+        &self,
+        loc_function: &'a Loc<Expr<'a>>,
+        loc_args: &'a [&'a Loc<Expr<'a>>],
+        called_via: roc_module::called_via::CalledVia,
+        col: u16,
+        indent: u16,
+    ) -> Option<Expr<'a>> {
+        let (loc_last, loc_init) = loc_args.split_last()?;
+        let last = strip_spaces(&loc_last.value);
+
+        // The first line can't fit if one of its parts doesn't on its own
+        if self.flat_width(&loc_function.value).is_none()
+            || loc_init
+                .iter()
+                .any(|arg| self.flat_width(&arg.value).is_none())
+        {
+            return None;
+        }
+
+        let loc_function = self.flat_loc_expr(loc_function);
+        let loc_init = self
+            .arena
+            .alloc_slice_fill_iter(loc_init.iter().map(|arg| self.flat_loc_expr(arg)));
+
+        // The first line also ends with the start of the last argument, e.g. ` [` or ` \x ->`
+        let head = Expr::Apply(loc_function, loc_init, called_via);
+        let head_width = self.width(&head);
+
+        if !self.fits(&head, col + 2, indent) {
+            return None;
+        }
+
+        let last_col = col + head_width + 1;
+        let last = match last {
+            Expr::Closure(..) => self.expr(last, last_col, indent),
+            Expr::List(_) | Expr::Tuple(_) | Expr::Record(_) => {
+                self.spread(last, last_col, indent, true)
+            }
+            _ => return None,
+        };
+
+        if !last.is_multiline() {
+            return None;
+        }
+
+        let mut loc_args = Vec::with_capacity_in(loc_args.len(), self.arena);
+        loc_args.extend_from_slice(loc_init);
+        loc_args.push(&*self.arena.alloc(Loc::at(loc_last.region, last)));
+
+        Some(Expr::Apply(
+            loc_function,
+            loc_args.into_bump_slice(),
+            called_via,
+        ))
+    }
+
+    /// An item of a collection, or an argument of a function application.
+    fn item(&self, item: &'a Loc<Expr<'a>>, indent: u16, break_lines: bool) -> &'a Loc<Expr<'a>> {
+        if break_lines {
+            let expr = self.expr(strip_spaces(&item.value), indent, indent);
+
+            self.arena.alloc(Loc::at(
+                item.region,
+                Expr::SpaceBefore(self.arena.alloc(expr), NEWLINE),
+            ))
+        } else {
+            self.unbroken_loc_expr(item, indent, indent)
+        }
+    }
+
+    fn expr_fields(
+        &self,
+        fields: Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>,
+        indent: u16,
+        break_lines: bool,
+    ) -> Collection<'a, Loc<AssignedField<'a, Expr<'a>>>> {
+        let reflow_value = |value: &'a Expr<'a>, col, indent| {
+            if break_lines {
+                self.expr(value, col, indent)
+            } else {
+                self.unbroken_expr(value, col, indent)
+            }
+        };
+
+        self.fields(fields, indent, break_lines, &reflow_value)
+    }
+
+    fn fields<V: Copy>(
+        &self,
+        fields: Collection<'a, Loc<AssignedField<'a, V>>>,
+        indent: u16,
+        break_lines: bool,
+        reflow_value: &dyn Fn(&'a V, u16, u16) -> V,
+    ) -> Collection<'a, Loc<AssignedField<'a, V>>> {
+        let new_fields = self
+            .arena
+            .alloc_slice_fill_iter(fields.items.iter().map(|loc_field| {
+                let field = if break_lines {
+                    let field = self.field(strip_field(loc_field.value), indent, reflow_value);
+
+                    AssignedField::SpaceBefore(self.arena.alloc(field), NEWLINE)
+                } else {
+                    self.field(loc_field.value, indent, reflow_value)
+                };
+
+                Loc::at(loc_field.region, field)
+            }));
+
+        self.collection(fields, new_fields, break_lines)
+    }
+
+    fn field<V: Copy>(
+        &self,
+        field: AssignedField<'a, V>,
+        indent: u16,
+        reflow_value: &dyn Fn(&'a V, u16, u16) -> V,
+    ) -> AssignedField<'a, V> {
+        use roc_parse::ast::AssignedField::*;
+
+        let value = |label: Loc<&str>, loc_value: &'a Loc<V>| -> &'a Loc<V> {
+            // e.g. `name: `
+            let col = indent + label.value.len() as u16 + 2;
+
+            self.arena.alloc(Loc::at(
+                loc_value.region,
+                reflow_value(&loc_value.value, col, indent),
+            ))
+        };
+
+        match field {
+            RequiredValue(label, spaces, loc_value) => {
+                RequiredValue(label, spaces, value(label, loc_value))
+            }
+            OptionalValue(label, spaces, loc_value) => {
+                OptionalValue(label, spaces, value(label, loc_value))
+            }
+            SpaceBefore(sub_field, spaces) => SpaceBefore(
+                self.arena
+                    .alloc(self.field(*sub_field, indent, reflow_value)),
+                spaces,
+            ),
+            SpaceAfter(sub_field, spaces) => SpaceAfter(
+                self.arena
+                    .alloc(self.field(*sub_field, indent, reflow_value)),
+                spaces,
+            ),
+            LabelOnly(_) | Malformed(_) => field,
+        }
+    }
+
+    fn collection<T, U>(
+        &self,
+        original: Collection<'a, T>,
+        items: &'a [U],
+        break_lines: bool,
+    ) -> Collection<'a, U> {
+        if break_lines {
+            // Without comments, the final "comments" can only be blank lines
+            Collection::with_items(items)
+        } else {
+            original.replace_items(items)
+        }
+    }
+
+    /// A value's annotation can also spread a function type's arguments over several lines.
+    fn value_ann(
+        &self,
+        loc_ann: &'a Loc<TypeAnnotation<'a>>,
+        col: u16,
+        indent: u16,
+    ) -> Loc<TypeAnnotation<'a>> {
+        match strip_ann_spaces(&loc_ann.value) {
+            TypeAnnotation::Function(args, ret) if !self.has_comments(&loc_ann.value) => {
+                let flat = self.flat_ann(&loc_ann.value);
+
+                // Function-typed arguments can't go on lines of their own yet,
+                // see github.com/roc-lang/roc/issues/2586
+                let has_function_arg = args.iter().any(|arg| {
+                    matches!(strip_ann_spaces(&arg.value), TypeAnnotation::Function(..))
+                });
+
+                let ann = if has_function_arg || self.fits(&flat, col, indent) {
+                    flat
+                } else {
+                    let args = self.arena.alloc_slice_fill_iter(
+                        args.iter()
+                            .map(|arg| Loc::at(arg.region, self.flat_ann(&arg.value))),
+                    );
+                    let ret = self
+                        .arena
+                        .alloc(Loc::at(ret.region, self.flat_ann(&ret.value)));
+
+                    TypeAnnotation::SpaceBefore(
+                        self.arena.alloc(TypeAnnotation::Function(args, ret)),
+                        NEWLINE,
+                    )
+                };
+
+                Loc::at(loc_ann.region, ann)
+            }
+            _ => self.loc_ann(loc_ann, col, indent),
+        }
+    }
+
+    fn loc_ann(
+        &self,
+        loc_ann: &'a Loc<TypeAnnotation<'a>>,
+        col: u16,
+        indent: u16,
+    ) -> Loc<TypeAnnotation<'a>> {
+        Loc::at(loc_ann.region, self.ann(&loc_ann.value, col, indent))
+    }
+
+    fn loc_anns(
+        &self,
+        loc_anns: &'a [Loc<TypeAnnotation<'a>>],
+        col: u16,
+        indent: u16,
+    ) -> &'a [Loc<TypeAnnotation<'a>>] {
+        self.arena.alloc_slice_fill_iter(
+            loc_anns
+                .iter()
+                .map(|loc_ann| self.loc_ann(loc_ann, col, indent)),
+        )
+    }
+
+    fn ann(&self, ann: &'a TypeAnnotation<'a>, col: u16, indent: u16) -> TypeAnnotation<'a> {
+        use roc_parse::ast::TypeAnnotation::*;
+
+        match *ann {
+            Record { .. } | TagUnion { .. } | Tuple(_) => {
+                if self.has_comments(ann) {
+                    return self.spread_ann(ann, indent, false);
+                }
+
+                let flat = self.flat_ann(ann);
+
+                if self.fits(&flat, col, indent) {
+                    flat
+                } else {
+                    self.spread_ann(ann, indent, true)
+                }
+            }
+            SpaceBefore(sub_ann, spaces) => {
+                let col = if spaces.iter().any(|space| space.is_newline()) {
+                    indent
+                } else {
+                    col
+                };
+
+                SpaceBefore(self.arena.alloc(self.ann(sub_ann, col, indent)), spaces)
+            }
+            SpaceAfter(sub_ann, spaces) => {
+                SpaceAfter(self.arena.alloc(self.ann(sub_ann, col, indent)), spaces)
+            }
+            Function(args, ret) => Function(
+                self.loc_anns(args, col, indent),
+                self.arena.alloc(self.loc_ann(ret, col, indent)),
+            ),
+            Apply(module_name, name, args) => {
+                Apply(module_name, name, self.loc_anns(args, col, indent))
+            }
+            As(lhs, spaces, header) => As(
+                self.arena.alloc(self.loc_ann(lhs, col, indent)),
+                spaces,
+                header,
+            ),
+            Where(sub_ann, has_clauses) => Where(
+                self.arena.alloc(self.loc_ann(sub_ann, col, indent)),
+                has_clauses,
+            ),
+            BoundVariable(_) | Inferred | Wildcard | Malformed(_) => *ann,
+        }
+    }
+
+    fn spread_ann(
+        &self,
+        ann: &'a TypeAnnotation<'a>,
+        indent: u16,
+        break_lines: bool,
+    ) -> TypeAnnotation<'a> {
+        use roc_parse::ast::TypeAnnotation::*;
+
+        let item_indent = indent + INDENT;
+
+        match *ann {
+            Record { fields, ext } => {
+                let reflow_value =
+                    |value: &'a TypeAnnotation<'a>, col, indent| self.ann(value, col, indent);
+
+                Record {
+                    fields: self.fields(fields, item_indent, break_lines, &reflow_value),
+                    ext,
+                }
+            }
+            TagUnion { tags, ext } => {
+                let new_tags = self
+                    .arena
+                    .alloc_slice_fill_iter(tags.items.iter().map(|loc_tag| {
+                        if break_lines {
+                            let tag = self.flat_tag(&loc_tag.value);
+
+                            Loc::at(
+                                loc_tag.region,
+                                Tag::SpaceBefore(self.arena.alloc(tag), NEWLINE),
+                            )
+                        } else {
+                            *loc_tag
+                        }
+                    }));
+
+                TagUnion {
+                    tags: self.collection(tags, new_tags, break_lines),
+                    ext,
+                }
+            }
+            Tuple(elems) => {
+                let new_elems =
+                    self.arena
+                        .alloc_slice_fill_iter(elems.items.iter().map(|loc_elem| {
+                            if break_lines {
+                                let elem = self.flat_ann(&loc_elem.value);
+
+                                Loc::at(
+                                    loc_elem.region,
+                                    TypeAnnotation::SpaceBefore(self.arena.alloc(elem), NEWLINE),
+                                )
+                            } else {
+                                *loc_elem
+                            }
+                        }));
+
+                Tuple(self.collection(elems, new_elems, break_lines))
+            }
+            _ => *ann,
+        }
+    }
+
+    /// The expression with its line breaks removed, so that it formats on a single line
+    /// unless it contains something that can never be on one line (like a `when`).
+    fn flat_expr(&self, expr: &'a Expr<'a>) -> Expr<'a> {
+        use roc_parse::ast::Expr::*;
+
+        match *expr {
+            SpaceBefore(sub_expr, _) | SpaceAfter(sub_expr, _) => self.flat_expr(sub_expr),
+            ParensAround(sub_expr) => ParensAround(self.arena.alloc(self.flat_expr(sub_expr))),
+            Access(sub_expr, field) => Access(self.arena.alloc(self.flat_expr(sub_expr)), field),
+            UnaryOp(sub_expr, op) => UnaryOp(self.flat_loc_expr(sub_expr), op),
+            List(items) => {
+                let items = self
+                    .arena
+                    .alloc_slice_fill_iter(items.items.iter().map(|item| self.flat_loc_expr(item)));
+
+                List(Collection::with_items(items))
+            }
+            Tuple(items) => {
+                let items = self
+                    .arena
+                    .alloc_slice_fill_iter(items.items.iter().map(|item| self.flat_loc_expr(item)));
+
+                Tuple(Collection::with_items(items))
+            }
+            Record(fields) => Record(self.flat_expr_fields(fields)),
+            RecordUpdate { update, fields } => RecordUpdate {
+                update: self.flat_loc_expr(update),
+                fields: self.flat_expr_fields(fields),
+            },
+            Apply(loc_function, loc_args, called_via) => {
+                let loc_args = self
+                    .arena
+                    .alloc_slice_fill_iter(loc_args.iter().map(|arg| self.flat_loc_expr(arg)));
+
+                Apply(self.flat_loc_expr(loc_function), loc_args, called_via)
+            }
+            BinOps(lefts, loc_right) => {
+                let lefts =
+                    self.arena
+                        .alloc_slice_fill_iter(lefts.iter().map(|(loc_left, loc_binop)| {
+                            (
+                                Loc::at(loc_left.region, self.flat_expr(&loc_left.value)),
+                                *loc_binop,
+                            )
+                        }));
+
+                BinOps(lefts, self.flat_loc_expr(loc_right))
+            }
+            Closure(loc_patterns, loc_body) => Closure(loc_patterns, self.flat_loc_expr(loc_body)),
+            If(branches, final_else) => {
+                let branches =
+                    self.arena
+                        .alloc_slice_fill_iter(branches.iter().map(|(condition, then)| {
+                            (
+                                Loc::at(condition.region, self.flat_expr(&condition.value)),
+                                Loc::at(then.region, self.flat_expr(&then.value)),
+                            )
+                        }));
+
+                If(branches, self.flat_loc_expr(final_else))
+            }
+            _ => *expr,
+        }
+    }
+
+    /// The width of the expression's flat version, or `None` if that has several lines or is
+    /// wider than `max_width`, so that it can't fit anywhere.
+    fn flat_width(&self, expr: &'a Expr<'a>) -> Option<u16> {
+        use roc_parse::ast::Expr::*;
+
+        let key = expr as *const Expr<'a>;
+
+        if let Some(width) = self.flat_widths.borrow().get(&key) {
+            return *width;
+        }
+
+        let width = match *expr {
+            When(..) | Defs(..) | Backpassing(..) | Expect(..) | Dbg(..) => None,
+            // The flat version contains the flat version of each of its parts, so there's no
+            // need to render it unless each of those fits. That keeps large expressions from
+            // being rendered again at every level of nesting.
+            _ if self
+                .flat_parts(expr)
+                .iter()
+                .any(|part| self.flat_width(part).is_none()) =>
+            {
+                None
+            }
+            _ => {
+                let text = self.render(&self.flat_expr(expr), 0).into_bump_str();
+                let text = text.trim_start();
+                let width = text.chars().count();
+
+                if text.contains('\n') || width > self.max_width as usize {
+                    None
+                } else {
+                    Some(width as u16)
+                }
+            }
+        };
+
+        self.flat_widths.borrow_mut().insert(key, width);
+
+        width
+    }
+
+    /// Whether the expression's flat version fits when it starts at `col`.
+    fn flat_fits(&self, expr: &'a Expr<'a>, col: u16) -> bool {
+        match self.flat_width(expr) {
+            Some(width) => col as usize + width as usize <= self.max_width as usize,
+            None => false,
+        }
+    }
+
+    /// The parts `flat_expr` flattens along with an expression.
+    fn flat_parts(&self, expr: &'a Expr<'a>) -> Vec<'a, &'a Expr<'a>> {
+        use roc_parse::ast::Expr::*;
+
+        let mut parts = Vec::new_in(self.arena);
+        let push_fields =
+            |parts: &mut Vec<'a, &'a Expr<'a>>, fields: &'a [Loc<AssignedField<'a, Expr<'a>>>]| {
+                parts.extend(fields.iter().filter_map(|loc_field| {
+                    match strip_field(loc_field.value) {
+                        AssignedField::RequiredValue(_, _, loc_value)
+                        | AssignedField::OptionalValue(_, _, loc_value) => Some(&loc_value.value),
+                        _ => None,
+                    }
+                }))
+            };
+
+        match *expr {
+            SpaceBefore(sub_expr, _)
+            | SpaceAfter(sub_expr, _)
+            | ParensAround(sub_expr)
+            | Access(sub_expr, _) => parts.push(sub_expr),
+            UnaryOp(sub_expr, _) => parts.push(&sub_expr.value),
+            List(items) | Tuple(items) => parts.extend(items.items.iter().map(|item| &item.value)),
+            Record(fields) => push_fields(&mut parts, fields.items),
+            RecordUpdate { update, fields } => {
+                parts.push(&update.value);
+                push_fields(&mut parts, fields.items);
+            }
+            Apply(loc_function, loc_args, _) => {
+                parts.push(&loc_function.value);
+                parts.extend(loc_args.iter().map(|arg| &arg.value));
+            }
+            BinOps(lefts, loc_right) => {
+                parts.extend(lefts.iter().map(|(loc_left, _)| &loc_left.value));
+                parts.push(&loc_right.value);
+            }
+            Closure(_, loc_body) => parts.push(&loc_body.value),
+            If(branches, final_else) => {
+                for (condition, then) in branches.iter() {
+                    parts.push(&condition.value);
+                    parts.push(&then.value);
+                }
+
+                parts.push(&final_else.value);
+            }
+            _ => {}
+        }
+
+        parts
+    }
+
+    fn flat_loc_expr(&self, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc<Expr<'a>> {
+        self.arena
+            .alloc(Loc::at(loc_expr.region, self.flat_expr(&loc_expr.value)))
+    }
+
+    fn flat_expr_fields(
+        &self,
+        fields: Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>,
+    ) -> Collection<'a, Loc<AssignedField<'a, Expr<'a>>>> {
+        let flat_value = |value: &'a Expr<'a>| self.flat_expr(value);
+
+        self.flat_fields(fields, &flat_value)
+    }
+
+    fn flat_fields<V: Copy>(
+        &self,
+        fields: Collection<'a, Loc<AssignedField<'a, V>>>,
+        flat_value: &dyn Fn(&'a V) -> V,
+    ) -> Collection<'a, Loc<AssignedField<'a, V>>> {
+        let fields = self
+            .arena
+            .alloc_slice_fill_iter(fields.items.iter().map(|loc_field| {
+                let field = match strip_field(loc_field.value) {
+                    AssignedField::RequiredValue(label, spaces, loc_value) => {
+                        let value = Loc::at(loc_value.region, flat_value(&loc_value.value));
+
+                        AssignedField::RequiredValue(label, spaces, self.arena.alloc(value))
+                    }
+                    AssignedField::OptionalValue(label, spaces, loc_value) => {
+                        let value = Loc::at(loc_value.region, flat_value(&loc_value.value));
+
+                        AssignedField::OptionalValue(label, spaces, self.arena.alloc(value))
+                    }
+                    field => field,
+                };
+
+                Loc::at(loc_field.region, field)
+            }));
+
+        Collection::with_items(fields)
+    }
+
+    fn flat_ann(&self, ann: &'a TypeAnnotation<'a>) -> TypeAnnotation<'a> {
+        use roc_parse::ast::TypeAnnotation::*;
+
+        match *ann {
+            SpaceBefore(sub_ann, _) | SpaceAfter(sub_ann, _) => self.flat_ann(sub_ann),
+            Function(args, ret) => Function(self.flat_loc_anns(args), self.flat_loc_ann(ret)),
+            Apply(module_name, name, args) => Apply(module_name, name, self.flat_loc_anns(args)),
+            As(lhs, _, header) => As(self.flat_loc_ann(lhs), &[], header),
+            Where(sub_ann, has_clauses) => Where(self.flat_loc_ann(sub_ann), has_clauses),
+            Record { fields, ext } => {
+                let flat_value = |value: &'a TypeAnnotation<'a>| self.flat_ann(value);
+
+                Record {
+                    fields: self.flat_fields(fields, &flat_value),
+                    ext,
+                }
+            }
+            TagUnion { tags, ext } => {
+                let tags = self.arena.alloc_slice_fill_iter(
+                    tags.items
+                        .iter()
+                        .map(|loc_tag| Loc::at(loc_tag.region, self.flat_tag(&loc_tag.value))),
+                );
+
+                TagUnion {
+                    tags: Collection::with_items(tags),
+                    ext,
+                }
+            }
+            Tuple(elems) => Tuple(Collection::with_items(self.flat_loc_anns(elems.items))),
+            BoundVariable(_) | Inferred | Wildcard | Malformed(_) => *ann,
+        }
+    }
+
+    fn flat_loc_ann(&self, loc_ann: &'a Loc<TypeAnnotation<'a>>) -> &'a Loc<TypeAnnotation<'a>> {
+        self.arena
+            .alloc(Loc::at(loc_ann.region, self.flat_ann(&loc_ann.value)))
+    }
+
+    fn flat_loc_anns(
+        &self,
+        loc_anns: &'a [Loc<TypeAnnotation<'a>>],
+    ) -> &'a [Loc<TypeAnnotation<'a>>] {
+        self.arena.alloc_slice_fill_iter(
+            loc_anns
+                .iter()
+                .map(|loc_ann| Loc::at(loc_ann.region, self.flat_ann(&loc_ann.value))),
+        )
+    }
+
+    fn flat_tag(&self, tag: &'a Tag<'a>) -> Tag<'a> {
+        match *tag {
+            Tag::SpaceBefore(sub_tag, _) | Tag::SpaceAfter(sub_tag, _) => self.flat_tag(sub_tag),
+            Tag::Apply { name, args } => Tag::Apply {
+                name,
+                args: self.flat_loc_anns(args),
+            },
+            Tag::Malformed(_) => *tag,
+        }
+    }
+
+    /// Formats the node on its own, without re-flowing it.
+    fn render<T: Formattable>(&self, node: &T, indent: u16) -> Buf<'a> {
+        let mut buf = Buf::new_in(self.arena);
+
+        node.format_with_options(&mut buf, Parens::NotNeeded, Newlines::No, indent);
+
+        buf
+    }
+
+    fn has_comments<T: Formattable>(&self, node: &T) -> bool {
+        !self.comment_free.get() && self.render(node, 0).wrote_comment()
+    }
+
+    /// Runs `f` on the inside of a node that has no comments. Nothing inside it has any
+    /// either, so `has_comments` doesn't need to render any of it again.
+    fn without_comments<T>(&self, f: impl FnOnce() -> T) -> T {
+        let was_comment_free = self.comment_free.replace(true);
+        let result = f();
+        self.comment_free.set(was_comment_free);
+
+        result
+    }
+
+    /// Whether the node formats as a single line that fits when it starts at `col`.
+    fn fits<T: Formattable>(&self, node: &T, col: u16, indent: u16) -> bool {
+        let text = self.render(node, indent).into_bump_str().trim_start();
+
+        !text.contains('\n') && col as usize + text.chars().count() <= self.max_width as usize
+    }
+
+    /// The width of the first line the node formats as.
+    fn width<T: Formattable>(&self, node: &T) -> u16 {
+        let text = self.render(node, 0).into_bump_str();
+
+        text.lines().next().unwrap_or("").chars().count() as u16
+    }
+
+    fn patterns_width(&self, loc_patterns: &[Loc<Pattern<'a>>], separator: &str) -> u16 {
+        let separators = loc_patterns.len().saturating_sub(1) * separator.len();
+
+        loc_patterns
+            .iter()
+            .map(|loc_pattern| self.width(&loc_pattern.value))
+            .sum::<u16>()
+            + separators as u16
+    }
+
+    fn header_width(&self, name: &str, vars: &[Loc<Pattern<'a>>]) -> u16 {
+        let vars_width: u16 = vars.iter().map(|var| 1 + self.width(&var.value)).sum();
+
+        name.len() as u16 + vars_width
+    }
+}
+
+fn strip_spaces<'a>(expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    let mut stripped = expr;
+
+    while let Expr::SpaceBefore(sub_expr, _) | Expr::SpaceAfter(sub_expr, _) = stripped {
+        stripped = sub_expr;
+    }
+
+    // The lines of a block string are indented relative to the line it begins on,
+    // so where it begins is left alone.
+    match stripped {
+        Expr::Str(StrLiteral::Block(_)) => expr,
+        _ => stripped,
+    }
+}
+
+fn strip_ann_spaces<'a>(mut ann: &'a TypeAnnotation<'a>) -> &'a TypeAnnotation<'a> {
+    while let TypeAnnotation::SpaceBefore(sub_ann, _) | TypeAnnotation::SpaceAfter(sub_ann, _) = ann
+    {
+        ann = sub_ann;
+    }
+
+    ann
+}
+
+/// The field without any line breaks around it, or between its label and its value.
+fn strip_field<'a, V: Copy>(field: AssignedField<'a, V>) -> AssignedField<'a, V> {
+    use roc_parse::ast::AssignedField::*;
+
+    match field {
+        SpaceBefore(sub_field, _) | SpaceAfter(sub_field, _) => strip_field(*sub_field),
+        RequiredValue(label, _, loc_value) => RequiredValue(label, &[], loc_value),
+        OptionalValue(label, _, loc_value) => OptionalValue(label, &[], loc_value),
+        LabelOnly(_) | Malformed(_) => field,
+    }
+}
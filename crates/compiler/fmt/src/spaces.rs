@@ -3,13 +3,14 @@ use bumpalo::Bump;
 use roc_module::called_via::{BinOp, UnaryOp};
 use roc_parse::{
     ast::{
-        AbilityMember, AssignedField, Collection, CommentOrNewline, Defs, Expr, Has, HasAbilities,
-        HasAbility, HasClause, HasImpls, Module, Pattern, Spaced, StrLiteral, StrSegment, Tag,
-        TypeAnnotation, TypeDef, TypeHeader, ValueDef, WhenBranch,
+        AbilityMember, AbilityMemberDefault, AssignedField, Collection, CommentOrNewline, Defs,
+        Expr, Has, HasAbilities, HasAbility, HasClause, HasImpls, Module, Pattern, PatternAs,
+        Spaced, StrLiteral, StrSegment, Tag, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
+        WhenBranch,
     },
     header::{
         AppHeader, ExposedName, HostedHeader, ImportsEntry, InterfaceHeader, ModuleName,
-        PackageEntry, PackageName, PlatformHeader, PlatformRequires, To, TypedIdent,
+        PackageEntry, PackageHeader, PackageName, PlatformHeader, PlatformRequires, To, TypedIdent,
     },
     ident::UppercaseIdent,
 };
@@ -149,6 +150,7 @@ fn fmt_comment<'buf>(buf: &mut Buf<'buf>, comment: &str) {
         buf.spaces(1);
     }
 
+    buf.note_comment();
     buf.push('#');
     if !comment.starts_with(' ') {
         buf.spaces(1);
@@ -188,6 +190,7 @@ fn fmt_docs<'buf>(buf: &mut Buf<'buf>, docs: &str) {
         buf.spaces(1);
     }
 
+    buf.note_comment();
     buf.push_str("##");
     if !docs.is_empty() {
         buf.spaces(1);
@@ -300,6 +303,19 @@ impl<'a> RemoveSpaces<'a> for Module<'a> {
                     after_provides: &[],
                 },
             },
+            Module::Package { header } => Module::Package {
+                header: PackageHeader {
+                    name: header.name.remove_spaces(arena),
+                    exposes: header.exposes.remove_spaces(arena),
+                    packages: header.packages.remove_spaces(arena),
+                    before_header: &[],
+                    after_package_keyword: &[],
+                    before_exposes: &[],
+                    after_exposes: &[],
+                    before_packages: &[],
+                    after_packages: &[],
+                },
+            },
             Module::Hosted { header } => Module::Hosted {
                 header: HostedHeader {
                     name: header.name.remove_spaces(arena),
@@ -504,6 +520,7 @@ impl<'a> RemoveSpaces<'a> for TypeDef<'a> {
             Ability {
                 header: TypeHeader { name, vars },
                 loc_has,
+                required_abilities,
                 members,
             } => Ability {
                 header: TypeHeader {
@@ -511,8 +528,19 @@ impl<'a> RemoveSpaces<'a> for TypeDef<'a> {
                     vars: vars.remove_spaces(arena),
                 },
                 loc_has: loc_has.remove_spaces(arena),
+                required_abilities: required_abilities.remove_spaces(arena),
                 members: members.remove_spaces(arena),
             },
+            BuiltinImpls {
+                header: TypeHeader { name, vars },
+                has_abilities,
+            } => BuiltinImpls {
+                header: TypeHeader {
+                    name: name.remove_spaces(arena),
+                    vars: vars.remove_spaces(arena),
+                },
+                has_abilities: has_abilities.remove_spaces(arena),
+            },
         }
     }
 }
@@ -569,6 +597,10 @@ impl<'a> RemoveSpaces<'a> for AbilityMember<'a> {
         AbilityMember {
             name: self.name.remove_spaces(arena),
             typ: self.typ.remove_spaces(arena),
+            default: self.default.map(|default| AbilityMemberDefault {
+                name: default.name.remove_spaces(arena),
+                body: arena.alloc(default.body.remove_spaces(arena)),
+            }),
         }
     }
 }
@@ -643,6 +675,7 @@ impl<'a> RemoveSpaces<'a> for Expr<'a> {
             Expr::Access(a, b) => Expr::Access(arena.alloc(a.remove_spaces(arena)), b),
             Expr::AccessorFunction(a) => Expr::AccessorFunction(a),
             Expr::List(a) => Expr::List(a.remove_spaces(arena)),
+            Expr::Tuple(a) => Expr::Tuple(a.remove_spaces(arena)),
             Expr::RecordUpdate { update, fields } => Expr::RecordUpdate {
                 update: arena.alloc(update.remove_spaces(arena)),
                 fields: fields.remove_spaces(arena),
@@ -650,6 +683,7 @@ impl<'a> RemoveSpaces<'a> for Expr<'a> {
             Expr::Record(a) => Expr::Record(a.remove_spaces(arena)),
             Expr::Var { module_name, ident } => Expr::Var { module_name, ident },
             Expr::Underscore(a) => Expr::Underscore(a),
+            Expr::Crash => Expr::Crash,
             Expr::Tag(a) => Expr::Tag(a),
             Expr::OpaqueRef(a) => Expr::OpaqueRef(a),
             Expr::Closure(a, b) => Expr::Closure(
@@ -682,6 +716,10 @@ impl<'a> RemoveSpaces<'a> for Expr<'a> {
                 arena.alloc(a.remove_spaces(arena)),
                 arena.alloc(b.remove_spaces(arena)),
             ),
+            Expr::Dbg(a, b) => Expr::Dbg(
+                arena.alloc(a.remove_spaces(arena)),
+                arena.alloc(b.remove_spaces(arena)),
+            ),
             Expr::Apply(a, b, c) => Expr::Apply(
                 arena.alloc(a.remove_spaces(arena)),
                 b.remove_spaces(arena),
@@ -722,6 +760,18 @@ impl<'a> RemoveSpaces<'a> for Pattern<'a> {
                 arena.alloc(b.remove_spaces(arena)),
             ),
             Pattern::RecordDestructure(a) => Pattern::RecordDestructure(a.remove_spaces(arena)),
+            Pattern::Tuple(a) => Pattern::Tuple(a.remove_spaces(arena)),
+            Pattern::List(a) => Pattern::List(a.remove_spaces(arena)),
+            Pattern::ListRest(opt_rest) => {
+                Pattern::ListRest(opt_rest.map(|(_, name)| (&[] as &[_], Loc::at_zero(name.value))))
+            }
+            Pattern::As(pattern, pattern_as) => Pattern::As(
+                arena.alloc(pattern.remove_spaces(arena)),
+                PatternAs {
+                    spaces_before: &[],
+                    identifier: Loc::at_zero(pattern_as.identifier.value),
+                },
+            ),
             Pattern::RequiredField(a, b) => {
                 Pattern::RequiredField(a, arena.alloc(b.remove_spaces(arena)))
             }
@@ -778,6 +828,7 @@ impl<'a> RemoveSpaces<'a> for TypeAnnotation<'a> {
                 ext: ext.remove_spaces(arena),
                 tags: tags.remove_spaces(arena),
             },
+            TypeAnnotation::Tuple(elems) => TypeAnnotation::Tuple(elems.remove_spaces(arena)),
             TypeAnnotation::Inferred => TypeAnnotation::Inferred,
             TypeAnnotation::Wildcard => TypeAnnotation::Wildcard,
             TypeAnnotation::Where(annot, has_clauses) => TypeAnnotation::Where(
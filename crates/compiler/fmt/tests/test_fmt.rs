@@ -9,6 +9,7 @@ mod test_fmt {
     use roc_fmt::annotation::{Formattable, Newlines, Parens};
     use roc_fmt::def::fmt_defs;
     use roc_fmt::module::fmt_module;
+    use roc_fmt::reflow::reflow_expr;
     use roc_fmt::Buf;
     use roc_parse::ast::Module;
     use roc_parse::module::{self, module_defs};
@@ -77,6 +78,55 @@ mod test_fmt {
         expr_formats_to(input, input);
     }
 
+    // Formats using the width-aware layout, so that lines fit within max_width where possible
+    fn expr_formats_to_with_max_width(input: &str, expected: &str, max_width: u16) {
+        use roc_fmt::spaces::RemoveSpaces;
+
+        let arena = Bump::new();
+        let input = input.trim();
+        let expected = expected.trim();
+
+        let format = |src: &str| {
+            let src = arena.alloc_str(src);
+            let ast = roc_parse::test_helpers::parse_expr_with(&arena, src).unwrap_or_else(|err| {
+                panic!(
+                    "Unexpected parse failure when parsing this for formatting:\n\n{}\n\nParse error was:\n\n{:?}\n\n",
+                    src, err
+                );
+            });
+            let reflowed = reflow_expr(&arena, arena.alloc(ast), max_width);
+
+            let mut buf = Buf::new_in(&arena);
+            reflowed.format_with_options(&mut buf, Parens::NotNeeded, Newlines::Yes, 0);
+
+            (ast, buf.into_bump_str())
+        };
+
+        let (actual, output) = format(input);
+
+        assert_multiline_str_eq!(expected, output);
+
+        let (reparsed_ast, reformatted) = format(output);
+
+        if format!("{:?}", actual.remove_spaces(&arena))
+            != format!("{:?}", reparsed_ast.remove_spaces(&arena))
+        {
+            panic!(
+                "Formatting bug; formatting didn't reparse to the same AST (after removing spaces)\n\n\
+                * * * Source code before formatting:\n{}\n\n\
+                * * * Source code after formatting:\n{}\n\n",
+                input, output
+            );
+        }
+
+        // Re-flowing is also stable
+        assert_multiline_str_eq!(output, reformatted);
+    }
+
+    fn expr_formats_same_with_max_width(input: &str, max_width: u16) {
+        expr_formats_to_with_max_width(input, input, max_width);
+    }
+
     fn fmt_module_and_defs<'a>(
         arena: &Bump,
         src: &str,
@@ -1539,6 +1589,91 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn tuple_destructuring() {
+        expr_formats_same(indoc!(
+            r#"
+            (x, (y, _)) = (1, (2, 3))
+
+            when pair is
+                (0, b) -> b
+                (a, _) -> a
+            "#
+        ));
+    }
+
+    #[test]
+    fn tuples() {
+        expr_formats_to(
+            indoc!(
+                r#"
+                ( 1 ,"two",[ 3 ] , )
+                "#
+            ),
+            indoc!(
+                r#"
+                (1, "two", [3])
+                "#
+            ),
+        );
+
+        expr_formats_same(indoc!(
+            r#"
+            (
+                1,
+                # the second element
+                "two",
+            )
+            "#
+        ));
+
+        expr_formats_same(indoc!(
+            r#"
+            pair.0 + (pair).1 + .1 pair
+            "#
+        ));
+    }
+
+    #[test]
+    fn tuple_types() {
+        expr_formats_same(indoc!(
+            r#"
+            swap : (a, b) -> (b, a)
+            swap = \(a, b) -> (b, a)
+
+            swap
+            "#
+        ));
+
+        expr_formats_same(indoc!(
+            r#"
+            apply : (Str, (U8 -> Str)) -> Str
+            apply = \(s, g) -> g s
+
+            apply
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                f : (Str , U8) -> (U8, Str )
+                f = \( s, n ) -> (n , s)
+
+                f
+                "#
+            ),
+            indoc!(
+                r#"
+                f : (Str, U8) -> (U8, Str)
+                f = \(s, n) -> (n, s)
+
+                f
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn lambda_returns_record() {
         expr_formats_same(indoc!(
@@ -3911,6 +4046,86 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn when_with_list_patterns() {
+        expr_formats_same(indoc!(
+            r#"
+            when list is
+                [] -> 0
+                [x] -> x
+                [first, .., last] -> first + last
+                [_, .. as rest] -> List.len rest
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                when list is
+                    [ ] -> 0
+                    [ first , ..  as   rest ] -> first
+                "#
+            ),
+            indoc!(
+                r#"
+                when list is
+                    [] -> 0
+                    [first, .. as rest] -> first
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn when_with_as_patterns() {
+        expr_formats_same(indoc!(
+            r#"
+            when result is
+                Ok (Pair a b as pair) -> pair
+                Err _ as err -> err
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                when result is
+                    Ok   x   as   value -> value
+                    Err  _ as  err -> err
+                "#
+            ),
+            indoc!(
+                r#"
+                when result is
+                    Ok x as value -> value
+                    Err _ as err -> err
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn closure_with_as_pattern() {
+        expr_formats_same(indoc!(
+            r#"
+            \Pair x _ as pair, { y } as rec -> pair
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                \(Pair x _ as pair) -> pair
+                "#
+            ),
+            indoc!(
+                r#"
+                \Pair x _ as pair -> pair
+                "#
+            ),
+        );
+    }
+
     // ACCESSOR
 
     #[test]
@@ -4669,6 +4884,25 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn single_line_package() {
+        module_formats_same(
+            "package \"rtfeldman/json\" \
+            exposes [Json, Json.Decode] \
+            packages {}",
+        );
+    }
+
+    #[test]
+    fn multiline_package() {
+        module_formats_same(indoc!(
+            r#"
+            package "rtfeldman/json"
+                exposes [Json, Json.Decode]
+                packages {}"#
+        ));
+    }
+
     #[test]
     fn module_defs_with_comments() {
         module_formats_to(
@@ -5315,6 +5549,43 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn ability_required_abilities_and_defaults() {
+        expr_formats_same(indoc!(
+            r#"
+            Ord has Eq, Hash
+                compare : a, a -> [LT, EQ, GT] | a has Ord
+                lt : a, a -> Bool | a has Ord
+                lt = \x, y -> compare x y == LT
+
+            0
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                Ord has Eq , Hash
+                  compare : a, a -> [LT, EQ, GT] | a has Ord
+                  lt : a, a -> Bool | a has Ord
+                  lt =    \x, y -> compare x y == LT
+
+                0
+                "#
+            ),
+            indoc!(
+                r#"
+                Ord has Eq, Hash
+                    compare : a, a -> [LT, EQ, GT] | a has Ord
+                    lt : a, a -> Bool | a has Ord
+                    lt = \x, y -> compare x y == LT
+
+                0
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn opaque_has_with_impls() {
         expr_formats_same(indoc!(
@@ -5378,6 +5649,35 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn builtin_impls() {
+        expr_formats_same(indoc!(
+            r#"
+            Str has [Pretty { pretty: prettyStr }]
+            List a has [Pretty { pretty: prettyList }, Eq]
+
+            0
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                Str   has [Pretty {  pretty : prettyStr }]
+
+                0
+                "#
+            ),
+            indoc!(
+                r#"
+                Str has [Pretty { pretty: prettyStr }]
+
+                0
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn comments_in_multiline_tag_union_annotation() {
         expr_formats_to(
@@ -5524,6 +5824,70 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn dbg_single_line() {
+        expr_formats_same(indoc!(
+            r#"
+            x = 5
+
+            dbg x
+            x + 1
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                dbg x
+
+                x + 1
+                "#
+            ),
+            indoc!(
+                r#"
+                dbg x
+                x + 1
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn dbg_multiline() {
+        expr_formats_same(indoc!(
+            r#"
+            dbg
+                foo bar
+                |> baz
+            42
+            "#
+        ));
+    }
+
+    #[test]
+    fn crash() {
+        expr_formats_same(indoc!(
+            r#"
+            when x is
+                Ok y -> y
+                Err _ -> crash "unreachable"
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                crash   "unreachable"
+                "#
+            ),
+            indoc!(
+                r#"
+                crash "unreachable"
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn single_line_string_literal_in_pattern() {
         expr_formats_same(indoc!(
@@ -5567,6 +5931,527 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn max_width_joins_short_list() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                [
+                    1,
+                    2,
+                    3,
+                ]
+                "#
+            ),
+            indoc!(
+                r#"
+                [1, 2, 3]
+                "#
+            ),
+            80,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_list() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                ["first item", "second item", "third item"]
+                "#
+            ),
+            indoc!(
+                r#"
+                [
+                    "first item",
+                    "second item",
+                    "third item",
+                ]
+                "#
+            ),
+            30,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_tuple() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                ("first item", "second item", "third item")
+                "#
+            ),
+            indoc!(
+                r#"
+                (
+                    "first item",
+                    "second item",
+                    "third item",
+                )
+                "#
+            ),
+            30,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_only_the_collection_that_is_too_long() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                [{ x: 1, y: 2 }, { x: 3, y: 4 }, { x: 5, y: 6 }]
+                "#
+            ),
+            indoc!(
+                r#"
+                [
+                    { x: 1, y: 2 },
+                    { x: 3, y: 4 },
+                    { x: 5, y: 6 },
+                ]
+                "#
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_joins_and_breaks_records() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                {
+                    short: 1,
+                    nested: { name: "a name that is rather long", age: 42 },
+                }
+                "#
+            ),
+            indoc!(
+                r#"
+                {
+                    short: 1,
+                    nested: {
+                        name: "a name that is rather long",
+                        age: 42,
+                    },
+                }
+                "#
+            ),
+            40,
+        );
+
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                { rec &
+                    x: 1,
+                    y: 2,
+                }
+                "#
+            ),
+            indoc!(
+                r#"
+                { rec & x: 1, y: 2 }
+                "#
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_application() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                someFunction firstArgument secondArgument
+                "#
+            ),
+            indoc!(
+                r#"
+                someFunction
+                    firstArgument
+                    secondArgument
+                "#
+            ),
+            30,
+        );
+    }
+
+    #[test]
+    fn max_width_joins_short_application() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                someFunction
+                    firstArgument
+                    secondArgument
+                "#
+            ),
+            indoc!(
+                r#"
+                someFunction firstArgument secondArgument
+                "#
+            ),
+            80,
+        );
+    }
+
+    #[test]
+    fn max_width_keeps_trailing_block_on_first_line() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                List.map items \item -> combine item.first item.second
+                "#
+            ),
+            indoc!(
+                r#"
+                List.map items \item ->
+                    combine item.first item.second
+                "#
+            ),
+            40,
+        );
+
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                Dict.fromList [Pair "one" 1, Pair "two" 2, Pair "three" 3]
+                "#
+            ),
+            indoc!(
+                r#"
+                Dict.fromList [
+                    Pair "one" 1,
+                    Pair "two" 2,
+                    Pair "three" 3,
+                ]
+                "#
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_pipeline() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                numbers |> List.map square |> List.keepIf isEven |> List.sum
+                "#
+            ),
+            indoc!(
+                r#"
+                numbers
+                |> List.map square
+                |> List.keepIf isEven
+                |> List.sum
+                "#
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_joins_short_pipeline() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                numbers
+                |> List.map square
+                |> List.sum
+                "#
+            ),
+            indoc!(
+                r#"
+                numbers |> List.map square |> List.sum
+                "#
+            ),
+            80,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_pipeline_in_def() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                total = numbers |> List.map square |> List.keepIf isEven |> List.sum
+
+                total
+                "#
+            ),
+            indoc!(
+                r#"
+                total =
+                    numbers
+                    |> List.map square
+                    |> List.keepIf isEven
+                    |> List.sum
+
+                total
+                "#
+            ),
+            50,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_if() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                x = if List.isEmpty list then fallback else List.len list
+
+                x
+                "#
+            ),
+            indoc!(
+                r#"
+                x =
+                    if List.isEmpty list then
+                        fallback
+                    else
+                        List.len list
+
+                x
+                "#
+            ),
+            40,
+        );
+
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                x =
+                    if a then
+                        b
+                    else
+                        c
+
+                x
+                "#
+            ),
+            indoc!(
+                r#"
+                x =
+                    if a then b else c
+
+                x
+                "#
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_function_annotation() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                concat : Str, Str, Str -> Str
+                concat = \a, b, c -> a
+
+                concat
+                "#
+            ),
+            indoc!(
+                r#"
+                concat :
+                    Str,
+                    Str,
+                    Str
+                    -> Str
+                concat = \a, b, c -> a
+
+                concat
+                "#
+            ),
+            28,
+        );
+
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                swap : Nat, Nat, List a -> List a
+                swap = \i, j, list -> list
+
+                swap
+                "#
+            ),
+            indoc!(
+                r#"
+                swap :
+                    Nat,
+                    Nat,
+                    List a
+                    -> List a
+                swap = \i, j, list -> list
+
+                swap
+                "#
+            ),
+            28,
+        );
+
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                pick : Bool, elem, elem -> elem
+                pick = \b, x, y -> x
+
+                pick
+                "#
+            ),
+            indoc!(
+                r#"
+                pick :
+                    Bool,
+                    elem,
+                    elem
+                    -> elem
+                pick = \b, x, y -> x
+
+                pick
+                "#
+            ),
+            28,
+        );
+    }
+
+    #[test]
+    fn max_width_joins_short_function_annotation() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                concat :
+                    Str,
+                    Str
+                    -> Str
+
+                concat
+                "#
+            ),
+            indoc!(
+                r#"
+                concat : Str, Str -> Str
+
+                concat
+                "#
+            ),
+            80,
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_type_annotations() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                color : [Red, Green, Blue, Custom { red : U8, green : U8, blue : U8 }]
+
+                color
+                "#
+            ),
+            indoc!(
+                r#"
+                color : [
+                    Red,
+                    Green,
+                    Blue,
+                    Custom { red : U8, green : U8, blue : U8 },
+                ]
+
+                color
+                "#
+            ),
+            60,
+        );
+
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                user : {
+                    name : Str,
+                    age : U8,
+                }
+
+                user
+                "#
+            ),
+            indoc!(
+                r#"
+                user : { name : Str, age : U8 }
+
+                user
+                "#
+            ),
+            60,
+        );
+    }
+
+    #[test]
+    fn max_width_deeply_nested() {
+        // Each of these is laid out once per level, rather than twice per enclosing level
+        let depth = 30;
+
+        let mut closures = String::new();
+
+        for level in 0..depth {
+            closures.push_str(&format!("{}\\x{} ->\n", " ".repeat(4 * level), level));
+        }
+
+        // The `when` can't be on one line, so neither can any of the closures around it
+        let indent = " ".repeat(4 * depth);
+        closures.push_str(&format!("{}when x0 is\n{}    _ -> x0", indent, indent));
+
+        expr_formats_same_with_max_width(&closures, 80);
+
+        let mut whens = String::new();
+
+        for level in 0..depth {
+            let indent = " ".repeat(8 * level);
+
+            whens.push_str(&format!(
+                "{}when x{} is\n{}    _ ->\n",
+                indent, level, indent
+            ));
+        }
+
+        let indent = " ".repeat(8 * depth);
+        whens.push_str(&format!("{}when x0 is\n{}    _ -> x0", indent, indent));
+
+        expr_formats_same_with_max_width(&whens, 80);
+    }
+
+    #[test]
+    fn max_width_keeps_comments() {
+        expr_formats_same_with_max_width(
+            indoc!(
+                r#"
+                [
+                    # the first one
+                    1,
+                    2,
+                ]
+                "#
+            ),
+            80,
+        );
+
+        expr_formats_same_with_max_width(
+            indoc!(
+                r#"
+                f
+                    # the first argument
+                    a
+                    b
+                "#
+            ),
+            80,
+        );
+    }
+
     // this is a parse error atm
     //    #[test]
     //    fn multiline_apply() {
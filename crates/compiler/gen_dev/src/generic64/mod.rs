@@ -1003,7 +1003,7 @@ impl<
 
     fn build_eq(&mut self, dst: &Symbol, src1: &Symbol, src2: &Symbol, arg_layout: &Layout<'a>) {
         match arg_layout {
-            Layout::Builtin(single_register_int_builtins!()) => {
+            Layout::Builtin(single_register_int_builtins!() | Builtin::Bool) => {
                 let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
                 let src1_reg = self
                     .storage_manager
@@ -1537,9 +1537,9 @@ impl<
                 ASM::mov_reg64_imm64(&mut self.buf, reg, i128::from_ne_bytes(val) as i64);
             }
             (Literal::Bool(x), Layout::Builtin(Builtin::Bool)) => {
+                // the comparisons set a whole register to 0 or 1, so literals must match that
                 let reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
-                let val = [*x as u8; 16];
-                ASM::mov_reg64_imm64(&mut self.buf, reg, i128::from_ne_bytes(val) as i64);
+                ASM::mov_reg64_imm64(&mut self.buf, reg, *x as i64);
             }
             (Literal::Float(x), Layout::Builtin(Builtin::Float(FloatWidth::F64))) => {
                 let reg = self.storage_manager.claim_float_reg(&mut self.buf, sym);
@@ -0,0 +1,786 @@
+//! Loads objects made by the dev backend straight into the running process, so that the code in
+//! them can be called without writing them to disk and linking them into a shared library.
+//!
+//! This only does as much linking as the dev backend and the builtins need: it lays out their
+//! sections in freshly mapped memory, resolves symbols against the loaded objects, the symbols
+//! the host provides and the process itself, and then applies the relocations.
+
+use object::elf;
+use object::{
+    Architecture, BinaryFormat, Object, ObjectSection, ObjectSymbol, RelocationEncoding,
+    RelocationKind, RelocationTarget, SectionIndex, SectionKind, SymbolIndex, SymbolSection,
+};
+use roc_builtins::bitcode::{FloatWidth, IntWidth};
+use roc_collections::all::MutMap;
+use roc_mono::layout::{Builtin, Layout, STLayoutInterner};
+use roc_target::TargetInfo;
+use std::ffi::CString;
+
+use crate::{single_register_floats, single_register_int_builtins, single_register_integers};
+
+/// Every stub is a jump through an absolute address, which is padded to this many bytes
+const STUB_SIZE: usize = 16;
+const GOT_ENTRY_SIZE: usize = 8;
+
+/// How a procedure built by the dev backend hands a value of some layout back to its caller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnConvention {
+    /// Zero-sized values are not returned at all
+    Nothing,
+    /// In the first float register
+    FloatRegister,
+    /// In up to two general-purpose registers
+    GeneralRegisters,
+    /// Written to memory that the caller passes a pointer to as the first argument
+    ArgPointer,
+}
+
+impl ReturnConvention {
+    /// This mirrors `return_complex_symbol` in the calling conventions, which return values of
+    /// more than 16 bytes through the arg pointer
+    pub fn of<'a>(
+        interner: &STLayoutInterner<'a>,
+        target_info: TargetInfo,
+        layout: &Layout<'a>,
+    ) -> Self {
+        match layout {
+            single_register_floats!() => ReturnConvention::FloatRegister,
+            single_register_integers!() => ReturnConvention::GeneralRegisters,
+            _ => match layout.stack_size(interner, target_info) {
+                0 => ReturnConvention::Nothing,
+                1..=16 => ReturnConvention::GeneralRegisters,
+                _ => ReturnConvention::ArgPointer,
+            },
+        }
+    }
+}
+
+/// Code and data that have been loaded into this process
+pub struct JitObject {
+    memory: *mut u8,
+    size: usize,
+    symbols: MutMap<String, usize>,
+}
+
+impl JitObject {
+    /// Load the given objects together, like a linker would. Undefined symbols are looked up in
+    /// `host_symbols` first, and then in the current process. Calls to functions that can't be
+    /// found go to a trap, so that they only fail if they are actually made.
+    pub fn load(objects: &[&[u8]], host_symbols: &[(&str, usize)]) -> Result<Self, String> {
+        let files = objects
+            .iter()
+            .map(|bytes| object::File::parse(*bytes).map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let architecture = match files.first() {
+            Some(file) => file.architecture(),
+            None => return Err("there are no objects to load".to_string()),
+        };
+
+        for file in files.iter() {
+            if file.format() != BinaryFormat::Elf || file.architecture() != architecture {
+                return Err(format!(
+                    "can't load {:?} objects for {:?}",
+                    file.format(),
+                    file.architecture()
+                ));
+            }
+        }
+
+        if !matches!(architecture, Architecture::X86_64 | Architecture::Aarch64) {
+            return Err(format!("can't load objects for {:?}", architecture));
+        }
+
+        let layout = MemoryLayout::new(&files)?;
+        let page_size = page_size();
+        let text_size = align_to(layout.text_size, page_size);
+        let size = text_size + align_to(layout.data_size, page_size);
+
+        let memory = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        };
+
+        if memory == libc::MAP_FAILED {
+            return Err(std::io::Error::last_os_error().to_string());
+        }
+
+        // From here on, dropping `jit` unmaps the memory again if anything goes wrong
+        let mut jit = JitObject {
+            memory: memory.cast(),
+            size,
+            symbols: MutMap::default(),
+        };
+
+        let base = jit.memory as usize;
+        let mut linker = Linker {
+            architecture,
+            text_base: base,
+            data_base: base + text_size,
+            next_stub: layout.stubs_offset,
+            next_got_entry: layout.got_offset,
+            stubs: MutMap::default(),
+            got_entries: MutMap::default(),
+            trap: None,
+        };
+
+        for (file, placement) in files.iter().zip(layout.objects.iter()) {
+            for section in file.sections() {
+                if let Some(address) = linker.section_address(placement, section.index()) {
+                    let data = section.data().map_err(|e| e.to_string())?;
+
+                    unsafe {
+                        std::ptr::copy_nonoverlapping(data.as_ptr(), address as *mut u8, data.len())
+                    };
+                }
+            }
+        }
+
+        // Strong definitions win over weak ones; otherwise the first definition wins
+        let mut globals: MutMap<String, (usize, bool)> = MutMap::default();
+
+        for (file, placement) in files.iter().zip(layout.objects.iter()) {
+            for symbol in file.symbols() {
+                if !symbol.is_global() || symbol.is_undefined() {
+                    continue;
+                }
+
+                let name = symbol.name().map_err(|e| e.to_string())?;
+
+                if let Some(address) = linker.defined_address(placement, &symbol) {
+                    let is_weak = symbol.is_weak();
+
+                    match globals.get(name) {
+                        Some((_, false)) => {}
+                        Some((_, true)) if is_weak => {}
+                        _ => {
+                            globals.insert(name.to_string(), (address, is_weak));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (file, placement) in files.iter().zip(layout.objects.iter()) {
+            for section in file.sections() {
+                let section_address = match linker.section_address(placement, section.index()) {
+                    Some(address) => address,
+                    None => continue,
+                };
+
+                for (offset, relocation) in section.relocations() {
+                    let place = section_address + offset as usize;
+
+                    let target = match relocation.target() {
+                        RelocationTarget::Symbol(index) => linker.resolve(
+                            file,
+                            placement,
+                            index,
+                            &globals,
+                            host_symbols,
+                        )?,
+                        RelocationTarget::Section(index) => {
+                            match linker.section_address(placement, index) {
+                                Some(address) => Target::Found(address),
+                                None => {
+                                    return Err(format!(
+                                        "a relocation refers to section {:?}, which isn't loaded",
+                                        index
+                                    ))
+                                }
+                            }
+                        }
+                        RelocationTarget::Absolute => Target::Found(0),
+                        _ => return Err("unsupported relocation target".to_string()),
+                    };
+
+                    if relocation.has_implicit_addend() {
+                        return Err("relocations with implicit addends aren't supported".into());
+                    }
+
+                    linker.apply(place, &relocation, target)?;
+                }
+            }
+        }
+
+        jit.symbols = globals
+            .into_iter()
+            .map(|(name, (address, _))| (name, address))
+            .collect();
+
+        let result = unsafe {
+            libc::mprotect(
+                jit.memory.cast(),
+                text_size,
+                libc::PROT_READ | libc::PROT_EXEC,
+            )
+        };
+
+        if result != 0 {
+            return Err(std::io::Error::last_os_error().to_string());
+        }
+
+        Ok(jit)
+    }
+
+    /// The address of a global symbol defined by one of the loaded objects
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.symbols.get(name).copied()
+    }
+}
+
+impl Drop for JitObject {
+    fn drop(&mut self) {
+        unsafe { libc::munmap(self.memory.cast(), self.size) };
+    }
+}
+
+/// Where the loaded sections of one object go, as offsets into the text or the data area
+#[derive(Default)]
+struct Placement {
+    text: MutMap<SectionIndex, usize>,
+    data: MutMap<SectionIndex, usize>,
+    common: MutMap<SymbolIndex, usize>,
+}
+
+struct MemoryLayout {
+    objects: Vec<Placement>,
+    text_size: usize,
+    stubs_offset: usize,
+    data_size: usize,
+    got_offset: usize,
+}
+
+impl MemoryLayout {
+    fn new(files: &[object::File]) -> Result<Self, String> {
+        let mut objects = Vec::with_capacity(files.len());
+        let mut text_size = 0;
+        let mut data_size = 0;
+        let mut stub_count = 1;
+        let mut got_count = 0;
+
+        for file in files {
+            let mut placement = Placement::default();
+
+            for section in file.sections() {
+                let align = section.align().max(1) as usize;
+                let size = section.size() as usize;
+
+                match section.kind() {
+                    SectionKind::Text => {
+                        text_size = align_to(text_size, align);
+                        placement.text.insert(section.index(), text_size);
+                        text_size += size;
+                    }
+                    SectionKind::Data
+                    | SectionKind::ReadOnlyData
+                    | SectionKind::ReadOnlyString
+                    | SectionKind::UninitializedData => {
+                        data_size = align_to(data_size, align);
+                        placement.data.insert(section.index(), data_size);
+                        data_size += size;
+                    }
+                    SectionKind::Tls | SectionKind::UninitializedTls if size > 0 => {
+                        return Err("thread-local storage isn't supported".to_string());
+                    }
+                    _ => continue,
+                }
+
+                // at most one stub or GOT entry per relocation
+                let relocations = section.relocations().count();
+                stub_count += relocations;
+                got_count += relocations;
+            }
+
+            for symbol in file.symbols() {
+                if symbol.section() == SymbolSection::Common {
+                    // the value of a common symbol is its alignment
+                    data_size = align_to(data_size, symbol.address().max(1) as usize);
+                    placement.common.insert(symbol.index(), data_size);
+                    data_size += symbol.size() as usize;
+                }
+            }
+
+            objects.push(placement);
+        }
+
+        let stubs_offset = align_to(text_size, STUB_SIZE);
+        let got_offset = align_to(data_size, GOT_ENTRY_SIZE);
+
+        Ok(MemoryLayout {
+            objects,
+            text_size: stubs_offset + stub_count * STUB_SIZE,
+            stubs_offset,
+            data_size: got_offset + got_count * GOT_ENTRY_SIZE,
+            got_offset,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    Found(usize),
+    /// An undefined symbol that nothing provides
+    Missing,
+}
+
+struct Linker {
+    architecture: Architecture,
+    text_base: usize,
+    data_base: usize,
+    next_stub: usize,
+    next_got_entry: usize,
+    stubs: MutMap<usize, usize>,
+    got_entries: MutMap<usize, usize>,
+    trap: Option<usize>,
+}
+
+impl Linker {
+    fn section_address(&self, placement: &Placement, index: SectionIndex) -> Option<usize> {
+        match placement.text.get(&index) {
+            Some(offset) => Some(self.text_base + offset),
+            None => placement.data.get(&index).map(|offset| self.data_base + offset),
+        }
+    }
+
+    fn defined_address(&self, placement: &Placement, symbol: &object::Symbol) -> Option<usize> {
+        match symbol.section() {
+            SymbolSection::Section(index) => self
+                .section_address(placement, index)
+                .map(|address| address + symbol.address() as usize),
+            SymbolSection::Common => placement
+                .common
+                .get(&symbol.index())
+                .map(|offset| self.data_base + offset),
+            SymbolSection::Absolute => Some(symbol.address() as usize),
+            _ => None,
+        }
+    }
+
+    fn resolve(
+        &self,
+        file: &object::File,
+        placement: &Placement,
+        index: SymbolIndex,
+        globals: &MutMap<String, (usize, bool)>,
+        host_symbols: &[(&str, usize)],
+    ) -> Result<Target, String> {
+        let symbol = file.symbol_by_index(index).map_err(|e| e.to_string())?;
+        let name = symbol.name().map_err(|e| e.to_string())?;
+
+        if !symbol.is_undefined() {
+            if symbol.is_global() {
+                if let Some((address, _)) = globals.get(name) {
+                    return Ok(Target::Found(*address));
+                }
+            }
+
+            return match self.defined_address(placement, &symbol) {
+                Some(address) => Ok(Target::Found(address)),
+                None => Err(format!("{} is defined in a section that isn't loaded", name)),
+            };
+        }
+
+        if let Some((address, _)) = globals.get(name) {
+            return Ok(Target::Found(*address));
+        }
+
+        if let Some((_, address)) = host_symbols.iter().find(|(host, _)| *host == name) {
+            return Ok(Target::Found(*address));
+        }
+
+        let c_name = CString::new(name).map_err(|e| e.to_string())?;
+        let address = unsafe { libc::dlsym(libc::RTLD_DEFAULT, c_name.as_ptr()) };
+
+        if !address.is_null() {
+            Ok(Target::Found(address as usize))
+        } else if symbol.is_weak() {
+            Ok(Target::Found(0))
+        } else {
+            Ok(Target::Missing)
+        }
+    }
+
+    /// The address to call for a target: functions that can't be found go to a trap
+    fn callable(&mut self, target: Target) -> usize {
+        match target {
+            Target::Found(address) => address,
+            Target::Missing => match self.trap {
+                Some(trap) => trap,
+                None => {
+                    let trap = self.text_base + self.next_stub;
+                    self.next_stub += STUB_SIZE;
+
+                    let bytes: &[u8] = match self.architecture {
+                        // ud2
+                        Architecture::X86_64 => &[0x0f, 0x0b],
+                        // brk #0
+                        _ => &0xd420_0000u32.to_le_bytes(),
+                    };
+
+                    unsafe { write_bytes(trap, bytes) };
+
+                    self.trap = Some(trap);
+
+                    trap
+                }
+            },
+        }
+    }
+
+    /// A stub that jumps to the given address, for calls that can't reach it directly
+    fn stub(&mut self, address: usize) -> usize {
+        if let Some(stub) = self.stubs.get(&address) {
+            return *stub;
+        }
+
+        let stub = self.text_base + self.next_stub;
+        self.next_stub += STUB_SIZE;
+
+        match self.architecture {
+            Architecture::X86_64 => unsafe {
+                // jmp [rip + 0]
+                write_bytes(stub, &[0xff, 0x25, 0, 0, 0, 0]);
+                write_bytes(stub + 6, &(address as u64).to_le_bytes());
+            },
+            _ => unsafe {
+                // ldr x16, #8; br x16
+                write_bytes(stub, &0x5800_0050u32.to_le_bytes());
+                write_bytes(stub + 4, &0xd61f_0200u32.to_le_bytes());
+                write_bytes(stub + 8, &(address as u64).to_le_bytes());
+            },
+        }
+
+        self.stubs.insert(address, stub);
+
+        stub
+    }
+
+    fn got_entry(&mut self, target: Target) -> usize {
+        let address = self.callable(target);
+
+        if let Some(entry) = self.got_entries.get(&address) {
+            return *entry;
+        }
+
+        let entry = self.data_base + self.next_got_entry;
+        self.next_got_entry += GOT_ENTRY_SIZE;
+
+        unsafe { write_bytes(entry, &(address as u64).to_le_bytes()) };
+
+        self.got_entries.insert(address, entry);
+
+        entry
+    }
+
+    fn apply(
+        &mut self,
+        place: usize,
+        relocation: &object::Relocation,
+        target: Target,
+    ) -> Result<(), String> {
+        let addend = relocation.addend();
+        let kind = relocation.kind();
+        let size = relocation.size();
+
+        let found = |target: Target| match target {
+            Target::Found(address) => Ok(address),
+            Target::Missing => Err(format!("{:?} needs a symbol that isn't defined", kind)),
+        };
+
+        let relative = |address: usize| (address as i64).wrapping_add(addend) - place as i64;
+
+        match (self.architecture, kind, size) {
+            (_, RelocationKind::Absolute, 64) => {
+                let value = (found(target)? as i64).wrapping_add(addend);
+
+                unsafe { write_bytes(place, &value.to_le_bytes()) };
+            }
+            (_, RelocationKind::Absolute, 32) => {
+                let value = (found(target)? as i64).wrapping_add(addend);
+
+                let fits = match relocation.encoding() {
+                    RelocationEncoding::X86Signed => i32::try_from(value).is_ok(),
+                    _ => u32::try_from(value).is_ok(),
+                };
+
+                if !fits {
+                    return Err(format!("{:#x} doesn't fit in 32 bits", value));
+                }
+
+                unsafe { write_bytes(place, &(value as u32).to_le_bytes()) };
+            }
+            (_, RelocationKind::Relative, 64) => {
+                let value = relative(found(target)?);
+
+                unsafe { write_bytes(place, &value.to_le_bytes()) };
+            }
+            (_, RelocationKind::Relative, 32) => {
+                let value = relative(found(target)?);
+
+                unsafe { write_bytes(place, &to_i32(value)?.to_le_bytes()) };
+            }
+            (Architecture::X86_64, RelocationKind::PltRelative, 32) => {
+                let mut address = self.callable(target);
+
+                if i32::try_from(relative(address)).is_err() {
+                    address = self.stub(address);
+                }
+
+                unsafe { write_bytes(place, &to_i32(relative(address))?.to_le_bytes()) };
+            }
+            (Architecture::X86_64, RelocationKind::GotRelative, 32)
+            | (
+                Architecture::X86_64,
+                RelocationKind::Elf(elf::R_X86_64_GOTPCRELX | elf::R_X86_64_REX_GOTPCRELX),
+                _,
+            ) => {
+                let entry = self.got_entry(target);
+
+                unsafe { write_bytes(place, &to_i32(relative(entry))?.to_le_bytes()) };
+            }
+            (Architecture::Aarch64, RelocationKind::PltRelative, 26)
+            | (Architecture::Aarch64, RelocationKind::Elf(elf::R_AARCH64_JUMP26), _) => {
+                let mut address = self.callable(target);
+
+                if !fits_signed(relative(address), 28) {
+                    address = self.stub(address);
+                }
+
+                let offset = relative(address) >> 2;
+
+                unsafe { patch_instruction(place, 0x03ff_ffff, 0, offset) };
+            }
+            (Architecture::Aarch64, RelocationKind::Elf(r_type), _) => {
+                self.apply_aarch64(place, r_type, addend, target)?;
+            }
+            _ => {
+                return Err(format!(
+                    "unsupported {:?} relocation {:?} of {} bits",
+                    self.architecture, kind, size
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_aarch64(
+        &mut self,
+        place: usize,
+        r_type: u32,
+        addend: i64,
+        target: Target,
+    ) -> Result<(), String> {
+        let page = |address: i64| address & !0xfff;
+
+        let address = match r_type {
+            elf::R_AARCH64_ADR_GOT_PAGE | elf::R_AARCH64_LD64_GOT_LO12_NC => {
+                self.got_entry(target) as i64
+            }
+            _ => match target {
+                Target::Found(address) => address as i64,
+                Target::Missing => {
+                    return Err(format!("relocation {} needs a symbol that isn't defined", r_type))
+                }
+            },
+        };
+
+        let value = address.wrapping_add(addend);
+        let place_i64 = place as i64;
+
+        match r_type {
+            elf::R_AARCH64_ADR_PREL_PG_HI21 | elf::R_AARCH64_ADR_GOT_PAGE => {
+                let pages = (page(value) - page(place_i64)) >> 12;
+
+                if !fits_signed(pages, 21) {
+                    return Err(format!("page offset {:#x} is out of range", pages));
+                }
+
+                // adrp splits its immediate into 2 low bits and 19 high bits
+                unsafe {
+                    patch_instruction(place, 0x3, 29, pages);
+                    patch_instruction(place, 0x7ffff, 5, pages >> 2);
+                }
+            }
+            elf::R_AARCH64_ADD_ABS_LO12_NC | elf::R_AARCH64_LDST8_ABS_LO12_NC => unsafe {
+                patch_instruction(place, 0xfff, 10, value & 0xfff)
+            },
+            elf::R_AARCH64_LDST16_ABS_LO12_NC => unsafe {
+                patch_instruction(place, 0xfff, 10, (value & 0xfff) >> 1)
+            },
+            elf::R_AARCH64_LDST32_ABS_LO12_NC => unsafe {
+                patch_instruction(place, 0xfff, 10, (value & 0xfff) >> 2)
+            },
+            elf::R_AARCH64_LDST64_ABS_LO12_NC | elf::R_AARCH64_LD64_GOT_LO12_NC => unsafe {
+                patch_instruction(place, 0xfff, 10, (value & 0xfff) >> 3)
+            },
+            elf::R_AARCH64_LDST128_ABS_LO12_NC => unsafe {
+                patch_instruction(place, 0xfff, 10, (value & 0xfff) >> 4)
+            },
+            elf::R_AARCH64_CONDBR19 | elf::R_AARCH64_TSTBR14 => {
+                let (bits, mask) = match r_type {
+                    elf::R_AARCH64_CONDBR19 => (21, 0x7ffff),
+                    _ => (16, 0x3fff),
+                };
+
+                let offset = value - place_i64;
+
+                if !fits_signed(offset, bits) {
+                    return Err(format!("branch offset {:#x} is out of range", offset));
+                }
+
+                unsafe { patch_instruction(place, mask, 5, offset >> 2) };
+            }
+            _ => return Err(format!("unsupported AArch64 relocation {}", r_type)),
+        }
+
+        Ok(())
+    }
+}
+
+fn to_i32(value: i64) -> Result<i32, String> {
+    i32::try_from(value).map_err(|_| format!("{:#x} doesn't fit in 32 bits", value))
+}
+
+fn fits_signed(value: i64, bits: u32) -> bool {
+    let limit = 1i64 << (bits - 1);
+
+    (-limit..limit).contains(&value)
+}
+
+fn align_to(offset: usize, align: usize) -> usize {
+    (offset + align - 1) / align * align
+}
+
+fn page_size() -> usize {
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+unsafe fn write_bytes(address: usize, bytes: &[u8]) {
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), address as *mut u8, bytes.len());
+}
+
+/// Replace the bits of a 32-bit instruction at `shift` that are covered by `mask`
+unsafe fn patch_instruction(address: usize, mask: u32, shift: u32, value: i64) {
+    let ptr = address as *mut u32;
+    let instruction = u32::from_le(ptr.read_unaligned());
+    let patched = (instruction & !(mask << shift)) | (((value as u32) & mask) << shift);
+
+    ptr.write_unaligned(patched.to_le());
+}
+
+#[cfg(all(test, target_arch = "x86_64", target_os = "linux"))]
+mod tests {
+    use super::*;
+    use object::write::{Object as ObjectWriter, Relocation, StandardSection, Symbol};
+    use object::{Endianness, SymbolFlags, SymbolKind, SymbolScope};
+
+    extern "C" fn add_one(value: *const u64) -> u64 {
+        unsafe { *value + 1 }
+    }
+
+    /// An object with a `main` that passes the address of its data to a host function
+    fn object_calling(host_fn: &str) -> Vec<u8> {
+        let mut output = ObjectWriter::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+
+        let text = output.section_id(StandardSection::Text);
+        let data = output.section_id(StandardSection::Data);
+
+        // lea rdi, [rip + value]; jmp host_fn
+        let code = [0x48, 0x8d, 0x3d, 0, 0, 0, 0, 0xe9, 0, 0, 0, 0];
+        let code_offset = output.append_section_data(text, &code, 16);
+        let value_offset = output.append_section_data(data, &41u64.to_le_bytes(), 8);
+
+        let symbol = |name: &str, section, value, kind| Symbol {
+            name: name.as_bytes().to_vec(),
+            value,
+            size: 0,
+            kind,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section,
+            flags: SymbolFlags::None,
+        };
+
+        output.add_symbol(symbol(
+            "main",
+            object::write::SymbolSection::Section(text),
+            code_offset,
+            SymbolKind::Text,
+        ));
+        let value = output.add_symbol(symbol(
+            "value",
+            object::write::SymbolSection::Section(data),
+            value_offset,
+            SymbolKind::Data,
+        ));
+        let host = output.add_symbol(symbol(
+            host_fn,
+            object::write::SymbolSection::Undefined,
+            0,
+            SymbolKind::Text,
+        ));
+
+        let relocation = |offset, symbol, kind| Relocation {
+            offset,
+            size: 32,
+            kind,
+            encoding: RelocationEncoding::Generic,
+            symbol,
+            addend: -4,
+        };
+
+        output
+            .add_relocation(text, relocation(3, value, RelocationKind::Relative))
+            .unwrap();
+        output
+            .add_relocation(text, relocation(8, host, RelocationKind::PltRelative))
+            .unwrap();
+
+        output.write().unwrap()
+    }
+
+    #[test]
+    fn calls_host_function_with_loaded_data() {
+        let bytes = object_calling("add_one");
+        let jit = JitObject::load(&[&bytes], &[("add_one", add_one as usize)]).unwrap();
+
+        let main: extern "C" fn() -> u64 =
+            unsafe { std::mem::transmute(jit.get("main").unwrap()) };
+
+        assert_eq!(main(), 42);
+        assert_eq!(jit.get("add_one"), None);
+    }
+
+    #[test]
+    fn calls_function_from_the_process() {
+        let bytes = object_calling("strlen");
+        let jit = JitObject::load(&[&bytes], &[]).unwrap();
+
+        let main: extern "C" fn() -> u64 =
+            unsafe { std::mem::transmute(jit.get("main").unwrap()) };
+
+        // the data is 41 followed by zeros, which is a one-byte C string
+        assert_eq!(main(), 1);
+    }
+
+    #[test]
+    fn missing_function_is_only_an_error_when_called() {
+        let bytes = object_calling("roc_does_not_exist");
+
+        assert!(JitObject::load(&[&bytes], &[]).is_ok());
+    }
+
+    #[test]
+    fn rejects_foreign_objects() {
+        assert!(JitObject::load(&[], &[]).is_err());
+        assert!(JitObject::load(&[b"not an object"], &[]).is_err());
+    }
+}
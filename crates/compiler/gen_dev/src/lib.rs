@@ -19,6 +19,8 @@ use roc_mono::layout::{
 };
 
 mod generic64;
+#[cfg(unix)]
+pub mod jit;
 mod object_builder;
 pub use object_builder::build_module;
 mod run_roc;
@@ -209,6 +211,44 @@ trait Backend<'a> {
                 self.build_jump(id, args, arg_layouts.into_bump_slice(), ret_layout);
                 self.free_symbols(stmt);
             }
+            Stmt::Dbg {
+                message,
+                location,
+                remainder,
+                ..
+            } => {
+                // The builtin passes both strings on to roc_dbg, which the host can provide
+                self.build_fn_call(
+                    &Symbol::DEV_TMP,
+                    bitcode::UTILS_DBG.to_string(),
+                    &[*location, *message],
+                    &[Layout::Builtin(Builtin::Str), Layout::Builtin(Builtin::Str)],
+                    &Layout::UNIT,
+                );
+                self.free_symbol(&Symbol::DEV_TMP);
+                self.free_symbols(stmt);
+                self.build_stmt(remainder, ret_layout);
+            }
+            Stmt::Crash(msg, crash_tag, _) => {
+                // The builtin copies the message into a zero-terminated string and passes it
+                // to roc_panic, which never returns.
+                self.load_literal_symbols(&[*msg]);
+                self.load_literal(
+                    &Symbol::DEV_TMP,
+                    &Layout::u32(),
+                    &Literal::Int((*crash_tag as u32 as i128).to_ne_bytes()),
+                );
+                self.build_fn_call(
+                    &Symbol::DEV_TMP2,
+                    bitcode::STR_CRASH.to_string(),
+                    &[*msg, Symbol::DEV_TMP],
+                    &[Layout::Builtin(Builtin::Str), Layout::u32()],
+                    &Layout::UNIT,
+                );
+                self.free_symbol(&Symbol::DEV_TMP);
+                self.free_symbol(&Symbol::DEV_TMP2);
+                self.free_symbols(stmt);
+            }
             x => todo!("the statement, {:?}", x),
         }
     }
@@ -499,6 +539,19 @@ trait Backend<'a> {
                 );
                 self.build_num_sub(sym, &args[0], &args[1], ret_layout)
             }
+            LowLevel::NumSubWrap => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "NumSubWrap: expected to have exactly two argument"
+                );
+                debug_assert_eq!(
+                    arg_layouts[0], *ret_layout,
+                    "NumSubWrap: expected to have the same argument and return layout"
+                );
+                // subtraction is not checked for overflow yet, so it already wraps
+                self.build_num_sub(sym, &args[0], &args[1], ret_layout)
+            }
             LowLevel::NumBitwiseAnd => {
                 if let Layout::Builtin(Builtin::Int(int_width)) = ret_layout {
                     self.build_int_bitwise_and(sym, &args[0], &args[1], *int_width)
@@ -648,6 +701,16 @@ trait Backend<'a> {
                 );
                 self.build_list_replace_unsafe(sym, args, arg_layouts, ret_layout)
             }
+            LowLevel::NumToStr => {
+                let fn_name = match arg_layouts[0] {
+                    Layout::Builtin(Builtin::Int(width)) => &bitcode::STR_FROM_INT[width],
+                    Layout::Builtin(Builtin::Float(width)) => &bitcode::STR_FROM_FLOAT[width],
+                    Layout::Builtin(Builtin::Decimal) => bitcode::DEC_TO_STR,
+                    x => internal_error!("NumToStr is not defined for {:?}", x),
+                };
+
+                self.build_fn_call(sym, fn_name.to_string(), args, arg_layouts, ret_layout)
+            }
             LowLevel::StrConcat => self.build_fn_call(
                 sym,
                 bitcode::STR_CONCAT.to_string(),
@@ -655,6 +718,20 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::StrSplit => self.build_fn_call(
+                sym,
+                bitcode::STR_STR_SPLIT.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrJoinWith => self.build_fn_call(
+                sym,
+                bitcode::STR_JOIN_WITH.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::PtrCast => {
                 debug_assert_eq!(
                     1,
@@ -1094,9 +1171,26 @@ trait Backend<'a> {
                 }
             }
 
+            Stmt::Dbg {
+                symbol,
+                message,
+                location,
+                remainder,
+                ..
+            } => {
+                self.set_last_seen(*symbol, stmt);
+                self.set_last_seen(*message, stmt);
+                self.set_last_seen(*location, stmt);
+                self.scan_ast(remainder);
+            }
+
             Stmt::Expect { .. } => todo!("expect is not implemented in the dev backend"),
             Stmt::ExpectFx { .. } => todo!("expect-fx is not implemented in the dev backend"),
 
+            Stmt::Crash(msg, _, _) => {
+                self.set_last_seen(*msg, stmt);
+            }
+
             Stmt::RuntimeError(_) => {}
         }
     }
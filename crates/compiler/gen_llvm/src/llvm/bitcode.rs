@@ -827,7 +827,7 @@ pub(crate) fn pass_list_to_zig_64bit<'a, 'ctx, 'env>(
     list_alloca
 }
 
-fn pass_string_to_zig_64bit<'a, 'ctx, 'env>(
+pub(crate) fn pass_string_to_zig_64bit<'a, 'ctx, 'env>(
     _env: &Env<'a, 'ctx, 'env>,
     string: BasicValueEnum<'ctx>,
 ) -> PointerValue<'ctx> {
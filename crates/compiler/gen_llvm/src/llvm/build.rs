@@ -1,6 +1,7 @@
 use crate::llvm::bitcode::{
     call_bitcode_fn, call_bitcode_fn_fixing_for_convention, call_list_bitcode_fn,
-    call_str_bitcode_fn, call_void_bitcode_fn, pass_list_or_string_to_zig_32bit, BitcodeReturns,
+    call_str_bitcode_fn, call_void_bitcode_fn, pass_list_or_string_to_zig_32bit,
+    pass_string_to_zig_64bit, BitcodeReturns,
 };
 use crate::llvm::build_list::{
     self, allocate_list, empty_polymorphic_list, list_append_unsafe, list_capacity, list_concat,
@@ -12,7 +13,7 @@ use crate::llvm::compare::{generic_eq, generic_neq};
 use crate::llvm::convert::{
     self, argument_type_from_layout, basic_type_from_builtin, basic_type_from_layout, zig_str_type,
 };
-use crate::llvm::expect::clone_to_shared_memory;
+use crate::llvm::expect::{clone_to_shared_memory, write_crash_location};
 use crate::llvm::refcounting::{
     build_reset, decrement_refcount_layout, increment_refcount_layout, PointerToRefcount,
 };
@@ -51,8 +52,8 @@ use roc_error_macros::internal_error;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::ir::{
-    BranchInfo, CallType, EntryPoint, HigherOrderLowLevel, JoinPointId, ListLiteralElement,
-    ModifyRc, OptLevel, ProcLayout,
+    BranchInfo, CallType, CrashTag, EntryPoint, HigherOrderLowLevel, JoinPointId,
+    ListLiteralElement, ModifyRc, OptLevel, ProcLayout,
 };
 use roc_mono::layout::{
     Builtin, CapturesNiche, LambdaName, LambdaSet, Layout, LayoutIds, RawFunctionLayout,
@@ -214,22 +215,6 @@ pub struct Env<'a, 'ctx, 'env> {
     pub exposed_to_host: MutSet<Symbol>,
 }
 
-#[repr(u32)]
-pub enum PanicTagId {
-    NullTerminatedString = 0,
-}
-
-impl std::convert::TryFrom<u32> for PanicTagId {
-    type Error = ();
-
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(PanicTagId::NullTerminatedString),
-            _ => Err(()),
-        }
-    }
-}
-
 impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
     /// The integer type representing a pointer
     ///
@@ -375,12 +360,9 @@ impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
         )
     }
 
-    pub fn call_panic(&self, message: PointerValue<'ctx>, tag_id: PanicTagId) {
+    pub fn call_panic(&self, message: PointerValue<'ctx>, tag: CrashTag) {
         let function = self.module.get_function("roc_panic").unwrap();
-        let tag_id = self
-            .context
-            .i32_type()
-            .const_int(tag_id as u32 as u64, false);
+        let tag_id = self.context.i32_type().const_int(tag as u32 as u64, false);
 
         let call = self
             .builder
@@ -2793,6 +2775,32 @@ pub fn build_exp_stmt<'a, 'ctx, 'env>(
             }
         }
 
+        Dbg {
+            symbol,
+            message,
+            location,
+            region,
+            remainder,
+        } => {
+            if env.mode.runs_expects() {
+                // `roc test` renders the value itself, with the types of the module at hand
+                if let roc_target::PtrWidth::Bytes8 = env.target_info.ptr_width() {
+                    clone_to_shared_memory(env, scope, layout_ids, *symbol, *region, &[*symbol]);
+                }
+            } else {
+                report_dbg(env, scope, location, message);
+            }
+
+            build_exp_stmt(
+                env,
+                layout_ids,
+                func_spec_solutions,
+                scope,
+                parent,
+                remainder,
+            )
+        }
+
         Expect {
             condition: cond_symbol,
             region,
@@ -2917,6 +2925,18 @@ pub fn build_exp_stmt<'a, 'ctx, 'env>(
             )
         }
 
+        Crash(sym, tag, region) => {
+            if env.mode.runs_expects() {
+                write_crash_location(env, *sym, *region);
+            }
+
+            throw_user_exception(env, scope, sym, *tag);
+
+            // unused value (must return a BasicValue)
+            let zero = env.context.i64_type().const_zero();
+            zero.into()
+        }
+
         RuntimeError(error_msg) => {
             throw_exception(env, error_msg);
 
@@ -4102,6 +4122,20 @@ pub fn get_panic_msg_ptr<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>) -> PointerVa
     global.as_pointer_value()
 }
 
+/// Pointer to the tag id that was last passed to `roc_panic`.
+pub fn get_panic_tag_ptr<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>) -> PointerValue<'ctx> {
+    let i32_type = env.context.i32_type();
+
+    let global_name = "roc_panic_tag";
+    let global = env.module.get_global(global_name).unwrap_or_else(|| {
+        let global = env.module.add_global(i32_type, None, global_name);
+        global.set_initializer(&i32_type.const_zero());
+        global
+    });
+
+    global.as_pointer_value()
+}
+
 fn set_jump_and_catch_long_jump<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     parent: FunctionValue<'ctx>,
@@ -4159,8 +4193,12 @@ fn set_jump_and_catch_long_jump<'a, 'ctx, 'env>(
         let return_value = {
             let v1 = call_result_type.const_zero();
 
-            // flag is non-zero, indicating failure
-            let flag = context.i64_type().const_int(1, false);
+            // flag is non-zero, indicating failure; it is one more than the crash tag
+            let tag_id = builder.build_load(get_panic_tag_ptr(env), "load_panic_tag");
+            let tag_id =
+                builder.build_int_z_extend(tag_id.into_int_value(), context.i64_type(), "tag_id");
+            let flag =
+                builder.build_int_add(tag_id, context.i64_type().const_int(1, false), "flag");
 
             let v2 = builder
                 .build_insert_value(v1, flag, 0, "set_error")
@@ -4667,14 +4705,27 @@ fn build_proc_header<'a, 'ctx, 'env>(
 fn expose_alias_to_host<'a, 'ctx, 'env>(
     env: &'a Env<'a, 'ctx, 'env>,
     mod_solutions: &'a ModSolutions,
-    proc_name: LambdaName,
+    layout_ids: &mut LayoutIds<'a>,
+    proc: &roc_mono::ir::Proc<'a>,
     alias_symbol: Symbol,
     exposed_function_symbol: Symbol,
     top_level: ProcLayout<'a>,
     layout: RawFunctionLayout<'a>,
 ) {
-    let ident_string = proc_name.name().as_str(&env.interns);
-    let fn_name: String = format!("{}_1", ident_string);
+    // Named after the layout of the proc, just like the proc itself when it's exposed,
+    // e.g. `roc__mainForHost_1__Fx_caller` next to `roc__mainForHost_1_exposed`.
+    let fn_name: String = {
+        let proc_layout = ProcLayout {
+            arguments: Vec::from_iter_in(proc.args.iter().map(|(layout, _)| *layout), env.arena)
+                .into_bump_slice(),
+            result: proc.ret_layout,
+            captures_niche: proc.name.captures_niche(),
+        };
+
+        layout_ids
+            .get_toplevel(proc.name.name(), &proc_layout)
+            .to_exposed_alias_def_name(proc.name.name(), &env.interns)
+    };
 
     match layout {
         RawFunctionLayout::Function(arguments, closure, result) => {
@@ -4963,7 +5014,8 @@ pub fn build_proc<'a, 'ctx, 'env>(
                         expose_alias_to_host(
                             env,
                             mod_solutions,
-                            proc.name,
+                            layout_ids,
+                            proc,
                             *alias_name,
                             *generated_function,
                             *top_level,
@@ -8193,11 +8245,71 @@ fn throw_exception<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>, message: &str) {
         )
         .into_pointer_value();
 
-    env.call_panic(cast, PanicTagId::NullTerminatedString);
+    env.call_panic(cast, CrashTag::Roc);
 
     builder.build_unreachable();
 }
 
+fn throw_user_exception<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    scope: &Scope<'a, 'ctx>,
+    message: &Symbol,
+    tag: CrashTag,
+) {
+    let message = load_symbol(scope, message);
+    let tag_id = env.context.i32_type().const_int(tag as u32 as u64, false);
+
+    // the builtin copies the message into a null-terminated string for roc_panic
+    match env.target_info.ptr_width() {
+        roc_target::PtrWidth::Bytes4 => {
+            let (a, b) = pass_list_or_string_to_zig_32bit(env, message.into_struct_value());
+
+            call_void_bitcode_fn(
+                env,
+                &[a.into(), b.into(), tag_id.into()],
+                bitcode::STR_CRASH,
+            );
+        }
+        roc_target::PtrWidth::Bytes8 => {
+            let message = pass_string_to_zig_64bit(env, message);
+
+            call_void_bitcode_fn(env, &[message.into(), tag_id.into()], bitcode::STR_CRASH);
+        }
+    }
+
+    env.builder.build_unreachable();
+}
+
+/// Passes the location and rendered value of a `dbg` to `roc_dbg`, which the host can provide
+fn report_dbg<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    scope: &Scope<'a, 'ctx>,
+    location: &Symbol,
+    message: &Symbol,
+) {
+    let location = load_symbol(scope, location);
+    let message = load_symbol(scope, message);
+
+    match env.target_info.ptr_width() {
+        roc_target::PtrWidth::Bytes4 => {
+            let (a, b) = pass_list_or_string_to_zig_32bit(env, location.into_struct_value());
+            let (c, d) = pass_list_or_string_to_zig_32bit(env, message.into_struct_value());
+
+            call_void_bitcode_fn(
+                env,
+                &[a.into(), b.into(), c.into(), d.into()],
+                bitcode::UTILS_DBG,
+            );
+        }
+        roc_target::PtrWidth::Bytes8 => {
+            let location = pass_string_to_zig_64bit(env, location);
+            let message = pass_string_to_zig_64bit(env, message);
+
+            call_void_bitcode_fn(env, &[location.into(), message.into()], bitcode::UTILS_DBG);
+        }
+    }
+}
+
 fn get_foreign_symbol<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     foreign_symbol: roc_module::ident::ForeignSymbol,
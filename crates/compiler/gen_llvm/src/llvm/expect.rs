@@ -59,6 +59,34 @@ fn write_header<'a, 'ctx, 'env>(
     offset
 }
 
+/// Records where a `crash` happened in the `roc_crash_location` global, as the region start,
+/// region end and module id, so that `roc test` can point at it when the crash is caught
+pub(crate) fn write_crash_location<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    message: Symbol,
+    region: Region,
+) {
+    let i32_type = env.context.i32_type();
+    let location_type = i32_type.array_type(3);
+
+    let global_name = "roc_crash_location";
+    let global = env.module.get_global(global_name).unwrap_or_else(|| {
+        let global = env.module.add_global(location_type, None, global_name);
+        global.set_initializer(&location_type.const_zero());
+        global
+    });
+
+    let module_id: u32 = unsafe { std::mem::transmute(message.module_id()) };
+
+    let location = i32_type.const_array(&[
+        i32_type.const_int(region.start().offset as _, false),
+        i32_type.const_int(region.end().offset as _, false),
+        i32_type.const_int(module_id as _, false),
+    ]);
+
+    env.builder.build_store(global.as_pointer_value(), location);
+}
+
 /// Read the first two 32-bit values from the shared memory,
 /// representing the total number of expect frames and the next free position
 fn read_state<'a, 'ctx, 'env>(
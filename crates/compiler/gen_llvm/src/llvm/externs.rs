@@ -1,5 +1,5 @@
 use crate::llvm::bitcode::call_void_bitcode_fn;
-use crate::llvm::build::{add_func, get_panic_msg_ptr, C_CALL_CONV};
+use crate::llvm::build::{add_func, get_panic_msg_ptr, get_panic_tag_ptr, C_CALL_CONV};
 use crate::llvm::build::{CCReturn, Env, FunctionSpec};
 use inkwell::module::Linkage;
 use inkwell::types::BasicType;
@@ -21,16 +21,23 @@ pub fn add_default_roc_externs(env: &Env<'_, '_, '_>) {
 
     match env.mode {
         super::build::LlvmBackendMode::CliTest => {
-            // expose this function
-            if let Some(fn_val) = module.get_function("set_shared_buffer") {
-                fn_val.set_linkage(Linkage::External);
+            // expose these functions
+            for name in ["set_shared_buffer", "roc_dbg"] {
+                if let Some(fn_val) = module.get_function(name) {
+                    fn_val.set_linkage(Linkage::External);
+                }
             }
         }
         _ => {
-            // remove this function from the module
+            // remove these functions from the module
             if let Some(fn_val) = module.get_function("set_shared_buffer") {
                 unsafe { fn_val.delete() };
             }
+
+            // a `dbg` reports through this; it stays weak, so that the host can provide its own
+            if let Some(fn_val) = module.get_function("roc_dbg") {
+                fn_val.set_linkage(Linkage::WeakAny);
+            }
         }
     }
 
@@ -172,8 +179,7 @@ pub fn add_sjlj_roc_panic(env: &Env<'_, '_, '_>) {
         let mut params = fn_val.get_param_iter();
         let ptr_arg = params.next().unwrap();
 
-        // in debug mode, this is assumed to be NullTerminatedString
-        let _tag_id_arg = params.next().unwrap();
+        let tag_id_arg = params.next().unwrap();
 
         debug_assert!(params.next().is_none());
 
@@ -190,6 +196,9 @@ pub fn add_sjlj_roc_panic(env: &Env<'_, '_, '_>) {
         // write our error message pointer
         env.builder.build_store(get_panic_msg_ptr(env), ptr_arg);
 
+        // and who caused the crash, so the catcher can report it
+        env.builder.build_store(get_panic_tag_ptr(env), tag_id_arg);
+
         build_longjmp_call(env);
 
         builder.build_unreachable();
@@ -2,11 +2,14 @@ use std::ffi::CStr;
 use std::mem::MaybeUninit;
 use std::os::raw::c_char;
 
+use roc_mono::ir::CrashTag;
+
 /// This must have the same size as the repr() of RocCallResult!
 pub const ROC_CALL_RESULT_DISCRIMINANT_SIZE: usize = std::mem::size_of::<u64>();
 
 #[repr(C)]
 pub struct RocCallResult<T> {
+    /// 0 on success, otherwise one more than the `CrashTag` that was passed to `roc_panic`
     tag: u64,
     error_msg: *mut c_char,
     value: MaybeUninit<T>,
@@ -45,6 +48,19 @@ impl<T: Sized> From<RocCallResult<T>> for Result<T, String> {
     }
 }
 
+impl<T: Sized> From<RocCallResult<T>> for Result<T, (String, CrashTag)> {
+    fn from(call_result: RocCallResult<T>) -> Self {
+        let crash_tag = match call_result.tag {
+            0 => return Ok(unsafe { call_result.value.assume_init() }),
+            tag => CrashTag::try_from((tag - 1) as u32).unwrap_or(CrashTag::Roc),
+        };
+
+        let raw = unsafe { CStr::from_ptr(call_result.error_msg) };
+
+        Err((raw.to_str().unwrap().to_owned(), crash_tag))
+    }
+}
+
 #[macro_export]
 macro_rules! run_roc_dylib {
     ($lib:expr, $main_fn_name:expr, $argument_type:ty, $return_type:ty) => {{
@@ -2,22 +2,22 @@ use bitvec::vec::BitVec;
 use bumpalo::collections::{String, Vec};
 
 use code_builder::Align;
-use roc_builtins::bitcode::{FloatWidth, IntWidth};
+use roc_builtins::bitcode::{self, FloatWidth, IntWidth};
 use roc_collections::all::MutMap;
 use roc_error_macros::internal_error;
 use roc_module::low_level::{LowLevel, LowLevelWrapperType};
 use roc_module::symbol::{Interns, Symbol};
 use roc_mono::code_gen_help::{CodeGenHelp, HelperOp, REFCOUNT_MAX};
 use roc_mono::ir::{
-    BranchInfo, CallType, Expr, JoinPointId, ListLiteralElement, Literal, ModifyRc, Param, Proc,
-    ProcLayout, Stmt,
+    BranchInfo, CallType, CrashTag, Expr, JoinPointId, ListLiteralElement, Literal, ModifyRc,
+    Param, Proc, ProcLayout, Stmt,
 };
 use roc_mono::layout::{Builtin, Layout, LayoutIds, TagIdIntType, UnionLayout};
 use roc_std::RocDec;
 
 use crate::layout::{CallConv, ReturnMethod, WasmLayout};
 use crate::low_level::{call_higher_order_lowlevel, LowLevelCall};
-use crate::storage::{AddressValue, Storage, StoredValue, StoredVarKind};
+use crate::storage::{AddressValue, StackMemoryLocation, Storage, StoredValue, StoredVarKind};
 use crate::wasm_module::linking::{DataSymbol, WasmObjectSymbol};
 use crate::wasm_module::sections::{
     ConstExpr, DataMode, DataSegment, Export, Global, GlobalType, Import, ImportDesc, Limits,
@@ -384,13 +384,15 @@ impl<'a> WasmBackend<'a> {
             println!("\ngenerating procedure {:?}\n", proc.name);
         }
 
-        self.append_proc_debug_name(proc.name.name());
+        let wasm_fn_index = self.append_proc_debug_name(proc.name.name());
+        self.set_source_position(proc.name.name());
 
         self.start_proc(proc);
 
         self.stmt(&proc.body);
 
         self.finalize_proc();
+        self.append_proc_local_names(wasm_fn_index);
         self.reset();
 
         if DEBUG_SETTINGS.proc_start_end {
@@ -459,7 +461,7 @@ impl<'a> WasmBackend<'a> {
         }
     }
 
-    fn append_proc_debug_name(&mut self, sym: Symbol) {
+    fn append_proc_debug_name(&mut self, sym: Symbol) -> u32 {
         let proc_index = self
             .proc_lookup
             .iter()
@@ -469,6 +471,49 @@ impl<'a> WasmBackend<'a> {
 
         let name = String::from_str_in(sym.as_str(self.interns), self.env.arena).into_bump_str();
         self.module.names.append_function(wasm_fn_index, name);
+
+        wasm_fn_index
+    }
+
+    /// If we're emitting debug info, record that the next instructions come from
+    /// the place in the Roc source where this symbol is introduced
+    fn set_source_position(&mut self, sym: Symbol) {
+        if let Some(debug_sources) = self.env.debug_sources {
+            if let Some(position) = debug_sources.position(sym) {
+                self.code_builder.set_source_position(position);
+            }
+        }
+    }
+
+    /// Name the Wasm locals after the Roc symbols stored in them, so that debuggers
+    /// can show Roc variable names. Call this before `reset` clears the storage.
+    fn append_proc_local_names(&mut self, wasm_fn_index: u32) {
+        let arena = self.env.arena;
+        let mut locals = Vec::with_capacity_in(self.storage.symbol_storage_map.len() + 2, arena);
+
+        for (sym, storage) in self.storage.symbol_storage_map.iter() {
+            let local_id = match storage {
+                StoredValue::Local { local_id, .. } => *local_id,
+                StoredValue::StackMemory {
+                    location: StackMemoryLocation::PointerArg(local_id),
+                    ..
+                } => *local_id,
+                StoredValue::StackMemory { .. } | StoredValue::VirtualMachineStack { .. } => {
+                    continue;
+                }
+            };
+            let name = String::from_str_in(sym.as_str(self.interns), arena).into_bump_str();
+            locals.push((local_id.0, name));
+        }
+
+        if let Some(frame_pointer) = self.storage.stack_frame_pointer {
+            locals.push((frame_pointer.0, "$frame_pointer"));
+        }
+        if let Some(return_var) = self.storage.return_var {
+            locals.push((return_var.0, "$return_value"));
+        }
+
+        self.module.names.append_locals(wasm_fn_index, locals);
     }
 
     /// Build a wrapper around a Roc procedure so that it can be called from Zig builtins List.map*
@@ -711,9 +756,18 @@ impl<'a> WasmBackend<'a> {
 
             Stmt::Refcounting(modify, following) => self.stmt_refcounting(modify, following),
 
+            Stmt::Dbg {
+                message,
+                location,
+                remainder,
+                ..
+            } => self.stmt_dbg(*location, *message, remainder),
+
             Stmt::Expect { .. } => todo!("expect is not implemented in the wasm backend"),
             Stmt::ExpectFx { .. } => todo!("expect-fx is not implemented in the wasm backend"),
 
+            Stmt::Crash(sym, tag, _) => self.stmt_crash(*sym, *tag),
+
             Stmt::RuntimeError(msg) => self.stmt_runtime_error(msg),
         }
     }
@@ -749,6 +803,7 @@ impl<'a> WasmBackend<'a> {
                 _ => StoredVarKind::Variable,
             };
 
+            self.set_source_position(*sym);
             self.stmt_let_store_expr(*sym, layout, expr, kind);
 
             current_stmt = *following;
@@ -984,6 +1039,24 @@ impl<'a> WasmBackend<'a> {
         self.stmt(rc_stmt);
     }
 
+    fn stmt_dbg(&mut self, location: Symbol, message: Symbol, remainder: &Stmt<'a>) {
+        // The builtin passes both strings on to roc_dbg, which the host can provide
+        self.storage
+            .load_symbols(&mut self.code_builder, &[location, message]);
+        self.call_host_fn_after_loading_args(bitcode::UTILS_DBG, 2, false);
+
+        self.stmt(remainder);
+    }
+
+    fn stmt_crash(&mut self, msg: Symbol, tag: CrashTag) {
+        // The builtin copies the message into a zero-terminated string and passes it to roc_panic
+        self.storage.load_symbols(&mut self.code_builder, &[msg]);
+        self.code_builder.i32_const(tag as i32);
+        self.call_host_fn_after_loading_args(bitcode::STR_CRASH, 2, false);
+
+        self.code_builder.unreachable_();
+    }
+
     pub fn stmt_runtime_error(&mut self, msg: &'a str) {
         // Create a zero-terminated version of the message string
         let mut bytes = Vec::with_capacity_in(msg.len() + 1, self.env.arena);
@@ -994,9 +1067,8 @@ impl<'a> WasmBackend<'a> {
         let elements_addr = self.store_bytes_in_data_section(&bytes);
 
         // Pass its address to roc_panic
-        let tag_id = 0;
         self.code_builder.i32_const(elements_addr as i32);
-        self.code_builder.i32_const(tag_id);
+        self.code_builder.i32_const(CrashTag::Roc as i32);
         self.call_host_fn_after_loading_args("roc_panic", 2, false);
 
         self.code_builder.unreachable_();
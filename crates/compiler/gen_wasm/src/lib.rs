@@ -11,17 +11,20 @@ pub mod wasm32_sized;
 use bitvec::prelude::BitVec;
 use bumpalo::collections::Vec;
 use bumpalo::{self, Bump};
+use std::path::PathBuf;
 
 use roc_collections::all::{MutMap, MutSet};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::code_gen_help::CodeGenHelp;
 use roc_mono::ir::{Proc, ProcLayout};
 use roc_mono::layout::{LayoutIds, STLayoutInterner};
+use roc_region::all::{LineInfo, Region};
 use roc_target::TargetInfo;
+use wasm_module::debug_info::{append_debug_sections, SourceFile};
 use wasm_module::parse::ParseError;
 
 use crate::backend::{ProcLookupData, ProcSource, WasmBackend};
-use crate::wasm_module::{Align, CodeBuilder, LocalId, ValueType, WasmModule};
+use crate::wasm_module::{Align, CodeBuilder, LocalId, SourcePosition, ValueType, WasmModule};
 
 const TARGET_INFO: TargetInfo = TargetInfo::default_wasm32();
 const PTR_SIZE: u32 = {
@@ -47,12 +50,58 @@ pub struct Env<'a> {
     pub module_id: ModuleId,
     pub exposed_to_host: MutSet<Symbol>,
     pub stack_bytes: u32,
+    /// If present, emit debug info mapping the generated code back to these sources
+    pub debug_sources: Option<&'a DebugSources<'a>>,
 }
 
 impl Env<'_> {
     pub const DEFAULT_STACK_BYTES: u32 = 1024 * 1024;
 }
 
+/// The Roc source files of an app, and where each of its symbols is introduced
+pub struct DebugSources<'a> {
+    files: std::vec::Vec<SourceFile<'a>>,
+    positions: MutMap<Symbol, SourcePosition>,
+}
+
+impl<'a> DebugSources<'a> {
+    pub fn new(
+        sources: &'a MutMap<ModuleId, (PathBuf, Box<str>)>,
+        symbol_regions: &MutMap<Symbol, Region>,
+    ) -> Self {
+        let mut files = std::vec::Vec::with_capacity(sources.len());
+        let mut line_infos = MutMap::default();
+
+        for (module_id, (path, src)) in sources.iter() {
+            line_infos.insert(*module_id, (files.len() as u32, LineInfo::new(src)));
+            files.push(SourceFile {
+                path: path.to_string_lossy().into_owned(),
+                contents: src,
+            });
+        }
+
+        let positions = symbol_regions
+            .iter()
+            .filter_map(|(symbol, region)| {
+                let (file, line_info) = line_infos.get(&symbol.module_id())?;
+                let line_column = line_info.convert_pos(region.start());
+                let position = SourcePosition {
+                    file: *file,
+                    line: line_column.line,
+                    column: line_column.column,
+                };
+                Some((*symbol, position))
+            })
+            .collect();
+
+        DebugSources { files, positions }
+    }
+
+    pub fn position(&self, symbol: Symbol) -> Option<SourcePosition> {
+        self.positions.get(&symbol).copied()
+    }
+}
+
 /// Parse the preprocessed host binary
 /// If successful, the module can be passed to build_app_binary
 pub fn parse_host<'a>(arena: &'a Bump, host_bytes: &[u8]) -> Result<WasmModule<'a>, ParseError> {
@@ -70,16 +119,65 @@ pub fn build_app_binary<'a>(
     host_module: WasmModule<'a>,
     procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
 ) -> std::vec::Vec<u8> {
-    let (mut wasm_module, called_preload_fns, _) =
-        build_app_module(env, interns, host_module, procedures);
+    let wasm_module = build_linked_app_module(env, interns, host_module, procedures);
 
-    wasm_module.eliminate_dead_code(env.arena, called_preload_fns);
+    serialize_app_module(env, &wasm_module)
+}
+
+/// Generate a standalone Wasm module in binary form, for a WASI runtime.
+/// WASI runtimes only provide the `wasi_snapshot_preview1` import module, so the host
+/// must define every function that it or the builtins would otherwise import from
+/// the `env` module. If any such imports are left over, their names are returned instead.
+pub fn build_wasi_app_binary<'a>(
+    env: &'a Env<'a>,
+    interns: &'a mut Interns,
+    host_module: WasmModule<'a>,
+    procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) -> Result<std::vec::Vec<u8>, Vec<'a, &'a str>> {
+    let wasm_module = build_linked_app_module(env, interns, host_module, procedures);
 
+    let unresolved = Vec::from_iter_in(
+        wasm_module
+            .import
+            .imports
+            .iter()
+            .filter(|import| import.module == BUILTINS_IMPORT_MODULE_NAME && import.is_function())
+            .map(|import| import.name),
+        env.arena,
+    );
+
+    if !unresolved.is_empty() {
+        return Err(unresolved);
+    }
+
+    Ok(serialize_app_module(env, &wasm_module))
+}
+
+fn serialize_app_module(env: &Env, wasm_module: &WasmModule) -> std::vec::Vec<u8> {
     let mut buffer = std::vec::Vec::with_capacity(wasm_module.size());
     wasm_module.serialize(&mut buffer);
+
+    if let Some(debug_sources) = env.debug_sources {
+        append_debug_sections(&mut buffer, wasm_module, &debug_sources.files);
+    }
+
     buffer
 }
 
+fn build_linked_app_module<'a>(
+    env: &'a Env<'a>,
+    interns: &'a mut Interns,
+    host_module: WasmModule<'a>,
+    procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) -> WasmModule<'a> {
+    let (mut wasm_module, called_preload_fns, _) =
+        build_app_module(env, interns, host_module, procedures);
+
+    wasm_module.eliminate_dead_code(env.arena, called_preload_fns);
+
+    wasm_module
+}
+
 /// Generate an unserialized Wasm module
 /// Shared by all consumers of gen_wasm: roc_build, roc_repl_wasm, and test_gen
 /// (roc_repl_wasm and test_gen will add more generated code for a wrapper function
@@ -15,7 +15,7 @@ use crate::wasm_module::{
     linking::SymInfo, linking::WasmObjectSymbol, Align, CodeBuilder, Export, ExportType, LocalId,
     Signature, ValueType, WasmModule,
 };
-use roc_std::{RocDec, RocList, RocOrder, RocResult, RocStr, I128, U128};
+use roc_std::{RocDec, RocDict, RocList, RocOrder, RocResult, RocStr, I128, U128};
 
 /// Type-driven wrapper generation
 pub trait Wasm32Result {
@@ -204,6 +204,12 @@ impl<T: Wasm32Result> Wasm32Result for RocList<T> {
     }
 }
 
+impl<K: Wasm32Sized, V: Wasm32Sized> Wasm32Result for RocDict<K, V> {
+    fn build_wrapper_body(code_builder: &mut CodeBuilder, main_function_index: u32) {
+        build_wrapper_body_stack_memory(code_builder, main_function_index, 12)
+    }
+}
+
 impl<T: Wasm32Sized, E: Wasm32Sized> Wasm32Result for RocResult<T, E> {
     fn build_wrapper_body(code_builder: &mut CodeBuilder, main_function_index: u32) {
         build_wrapper_body_stack_memory(
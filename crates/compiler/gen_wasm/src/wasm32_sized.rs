@@ -1,4 +1,4 @@
-use roc_std::{RocDec, RocList, RocOrder, RocResult, RocStr, I128, U128};
+use roc_std::{RocDec, RocDict, RocDictItem, RocList, RocOrder, RocResult, RocStr, I128, U128};
 
 pub trait Wasm32Sized: Sized {
     const SIZE_OF_WASM: usize;
@@ -45,6 +45,16 @@ impl<T: Wasm32Sized> Wasm32Sized for RocList<T> {
     const ALIGN_OF_WASM: usize = 4;
 }
 
+impl<K: Wasm32Sized, V: Wasm32Sized> Wasm32Sized for RocDict<K, V> {
+    const SIZE_OF_WASM: usize = 12;
+    const ALIGN_OF_WASM: usize = 4;
+}
+
+impl<K: Wasm32Sized, V: Wasm32Sized> Wasm32Sized for RocDictItem<K, V> {
+    const SIZE_OF_WASM: usize = K::ACTUAL_WIDTH + V::ACTUAL_WIDTH;
+    const ALIGN_OF_WASM: usize = max(&[K::ALIGN_OF_WASM, V::ALIGN_OF_WASM]);
+}
+
 impl<T: Wasm32Sized, E: Wasm32Sized> Wasm32Sized for RocResult<T, E> {
     const ALIGN_OF_WASM: usize = max(&[T::ALIGN_OF_WASM, E::ALIGN_OF_WASM]);
     const SIZE_OF_WASM: usize = max(&[T::ACTUAL_WIDTH, E::ACTUAL_WIDTH]) + 1;
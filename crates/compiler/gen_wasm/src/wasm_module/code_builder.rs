@@ -127,6 +127,14 @@ pub enum VmSymbolState {
 }
 
 // An instruction (local.set or local.tee) to be inserted into the function code
+/// A position in a source file, for debug info. All fields are zero-based.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourcePosition {
+    pub file: u32,
+    pub line: u32,
+    pub column: u32,
+}
+
 #[derive(Debug)]
 struct Insertion {
     at: usize,
@@ -182,6 +190,9 @@ pub struct CodeBuilder<'a> {
     /// Relocations for calls to JS imports
     /// When we remove unused imports, the live ones are re-indexed
     import_relocations: Vec<'a, (usize, u32)>,
+
+    /// Source positions of the code from each offset in `code` onwards, for debug info
+    source_positions: Vec<'a, (usize, SourcePosition)>,
 }
 
 impl<'a> Serialize for CodeBuilder<'a> {
@@ -209,6 +220,7 @@ impl<'a> CodeBuilder<'a> {
             inner_length: Vec::with_capacity_in(5, arena),
             vm_block_stack,
             import_relocations: Vec::with_capacity_in(0, arena),
+            source_positions: Vec::with_capacity_in(0, arena),
         }
     }
 
@@ -523,6 +535,41 @@ impl<'a> CodeBuilder<'a> {
         self.insertions.sort_by_key(|ins| ins.at);
     }
 
+    /**********************************************************
+
+        DEBUG INFO
+
+    ***********************************************************/
+
+    /// Record that the instructions generated from now on come from this source position
+    pub fn set_source_position(&mut self, position: SourcePosition) {
+        let offset = self.code.len();
+        match self.source_positions.last_mut() {
+            Some((_, last_position)) if *last_position == position => {}
+            Some((last_offset, last_position)) if *last_offset == offset => {
+                *last_position = position;
+            }
+            _ => self.source_positions.push((offset, position)),
+        }
+    }
+
+    /// Source positions at byte offsets from the start of the serialized function
+    /// Only valid after `build_fn_header_and_footer`
+    pub fn serialized_source_positions(
+        &self,
+    ) -> impl Iterator<Item = (usize, SourcePosition)> + '_ {
+        let header_len = self.inner_length.len() + self.preamble.len();
+        let mut insertions = self.insertions.iter().peekable();
+        let mut inserted_len = 0;
+
+        self.source_positions.iter().map(move |(offset, position)| {
+            while let Some(ins) = insertions.next_if(|ins| ins.at <= *offset) {
+                inserted_len += ins.end - ins.start;
+            }
+            (header_len + inserted_len + offset, *position)
+        })
+    }
+
     /**********************************************************
 
         SERIALIZE
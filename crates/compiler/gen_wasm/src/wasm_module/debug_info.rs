@@ -0,0 +1,561 @@
+//! Debug info that maps the code of a module back to its source files.
+//!
+//! We emit two formats, each in its own custom sections:
+//! - DWARF line tables, for debuggers like LLDB or browser devtools with a DWARF extension.
+//!   https://yurydelendik.github.io/webassembly-dwarf/
+//! - A source map, for browser devtools. The map is embedded as a data URL in the
+//!   `sourceMappingURL` section, so there is no extra file to serve.
+//!   https://sourcemaps.info/spec.html
+//!
+//! DWARF addresses are offsets from the start of the Code section body.
+//! Source map "columns" are offsets from the start of the module. (It only has one "line".)
+
+use std::fmt::Write;
+
+use roc_error_macros::internal_error;
+
+use super::parse::Parse;
+use super::sections::{update_section_size, write_custom_section_header, SectionId};
+use super::serialize::SerialBuffer;
+use super::{SourcePosition, WasmModule};
+
+/// A source file that positions refer to by its index
+#[derive(Debug)]
+pub struct SourceFile<'a> {
+    pub path: String,
+    pub contents: &'a str,
+}
+
+/// A source position for each address in one function, from that address until the next row
+#[derive(Debug, PartialEq, Eq)]
+struct FunctionLines {
+    /// Offset of the end of the function in the module
+    end: usize,
+    /// Offsets in the module where each source position starts, in increasing order
+    rows: Vec<(usize, SourcePosition)>,
+}
+
+/// Append DWARF and source map sections to a serialized module, covering the code
+/// generated by the CodeBuilders of `module`.
+pub fn append_debug_sections(buffer: &mut Vec<u8>, module: &WasmModule, files: &[SourceFile]) {
+    let (code_section_start, code_section_end) = find_code_section(buffer);
+
+    // Generated functions come last in the Code section, after any preloaded ones
+    let generated_size: usize = module.code.code_builders.iter().map(|cb| cb.size()).sum();
+    let mut fn_start = code_section_end - generated_size;
+
+    let mut functions = Vec::with_capacity(module.code.code_builders.len());
+    for code_builder in module.code.code_builders.iter() {
+        let fn_end = fn_start + code_builder.size();
+        let rows: Vec<_> = code_builder
+            .serialized_source_positions()
+            .map(|(offset, position)| (fn_start + offset, position))
+            .collect();
+
+        if !rows.is_empty() {
+            functions.push(FunctionLines { end: fn_end, rows });
+        }
+
+        fn_start = fn_end;
+    }
+
+    let used_files = remove_unused_files(files, &mut functions);
+
+    append_dwarf_sections(buffer, code_section_start, &used_files, &functions);
+    append_source_map_section(buffer, &used_files, &functions);
+}
+
+/// Find the start and end of the Code section body
+fn find_code_section(bytes: &[u8]) -> (usize, usize) {
+    let mut cursor = 8;
+    while cursor < bytes.len() {
+        let id = bytes[cursor];
+        cursor += 1;
+        let size = u32::parse((), bytes, &mut cursor)
+            .unwrap_or_else(|e| internal_error!("{}", e.message)) as usize;
+
+        if id == SectionId::Code as u8 {
+            return (cursor, cursor + size);
+        }
+        cursor += size;
+    }
+
+    internal_error!("Tried to generate debug info for a module without a Code section")
+}
+
+/// Keep only the files that some position refers to, so that we don't embed the
+/// contents of every builtin module, and renumber the positions to match.
+fn remove_unused_files<'a, 'f>(
+    files: &'f [SourceFile<'a>],
+    functions: &mut [FunctionLines],
+) -> Vec<&'f SourceFile<'a>> {
+    let mut new_indices: Vec<Option<u32>> = vec![None; files.len()];
+    let mut used_files = Vec::new();
+
+    for (_, position) in functions.iter_mut().flat_map(|f| f.rows.iter_mut()) {
+        let old_index = position.file as usize;
+        position.file = match new_indices[old_index] {
+            Some(new_index) => new_index,
+            None => {
+                let new_index = used_files.len() as u32;
+                new_indices[old_index] = Some(new_index);
+                used_files.push(&files[old_index]);
+                new_index
+            }
+        };
+    }
+
+    used_files
+}
+
+/*******************************************************************
+ *
+ * DWARF
+ *
+ *******************************************************************/
+
+const DWARF_VERSION: u16 = 4;
+const ADDRESS_SIZE: u8 = 4;
+
+const DW_TAG_COMPILE_UNIT: u32 = 0x11;
+const DW_CHILDREN_NO: u8 = 0;
+
+const DW_AT_NAME: u32 = 0x03;
+const DW_AT_STMT_LIST: u32 = 0x10;
+const DW_AT_LOW_PC: u32 = 0x11;
+const DW_AT_HIGH_PC: u32 = 0x12;
+const DW_AT_LANGUAGE: u32 = 0x13;
+const DW_AT_PRODUCER: u32 = 0x25;
+
+const DW_FORM_ADDR: u32 = 0x01;
+const DW_FORM_DATA2: u32 = 0x05;
+const DW_FORM_DATA4: u32 = 0x06;
+const DW_FORM_STRING: u32 = 0x08;
+const DW_FORM_SEC_OFFSET: u32 = 0x17;
+
+/// There is no language code for Roc. Debuggers only step by line if they know the
+/// language, so we claim to be C, which has no effect on the line table itself.
+const DW_LANG_C99: u16 = 0x0c;
+
+const DW_LNS_COPY: u8 = 0x01;
+const DW_LNS_ADVANCE_PC: u8 = 0x02;
+const DW_LNS_ADVANCE_LINE: u8 = 0x03;
+const DW_LNS_SET_FILE: u8 = 0x04;
+const DW_LNS_SET_COLUMN: u8 = 0x05;
+const DW_LNE_END_SEQUENCE: u8 = 0x01;
+const DW_LNE_SET_ADDRESS: u8 = 0x02;
+
+const LINE_BASE: i8 = -5;
+const LINE_RANGE: u8 = 14;
+const OPCODE_BASE: u8 = 13;
+const STANDARD_OPCODE_LENGTHS: [u8; OPCODE_BASE as usize - 1] =
+    [0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1];
+
+fn append_dwarf_sections(
+    buffer: &mut Vec<u8>,
+    code_section_start: usize,
+    files: &[&SourceFile],
+    functions: &[FunctionLines],
+) {
+    let (low_pc, high_pc) = match (functions.first(), functions.last()) {
+        (Some(first), Some(last)) => (first.rows[0].0, last.end),
+        _ => return,
+    };
+
+    // .debug_abbrev: the only entry we use is a compile unit without children
+    let header_indices = write_custom_section_header(buffer, ".debug_abbrev");
+    buffer.encode_u32(1);
+    buffer.encode_u32(DW_TAG_COMPILE_UNIT);
+    buffer.append_u8(DW_CHILDREN_NO);
+    for (attribute, form) in [
+        (DW_AT_PRODUCER, DW_FORM_STRING),
+        (DW_AT_LANGUAGE, DW_FORM_DATA2),
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_STMT_LIST, DW_FORM_SEC_OFFSET),
+        (DW_AT_LOW_PC, DW_FORM_ADDR),
+        (DW_AT_HIGH_PC, DW_FORM_DATA4),
+    ] {
+        buffer.encode_u32(attribute);
+        buffer.encode_u32(form);
+    }
+    buffer.encode_u32(0);
+    buffer.encode_u32(0);
+    buffer.encode_u32(0);
+    update_section_size(buffer, header_indices);
+
+    // .debug_info: one compile unit for the whole module
+    let mut unit: Vec<u8> = Vec::with_capacity(64);
+    unit.extend_from_slice(&DWARF_VERSION.to_le_bytes());
+    unit.write_unencoded_u32(0); // offset of the abbreviations
+    unit.append_u8(ADDRESS_SIZE);
+    unit.encode_u32(1); // abbreviation code
+    write_c_string(&mut unit, "roc");
+    unit.extend_from_slice(&DW_LANG_C99.to_le_bytes());
+    write_c_string(&mut unit, &files[0].path);
+    unit.write_unencoded_u32(0); // offset of the line program
+    unit.write_unencoded_u32((low_pc - code_section_start) as u32);
+    unit.write_unencoded_u32((high_pc - low_pc) as u32);
+
+    let header_indices = write_custom_section_header(buffer, ".debug_info");
+    buffer.write_unencoded_u32(unit.len() as u32);
+    buffer.extend_from_slice(&unit);
+    update_section_size(buffer, header_indices);
+
+    // .debug_line: a line program with one sequence per function
+    let mut header: Vec<u8> = Vec::with_capacity(64);
+    header.append_u8(1); // minimum_instruction_length
+    header.append_u8(1); // maximum_operations_per_instruction
+    header.append_u8(1); // default_is_stmt
+    header.append_u8(LINE_BASE as u8);
+    header.append_u8(LINE_RANGE);
+    header.append_u8(OPCODE_BASE);
+    header.extend_from_slice(&STANDARD_OPCODE_LENGTHS);
+    header.append_u8(0); // no include_directories
+    for file in files {
+        write_c_string(&mut header, &file.path);
+        header.encode_u32(0); // directory index
+        header.encode_u32(0); // modification time
+        header.encode_u32(0); // file length
+    }
+    header.append_u8(0);
+
+    let mut program: Vec<u8> = Vec::with_capacity(16 * functions.len());
+    for function in functions {
+        write_line_sequence(&mut program, code_section_start, function);
+    }
+
+    let mut unit: Vec<u8> = Vec::with_capacity(6 + header.len() + program.len());
+    unit.extend_from_slice(&DWARF_VERSION.to_le_bytes());
+    unit.write_unencoded_u32(header.len() as u32);
+    unit.extend_from_slice(&header);
+    unit.extend_from_slice(&program);
+
+    let header_indices = write_custom_section_header(buffer, ".debug_line");
+    buffer.write_unencoded_u32(unit.len() as u32);
+    buffer.extend_from_slice(&unit);
+    update_section_size(buffer, header_indices);
+}
+
+fn write_line_sequence(program: &mut Vec<u8>, code_section_start: usize, function: &FunctionLines) {
+    // Initial values of the state machine registers, in our zero-based positions
+    let mut file = 0;
+    let mut line = 0;
+    let mut column = None;
+
+    let mut address = function.rows[0].0;
+    program.append_u8(0);
+    program.encode_u32(1 + ADDRESS_SIZE as u32);
+    program.append_u8(DW_LNE_SET_ADDRESS);
+    program.write_unencoded_u32((address - code_section_start) as u32);
+
+    for (row_address, position) in function.rows.iter() {
+        if *row_address != address {
+            program.append_u8(DW_LNS_ADVANCE_PC);
+            program.encode_u32((row_address - address) as u32);
+            address = *row_address;
+        }
+        if position.file != file {
+            program.append_u8(DW_LNS_SET_FILE);
+            program.encode_u32(position.file + 1);
+            file = position.file;
+        }
+        if position.line != line {
+            program.append_u8(DW_LNS_ADVANCE_LINE);
+            program.encode_i32(position.line as i32 - line as i32);
+            line = position.line;
+        }
+        if column != Some(position.column) {
+            program.append_u8(DW_LNS_SET_COLUMN);
+            program.encode_u32(position.column + 1);
+            column = Some(position.column);
+        }
+        program.append_u8(DW_LNS_COPY);
+    }
+
+    program.append_u8(DW_LNS_ADVANCE_PC);
+    program.encode_u32((function.end - address) as u32);
+    program.append_u8(0);
+    program.encode_u32(1);
+    program.append_u8(DW_LNE_END_SEQUENCE);
+}
+
+fn write_c_string(buffer: &mut Vec<u8>, s: &str) {
+    buffer.extend_from_slice(s.as_bytes());
+    buffer.append_u8(0);
+}
+
+/*******************************************************************
+ *
+ * Source map
+ *
+ *******************************************************************/
+
+const SOURCE_MAPPING_URL: &str = "sourceMappingURL";
+
+fn append_source_map_section(
+    buffer: &mut Vec<u8>,
+    files: &[&SourceFile],
+    functions: &[FunctionLines],
+) {
+    if functions.is_empty() {
+        return;
+    }
+
+    let url = format!(
+        "data:application/json;base64,{}",
+        encode_base64(source_map_json(files, functions).as_bytes())
+    );
+
+    let header_indices = write_custom_section_header(buffer, SOURCE_MAPPING_URL);
+    buffer.encode_u32(url.len() as u32);
+    buffer.extend_from_slice(url.as_bytes());
+    update_section_size(buffer, header_indices);
+}
+
+fn source_map_json(files: &[&SourceFile], functions: &[FunctionLines]) -> String {
+    let mut json = String::from("{\"version\":3,\"sources\":[");
+    for (i, file) in files.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        write_json_string(&mut json, &file.path);
+    }
+
+    json.push_str("],\"sourcesContent\":[");
+    for (i, file) in files.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        write_json_string(&mut json, file.contents);
+    }
+
+    json.push_str("],\"names\":[],\"mappings\":\"");
+    json.push_str(&source_map_mappings(functions));
+    json.push_str("\"}");
+    json
+}
+
+/// Each segment has the fields [address, file, line, column], each relative to the previous
+/// segment. A segment with only an address marks the end of a function, so that the code in
+/// between is not attributed to any source.
+fn source_map_mappings(functions: &[FunctionLines]) -> String {
+    let mut mappings = String::with_capacity(8 * functions.len());
+    let mut prev = [0_i64; 4];
+
+    for function in functions {
+        for (address, position) in function.rows.iter() {
+            let fields = [
+                *address as i64,
+                position.file as i64,
+                position.line as i64,
+                position.column as i64,
+            ];
+            if !mappings.is_empty() {
+                mappings.push(',');
+            }
+            for (field, prev_field) in fields.iter().zip(prev.iter_mut()) {
+                encode_vlq(&mut mappings, field - *prev_field);
+                *prev_field = *field;
+            }
+        }
+
+        mappings.push(',');
+        encode_vlq(&mut mappings, function.end as i64 - prev[0]);
+        prev[0] = function.end as i64;
+    }
+
+    mappings
+}
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Base64 VLQ: 5 bits per digit, least significant first, with the sign in the lowest bit
+fn encode_vlq(out: &mut String, value: i64) {
+    let mut bits = if value < 0 {
+        ((-value as u64) << 1) | 1
+    } else {
+        (value as u64) << 1
+    };
+
+    loop {
+        let mut digit = bits & 0b1_1111;
+        bits >>= 5;
+        if bits != 0 {
+            digit |= 0b10_0000;
+        }
+        out.push(BASE64_CHARS[digit as usize] as char);
+        if bits == 0 {
+            break;
+        }
+    }
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(4 * (bytes.len() / 3 + 1));
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as usize) << 16 | (b[1] as usize) << 8 | b[2] as usize;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_CHARS[(n >> (18 - 6 * i)) & 0b11_1111] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+fn write_json_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm_module::opcodes::OpCode;
+    use crate::wasm_module::{CodeBuilder, ValueType};
+    use bumpalo::Bump;
+
+    fn pos(file: u32, line: u32, column: u32) -> SourcePosition {
+        SourcePosition { file, line, column }
+    }
+
+    #[test]
+    fn test_serialized_source_positions() {
+        let arena = Bump::new();
+        let mut code_builder = CodeBuilder::new(&arena);
+
+        code_builder.set_source_position(pos(0, 1, 0));
+        code_builder.nop();
+        code_builder.set_source_position(pos(0, 2, 4));
+        code_builder.i32_const(1);
+        code_builder.drop_();
+        code_builder.set_source_position(pos(0, 2, 4));
+        code_builder.nop();
+        code_builder.build_fn_header_and_footer(&[ValueType::I32], 0, None);
+
+        let mut bytes = Vec::new();
+        code_builder.serialize_without_relocs(&mut bytes);
+        let positions: Vec<_> = code_builder.serialized_source_positions().collect();
+
+        // function length, then one batch of one i32 local
+        assert_eq!(positions, [(4, pos(0, 1, 0)), (5, pos(0, 2, 4))]);
+        assert_eq!(bytes[4], OpCode::NOP as u8);
+        assert_eq!(bytes[5], OpCode::I32CONST as u8);
+    }
+
+    #[test]
+    fn test_encode_vlq() {
+        let mut out = String::new();
+        for value in [0, 1, -1, 15, 16, -16, 1000] {
+            encode_vlq(&mut out, value);
+            out.push(' ');
+        }
+        assert_eq!(out, "A C D e gB hB w+B ");
+    }
+
+    #[test]
+    fn test_encode_base64() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"M"), "TQ==");
+        assert_eq!(encode_base64(b"Ma"), "TWE=");
+        assert_eq!(encode_base64(b"Man"), "TWFu");
+        assert_eq!(encode_base64(b"Many"), "TWFueQ==");
+    }
+
+    #[test]
+    fn test_source_map_json() {
+        let a = SourceFile {
+            path: "src/\"a\".roc".into(),
+            contents: "x = 1\n",
+        };
+        let b = SourceFile {
+            path: "b.roc".into(),
+            contents: "",
+        };
+        let functions = [
+            FunctionLines {
+                end: 120,
+                rows: vec![(100, pos(0, 0, 0)), (110, pos(0, 2, 4))],
+            },
+            FunctionLines {
+                end: 140,
+                rows: vec![(130, pos(1, 1, 0))],
+            },
+        ];
+
+        assert_eq!(
+            source_map_json(&[&a, &b], &functions),
+            r#"{"version":3,"sources":["src/\"a\".roc","b.roc"],"sourcesContent":["x = 1\n",""],"names":[],"mappings":"oGAAA,UAEI,U,UCDJ,U"}"#
+        );
+    }
+
+    #[test]
+    fn test_remove_unused_files() {
+        let files: Vec<_> = ["a", "b", "c"]
+            .iter()
+            .map(|path| SourceFile {
+                path: path.to_string(),
+                contents: "",
+            })
+            .collect();
+        let mut functions = [FunctionLines {
+            end: 10,
+            rows: vec![(0, pos(2, 0, 0)), (5, pos(0, 1, 0)), (8, pos(2, 3, 0))],
+        }];
+
+        let used_files = remove_unused_files(&files, &mut functions);
+
+        let paths: Vec<_> = used_files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["c", "a"]);
+        assert_eq!(
+            functions[0].rows,
+            [(0, pos(0, 0, 0)), (5, pos(1, 1, 0)), (8, pos(0, 3, 0))]
+        );
+    }
+
+    #[test]
+    fn test_line_sequence() {
+        let function = FunctionLines {
+            end: 130,
+            rows: vec![(110, pos(0, 2, 4)), (120, pos(1, 0, 4))],
+        };
+        let mut program = Vec::new();
+        write_line_sequence(&mut program, 100, &function);
+
+        #[rustfmt::skip]
+        let expected = [
+            0, 5, DW_LNE_SET_ADDRESS, 10, 0, 0, 0,
+            DW_LNS_ADVANCE_LINE, 2,
+            DW_LNS_SET_COLUMN, 5,
+            DW_LNS_COPY,
+            DW_LNS_ADVANCE_PC, 10,
+            DW_LNS_SET_FILE, 2,
+            DW_LNS_ADVANCE_LINE, 0x7e,
+            DW_LNS_COPY,
+            DW_LNS_ADVANCE_PC, 10,
+            0, 1, DW_LNE_END_SEQUENCE,
+        ];
+        assert_eq!(program, expected);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod code_builder;
+pub mod debug_info;
 pub mod linking;
 pub mod opcodes;
 pub mod parse;
@@ -7,7 +8,7 @@ pub mod serialize;
 
 use std::iter::repeat;
 
-pub use code_builder::{Align, CodeBuilder, LocalId, ValueType, VmSymbolState};
+pub use code_builder::{Align, CodeBuilder, LocalId, SourcePosition, ValueType, VmSymbolState};
 pub use linking::{OffsetRelocType, RelocationEntry, SymInfo};
 pub use sections::{ConstExpr, Export, ExportType, Global, GlobalType, Signature};
 
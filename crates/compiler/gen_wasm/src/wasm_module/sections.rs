@@ -1462,6 +1462,9 @@ enum NameSubSections {
 
 pub struct NameSection<'a> {
     pub function_names: Vec<'a, (u32, &'a str)>,
+    /// Names of each function's locals (including its arguments), by function index.
+    /// We only generate these for Roc procedures, so the host's local names are not preloaded.
+    pub local_names: Vec<'a, (u32, Vec<'a, (u32, &'a str)>)>,
 }
 
 impl<'a> NameSection<'a> {
@@ -1469,19 +1472,50 @@ impl<'a> NameSection<'a> {
     const NAME: &'static str = "name";
 
     pub fn size(&self) -> usize {
-        self.function_names
+        let function_names_size: usize = self
+            .function_names
             .iter()
             .map(|(_, s)| MAX_SIZE_ENCODED_U32 + s.len())
-            .sum()
+            .sum();
+
+        let local_names_size: usize = self
+            .local_names
+            .iter()
+            .map(|(_, locals)| {
+                2 * MAX_SIZE_ENCODED_U32
+                    + locals
+                        .iter()
+                        .map(|(_, s)| MAX_SIZE_ENCODED_U32 + s.len())
+                        .sum::<usize>()
+            })
+            .sum();
+
+        function_names_size + local_names_size
     }
 
     pub fn append_function(&mut self, index: u32, name: &'a str) {
         self.function_names.push((index, name));
     }
 
+    /// Record the names of a function's locals. Functions must be appended in index order.
+    pub fn append_locals(&mut self, fn_index: u32, mut locals: Vec<'a, (u32, &'a str)>) {
+        if locals.is_empty() {
+            return;
+        }
+        debug_assert!(self
+            .local_names
+            .last()
+            .map_or(true, |(prev_index, _)| *prev_index < fn_index));
+
+        locals.sort_unstable();
+        locals.dedup_by_key(|(local_index, _)| *local_index);
+        self.local_names.push((fn_index, locals));
+    }
+
     pub fn empty(arena: &'a Bump) -> Self {
         NameSection {
             function_names: bumpalo::vec![in arena],
+            local_names: bumpalo::vec![in arena],
         }
     }
 
@@ -1507,7 +1541,10 @@ impl<'a> NameSection<'a> {
         let mut function_names = Vec::from_iter_in(names, arena);
         function_names.sort_by_key(|(idx, _name)| *idx);
 
-        NameSection { function_names }
+        NameSection {
+            function_names,
+            local_names: bumpalo::vec![in arena],
+        }
     }
 }
 
@@ -1566,6 +1603,7 @@ impl<'a> Parse<&'a Bump> for NameSection<'a> {
         let count = u32::parse((), module_bytes, cursor)?;
         let mut section = NameSection {
             function_names: Vec::with_capacity_in(count as usize, arena),
+            local_names: bumpalo::vec![in arena],
         };
 
         // Function names
@@ -1599,6 +1637,25 @@ impl<'a> Serialize for NameSection<'a> {
                 (buffer.size() - subsection_start) as u32,
             );
 
+            if !self.local_names.is_empty() {
+                let subsection_id = NameSubSections::LocalNames as u8;
+                subsection_id.serialize(buffer);
+
+                let subsection_size_index = buffer.encode_padded_u32(0);
+                let subsection_start = buffer.size();
+
+                buffer.encode_u32(self.local_names.len() as u32);
+                for (fn_index, locals) in self.local_names.iter() {
+                    fn_index.serialize(buffer);
+                    locals.serialize(buffer);
+                }
+
+                buffer.overwrite_padded_u32(
+                    subsection_size_index,
+                    (buffer.size() - subsection_start) as u32,
+                );
+            }
+
             update_section_size(buffer, header_indices);
         }
     }
@@ -1612,6 +1669,13 @@ impl<'a> Debug for NameSection<'a> {
             writeln!(f, "  {:4}: {}", index, name)?;
         }
 
+        for (fn_index, locals) in self.local_names.iter() {
+            writeln!(f, "  locals of {}:", fn_index)?;
+            for (index, name) in locals.iter() {
+                writeln!(f, "    {:4}: {}", index, name)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -1670,4 +1734,29 @@ mod tests {
         }
         test_assert_types_preload(arena, &section);
     }
+
+    #[test]
+    fn test_name_section_local_names() {
+        let arena = &Bump::new();
+        let mut section = NameSection::empty(arena);
+        section.append_function(0, "host_fn");
+        section.append_function(1, "roc_fn");
+        section.append_locals(
+            1,
+            bumpalo::vec![in arena; (1, "y"), (0, "x"), (1, "y_copy")],
+        );
+
+        assert_eq!(section.local_names[0].1.as_slice(), &[(0, "x"), (1, "y")]);
+
+        let mut serialized = Vec::with_capacity_in(section.size(), arena);
+        section.serialize(&mut serialized);
+
+        // The local names subsection comes after the function names, and the parser skips it
+        let mut cursor = 0;
+        let preloaded = NameSection::parse(arena, &serialized, &mut cursor).unwrap();
+
+        assert_eq!(cursor, serialized.len());
+        assert_eq!(preloaded.function_names, section.function_names);
+        assert!(preloaded.local_names.is_empty());
+    }
 }
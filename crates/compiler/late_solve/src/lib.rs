@@ -148,7 +148,7 @@ impl<'a> AbilityResolver for LateResolver<'a> {
         impl_key: roc_can::abilities::ImplKey,
     ) -> Option<roc_types::types::MemberImpl> {
         self.abilities
-            .with_module_abilities_store(impl_key.opaque.module_id(), |store| {
+            .with_module_abilities_store(impl_key.home(), |store| {
                 store.get_implementation(impl_key).copied()
             })
     }
@@ -225,6 +225,19 @@ impl Phase for LatePhase<'_> {
                     .export_variable_to_directly_to_use_site(target_subs, ambient_function);
                 let our_ambient_function_var = copied.variable;
 
+                // This copy is instantiated at its use site, so any rigid variables in it (as in
+                // a default implementation, generic over its ability) are free to be unified.
+                for var in copied.rigid.iter().chain(copied.rigid_able.iter()) {
+                    let flex_content = match *target_subs.get_content_without_compacting(*var) {
+                        Content::RigidVar(name) => Content::FlexVar(Some(name)),
+                        Content::RigidAbleVar(name, ability) => {
+                            Content::FlexAbleVar(Some(name), ability)
+                        }
+                        _ => continue,
+                    };
+                    target_subs.set_content(*var, flex_content);
+                }
+
                 debug_assert!(matches!(
                     target_subs.get_content_without_compacting(our_ambient_function_var),
                     Content::Structure(FlatType::Func(..))
@@ -16,8 +16,8 @@ const SKIP_SUBS_CACHE: bool = {
 
 pub use roc_load_internal::docs;
 pub use roc_load_internal::file::{
-    EntryPoint, ExecutionMode, Expectations, LoadConfig, LoadResult, LoadStart, LoadedModule,
-    LoadingProblem, MonomorphizedModule, Phase, Threading,
+    EntryPoint, ExecutionMode, Expectations, InferredHoles, LoadConfig, LoadResult, LoadStart,
+    LoadedModule, LoadingProblem, MonomorphizedModule, Phase, Threading,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -128,6 +128,29 @@ pub fn load_and_typecheck(
     }
 }
 
+/// Like [load_and_typecheck], for a module of a package whose header gives its dependencies
+/// these shorthands, e.g. `("json", "json/main.roc")`, so that the module can import from them.
+pub fn load_and_typecheck_in_package<'a>(
+    arena: &'a Bump,
+    filename: PathBuf,
+    shorthands: &[(&'a str, &'a str)],
+    exposed_types: ExposedByModule,
+    load_config: LoadConfig,
+) -> Result<LoadedModule, LoadingProblem<'a>> {
+    use LoadResult::*;
+
+    let load_start = LoadStart::from_path(arena, filename, load_config.render)?.with_shorthands(
+        shorthands
+            .iter()
+            .map(|(shorthand, package_path)| (*shorthand, (*package_path).into())),
+    );
+
+    match load(arena, load_start, exposed_types, load_config)? {
+        Monomorphized(_) => unreachable!(""),
+        TypeChecked(module) => Ok(module),
+    }
+}
+
 pub fn load_and_typecheck_str<'a>(
     arena: &'a Bump,
     filename: PathBuf,
@@ -1,5 +1,7 @@
 use crate::docs::DocEntry::DetachedDoc;
-use crate::docs::TypeAnnotation::{Apply, BoundVariable, Function, NoTypeAnn, Record, TagUnion};
+use crate::docs::TypeAnnotation::{
+    Apply, BoundVariable, Function, NoTypeAnn, Record, TagUnion, Tuple,
+};
 use crate::file::LoadedModule;
 use roc_can::scope::Scope;
 use roc_module::ident::ModuleName;
@@ -7,6 +9,7 @@ use roc_module::symbol::IdentIds;
 use roc_parse::ast::AssignedField;
 use roc_parse::ast::{self, ExtractSpaces, TypeHeader};
 use roc_parse::ast::{CommentOrNewline, TypeDef, ValueDef};
+use roc_region::all::Region;
 
 // Documentation generation requirements
 
@@ -37,6 +40,79 @@ pub struct DocDef {
     pub type_vars: Vec<String>,
     pub type_annotation: TypeAnnotation,
     pub docs: Option<String>,
+    /// The region of the definition itself; its docs are on the lines right above it.
+    pub region: Region,
+}
+
+/// A code example in a doc comment, which is written using `>>>` like so:
+///
+/// ```text
+/// ## >>> List.reverse [1, 2, 3]
+/// ## [3, 2, 1]
+/// ```
+///
+/// Consecutive `>>>` lines are all part of the same example. If the line right after the
+/// example isn't blank, it's the value that the example should evaluate to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocExample {
+    pub code: String,
+    pub expected: Option<String>,
+    /// The line of the doc comment that the example starts on, counting from 0.
+    pub line: usize,
+}
+
+/// Find the `>>>` code examples in the given docs.
+pub fn doc_examples(docs: &str) -> Vec<DocExample> {
+    let mut examples = Vec::new();
+    let mut lines = docs.lines().enumerate().peekable();
+
+    while let Some((line, text)) = lines.next() {
+        let mut code_lines = match text.trim_start().strip_prefix(">>>") {
+            Some(code_line) => vec![code_line],
+            None => continue,
+        };
+
+        while let Some(code_line) = lines
+            .peek()
+            .and_then(|(_, text)| text.trim_start().strip_prefix(">>>"))
+        {
+            code_lines.push(code_line);
+            lines.next();
+        }
+
+        let expected = match lines.peek() {
+            Some((_, text)) if !text.trim().is_empty() => {
+                let expected = text.trim().to_string();
+
+                lines.next();
+
+                Some(expected)
+            }
+            _ => None,
+        };
+
+        // Keep the indentation of the lines relative to each other, e.g. for pipelines
+        let indent = code_lines
+            .iter()
+            .filter(|code_line| !code_line.trim().is_empty())
+            .map(|code_line| code_line.len() - code_line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+
+        let code = code_lines
+            .iter()
+            .map(|code_line| code_line.get(indent..).unwrap_or("").trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        examples.push(DocExample {
+            code,
+            expected,
+            line,
+        });
+    }
+
+    examples
 }
 
 #[derive(Debug, Clone)]
@@ -60,6 +136,9 @@ pub enum TypeAnnotation {
         fields: Vec<RecordField>,
         extension: Box<TypeAnnotation>,
     },
+    Tuple {
+        elems: Vec<TypeAnnotation>,
+    },
     Ability {
         members: Vec<AbilityMember>,
     },
@@ -160,6 +239,7 @@ fn generate_entry_docs<'a>(
         scratchpad.extend(spaces_before);
 
         let docs = comments_or_new_lines_to_docs(&scratchpad);
+        let region = defs.regions[index];
 
         match either_index.split() {
             Err(value_index) => match &defs.value_defs[value_index.index()] {
@@ -173,6 +253,7 @@ fn generate_entry_docs<'a>(
                                 type_annotation: type_to_docs(false, loc_ann.value),
                                 type_vars: Vec::new(),
                                 docs,
+                                region,
                             };
                             acc.push(DocEntry::DocDef(doc_def));
                         }
@@ -192,6 +273,7 @@ fn generate_entry_docs<'a>(
                                 type_annotation: type_to_docs(false, ann_type.value),
                                 type_vars: Vec::new(),
                                 docs,
+                                region,
                             };
                             acc.push(DocEntry::DocDef(doc_def));
                         }
@@ -226,6 +308,7 @@ fn generate_entry_docs<'a>(
                         type_annotation: type_to_docs(false, ann.value),
                         type_vars,
                         docs,
+                        region,
                     };
                     acc.push(DocEntry::DocDef(doc_def));
                 }
@@ -247,6 +330,7 @@ fn generate_entry_docs<'a>(
                         type_annotation: TypeAnnotation::NoTypeAnn,
                         type_vars,
                         docs,
+                        region,
                     };
                     acc.push(DocEntry::DocDef(doc_def));
                 }
@@ -285,9 +369,13 @@ fn generate_entry_docs<'a>(
                         type_annotation: TypeAnnotation::Ability { members },
                         type_vars,
                         docs,
+                        region,
                     };
                     acc.push(DocEntry::DocDef(doc_def));
                 }
+
+                // Implementations of abilities for builtin types introduce no new names.
+                TypeDef::BuiltinImpls { .. } => {}
             },
         }
 
@@ -362,6 +450,12 @@ fn type_to_docs(in_func_type_ann: bool, type_annotation: ast::TypeAnnotation) ->
                 extension: Box::new(extension),
             }
         }
+        ast::TypeAnnotation::Tuple(elems) => Tuple {
+            elems: elems
+                .iter()
+                .map(|elem| type_to_docs(in_func_type_ann, elem.value))
+                .collect(),
+        },
         ast::TypeAnnotation::SpaceBefore(&sub_type_ann, _) => {
             type_to_docs(in_func_type_ann, sub_type_ann)
         }
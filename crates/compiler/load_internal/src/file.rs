@@ -11,7 +11,7 @@ use roc_can::expr::Declarations;
 use roc_can::expr::PendingDerives;
 use roc_can::module::{
     canonicalize_module_defs, ExposedByModule, ExposedForModule, ExposedModuleTypes, Module,
-    ResolvedImplementations,
+    ResolvedDefaultImplementations, ResolvedImplementations,
 };
 use roc_collections::{default_hasher, BumpMap, MutMap, MutSet, VecMap, VecSet};
 use roc_constrain::module::constrain_module;
@@ -45,9 +45,13 @@ use roc_parse::module::module_defs;
 use roc_parse::parser::{FileError, Parser, SyntaxError};
 use roc_region::all::{LineInfo, Loc, Region};
 use roc_reporting::report::RenderTarget;
-use roc_solve::module::{extract_module_owned_implementations, Solved, SolvedModule};
+use roc_solve::module::{
+    extract_module_owned_default_implementations, extract_module_owned_implementations, Solved,
+    SolvedModule,
+};
 use roc_solve_problem::TypeError;
 use roc_target::TargetInfo;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
 use roc_types::subs::{ExposedTypesStorageSubs, Subs, VarStore, Variable};
 use roc_types::types::{Alias, AliasKind};
 use std::collections::hash_map::Entry::{Occupied, Vacant};
@@ -162,6 +166,7 @@ struct ModuleCache<'a> {
     late_specializations: MutMap<ModuleId, LateSpecializationsModule<'a>>,
     external_specializations_requested: MutMap<ModuleId, Vec<ExternalSpecializations<'a>>>,
     expectations: VecMap<ModuleId, Expectations>,
+    inferred_holes: MutMap<ModuleId, InferredHoles>,
 
     /// Various information
     imports: MutMap<ModuleId, MutSet<ModuleId>>,
@@ -234,6 +239,7 @@ impl Default for ModuleCache<'_> {
             type_problems: Default::default(),
             sources: Default::default(),
             expectations: Default::default(),
+            inferred_holes: Default::default(),
         }
     }
 }
@@ -479,6 +485,7 @@ fn start_phase<'a>(
                     abilities_store,
                     // TODO: awful, how can we get rid of the clone?
                     exposed_by_module: state.exposed_types.clone(),
+                    exposed_idents: (*state.ident_ids_by_module).lock().clone(),
                     derived_module,
                     build_expects,
                 }
@@ -591,6 +598,7 @@ fn start_phase<'a>(
                     world_abilities: state.world_abilities.clone_ref(),
                     // TODO: awful, how can we get rid of the clone?
                     exposed_by_module: state.exposed_types.clone(),
+                    exposed_idents: (*state.ident_ids_by_module).lock().clone(),
                     derived_module,
                 }
             }
@@ -618,6 +626,10 @@ pub struct LoadedModule {
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub documentation: MutMap<ModuleId, ModuleDocumentation>,
     pub abilities_store: AbilitiesStore,
+    /// The shorthand of the package that each module from another package was imported from,
+    /// e.g. `pf` for a platform's modules
+    pub package_shorthands: MutMap<ModuleId, String>,
+    pub inferred_holes_by_id: MutMap<ModuleId, InferredHoles>,
 }
 
 impl LoadedModule {
@@ -641,6 +653,50 @@ impl LoadedModule {
             .map(|symbol| symbol.as_str(&self.interns))
             .collect()
     }
+
+    /// The solved types of the `_` placeholders in the annotations of every (non-builtin)
+    /// module, printed the way they would be written in a signature. They're ordered by the
+    /// path of their module, and then by where they are in it.
+    pub fn inferred_holes(&mut self) -> Vec<(ModuleId, Loc<String>)> {
+        let interns = &self.interns;
+        let mut holes: Vec<(ModuleId, Loc<String>)> = Vec::new();
+
+        for (module_id, inferred_holes) in self.inferred_holes_by_id.iter_mut() {
+            let subs = &mut inferred_holes.subs;
+
+            holes.extend(inferred_holes.holes.iter().map(|hole| {
+                let typ =
+                    name_and_print_var(hole.value, subs, *module_id, interns, DebugPrint::NOTHING);
+
+                (*module_id, Loc::at(hole.region, typ))
+            }));
+        }
+
+        let sources = &self.sources;
+
+        holes.sort_by(|(a_id, a), (b_id, b)| {
+            let a_path = sources.get(a_id).map(|(path, _)| path);
+            let b_path = sources.get(b_id).map(|(path, _)| path);
+
+            a_path.cmp(&b_path).then(a.region.cmp(&b.region))
+        });
+
+        holes
+    }
+
+    /// Add a [TypeError::InferredHole] to the type problems of its module for each of the
+    /// [inferred holes](Self::inferred_holes), so they get reported like any other problem.
+    pub fn add_inferred_hole_problems(&mut self) {
+        for (module_id, hole) in self.inferred_holes() {
+            self.type_problems
+                .entry(module_id)
+                .or_default()
+                .push(TypeError::InferredHole {
+                    region: hole.region,
+                    typ: hole.value,
+                });
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -733,6 +789,8 @@ pub struct MonomorphizedModule<'a> {
     pub entry_point: EntryPoint<'a>,
     pub exposed_to_host: ExposedToHost,
     pub sources: MutMap<ModuleId, (PathBuf, Box<str>)>,
+    /// Where each symbol is introduced in its module's source, for debug info
+    pub symbol_regions: MutMap<Symbol, Region>,
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub expectations: VecMap<ModuleId, Expectations>,
 }
@@ -747,11 +805,19 @@ pub enum EntryPoint<'a> {
     Test,
 }
 
+/// The `_` placeholders in a module's annotations, along with the subs their types were solved in
+#[derive(Debug)]
+pub struct InferredHoles {
+    pub holes: Vec<Loc<Variable>>,
+    pub subs: Subs,
+}
+
 #[derive(Debug)]
 pub struct Expectations {
     pub subs: roc_types::subs::Subs,
     pub path: PathBuf,
     pub expectations: VecMap<Region, Vec<(Symbol, Variable)>>,
+    pub dbgs: VecMap<Region, Variable>,
     pub ident_ids: IdentIds,
 }
 
@@ -796,6 +862,7 @@ struct ParsedModule<'a> {
 }
 
 type LocExpects = VecMap<Region, Vec<(Symbol, Variable)>>;
+type LocDbgs = VecMap<Region, Variable>;
 
 /// A message sent out _from_ a worker thread,
 /// representing a result of work done, or a request for further work
@@ -815,6 +882,7 @@ enum Msg<'a> {
         module_timing: ModuleTiming,
         abilities_store: AbilitiesStore,
         loc_expects: LocExpects,
+        loc_dbgs: LocDbgs,
     },
     FinishedAllTypeChecking {
         solved_subs: Solved<Subs>,
@@ -835,6 +903,7 @@ enum Msg<'a> {
         module_timing: ModuleTiming,
         abilities_store: AbilitiesStore,
         toplevel_expects: ToplevelExpects,
+        symbol_regions: MutMap<Symbol, Region>,
     },
     MadeSpecializations {
         module_id: ModuleId,
@@ -925,6 +994,7 @@ struct State<'a> {
     pub dependencies: Dependencies<'a>,
     pub procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
     pub toplevel_expects: ToplevelExpects,
+    pub symbol_regions: MutMap<Symbol, Region>,
     pub exposed_to_host: ExposedToHost,
 
     /// This is the "final" list of IdentIds, after canonicalization and constraint gen
@@ -984,10 +1054,16 @@ impl<'a> State<'a> {
         render: RenderTarget,
         number_of_workers: usize,
         exec_mode: ExecutionMode,
+        shorthands: MutMap<&'a str, PackageName<'a>>,
     ) -> Self {
-        let arc_shorthands = Arc::new(Mutex::new(MutMap::default()));
+        let mut dependencies = Dependencies::new(exec_mode.goal_phase());
 
-        let dependencies = Dependencies::new(exec_mode.goal_phase());
+        // These packages were given up front, so their shorthands are already resolved
+        for shorthand in shorthands.keys() {
+            dependencies.notify_package(shorthand);
+        }
+
+        let arc_shorthands = Arc::new(Mutex::new(shorthands));
 
         Self {
             root_id,
@@ -1000,6 +1076,7 @@ impl<'a> State<'a> {
             dependencies,
             procedures: MutMap::default(),
             toplevel_expects: ToplevelExpects::default(),
+            symbol_regions: MutMap::default(),
             exposed_to_host: ExposedToHost::default(),
             exposed_types,
             arc_modules,
@@ -1137,6 +1214,7 @@ enum BuildTask<'a> {
         decls: Declarations,
         exposed_to_host: ExposedToHost,
         exposed_by_module: ExposedByModule,
+        exposed_idents: IdentIdsByModule,
         abilities_store: AbilitiesStore,
         derived_module: SharedDerivedModule,
         build_expects: bool,
@@ -1150,6 +1228,7 @@ enum BuildTask<'a> {
         specializations_we_must_make: Vec<ExternalSpecializations<'a>>,
         module_timing: ModuleTiming,
         exposed_by_module: ExposedByModule,
+        exposed_idents: IdentIdsByModule,
         world_abilities: WorldAbilities,
         derived_module: SharedDerivedModule,
     },
@@ -1247,6 +1326,7 @@ pub struct LoadStart<'a> {
     root_id: ModuleId,
     root_msg: Msg<'a>,
     src_dir: PathBuf,
+    shorthands: MutMap<&'a str, PackageName<'a>>,
 }
 
 impl<'a> LoadStart<'a> {
@@ -1334,6 +1414,7 @@ impl<'a> LoadStart<'a> {
             src_dir,
             root_id,
             root_msg,
+            shorthands: MutMap::default(),
         })
     }
 
@@ -1367,8 +1448,21 @@ impl<'a> LoadStart<'a> {
             ident_ids_by_module,
             root_id,
             root_msg,
+            shorthands: MutMap::default(),
         })
     }
+
+    /// Let the root module import from the packages with these shorthands, even though its header
+    /// doesn't list them, e.g. the `json` package of a `package` header with
+    /// `packages { json: "json/main.roc" }`, when loading one of that package's modules.
+    pub fn with_shorthands<I>(mut self, shorthands: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, PackageName<'a>)>,
+    {
+        self.shorthands.extend(shorthands);
+
+        self
+    }
 }
 
 pub enum LoadResult<'a> {
@@ -1505,7 +1599,7 @@ pub fn load_single_threaded<'a>(
         root_id,
         root_msg,
         src_dir,
-        ..
+        shorthands,
     } = load_start;
 
     let (msg_tx, msg_rx) = bounded(1024);
@@ -1525,6 +1619,7 @@ pub fn load_single_threaded<'a>(
         render,
         number_of_workers,
         exec_mode,
+        shorthands,
     );
 
     // We'll add tasks to this, and then worker threads will take tasks from it.
@@ -1709,7 +1804,7 @@ fn load_multi_threaded<'a>(
         root_id,
         root_msg,
         src_dir,
-        ..
+        shorthands,
     } = load_start;
 
     let (msg_tx, msg_rx) = bounded(1024);
@@ -1744,6 +1839,7 @@ fn load_multi_threaded<'a>(
         render,
         num_workers,
         exec_mode,
+        shorthands,
     );
 
     // an arena for every worker, stored in an arena-allocated bumpalo vec to make the lifetimes work
@@ -2034,6 +2130,15 @@ fn report_unused_imported_modules<'a>(
         Occupied(entry) => entry.into_mut(),
     };
 
+    // When nothing from a module is used, the names exposed by its import are unused too, but
+    // reporting the whole import is enough.
+    existing.retain(|problem| match problem {
+        roc_problem::can::Problem::UnusedExposedImport(symbol, _) => {
+            !unused_imported_modules.contains_key(&symbol.module_id())
+        }
+        _ => true,
+    });
+
     for (unused, region) in unused_imported_modules.drain() {
         if !unused.is_builtin() {
             existing.push(roc_problem::can::Problem::UnusedImport(unused, region));
@@ -2364,6 +2469,7 @@ fn update<'a>(
             mut module_timing,
             abilities_store,
             loc_expects,
+            loc_dbgs,
         } => {
             log!("solved types for {:?}", module_id);
             module_timing.end_time = Instant::now();
@@ -2373,7 +2479,23 @@ fn update<'a>(
                 .type_problems
                 .insert(module_id, solved_module.problems);
 
-            let should_include_expects = !loc_expects.is_empty() && {
+            if !module_id.is_builtin() {
+                let holes = roc_can::traverse::find_inferred_holes(&decls);
+
+                if !holes.is_empty() {
+                    let inferred_holes = InferredHoles {
+                        holes,
+                        subs: solved_subs.clone().into_inner(),
+                    };
+
+                    state
+                        .module_cache
+                        .inferred_holes
+                        .insert(module_id, inferred_holes);
+                }
+            }
+
+            let should_include_expects = (!loc_expects.is_empty() || !loc_dbgs.is_empty()) && {
                 let modules = state.arc_modules.lock();
                 modules
                     .package_eq(module_id, state.root_id)
@@ -2385,6 +2507,7 @@ fn update<'a>(
 
                 let expectations = Expectations {
                     expectations: loc_expects,
+                    dbgs: loc_dbgs,
                     subs: solved_subs.clone().into_inner(),
                     path: path.to_owned(),
                     ident_ids: ident_ids.clone(),
@@ -2415,7 +2538,11 @@ fn update<'a>(
                         .exposed_vars_by_symbol
                         .iter()
                         .filter_map(|(k, v)| {
-                            if abilities_store.is_specialization_name(*k) {
+                            if abilities_store.is_specialization_name(*k)
+                                || abilities_store
+                                    .member_of_default_implementation(*k)
+                                    .is_some()
+                            {
                                 None
                             } else {
                                 Some((*k, *v))
@@ -2491,6 +2618,8 @@ fn update<'a>(
                     ExposedModuleTypes {
                         exposed_types_storage_subs: solved_module.exposed_types,
                         resolved_implementations: solved_module.solved_implementations,
+                        resolved_default_implementations: solved_module
+                            .solved_default_implementations,
                     },
                 );
 
@@ -2553,6 +2682,7 @@ fn update<'a>(
             module_timing,
             abilities_store,
             toplevel_expects,
+            symbol_regions,
         } => {
             log!("found specializations for {:?}", module_id);
 
@@ -2560,6 +2690,7 @@ fn update<'a>(
 
             state.toplevel_expects.pure.extend(toplevel_expects.pure);
             state.toplevel_expects.fx.extend(toplevel_expects.fx);
+            state.symbol_regions.extend(symbol_regions);
 
             state
                 .module_cache
@@ -2911,6 +3042,7 @@ fn finish_specialization<'a>(
 
     let State {
         toplevel_expects,
+        symbol_regions,
         procedures,
         module_cache,
         output_path,
@@ -3008,6 +3140,7 @@ fn finish_specialization<'a>(
         procedures,
         entry_point,
         sources,
+        symbol_regions,
         timings: state.timings,
         toplevel_expects,
     })
@@ -3025,10 +3158,16 @@ fn finish(
     documentation: MutMap<ModuleId, ModuleDocumentation>,
     abilities_store: AbilitiesStore,
 ) -> LoadedModule {
-    let module_ids = Arc::try_unwrap(state.arc_modules)
+    let package_module_ids = Arc::try_unwrap(state.arc_modules)
         .unwrap_or_else(|_| panic!("There were still outstanding Arc references to module_ids"))
-        .into_inner()
-        .into_module_ids();
+        .into_inner();
+
+    let package_shorthands = package_module_ids
+        .package_shorthands()
+        .map(|(module_id, shorthand)| (module_id, shorthand.to_string()))
+        .collect();
+
+    let module_ids = package_module_ids.into_module_ids();
 
     // Associate the ident IDs from the derived synth module
     let (_, derived_synth_ident_ids) = Arc::try_unwrap(state.derived_module)
@@ -3072,6 +3211,8 @@ fn finish(
         timings: state.timings,
         documentation,
         abilities_store,
+        package_shorthands,
+        inferred_holes_by_id: state.module_cache.inferred_holes,
     }
 }
 
@@ -3122,6 +3263,12 @@ fn load_platform_module<'a>(
                         header
                     )))
                 }
+                Ok((ast::Module::Package { header }, _parse_state)) => {
+                    Err(LoadingProblem::UnexpectedHeader(format!(
+                        "expected platform module, got Package with header\n{:?}",
+                        header
+                    )))
+                }
                 Ok((ast::Module::Platform { header }, parser_state)) => {
                     // make a `platform` module that ultimately exposes `main` to the host
                     let platform_module_msg = fabricate_platform_module(
@@ -3536,6 +3683,14 @@ fn parse_header<'a>(
             &header,
             module_timing,
         )),
+        Ok((ast::Module::Package { header }, _parse_state)) => {
+            // A package module only describes a package (its name and which modules it exposes)
+            // for tools like `roc docs`; the modules themselves get loaded one by one.
+            Err(LoadingProblem::UnexpectedHeader(format!(
+                "expected an app, interface, platform or hosted module, got Package with header\n{:?}",
+                header
+            )))
+        }
 
         Err(fail) => Err(LoadingProblem::ParsingFailed(
             fail.map_problem(SyntaxError::Header)
@@ -3649,7 +3804,7 @@ fn send_header<'a>(
         }
     };
 
-    let mut imported: Vec<(QualifiedModuleName, Vec<Ident>, Region)> =
+    let mut imported: Vec<(QualifiedModuleName, Vec<Loc<Ident>>, Region)> =
         Vec::with_capacity(imports.len());
     let mut imported_modules: MutMap<ModuleId, Region> = MutMap::default();
     let mut scope_size = 0;
@@ -3714,14 +3869,18 @@ fn send_header<'a>(
             // to the same symbols as the ones we're using here.
             let ident_ids = ident_ids_by_module.get_or_insert(module_id);
 
-            for ident in exposed_idents {
+            for Loc {
+                region: ident_region,
+                value: ident,
+            } in exposed_idents
+            {
                 let ident_id = ident_ids.get_or_insert(ident.as_str());
                 let symbol = Symbol::new(module_id, ident_id);
 
                 // Since this value is exposed, add it to our module's default scope.
                 debug_assert!(!scope.contains_key(&ident));
 
-                scope.insert(ident, (symbol, region));
+                scope.insert(ident, (symbol, ident_region));
             }
         }
 
@@ -3853,7 +4012,7 @@ fn send_header_two<'a>(
     let declared_name: ModuleName = "".into();
     let mut symbols_from_requires = Vec::with_capacity(requires.len());
 
-    let mut imported: Vec<(QualifiedModuleName, Vec<Ident>, Region)> =
+    let mut imported: Vec<(QualifiedModuleName, Vec<Loc<Ident>>, Region)> =
         Vec::with_capacity(imports.len());
     let mut imported_modules: MutMap<ModuleId, Region> = MutMap::default();
 
@@ -3931,14 +4090,18 @@ fn send_header_two<'a>(
             // to the same symbols as the ones we're using here.
             let ident_ids = ident_ids_by_module.get_or_insert(module_id);
 
-            for ident in exposed_idents {
+            for Loc {
+                region: ident_region,
+                value: ident,
+            } in exposed_idents
+            {
                 let ident_id = ident_ids.get_or_insert(ident.as_str());
                 let symbol = Symbol::new(module_id, ident_id);
 
                 // Since this value is exposed, add it to our module's default scope.
                 debug_assert!(!scope.contains_key(&ident.clone()));
 
-                scope.insert(ident, (symbol, region));
+                scope.insert(ident, (symbol, ident_region));
             }
         }
 
@@ -4170,6 +4333,7 @@ pub fn add_imports(
                 Some(ExposedModuleTypes {
                     exposed_types_storage_subs: exposed_types,
                     resolved_implementations: _,
+                    resolved_default_implementations: _,
                 }) => {
                     let variable = match exposed_types.stored_vars_by_symbol.iter().find(|(s, _)| **s == $symbol) {
                         None => {
@@ -4231,6 +4395,10 @@ pub fn add_imports(
         for (impl_key, resolved_impl) in module_types.resolved_implementations.iter() {
             pending_abilities.import_implementation(*impl_key, resolved_impl);
         }
+
+        for (default, resolved_impl) in module_types.resolved_default_implementations.iter() {
+            pending_abilities.import_default_implementation(*default, resolved_impl);
+        }
     }
 
     struct Ctx<'a> {
@@ -4260,15 +4428,35 @@ pub fn add_imports(
             Some(ExposedModuleTypes {
                 exposed_types_storage_subs: exposed_types,
                 resolved_implementations: _,
+                resolved_default_implementations,
             }) => {
                 let var = exposed_types
                     .stored_specialization_lambda_set_vars
                     .get(&lset_var)
                     .expect("Lambda set var from other module not available");
 
-                let copied_import = exposed_types
-                    .storage_subs
-                    .export_variable_to(ctx.subs, *var);
+                let is_default_implementation_lset =
+                    resolved_default_implementations
+                        .values()
+                        .any(|resolved_impl| match resolved_impl {
+                            ResolvedImpl::Impl(specialization) => specialization
+                                .specialization_lambda_sets
+                                .values()
+                                .any(|var| *var == lset_var),
+                            ResolvedImpl::Derived | ResolvedImpl::Error => false,
+                        });
+
+                // Default implementations are generic over their ability, so each type using
+                // one needs a fresh instance of its lambda sets.
+                let copied_import = if is_default_implementation_lset {
+                    exposed_types
+                        .storage_subs
+                        .export_generalized_variable_to(ctx.subs, *var)
+                } else {
+                    exposed_types
+                        .storage_subs
+                        .export_variable_to(ctx.subs, *var)
+                };
 
                 copied_import.variable
             }
@@ -4291,6 +4479,7 @@ fn run_solve_solve(
 ) -> (
     Solved<Subs>,
     ResolvedImplementations,
+    ResolvedDefaultImplementations,
     Vec<(Symbol, Variable)>,
     Vec<TypeError>,
     AbilitiesStore,
@@ -4326,7 +4515,14 @@ fn run_solve_solve(
         solve_aliases.insert(*name, alias.clone());
     }
 
-    let (solved_subs, solved_implementations, exposed_vars_by_symbol, problems, abilities_store) = {
+    let (
+        solved_subs,
+        solved_implementations,
+        solved_default_implementations,
+        exposed_vars_by_symbol,
+        problems,
+        abilities_store,
+    ) = {
         let module_id = module.module_id;
 
         let (solved_subs, solved_env, problems, abilities_store) = roc_solve::module::run_solve(
@@ -4344,10 +4540,13 @@ fn run_solve_solve(
 
         let solved_implementations =
             extract_module_owned_implementations(module_id, &abilities_store);
+        let solved_default_implementations =
+            extract_module_owned_default_implementations(module_id, &abilities_store);
 
         let is_specialization_symbol = |sym| {
             solved_implementations
                 .values()
+                .chain(solved_default_implementations.values())
                 .any(|resolved_impl| match resolved_impl {
                     ResolvedImpl::Impl(specialization) => specialization.symbol == sym,
                     ResolvedImpl::Derived | ResolvedImpl::Error => false,
@@ -4355,7 +4554,7 @@ fn run_solve_solve(
         };
 
         // Expose anything that is explicitly exposed by the header, or is a specialization of an
-        // ability.
+        // ability, including the default implementations of ability members.
         let exposed_vars_by_symbol: Vec<_> = solved_env
             .vars_by_symbol()
             .filter(|(k, _)| exposed_symbols.contains(k) || is_specialization_symbol(*k))
@@ -4364,6 +4563,7 @@ fn run_solve_solve(
         (
             solved_subs,
             solved_implementations,
+            solved_default_implementations,
             exposed_vars_by_symbol,
             problems,
             abilities_store,
@@ -4373,6 +4573,7 @@ fn run_solve_solve(
     (
         solved_subs,
         solved_implementations,
+        solved_default_implementations,
         exposed_vars_by_symbol,
         problems,
         abilities_store,
@@ -4403,9 +4604,17 @@ fn run_solve<'a>(
 
     let mut module = module;
     let loc_expects = std::mem::take(&mut module.loc_expects);
+    let loc_dbgs = std::mem::take(&mut module.loc_dbgs);
     let module = module;
 
-    let (solved_subs, solved_implementations, exposed_vars_by_symbol, problems, abilities_store) = {
+    let (
+        solved_subs,
+        solved_implementations,
+        solved_default_implementations,
+        exposed_vars_by_symbol,
+        problems,
+        abilities_store,
+    ) = {
         if module_id.is_builtin() {
             match cached_subs.lock().remove(&module_id) {
                 None => run_solve_solve(
@@ -4422,6 +4631,7 @@ fn run_solve<'a>(
                         Solved(subs),
                         // TODO(abilities) cache abilities for builtins
                         VecMap::default(),
+                        VecMap::default(),
                         exposed_vars_by_symbol.to_vec(),
                         vec![],
                         // TODO(abilities) cache abilities for builtins
@@ -4448,6 +4658,7 @@ fn run_solve<'a>(
         &mut solved_subs,
         &exposed_vars_by_symbol,
         &solved_implementations,
+        &solved_default_implementations,
         &abilities_store,
     );
 
@@ -4456,6 +4667,7 @@ fn run_solve<'a>(
         problems,
         aliases,
         solved_implementations,
+        solved_default_implementations,
         exposed_types,
     };
 
@@ -4474,6 +4686,7 @@ fn run_solve<'a>(
         module_timing,
         abilities_store,
         loc_expects,
+        loc_dbgs,
     }
 }
 
@@ -4543,6 +4756,8 @@ fn canonicalize_and_constrain<'a>(
 
     let ParsedModule {
         module_id,
+        module_path,
+        src,
         module_name,
         header_for,
         exposed_ident_ids,
@@ -4559,6 +4774,10 @@ fn canonicalize_and_constrain<'a>(
 
     let parsed_defs_for_docs = parsed_defs.clone();
     let parsed_defs = arena.alloc(parsed_defs);
+    let src_location = (
+        arena.alloc(module_path).as_path(),
+        &*arena.alloc(LineInfo::new(src)),
+    );
 
     let mut var_store = VarStore::default();
     let module_output = canonicalize_module_defs(
@@ -4574,6 +4793,7 @@ fn canonicalize_and_constrain<'a>(
         exposed_imports,
         &exposed_symbols,
         &symbols_from_requires,
+        src_location,
         &mut var_store,
     );
 
@@ -4674,6 +4894,7 @@ fn canonicalize_and_constrain<'a>(
         rigid_variables: module_output.rigid_variables,
         abilities_store: module_output.scope.abilities_store,
         loc_expects: module_output.loc_expects,
+        loc_dbgs: module_output.loc_dbgs,
     };
 
     let constrained_module = ConstrainedModule {
@@ -4753,7 +4974,7 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
     Ok(Msg::Parsed(parsed))
 }
 
-fn exposed_from_import<'a>(entry: &ImportsEntry<'a>) -> (QualifiedModuleName<'a>, Vec<Ident>) {
+fn exposed_from_import<'a>(entry: &ImportsEntry<'a>) -> (QualifiedModuleName<'a>, Vec<Loc<Ident>>) {
     use roc_parse::header::ImportsEntry::*;
 
     match entry {
@@ -4761,7 +4982,10 @@ fn exposed_from_import<'a>(entry: &ImportsEntry<'a>) -> (QualifiedModuleName<'a>
             let mut exposed = Vec::with_capacity(exposes.len());
 
             for loc_entry in exposes.iter() {
-                exposed.push(ident_from_exposed(&loc_entry.value));
+                exposed.push(Loc::at(
+                    loc_entry.region,
+                    ident_from_exposed(&loc_entry.value),
+                ));
             }
 
             let qualified_module_name = QualifiedModuleName {
@@ -4776,7 +5000,10 @@ fn exposed_from_import<'a>(entry: &ImportsEntry<'a>) -> (QualifiedModuleName<'a>
             let mut exposed = Vec::with_capacity(exposes.len());
 
             for loc_entry in exposes.iter() {
-                exposed.push(ident_from_exposed(&loc_entry.value));
+                exposed.push(Loc::at(
+                    loc_entry.region,
+                    ident_from_exposed(&loc_entry.value),
+                ));
             }
 
             let qualified_module_name = QualifiedModuleName {
@@ -4806,6 +5033,7 @@ fn make_specializations<'a>(
     target_info: TargetInfo,
     world_abilities: WorldAbilities,
     exposed_by_module: &ExposedByModule,
+    exposed_idents: &IdentIdsByModule,
     derived_module: SharedDerivedModule,
 ) -> Msg<'a> {
     let make_specializations_start = Instant::now();
@@ -4823,6 +5051,7 @@ fn make_specializations<'a>(
         abilities: AbilitiesView::World(&world_abilities),
         exposed_by_module,
         derived_module: &derived_module,
+        exposed_idents,
     };
 
     let mut procs = Procs::new_in(arena);
@@ -4883,6 +5112,7 @@ fn build_pending_specializations<'a>(
     target_info: TargetInfo,
     exposed_to_host: ExposedToHost,
     exposed_by_module: &ExposedByModule,
+    exposed_idents: &IdentIdsByModule,
     abilities_store: AbilitiesStore,
     derived_module: SharedDerivedModule,
     build_expects: bool,
@@ -4892,6 +5122,13 @@ fn build_pending_specializations<'a>(
     let mut module_thunks = bumpalo::collections::Vec::new_in(arena);
     let mut toplevel_expects = ToplevelExpects::default();
 
+    let mut symbol_regions = MutMap::default();
+    for loc_symbol in roc_can::traverse::find_symbol_regions(&declarations) {
+        symbol_regions
+            .entry(loc_symbol.value)
+            .or_insert(loc_symbol.region);
+    }
+
     let mut procs_base = ProcsBase {
         partial_procs: BumpMap::default(),
         module_thunks: &[],
@@ -4917,6 +5154,7 @@ fn build_pending_specializations<'a>(
         abilities: AbilitiesView::Module(&abilities_store),
         exposed_by_module,
         derived_module: &derived_module,
+        exposed_idents,
     };
 
     // Add modules' decls to Procs
@@ -5294,6 +5532,7 @@ fn build_pending_specializations<'a>(
         module_timing,
         abilities_store,
         toplevel_expects,
+        symbol_regions,
     }
 }
 
@@ -5348,6 +5587,8 @@ fn load_derived_partial_procs<'a>(
             abilities: AbilitiesView::World(world_abilities),
             exposed_by_module,
             derived_module,
+            // derived implementations never contain a `dbg`
+            exposed_idents: &IdentIdsByModule::default(),
         };
 
         let partial_proc = match derived_expr {
@@ -5490,6 +5731,7 @@ fn run_task<'a>(
             exposed_to_host,
             abilities_store,
             exposed_by_module,
+            exposed_idents,
             derived_module,
             build_expects,
         } => Ok(build_pending_specializations(
@@ -5504,6 +5746,7 @@ fn run_task<'a>(
             target_info,
             exposed_to_host,
             &exposed_by_module,
+            &exposed_idents,
             abilities_store,
             derived_module,
             build_expects,
@@ -5518,6 +5761,7 @@ fn run_task<'a>(
             module_timing,
             world_abilities,
             exposed_by_module,
+            exposed_idents,
             derived_module,
         } => Ok(make_specializations(
             arena,
@@ -5531,6 +5775,7 @@ fn run_task<'a>(
             target_info,
             world_abilities,
             &exposed_by_module,
+            &exposed_idents,
             derived_module,
         )),
     }?;
@@ -5574,6 +5819,7 @@ fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "FILE NOT FOUND".to_string(),
+                code: None,
                 severity: Severity::RuntimeError,
             }
         }
@@ -5591,6 +5837,7 @@ fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "FILE PERMISSION DENIED".to_string(),
+                code: None,
                 severity: Severity::RuntimeError,
             }
         }
@@ -5607,6 +5854,7 @@ fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
                 filename: "UNKNOWN.roc".into(),
                 doc,
                 title: "FILE PROBLEM".to_string(),
+                code: None,
                 severity: Severity::RuntimeError,
             }
         }
@@ -5689,6 +5937,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: PlatformPath) -> Strin
                     filename: "UNKNOWN.roc".into(),
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: None,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -5704,6 +5953,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: PlatformPath) -> Strin
                     filename: "UNKNOWN.roc".into(),
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: None,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -5719,6 +5969,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: PlatformPath) -> Strin
                     filename: "UNKNOWN.roc".into(),
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: None,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -5734,6 +5985,7 @@ fn to_missing_platform_report(module_id: ModuleId, other: PlatformPath) -> Strin
                     filename: "UNKNOWN.roc".into(),
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: None,
                     severity: Severity::RuntimeError,
                 }
             }
@@ -333,6 +333,49 @@ fn import_transitive_alias() {
     assert!(multiple_modules("import_transitive_alias", modules).is_ok());
 }
 
+#[test]
+fn imported_ability_default_implementation() {
+    let modules = vec![
+        (
+            "MEq",
+            indoc!(
+                r#"
+                interface MEq exposes [MEq, eq, neq] imports []
+
+                MEq has
+                    eq : a, a -> Bool | a has MEq
+                    neq : a, a -> Bool | a has MEq
+                    neq = \x, y -> Bool.not (eq x y)
+                "#
+            ),
+        ),
+        (
+            "Main",
+            indoc!(
+                r#"
+                interface Main exposes [different] imports [MEq.{ MEq, neq }]
+
+                Id := U64 has [MEq { eq: idEq }]
+
+                idEq = \@Id m, @Id n -> m == n
+
+                different = neq (@Id 1) (@Id 2)
+                "#
+            ),
+        ),
+    ];
+
+    let loaded_module = multiple_modules("imported_ability_default_implementation", modules);
+
+    expect_types(
+        loaded_module.unwrap(),
+        hashmap! {
+            "different" => "Bool",
+            "idEq" => "Id, Id -> Bool",
+        },
+    );
+}
+
 #[test]
 fn interface_with_deps() {
     let subs_by_module = Default::default();
@@ -580,7 +623,7 @@ fn parse_problem() {
             report,
             indoc!(
                 "
-                    ── UNFINISHED LIST ──────────────────────────────────── tmp/parse_problem/Main ─
+                    ── UNFINISHED LIST [E1040] ──────────────────────────── tmp/parse_problem/Main ─
 
                     I cannot find the end of this list:
 
@@ -736,6 +779,74 @@ fn platform_exposes_main_return_by_pointer_issue() {
     assert!(multiple_modules("platform_exposes_main_return_by_pointer_issue", modules).is_ok());
 }
 
+#[test]
+fn package_shorthands_of_platform_modules() {
+    let modules = vec![
+        (
+            "platform/main.roc",
+            indoc!(
+                r#"
+                    platform "hello-world"
+                        requires {} { main : Str }
+                        exposes [Greeting]
+                        packages {}
+                        imports []
+                        provides [mainForHost]
+
+                    mainForHost : Str
+                    mainForHost = main
+                    "#
+            ),
+        ),
+        (
+            "platform/Greeting",
+            indoc!(
+                r#"
+                    interface Greeting exposes [hello] imports []
+
+                    hello : Str
+                    hello = "Hello, World!\n"
+                    "#
+            ),
+        ),
+        (
+            "Main",
+            indoc!(
+                r#"
+                    app "hello-world"
+                        packages { pf: "platform/main.roc" }
+                        imports [pf.Greeting]
+                        provides [main] to pf
+
+                    main = Greeting.hello
+                    "#
+            ),
+        ),
+    ];
+
+    let loaded_module =
+        multiple_modules("package_shorthands_of_platform_modules", modules).unwrap();
+    let greeting_id = loaded_module
+        .interns
+        .module_ids
+        .get_id(&"Greeting".into())
+        .unwrap();
+
+    assert_eq!(
+        loaded_module
+            .package_shorthands
+            .get(&greeting_id)
+            .map(String::as_str),
+        Some("pf")
+    );
+    assert_eq!(
+        loaded_module
+            .package_shorthands
+            .get(&loaded_module.module_id),
+        None
+    );
+}
+
 #[test]
 fn opaque_wrapped_unwrapped_outside_defining_module() {
     let modules = vec![
@@ -769,7 +880,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
         err,
         indoc!(
             r#"
-                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE ─ ...rapped_outside_defining_module/Main ─
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE [E2038] ─ ...utside_defining_module/Main ─
 
                 The unwrapped opaque type Age referenced here:
 
@@ -779,11 +890,11 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
                 is imported from another module:
 
                 1│  interface Main exposes [twenty, readAge] imports [Age.{ Age }]
-                                                                      ^^^^^^^^^^^
+                                                                            ^^^
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
 
-                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE ─ ...rapped_outside_defining_module/Main ─
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE [E2038] ─ ...utside_defining_module/Main ─
 
                 The unwrapped opaque type Age referenced here:
 
@@ -793,11 +904,11 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
                 is imported from another module:
 
                 1│  interface Main exposes [twenty, readAge] imports [Age.{ Age }]
-                                                                      ^^^^^^^^^^^
+                                                                            ^^^
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
 
-                ── UNUSED IMPORT ─── tmp/opaque_wrapped_unwrapped_outside_defining_module/Main ─
+                ── UNUSED IMPORT [E2054] ─ ...e_wrapped_unwrapped_outside_defining_module/Main ─
 
                 Nothing from Age is used in this module.
 
@@ -812,6 +923,173 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
     );
 }
 
+#[test]
+fn unused_exposed_import() {
+    let modules = vec![
+        (
+            "Arith",
+            indoc!(
+                r#"
+                    interface Arith exposes [twice, thrice] imports []
+
+                    twice = \n -> n * 2
+
+                    thrice = \n -> n * 3
+                    "#
+            ),
+        ),
+        (
+            "Main",
+            indoc!(
+                r#"
+                    interface Main exposes [four] imports [Arith.{ twice, thrice }]
+
+                    four = twice 2
+                    "#
+            ),
+        ),
+    ];
+
+    let err = multiple_modules("unused_exposed_import", modules).unwrap_err();
+    assert_eq!(
+        err,
+        indoc!(
+            r#"
+                ── UNUSED IMPORT [E2056] ────────────────────── tmp/unused_exposed_import/Main ─
+
+                `thrice` is imported from Arith, but it isn't used in this module.
+
+                1│  interface Main exposes [four] imports [Arith.{ twice, thrice }]
+                                                                          ^^^^^^
+
+                Since `thrice` isn't used, you can remove it from the import.
+                "#
+        ),
+        "\n{}",
+        err
+    );
+}
+
+#[test]
+fn inferred_holes_in_annotations() {
+    let modules = vec![(
+        "Main",
+        indoc!(
+            r#"
+                interface Main exposes [toStr, pairUp] imports []
+
+                toStr : _ -> Str
+                toStr = \n -> Num.toStr (n + 1u8)
+
+                pairUp : a -> { first : a, second : _ }
+                pairUp = \x ->
+                    second : List _
+                    second = ["two"]
+
+                    { first: x, second }
+                "#
+        ),
+    )];
+
+    let mut loaded_module = multiple_modules("inferred_holes_in_annotations", modules).unwrap();
+
+    let holes: Vec<String> = loaded_module
+        .inferred_holes()
+        .into_iter()
+        .map(|(_, hole)| hole.value)
+        .collect();
+
+    assert_eq!(holes, ["U8", "List Str", "Str"]);
+}
+
+#[test]
+fn inferred_holes_in_imported_modules() {
+    let modules = vec![
+        (
+            "Dep",
+            indoc!(
+                r#"
+                interface Dep exposes [wrap] imports []
+
+                wrap : _ -> List Str
+                wrap = \str -> [str]
+                "#
+            ),
+        ),
+        (
+            "Main",
+            indoc!(
+                r#"
+                interface Main exposes [twice] imports [Dep]
+
+                twice : Str -> _
+                twice = \str -> Dep.wrap (Str.concat str str)
+                "#
+            ),
+        ),
+    ];
+
+    let mut loaded_module =
+        multiple_modules("inferred_holes_in_imported_modules", modules).unwrap();
+    let interns = loaded_module.interns.clone();
+
+    let holes: Vec<(String, String)> = loaded_module
+        .inferred_holes()
+        .into_iter()
+        .map(|(module_id, hole)| (module_id.to_ident_str(&interns).to_string(), hole.value))
+        .collect();
+
+    assert_eq!(
+        holes,
+        [
+            ("Dep".to_string(), "Str".to_string()),
+            ("Main".to_string(), "List Str".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn inferred_holes_as_type_problems() {
+    use roc_reporting::report::{type_problem, Severity};
+    use roc_solve_problem::TypeError;
+
+    let modules = vec![(
+        "Main",
+        indoc!(
+            r#"
+                interface Main exposes [toStr] imports []
+
+                toStr : _ -> Str
+                toStr = \n -> Num.toStr (n + 1u8)
+                "#
+        ),
+    )];
+
+    let mut loaded_module = multiple_modules("inferred_holes_as_type_problems", modules).unwrap();
+
+    loaded_module.add_inferred_hole_problems();
+
+    let home = loaded_module.module_id;
+    let problems = loaded_module.type_problems.remove(&home).unwrap();
+    let (filepath, src) = loaded_module.sources.get(&home).unwrap();
+
+    match problems.as_slice() {
+        [TypeError::InferredHole { region, typ }] => {
+            assert_eq!(&src[region.start().offset as usize..][..1], "_");
+            assert_eq!(typ, "U8");
+        }
+        _ => panic!("Expected one inferred hole, but got {:?}", problems),
+    }
+
+    let src_lines: Vec<&str> = src.split('\n').collect();
+    let lines = LineInfo::new(src);
+    let alloc = RocDocAllocator::new(&src_lines, home, &loaded_module.interns);
+    let report = type_problem(&alloc, &lines, filepath.clone(), problems[0].clone()).unwrap();
+
+    assert_eq!(report.title, "INFERRED HOLE");
+    assert_eq!(report.severity, Severity::Warning);
+}
+
 #[test]
 fn issue_2863_module_type_does_not_exist() {
     let modules = vec![
@@ -852,7 +1130,7 @@ fn issue_2863_module_type_does_not_exist() {
                 report,
                 indoc!(
                     "
-                        ── UNRECOGNIZED NAME ────────── tmp/issue_2863_module_type_does_not_exist/Main ─
+                        ── UNRECOGNIZED NAME [E2051] ── tmp/issue_2863_module_type_does_not_exist/Main ─
 
                         Nothing is named `DoesNotExist` in this scope.
 
@@ -872,3 +1150,46 @@ fn issue_2863_module_type_does_not_exist() {
         Ok(_) => unreachable!("we expect failure here"),
     }
 }
+
+#[test]
+fn doc_examples() {
+    use roc_load_internal::docs::{doc_examples, DocExample};
+
+    let docs = indoc!(
+        r#"
+         Add an element to the end of a list.
+
+         >>> List.append [1, 2, 3] 4
+         [1, 2, 3, 4]
+
+         >>> [0, 1, 2]
+         >>>     |> List.append 3
+
+         Examples can define things, too.
+         >>> fn = \x -> x + 1
+         >>>
+         >>> fn 1
+        "#
+    );
+
+    assert_eq!(
+        doc_examples(docs),
+        vec![
+            DocExample {
+                code: "List.append [1, 2, 3] 4".to_string(),
+                expected: Some("[1, 2, 3, 4]".to_string()),
+                line: 2,
+            },
+            DocExample {
+                code: "[0, 1, 2]\n    |> List.append 3".to_string(),
+                expected: None,
+                line: 5,
+            },
+            DocExample {
+                code: "fn = \\x -> x + 1\n\nfn 1".to_string(),
+                expected: None,
+                line: 9,
+            },
+        ]
+    );
+}
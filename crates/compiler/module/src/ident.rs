@@ -191,6 +191,48 @@ impl Lowercase {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
+
+    /// Tuples are records whose fields are named after the positions of their elements,
+    /// so `(a, b)` is `{ 0: a, 1: b }`. Field names written in source code can't start
+    /// with a digit, so these never clash with the fields of an ordinary record.
+    pub fn tuple_elem(index: usize) -> Self {
+        Self(index.to_string().into())
+    }
+
+    /// The position of the tuple element this field stands for, if it is one
+    pub fn as_tuple_elem(&self) -> Option<usize> {
+        let name = self.as_str();
+
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name.parse().ok()
+        } else {
+            None
+        }
+    }
+}
+
+/// If a record's fields are exactly the elements of a tuple (at least two of them, with none
+/// missing), the number of elements; in other words, whether the record is really a tuple.
+pub fn tuple_arity<'a>(fields: impl IntoIterator<Item = &'a Lowercase>) -> Option<usize> {
+    let mut positions: Vec<usize> = Vec::new();
+
+    for field in fields {
+        positions.push(field.as_tuple_elem()?);
+    }
+
+    positions.sort_unstable();
+
+    let is_tuple = positions.len() > 1
+        && positions
+            .iter()
+            .enumerate()
+            .all(|(index, pos)| index == *pos);
+
+    if is_tuple {
+        Some(positions.len())
+    } else {
+        None
+    }
 }
 
 impl From<Lowercase> for String {
@@ -513,6 +513,20 @@ impl<'a> PackageModuleIds<'a> {
         self.by_id.iter()
     }
 
+    /// The shorthand of the package each module was imported from, e.g. `pf` for `pf.Task`.
+    /// Modules of the root package aren't qualified by a shorthand, so they're skipped.
+    pub fn package_shorthands(&self) -> impl Iterator<Item = (ModuleId, &'a str)> + '_ {
+        self.by_id
+            .iter()
+            .enumerate()
+            .filter_map(|(index, name)| match name {
+                PQModuleName::Unqualified(_) => None,
+                PQModuleName::Qualified(shorthand, _) => {
+                    Some((ModuleId::from_zero_indexed(index), *shorthand))
+                }
+            })
+    }
+
     /// Returns true iff two modules belong to the same package.
     /// Returns [None] if one module is unknown.
     pub fn package_eq(&self, left: ModuleId, right: ModuleId) -> Option<bool> {
@@ -1410,6 +1424,7 @@ define_builtins! {
         23 ENCODE_APPEND_WITH: "appendWith"
         24 ENCODE_APPEND: "append"
         25 ENCODE_TO_BYTES: "toBytes"
+        26 ENCODE_TUPLE: "tuple"
     }
     12 DECODE: "Decode" => {
         0 DECODE_DECODE_ERROR: "DecodeError"
@@ -1439,6 +1454,7 @@ define_builtins! {
         24 DECODE_DECODE_WITH: "decodeWith"
         25 DECODE_FROM_BYTES_PARTIAL: "fromBytesPartial"
         26 DECODE_FROM_BYTES: "fromBytes"
+        27 DECODE_TUPLE: "tuple"
     }
     13 JSON: "Json" => {
         0 JSON_JSON: "Json"
@@ -310,6 +310,7 @@ impl<'a> ParamMap<'a> {
 
                 Expect { remainder, .. } => stack.push(remainder),
                 ExpectFx { remainder, .. } => stack.push(remainder),
+                Dbg { remainder, .. } => stack.push(remainder),
 
                 Switch {
                     branches,
@@ -321,7 +322,7 @@ impl<'a> ParamMap<'a> {
                 }
                 Refcounting(_, _) => unreachable!("these have not been introduced yet"),
 
-                Ret(_) | Jump(_, _) | RuntimeError(_) => {
+                Ret(_) | Jump(_, _) | Crash(..) | RuntimeError(_) => {
                     // these are terminal, do nothing
                 }
             }
@@ -825,9 +826,13 @@ impl<'a> BorrowInfState<'a> {
                 self.collect_stmt(param_map, remainder);
             }
 
+            Dbg { remainder, .. } => {
+                self.collect_stmt(param_map, remainder);
+            }
+
             Refcounting(_, _) => unreachable!("these have not been introduced yet"),
 
-            Ret(_) | RuntimeError(_) => {
+            Ret(_) | Crash(..) | RuntimeError(_) => {
                 // these are terminal, do nothing
             }
         }
@@ -994,10 +999,11 @@ fn call_info_stmt<'a>(arena: &'a Bump, stmt: &Stmt<'a>, info: &mut CallInfo<'a>)
 
             Expect { remainder, .. } => stack.push(remainder),
             ExpectFx { remainder, .. } => stack.push(remainder),
+            Dbg { remainder, .. } => stack.push(remainder),
 
             Refcounting(_, _) => unreachable!("these have not been introduced yet"),
 
-            Ret(_) | Jump(_, _) | RuntimeError(_) => {
+            Ret(_) | Jump(_, _) | Crash(..) | RuntimeError(_) => {
                 // these are terminal, do nothing
             }
         }
@@ -1,10 +1,12 @@
 use crate::ir::{
-    BranchInfo, DestructType, Env, Expr, JoinPointId, Literal, Param, Pattern, Procs, Stmt,
+    build_list_index_probe, BranchInfo, Call, CallType, DestructType, Env, Expr, JoinPointId,
+    ListIndex, Literal, Param, Pattern, Procs, Stmt,
 };
 use crate::layout::{Builtin, Layout, LayoutCache, TagIdIntType, UnionLayout};
 use roc_builtins::bitcode::{FloatWidth, IntWidth};
 use roc_collections::all::{MutMap, MutSet};
-use roc_exhaustive::{Ctor, CtorName, RenderAs, TagId, Union};
+use roc_error_macros::internal_error;
+use roc_exhaustive::{Ctor, CtorName, ListArity, RenderAs, TagId, Union};
 use roc_module::ident::TagName;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::Symbol;
@@ -94,6 +96,51 @@ enum Test<'a> {
         tag_id: TagIdIntType,
         num_alts: usize,
     },
+    IsListLen {
+        bound: ListLenBound,
+        len: u64,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum ListLenBound {
+    Exact,
+    AtLeast,
+}
+
+impl ListLenBound {
+    fn from_arity(arity: ListArity) -> Self {
+        match arity {
+            ListArity::Exact(_) => ListLenBound::Exact,
+            ListArity::Slice(..) => ListLenBound::AtLeast,
+        }
+    }
+
+    /// Is every list whose length passes `self` with `my_len` guaranteed to also
+    /// pass `other` with `other_len`?
+    fn implies(self, my_len: u64, other: ListLenBound, other_len: u64) -> bool {
+        use ListLenBound::*;
+
+        match (self, other) {
+            (Exact, Exact) => my_len == other_len,
+            (Exact, AtLeast) => my_len >= other_len,
+            (AtLeast, Exact) => false,
+            (AtLeast, AtLeast) => my_len >= other_len,
+        }
+    }
+
+    /// Is there some list whose length passes both `self` with `my_len` and `other`
+    /// with `other_len`?
+    fn overlaps(self, my_len: u64, other: ListLenBound, other_len: u64) -> bool {
+        use ListLenBound::*;
+
+        match (self, other) {
+            (Exact, Exact) => my_len == other_len,
+            (Exact, AtLeast) => my_len >= other_len,
+            (AtLeast, Exact) => other_len >= my_len,
+            (AtLeast, AtLeast) => true,
+        }
+    }
 }
 
 impl<'a> Test<'a> {
@@ -109,6 +156,8 @@ impl<'a> Test<'a> {
             Test::IsStr(_) => false,
             Test::IsBit(_) => true,
             Test::IsByte { .. } => true,
+            // the lengths of lists are compared rather than switched on
+            Test::IsListLen { .. } => false,
         }
     }
 }
@@ -152,6 +201,11 @@ impl<'a> Hash for Test<'a> {
                 state.write_u8(6);
                 v.hash(state);
             }
+            IsListLen { bound, len } => {
+                state.write_u8(7);
+                bound.hash(state);
+                len.hash(state);
+            }
         }
     }
 }
@@ -330,6 +384,7 @@ fn tests_are_complete_help(last_test: &Test, number_of_tests: usize) -> bool {
         Test::IsFloat(_, _) => false,
         Test::IsDecimal(_) => false,
         Test::IsStr(_) => false,
+        Test::IsListLen { .. } => false,
     }
 }
 
@@ -388,6 +443,12 @@ fn flatten<'a>(
             }
         }
 
+        Pattern::As(subpattern, _symbol) => {
+            // the name is only bound after the decision tree has picked a branch, so the
+            // sub-pattern is all we need to decide on
+            flatten((path_pattern.0, *subpattern), path_patterns);
+        }
+
         _ => {
             path_patterns.push(path_pattern);
         }
@@ -505,7 +566,14 @@ fn test_at_path<'a>(
     {
         None => None,
         Some((_, pattern)) => {
+            let mut pattern = pattern;
+            while let As(subpattern, _) = pattern {
+                pattern = subpattern;
+            }
+
             let test = match pattern {
+                As(..) => unreachable!("as patterns were unwrapped above"),
+
                 Identifier(_) | Underscore => {
                     if let Guard::Guard { .. } = &branch.guard {
                         // no tests for this pattern remain, but we cannot discard it yet
@@ -604,6 +672,11 @@ fn test_at_path<'a>(
                 FloatLiteral(v, precision) => IsFloat(*v, *precision),
                 DecimalLiteral(v) => IsDecimal(*v),
                 StrLiteral(v) => IsStr(v.clone()),
+
+                List { arity, .. } => IsListLen {
+                    bound: ListLenBound::from_arity(*arity),
+                    len: arity.min_len() as _,
+                },
             };
 
             let guarded_test = GuardedTest::TestNotGuarded { test };
@@ -659,11 +732,7 @@ fn to_relevant_branch<'a>(
         } => match guarded_test {
             GuardedTest::Placeholder | GuardedTest::GuardedNoTest { .. } => {
                 // if there is no test, the pattern should not require any
-                debug_assert!(
-                    matches!(pattern, Pattern::Identifier(_) | Pattern::Underscore,),
-                    "{:?}",
-                    pattern,
-                );
+                debug_assert!(!needs_tests(&pattern), "{:?}", pattern);
 
                 Some(branch.clone())
             }
@@ -688,6 +757,10 @@ fn to_relevant_branch_help<'a>(
     match pattern {
         Identifier(_) | Underscore => Some(branch.clone()),
 
+        As(subpattern, _symbol) => {
+            to_relevant_branch_help(test, path, start, end, branch, *subpattern)
+        }
+
         RecordDestructure(destructs, _) => match test {
             IsCtor {
                 ctor_name: test_name,
@@ -951,6 +1024,47 @@ fn to_relevant_branch_help<'a>(
 
             _ => None,
         },
+
+        List {
+            arity: my_arity,
+            elements,
+            ..
+        } => match test {
+            IsListLen {
+                bound: test_bound,
+                len: test_len,
+            } => {
+                let my_bound = ListLenBound::from_arity(my_arity);
+                let my_len = my_arity.min_len() as u64;
+
+                if test_bound.implies(*test_len, my_bound, my_len) {
+                    // every list that passes the test has a length this pattern matches, so
+                    // we can go on to match its elements
+                    let sub_positions = elements.into_iter().enumerate().map(|(index, pattern)| {
+                        let mut new_path = path.to_vec();
+                        new_path.push(PathInstruction::ListIndex {
+                            index: ListIndex::from_pattern_index(index, my_arity),
+                        });
+                        (new_path, pattern)
+                    });
+                    start.extend(sub_positions);
+                    start.extend(end);
+
+                    Some(Branch {
+                        goal: branch.goal,
+                        guard: branch.guard.clone(),
+                        patterns: start,
+                    })
+                } else if test_bound.overlaps(*test_len, my_bound, my_len) {
+                    // some lists that pass the test may match this pattern, but we need another
+                    // test on the length to know for sure
+                    Some(branch.clone())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        },
     }
 }
 
@@ -1008,6 +1122,8 @@ fn needs_tests(pattern: &Pattern) -> bool {
     match pattern {
         Identifier(_) | Underscore => false,
 
+        As(subpattern, _) => needs_tests(subpattern),
+
         NewtypeDestructure { .. }
         | RecordDestructure(..)
         | AppliedTag { .. }
@@ -1017,7 +1133,8 @@ fn needs_tests(pattern: &Pattern) -> bool {
         | IntLiteral(_, _)
         | FloatLiteral(_, _)
         | DecimalLiteral(_)
-        | StrLiteral(_) => true,
+        | StrLiteral(_)
+        | List { .. } => true,
     }
 }
 
@@ -1262,6 +1379,7 @@ pub fn optimize_when<'a>(
 enum PathInstruction {
     NewType,
     TagIndex { index: u64, tag_id: TagIdIntType },
+    ListIndex { index: ListIndex },
 }
 
 fn path_to_expr_help<'a>(
@@ -1331,6 +1449,31 @@ fn path_to_expr_help<'a>(
                     }
                 }
             }
+
+            PathInstruction::ListIndex { index } => {
+                let element_layout = match layout {
+                    Layout::Builtin(Builtin::List(element_layout)) => *element_layout,
+                    _ => internal_error!("{:?}", layout),
+                };
+
+                let (index_sym, new_stores) = build_list_index_probe(env, symbol, index);
+
+                stores.extend(new_stores);
+
+                let load_sym = env.unique_symbol();
+                let load_expr = Expr::Call(Call {
+                    call_type: CallType::LowLevel {
+                        op: LowLevel::ListGetUnsafe,
+                        update_mode: env.next_update_mode_id(),
+                    },
+                    arguments: env.arena.alloc([symbol, index_sym]),
+                });
+
+                stores.push((load_sym, element_layout, load_expr));
+
+                symbol = load_sym;
+                layout = element_layout;
+            }
         }
     }
 
@@ -1440,6 +1583,53 @@ fn test_to_equality<'a>(
 
             (stores, lhs_symbol, rhs_symbol, None)
         }
+
+        Test::IsListLen { bound, len } => {
+            let list_sym = rhs_symbol;
+
+            let real_len_expr = Expr::Call(Call {
+                call_type: CallType::LowLevel {
+                    op: LowLevel::ListLen,
+                    update_mode: env.next_update_mode_id(),
+                },
+                arguments: env.arena.alloc([list_sym]),
+            });
+            let test_len_expr = Expr::Literal(Literal::Int((len as i128).to_ne_bytes()));
+
+            let real_len = env.unique_symbol();
+            let test_len = env.unique_symbol();
+
+            let usize_layout = Layout::usize(env.target_info);
+
+            stores.push((real_len, usize_layout, real_len_expr));
+            stores.push((test_len, usize_layout, test_len_expr));
+
+            match bound {
+                ListLenBound::Exact => (stores, real_len, test_len, None),
+                ListLenBound::AtLeast => {
+                    // tests always compare two values for equality, so check that
+                    // `real_len >= test_len` is true
+                    let len_check_expr = Expr::Call(Call {
+                        call_type: CallType::LowLevel {
+                            op: LowLevel::NumGte,
+                            update_mode: env.next_update_mode_id(),
+                        },
+                        arguments: env.arena.alloc([real_len, test_len]),
+                    });
+                    let true_expr = Expr::Literal(Literal::Bool(true));
+
+                    let len_check = env.unique_symbol();
+                    let true_sym = env.unique_symbol();
+
+                    let bool_layout = Layout::Builtin(Builtin::Bool);
+
+                    stores.push((len_check, bool_layout, len_check_expr));
+                    stores.push((true_sym, bool_layout, true_expr));
+
+                    (stores, len_check, true_sym, None)
+                }
+            }
+        }
     }
 }
 
@@ -1850,6 +2040,7 @@ fn decide_to_branching<'a>(
                     Test::IsCtor { tag_id, .. } => tag_id as u64,
                     Test::IsDecimal(_) => unreachable!("decimals cannot be switched on"),
                     Test::IsStr(_) => unreachable!("strings cannot be switched on"),
+                    Test::IsListLen { .. } => unreachable!("list lengths cannot be switched on"),
                 };
 
                 // branch info is only useful for refcounted values
@@ -102,6 +102,10 @@ pub fn occurring_variables(stmt: &Stmt<'_>) -> (MutSet<Symbol>, MutSet<Symbol>)
                 result.insert(*symbol);
             }
 
+            Crash(msg, _, _) => {
+                result.insert(*msg);
+            }
+
             Refcounting(modify, cont) => {
                 let symbol = modify.get_symbol();
                 result.insert(symbol);
@@ -130,6 +134,19 @@ pub fn occurring_variables(stmt: &Stmt<'_>) -> (MutSet<Symbol>, MutSet<Symbol>)
                 stack.push(remainder);
             }
 
+            Dbg {
+                symbol,
+                message,
+                location,
+                remainder,
+                ..
+            } => {
+                result.insert(*symbol);
+                result.insert(*message);
+                result.insert(*location);
+                stack.push(remainder);
+            }
+
             Jump(_, arguments) => {
                 result.extend(arguments.iter().copied());
             }
@@ -1132,6 +1149,14 @@ impl<'a, 'i> Context<'a, 'i> {
                 }
             }
 
+            Crash(x, _, _) => {
+                // the message is only borrowed, because the program stops right here
+                let mut live_vars = MutSet::default();
+                live_vars.insert(*x);
+
+                (stmt, live_vars)
+            }
+
             Jump(j, xs) => {
                 let empty = MutSet::default();
                 let j_live_vars = match self.jp_live_vars.get(j) {
@@ -1240,6 +1265,35 @@ impl<'a, 'i> Context<'a, 'i> {
                 (expect, b_live_vars)
             }
 
+            Dbg {
+                symbol,
+                message,
+                location,
+                region,
+                remainder,
+            } => {
+                let (b, mut b_live_vars) = self.visit_stmt(codegen, remainder);
+
+                // the values are only borrowed to report them
+                let b = self.add_dec_if_needed(*symbol, b, &b_live_vars);
+                let b = self.add_dec_if_needed(*message, b, &b_live_vars);
+                let b = self.add_dec_if_needed(*location, b, &b_live_vars);
+
+                let dbg = self.arena.alloc(Stmt::Dbg {
+                    symbol: *symbol,
+                    message: *message,
+                    location: *location,
+                    region: *region,
+                    remainder: b,
+                });
+
+                b_live_vars.insert(*symbol);
+                b_live_vars.insert(*message);
+                b_live_vars.insert(*location);
+
+                (dbg, b_live_vars)
+            }
+
             RuntimeError(_) | Refcounting(_, _) => (stmt, MutSet::default()),
         }
     }
@@ -1337,6 +1391,11 @@ pub fn collect_stmt(
             vars
         }
 
+        Crash(msg, _, _) => {
+            vars.insert(*msg);
+            vars
+        }
+
         Refcounting(modify, cont) => {
             let symbol = modify.get_symbol();
             vars.insert(symbol);
@@ -1365,6 +1424,19 @@ pub fn collect_stmt(
             collect_stmt(remainder, jp_live_vars, vars)
         }
 
+        Dbg {
+            symbol,
+            message,
+            location,
+            remainder,
+            ..
+        } => {
+            vars.insert(*symbol);
+            vars.insert(*message);
+            vars.insert(*location);
+            collect_stmt(remainder, jp_live_vars, vars)
+        }
+
         Join {
             id: j,
             parameters,
@@ -21,13 +21,13 @@ use roc_debug_flags::{
 };
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::{internal_error, todo_abilities};
-use roc_exhaustive::{Ctor, CtorName, RenderAs, TagId};
+use roc_exhaustive::{Ctor, CtorName, ListArity, RenderAs, TagId};
 use roc_intern::Interner;
 use roc_late_solve::storage::{ExternalModuleStorage, ExternalModuleStorageSnapshot};
 use roc_late_solve::{resolve_ability_specialization, AbilitiesView, Resolved, UnificationFailed};
-use roc_module::ident::{ForeignSymbol, Lowercase, TagName};
+use roc_module::ident::{tuple_arity, ForeignSymbol, Lowercase, TagName};
 use roc_module::low_level::LowLevel;
-use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_module::symbol::{IdentIds, IdentIdsByModule, ModuleId, Symbol};
 use roc_problem::can::{RuntimeError, ShadowKind};
 use roc_region::all::{Loc, Region};
 use roc_std::RocDec;
@@ -36,6 +36,7 @@ use roc_types::subs::{
     instantiate_rigids, Content, ExhaustiveMark, FlatType, RedundantMark, StorageSubs, Subs,
     Variable, VariableSubsSlice,
 };
+use roc_types::types::AliasKind;
 use std::collections::HashMap;
 use ven_pretty::{BoxAllocator, DocAllocator, DocBuilder};
 
@@ -1412,6 +1413,8 @@ pub struct Env<'a, 'i> {
     pub abilities: AbilitiesView<'i>,
     pub exposed_by_module: &'i ExposedByModule,
     pub derived_module: &'i SharedDerivedModule,
+    /// The identifiers every module exposes, to name the opaque types of other modules in a `dbg`
+    pub exposed_idents: &'i IdentIdsByModule,
 }
 
 impl<'a, 'i> Env<'a, 'i> {
@@ -1571,6 +1574,18 @@ pub enum Stmt<'a> {
         /// what happens after the expect
         remainder: &'a Stmt<'a>,
     },
+    Dbg {
+        /// the value to report
+        symbol: Symbol,
+        /// the value to report, rendered as a `Str`
+        message: Symbol,
+        /// where that value is in the source, as a `Str`
+        location: Symbol,
+        /// where that value is in the source
+        region: Region,
+        /// what happens after the dbg
+        remainder: &'a Stmt<'a>,
+    },
     /// a join point `join f <params> = <continuation> in remainder`
     Join {
         id: JoinPointId,
@@ -1582,9 +1597,34 @@ pub enum Stmt<'a> {
         remainder: &'a Stmt<'a>,
     },
     Jump(JoinPointId, &'a [Symbol]),
+    /// stop the program with the message in the given `Str` symbol;
+    /// the region is where the crash was written, in the module of the message symbol
+    Crash(Symbol, CrashTag, Region),
     RuntimeError(&'a str),
 }
 
+/// Who caused a crash, passed along to the host's `roc_panic` as its tag id
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrashTag {
+    /// The compiler inserted the crash, e.g. for an incomplete pattern match
+    Roc = 0,
+    /// The crash comes from a `crash` in the program itself
+    User = 1,
+}
+
+impl std::convert::TryFrom<u32> for CrashTag {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(CrashTag::Roc),
+            1 => Ok(CrashTag::User),
+            _ => Err(()),
+        }
+    }
+}
+
 /// in the block below, symbol `scrutinee` is assumed be be of shape `tag_id`
 #[derive(Clone, Debug, PartialEq)]
 pub enum BranchInfo<'a> {
@@ -2170,6 +2210,20 @@ impl<'a> Stmt<'a> {
                 .append(alloc.hardline())
                 .append(remainder.to_doc(alloc, interner)),
 
+            Dbg {
+                message,
+                location,
+                remainder,
+                ..
+            } => alloc
+                .text("dbg ")
+                .append(symbol_to_doc(alloc, *location))
+                .append(" ")
+                .append(symbol_to_doc(alloc, *message))
+                .append(";")
+                .append(alloc.hardline())
+                .append(remainder.to_doc(alloc, interner)),
+
             Ret(symbol) => alloc
                 .text("ret ")
                 .append(symbol_to_doc(alloc, *symbol))
@@ -2230,6 +2284,11 @@ impl<'a> Stmt<'a> {
                 }
             }
 
+            Crash(symbol, tag, _) => alloc
+                .text("Crash { ")
+                .append(symbol_to_doc(alloc, *symbol))
+                .append(alloc.text(format!(", {:?} }}", tag))),
+
             RuntimeError(s) => alloc.text(format!("Error {}", s)),
 
             Join {
@@ -2737,7 +2796,11 @@ fn pattern_to_when<'a>(
             (env.unique_symbol(), Loc::at_zero(RuntimeError(error)))
         }
 
-        AppliedTag { .. } | RecordDestructure { .. } | UnwrappedOpaque { .. } => {
+        AppliedTag { .. }
+        | RecordDestructure { .. }
+        | UnwrappedOpaque { .. }
+        | roc_can::pattern::Pattern::List { .. }
+        | roc_can::pattern::Pattern::As(..) => {
             let symbol = env.unique_symbol();
 
             let wrapped_body = When {
@@ -4310,6 +4373,33 @@ pub fn with_hole<'a>(
         Expect { .. } => unreachable!("I think this is unreachable"),
         ExpectFx { .. } => unreachable!("I think this is unreachable"),
 
+        Dbg {
+            loc_message,
+            loc_continuation,
+            variable: dbg_variable,
+            location,
+        } => {
+            let rest = with_hole(
+                env,
+                loc_continuation.value,
+                variable,
+                procs,
+                layout_cache,
+                assigned,
+                hole,
+            );
+
+            lower_dbg(
+                env,
+                procs,
+                layout_cache,
+                *loc_message,
+                dbg_variable,
+                &location,
+                rest,
+            )
+        }
+
         If {
             cond_var,
             branch_var,
@@ -5462,6 +5552,20 @@ pub fn with_hole<'a>(
                 }
             }
         }
+        Crash { msg, ret_var: _ } => {
+            let msg_sym = possible_reuse_symbol_or_specialize(
+                env,
+                procs,
+                layout_cache,
+                &msg.value,
+                Variable::STR,
+            );
+
+            // whatever would have happened after the crash is unreachable
+            let stmt = Stmt::Crash(msg_sym, CrashTag::User, msg.region);
+
+            assign_to_symbol(env, procs, layout_cache, Variable::STR, *msg, msg_sym, stmt)
+        }
         TypedHole(_) => Stmt::RuntimeError("Hit a blank"),
         RuntimeError(e) => Stmt::RuntimeError(env.arena.alloc(e.runtime_message())),
     }
@@ -6370,6 +6474,25 @@ pub fn from_can<'a>(
             stmt
         }
 
+        Dbg {
+            loc_message,
+            loc_continuation,
+            variable: dbg_variable,
+            location,
+        } => {
+            let rest = from_can(env, variable, loc_continuation.value, procs, layout_cache);
+
+            lower_dbg(
+                env,
+                procs,
+                layout_cache,
+                *loc_message,
+                dbg_variable,
+                &location,
+                rest,
+            )
+        }
+
         LetRec(defs, cont, _cycle_mark) => {
             // because Roc is strict, only functions can be recursive!
             for def in defs.into_iter() {
@@ -6760,6 +6883,27 @@ fn substitute_in_stmt_help<'a>(
             Some(arena.alloc(expect))
         }
 
+        Dbg {
+            symbol,
+            message,
+            location,
+            region,
+            remainder,
+        } => {
+            let new_remainder =
+                substitute_in_stmt_help(arena, remainder, subs).unwrap_or(remainder);
+
+            let dbg = Dbg {
+                symbol: substitute(subs, *symbol).unwrap_or(*symbol),
+                message: substitute(subs, *message).unwrap_or(*message),
+                location: substitute(subs, *location).unwrap_or(*location),
+                region: *region,
+                remainder: new_remainder,
+            };
+
+            Some(arena.alloc(dbg))
+        }
+
         Jump(id, args) => {
             let mut did_change = false;
             let new_args = Vec::from_iter_in(
@@ -6782,6 +6926,10 @@ fn substitute_in_stmt_help<'a>(
             }
         }
 
+        Crash(msg, tag, region) => {
+            substitute(subs, *msg).map(|new| &*arena.alloc(Crash(new, *tag, *region)))
+        }
+
         RuntimeError(_) => None,
     }
 }
@@ -7039,6 +7187,28 @@ fn store_pattern_help<'a>(
             // do nothing
             return StorePattern::NotProductive(stmt);
         }
+        As(subpattern, symbol) => {
+            // the name is bound to the matched value itself, so no load is needed
+            let stored_subpattern =
+                store_pattern_help(env, procs, layout_cache, subpattern, outer_symbol, stmt);
+
+            let mut stmt = match stored_subpattern {
+                StorePattern::Productive(stmt) => stmt,
+                StorePattern::NotProductive(stmt) => stmt,
+            };
+
+            // An as pattern can define at most one specialization!
+            let specialization_symbol = procs
+                .symbol_specializations
+                .remove_single(*symbol)
+                // Can happen when the symbol was never used under this body, and hence has no
+                // requested specialization.
+                .unwrap_or(*symbol);
+
+            substitute_in_exprs(env.arena, &mut stmt, specialization_symbol, outer_symbol);
+
+            return StorePattern::Productive(stmt);
+        }
         IntLiteral(_, _)
         | FloatLiteral(_, _)
         | DecimalLiteral(_)
@@ -7090,6 +7260,27 @@ fn store_pattern_help<'a>(
             return store_pattern_help(env, procs, layout_cache, pattern, outer_symbol, stmt);
         }
 
+        List {
+            arity,
+            list_layout,
+            element_layout,
+            elements,
+            opt_rest,
+        } => {
+            return store_list_pattern(
+                env,
+                procs,
+                layout_cache,
+                outer_symbol,
+                *arity,
+                *list_layout,
+                *element_layout,
+                elements,
+                *opt_rest,
+                stmt,
+            );
+        }
+
         RecordDestructure(destructs, [_single_field]) => {
             for destruct in destructs {
                 match &destruct.typ {
@@ -7153,6 +7344,217 @@ fn store_pattern_help<'a>(
     StorePattern::Productive(stmt)
 }
 
+/// An index into a list that a list pattern matches on. Non-negative indices count from the
+/// front of the list; negative indices count from the back, so `-1` is the last element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ListIndex(i64);
+
+impl ListIndex {
+    /// The index of the list element that the element pattern at `index` of a list pattern
+    /// with the given arity matches.
+    pub(crate) fn from_pattern_index(index: usize, arity: ListArity) -> Self {
+        match arity {
+            ListArity::Exact(_) => Self(index as _),
+            ListArity::Slice(before, after) => {
+                if index < before {
+                    Self(index as _)
+                } else {
+                    // e.g. in `[a, .., b, c]`, `b` (at pattern index 1) is the second-to-last
+                    // element of the list
+                    Self(-((before + after - index) as i64))
+                }
+            }
+        }
+    }
+}
+
+pub(crate) type Store<'a> = (Symbol, Layout<'a>, Expr<'a>);
+
+fn usize_literal<'a>(n: usize) -> Expr<'a> {
+    Expr::Literal(Literal::Int((n as i128).to_ne_bytes()))
+}
+
+fn low_level_call<'a>(env: &mut Env<'a, '_>, op: LowLevel, arguments: &[Symbol]) -> Expr<'a> {
+    Expr::Call(Call {
+        call_type: CallType::LowLevel {
+            op,
+            update_mode: env.next_update_mode_id(),
+        },
+        arguments: env.arena.alloc_slice_copy(arguments),
+    })
+}
+
+/// Builds the stores needed to compute the concrete (`Nat`) offset of `list_index` in the list
+/// `list_sym`, in the order they should be defined, together with the symbol holding the offset.
+pub(crate) fn build_list_index_probe<'a>(
+    env: &mut Env<'a, '_>,
+    list_sym: Symbol,
+    list_index: &ListIndex,
+) -> (Symbol, std::vec::Vec<Store<'a>>) {
+    let usize_layout = Layout::usize(env.target_info);
+
+    let ListIndex(index) = *list_index;
+
+    if index >= 0 {
+        let index_sym = env.unique_symbol();
+        let index_expr = usize_literal(index as usize);
+
+        (index_sym, vec![(index_sym, usize_layout, index_expr)])
+    } else {
+        // index = List.len list - offset
+        let len_sym = env.unique_symbol();
+        let len_expr = low_level_call(env, LowLevel::ListLen, &[list_sym]);
+
+        let offset_sym = env.unique_symbol();
+        let offset_expr = usize_literal(-index as usize);
+
+        let index_sym = env.unique_symbol();
+        let index_expr = low_level_call(env, LowLevel::NumSubWrap, &[len_sym, offset_sym]);
+
+        let stores = vec![
+            (len_sym, usize_layout, len_expr),
+            (offset_sym, usize_layout, offset_expr),
+            (index_sym, usize_layout, index_expr),
+        ];
+
+        (index_sym, stores)
+    }
+}
+
+fn prepend_stores<'a>(
+    env: &Env<'a, '_>,
+    stores: impl DoubleEndedIterator<Item = Store<'a>>,
+    mut stmt: Stmt<'a>,
+) -> Stmt<'a> {
+    for (symbol, layout, expr) in stores.rev() {
+        stmt = Stmt::Let(symbol, expr, layout, env.arena.alloc(stmt));
+    }
+
+    stmt
+}
+
+#[allow(clippy::too_many_arguments)]
+fn store_list_pattern<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    list_sym: Symbol,
+    list_arity: ListArity,
+    list_layout: Layout<'a>,
+    element_layout: Layout<'a>,
+    elements: &[Pattern<'a>],
+    opt_rest: Option<Symbol>,
+    mut stmt: Stmt<'a>,
+) -> StorePattern<'a> {
+    use Pattern::*;
+
+    let mut is_productive = false;
+
+    if let Some(rest_symbol) = opt_rest {
+        // Pattern can define only one specialization
+        let rest_symbol = procs
+            .symbol_specializations
+            .remove_single(rest_symbol)
+            .unwrap_or(rest_symbol);
+
+        // rest = List.sublist list { start: before, len: List.len list - (before + after) }
+        let usize_layout = Layout::usize(env.target_info);
+        let start = match list_arity {
+            ListArity::Exact(_) => internal_error!("rest binding in a list pattern with no rest"),
+            ListArity::Slice(before, _) => before,
+        };
+
+        let start_sym = env.unique_symbol();
+        let len_sym = env.unique_symbol();
+        let num_elements_sym = env.unique_symbol();
+        let rest_len_sym = env.unique_symbol();
+
+        let stores = [
+            (start_sym, usize_layout, usize_literal(start)),
+            (
+                len_sym,
+                usize_layout,
+                low_level_call(env, LowLevel::ListLen, &[list_sym]),
+            ),
+            (
+                num_elements_sym,
+                usize_layout,
+                usize_literal(list_arity.min_len()),
+            ),
+            (
+                rest_len_sym,
+                usize_layout,
+                low_level_call(env, LowLevel::NumSubWrap, &[len_sym, num_elements_sym]),
+            ),
+        ];
+
+        let load = low_level_call(
+            env,
+            LowLevel::ListSublist,
+            &[list_sym, start_sym, rest_len_sym],
+        );
+
+        stmt = Stmt::Let(rest_symbol, load, list_layout, env.arena.alloc(stmt));
+        stmt = prepend_stores(env, stores.into_iter(), stmt);
+
+        is_productive = true;
+    }
+
+    for (index, element) in elements.iter().enumerate().rev() {
+        let list_index = ListIndex::from_pattern_index(index, list_arity);
+
+        let symbol = match element {
+            Identifier(symbol) => {
+                // Pattern can define only one specialization
+                procs
+                    .symbol_specializations
+                    .remove_single(*symbol)
+                    .unwrap_or(*symbol)
+            }
+            Underscore
+            | IntLiteral(_, _)
+            | FloatLiteral(_, _)
+            | DecimalLiteral(_)
+            | EnumLiteral { .. }
+            | BitLiteral { .. }
+            | StrLiteral(_) => {
+                // ignore
+                continue;
+            }
+            _ => {
+                // store the element in a symbol, and continue matching on it
+                let symbol = env.unique_symbol();
+
+                match store_pattern_help(env, procs, layout_cache, element, symbol, stmt) {
+                    StorePattern::Productive(new) => {
+                        stmt = new;
+                        symbol
+                    }
+                    StorePattern::NotProductive(new) => {
+                        // only load the element if we bind one of its (sub)patterns to a used name
+                        stmt = new;
+                        continue;
+                    }
+                }
+            }
+        };
+
+        let (index_sym, stores) = build_list_index_probe(env, list_sym, &list_index);
+        let load = low_level_call(env, LowLevel::ListGetUnsafe, &[list_sym, index_sym]);
+
+        stmt = Stmt::Let(symbol, load, element_layout, env.arena.alloc(stmt));
+        stmt = prepend_stores(env, stores.into_iter(), stmt);
+
+        is_productive = true;
+    }
+
+    if is_productive {
+        StorePattern::Productive(stmt)
+    } else {
+        StorePattern::NotProductive(stmt)
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn store_tag_pattern<'a>(
     env: &mut Env<'a, '_>,
@@ -7466,48 +7868,1411 @@ fn can_reuse_symbol<'a>(
     }
 }
 
-fn possible_reuse_symbol_or_specialize<'a>(
+/// Lowers a `dbg`: renders the value of `loc_message` as a `Str`, and reports it together with
+/// where it is in the source, before continuing with `rest`
+#[allow(clippy::too_many_arguments)]
+fn lower_dbg<'a>(
     env: &mut Env<'a, '_>,
     procs: &mut Procs<'a>,
     layout_cache: &mut LayoutCache<'a>,
-    expr: &roc_can::expr::Expr,
+    loc_message: Loc<roc_can::expr::Expr>,
+    dbg_variable: Variable,
+    location: &str,
+    rest: Stmt<'a>,
+) -> Stmt<'a> {
+    let layout = match layout_cache.from_var(env.arena, dbg_variable, env.subs) {
+        Ok(layout) => layout,
+        Err(_) => return Stmt::RuntimeError("Can't dbg a value with improper layout"),
+    };
+
+    let dbg_symbol = possible_reuse_symbol_or_specialize(
+        env,
+        procs,
+        layout_cache,
+        &loc_message.value,
+        dbg_variable,
+    );
+    let message = env.unique_symbol();
+    let location_symbol = env.unique_symbol();
+
+    let stmt = Stmt::Dbg {
+        symbol: dbg_symbol,
+        message,
+        location: location_symbol,
+        region: loc_message.region,
+        remainder: env.arena.alloc(rest),
+    };
+
+    let stmt = Stmt::Let(
+        location_symbol,
+        Expr::Literal(Literal::Str(env.arena.alloc_str(location))),
+        Layout::Builtin(Builtin::Str),
+        env.arena.alloc(stmt),
+    );
+
+    let mut render = DbgRender::default();
+    let stmt = render_dbg_value(
+        env,
+        procs,
+        layout_cache,
+        &mut render,
+        DbgValue {
+            symbol: dbg_symbol,
+            var: dbg_variable,
+            layout,
+            parens: false,
+        },
+        message,
+        env.arena.alloc(stmt),
+    );
+
+    assign_to_symbol(
+        env,
+        procs,
+        layout_cache,
+        dbg_variable,
+        loc_message,
+        dbg_symbol,
+        stmt,
+    )
+}
+
+/// State kept while rendering the value of a `dbg`
+#[derive(Default)]
+struct DbgRender<'a> {
+    /// The procs that render a recursive tag union, by the root of the union's variable and
+    /// whether the rendered value is wrapped in parentheses
+    union_procs: std::vec::Vec<(Variable, bool, Symbol)>,
+    /// The procs that render a list, by the root of the list's variable and its layout
+    list_procs: std::vec::Vec<(Variable, Layout<'a>, Symbol)>,
+    /// The recursive tag union that a `RecursivePointer` currently refers to
+    recursive_union: Option<UnionLayout<'a>>,
+}
+
+#[derive(Clone, Copy)]
+struct DbgValue<'a> {
+    symbol: Symbol,
     var: Variable,
-) -> Symbol {
-    match can_reuse_symbol(env, procs, expr, var) {
-        ReuseSymbol::Value(symbol) => {
-            procs
-                .symbol_specializations
-                .get_or_insert(env, layout_cache, symbol, var)
+    layout: Layout<'a>,
+    /// whether a value that is an application, like `Ok 1`, must be wrapped in parentheses
+    parens: bool,
+}
+
+/// One part of a rendered value
+enum DbgPiece<'a> {
+    Text(&'a str),
+    /// a symbol that already holds a `Str`
+    Str(Symbol),
+    Value(DbgValue<'a>),
+}
+
+fn push_dbg_text<'a>(arena: &'a Bump, pieces: &mut std::vec::Vec<DbgPiece<'a>>, text: &str) {
+    match pieces.last_mut() {
+        Some(DbgPiece::Text(previous)) => {
+            let mut joined =
+                bumpalo::collections::String::with_capacity_in(previous.len() + text.len(), arena);
+            joined.push_str(previous);
+            joined.push_str(text);
+
+            *previous = joined.into_bump_str();
         }
-        _ => env.unique_symbol(),
+        _ => pieces.push(DbgPiece::Text(arena.alloc_str(text))),
     }
 }
 
-fn handle_variable_aliasing<'a, BuildRest>(
+/// Stores the text of `value` in the `Str` symbol `result`, then continues with `hole`
+fn render_dbg_value<'a>(
     env: &mut Env<'a, '_>,
     procs: &mut Procs<'a>,
     layout_cache: &mut LayoutCache<'a>,
-    variable: Variable,
-    left: Symbol,
-    right: Symbol,
-    build_rest: BuildRest,
-) -> Stmt<'a>
-where
-    BuildRest: FnOnce(&mut Env<'a, '_>, &mut Procs<'a>, &mut LayoutCache<'a>) -> Stmt<'a>,
-{
-    // 1. Handle references to ability members - we could be aliasing an ability member, or another
-    //    alias to an ability member.
-    {
-        let is_ability_member = env
-            .abilities
-            .with_module_abilities_store(env.home, |store| store.is_ability_member_name(right));
+    render: &mut DbgRender<'a>,
+    value: DbgValue<'a>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    let layout = match value.layout {
+        Layout::RecursivePointer => Layout::Union(
+            render
+                .recursive_union
+                .expect("a recursive pointer outside of a recursive tag union"),
+        ),
+        other => other,
+    };
 
-        if is_ability_member {
-            procs
-                .ability_member_aliases
-                .insert(left, AbilityMember(right));
-            return build_rest(env, procs, layout_cache);
-        }
+    let value = DbgValue { layout, ..value };
+
+    let text = |text: &'a str| {
+        Stmt::Let(
+            result,
+            Expr::Literal(Literal::Str(text)),
+            Layout::Builtin(Builtin::Str),
+            hole,
+        )
+    };
+
+    match *env.subs.get_content_without_compacting(value.var) {
+        Content::RecursionVar { structure, .. } => render_dbg_value(
+            env,
+            procs,
+            layout_cache,
+            render,
+            DbgValue {
+                var: structure,
+                ..value
+            },
+            result,
+            hole,
+        ),
+        Content::Alias(_, _, real_var, AliasKind::Structural) => render_dbg_value(
+            env,
+            procs,
+            layout_cache,
+            render,
+            DbgValue {
+                var: real_var,
+                ..value
+            },
+            result,
+            hole,
+        ),
+        Content::Alias(Symbol::BOX_BOX_TYPE, _, _, _) => {
+            render_dbg_box(env, procs, layout_cache, render, value, result, hole)
+        }
+        Content::Alias(symbol, _, _, _) | Content::Structure(FlatType::Apply(symbol, _))
+            if symbol.module_id() == ModuleId::NUM =>
+        {
+            render_dbg_number(env, value, result, hole)
+        }
+        Content::RangedNumber(_) => render_dbg_number(env, value, result, hole),
+        Content::Alias(symbol, _, real_var, AliasKind::Opaque) => {
+            let mut pieces = std::vec::Vec::new();
+
+            match dbg_opaque_name(env, symbol) {
+                Some(name) => {
+                    push_dbg_text(env.arena, &mut pieces, if value.parens { "(" } else { "" });
+                    push_dbg_text(env.arena, &mut pieces, &name);
+                    push_dbg_text(env.arena, &mut pieces, " ");
+                    pieces.push(DbgPiece::Value(DbgValue {
+                        var: real_var,
+                        parens: true,
+                        ..value
+                    }));
+                    if value.parens {
+                        push_dbg_text(env.arena, &mut pieces, ")");
+                    }
+                }
+                None => pieces.push(DbgPiece::Value(DbgValue {
+                    var: real_var,
+                    ..value
+                })),
+            }
+
+            render_dbg_pieces(env, procs, layout_cache, render, pieces, result, hole)
+        }
+        Content::Structure(flat_type) => match flat_type {
+            FlatType::Apply(Symbol::STR_STR, _) => {
+                render_dbg_str(env, procs, layout_cache, render, value, result, hole)
+            }
+            FlatType::Apply(Symbol::LIST_LIST, _) => {
+                render_dbg_list(env, procs, layout_cache, render, value, result, hole)
+            }
+            FlatType::Apply(Symbol::BOX_BOX_TYPE, _) => {
+                render_dbg_box(env, procs, layout_cache, render, value, result, hole)
+            }
+            FlatType::Apply(..) => text("<opaque>"),
+            FlatType::Func(..) => text("<function>"),
+            FlatType::Record(..) | FlatType::EmptyRecord => {
+                render_dbg_record(env, procs, layout_cache, render, value, result, hole)
+            }
+            FlatType::TagUnion(..)
+            | FlatType::RecursiveTagUnion(..)
+            | FlatType::FunctionOrTagUnion(..)
+            | FlatType::EmptyTagUnion => {
+                render_dbg_tag_union(env, procs, layout_cache, render, value, result, hole)
+            }
+            FlatType::Erroneous(_) => text("<error>"),
+        },
+        Content::LambdaSet(_) => text("<function>"),
+        Content::FlexVar(_)
+        | Content::RigidVar(_)
+        | Content::FlexAbleVar(..)
+        | Content::RigidAbleVar(..)
+        | Content::Error => text("<unknown>"),
+    }
+}
+
+fn render_dbg_number<'a>(
+    env: &mut Env<'a, '_>,
+    value: DbgValue<'a>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    match value.layout {
+        Layout::Builtin(Builtin::Int(_) | Builtin::Float(_) | Builtin::Decimal) => {
+            let expr = low_level_call(env, LowLevel::NumToStr, &[value.symbol]);
+
+            Stmt::Let(result, expr, Layout::Builtin(Builtin::Str), hole)
+        }
+        other => internal_error!("a number with layout {:?}", other),
+    }
+}
+
+/// The name of the opaque type `symbol`, like `@Age`. An opaque type of another module is only
+/// named if that module exposes it.
+fn dbg_opaque_name(env: &Env, symbol: Symbol) -> Option<String> {
+    let ident_ids = if symbol.module_id() == env.home {
+        Some(&*env.ident_ids)
+    } else {
+        env.exposed_idents.get(&symbol.module_id())
+    };
+
+    let name = ident_ids?.get_name(symbol.ident_id())?;
+
+    Some(format!("@{}", name))
+}
+
+/// Renders a `Str` like a string literal in source code: in quotes, and with the characters that
+/// cannot appear in a literal as such escaped
+fn render_dbg_str<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    value: DbgValue<'a>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    // the backslash goes first, so that the backslashes of the other escapes stay as they are
+    const ESCAPES: [(&str, &str); 5] = [
+        ("\\", "\\\\"),
+        ("\"", "\\\""),
+        ("\n", "\\n"),
+        ("\r", "\\r"),
+        ("\t", "\\t"),
+    ];
+
+    let str_layout = Layout::Builtin(Builtin::Str);
+    let list_layout = Layout::Builtin(Builtin::List(env.arena.alloc(str_layout)));
+
+    // every escape splits the string at a character, and joins the parts with its escape
+    let mut steps = std::vec::Vec::with_capacity(ESCAPES.len());
+    let mut escaped = value.symbol;
+    for (character, escape) in ESCAPES {
+        let step = [
+            env.unique_symbol(),
+            env.unique_symbol(),
+            env.unique_symbol(),
+            env.unique_symbol(),
+        ];
+        steps.push((escaped, character, escape, step));
+        escaped = step[3];
+    }
+
+    let pieces = vec![
+        DbgPiece::Text("\""),
+        DbgPiece::Str(escaped),
+        DbgPiece::Text("\""),
+    ];
+
+    let mut stmt = render_dbg_pieces(env, procs, layout_cache, render, pieces, result, hole);
+
+    for (string, character, escape, [delimiter, parts, replacement, joined]) in
+        steps.into_iter().rev()
+    {
+        let join = low_level_call(env, LowLevel::StrJoinWith, &[parts, replacement]);
+        let split = low_level_call(env, LowLevel::StrSplit, &[string, delimiter]);
+
+        stmt = Stmt::Let(joined, join, str_layout, env.arena.alloc(stmt));
+        stmt = Stmt::Let(
+            replacement,
+            Expr::Literal(Literal::Str(escape)),
+            str_layout,
+            env.arena.alloc(stmt),
+        );
+        stmt = Stmt::Let(parts, split, list_layout, env.arena.alloc(stmt));
+        stmt = Stmt::Let(
+            delimiter,
+            Expr::Literal(Literal::Str(character)),
+            str_layout,
+            env.arena.alloc(stmt),
+        );
+    }
+
+    stmt
+}
+
+/// Renders each piece into its own `Str`, and concatenates them into `result`
+fn render_dbg_pieces<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    pieces: std::vec::Vec<DbgPiece<'a>>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    let str_layout = Layout::Builtin(Builtin::Str);
+
+    let symbols: std::vec::Vec<Symbol> = pieces
+        .iter()
+        .enumerate()
+        .map(|(index, piece)| match piece {
+            DbgPiece::Str(symbol) => *symbol,
+            _ if pieces.len() == 1 && index == 0 => result,
+            _ => env.unique_symbol(),
+        })
+        .collect();
+
+    let mut stmt = match symbols.as_slice() {
+        [only] if *only == result => hole.clone(),
+        [only] => {
+            // only a symbol that already holds a `Str`; it must still end up in `result`
+            let empty = env.unique_symbol();
+            let concat = low_level_call(env, LowLevel::StrConcat, &[empty, *only]);
+            let stmt = Stmt::Let(result, concat, str_layout, hole);
+
+            Stmt::Let(
+                empty,
+                Expr::Literal(Literal::Str("")),
+                str_layout,
+                env.arena.alloc(stmt),
+            )
+        }
+        [first, rest @ ..] => {
+            let mut accumulated = std::vec::Vec::with_capacity(rest.len());
+            for index in 0..rest.len() {
+                accumulated.push(if index + 1 == rest.len() {
+                    result
+                } else {
+                    env.unique_symbol()
+                });
+            }
+
+            let mut stmt = hole.clone();
+            for (index, symbol) in rest.iter().enumerate().rev() {
+                let previous = if index == 0 {
+                    *first
+                } else {
+                    accumulated[index - 1]
+                };
+
+                let concat = low_level_call(env, LowLevel::StrConcat, &[previous, *symbol]);
+                stmt = Stmt::Let(
+                    accumulated[index],
+                    concat,
+                    str_layout,
+                    env.arena.alloc(stmt),
+                );
+            }
+
+            stmt
+        }
+        [] => Stmt::Let(result, Expr::Literal(Literal::Str("")), str_layout, hole),
+    };
+
+    for (piece, symbol) in pieces.into_iter().zip(symbols).rev() {
+        stmt = match piece {
+            DbgPiece::Str(_) => stmt,
+            DbgPiece::Text(text) => Stmt::Let(
+                symbol,
+                Expr::Literal(Literal::Str(text)),
+                str_layout,
+                env.arena.alloc(stmt),
+            ),
+            DbgPiece::Value(value) => render_dbg_value(
+                env,
+                procs,
+                layout_cache,
+                render,
+                value,
+                symbol,
+                env.arena.alloc(stmt),
+            ),
+        };
+    }
+
+    stmt
+}
+
+fn render_dbg_box<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    value: DbgValue<'a>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    let inner_var = match env.subs.get_content_without_compacting(value.var) {
+        Content::Structure(FlatType::Apply(_, args)) => env.subs[args.into_iter().next().unwrap()],
+        Content::Alias(_, args, _, _) => env.subs[args.all_variables().into_iter().next().unwrap()],
+        other => internal_error!("a Box with content {:?}", other),
+    };
+
+    let inner_layout = match value.layout {
+        Layout::Boxed(inner) => *inner,
+        other => internal_error!("a Box with layout {:?}", other),
+    };
+
+    let unboxed = env.unique_symbol();
+
+    let mut pieces = std::vec::Vec::new();
+    push_dbg_text(env.arena, &mut pieces, if value.parens { "(" } else { "" });
+    push_dbg_text(env.arena, &mut pieces, "Box.box ");
+    pieces.push(DbgPiece::Value(DbgValue {
+        symbol: unboxed,
+        var: inner_var,
+        layout: inner_layout,
+        parens: true,
+    }));
+    if value.parens {
+        push_dbg_text(env.arena, &mut pieces, ")");
+    }
+
+    let stmt = render_dbg_pieces(env, procs, layout_cache, render, pieces, result, hole);
+
+    Stmt::Let(
+        unboxed,
+        Expr::ExprUnbox {
+            symbol: value.symbol,
+        },
+        inner_layout,
+        env.arena.alloc(stmt),
+    )
+}
+
+fn render_dbg_record<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    value: DbgValue<'a>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    let sorted_fields_result = {
+        let mut layout_env =
+            layout::Env::from_components(layout_cache, env.subs, env.arena, env.target_info);
+        layout::sort_record_fields(&mut layout_env, value.var)
+    };
+
+    let sorted_fields = match sorted_fields_result {
+        Ok(fields) => fields,
+        Err(_) => return Stmt::RuntimeError("Can't dbg a record with improper layout"),
+    };
+
+    // fields that are optional and missing are not stored
+    let mut fields = std::vec::Vec::with_capacity(sorted_fields.len());
+    let mut field_layouts = Vec::with_capacity_in(sorted_fields.len(), env.arena);
+    for (label, field_var, opt_field_layout) in sorted_fields.into_iter() {
+        if let Ok(field_layout) = opt_field_layout {
+            fields.push((label, field_var, field_layouts.len()));
+            field_layouts.push(field_layout);
+        }
+    }
+
+    // prefer the layout we were given, which may refer to a recursive tag union we are in
+    let field_layouts = match value.layout {
+        Layout::Struct { field_layouts, .. } if field_layouts.len() == fields.len() => {
+            field_layouts
+        }
+        _ => field_layouts.into_bump_slice(),
+    };
+
+    let is_tuple = tuple_arity(fields.iter().map(|(label, _, _)| label)).is_some();
+
+    if is_tuple {
+        fields.sort_by_key(|(label, _, _)| label.as_tuple_elem());
+    } else {
+        fields.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+    }
+
+    if fields.is_empty() {
+        return Stmt::Let(
+            result,
+            Expr::Literal(Literal::Str("{}")),
+            Layout::Builtin(Builtin::Str),
+            hole,
+        );
+    }
+
+    let mut loads = std::vec::Vec::with_capacity(fields.len());
+    let mut pieces = std::vec::Vec::new();
+
+    push_dbg_text(env.arena, &mut pieces, if is_tuple { "(" } else { "{ " });
+
+    for (position, (label, field_var, index)) in fields.iter().enumerate() {
+        if position > 0 {
+            push_dbg_text(env.arena, &mut pieces, ", ");
+        }
+
+        if !is_tuple {
+            push_dbg_text(env.arena, &mut pieces, label.as_str());
+            push_dbg_text(env.arena, &mut pieces, ": ");
+        }
+
+        // a record with just one field is represented by that field
+        let field_symbol = if field_layouts.len() == 1 {
+            value.symbol
+        } else {
+            let field_symbol = env.unique_symbol();
+            let load = Expr::StructAtIndex {
+                index: *index as u64,
+                field_layouts,
+                structure: value.symbol,
+            };
+            loads.push((field_symbol, field_layouts[*index], load));
+
+            field_symbol
+        };
+
+        pieces.push(DbgPiece::Value(DbgValue {
+            symbol: field_symbol,
+            var: *field_var,
+            layout: field_layouts[*index],
+            parens: false,
+        }));
+    }
+
+    push_dbg_text(env.arena, &mut pieces, if is_tuple { ")" } else { " }" });
+
+    let stmt = render_dbg_pieces(env, procs, layout_cache, render, pieces, result, hole);
+
+    prepend_stores(env, loads.into_iter(), stmt)
+}
+
+fn render_dbg_list<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    value: DbgValue<'a>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    // the loop over the elements is in a proc of its own, so that everything it needs is passed
+    // from one iteration to the next
+    let root = env.subs.get_root_key_without_compacting(value.var);
+
+    let existing = render
+        .list_procs
+        .iter()
+        .find(|(var, layout, _)| *var == root && *layout == value.layout);
+
+    let proc_symbol = match existing {
+        Some((_, _, proc_symbol)) => *proc_symbol,
+        None => {
+            let proc_symbol = env.unique_symbol();
+            render.list_procs.push((root, value.layout, proc_symbol));
+
+            let argument = env.unique_symbol();
+            let body = render_dbg_list_loop(
+                env,
+                procs,
+                layout_cache,
+                render,
+                DbgValue {
+                    symbol: argument,
+                    ..value
+                },
+            );
+
+            insert_dbg_proc(env, procs, proc_symbol, value.layout, argument, body);
+
+            proc_symbol
+        }
+    };
+
+    let call = dbg_proc_call(env, proc_symbol, value.layout, value.symbol);
+
+    Stmt::Let(result, call, Layout::Builtin(Builtin::Str), hole)
+}
+
+/// Renders a list by looping over its elements, and returns the result
+///
+/// ```text
+/// join loop list len index accumulated separator =
+///     if index < len then
+///         element = List.getUnsafe list index
+///         jump loop list len (index + 1) (accumulated ++ separator ++ render element) ", "
+///     else
+///         ret (accumulated ++ "]")
+/// in
+/// jump loop list (List.len list) 0 "[" ""
+/// ```
+fn render_dbg_list_loop<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    value: DbgValue<'a>,
+) -> Stmt<'a> {
+    let arena = env.arena;
+    let str_layout = Layout::Builtin(Builtin::Str);
+    let usize_layout = Layout::usize(env.target_info);
+    let str_literal = |text: &'a str| Expr::Literal(Literal::Str(text));
+
+    let element_var = match env.subs.get_content_without_compacting(value.var) {
+        Content::Structure(FlatType::Apply(_, args)) => env.subs[args.into_iter().next().unwrap()],
+        other => internal_error!("a List with content {:?}", other),
+    };
+
+    let element_layout = match value.layout {
+        Layout::Builtin(Builtin::List(element_layout)) => *element_layout,
+        other => internal_error!("a List with layout {:?}", other),
+    };
+
+    let looped = JoinPointId(env.unique_symbol());
+
+    let list = env.unique_symbol();
+    let len = env.unique_symbol();
+    let index = env.unique_symbol();
+    let accumulated = env.unique_symbol();
+    let separator = env.unique_symbol();
+
+    // the elements are done
+    let done = {
+        let close = env.unique_symbol();
+        let closed = env.unique_symbol();
+
+        let ret = arena.alloc(Stmt::Ret(closed));
+        let concat = low_level_call(env, LowLevel::StrConcat, &[accumulated, close]);
+        let stmt = Stmt::Let(closed, concat, str_layout, ret);
+
+        Stmt::Let(close, str_literal("]"), str_layout, arena.alloc(stmt))
+    };
+
+    // render the element at `index`, and continue with the next one
+    let next = {
+        let element = env.unique_symbol();
+        let rendered = env.unique_symbol();
+        let separated = env.unique_symbol();
+        let next_accumulated = env.unique_symbol();
+        let one = env.unique_symbol();
+        let next_index = env.unique_symbol();
+        let comma = env.unique_symbol();
+
+        let jump = Stmt::Jump(
+            looped,
+            arena.alloc([list, len, next_index, next_accumulated, comma]),
+        );
+
+        let stores = [
+            (
+                separated,
+                str_layout,
+                low_level_call(env, LowLevel::StrConcat, &[accumulated, separator]),
+            ),
+            (
+                next_accumulated,
+                str_layout,
+                low_level_call(env, LowLevel::StrConcat, &[separated, rendered]),
+            ),
+            (one, usize_layout, usize_literal(1)),
+            (
+                next_index,
+                usize_layout,
+                low_level_call(env, LowLevel::NumAdd, &[index, one]),
+            ),
+            (comma, str_layout, str_literal(", ")),
+        ];
+        let stmt = prepend_stores(env, stores.into_iter(), jump);
+
+        let element_value = DbgValue {
+            symbol: element,
+            var: element_var,
+            layout: element_layout,
+            parens: false,
+        };
+        let stmt = render_dbg_value(
+            env,
+            procs,
+            layout_cache,
+            render,
+            element_value,
+            rendered,
+            arena.alloc(stmt),
+        );
+
+        let element_layout = match element_layout {
+            Layout::RecursivePointer => Layout::Union(
+                render
+                    .recursive_union
+                    .expect("a recursive pointer outside of a recursive tag union"),
+            ),
+            other => other,
+        };
+        let get = low_level_call(env, LowLevel::ListGetUnsafe, &[list, index]);
+
+        Stmt::Let(element, get, element_layout, arena.alloc(stmt))
+    };
+
+    let more = env.unique_symbol();
+    let loop_body = Stmt::Let(
+        more,
+        low_level_call(env, LowLevel::NumLt, &[index, len]),
+        Layout::Builtin(Builtin::Bool),
+        arena.alloc(Stmt::Switch {
+            cond_symbol: more,
+            cond_layout: Layout::Builtin(Builtin::Bool),
+            branches: arena.alloc([(1, BranchInfo::None, next)]),
+            default_branch: (BranchInfo::None, arena.alloc(done)),
+            ret_layout: str_layout,
+        }),
+    );
+
+    let start_len = env.unique_symbol();
+    let zero = env.unique_symbol();
+    let open = env.unique_symbol();
+    let empty = env.unique_symbol();
+
+    let param = |symbol, layout| Param {
+        symbol,
+        borrow: false,
+        layout,
+    };
+
+    let start = Stmt::Join {
+        id: looped,
+        parameters: arena.alloc([
+            param(list, value.layout),
+            param(len, usize_layout),
+            param(index, usize_layout),
+            param(accumulated, str_layout),
+            param(separator, str_layout),
+        ]),
+        body: arena.alloc(loop_body),
+        remainder: arena.alloc(Stmt::Jump(
+            looped,
+            arena.alloc([value.symbol, start_len, zero, open, empty]),
+        )),
+    };
+
+    let stores = [
+        (
+            start_len,
+            usize_layout,
+            low_level_call(env, LowLevel::ListLen, &[value.symbol]),
+        ),
+        (zero, usize_layout, usize_literal(0)),
+        (open, str_layout, str_literal("[")),
+        (empty, str_layout, str_literal("")),
+    ];
+
+    prepend_stores(env, stores.into_iter(), start)
+}
+
+/// The arguments of a tag, in the order they are written in, together with where each one is
+/// stored; arguments that are not stored (because they are zero-sized) have no position
+type DbgTagArguments = std::vec::Vec<(Variable, Option<usize>)>;
+
+/// The tags of a tag union, sorted by name
+fn dbg_union_tags<'a>(
+    env: &mut Env<'a, '_>,
+    layout_cache: &mut LayoutCache<'a>,
+    var: Variable,
+) -> std::vec::Vec<(TagName, DbgTagArguments)> {
+    let mut tags = std::vec::Vec::new();
+    roc_types::pretty_print::chase_ext_tag_union(env.subs, var, &mut tags);
+    tags.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let is_single_tag = tags.len() == 1;
+
+    let mut result = std::vec::Vec::with_capacity(tags.len());
+
+    for (tag_name, vars) in tags {
+        // the arguments are stored sorted by alignment, like `union_sorted_tags` does
+        let mut stored = std::vec::Vec::with_capacity(vars.len());
+
+        for (position, var) in vars.iter().enumerate() {
+            let layout = match layout_cache.from_var(env.arena, *var, env.subs) {
+                // a union with just one tag drops its zero-sized arguments
+                Ok(layout) if is_single_tag && layout.is_dropped_because_empty() => continue,
+                Ok(layout) => layout,
+                Err(LayoutProblem::UnresolvedTypeVar(_)) => Layout::VOID,
+                Err(LayoutProblem::Erroneous) => continue,
+            };
+
+            let alignment = layout.alignment_bytes(&layout_cache.interner, env.target_info);
+            stored.push((alignment, position));
+        }
+
+        stored.sort_by_key(|(alignment, _)| std::cmp::Reverse(*alignment));
+
+        let mut arguments: DbgTagArguments = vars.iter().map(|var| (*var, None)).collect();
+        for (index, (_, position)) in stored.into_iter().enumerate() {
+            arguments[position].1 = Some(index);
+        }
+
+        result.push((tag_name, arguments));
+    }
+
+    result
+}
+
+/// The text of a value that is not stored because it is zero-sized, like `{}`
+fn dbg_zero_sized_text(env: &Env, var: Variable, parens: bool) -> Option<String> {
+    let subs = &*env.subs;
+
+    match subs.get_content_without_compacting(var) {
+        Content::RecursionVar { structure, .. } => dbg_zero_sized_text(env, *structure, parens),
+        Content::Alias(_, _, real_var, AliasKind::Structural) => {
+            dbg_zero_sized_text(env, *real_var, parens)
+        }
+        Content::Alias(symbol, _, real_var, AliasKind::Opaque) => {
+            match dbg_opaque_name(env, *symbol) {
+                Some(name) => {
+                    let text = format!("{} {}", name, dbg_zero_sized_text(env, *real_var, true)?);
+
+                    if parens {
+                        Some(format!("({})", text))
+                    } else {
+                        Some(text)
+                    }
+                }
+                None => dbg_zero_sized_text(env, *real_var, parens),
+            }
+        }
+        Content::Structure(FlatType::EmptyRecord) => Some("{}".to_string()),
+        Content::Structure(FlatType::Record(fields, ext)) => {
+            let mut texts = std::vec::Vec::new();
+
+            for (label, field) in fields.sorted_iterator(subs, *ext) {
+                let field_text = dbg_zero_sized_text(env, *field.as_inner(), false)?;
+                texts.push(format!("{}: {}", label.as_str(), field_text));
+            }
+
+            Some(format!("{{ {} }}", texts.join(", ")))
+        }
+        Content::Structure(
+            FlatType::TagUnion(..)
+            | FlatType::RecursiveTagUnion(..)
+            | FlatType::FunctionOrTagUnion(..),
+        ) => {
+            let mut tags = std::vec::Vec::new();
+            roc_types::pretty_print::chase_ext_tag_union(subs, var, &mut tags);
+
+            match tags.as_slice() {
+                [(tag_name, arguments)] => {
+                    let mut text = tag_name.0.as_str().to_string();
+
+                    for argument in arguments {
+                        text.push(' ');
+                        text.push_str(&dbg_zero_sized_text(env, *argument, true)?);
+                    }
+
+                    if parens && !arguments.is_empty() {
+                        Some(format!("({})", text))
+                    } else {
+                        Some(text)
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The pieces of `Tag arg1 arg2`, where `loaded` are the values of the stored arguments
+fn dbg_tag_pieces<'a>(
+    env: &Env<'a, '_>,
+    tag_name: &TagName,
+    arguments: &DbgTagArguments,
+    loaded: &[DbgValue<'a>],
+    parens: bool,
+) -> std::vec::Vec<DbgPiece<'a>> {
+    let arena = env.arena;
+    let parens = parens && !arguments.is_empty();
+
+    let mut pieces = std::vec::Vec::new();
+
+    if parens {
+        push_dbg_text(arena, &mut pieces, "(");
+    }
+
+    push_dbg_text(arena, &mut pieces, tag_name.0.as_str());
+
+    for (var, index) in arguments {
+        push_dbg_text(arena, &mut pieces, " ");
+
+        match index {
+            Some(index) => pieces.push(DbgPiece::Value(loaded[*index])),
+            None => {
+                let text = dbg_zero_sized_text(env, *var, true);
+                push_dbg_text(arena, &mut pieces, text.as_deref().unwrap_or("_"));
+            }
+        }
+    }
+
+    if parens {
+        push_dbg_text(arena, &mut pieces, ")");
+    }
+
+    pieces
+}
+
+/// Renders one of a few tags into `result` with a `Switch` on `cond_symbol`, where the last
+/// branch is the default
+fn dbg_switch<'a>(
+    env: &mut Env<'a, '_>,
+    cond_symbol: Symbol,
+    cond_layout: Layout<'a>,
+    mut branches: std::vec::Vec<(u64, Stmt<'a>)>,
+    after: JoinPointId,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    let arena = env.arena;
+    let (_, default) = branches.pop().expect("a tag union without tags");
+
+    let switch = Stmt::Switch {
+        cond_symbol,
+        cond_layout,
+        branches: Vec::from_iter_in(
+            branches
+                .into_iter()
+                .map(|(id, stmt)| (id, BranchInfo::None, stmt)),
+            arena,
+        )
+        .into_bump_slice(),
+        default_branch: (BranchInfo::None, arena.alloc(default)),
+        ret_layout: Layout::Builtin(Builtin::Str),
+    };
+
+    Stmt::Join {
+        id: after,
+        parameters: arena.alloc([Param {
+            symbol: result,
+            borrow: false,
+            layout: Layout::Builtin(Builtin::Str),
+        }]),
+        body: hole,
+        remainder: arena.alloc(switch),
+    }
+}
+
+fn render_dbg_tag_union<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    value: DbgValue<'a>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    use crate::layout::UnionVariant::*;
+
+    let arena = env.arena;
+
+    let variant = {
+        let mut layout_env =
+            layout::Env::from_components(layout_cache, env.subs, env.arena, env.target_info);
+        layout::union_sorted_tags(&mut layout_env, value.var)
+    };
+
+    let variant = match variant {
+        Ok(variant) => variant,
+        Err(_) => return Stmt::RuntimeError("Can't dbg a tag union with improper layout"),
+    };
+
+    let tags = dbg_union_tags(env, layout_cache, value.var);
+
+    match variant {
+        Never => Stmt::Let(
+            result,
+            Expr::Literal(Literal::Str("<never>")),
+            Layout::Builtin(Builtin::Str),
+            hole,
+        ),
+        Unit | UnitWithArguments => {
+            let (tag_name, arguments) = &tags[0];
+            let pieces = dbg_tag_pieces(env, tag_name, arguments, &[], value.parens);
+
+            render_dbg_pieces(env, procs, layout_cache, render, pieces, result, hole)
+        }
+        BoolUnion { ttrue, ffalse } => {
+            let after = JoinPointId(env.unique_symbol());
+            let mut branches = std::vec::Vec::with_capacity(2);
+
+            for (id, tag_name) in [(1, ttrue), (0, ffalse)] {
+                let rendered = env.unique_symbol();
+                let jump = arena.alloc(Stmt::Jump(after, arena.alloc([rendered])));
+                let text = arena.alloc_str(tag_name.expect_tag_ref().0.as_str());
+                let stmt = Stmt::Let(
+                    rendered,
+                    Expr::Literal(Literal::Str(text)),
+                    Layout::Builtin(Builtin::Str),
+                    jump,
+                );
+
+                branches.push((id, stmt));
+            }
+
+            dbg_switch(
+                env,
+                value.symbol,
+                Layout::Builtin(Builtin::Bool),
+                branches,
+                after,
+                result,
+                hole,
+            )
+        }
+        ByteUnion(tag_names) => {
+            let after = JoinPointId(env.unique_symbol());
+            let mut branches = std::vec::Vec::with_capacity(tag_names.len());
+
+            for (id, tag_name) in tag_names.iter().enumerate() {
+                let rendered = env.unique_symbol();
+                let jump = arena.alloc(Stmt::Jump(after, arena.alloc([rendered])));
+                let text = arena.alloc_str(tag_name.expect_tag_ref().0.as_str());
+                let stmt = Stmt::Let(
+                    rendered,
+                    Expr::Literal(Literal::Str(text)),
+                    Layout::Builtin(Builtin::Str),
+                    jump,
+                );
+
+                branches.push((id as u64, stmt));
+            }
+
+            dbg_switch(
+                env,
+                value.symbol,
+                Layout::Builtin(Builtin::Int(IntWidth::U8)),
+                branches,
+                after,
+                result,
+                hole,
+            )
+        }
+        Newtype {
+            arguments: field_layouts,
+            ..
+        } => {
+            let (tag_name, arguments) = &tags[0];
+
+            let field_layouts = field_layouts.into_bump_slice();
+            let mut loads = std::vec::Vec::with_capacity(field_layouts.len());
+            let mut loaded = std::vec::Vec::with_capacity(field_layouts.len());
+
+            for index in 0..field_layouts.len() {
+                let var = arguments
+                    .iter()
+                    .find(|(_, stored)| *stored == Some(index))
+                    .map(|(var, _)| *var)
+                    .expect("a stored argument that is not in the tag");
+
+                // a tag with just one argument is represented by that argument
+                let symbol = if field_layouts.len() == 1 {
+                    value.symbol
+                } else {
+                    let symbol = env.unique_symbol();
+                    let load = Expr::StructAtIndex {
+                        index: index as u64,
+                        field_layouts,
+                        structure: value.symbol,
+                    };
+                    loads.push((symbol, field_layouts[index], load));
+
+                    symbol
+                };
+
+                loaded.push(DbgValue {
+                    symbol,
+                    var,
+                    layout: field_layouts[index],
+                    parens: true,
+                });
+            }
+
+            let pieces = dbg_tag_pieces(env, tag_name, arguments, &loaded, value.parens);
+            let stmt = render_dbg_pieces(env, procs, layout_cache, render, pieces, result, hole);
+
+            prepend_stores(env, loads.into_iter(), stmt)
+        }
+        Wrapped(variant) => {
+            let union_layout = match value.layout {
+                Layout::Union(union_layout) => union_layout,
+                other => internal_error!("a wrapped tag union with layout {:?}", other),
+            };
+
+            match union_layout {
+                UnionLayout::NonRecursive(_) => render_dbg_wrapped(
+                    env,
+                    procs,
+                    layout_cache,
+                    render,
+                    &variant,
+                    &tags,
+                    union_layout,
+                    value,
+                    result,
+                    hole,
+                ),
+                UnionLayout::Recursive(_)
+                | UnionLayout::NonNullableUnwrapped(_)
+                | UnionLayout::NullableWrapped { .. }
+                | UnionLayout::NullableUnwrapped { .. } => {
+                    // recursive values are rendered by a proc that calls itself
+                    let proc_symbol = dbg_union_proc(
+                        env,
+                        procs,
+                        layout_cache,
+                        render,
+                        &variant,
+                        &tags,
+                        union_layout,
+                        value,
+                    );
+                    let union_layout = Layout::Union(union_layout);
+                    let call = dbg_proc_call(env, proc_symbol, union_layout, value.symbol);
+
+                    Stmt::Let(result, call, Layout::Builtin(Builtin::Str), hole)
+                }
+            }
+        }
+    }
+}
+
+/// Renders a tag union that is stored as a (possibly recursive) `Union`, by switching on its tag
+#[allow(clippy::too_many_arguments)]
+fn render_dbg_wrapped<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    variant: &WrappedVariant<'a>,
+    tags: &[(TagName, DbgTagArguments)],
+    union_layout: UnionLayout<'a>,
+    value: DbgValue<'a>,
+    result: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    let arena = env.arena;
+    let after = JoinPointId(env.unique_symbol());
+
+    let mut branches = std::vec::Vec::with_capacity(tags.len());
+
+    for (tag_name, arguments) in tags {
+        let (tag_id, argument_layouts) = variant.tag_name_to_id(tag_name);
+
+        let mut loads = std::vec::Vec::with_capacity(argument_layouts.len());
+        let mut loaded = std::vec::Vec::with_capacity(argument_layouts.len());
+
+        for (index, layout) in argument_layouts.iter().enumerate() {
+            let var = arguments
+                .iter()
+                .find(|(_, stored)| *stored == Some(index))
+                .map(|(var, _)| *var)
+                .expect("a stored argument that is not in the tag");
+
+            let layout = match layout {
+                Layout::RecursivePointer => Layout::Union(union_layout),
+                other => *other,
+            };
+
+            let symbol = env.unique_symbol();
+            let load = Expr::UnionAtIndex {
+                structure: value.symbol,
+                tag_id,
+                union_layout,
+                index: index as u64,
+            };
+            loads.push((symbol, layout, load));
+
+            loaded.push(DbgValue {
+                symbol,
+                var,
+                layout,
+                parens: true,
+            });
+        }
+
+        let rendered = env.unique_symbol();
+        let jump = arena.alloc(Stmt::Jump(after, arena.alloc([rendered])));
+
+        let pieces = dbg_tag_pieces(env, tag_name, arguments, &loaded, value.parens);
+        let stmt = render_dbg_pieces(env, procs, layout_cache, render, pieces, rendered, jump);
+
+        branches.push((tag_id as u64, prepend_stores(env, loads.into_iter(), stmt)));
+    }
+
+    let tag_id_symbol = env.unique_symbol();
+    let tag_id_layout = union_layout.tag_id_layout();
+    let stmt = dbg_switch(
+        env,
+        tag_id_symbol,
+        tag_id_layout,
+        branches,
+        after,
+        result,
+        hole,
+    );
+
+    Stmt::Let(
+        tag_id_symbol,
+        Expr::GetTagId {
+            structure: value.symbol,
+            union_layout,
+        },
+        tag_id_layout,
+        arena.alloc(stmt),
+    )
+}
+
+/// The proc that renders values of a recursive tag union; it is only made once per union
+#[allow(clippy::too_many_arguments)]
+fn dbg_union_proc<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    render: &mut DbgRender<'a>,
+    variant: &WrappedVariant<'a>,
+    tags: &[(TagName, DbgTagArguments)],
+    union_layout: UnionLayout<'a>,
+    value: DbgValue<'a>,
+) -> Symbol {
+    let arena = env.arena;
+    let root = env.subs.get_root_key_without_compacting(value.var);
+
+    let existing = render
+        .union_procs
+        .iter()
+        .find(|(var, parens, _)| *var == root && *parens == value.parens);
+
+    if let Some((_, _, proc_symbol)) = existing {
+        return *proc_symbol;
+    }
+
+    let proc_symbol = env.unique_symbol();
+    render.union_procs.push((root, value.parens, proc_symbol));
+
+    let argument = env.unique_symbol();
+    let rendered = env.unique_symbol();
+    let ret = arena.alloc(Stmt::Ret(rendered));
+
+    let outer_union = render.recursive_union.replace(union_layout);
+    let body = render_dbg_wrapped(
+        env,
+        procs,
+        layout_cache,
+        render,
+        variant,
+        tags,
+        union_layout,
+        DbgValue {
+            symbol: argument,
+            ..value
+        },
+        rendered,
+        ret,
+    );
+    render.recursive_union = outer_union;
+
+    insert_dbg_proc(
+        env,
+        procs,
+        proc_symbol,
+        Layout::Union(union_layout),
+        argument,
+        body,
+    );
+
+    proc_symbol
+}
+
+/// Adds a proc that renders its one argument, of the given layout, to a `Str`
+fn insert_dbg_proc<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    proc_symbol: Symbol,
+    layout: Layout<'a>,
+    argument: Symbol,
+    body: Stmt<'a>,
+) {
+    let proc = Proc {
+        name: LambdaName::no_niche(proc_symbol),
+        args: env.arena.alloc([(layout, argument)]),
+        body,
+        closure_data_layout: None,
+        ret_layout: Layout::Builtin(Builtin::Str),
+        is_self_recursive: SelfRecursive::NotSelfRecursive,
+        must_own_arguments: false,
+        host_exposed_layouts: HostExposedLayouts::NotHostExposed,
+    };
+
+    let proc_layout = ProcLayout {
+        arguments: env.arena.alloc([layout]),
+        result: Layout::Builtin(Builtin::Str),
+        captures_niche: CapturesNiche::no_niche(),
+    };
+
+    procs
+        .specialized
+        .insert_specialized(proc_symbol, proc_layout, proc);
+}
+
+fn dbg_proc_call<'a>(
+    env: &mut Env<'a, '_>,
+    proc_symbol: Symbol,
+    layout: Layout<'a>,
+    argument: Symbol,
+) -> Expr<'a> {
+    Expr::Call(Call {
+        call_type: CallType::ByName {
+            name: LambdaName::no_niche(proc_symbol),
+            ret_layout: env.arena.alloc(Layout::Builtin(Builtin::Str)),
+            arg_layouts: env.arena.alloc([layout]),
+            specialization_id: env.next_call_specialization_id(),
+        },
+        arguments: env.arena.alloc([argument]),
+    })
+}
+
+fn possible_reuse_symbol_or_specialize<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    expr: &roc_can::expr::Expr,
+    var: Variable,
+) -> Symbol {
+    match can_reuse_symbol(env, procs, expr, var) {
+        ReuseSymbol::Value(symbol) => {
+            procs
+                .symbol_specializations
+                .get_or_insert(env, layout_cache, symbol, var)
+        }
+        _ => env.unique_symbol(),
+    }
+}
+
+fn handle_variable_aliasing<'a, BuildRest>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    variable: Variable,
+    left: Symbol,
+    right: Symbol,
+    build_rest: BuildRest,
+) -> Stmt<'a>
+where
+    BuildRest: FnOnce(&mut Env<'a, '_>, &mut Procs<'a>, &mut LayoutCache<'a>) -> Stmt<'a>,
+{
+    // 1. Handle references to ability members - we could be aliasing an ability member, or another
+    //    alias to an ability member.
+    {
+        let is_ability_member = env
+            .abilities
+            .with_module_abilities_store(env.home, |store| store.is_ability_member_name(right));
+
+        if is_ability_member {
+            procs
+                .ability_member_aliases
+                .insert(left, AbilityMember(right));
+            return build_rest(env, procs, layout_cache);
+        }
         if let Some(&ability_member) = procs.ability_member_aliases.get(right) {
             procs.ability_member_aliases.insert(left, ability_member);
             return build_rest(env, procs, layout_cache);
@@ -8649,6 +10414,7 @@ fn call_specialized_proc<'a>(
 pub enum Pattern<'a> {
     Identifier(Symbol),
     Underscore,
+    As(Box<Pattern<'a>>, Symbol),
     IntLiteral([u8; 16], IntWidth),
     FloatLiteral(u64, FloatWidth),
     DecimalLiteral([u8; 16]),
@@ -8680,6 +10446,14 @@ pub enum Pattern<'a> {
         opaque: Symbol,
         argument: Box<(Pattern<'a>, Layout<'a>)>,
     },
+    List {
+        arity: ListArity,
+        list_layout: Layout<'a>,
+        element_layout: Layout<'a>,
+        elements: Vec<'a, Pattern<'a>>,
+        /// The symbol the rest of the list is bound to, e.g. `rest` in `[x, .. as rest]`
+        opt_rest: Option<Symbol>,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -8734,6 +10508,12 @@ fn from_can_pattern_help<'a>(
     match can_pattern {
         Underscore => Ok(Pattern::Underscore),
         Identifier(symbol) => Ok(Pattern::Identifier(*symbol)),
+        As(subpattern, symbol) => {
+            let mono_subpattern =
+                from_can_pattern_help(env, procs, layout_cache, &subpattern.value, assignments)?;
+
+            Ok(Pattern::As(Box::new(mono_subpattern), symbol.value))
+        }
         AbilityMemberSpecialization { ident, .. } => Ok(Pattern::Identifier(*ident)),
         IntLiteral(var, _, int_str, int, _bound) => Ok(make_num_literal_pattern(
             env,
@@ -9372,6 +11152,39 @@ fn from_can_pattern_help<'a>(
                 field_layouts.into_bump_slice(),
             ))
         }
+
+        List {
+            list_var,
+            elem_var,
+            patterns,
+        } => {
+            let list_layout = layout_cache
+                .from_var(env.arena, *list_var, env.subs)
+                .map_err(RuntimeError::from)?;
+            let element_layout = layout_cache
+                .from_var(env.arena, *elem_var, env.subs)
+                .map_err(RuntimeError::from)?;
+
+            let mut mono_patterns = Vec::with_capacity_in(patterns.patterns.len(), env.arena);
+            for loc_pat in patterns.patterns.iter() {
+                let mono_pat =
+                    from_can_pattern_help(env, procs, layout_cache, &loc_pat.value, assignments)?;
+                mono_patterns.push(mono_pat);
+            }
+
+            let opt_rest = patterns
+                .opt_rest
+                .as_ref()
+                .and_then(|(_, opt_name)| opt_name.as_ref().map(|name| name.value));
+
+            Ok(Pattern::List {
+                arity: patterns.arity(),
+                list_layout,
+                element_layout,
+                elements: mono_patterns,
+                opt_rest,
+            })
+        }
     }
 }
 
@@ -4152,6 +4152,14 @@ impl LayoutId {
         let ident_string = symbol.as_str(interns);
         format!("roc__{}_{}_exposed", ident_string, self.0)
     }
+
+    // Returns something like "foo_1" when given a symbol that interns to "foo"
+    // and a LayoutId of 1. Functions that "foo" returns to the host are named after it,
+    // e.g. "roc__foo_1__Fx_caller" for a function exposed with `as Fx`.
+    pub fn to_exposed_alias_def_name(self, symbol: Symbol, interns: &Interns) -> String {
+        let ident_string = symbol.as_str(interns);
+        format!("{}_{}", ident_string, self.0)
+    }
 }
 
 struct IdsByLayout<'a> {
@@ -241,7 +241,32 @@ fn function_s<'a, 'i>(
             }
         }
 
-        Ret(_) | Jump(_, _) | RuntimeError(_) => stmt,
+        Dbg {
+            symbol,
+            message,
+            location,
+            region,
+            remainder,
+        } => {
+            let continuation: &Stmt = *remainder;
+            let new_continuation = function_s(env, w, c, continuation);
+
+            if std::ptr::eq(continuation, new_continuation) || continuation == new_continuation {
+                stmt
+            } else {
+                let new_dbg = Dbg {
+                    symbol: *symbol,
+                    message: *message,
+                    location: *location,
+                    region: *region,
+                    remainder: new_continuation,
+                };
+
+                arena.alloc(new_dbg)
+            }
+        }
+
+        Ret(_) | Jump(_, _) | Crash(..) | RuntimeError(_) => stmt,
     }
 }
 
@@ -504,6 +529,31 @@ fn function_d_main<'a, 'i>(
                 (arena.alloc(refcounting), found)
             }
         }
+        Dbg {
+            symbol,
+            message,
+            location,
+            region,
+            remainder,
+        } => {
+            let (b, found) = function_d_main(env, x, c, remainder);
+
+            let b = if found || *symbol != x {
+                b
+            } else {
+                try_function_s(env, x, c, b)
+            };
+
+            let dbg = Dbg {
+                symbol: *symbol,
+                message: *message,
+                location: *location,
+                region: *region,
+                remainder: b,
+            };
+
+            (arena.alloc(dbg), found)
+        }
         Join {
             id,
             parameters,
@@ -535,7 +585,9 @@ fn function_d_main<'a, 'i>(
 
             (arena.alloc(new_join), found)
         }
-        Ret(_) | Jump(_, _) | RuntimeError(_) => (stmt, has_live_var(&env.jp_live_vars, stmt, x)),
+        Ret(_) | Jump(_, _) | Crash(..) | RuntimeError(_) => {
+            (stmt, has_live_var(&env.jp_live_vars, stmt, x))
+        }
     }
 }
 
@@ -696,7 +748,27 @@ fn function_r<'a, 'i>(env: &mut Env<'a, 'i>, stmt: &'a Stmt<'a>) -> &'a Stmt<'a>
             arena.alloc(expect)
         }
 
-        Ret(_) | Jump(_, _) | RuntimeError(_) => {
+        Dbg {
+            symbol,
+            message,
+            location,
+            region,
+            remainder,
+        } => {
+            let b = function_r(env, remainder);
+
+            let dbg = Dbg {
+                symbol: *symbol,
+                message: *message,
+                location: *location,
+                region: *region,
+                remainder: b,
+            };
+
+            arena.alloc(dbg)
+        }
+
+        Ret(_) | Jump(_, _) | Crash(..) | RuntimeError(_) => {
             // terminals
             stmt
         }
@@ -723,6 +795,7 @@ fn has_live_var<'a>(jp_live_vars: &JPLiveVarMap, stmt: &'a Stmt<'a>, needle: Sym
                     .any(|(_, _, body)| has_live_var(jp_live_vars, body, needle))
         }
         Ret(s) => *s == needle,
+        Crash(m, _, _) => *m == needle,
         Refcounting(modify_rc, cont) => {
             modify_rc.get_symbol() == needle || has_live_var(jp_live_vars, cont, needle)
         }
@@ -736,6 +809,18 @@ fn has_live_var<'a>(jp_live_vars: &JPLiveVarMap, stmt: &'a Stmt<'a>, needle: Sym
             remainder,
             ..
         } => *condition == needle || has_live_var(jp_live_vars, remainder, needle),
+        Dbg {
+            symbol,
+            message,
+            location,
+            remainder,
+            ..
+        } => {
+            *symbol == needle
+                || *message == needle
+                || *location == needle
+                || has_live_var(jp_live_vars, remainder, needle)
+        }
         Join {
             id,
             parameters,
@@ -297,8 +297,33 @@ fn insert_jumps<'a>(
             None => None,
         },
 
+        Dbg {
+            symbol,
+            message,
+            location,
+            region,
+            remainder,
+        } => match insert_jumps(
+            arena,
+            remainder,
+            goal_id,
+            needle,
+            needle_arguments,
+            needle_result,
+        ) {
+            Some(cont) => Some(arena.alloc(Dbg {
+                symbol: *symbol,
+                message: *message,
+                location: *location,
+                region: *region,
+                remainder: cont,
+            })),
+            None => None,
+        },
+
         Ret(_) => None,
         Jump(_, _) => None,
+        Crash(..) => None,
         RuntimeError(_) => None,
     }
 }
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use crate::header::{AppHeader, HostedHeader, InterfaceHeader, PlatformHeader};
+use crate::header::{AppHeader, HostedHeader, InterfaceHeader, PackageHeader, PlatformHeader};
 use crate::ident::Ident;
 use bumpalo::collections::{String, Vec};
 use bumpalo::Bump;
@@ -85,6 +85,7 @@ pub enum Module<'a> {
     Interface { header: InterfaceHeader<'a> },
     App { header: AppHeader<'a> },
     Platform { header: PlatformHeader<'a> },
+    Package { header: PackageHeader<'a> },
     Hosted { header: HostedHeader<'a> },
 }
 
@@ -180,6 +181,9 @@ pub enum Expr<'a> {
 
     Record(Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>),
 
+    /// e.g. `(a, b, c)`; always has at least two elements
+    Tuple(Collection<'a, &'a Loc<Expr<'a>>>),
+
     // Lookups
     Var {
         module_name: &'a str, // module_name will only be filled if the original Roc code stated something like `5 + SomeModule.myVar`, module_name will be blank if it was `5 + myVar`
@@ -188,6 +192,9 @@ pub enum Expr<'a> {
 
     Underscore(&'a str),
 
+    // The "crash" keyword
+    Crash,
+
     // Tags
     Tag(&'a str),
 
@@ -200,6 +207,7 @@ pub enum Expr<'a> {
     Defs(&'a Defs<'a>, &'a Loc<Expr<'a>>),
     Backpassing(&'a [Loc<Pattern<'a>>], &'a Loc<Expr<'a>>, &'a Loc<Expr<'a>>),
     Expect(&'a Loc<Expr<'a>>, &'a Loc<Expr<'a>>),
+    Dbg(&'a Loc<Expr<'a>>, &'a Loc<Expr<'a>>),
 
     // Application
     /// To apply by name, do Apply(Var(...), ...)
@@ -275,14 +283,26 @@ pub enum Has<'a> {
 pub struct AbilityMember<'a> {
     pub name: Loc<Spaced<'a, &'a str>>,
     pub typ: Loc<TypeAnnotation<'a>>,
+    pub default: Option<AbilityMemberDefault<'a>>,
 }
 
 impl AbilityMember<'_> {
     pub fn region(&self) -> Region {
-        Region::across_all([self.name.region, self.typ.region].iter())
+        match &self.default {
+            Some(default) => Region::span_across(&self.name.region, &default.body.region),
+            None => Region::across_all([self.name.region, self.typ.region].iter()),
+        }
     }
 }
 
+/// A default implementation of an ability member, given right after the member's demand; for
+/// example `neq = \a, b -> !(eq a b)` following `neq : a, a -> Bool | a has Eq`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbilityMemberDefault<'a> {
+    pub name: Loc<Spaced<'a, &'a str>>,
+    pub body: &'a Loc<Expr<'a>>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TypeDef<'a> {
     /// A type alias. This is like a standalone annotation, except the pattern
@@ -304,11 +324,24 @@ pub enum TypeDef<'a> {
     /// An ability definition. E.g.
     ///   Hash has
     ///     hash : a -> U64 | a has Hash
+    ///
+    /// An ability may require other abilities of the types implementing it, as in
+    ///   Ord has Eq
+    ///     compare : a, a -> [LT, EQ, GT] | a has Ord
     Ability {
         header: TypeHeader<'a>,
         loc_has: Loc<Has<'a>>,
+        required_abilities: &'a [Loc<TypeAnnotation<'a>>],
         members: &'a [AbilityMember<'a>],
     },
+
+    /// Implementations of abilities for a builtin type, declared in the module defining the
+    /// abilities. E.g.
+    ///   Str has [Pretty { pretty: prettyStr }]
+    BuiltinImpls {
+        header: TypeHeader<'a>,
+        has_abilities: Loc<HasAbilities<'a>>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -519,6 +552,9 @@ pub enum TypeAnnotation<'a> {
         ext: Option<&'a Loc<TypeAnnotation<'a>>>,
     },
 
+    /// A tuple, e.g. `(Str, U64)`; always has at least two elements
+    Tuple(Collection<'a, Loc<TypeAnnotation<'a>>>),
+
     /// A tag union, e.g. `[
     TagUnion {
         /// The row type variable in an open tag union, e.g. the `a` in `[Foo, Bar]a`.
@@ -633,6 +669,19 @@ pub enum Pattern<'a> {
     /// In practice, these patterns will always be Identifier
     RecordDestructure(Collection<'a, Loc<Pattern<'a>>>),
 
+    /// A tuple destructure, e.g. `(x, y)`; always has at least two elements
+    Tuple(Collection<'a, Loc<Pattern<'a>>>),
+
+    /// A list pattern, e.g. `[first, .. as rest]`
+    List(Collection<'a, Loc<Pattern<'a>>>),
+
+    /// The `..` in a list pattern, optionally binding the rest of the list, e.g. `.. as rest`.
+    /// Can only occur inside of a List pattern
+    ListRest(Option<(&'a [CommentOrNewline<'a>], Loc<&'a str>)>),
+
+    /// A pattern that also binds the whole matched value to a name, e.g. `Ok x as result`
+    As(&'a Loc<Pattern<'a>>, PatternAs<'a>),
+
     /// A required field pattern, e.g. { x: Just 0 } -> ...
     /// Can only occur inside of a RecordDestructure
     RequiredField(&'a str, &'a Loc<Pattern<'a>>),
@@ -666,6 +715,14 @@ pub enum Pattern<'a> {
     },
 }
 
+/// The `as name` part of an `as` pattern
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PatternAs<'a> {
+    /// Spaces between the `as` keyword and the name
+    pub spaces_before: &'a [CommentOrNewline<'a>],
+    pub identifier: Loc<&'a str>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Base {
     Octal,
@@ -739,6 +796,29 @@ impl<'a> Pattern<'a> {
                 .iter()
                 .zip(fields_y.iter())
                 .all(|(p, q)| p.value.equivalent(&q.value)),
+            (Tuple(elems_x), Tuple(elems_y)) => {
+                elems_x.len() == elems_y.len()
+                    && elems_x
+                        .iter()
+                        .zip(elems_y.iter())
+                        .all(|(p, q)| p.value.equivalent(&q.value))
+            }
+            (List(elems_x), List(elems_y)) => {
+                elems_x.len() == elems_y.len()
+                    && elems_x
+                        .iter()
+                        .zip(elems_y.iter())
+                        .all(|(p, q)| p.value.equivalent(&q.value))
+            }
+            (ListRest(x), ListRest(y)) => match (x, y) {
+                (None, None) => true,
+                (Some((_, name_x)), Some((_, name_y))) => name_x.value == name_y.value,
+                _ => false,
+            },
+            (As(pattern_x, as_x), As(pattern_y, as_y)) => {
+                as_x.identifier.value == as_y.identifier.value
+                    && pattern_x.value.equivalent(&pattern_y.value)
+            }
             (RequiredField(x, inner_x), RequiredField(y, inner_y)) => {
                 x == y && inner_x.value.equivalent(&inner_y.value)
             }
@@ -1,16 +1,16 @@
 use crate::ast::{
-    AssignedField, Collection, CommentOrNewline, Defs, Expr, ExtractSpaces, Has, HasAbilities,
-    Pattern, Spaceable, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
+    AbilityMember, AssignedField, Collection, CommentOrNewline, Defs, Expr, ExtractSpaces, Has,
+    HasAbilities, Pattern, Spaceable, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
 };
 use crate::blankspace::{
     space0_after_e, space0_around_ee, space0_before_e, space0_before_optional_after, space0_e,
 };
-use crate::ident::{lowercase_ident, parse_ident, Ident};
+use crate::ident::{field_name, lowercase_ident, parse_ident, Ident};
 use crate::keyword;
 use crate::parser::{
     self, backtrackable, optional, sep_by1, sep_by1_e, specialize, specialize_ref, then,
-    trailing_sep_by0, word1, word2, EExpect, EExpr, EIf, EInParens, ELambda, EList, ENumber,
-    EPattern, ERecord, EString, EType, EWhen, Either, ParseResult, Parser,
+    trailing_sep_by0, word1, word2, EAbility, EExpect, EExpr, EIf, EInParens, ELambda, EList,
+    ENumber, EPattern, ERecord, EString, EType, EWhen, Either, ParseResult, Parser,
 };
 use crate::pattern::{loc_closure_param, loc_has_parser};
 use crate::state::State;
@@ -85,34 +85,54 @@ pub fn expr_help<'a>(min_indent: u32) -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
 
 fn loc_expr_in_parens_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Expr<'a>>, EInParens<'a>> {
     move |arena, state| {
-        let (_, loc_expr, state) = loc_expr_in_parens_help_help(min_indent).parse(arena, state)?;
-
-        Ok((
-            MadeProgress,
-            Loc {
-                region: loc_expr.region,
-                value: Expr::ParensAround(arena.alloc(loc_expr.value)),
-            },
-            state,
-        ))
-    }
-}
-
-fn loc_expr_in_parens_help_help<'a>(
-    min_indent: u32,
-) -> impl Parser<'a, Loc<Expr<'a>>, EInParens<'a>> {
-    between!(
-        word1(b'(', EInParens::Open),
-        space0_around_ee(
+        let (_, loc_elements, state) = parens_trailing_sep_e!(
             specialize_ref(EInParens::Expr, move |arena, state| parse_loc_expr(
                 min_indent, arena, state
             )),
             min_indent,
+            EInParens::Open,
+            EInParens::End,
+            EInParens::End,
             EInParens::IndentOpen,
             EInParens::IndentEnd,
-        ),
-        word1(b')', EInParens::End)
-    )
+            EInParens::Empty,
+            Expr::SpaceBefore
+        )
+        .parse(arena, state)?;
+
+        let elements = loc_elements.value;
+
+        if elements.len() > 1 {
+            // e.g. `(a, b)` is a tuple, whose region includes the parentheses
+            Ok((
+                MadeProgress,
+                Loc::at(
+                    loc_elements.region,
+                    Expr::Tuple(elements.ptrify_items(arena)),
+                ),
+                state,
+            ))
+        } else {
+            // e.g. `(a)` or `(a,)` is just `a` in parentheses
+            let loc_expr = elements.items[0];
+            let loc_expr = if elements.final_comments().is_empty() {
+                loc_expr
+            } else {
+                arena
+                    .alloc(loc_expr.value)
+                    .with_spaces_after(elements.final_comments(), loc_expr.region)
+            };
+
+            Ok((
+                MadeProgress,
+                Loc {
+                    region: loc_expr.region,
+                    value: Expr::ParensAround(arena.alloc(loc_expr.value)),
+                },
+                state,
+            ))
+        }
+    }
 }
 
 fn loc_expr_in_parens_etc_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>> {
@@ -183,7 +203,7 @@ fn record_field_access_chain<'a>() -> impl Parser<'a, Vec<'a, &'a str>, EExpr<'a
 fn record_field_access<'a>() -> impl Parser<'a, &'a str, EExpr<'a>> {
     skip_first!(
         word1(b'.', EExpr::Access),
-        specialize(|_, pos| EExpr::Access(pos), lowercase_ident())
+        specialize(|_, pos| EExpr::Access(pos), field_name())
     )
 }
 
@@ -209,6 +229,7 @@ fn parse_loc_term_or_underscore_or_conditional<'a>(
         loc!(underscore_expression()),
         loc!(record_literal_help(min_indent)),
         loc!(specialize(EExpr::List, list_literal_help(min_indent))),
+        loc!(crash_kw()),
         loc!(map_with_arena!(
             assign_or_destructure_identifier(),
             ident_to_expr
@@ -234,6 +255,7 @@ fn parse_loc_term_or_underscore<'a>(
         loc!(underscore_expression()),
         loc!(record_literal_help(min_indent)),
         loc!(specialize(EExpr::List, list_literal_help(min_indent))),
+        loc!(crash_kw()),
         loc!(map_with_arena!(
             assign_or_destructure_identifier(),
             ident_to_expr
@@ -256,6 +278,7 @@ fn parse_loc_term<'a>(
         loc!(specialize(EExpr::Lambda, closure_help(min_indent, options))),
         loc!(record_literal_help(min_indent)),
         loc!(specialize(EExpr::List, list_literal_help(min_indent))),
+        loc!(crash_kw()),
         loc!(map_with_arena!(
             assign_or_destructure_identifier(),
             ident_to_expr
@@ -281,6 +304,15 @@ fn underscore_expression<'a>() -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
     }
 }
 
+fn crash_kw<'a>() -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
+    move |arena: &'a Bump, state: State<'a>| {
+        let (_, _, next_state) =
+            crate::parser::keyword_e(crate::keyword::CRASH, EExpr::Crash).parse(arena, state)?;
+
+        Ok((MadeProgress, Expr::Crash, next_state))
+    }
+}
+
 fn loc_possibly_negative_or_negated_term<'a>(
     min_indent: u32,
     options: ExprParseOptions,
@@ -355,6 +387,7 @@ fn parse_expr_start<'a>(
             when::expr_help(min_indent, options)
         )),
         loc!(specialize(EExpr::Expect, expect_help(min_indent, options))),
+        loc!(specialize(EExpr::Dbg, dbg_help(min_indent, options))),
         loc!(specialize(EExpr::Lambda, closure_help(min_indent, options))),
         loc!(move |a, s| parse_expr_operator_chain(min_indent, options, start_column, a, s)),
         fail_expr_start_e()
@@ -719,9 +752,32 @@ fn parse_defs_end<'a>(
                     };
 
                 if let Some((name, name_region, args)) = opt_tag_and_args {
-                    if let Ok((_, loc_has, state)) =
+                    if let Ok((_, loc_has, has_state)) =
                         loc_has_parser(min_indent).parse(arena, state.clone())
                     {
+                        if starts_with_has_clause(min_indent, arena, has_state.clone()) {
+                            // This implements abilities for a builtin type, as in
+                            // `Str has [Pretty { pretty: prettyStr }]`.
+                            let (_, has_abilities, state) =
+                                specialize(EExpr::Type, type_annotation::has_abilities(min_indent))
+                                    .parse(arena, state)?;
+
+                            let region = Region::span_across(&name_region, &has_abilities.region);
+                            let type_def = TypeDef::BuiltinImpls {
+                                header: TypeHeader {
+                                    name: Loc::at(name_region, name),
+                                    vars: args,
+                                },
+                                has_abilities,
+                            };
+
+                            defs.push_type_def(type_def, region, spaces_before_current, &[]);
+
+                            global_state = state;
+                            continue;
+                        }
+
+                        let state = has_state;
                         let (_, (type_def, def_region), state) = finish_parsing_ability_def_help(
                             start_column,
                             Loc::at(name_region, name),
@@ -1180,10 +1236,7 @@ fn finish_parsing_alias_or_opaque<'a>(
 
 mod ability {
     use super::*;
-    use crate::{
-        ast::{AbilityMember, Spaceable, Spaced},
-        parser::EAbility,
-    };
+    use crate::ast::{AbilityMemberDefault, Spaceable, Spaced};
 
     /// Parses a single ability demand line; see `parse_demand`.
     fn parse_demand_help<'a>(
@@ -1209,6 +1262,7 @@ mod ability {
                 AbilityMember {
                     name: name.map_owned(Spaced::Item),
                     typ,
+                    default: None,
                 }
             }
         )
@@ -1295,6 +1349,75 @@ mod ability {
             }
         }
     }
+
+    /// Parses the default implementation of the ability member named `member_name`, like the
+    /// second line of
+    ///
+    ///   neq : a, a -> Bool | a has Eq
+    ///   neq = \a, b -> !(eq a b)
+    ///
+    /// The default must be aligned with the demands of the ability, at `indent_column`.
+    pub fn parse_default<'a>(
+        indent_column: u32,
+        member_name: &'a str,
+    ) -> impl Parser<'a, AbilityMemberDefault<'a>, EExpr<'a>> {
+        move |arena, state: State<'a>| {
+            let initial = state.clone();
+            let not_a_default =
+                |initial: State<'a>| Err((NoProgress, EExpr::Start(initial.pos()), initial));
+
+            let (spaces, state) = match space0_e(0, EExpr::IndentStart).parse(arena, state) {
+                Ok((_, spaces, state)) if state.column() == indent_column => (spaces, state),
+                _ => return not_a_default(initial),
+            };
+
+            // Anything but `member_name =` here is the next demand, or the end of the ability.
+            let (name, state) = match loc!(lowercase_ident()).parse(arena, state) {
+                Ok((_, name, state)) if name.value == member_name => (name, state),
+                _ => return not_a_default(initial),
+            };
+            let state = match space0_e(indent_column, EExpr::IndentEnd).parse(arena, state) {
+                Ok((_, _, state)) => match operator().parse(arena, state) {
+                    Ok((_, BinOp::Assignment, state)) => state,
+                    _ => return not_a_default(initial),
+                },
+                Err(_) => return not_a_default(initial),
+            };
+
+            let parse_body = space0_before_e(
+                move |a, s| parse_loc_expr(indent_column + 1, a, s),
+                indent_column,
+                EExpr::IndentEnd,
+            );
+            let (_, body, state) = parse_body
+                .parse(arena, state)
+                .map_err(|(_, fail, state)| (MadeProgress, fail, state))?;
+
+            let name = if spaces.is_empty() {
+                name.map_owned(Spaced::Item)
+            } else {
+                arena
+                    .alloc(Spaced::Item(name.value))
+                    .with_spaces_before(spaces, name.region)
+            };
+
+            let default = AbilityMemberDefault {
+                name,
+                body: arena.alloc(body),
+            };
+
+            Ok((MadeProgress, default, state))
+        }
+    }
+}
+
+/// Whether the `has` keyword just parsed is followed by a list of abilities, as in
+/// `Str has [Pretty]`, rather than by the members of an ability definition.
+fn starts_with_has_clause<'a>(min_indent: u32, arena: &'a Bump, state: State<'a>) -> bool {
+    match space0_e(min_indent, EExpr::IndentEnd).parse(arena, state) {
+        Ok((_, _, state)) => state.bytes().first() == Some(&b'['),
+        Err(_) => false,
+    }
 }
 
 fn finish_parsing_ability_def_help<'a>(
@@ -1309,14 +1432,33 @@ fn finish_parsing_ability_def_help<'a>(
 
     let min_indent_for_demand = start_column + 1;
 
+    // Parse the abilities this one requires, if any, as in `Ord has Eq`.
+    let (required_abilities, state) = match type_annotation::required_abilities(
+        min_indent_for_demand,
+    )
+    .parse(arena, state.clone())
+    {
+        Ok((_, required_abilities, state)) => (required_abilities, state),
+        Err((NoProgress, _, _)) => (&[][..], state),
+        Err((MadeProgress, fail, state)) => {
+            let pos = state.pos();
+            return Err((
+                MadeProgress,
+                EExpr::Ability(EAbility::Type(fail, pos), pos),
+                state,
+            ));
+        }
+    };
+
     // Parse the first demand. This will determine the indentation level all the
     // other demands must observe.
-    let (_, (demand_indent_level, first_demand), mut state) =
+    let (_, (demand_indent_level, mut first_demand), mut state) =
         ability::parse_demand(ability::IndentLevel::PendingMin(min_indent_for_demand))
             .parse(arena, state)
             .map_err(|(progress, err, state)| {
                 (progress, EExpr::Ability(err, state.pos()), state)
             })?;
+    state = parse_ability_member_default(demand_indent_level, &mut first_demand, arena, state)?;
     demands.push(first_demand);
 
     let demand_indent = ability::IndentLevel::Exact(demand_indent_level);
@@ -1324,8 +1466,13 @@ fn finish_parsing_ability_def_help<'a>(
 
     loop {
         match demand_parser.parse(arena, state.clone()) {
-            Ok((_, (_indent, demand), next_state)) => {
-                state = next_state;
+            Ok((_, (_indent, mut demand), next_state)) => {
+                state = parse_ability_member_default(
+                    demand_indent_level,
+                    &mut demand,
+                    arena,
+                    next_state,
+                )?;
                 demands.push(demand);
             }
             Err((MadeProgress, problem, old_state)) => {
@@ -1342,16 +1489,36 @@ fn finish_parsing_ability_def_help<'a>(
         }
     }
 
-    let def_region = Region::span_across(&name.region, &demands.last().unwrap().typ.region);
+    let def_region = Region::span_across(&name.region, &demands.last().unwrap().region());
     let type_def = TypeDef::Ability {
         header: TypeHeader { name, vars: args },
         loc_has,
+        required_abilities,
         members: demands.into_bump_slice(),
     };
 
     Ok((MadeProgress, (type_def, def_region), state))
 }
 
+/// Attaches the default implementation following an ability demand to it, if there is one.
+fn parse_ability_member_default<'a>(
+    demand_indent_level: u32,
+    demand: &mut AbilityMember<'a>,
+    arena: &'a Bump,
+    state: State<'a>,
+) -> Result<State<'a>, (Progress, EExpr<'a>, State<'a>)> {
+    let member_name = demand.name.value.extract_spaces().item;
+
+    match ability::parse_default(demand_indent_level, member_name).parse(arena, state) {
+        Ok((_, default, state)) => {
+            demand.default = Some(default);
+            Ok(state)
+        }
+        Err((NoProgress, _, state)) => Ok(state),
+        Err(fail) => Err(fail),
+    }
+}
+
 fn parse_expr_operator<'a>(
     min_indent: u32,
     options: ExprParseOptions,
@@ -1567,6 +1734,8 @@ fn parse_expr_end<'a>(
         move |a, s| parse_loc_term_or_underscore(min_indent, options, a, s)
     );
 
+    let before_term = state.clone();
+
     match parser.parse(arena, state.clone()) {
         Err((MadeProgress, f, s)) => Err((MadeProgress, f, s)),
         Ok((
@@ -1606,6 +1775,28 @@ fn parse_expr_end<'a>(
                 }
             }
 
+            if starts_with_has_clause(min_indent, arena, state.clone()) {
+                // This implements abilities for a builtin type, as in
+                // `Str has [Pretty { pretty: prettyStr }]`.
+                let (_, has_abilities, state) =
+                    specialize(EExpr::Type, type_annotation::has_abilities(min_indent))
+                        .parse(arena, before_term)?;
+
+                let region = Region::span_across(&name.region, &has_abilities.region);
+                let type_def = TypeDef::BuiltinImpls {
+                    header: TypeHeader {
+                        name,
+                        vars: arguments.into_bump_slice(),
+                    },
+                    has_abilities,
+                };
+
+                let mut defs = Defs::default();
+                defs.push_type_def(type_def, region, &[], &[]);
+
+                return parse_defs_expr(options, start_column, defs, arena, state);
+            }
+
             // Attach any spaces to the `has` keyword
             let has = if !expr_state.spaces_after.is_empty() {
                 arena
@@ -1673,38 +1864,38 @@ fn parse_expr_end<'a>(
                         state,
                     )
                 }
-                Err((NoProgress, _, mut state)) => {
+                Err((NoProgress, _, state)) => {
                     // try multi-backpassing
                     if options.accept_multi_backpassing && state.bytes().starts_with(b",") {
-                        state = state.advance(1);
-
-                        let (_, mut patterns, state) = specialize_ref(
-                            EExpr::Pattern,
-                            crate::parser::sep_by0(
-                                word1(b',', EPattern::Start),
-                                space0_around_ee(
-                                    crate::pattern::loc_pattern_help(min_indent),
-                                    min_indent,
-                                    EPattern::Start,
-                                    EPattern::IndentEnd,
+                        let patterns_and_arrow = and!(
+                            specialize_ref(
+                                EExpr::Pattern,
+                                crate::parser::sep_by0(
+                                    word1(b',', EPattern::Start),
+                                    space0_around_ee(
+                                        crate::pattern::loc_pattern_help(min_indent),
+                                        min_indent,
+                                        EPattern::Start,
+                                        EPattern::IndentEnd,
+                                    ),
                                 ),
                             ),
+                            word2(b'<', b'-', EExpr::BackpassArrow)
                         )
-                        .parse(arena, state)?;
+                        .parse(arena, state.advance(1));
 
-                        expr_state.consume_spaces(arena);
-                        let call = to_call(arena, expr_state.arguments, expr_state.expr);
+                        match patterns_and_arrow {
+                            Ok((_, (mut patterns, _), state)) => {
+                                expr_state.consume_spaces(arena);
+                                let call = to_call(arena, expr_state.arguments, expr_state.expr);
 
-                        let loc_pattern = Loc::at(
-                            call.region,
-                            expr_to_pattern_help(arena, &call.value).unwrap(),
-                        );
+                                let loc_pattern = Loc::at(
+                                    call.region,
+                                    expr_to_pattern_help(arena, &call.value).unwrap(),
+                                );
 
-                        patterns.insert(0, loc_pattern);
+                                patterns.insert(0, loc_pattern);
 
-                        match word2(b'<', b'-', EExpr::BackpassArrow).parse(arena, state) {
-                            Err((_, fail, state)) => Err((MadeProgress, fail, state)),
-                            Ok((_, _, state)) => {
                                 let min_indent = start_column;
 
                                 let parse_body = space0_before_e(
@@ -1731,6 +1922,13 @@ fn parse_expr_end<'a>(
 
                                 Ok((MadeProgress, ret, state))
                             }
+                            Err(_) => {
+                                // Not a backpassing after all; the comma separates this
+                                // expression from the next element of e.g. a tuple.
+                                let state = expr_state.initial.clone();
+
+                                parse_expr_final(expr_state, arena, state)
+                            }
                         }
                     } else if options.check_for_arrow && state.bytes().starts_with(b"->") {
                         Err((MadeProgress, EExpr::BadOperator("->", state.pos()), state))
@@ -1842,6 +2040,16 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
             Ok(Pattern::RecordDestructure(patterns))
         }
 
+        Expr::Tuple(elements) => {
+            let patterns = elements.map_items_result(arena, |loc_elem| {
+                let region = loc_elem.region;
+                let value = expr_to_pattern_help(arena, &loc_elem.value)?;
+                Ok(Loc { region, value })
+            })?;
+
+            Ok(Pattern::Tuple(patterns))
+        }
+
         &Expr::Float(string) => Ok(Pattern::FloatLiteral(string)),
         &Expr::Num(string) => Ok(Pattern::NumLiteral(string)),
         Expr::NonBase10Int {
@@ -1864,6 +2072,8 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
         | Expr::If(_, _)
         | Expr::When(_, _)
         | Expr::Expect(_, _)
+        | Expr::Dbg(_, _)
+        | Expr::Crash
         | Expr::MalformedClosure
         | Expr::PrecedenceConflict { .. }
         | Expr::RecordUpdate { .. }
@@ -2361,6 +2571,42 @@ fn expect_help<'a>(
     }
 }
 
+fn dbg_help<'a>(
+    min_indent: u32,
+    options: ExprParseOptions,
+) -> impl Parser<'a, Expr<'a>, EExpect<'a>> {
+    move |arena: &'a Bump, state: State<'a>| {
+        let start_column = state.column();
+
+        let (_, _, state) = parser::keyword_e(keyword::DBG, EExpect::Dbg).parse(arena, state)?;
+
+        let (_, condition, state) = space0_before_e(
+            specialize_ref(EExpect::Condition, move |arena, state| {
+                parse_loc_expr_with_options(start_column + 1, options, arena, state)
+            }),
+            start_column + 1,
+            EExpect::IndentCondition,
+        )
+        .parse(arena, state)
+        .map_err(|(_, f, s)| (MadeProgress, f, s))?;
+
+        let parse_cont = specialize_ref(
+            EExpect::Continuation,
+            space0_before_e(
+                move |a, s| parse_loc_expr(min_indent, a, s),
+                min_indent,
+                EExpr::IndentEnd,
+            ),
+        );
+
+        let (_, loc_cont, state) = parse_cont.parse(arena, state)?;
+
+        let expr = Expr::Dbg(arena.alloc(condition), arena.alloc(loc_cont));
+
+        Ok((MadeProgress, expr, state))
+    }
+}
+
 fn if_expr_help<'a>(
     min_indent: u32,
     options: ExprParseOptions,
@@ -97,6 +97,17 @@ pub fn lowercase_ident<'a>() -> impl Parser<'a, &'a str, ()> {
     }
 }
 
+/// A field of a record, or the index of a tuple element, e.g. "email" in `.email` or "0" in `.0`
+pub fn field_name<'a>() -> impl Parser<'a, &'a str, ()> {
+    move |arena, state: State<'a>| match chomp_tuple_index_part(state.bytes()) {
+        Ok(index) => {
+            let width = index.len();
+            Ok((MadeProgress, index, state.advance(width)))
+        }
+        Err(_) => lowercase_ident().parse(arena, state),
+    }
+}
+
 pub fn tag_name<'a>() -> impl Parser<'a, &'a str, ()> {
     move |arena, state: State<'a>| uppercase_ident().parse(arena, state)
 }
@@ -229,6 +240,26 @@ fn chomp_lowercase_part(buffer: &[u8]) -> Result<&str, Progress> {
     chomp_part(|c: char| c.is_lowercase(), buffer)
 }
 
+/// The index of a tuple element, e.g. the `1` in `pair.1`. This is either `0`, or
+/// a number without leading zeros; digits directly followed by letters are not an index.
+fn chomp_tuple_index_part(buffer: &[u8]) -> Result<&str, Progress> {
+    let chomped = buffer.iter().take_while(|b| b.is_ascii_digit()).count();
+
+    if chomped == 0 || (chomped > 1 && buffer[0] == b'0') {
+        return Err(NoProgress);
+    }
+
+    match buffer.get(chomped) {
+        Some(b) if b.is_ascii_alphabetic() || *b == b'_' => Err(NoProgress),
+        _ => Ok(unsafe { std::str::from_utf8_unchecked(&buffer[..chomped]) }),
+    }
+}
+
+/// A record field or tuple element name, as it appears after the `.` of an access
+fn chomp_field_part(buffer: &[u8]) -> Result<&str, Progress> {
+    chomp_tuple_index_part(buffer).or_else(|_| chomp_lowercase_part(buffer))
+}
+
 fn chomp_uppercase_part(buffer: &[u8]) -> Result<&str, Progress> {
     chomp_part(|c: char| c.is_uppercase(), buffer)
 }
@@ -273,7 +304,7 @@ fn chomp_accessor(buffer: &[u8], pos: Position) -> Result<&str, BadIdent> {
     // assumes the leading `.` has been chomped already
     use encode_unicode::CharExt;
 
-    match chomp_lowercase_part(buffer) {
+    match chomp_field_part(buffer) {
         Ok(name) => {
             let chomped = name.len();
 
@@ -386,6 +417,13 @@ fn chomp_identifier_chain<'a>(
         }
 
         match chomp_access_chain(&buffer[chomped..], &mut parts) {
+            Ok(_) if first_is_uppercase && parts[0].starts_with(|c: char| c.is_ascii_digit()) => {
+                // e.g. `Foo.0`; a tuple index can't follow a module name
+                Err((
+                    chomped as u32 + 1,
+                    BadIdent::WeirdDotQualified(pos.bump_column(chomped as u32 + 1)),
+                ))
+            }
             Ok(width) => {
                 chomped += width as usize;
 
@@ -504,7 +542,7 @@ fn chomp_access_chain<'a>(buffer: &'a [u8], parts: &mut Vec<'a, &'a str>) -> Res
 
     while let Some(b'.') = buffer.get(chomped) {
         match &buffer.get(chomped + 1..) {
-            Some(slice) => match chomp_lowercase_part(slice) {
+            Some(slice) => match chomp_field_part(slice) {
                 Ok(name) => {
                     let value = unsafe {
                         std::str::from_utf8_unchecked(
@@ -107,6 +107,7 @@ impl_space_problem! {
     EWhen<'a>,
     EAbility<'a>,
     PInParens<'a>,
+    PList<'a>,
     PRecord<'a>
 }
 
@@ -125,6 +126,7 @@ pub enum EHeader<'a> {
     ModuleName(Position),
     AppName(EString<'a>, Position),
     PlatformName(EPackageName<'a>, Position),
+    PackageName(EPackageName<'a>, Position),
     IndentStart(Position),
 }
 
@@ -351,6 +353,8 @@ pub enum EExpr<'a> {
     If(EIf<'a>, Position),
 
     Expect(EExpect<'a>, Position),
+    Dbg(EExpect<'a>, Position),
+    Crash(Position),
 
     Lambda(ELambda<'a>, Position),
     Underscore(Position),
@@ -415,6 +419,8 @@ pub enum ERecord<'a> {
 pub enum EInParens<'a> {
     End(Position),
     Open(Position),
+    /// `()`, which is neither a parenthesized expression nor a tuple
+    Empty(Position),
     ///
     Expr(&'a EExpr<'a>, Position),
 
@@ -508,6 +514,7 @@ pub enum EIf<'a> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EExpect<'a> {
     Space(BadInputError, Position),
+    Dbg(Position),
     Expect(Position),
     Condition(&'a EExpr<'a>, Position),
     Continuation(&'a EExpr<'a>, Position),
@@ -524,11 +531,15 @@ pub enum EPattern<'a> {
     Space(BadInputError, Position),
 
     PInParens(PInParens<'a>, Position),
+    List(PList<'a>, Position),
     NumLiteral(ENumber, Position),
 
     IndentStart(Position),
     IndentEnd(Position),
     AsIndentStart(Position),
+
+    AsKeyword(Position),
+    AsIdentifier(Position),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -555,6 +566,21 @@ pub enum PRecord<'a> {
 pub enum PInParens<'a> {
     End(Position),
     Open(Position),
+    /// `()`, which is neither a parenthesized pattern nor a tuple
+    Empty(Position),
+    Pattern(&'a EPattern<'a>, Position),
+
+    Space(BadInputError, Position),
+    IndentOpen(Position),
+    IndentEnd(Position),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PList<'a> {
+    End(Position),
+    Open(Position),
+    /// `.. as` that is not followed by a name
+    Rest(Position),
     Pattern(&'a EPattern<'a>, Position),
 
     Space(BadInputError, Position),
@@ -622,6 +648,8 @@ pub enum ETypeTagUnion<'a> {
 pub enum ETypeInParens<'a> {
     End(Position),
     Open(Position),
+    /// `()`, which is neither a parenthesized type nor a tuple
+    Empty(Position),
     ///
     Type(&'a EType<'a>, Position),
 
@@ -1313,6 +1341,93 @@ macro_rules! collection_trailing_sep_e {
     };
 }
 
+/// The elements in parentheses: one for e.g. `(x)`, and more for a tuple like `(x, y)`.
+///
+/// The first element is parsed with the spaces around it just like a lone parenthesized element,
+/// so that e.g. bad indentation is reported the same way whether or not a `,` follows it.
+#[macro_export]
+macro_rules! parens_trailing_sep_e {
+    ($elem:expr, $min_indent:expr, $open_problem:expr, $sep_problem:expr, $close_problem:expr, $indent_open_problem:expr, $indent_end_problem:expr, $empty_problem:expr, $space_before:expr) => {
+        move |arena: &'a bumpalo::Bump, state: $crate::state::State<'a>| {
+            let start = state.pos();
+
+            let (_, _, state) = $crate::parser::word1(b'(', $open_problem).parse(arena, state)?;
+
+            // `()` is neither a parenthesized element nor a tuple
+            let empty = and!(
+                $crate::blankspace::space0_e($min_indent, $indent_open_problem),
+                $crate::parser::word1(b')', $close_problem)
+            )
+            .parse(arena, state.clone());
+
+            if empty.is_ok() {
+                return Err((MadeProgress, $empty_problem(start), state));
+            }
+
+            let (_, first, state) = $crate::blankspace::space0_around_ee(
+                $elem,
+                $min_indent,
+                $indent_open_problem,
+                $indent_end_problem,
+            )
+            .parse(arena, state)
+            .map_err(|(_, fail, state)| (MadeProgress, fail, state))?;
+
+            let (items, final_comments, state) = if state.bytes().starts_with(b",") {
+                let comma = state.clone();
+
+                let rest = collection_trailing_sep_e!(
+                    $crate::parser::word1(b',', $sep_problem),
+                    $elem,
+                    $crate::parser::word1(b',', $sep_problem),
+                    $crate::parser::word1(b')', $close_problem),
+                    $min_indent,
+                    $close_problem,
+                    $indent_end_problem,
+                    $space_before
+                )
+                .parse(arena, state);
+
+                let (_, rest, state) = match rest {
+                    Ok(parsed) => parsed,
+                    Err(fail) => {
+                        // with no element after the `,`, the `)` is what's missing
+                        let next_elem = skip_first!(
+                            $crate::parser::word1(b',', $sep_problem),
+                            $crate::blankspace::space0_e($min_indent, $indent_end_problem)
+                        )
+                        .parse(arena, comma.clone())
+                        .map(|(_, _, state)| $elem.parse(arena, state));
+
+                        return match next_elem {
+                            Ok(Ok(_)) | Ok(Err((MadeProgress, _, _))) => Err(fail),
+                            _ => Err((MadeProgress, $close_problem(comma.pos()), comma)),
+                        };
+                    }
+                };
+
+                let mut items = bumpalo::collections::Vec::with_capacity_in(rest.len() + 1, arena);
+                items.push(first);
+                items.extend(rest.items.iter().copied());
+
+                (items.into_bump_slice(), rest.final_comments(), state)
+            } else {
+                let (_, _, state) = $crate::parser::word1(b')', $close_problem)
+                    .parse(arena, state)
+                    .map_err(|(_, fail, state)| (MadeProgress, fail, state))?;
+
+                (arena.alloc([first]) as &[_], &[] as &[_], state)
+            };
+
+            let elements =
+                $crate::ast::Collection::with_items_and_comments(arena, items, final_comments);
+            let region = roc_region::all::Region::new(start, state.pos());
+
+            Ok((MadeProgress, roc_region::all::Loc::at(region, elements), state))
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! succeed {
     ($value:expr) => {
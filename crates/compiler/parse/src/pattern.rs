@@ -1,10 +1,11 @@
-use crate::ast::{Has, Pattern};
-use crate::blankspace::{space0_around_ee, space0_before_e, space0_e};
+use crate::ast::{Has, Pattern, PatternAs, Spaceable};
+use crate::blankspace::{space0_before_e, space0_e};
 use crate::ident::{lowercase_ident, parse_ident, Ident};
+use crate::keyword;
 use crate::parser::Progress::{self, *};
 use crate::parser::{
-    backtrackable, optional, specialize, specialize_ref, then, word1, EPattern, PInParens, PRecord,
-    ParseResult, Parser,
+    backtrackable, optional, specialize, specialize_ref, then, word1, word2, EPattern, PInParens,
+    PList, PRecord, ParseResult, Parser,
 };
 use crate::state::State;
 use bumpalo::collections::string::String;
@@ -48,9 +49,53 @@ fn parse_closure_param<'a>(
         specialize(EPattern::PInParens, loc_pattern_in_parens_help(min_indent))
     )
     .parse(arena, state)
+    .and_then(|(_, pattern, state)| loc_pattern_as_suffix(arena, state, min_indent, pattern))
 }
 
 pub fn loc_pattern_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
+    move |arena, state: State<'a>| {
+        let (_, pattern, state) = loc_pattern_help_help(min_indent).parse(arena, state)?;
+
+        loc_pattern_as_suffix(arena, state, min_indent, pattern)
+    }
+}
+
+/// An `as` may follow a pattern, e.g. `Ok x as result`
+fn loc_pattern_as_suffix<'a>(
+    arena: &'a Bump,
+    state: State<'a>,
+    min_indent: u32,
+    pattern: Loc<Pattern<'a>>,
+) -> ParseResult<'a, Loc<Pattern<'a>>, EPattern<'a>> {
+    let pattern_state = state.clone();
+
+    let (pattern_spaces, state) =
+        match space0_e(min_indent, EPattern::AsKeyword).parse(arena, state) {
+            Err(_) => return Ok((MadeProgress, pattern, pattern_state)),
+            Ok((_, pattern_spaces, state)) => (pattern_spaces, state),
+        };
+
+    match pattern_as(min_indent).parse(arena, state) {
+        Err((NoProgress, _, _)) => Ok((MadeProgress, pattern, pattern_state)),
+        Err(err) => Err(err),
+        Ok((_, pattern_as, state)) => {
+            let region = Region::span_across(&pattern.region, &pattern_as.identifier.region);
+
+            let pattern = if pattern_spaces.is_empty() {
+                pattern
+            } else {
+                arena
+                    .alloc(pattern.value)
+                    .with_spaces_after(pattern_spaces, pattern.region)
+            };
+            let as_pattern = Pattern::As(arena.alloc(pattern), pattern_as);
+
+            Ok((MadeProgress, Loc::at(region, as_pattern), state))
+        }
+    }
+}
+
+fn loc_pattern_help_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Pattern<'a>>, EPattern<'a>> {
     one_of!(
         specialize(EPattern::PInParens, loc_pattern_in_parens_help(min_indent)),
         loc!(underscore_pattern_help()),
@@ -59,12 +104,38 @@ pub fn loc_pattern_help<'a>(min_indent: u32) -> impl Parser<'a, Loc<Pattern<'a>>
             EPattern::Record,
             crate::pattern::record_pattern_help(min_indent)
         )),
+        loc!(specialize(EPattern::List, list_pattern_help(min_indent))),
         loc!(number_pattern_help()),
         loc!(string_pattern_help()),
         loc!(single_quote_pattern_help()),
     )
 }
 
+fn pattern_as<'a>(min_indent: u32) -> impl Parser<'a, PatternAs<'a>, EPattern<'a>> {
+    move |arena, state: State<'a>| {
+        let (_, _, state) =
+            crate::parser::keyword_e(keyword::AS, EPattern::AsKeyword).parse(arena, state)?;
+
+        let (_, spaces, state) = space0_e(min_indent, EPattern::AsIdentifier)
+            .parse(arena, state)
+            .map_err(|(_, e, s)| (MadeProgress, e, s))?;
+
+        let position = state.pos();
+
+        match loc!(lowercase_ident()).parse(arena, state) {
+            Ok((_, identifier, state)) => Ok((
+                MadeProgress,
+                PatternAs {
+                    spaces_before: spaces,
+                    identifier,
+                },
+                state,
+            )),
+            Err((_, (), state)) => Err((MadeProgress, EPattern::AsIdentifier(position), state)),
+        }
+    }
+}
+
 fn loc_tag_pattern_args_help<'a>(
     min_indent: u32,
 ) -> impl Parser<'a, Vec<'a, Loc<Pattern<'a>>>, EPattern<'a>> {
@@ -139,6 +210,7 @@ fn loc_parse_tag_pattern_arg<'a>(
             EPattern::Record,
             crate::pattern::record_pattern_help(min_indent)
         )),
+        loc!(specialize(EPattern::List, list_pattern_help(min_indent))),
         loc!(string_pattern_help()),
         loc!(single_quote_pattern_help()),
         loc!(number_pattern_help())
@@ -149,19 +221,102 @@ fn loc_parse_tag_pattern_arg<'a>(
 fn loc_pattern_in_parens_help<'a>(
     min_indent: u32,
 ) -> impl Parser<'a, Loc<Pattern<'a>>, PInParens<'a>> {
-    between!(
-        word1(b'(', PInParens::Open),
-        space0_around_ee(
-            move |arena, state| specialize_ref(PInParens::Pattern, loc_pattern_help(min_indent))
-                .parse(arena, state),
+    move |arena, state| {
+        let (_, loc_elements, state) = parens_trailing_sep_e!(
+            specialize_ref(PInParens::Pattern, loc_pattern_help(min_indent)),
             min_indent,
+            PInParens::Open,
+            PInParens::End,
+            PInParens::End,
             PInParens::IndentOpen,
             PInParens::IndentEnd,
-        ),
-        word1(b')', PInParens::End)
+            PInParens::Empty,
+            Pattern::SpaceBefore
+        )
+        .parse(arena, state)?;
+
+        let elements = loc_elements.value;
+
+        if elements.len() > 1 {
+            // e.g. `(x, y)` destructures a tuple
+            Ok((
+                MadeProgress,
+                Loc::at(loc_elements.region, Pattern::Tuple(elements)),
+                state,
+            ))
+        } else {
+            // e.g. `(x)` or `(x,)` is just `x` in parentheses
+            let loc_pattern = elements.items[0];
+            let loc_pattern = if elements.final_comments().is_empty() {
+                loc_pattern
+            } else {
+                arena
+                    .alloc(loc_pattern.value)
+                    .with_spaces_after(elements.final_comments(), loc_pattern.region)
+            };
+
+            Ok((MadeProgress, loc_pattern, state))
+        }
+    }
+}
+
+fn list_pattern_help<'a>(min_indent: u32) -> impl Parser<'a, Pattern<'a>, PList<'a>> {
+    move |arena, state| {
+        let (_, elements, state) = collection_trailing_sep_e!(
+            word1(b'[', PList::Open),
+            list_element_pattern(min_indent),
+            word1(b',', PList::End),
+            word1(b']', PList::End),
+            min_indent,
+            PList::Open,
+            PList::IndentEnd,
+            Pattern::SpaceBefore
+        )
+        .parse(arena, state)?;
+
+        Ok((MadeProgress, Pattern::List(elements), state))
+    }
+}
+
+fn list_element_pattern<'a>(min_indent: u32) -> impl Parser<'a, Loc<Pattern<'a>>, PList<'a>> {
+    one_of!(
+        list_rest_pattern(min_indent),
+        specialize_ref(PList::Pattern, loc_pattern_help(min_indent)),
     )
 }
 
+/// The `..` or `.. as rest` in a list pattern
+fn list_rest_pattern<'a>(min_indent: u32) -> impl Parser<'a, Loc<Pattern<'a>>, PList<'a>> {
+    move |arena: &'a Bump, state: State<'a>| {
+        let start = state.pos();
+        let (_, (), state) = word2(b'.', b'.', PList::Open).parse(arena, state)?;
+
+        let no_name = Loc::at(Region::new(start, state.pos()), Pattern::ListRest(None));
+
+        let (_, opt_spaces, state) = optional(skip_second!(
+            backtrackable(space0_e(min_indent, PList::IndentEnd)),
+            crate::parser::keyword_e(keyword::AS, PList::End)
+        ))
+        .parse(arena, state)?;
+
+        match opt_spaces {
+            None => Ok((MadeProgress, no_name, state)),
+            Some(spaces) => {
+                let (_, loc_name, state) = skip_first!(
+                    space0_e(min_indent, PList::IndentEnd),
+                    loc!(specialize(|_, pos| PList::Rest(pos), lowercase_ident()))
+                )
+                .parse(arena, state)?;
+
+                let region = Region::new(start, loc_name.region.end());
+                let value = Pattern::ListRest(Some((spaces, loc_name)));
+
+                Ok((MadeProgress, Loc::at(region, value), state))
+            }
+        }
+    }
+}
+
 fn number_pattern_help<'a>() -> impl Parser<'a, Pattern<'a>, EPattern<'a>> {
     specialize(
         EPattern::NumLiteral,
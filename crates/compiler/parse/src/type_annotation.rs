@@ -223,20 +223,45 @@ fn loc_applied_arg<'a>(
 fn loc_type_in_parens<'a>(
     min_indent: u32,
 ) -> impl Parser<'a, Loc<TypeAnnotation<'a>>, ETypeInParens<'a>> {
-    between!(
-        word1(b'(', ETypeInParens::Open),
-        space0_around_ee(
-            move |arena, state| specialize_ref(
-                ETypeInParens::Type,
-                expression(min_indent, true, false)
-            )
-            .parse(arena, state),
+    use crate::ast::Spaceable;
+
+    move |arena, state| {
+        // Each element is parsed with a trailing comma allowed, so `(a, b)` is a tuple of two
+        // types, whereas `(a, b -> c)` is a function in parentheses.
+        let (_, loc_elements, state) = parens_trailing_sep_e!(
+            specialize_ref(ETypeInParens::Type, expression(min_indent, true, false)),
             min_indent,
+            ETypeInParens::Open,
+            ETypeInParens::End,
+            ETypeInParens::IndentEnd,
             ETypeInParens::IndentOpen,
             ETypeInParens::IndentEnd,
-        ),
-        word1(b')', ETypeInParens::IndentEnd)
-    )
+            ETypeInParens::Empty,
+            TypeAnnotation::SpaceBefore
+        )
+        .parse(arena, state)?;
+
+        let elements = loc_elements.value;
+
+        if elements.len() > 1 {
+            Ok((
+                MadeProgress,
+                Loc::at(loc_elements.region, TypeAnnotation::Tuple(elements)),
+                state,
+            ))
+        } else {
+            let loc_type = elements.items[0];
+            let loc_type = if elements.final_comments().is_empty() {
+                loc_type
+            } else {
+                arena
+                    .alloc(loc_type.value)
+                    .with_spaces_after(elements.final_comments(), loc_type.region)
+            };
+
+            Ok((MadeProgress, loc_type, state))
+        }
+    }
 }
 
 #[inline(always)]
@@ -505,6 +530,52 @@ pub fn has_abilities<'a>(min_indent: u32) -> impl Parser<'a, Loc<HasAbilities<'a
     )
 }
 
+/// Parses the abilities an ability definition requires of the types implementing it, like the
+/// `Eq, Hash` in `Ord has Eq, Hash`. The first required ability must be on the same line as the
+/// `has`.
+pub fn required_abilities<'a>(
+    min_indent: u32,
+) -> impl Parser<'a, &'a [Loc<TypeAnnotation<'a>>], EType<'a>> {
+    move |arena, state: State<'a>| {
+        // Demands of the ability begin with a lowercase name, on a new line, so we are only
+        // looking at required abilities if an uppercase name follows on this line.
+        let leading_spaces = state.bytes().iter().take_while(|&&b| b == b' ').count();
+        match state.bytes().get(leading_spaces) {
+            Some(b) if b.is_ascii_uppercase() => {}
+            _ => return Err((NoProgress, EType::TStart(state.pos()), state)),
+        }
+        let state = state.advance(leading_spaces);
+
+        let (_, first, state) =
+            loc!(specialize(EType::TApply, parse_concrete_type)).parse(arena, state)?;
+
+        let (_, mut required, state) = zero_or_more!(skip_first!(
+            comma_on_same_line(),
+            space0_before_e(
+                loc!(specialize(EType::TApply, parse_concrete_type)),
+                min_indent,
+                EType::TIndentStart,
+            )
+        ))
+        .parse(arena, state)?;
+
+        required.insert(0, first);
+
+        Ok((MadeProgress, required.into_bump_slice(), state))
+    }
+}
+
+/// A `,` separating required abilities, possibly preceded by spaces on the same line.
+fn comma_on_same_line<'a>() -> impl Parser<'a, (), EType<'a>> {
+    |_arena, state: State<'a>| {
+        let spaces = state.bytes().iter().take_while(|&&b| b == b' ').count();
+        match state.bytes().get(spaces) {
+            Some(b',') => Ok((MadeProgress, (), state.advance(spaces + 1))),
+            _ => Err((NoProgress, EType::TEnd(state.pos()), state)),
+        }
+    }
+}
+
 fn parse_has_ability<'a>(min_indent: u32) -> impl Parser<'a, HasAbility<'a>, EType<'a>> {
     map!(
         and!(
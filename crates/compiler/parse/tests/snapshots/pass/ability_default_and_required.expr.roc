@@ -0,0 +1,6 @@
+Ord has Eq
+  compare : a, a -> [LT, EQ, GT] | a has Ord
+  lt : a, a -> Bool | a has Ord
+  lt = \x, y -> compare x y == LT
+
+1
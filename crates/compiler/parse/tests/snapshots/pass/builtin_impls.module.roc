@@ -0,0 +1,2 @@
+Str has [Pretty { pretty: prettyStr }]
+List a has [Pretty { pretty: prettyList }, Eq]
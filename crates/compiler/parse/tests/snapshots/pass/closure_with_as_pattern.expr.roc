@@ -0,0 +1 @@
+\Pair x _ as pair, { y } as rec -> pair
@@ -0,0 +1,3 @@
+dbg 1 == 1
+
+4
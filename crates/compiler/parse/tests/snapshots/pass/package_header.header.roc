@@ -0,0 +1,3 @@
+package "rtfeldman/json"
+    exposes [Json, Json.Decode]
+    packages {}
@@ -0,0 +1,3 @@
+pair = (1, 2)
+
+pair.0 + (pair).1 + .1 pair
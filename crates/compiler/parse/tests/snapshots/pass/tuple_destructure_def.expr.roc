@@ -0,0 +1,3 @@
+(x, (y, _)) = (1, (2, 3))
+
+x + y
@@ -0,0 +1 @@
+(1, "two", [3])
@@ -0,0 +1,4 @@
+f : (Str, (U8 -> Str)) -> Str
+f = \(s, g) -> g s
+
+f
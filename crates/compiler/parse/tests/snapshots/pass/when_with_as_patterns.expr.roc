@@ -0,0 +1,3 @@
+when result is
+    Ok (Pair a b as pair) -> pair
+    Err _ as err -> err
@@ -0,0 +1,5 @@
+when list is
+    [] -> 0
+    [x] -> x
+    [first, .., last] -> first + last
+    [_, .. as rest] -> List.len rest
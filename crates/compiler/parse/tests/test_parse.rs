@@ -122,6 +122,7 @@ mod test_parse {
     snapshot_tests! {
         fail/type_argument_no_arrow.expr,
         fail/type_double_comma.expr,
+        pass/ability_default_and_required.expr,
         pass/ability_demand_signature_is_multiline.expr,
         pass/ability_multi_line.expr,
         pass/ability_single_line.expr,
@@ -141,12 +142,16 @@ mod test_parse {
         pass/basic_field.expr,
         pass/basic_tag.expr,
         pass/basic_var.expr,
+        pass/closure_with_as_pattern.expr,
         pass/closure_with_underscores.expr,
+        pass/builtin_impls.module,
         pass/comment_after_def.module,
         pass/comment_after_op.expr,
         pass/comment_before_op.expr,
         pass/comment_inside_empty_list.expr,
         pass/comment_with_non_ascii.expr,
+        pass/crash.expr,
+        pass/dbg.expr,
         pass/destructure_tag_assignment.expr,
         pass/empty_app_header.header,
         pass/empty_hosted_header.header,
@@ -222,6 +227,7 @@ mod test_parse {
         pass/outdented_app_with_record.expr,
         pass/outdented_list.expr,
         pass/outdented_record.expr,
+        pass/package_header.header,
         pass/packed_singleton_list.expr,
         pass/parenthetical_apply.expr,
         pass/parenthetical_basic_field.expr,
@@ -258,6 +264,11 @@ mod test_parse {
         pass/tag_pattern.expr,
         pass/ten_times_eleven.expr,
         pass/three_arg_closure.expr,
+        pass/tuple_access.expr,
+        pass/tuple_destructure_def.expr,
+        pass/tuple_literal.expr,
+        pass/tuple_type.expr,
+        pass/tuple_type_with_function.expr,
         pass/two_arg_closure.expr,
         pass/two_backpassing.expr,
         pass/two_branch_when.expr,
@@ -283,7 +294,9 @@ mod test_parse {
         pass/when_in_parens_indented.expr,
         pass/when_in_parens.expr,
         pass/when_with_alternative_patterns.expr,
+        pass/when_with_as_patterns.expr,
         pass/when_with_function_application.expr,
+        pass/when_with_list_patterns.expr,
         pass/when_with_negative_numbers.expr,
         pass/when_with_numbers.expr,
         pass/when_with_records.expr,
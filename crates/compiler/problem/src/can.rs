@@ -32,12 +32,16 @@ pub enum ShadowKind {
 pub enum Problem {
     UnusedDef(Symbol, Region),
     UnusedImport(ModuleId, Region),
+    /// A name from an import's exposed list, like `bar` in `imports [Foo.{ bar }]`, that is
+    /// never used
+    UnusedExposedImport(Symbol, Region),
     ExposedButNotDefined(Symbol),
     UnknownGeneratesWith(Loc<Ident>),
     /// First symbol is the name of the closure with that argument
     /// Bool is whether the closure is anonymous
     /// Second symbol is the name of the argument that is unused
-    UnusedArgument(Symbol, bool, Symbol, Region),
+    /// The last field is how the pattern binds the argument
+    UnusedArgument(Symbol, bool, Symbol, Region, ArgumentBinding),
     UnusedBranchDef(Symbol, Region),
     PrecedenceProblem(PrecedenceProblem),
     // Example: (5 = 1 + 2) is an unsupported pattern in an assignment; Int patterns aren't allowed in assignments!
@@ -167,16 +171,136 @@ pub enum Problem {
         ability: Symbol,
         not_implemented: Vec<Symbol>,
     },
+    /// A type implements an ability, but not the abilities that ability requires.
+    MissingRequiredAbilities {
+        region: Region,
+        ability: Symbol,
+        missing: Vec<Symbol>,
+    },
+    /// Abilities are implemented for a type that is neither a builtin accepting implementations
+    /// nor a type alias of the module, as in `Age has [Pretty { pretty: prettyAge }]` for an
+    /// opaque `Age := U8`.
+    IllegalBuiltinImplementation {
+        typ: Symbol,
+        region: Region,
+    },
+    /// A builtin type implements an ability outside of the module defining the ability.
+    ForeignBuiltinImplementation {
+        typ: Symbol,
+        ability: Symbol,
+        region: Region,
+    },
+    /// A builtin type implements the same ability more than once.
+    DuplicateBuiltinImplementation {
+        typ: Symbol,
+        ability: Symbol,
+        original: Region,
+        duplicate: Region,
+    },
+    BuiltinImplementationNotOnToplevel {
+        region: Region,
+    },
     NotBoundInAllPatterns {
         unbound_symbol: Symbol,
         region: Region,
     },
     NoIdentifiersIntroduced(Region),
+    /// A list pattern with more than one `..`, e.g. `[.., x, ..]`
+    MultipleListRestPattern {
+        region: Region,
+    },
     OverloadedSpecialization {
         overload: Region,
         original_opaque: Symbol,
         ability_member: Symbol,
     },
+    /// `crash` used as a value, without a message
+    UnappliedCrash {
+        region: Region,
+    },
+    /// `crash` applied to more than one argument; the region covers the extra arguments
+    OverAppliedCrash {
+        region: Region,
+    },
+}
+
+impl Problem {
+    /// Edits to the source code that fix this problem without changing what the code does,
+    /// as applied by `roc fix`. Most problems have none.
+    pub fn suggested_edits(&self) -> Vec<SuggestedEdit> {
+        match self {
+            Problem::UnusedImport(_, region) | Problem::UnusedExposedImport(_, region) => {
+                vec![SuggestedEdit::RemoveListEntry(*region)]
+            }
+            Problem::UnusedArgument(_, _, _, region, binding) => match binding {
+                ArgumentBinding::Identifier => vec![SuggestedEdit::PrefixWithUnderscore(*region)],
+                ArgumentBinding::RecordField => vec![SuggestedEdit::IgnoreRecordField(*region)],
+                // `{ x ? 0 }` has no form that keeps the default but doesn't bind `x`
+                ArgumentBinding::OptionalRecordField => vec![],
+            },
+            Problem::UnusedDef(_, region) => vec![SuggestedEdit::RemoveDef(*region)],
+            Problem::RuntimeError(RuntimeError::Shadowing {
+                original_region,
+                shadow,
+                kind: ShadowKind::Variable,
+            }) => vec![SuggestedEdit::RenameShadow {
+                original_region: *original_region,
+                shadow: shadow.region,
+            }],
+            // Renaming a type or ability means following it into annotations and
+            // implementations, so a shadowing one is left for the programmer to rename,
+            // like all the other problems are left for them to fix.
+            _ => vec![],
+        }
+    }
+}
+
+/// How a pattern binds the name of an argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArgumentBinding {
+    /// `\x -> ...`, including identifiers nested in tags, like `\Pair x y -> ...`
+    Identifier,
+    /// `\{ x } -> ...`
+    RecordField,
+    /// `\{ x ? 0 } -> ...`
+    OptionalRecordField,
+}
+
+/// A change to the source code that fixes a [Problem].
+///
+/// Edits refer to the code by region, so they only apply to the source code the problem was
+/// found in. Applying one can change what follows it in the file, so a set of edits has to be
+/// applied starting from the last one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuggestedEdit {
+    /// Remove an entry from a comma-separated list, like `imports [...]`,
+    /// along with the comma that separates it from the other entries.
+    RemoveListEntry(Region),
+    /// Put an underscore in front of the name in this region, marking it as unused.
+    PrefixWithUnderscore(Region),
+    /// Turn a destructured record field like `{ x }` into `{ x: _x }`, so that the pattern
+    /// still matches on the field but doesn't bind it.
+    IgnoreRecordField(Region),
+    /// Remove the definition of the name in this region, along with its type annotation.
+    RemoveDef(Region),
+    /// Give the name in the `shadow` region, which shadows the one defined in
+    /// `original_region`, a name of its own, along with the lookups of it that follow it.
+    RenameShadow {
+        original_region: Region,
+        shadow: Region,
+    },
+}
+
+impl SuggestedEdit {
+    pub fn region(&self) -> Region {
+        match self {
+            SuggestedEdit::RemoveListEntry(region)
+            | SuggestedEdit::PrefixWithUnderscore(region)
+            | SuggestedEdit::IgnoreRecordField(region)
+            | SuggestedEdit::RemoveDef(region)
+            | SuggestedEdit::RenameShadow { shadow: region, .. } => *region,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -1,4 +1,4 @@
-use roc_can::abilities::AbilitiesStore;
+use roc_can::abilities::{builtin_number_type, is_implementable_builtin, AbilitiesStore};
 use roc_can::expr::PendingDerives;
 use roc_collections::{VecMap, VecSet};
 use roc_error_macros::{internal_error, todo_abilities};
@@ -257,6 +257,13 @@ impl ObligationCache {
         // Not worth caching ad-hoc checks because variables are unlikely to be the same between
         // independent queries.
 
+        if adhoc_implementation_types(subs, var)
+            .into_iter()
+            .any(|typ| abilities_store.has_declared_implementation(typ, ability))
+        {
+            return Ok(());
+        }
+
         let opt_can_derive_builtin = match ability {
             Symbol::ENCODE_ENCODING => Some(DeriveEncoding::is_derivable(
                 self,
@@ -893,6 +900,49 @@ impl DerivableVisitor for DeriveDecoding {
     }
 }
 
+/// The types `var` is an instance of that may have ad-hoc implementations of abilities, that is
+/// implementations declared in the module defining the ability rather than alongside the type.
+/// These are the structural type aliases `var` goes through (e.g. `Point` for a
+/// `Point : { x : I64, y : I64 }`), outermost first, followed by the builtin type underneath them
+/// if abilities defined outside of the builtins may be implemented for it (e.g. `Str` or `U64`).
+///
+/// An implementation for the first of these types that has one is the one that applies to `var`.
+pub fn adhoc_implementation_types(subs: &Subs, mut var: Variable) -> Vec<Symbol> {
+    let mut types = Vec::new();
+
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Content::Alias(symbol, _, real_var, kind) => {
+                if let Some(number) = builtin_number_type(*symbol) {
+                    types.push(number);
+
+                    return types;
+                }
+
+                match kind {
+                    AliasKind::Structural => types.push(*symbol),
+                    // Look through the opaque types that number types are made of, e.g. the
+                    // `Num` and `Integer` in `U64 : Num (Integer Unsigned64)`
+                    AliasKind::Opaque
+                        if matches!(
+                            *symbol,
+                            Symbol::NUM_NUM | Symbol::NUM_INTEGER | Symbol::NUM_FLOATINGPOINT
+                        ) => {}
+                    AliasKind::Opaque => return types,
+                }
+
+                var = *real_var;
+            }
+            Content::Structure(FlatType::Apply(symbol, _)) if is_implementable_builtin(*symbol) => {
+                types.push(*symbol);
+
+                return types;
+            }
+            _ => return types,
+        }
+    }
+}
+
 /// Determines what type implements an ability member of a specialized signature, given the
 /// [MustImplementAbility] constraints of the signature.
 pub fn type_implementing_specialization(
@@ -1016,8 +1066,28 @@ pub fn resolve_ability_specialization<R: AbilityResolver>(
             }
         }
         Obligated::Adhoc(variable) => {
-            // TODO: more rules need to be validated here, like is this a builtin ability?
-            Resolved::NeedsGenerated(variable)
+            let opt_adhoc_impl = adhoc_implementation_types(subs, variable)
+                .into_iter()
+                .find_map(|typ| {
+                    resolver.get_implementation(roc_can::abilities::ImplKey {
+                        opaque: typ,
+                        ability_member,
+                    })
+                });
+
+            match opt_adhoc_impl {
+                Some(roc_types::types::MemberImpl::Impl(spec_symbol)) => {
+                    Resolved::Specialization(spec_symbol)
+                }
+                Some(roc_types::types::MemberImpl::Error) => {
+                    Resolved::Specialization(Symbol::UNDERSCORE)
+                }
+                Some(roc_types::types::MemberImpl::Derived) => {
+                    internal_error!("builtin types never derive user abilities")
+                }
+                // TODO: more rules need to be validated here, like is this a builtin ability?
+                None => Resolved::NeedsGenerated(variable),
+            }
         }
     };
 
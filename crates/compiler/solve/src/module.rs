@@ -2,7 +2,9 @@ use crate::solve::{self, Aliases};
 use roc_can::abilities::{AbilitiesStore, ResolvedImpl};
 use roc_can::constraint::{Constraint as ConstraintSoa, Constraints};
 use roc_can::expr::PendingDerives;
-use roc_can::module::{ExposedByModule, ResolvedImplementations, RigidVariables};
+use roc_can::module::{
+    ExposedByModule, ResolvedDefaultImplementations, ResolvedImplementations, RigidVariables,
+};
 use roc_collections::all::MutMap;
 use roc_collections::VecMap;
 use roc_derive::SharedDerivedModule;
@@ -50,6 +52,7 @@ pub struct SolvedModule {
 
     /// Used when importing this module into another module
     pub solved_implementations: ResolvedImplementations,
+    pub solved_default_implementations: ResolvedDefaultImplementations,
     pub exposed_types: ExposedTypesStorageSubs,
 }
 
@@ -78,6 +81,10 @@ pub fn run_solve(
         subs.rigid_var(var, "*".into());
     }
 
+    for (ability, required) in abilities_store.iter_required_abilities() {
+        subs.required_abilities.insert(ability, required.to_vec());
+    }
+
     // Now that the module is parsed, canonicalized, and constrained,
     // we need to type check it.
     let mut problems = Vec::new();
@@ -105,6 +112,7 @@ pub fn exposed_types_storage_subs(
     solved_subs: &mut Solved<Subs>,
     exposed_vars_by_symbol: &[(Symbol, Variable)],
     solved_implementations: &ResolvedImplementations,
+    solved_default_implementations: &ResolvedDefaultImplementations,
     abilities_store: &AbilitiesStore,
 ) -> ExposedTypesStorageSubs {
     let subs = solved_subs.inner_mut();
@@ -119,7 +127,11 @@ pub fn exposed_types_storage_subs(
     let mut stored_specialization_lambda_set_vars =
         VecMap::with_capacity(solved_implementations.len());
 
-    for (_, member_impl) in solved_implementations.iter() {
+    let member_impls = solved_implementations
+        .values()
+        .chain(solved_default_implementations.values());
+
+    for member_impl in member_impls {
         match member_impl {
             ResolvedImpl::Impl(member_specialization) => {
                 // Export all the lambda sets and their ambient functions.
@@ -210,3 +222,24 @@ pub fn extract_module_owned_implementations(
         })
         .collect()
 }
+
+/// Extracts the default implementations of ability members owned by a solved module.
+pub fn extract_module_owned_default_implementations(
+    module_id: ModuleId,
+    abilities_store: &AbilitiesStore,
+) -> ResolvedDefaultImplementations {
+    abilities_store
+        .iter_abilities()
+        .flat_map(|(_, members)| members.iter())
+        .filter_map(|member| abilities_store.default_implementation(*member))
+        .filter(|default| default.module_id() == module_id)
+        .map(|default| {
+            let resolved_impl = match abilities_store.specialization_info(default) {
+                Some(specialization) => ResolvedImpl::Impl(specialization.clone()),
+                None => ResolvedImpl::Error,
+            };
+
+            (default, resolved_impl)
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 use crate::ability::{
-    resolve_ability_specialization, type_implementing_specialization, AbilityImplError,
-    CheckedDerives, ObligationCache, PendingDerivesTable, Resolved,
+    adhoc_implementation_types, resolve_ability_specialization, type_implementing_specialization,
+    AbilityImplError, CheckedDerives, ObligationCache, PendingDerivesTable, Resolved,
 };
 use crate::module::Solved;
 use crate::specialize::{
@@ -31,8 +31,8 @@ use roc_types::subs::{
 };
 use roc_types::types::Type::{self, *};
 use roc_types::types::{
-    gather_fields_unsorted_iter, AliasCommon, AliasKind, Category, OptAbleType, OptAbleVar, Reason,
-    RecordField, TypeExtension, Uls,
+    gather_fields_unsorted_iter, AliasCommon, AliasKind, Category, MemberImpl, OptAbleType,
+    OptAbleVar, Reason, RecordField, TypeExtension, Uls,
 };
 use roc_unify::unify::{
     unify, unify_introduced_ability_specialization, Env as UEnv, Mode, Obligated,
@@ -1675,7 +1675,25 @@ fn check_ability_specialization(
                 extra_metadata: SpecializationLsetCollector(specialization_lambda_sets),
             } => {
                 let specialization_type =
-                    type_implementing_specialization(&must_implement_ability, parent_ability);
+                    type_implementing_specialization(&must_implement_ability, parent_ability).map(
+                        |obligated| match obligated {
+                            // Builtin types and type aliases can have custom implementations
+                            // too, which we treat like those of opaque types.
+                            Obligated::Adhoc(var) => {
+                                let types = adhoc_implementation_types(subs, var);
+
+                                if types.contains(&impl_key.opaque) {
+                                    Obligated::Opaque(impl_key.opaque)
+                                } else {
+                                    match types.last() {
+                                        Some(typ) => Obligated::Opaque(*typ),
+                                        None => obligated,
+                                    }
+                                }
+                            }
+                            Obligated::Opaque(_) => obligated,
+                        },
+                    );
 
                 match specialization_type {
                     Some(Obligated::Opaque(opaque)) => {
@@ -1837,6 +1855,73 @@ fn check_ability_specialization(
             "still have lambda sets waiting for {:?}, but it was just resolved",
             impl_key
         );
+    } else if let Some(ability_member) = abilities_store.member_of_default_implementation(symbol) {
+        // This is the default implementation of an ability member. It is annotated with the
+        // member's signature, so its type has already been checked; all that is left is to find
+        // its specialization lambda sets, by unifying it with a fresh instance of the signature.
+        let root_signature_var = abilities_store
+            .member_def(ability_member)
+            .expect("default implementation is for an ability member")
+            .signature_var();
+        let root_signature_var =
+            deep_copy_var_in(subs, Rank::toplevel(), pools, root_signature_var, arena);
+
+        // Don't keep the unification; the default remains generic over the ability.
+        let snapshot = subs.snapshot();
+        let unified = unify_introduced_ability_specialization(
+            &mut UEnv::new(subs),
+            root_signature_var,
+            symbol_loc_var.value,
+            Mode::EQ,
+        );
+        subs.rollback_to(snapshot);
+
+        let resolved_mark = match unified {
+            Success {
+                extra_metadata: SpecializationLsetCollector(specialization_lambda_sets),
+                ..
+            } => {
+                let specialization_lambda_sets = specialization_lambda_sets
+                    .into_iter()
+                    .map(|((symbol, region), var)| {
+                        debug_assert_eq!(symbol, ability_member);
+                        (region, var)
+                    })
+                    .collect();
+
+                Ok(MemberSpecializationInfo::new(
+                    symbol,
+                    specialization_lambda_sets,
+                ))
+            }
+            // The mismatch with the annotation has already been reported.
+            Failure(..) | BadType(..) => Err(()),
+        };
+
+        abilities_store.mark_default_implementation(symbol, resolved_mark);
+
+        // Lambda sets waiting on the types using this default can now be specialized.
+        let impl_keys: Vec<_> = abilities_store
+            .iter_declared_implementations()
+            .filter(|(_, member_impl)| **member_impl == MemberImpl::Impl(symbol))
+            .map(|(impl_key, _)| impl_key)
+            .collect();
+
+        for impl_key in impl_keys {
+            let new_lambda_sets_to_specialize =
+                awaiting_specializations.remove_for_specialized(subs, impl_key);
+            compact_lambdas_and_check_obligations(
+                arena,
+                pools,
+                problems,
+                subs,
+                abilities_store,
+                obligation_cache,
+                awaiting_specializations,
+                derived_env,
+                new_lambda_sets_to_specialize,
+            );
+        }
     }
 }
 
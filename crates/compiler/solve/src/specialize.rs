@@ -24,6 +24,7 @@ use roc_types::{
 };
 use roc_unify::unify::{unify, Env as UEnv, Mode, MustImplementConstraints};
 
+use crate::ability::adhoc_implementation_types;
 use crate::solve::{deep_copy_var_in, introduce, Pools};
 
 /// What phase in the compiler is reaching out to specialize lambda sets?
@@ -623,38 +624,23 @@ fn make_specialization_decision<P: Phase>(
     use SpecializationTypeKey::*;
     match subs.get_content_without_compacting(var) {
         Alias(opaque, _, _, AliasKind::Opaque) if opaque.module_id() != ModuleId::NUM => {
-            if P::IS_LATE {
-                SpecializeDecision::Specialize(Opaque(*opaque))
-            } else {
-                // Solving within a module.
-                phase.with_module_abilities_store(opaque.module_id(), |abilities_store| {
-                    let impl_key = ImplKey {
-                        opaque: *opaque,
-                        ability_member,
-                    };
-                    match abilities_store.get_implementation(impl_key) {
-                        None => {
-                            // Doesn't specialize; an error will already be reported for this.
-                            SpecializeDecision::Drop
-                        }
-                        Some(MemberImpl::Error | MemberImpl::Derived) => {
-                            // TODO: probably not right, we may want to choose a derive decision!
-                            SpecializeDecision::Specialize(Opaque(*opaque))
-                        }
-                        Some(MemberImpl::Impl(specialization_symbol)) => {
-                            match abilities_store.specialization_info(*specialization_symbol) {
-                                Some(_) => SpecializeDecision::Specialize(Opaque(*opaque)),
-
-                                // If we expect a specialization impl but don't yet know it, we must hold off
-                                // compacting the lambda set until the specialization is well-known.
-                                None => SpecializeDecision::PendingSpecialization(impl_key),
-                            }
-                        }
-                    }
-                })
-            }
+            make_custom_specialization_decision(phase, *opaque, ability_member)
         }
         Structure(_) | Alias(_, _, _, _) => {
+            for typ in adhoc_implementation_types(subs, var) {
+                let impl_key = ImplKey {
+                    opaque: typ,
+                    ability_member,
+                };
+                let has_custom_impl = phase.with_module_abilities_store(impl_key.home(), |store| {
+                    store.get_implementation(impl_key).is_some()
+                });
+
+                if has_custom_impl {
+                    return make_custom_specialization_decision(phase, typ, ability_member);
+                }
+            }
+
             let builtin = match ability_member.try_into() {
                 Ok(builtin) => builtin,
                 Err(_) => return SpecializeDecision::Drop,
@@ -695,6 +681,47 @@ fn make_specialization_decision<P: Phase>(
     }
 }
 
+/// Decides how to specialize an ability member for a type with a custom implementation of the
+/// ability, that is an opaque type or a builtin type.
+fn make_custom_specialization_decision<P: Phase>(
+    phase: &P,
+    opaque: Symbol,
+    ability_member: Symbol,
+) -> SpecializeDecision {
+    use SpecializationTypeKey::*;
+
+    if P::IS_LATE {
+        return SpecializeDecision::Specialize(Opaque(opaque));
+    }
+
+    // Solving within a module.
+    let impl_key = ImplKey {
+        opaque,
+        ability_member,
+    };
+    phase.with_module_abilities_store(impl_key.home(), |abilities_store| {
+        match abilities_store.get_implementation(impl_key) {
+            None => {
+                // Doesn't specialize; an error will already be reported for this.
+                SpecializeDecision::Drop
+            }
+            Some(MemberImpl::Error | MemberImpl::Derived) => {
+                // TODO: probably not right, we may want to choose a derive decision!
+                SpecializeDecision::Specialize(Opaque(opaque))
+            }
+            Some(MemberImpl::Impl(specialization_symbol)) => {
+                match abilities_store.specialization_info(*specialization_symbol) {
+                    Some(_) => SpecializeDecision::Specialize(Opaque(opaque)),
+
+                    // If we expect a specialization impl but don't yet know it, we must hold off
+                    // compacting the lambda set until the specialization is well-known.
+                    None => SpecializeDecision::PendingSpecialization(impl_key),
+                }
+            }
+        }
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn get_specialization_lambda_set_ambient_function<P: Phase>(
     subs: &mut Subs,
@@ -707,13 +734,13 @@ fn get_specialization_lambda_set_ambient_function<P: Phase>(
 ) -> Result<Variable, ()> {
     match specialization_key {
         SpecializationTypeKey::Opaque(opaque) => {
-            let opaque_home = opaque.module_id();
-            let external_specialized_lset =
-                phase.with_module_abilities_store(opaque_home, |abilities_store| {
             let impl_key = roc_can::abilities::ImplKey {
                 opaque,
                 ability_member,
             };
+            let opaque_home = impl_key.home();
+            let external_specialized_lset =
+                phase.with_module_abilities_store(opaque_home, |abilities_store| {
                     let opt_specialization =
                         abilities_store.get_implementation(impl_key);
                     match opt_specialization {
@@ -1630,6 +1630,145 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn tuple_literal() {
+        infer_eq(r#"(5, "five", {})"#, "( Num *, Str, {} )");
+    }
+
+    #[test]
+    fn tuple_literal_accessor() {
+        infer_eq(r#"(5, "five").1"#, "Str");
+    }
+
+    #[test]
+    fn tuple_pattern_is_closed() {
+        infer_eq("\\(a, _) -> a", "( a, * ) -> a");
+    }
+
+    #[test]
+    fn tuple_destructure_def() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    (x, y) = (1u8, "one")
+
+                    { x, y }
+                "#
+            ),
+            "{ x : U8, y : Str }",
+        );
+    }
+
+    #[test]
+    fn tuple_type_annotation() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    swap : (a, b) -> (b, a)
+                    swap = \(a, b) -> (b, a)
+
+                    swap
+                "#
+            ),
+            "( a, b ) -> ( b, a )",
+        );
+    }
+
+    #[test]
+    fn tuple_with_ten_elements_prints_in_order() {
+        infer_eq(
+            "(0u8, 1u16, 2u32, 3u64, 4u128, 5i8, 6i16, 7i32, 8i64, 9i128, {})",
+            "( U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, {} )",
+        );
+    }
+
+    #[test]
+    fn list_pattern_elements() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    \list ->
+                        when list is
+                            [] -> 0u8
+                            [x] -> x
+                            [first, .., last] -> first + last
+                "#
+            ),
+            "List U8 -> U8",
+        );
+    }
+
+    #[test]
+    fn list_pattern_rest_binding() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    \list ->
+                        when list is
+                            [_, .. as rest] -> rest
+                            [] -> ["none"]
+                "#
+            ),
+            "List Str -> List Str",
+        );
+    }
+
+    #[test]
+    fn as_pattern_in_when() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    \result ->
+                        when result is
+                            Ok (Pair _ _ as pair) -> pair
+                            Err _ -> Pair "a" 1
+                "#
+            ),
+            "[Err *, Ok [Pair Str (Num a)]] -> [Pair Str (Num a)]",
+        );
+    }
+
+    #[test]
+    fn as_pattern_in_closure_arg() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    \{ x } as rec -> { rec & x: x + 1 }
+                "#
+            ),
+            "{ x : Num a }b -> { x : Num a }b",
+        );
+    }
+
+    #[test]
+    fn dbg_evaluates_to_continuation() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    \x ->
+                        dbg { x, y: "hello" }
+                        x + 1
+                "#
+            ),
+            "Num a -> Num a",
+        );
+    }
+
+    #[test]
+    fn crash_is_any_type() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    \x ->
+                        when x is
+                            Ok n -> n + 1
+                            Err _ -> crash "unreachable"
+                "#
+            ),
+            "[Err *, Ok (Num a)] -> Num a",
+        );
+    }
+
     #[test]
     fn record_field_pattern_match_with_guard() {
         infer_eq(
@@ -6095,6 +6234,167 @@ mod solve_expr {
         )
     }
 
+    #[test]
+    fn ability_default_implementation_called() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [different] to "./platform"
+
+                MEq has
+                    eq : a, a -> Bool | a has MEq
+                    neq : a, a -> Bool | a has MEq
+                    neq = \x, y -> Bool.not (eq x y)
+
+                Id := U64 has [MEq {eq}]
+
+                eq = \@Id m, @Id n -> m == n
+
+                different = neq (@Id 1) (@Id 2)
+                "#
+            ),
+            "Bool",
+        )
+    }
+
+    #[test]
+    fn ability_required_ability_member_usable() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [lte] to "./platform"
+
+                MEq has
+                    eq : a, a -> Bool | a has MEq
+
+                MOrd has MEq
+                    lt : a, a -> Bool | a has MOrd
+
+                lte : a, a -> Bool | a has MOrd
+                lte = \x, y -> lt x y || eq x y
+                "#
+            ),
+            "a, a -> Bool | a has MOrd",
+        )
+    }
+
+    #[test]
+    fn ability_required_ability_inferred() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [lte] to "./platform"
+
+                MEq has
+                    eq : a, a -> Bool | a has MEq
+
+                MOrd has MEq
+                    lt : a, a -> Bool | a has MOrd
+
+                lte = \x, y -> lt x y || eq x y
+                "#
+            ),
+            "a, a -> Bool | a has MOrd",
+        )
+    }
+
+    #[test]
+    fn builtin_type_ability_specializations() {
+        check_inferred_abilities(
+            indoc!(
+                r#"
+                app "test" provides [pretty] to "./platform"
+
+                Pretty has
+                    pretty : a -> Str | a has Pretty
+
+                Str has [Pretty { pretty: prettyStr }]
+                List a has [Pretty { pretty: prettyList }]
+
+                prettyStr = \s -> Str.concat "\"" s
+                prettyList = \l -> Num.toStr (List.len l)
+                "#
+            ),
+            [("Pretty:pretty", "Str"), ("Pretty:pretty", "List")],
+        )
+    }
+
+    #[test]
+    fn builtin_type_ability_specialization_called() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Pretty has
+                    pretty : a -> Str | a has Pretty
+
+                Str has [Pretty { pretty: prettyStr }]
+                List a has [Pretty { pretty: prettyList }]
+
+                prettyStr = \s -> Str.concat "\"" s
+                prettyList = \l -> Num.toStr (List.len l)
+
+                main = Str.concat (pretty "hi") (pretty [1, 2])
+                "#
+            ),
+            "Str",
+        )
+    }
+
+    #[test]
+    fn number_type_ability_specialization_called() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Pretty has
+                    pretty : a -> Str | a has Pretty
+
+                U64 has [Pretty { pretty: prettyU64 }]
+                F64 has [Pretty { pretty: prettyF64 }]
+
+                prettyU64 : U64 -> Str
+                prettyU64 = \n -> Str.concat (Num.toStr n) "u64"
+
+                prettyF64 : F64 -> Str
+                prettyF64 = \n -> Str.concat (Num.toStr n) "f64"
+
+                main = Str.concat (pretty (1u64 + 2)) (pretty 1.5f64)
+                "#
+            ),
+            "Str",
+        )
+    }
+
+    #[test]
+    fn structural_alias_ability_specialization_called() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Pretty has
+                    pretty : a -> Str | a has Pretty
+
+                Point : { x : I64, y : I64 }
+
+                Point has [Pretty { pretty: prettyPoint }]
+
+                prettyPoint : Point -> Str
+                prettyPoint = \{ x, y } -> Str.concat (Num.toStr x) (Num.toStr y)
+
+                origin : Point
+                origin = { x: 0, y: 0 }
+
+                main = pretty origin
+                "#
+            ),
+            "Str",
+        )
+    }
+
     #[test]
     fn alias_ability_member() {
         infer_eq_without_problem(
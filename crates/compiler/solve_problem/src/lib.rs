@@ -29,6 +29,12 @@ pub enum TypeError {
         expected_opaque: Symbol,
         found_opaque: Symbol,
     },
+    /// The solved type of a `_` in an annotation, printed the way it would be written in a
+    /// signature. Only reported on request, e.g. by `roc check --show-holes`.
+    InferredHole {
+        region: Region,
+        typ: String,
+    },
 }
 
 #[derive(PartialEq, Debug, Clone)]
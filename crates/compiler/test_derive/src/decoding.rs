@@ -28,6 +28,11 @@ test_key_eq! {
     explicit_empty_record_and_implicit_empty_record:
         v!(EMPTY_RECORD), v!({})
 
+    same_tuple:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16),))
+    same_tuple_elems_diff_types:
+        v!((v!(U8), v!(U16),)), v!((v!(STR), v!(U8),))
+
     list_list_diff_types:
         v!(Symbol::LIST_LIST v!(STR)), v!(Symbol::LIST_LIST v!(U8))
     str_str:
@@ -41,6 +46,8 @@ test_key_neq! {
         v!({ a: v!(U8), }), v!({ b: v!(U8), })
     record_empty_vs_nonempty:
         v!(EMPTY_RECORD), v!({ a: v!(U8), })
+    different_tuple_arities:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16), v!(U32),))
 }
 
 #[test]
@@ -93,6 +100,15 @@ fn derivable_record_with_record_ext() {
     );
 }
 
+#[test]
+fn derivable_tuple() {
+    check_derivable(
+        Decoder,
+        v!((v!(STR), v!(U8),)),
+        DeriveKey::Decoder(FlatDecodableKey::Tuple(2)),
+    );
+}
+
 #[test]
 fn list() {
     derive_test(Decoder, v!(Symbol::LIST_LIST v!(STR)), |golden| {
@@ -173,3 +189,65 @@ fn record_2_fields() {
         )
     })
 }
+
+#[test]
+fn tuple_2_fields() {
+    derive_test(Decoder, v!((v!(STR), v!(U8),)), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for ( Str, U8 )
+        # Decoder ( val, val1 ) fmt | fmt has DecoderFormatting, val has Decoding, val1 has Decoding
+        # List U8, fmt -[[custom(22)]]-> { rest : List U8, result : [Err [TooShort], Ok ( val, val1 )] } | fmt has DecoderFormatting, val has Decoding, val1 has Decoding
+        # Specialization lambda sets:
+        #   @<1>: [[custom(22)]]
+        #Derived.decoder_(arity:2) =
+          Decode.custom
+            \#Derived.bytes3, #Derived.fmt3 ->
+              Decode.decodeWith
+                #Derived.bytes3
+                (Decode.tuple
+                  { 0: Err NoField, 1: Err NoField }
+                  \#Derived.stateRecord2, #Derived.field ->
+                    when #Derived.field is
+                      0 ->
+                        Next (Decode.custom
+                          \#Derived.bytes, #Derived.fmt ->
+                            when Decode.decodeWith
+                                #Derived.bytes
+                                Decode.decoder
+                                #Derived.fmt is
+                              #Derived.rec ->
+                                {
+                                  result: when #Derived.rec.result is
+                                      Ok #Derived.val ->
+                                        Ok { stateRecord2 & 0: Ok #Derived.val }
+                                      Err #Derived.err -> Err #Derived.err,
+                                  rest: #Derived.rec.rest
+                                })
+                      1 ->
+                        Next (Decode.custom
+                          \#Derived.bytes2, #Derived.fmt2 ->
+                            when Decode.decodeWith
+                                #Derived.bytes2
+                                Decode.decoder
+                                #Derived.fmt2 is
+                              #Derived.rec2 ->
+                                {
+                                  result: when #Derived.rec2.result is
+                                      Ok #Derived.val2 ->
+                                        Ok { stateRecord2 & 1: Ok #Derived.val2 }
+                                      Err #Derived.err2 -> Err #Derived.err2,
+                                  rest: #Derived.rec2.rest
+                                })
+                      _ -> TooLong
+                  \#Derived.stateRecord ->
+                    when #Derived.stateRecord.0 is
+                      Ok #Derived.e0 ->
+                        when #Derived.stateRecord.1 is
+                          Ok #Derived.e1 -> Ok { 0: #Derived.e0, 1: #Derived.e1 }
+                          _ -> Err TooShort
+                      _ -> Err TooShort)
+                #Derived.fmt3
+        "###
+        )
+    })
+}
@@ -33,6 +33,11 @@ test_key_eq! {
         v!({ a: v!(U8), b: v!(U8), }),
         v!({ ?a: v!(U8), ?b: v!(U8), })
 
+    same_tuple:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16),))
+    same_tuple_elems_diff_types:
+        v!((v!(U8), v!(U16),)), v!((v!(STR), v!(U8),))
+
     same_tag_union:
         v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
     same_tag_union_tags_diff_types:
@@ -77,6 +82,8 @@ test_key_neq! {
         v!({ a: v!(U8), }), v!({ b: v!(U8), })
     record_empty_vs_nonempty:
         v!(EMPTY_RECORD), v!({ a: v!(U8), })
+    different_tuple_arities:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16), v!(U32),))
 
     different_tag_union_tags:
         v!([ A v!(U8) ]), v!([ B v!(U8) ])
@@ -145,6 +152,15 @@ fn derivable_record_with_record_ext() {
     );
 }
 
+#[test]
+fn derivable_tuple() {
+    check_derivable(
+        ToEncoder,
+        v!((v!(U8), v!(STR),)),
+        DeriveKey::ToEncoder(FlatEncodableKey::Tuple(2)),
+    );
+}
+
 #[test]
 fn derivable_tag_ext_flex_var() {
     check_derivable(
@@ -265,6 +281,33 @@ fn two_field_record() {
     })
 }
 
+#[test]
+fn two_elem_tuple() {
+    derive_test(ToEncoder, v!((v!(U8), v!(STR),)), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for ( U8, Str )
+        # ( val, val1 ) -[[toEncoder_(arity:2)(0)]]-> Encoder fmt | fmt has EncoderFormatting, val has Encoding, val1 has Encoding
+        # ( val, val1 ) -[[toEncoder_(arity:2)(0)]]-> (List U8, fmt -[[custom(2) ( val, val1 )]]-> List U8) | fmt has EncoderFormatting, val has Encoding, val1 has Encoding
+        # Specialization lambda sets:
+        #   @<1>: [[toEncoder_(arity:2)(0)]]
+        #   @<2>: [[custom(2) ( val, val1 )]] | val has Encoding, val1 has Encoding
+        #Derived.toEncoder_(arity:2) =
+          \#Derived.tup ->
+            Encode.custom
+              \#Derived.bytes, #Derived.fmt ->
+                Encode.appendWith
+                  #Derived.bytes
+                  (Encode.tuple
+                    [
+                      Encode.toEncoder #Derived.tup.0,
+                      Encode.toEncoder #Derived.tup.1,
+                    ])
+                  #Derived.fmt
+        "###
+        )
+    })
+}
+
 #[test]
 #[ignore = "NOTE: this would never actually happen, because [] is uninhabited, and hence toEncoder can never be called with a value of []!
 Rightfully it induces broken assertions in other parts of the compiler, so we ignore it."]
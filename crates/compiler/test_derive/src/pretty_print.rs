@@ -258,6 +258,8 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
         OpaqueRef { .. } => todo!(),
         Expect { .. } => todo!(),
         ExpectFx { .. } => todo!(),
+        Dbg { .. } => todo!(),
+        Crash { .. } => todo!(),
         TypedHole(_) => todo!(),
         RuntimeError(_) => todo!(),
     }
@@ -311,6 +313,17 @@ fn pattern<'a>(
             sym.module_string(c.interns),
             sym.as_str(c.interns),
         )),
+        As(subpattern, sym) => maybe_paren!(
+            Free,
+            prec,
+            pattern(c, Free, f, &subpattern.value)
+                .append(f.text(" as "))
+                .append(f.text(format!(
+                    "{}.{}",
+                    sym.value.module_string(c.interns),
+                    sym.value.as_str(c.interns),
+                )))
+        ),
         AppliedTag {
             tag_name,
             arguments,
@@ -362,6 +375,7 @@ fn pattern<'a>(
             )
             .append(f.text("}"))
             .group(),
+        List { .. } => todo!(),
         NumLiteral(_, n, _, _) | IntLiteral(_, _, n, _, _) | FloatLiteral(_, _, n, _, _) => {
             f.text(&**n)
         }
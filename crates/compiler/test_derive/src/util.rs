@@ -10,8 +10,8 @@ use roc_can::{
     def::Def,
     expr::Declarations,
     module::{
-        ExposedByModule, ExposedForModule, ExposedModuleTypes, ResolvedImplementations,
-        RigidVariables,
+        ExposedByModule, ExposedForModule, ExposedModuleTypes, ResolvedDefaultImplementations,
+        ResolvedImplementations, RigidVariables,
     },
 };
 use roc_collections::VecSet;
@@ -76,6 +76,21 @@ macro_rules! v {
              roc_derive::synth_var(subs, Content::Structure(FlatType::Record(fields, ext)))
          }
      }};
+     (( $($make_v:expr,)+ )) => {{
+         use roc_types::types::RecordField;
+         use roc_types::subs::{Subs, RecordFields, Content, FlatType, Variable};
+         |subs: &mut Subs| {
+             let elems = vec![ $( $make_v(subs), )+ ];
+             let fields = elems
+                 .into_iter()
+                 .enumerate()
+                 .map(|(index, elem)| (index.to_string().into(), RecordField::Required(elem)))
+                 .collect::<Vec<_>>();
+             let fields = RecordFields::insert_into_subs(subs, fields);
+
+             roc_derive::synth_var(subs, Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)))
+         }
+     }};
      ([ $($tag:ident $($payload:expr)*),* ] as $rec_var:ident) => {{
          use roc_types::subs::{Subs, SubsIndex, Variable, Content, FlatType, UnionTags};
          use roc_module::ident::TagName;
@@ -340,6 +355,7 @@ fn check_derived_typechecks_and_golden(
         ExposedModuleTypes {
             exposed_types_storage_subs: derive_builtin_env.exposed_types,
             resolved_implementations: ResolvedImplementations::default(),
+            resolved_default_implementations: ResolvedDefaultImplementations::default(),
         },
     );
     let exposed_for_module = ExposedForModule::new(
@@ -475,6 +491,7 @@ where
         ExposedModuleTypes {
             exposed_types_storage_subs: exposed_types_storage.clone(),
             resolved_implementations,
+            resolved_default_implementations: ResolvedDefaultImplementations::default(),
         },
     );
 
@@ -219,6 +219,127 @@ fn ability_used_as_type_still_compiles() {
     )
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ability_default_implementation_called() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [result] to "./platform"
+
+            Hash has
+                hash : a -> U64 | a has Hash
+                hashTwice : a -> U64 | a has Hash
+                hashTwice = \x -> 2 * hash x
+
+            Id := U64 has [Hash { hash: hashId }]
+            hashId = \@Id n -> n
+
+            Three := {} has [Hash { hash: hashThree, hashTwice: hashThreeTwice }]
+            hashThree = \@Three _ -> 3
+            hashThreeTwice = \@Three _ -> 1000
+
+            result = hashTwice (@Id 21) + hashTwice (@Three {})
+            "#
+        ),
+        1042,
+        u64
+    )
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ability_required_ability_members_called() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [result] to "./platform"
+
+            MEq has
+                eq : a, a -> Bool | a has MEq
+                neq : a, a -> Bool | a has MEq
+                neq = \x, y -> Bool.not (eq x y)
+
+            MOrd has MEq
+                lt : a, a -> Bool | a has MOrd
+
+            lte : a, a -> Bool | a has MOrd
+            lte = \x, y -> lt x y || eq x y
+
+            Id := U64 has [MEq { eq: idEq }, MOrd { lt: idLt }]
+            idEq = \@Id m, @Id n -> m == n
+            idLt = \@Id m, @Id n -> m < n
+
+            count = \b -> if b then 1 else 0
+
+            result =
+                count (lte (@Id 1) (@Id 2))
+                + 10 * count (lte (@Id 2) (@Id 2))
+                + 100 * count (lte (@Id 3) (@Id 2))
+                + 1000 * count (neq (@Id 3) (@Id 2))
+            "#
+        ),
+        1011,
+        u64
+    )
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn builtin_type_ability_specialization() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Pretty has
+                pretty : a -> Str | a has Pretty
+
+            Str has [Pretty { pretty: prettyStr }]
+            List a has [Pretty { pretty: prettyList }]
+
+            prettyStr = \s -> Str.concat "\"" s
+            prettyList = \l -> Num.toStr (List.len l)
+
+            main = Str.concat (pretty "hi") (pretty [1, 2])
+            "#
+        ),
+        RocStr::from("\"hi2"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn number_type_ability_specialization() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Hash has
+                hash : a -> U64 | a has Hash
+
+            U64 has [Hash { hash: hashU64 }]
+            U8 has [Hash { hash: hashU8 }]
+
+            hashU64 : U64 -> U64
+            hashU64 = \n -> n * 10
+
+            hashU8 : U8 -> U64
+            hashU8 = \n -> Num.toU64 n + 1000
+
+            mulHashes : a, a -> U64 | a has Hash
+            mulHashes = \x, y -> hash x * hash y
+
+            main = hash (1u64 + 2) + hash 5u8 + mulHashes 1u64 2u64
+            "#
+        ),
+        1235,
+        u64
+    )
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn encode() {
@@ -519,6 +640,29 @@ fn encode_derived_record_two_fields_strings() {
     )
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn encode_derived_tuple_two_fields() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test"
+                imports [Encode.{ toEncoder }, Json]
+                provides [main] to "./platform"
+
+            main =
+                tup = ("foo", 10u8)
+                result = Str.fromUtf8 (Encode.toBytes tup Json.toUtf8)
+                when result is
+                    Ok s -> s
+                    _ -> "<bad>"
+            "#
+        ),
+        RocStr::from(r#"["foo",10]"#),
+        RocStr
+    )
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn encode_derived_nested_record_string() {
@@ -970,6 +1114,28 @@ fn decode_record_two_fields() {
     )
 }
 
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_tuple_two_elements() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Encode, Decode, Json] provides [main] to "./platform"
+
+            main =
+                when Str.toUtf8 "[\"ab\",\"cd\"]" |> Decode.fromBytes Json.fromUtf8 is
+                    Ok ("ab", "cd") -> "abcd"
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("abcd"),
+        RocStr
+    )
+}
+
 #[test]
 #[cfg(all(
     any(feature = "gen-llvm", feature = "gen-wasm"),
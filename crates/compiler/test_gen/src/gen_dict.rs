@@ -10,7 +10,7 @@ use crate::helpers::llvm::assert_evals_to;
 use crate::helpers::wasm::assert_evals_to;
 
 use indoc::indoc;
-use roc_std::{RocList, RocStr};
+use roc_std::{RocDict, RocList, RocStr};
 
 #[test]
 #[cfg(any(feature = "gen-llvm"))]
@@ -549,3 +549,39 @@ fn walk_sum_keys() {
         i64
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn dict_to_host() {
+    // The values are more aligned than the keys, so each `Pair k v` has its value first
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Dict.empty
+                |> Dict.insert 1u8 100u64
+                |> Dict.insert 2u8 200u64
+                |> Dict.insert 1u8 300u64
+            "#
+        ),
+        RocDict::from_iter([(1, 300), (2, 200)].into_iter()),
+        RocDict<u8, u64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn dict_with_str_keys_to_host() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Dict.empty
+                |> Dict.insert "one" 1i64
+                |> Dict.insert "two" 2i64
+                |> Dict.remove "one"
+                |> Dict.insert "three" 3i64
+            "#
+        ),
+        RocDict::from_iter([(RocStr::from("two"), 2), (RocStr::from("three"), 3)].into_iter()),
+        RocDict<RocStr, i64>
+    );
+}
@@ -3394,3 +3394,180 @@ fn list_let_generalization() {
         usize
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_pattern_exact_lengths() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            describe : List I64 -> I64
+            describe = \list ->
+                when list is
+                    [] -> 0
+                    [x] -> x
+                    [_, _] -> 20
+                    _ -> 99
+
+            { a: describe [], b: describe [5], c: describe [1, 2], d: describe [1, 2, 3] }
+            "#
+        ),
+        [0, 5, 20, 99],
+        [i64; 4]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_pattern_at_least_after_exact() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            describe : List I64 -> I64
+            describe = \list ->
+                when list is
+                    [] -> 0
+                    [x] -> x
+                    [first, ..] -> first + 100
+
+            { a: describe [], b: describe [7], c: describe [3, 4], d: describe [3, 4, 5] }
+            "#
+        ),
+        [0, 7, 103, 103],
+        [i64; 4]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_pattern_at_least_before_exact() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            describe : List I64 -> I64
+            describe = \list ->
+                when list is
+                    [first, _, ..] -> first + 100
+                    [x] -> x
+                    [] -> 0
+
+            { a: describe [], b: describe [7], c: describe [3, 4], d: describe [3, 4, 5] }
+            "#
+        ),
+        [0, 7, 103, 103],
+        [i64; 4]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_pattern_exact_before_overlapping_at_least() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            describe : List I64 -> I64
+            describe = \list ->
+                when list is
+                    [_, _] -> 2
+                    [x, ..] -> x
+                    [] -> 0
+
+            { a: describe [], b: describe [7], c: describe [8, 9], d: describe [3, 4, 5] }
+            "#
+        ),
+        [0, 7, 2, 3],
+        [i64; 4]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_pattern_last() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            last : List I64 -> I64
+            last = \list ->
+                when list is
+                    [.., x] -> x
+                    [] -> 0
+
+            { a: last [], b: last [1], c: last [1, 2, 3] }
+            "#
+        ),
+        [0, 1, 3],
+        [i64; 3]
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_pattern_overlapping_element_tests() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            classify : List I64 -> I64
+            classify = \list ->
+                when list is
+                    [1, ..] -> 100
+                    [_, _] -> 2
+                    [.., 3] -> 30
+                    _ -> 0
+
+            {
+                a: classify [1, 2],
+                b: classify [5, 6],
+                c: classify [5, 6, 3],
+                d: classify [3],
+                e: classify [],
+                f: classify [5, 6, 7],
+            }
+            "#
+        ),
+        [100, 2, 30, 30, 0, 0],
+        [i64; 6]
+    );
+}
+
+#[test]
+// the dev backend does not implement List.sublist yet, which rest bindings use
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_pattern_rest() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            swapFirstTwo : List I64 -> List I64
+            swapFirstTwo = \list ->
+                when list is
+                    [a, b, .. as rest] -> List.concat [b, a] rest
+                    _ -> list
+
+            List.concat (swapFirstTwo [1, 2, 3, 4]) (swapFirstTwo [5, 6])
+            "#
+        ),
+        RocList::from_slice(&[2, 1, 3, 4, 6, 5]),
+        RocList<i64>
+    );
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dropEnds : List I64 -> List I64
+            dropEnds = \list ->
+                when list is
+                    [x] -> [x]
+                    [_, .. as middle, _] -> middle
+                    [] -> []
+
+            { a: dropEnds [1], b: dropEnds [1, 2], c: dropEnds [1, 2, 3, 4], d: dropEnds [] }
+            "#
+        ),
+        (
+            RocList::from_slice(&[1]),
+            RocList::from_slice(&[]),
+            RocList::from_slice(&[2, 3]),
+            RocList::from_slice(&[]),
+        ),
+        (RocList<i64>, RocList<i64>, RocList<i64>, RocList<i64>)
+    );
+}
@@ -1,11 +1,11 @@
 #[cfg(feature = "gen-llvm")]
-use crate::helpers::llvm::assert_evals_to;
+use crate::helpers::llvm::{assert_dbg_output, assert_evals_to};
 
 #[cfg(feature = "gen-dev")]
-use crate::helpers::dev::assert_evals_to;
+use crate::helpers::dev::{assert_crashes_with, assert_dbg_output, assert_evals_to};
 
 #[cfg(feature = "gen-wasm")]
-use crate::helpers::wasm::assert_evals_to;
+use crate::helpers::wasm::{assert_dbg_output, assert_evals_to};
 
 use indoc::indoc;
 #[allow(unused_imports)]
@@ -4065,3 +4065,154 @@ fn int_let_generalization() {
         RocStr
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[should_panic(expected = r#"User crash with message: "hello crash""#)]
+fn crash_literal() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            xs : List U64
+            xs = []
+
+            when List.first xs is
+                Ok x -> x
+                Err _ -> crash "hello crash"
+            "#
+        ),
+        0,
+        u64
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-dev")]
+fn crash_literal_dev() {
+    assert_crashes_with!(
+        "gen_primitives::crash_literal_dev",
+        indoc!(
+            r#"
+            xs : List U64
+            xs = []
+
+            when List.first xs is
+                Ok x -> x
+                Err _ -> crash "hello crash"
+            "#
+        ),
+        "User crash with message: hello crash"
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[should_panic(expected = r#"User crash with message: "n is too big: 41""#)]
+fn crash_computed_message() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            n : U64
+            n = 41
+
+            if n > 40 then
+                crash (Str.concat "n is too big: " (Num.toStr n))
+            else
+                n
+            "#
+        ),
+        0,
+        u64
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-dev")]
+fn crash_computed_message_dev() {
+    assert_crashes_with!(
+        "gen_primitives::crash_computed_message_dev",
+        indoc!(
+            r#"
+            n : U64
+            n = 41
+
+            if n > 40 then
+                crash (Str.concat "n is too big: " (Num.toStr n))
+            else
+                n
+            "#
+        ),
+        "User crash with message: n is too big: 41"
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn dbg_escapes_str() {
+    assert_dbg_output!(
+        "gen_primitives::dbg_escapes_str",
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                dbg "a \"quoted\" \\ path\n\tend"
+
+                1u8
+            "#
+        ),
+        u8,
+        "[Test.roc:4] \"a \\\"quoted\\\" \\\\ path\\n\\tend\"\n"
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn dbg_record_with_list_and_tag() {
+    assert_dbg_output!(
+        "gen_primitives::dbg_record_with_list_and_tag",
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                value = { name: "Roc", scores: [1u8, 2], status: Ok (Pair 1u64 2u64) }
+
+                dbg value
+
+                dbg value.scores
+
+                1u8
+            "#
+        ),
+        u8,
+        concat!(
+            "[Test.roc:6] { name: \"Roc\", scores: [1, 2], status: Ok (Pair 1 2) }\n",
+            "[Test.roc:8] [1, 2]\n",
+        )
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn dbg_opaque() {
+    assert_dbg_output!(
+        "gen_primitives::dbg_opaque",
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Age := U8
+
+            main =
+                age = @Age 21
+
+                dbg (Ok age)
+
+                1u8
+            "#
+        ),
+        u8,
+        "[Test.roc:8] Ok (@Age 21)\n"
+    );
+}
@@ -170,6 +170,39 @@ fn when_on_record() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn as_pattern_in_record_argument() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                addX = \{ x } as rec -> { rec & x: x + 1 }
+
+                result = addX { x: 41, y: 2 }
+
+                result.x + result.y
+                "#
+        ),
+        44,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn as_pattern_in_nested_record() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when { inner: { x: 1, y: 2 }, z: 3 } is
+                    { inner: { x } as inner, z } -> x + inner.y + z
+                "#
+        ),
+        6,
+        i64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn when_record_with_guard_pattern() {
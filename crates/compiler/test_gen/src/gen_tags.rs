@@ -479,6 +479,75 @@ fn nested_pattern_match() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn as_pattern_in_when_branch() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                x : Result [Pair I64 I64] {}
+                x = Ok (Pair 40 2)
+
+                when x is
+                    Ok (Pair a _ as pair) ->
+                        when pair is
+                            Pair _ b -> a + b
+
+                    Err _ -> 0
+                "#
+        ),
+        42,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn as_pattern_binds_whole_tag() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                Maybe a : [Nothing, Just a]
+
+                x : Maybe (Maybe I64)
+                x = Just (Just 41)
+
+                when x is
+                    Just (Just _ as inner) ->
+                        when inner is
+                            Just v -> v + 1
+                            Nothing -> 0
+
+                    _ -> 0
+                "#
+        ),
+        42,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn as_pattern_in_closure_argument() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                swap : [Pair I64 I64] -> [Pair I64 I64]
+                swap = \Pair a b as pair ->
+                    if a > b then
+                        pair
+                    else
+                        Pair b a
+
+                when swap (Pair 2 40) is
+                    Pair a b -> a - b
+                "#
+        ),
+        38,
+        i64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn if_guard_vanilla() {
@@ -0,0 +1,190 @@
+#[cfg(feature = "gen-llvm")]
+use crate::helpers::llvm::assert_evals_to;
+
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
+
+#[cfg(feature = "gen-wasm")]
+use crate::helpers::wasm::assert_evals_to;
+
+use indoc::indoc;
+
+#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+use roc_std::RocStr;
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn i64_tuple2_literal() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (3, 5)
+                "#
+        ),
+        (3, 5),
+        (i64, i64)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn i64_tuple3_literal() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (3, 5, 17)
+                "#
+        ),
+        (3, 5, 17),
+        (i64, i64, i64)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn mixed_tuple_literal() {
+    // elements are laid out by alignment, like the fields of a record
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (1u8, 2i64)
+                "#
+        ),
+        (2, 1),
+        (i64, u8)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn tuple_accessors() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (15, 17, 19).0
+                "#
+        ),
+        15,
+        i64
+    );
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (15, 17, 19).1
+                "#
+        ),
+        17,
+        i64
+    );
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                tup = (15, 17, 19)
+
+                tup.2
+                "#
+        ),
+        19,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn nested_tuple_accessor() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                tup = (1, (2, 3))
+
+                tup.1.0 + tup.1.1
+                "#
+        ),
+        5,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn tuple_destructure_in_let() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (x, y) = (15, 17)
+
+                x + y
+                "#
+        ),
+        32,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn tuple_pattern_in_when() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when (2, 3) is
+                    (1, y) -> y
+                    (x, 3) -> x * 10
+                    _ -> 0
+                "#
+        ),
+        20,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn tuple_pattern_in_lambda_arg() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                swap = \(a, b) -> (b, a)
+
+                (swap (3, 5)).0
+                "#
+        ),
+        5,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn tuple_in_record() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                rec = { point: (3, 4), scale: 2 }
+
+                when rec is
+                    { point: (x, y), scale } -> (x + y) * scale
+                "#
+        ),
+        14,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn tuple_of_strings() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                (first, second) = ("foo", "bar")
+
+                Str.concat second first
+                "#
+        ),
+        RocStr::from("barfoo"),
+        RocStr
+    );
+}
@@ -144,7 +144,10 @@ pub fn helper(
         for problem in can_problems.into_iter() {
             // Ignore "unused" problems
             match problem {
-                UnusedDef(_, _) | UnusedArgument(_, _, _, _) | UnusedImport(_, _) => {
+                UnusedDef(_, _)
+                | UnusedArgument(_, _, _, _, _)
+                | UnusedImport(_, _)
+                | UnusedExposedImport(_, _) => {
                     delayed_errors.push(problem);
                     continue;
                 }
@@ -256,5 +259,54 @@ macro_rules! assert_evals_to {
     };
 }
 
+/// The dev backend's test host exits the process in `roc_panic` (see platform_functions.rs), so
+/// unlike on the other backends, a crash cannot be caught with `#[should_panic]`. Instead the test
+/// runs itself again in a child process, and checks what that process printed before exiting.
+#[allow(unused_macros)]
+macro_rules! assert_crashes_with {
+    ($test_name:expr, $src:expr, $expected_stderr:expr) => {{
+        const CRASH_TEST_CHILD: &str = "ROC_DEV_CRASH_TEST_CHILD";
+
+        if std::env::var_os(CRASH_TEST_CHILD).is_some() {
+            assert_evals_to!($src, 0, u64);
+        } else {
+            let output = std::process::Command::new(std::env::current_exe().unwrap())
+                .args([$test_name, "--exact", "--nocapture", "--test-threads=1"])
+                .env(CRASH_TEST_CHILD, "1")
+                .output()
+                .unwrap();
+
+            let stderr = String::from_utf8_lossy(&output.stderr);
+
+            assert!(
+                !output.status.success(),
+                "Expected a crash, but the test finished:\n{}",
+                stderr
+            );
+            assert!(stderr.contains($expected_stderr), "{}", stderr);
+        }
+    }};
+}
+
+/// Checks the exact text that the `dbg`s in `$src` report through the default `roc_dbg`. That
+/// prints to stderr, so like with `assert_crashes_with`, the test runs itself again in a child
+/// process to see it.
+#[allow(unused_macros)]
+macro_rules! assert_dbg_output {
+    ($test_name:expr, $src:expr, $ty:ty, $expected:expr) => {{
+        if std::env::var_os($crate::helpers::TEST_CHILD_PROCESS).is_some() {
+            // just once, without lazy literals, so that every `dbg` reports once
+            $crate::helpers::dev::assert_evals_to!($src, (), $ty, |_| (), true, false);
+        } else {
+            let output = $crate::helpers::dbg_output_of_test_in_child_process($test_name);
+            assert_eq!(output, $expected);
+        }
+    }};
+}
+
+#[allow(unused_imports)]
+pub(crate) use assert_crashes_with;
+#[allow(unused_imports)]
+pub(crate) use assert_dbg_output;
 #[allow(unused_imports)]
 pub(crate) use assert_evals_to;
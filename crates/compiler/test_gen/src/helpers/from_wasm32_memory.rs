@@ -1,7 +1,7 @@
 use roc_error_macros::internal_error;
 use roc_gen_wasm::{round_up_to_alignment, wasm32_sized::Wasm32Sized};
 use roc_mono::layout::Builtin;
-use roc_std::{RocDec, RocList, RocOrder, RocResult, RocStr, I128, U128};
+use roc_std::{RocDec, RocDict, RocDictItem, RocList, RocOrder, RocResult, RocStr, I128, U128};
 use std::convert::TryInto;
 
 pub trait FromWasm32Memory: Wasm32Sized {
@@ -103,6 +103,35 @@ impl<T: FromWasm32Memory + Clone> FromWasm32Memory for RocList<T> {
     }
 }
 
+impl<K, V> FromWasm32Memory for RocDict<K, V>
+where
+    K: FromWasm32Memory + PartialEq + Clone,
+    V: FromWasm32Memory + Clone,
+{
+    fn decode(memory: &[u8], offset: u32) -> Self {
+        // A `Dict k v` is a `List [Pair k v]`
+        let items = <RocList<RocDictItem<K, V>> as FromWasm32Memory>::decode(memory, offset);
+
+        RocDict::from_iter(items.into_iter().map(RocDictItem::into_parts))
+    }
+}
+
+impl<K: FromWasm32Memory, V: FromWasm32Memory> FromWasm32Memory for RocDictItem<K, V> {
+    fn decode(memory: &[u8], offset: u32) -> Self {
+        // Like in `Pair k v`, the value only comes first if it's more aligned than the key
+        let (key_offset, value_offset) = if V::ALIGN_OF_WASM > K::ALIGN_OF_WASM {
+            (offset + V::ACTUAL_WIDTH as u32, offset)
+        } else {
+            (offset, offset + K::ACTUAL_WIDTH as u32)
+        };
+
+        RocDictItem::new(
+            <K as FromWasm32Memory>::decode(memory, key_offset),
+            <V as FromWasm32Memory>::decode(memory, value_offset),
+        )
+    }
+}
+
 impl<T, E> FromWasm32Memory for RocResult<T, E>
 where
     T: FromWasm32Memory + Wasm32Sized,
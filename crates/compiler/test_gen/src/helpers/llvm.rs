@@ -9,7 +9,7 @@ use roc_collections::all::MutSet;
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
 use roc_gen_llvm::{llvm::build::LlvmBackendMode, run_roc::RocCallResult};
 use roc_load::{EntryPoint, ExecutionMode, LoadConfig, Threading};
-use roc_mono::ir::OptLevel;
+use roc_mono::ir::{CrashTag, OptLevel};
 use roc_region::all::LineInfo;
 use roc_reporting::report::RenderTarget;
 use target_lexicon::Triple;
@@ -128,8 +128,9 @@ fn create_llvm_module<'a>(
             match problem {
                 // Ignore "unused" problems
                 UnusedDef(_, _)
-                | UnusedArgument(_, _, _, _)
+                | UnusedArgument(_, _, _, _, _)
                 | UnusedImport(_, _)
+                | UnusedExposedImport(_, _)
                 | RuntimeError(_)
                 | UnsupportedPattern(_, _)
                 | ExposedButNotDefined(_) => {
@@ -544,7 +545,10 @@ macro_rules! assert_wasm_evals_to {
 }
 
 #[allow(dead_code)]
-pub fn try_run_lib_function<T>(main_fn_name: &str, lib: &libloading::Library) -> Result<T, String> {
+pub fn try_run_lib_function<T>(
+    main_fn_name: &str,
+    lib: &libloading::Library,
+) -> Result<T, (String, CrashTag)> {
     unsafe {
         let main: libloading::Symbol<unsafe extern "C" fn(*mut RocCallResult<T>)> = lib
             .get(main_fn_name.as_bytes())
@@ -594,7 +598,12 @@ macro_rules! assert_llvm_evals_to {
                 #[cfg(windows)]
                 std::mem::forget(given);
             }
-            Err(msg) => panic!("Roc failed with message: \"{}\"", msg),
+            Err((msg, roc_mono::ir::CrashTag::Roc)) => {
+                panic!("Roc failed with message: \"{}\"", msg)
+            }
+            Err((msg, roc_mono::ir::CrashTag::User)) => {
+                panic!("User crash with message: \"{}\"", msg)
+            }
         }
 
         // artificially extend the lifetime of `lib`
@@ -678,11 +687,27 @@ macro_rules! expect_runtime_error_panic {
     }};
 }
 
+/// Checks the exact text that the `dbg`s in `$src` report through the default `roc_dbg`, which
+/// prints to stderr. To see that, the test runs itself again in a child process.
+#[allow(unused_macros)]
+macro_rules! assert_dbg_output {
+    ($test_name:expr, $src:expr, $ty:ty, $expected:expr) => {{
+        if std::env::var_os($crate::helpers::TEST_CHILD_PROCESS).is_some() {
+            $crate::helpers::llvm::assert_llvm_evals_to!($src, (), $ty, |_| (), false);
+        } else {
+            let output = $crate::helpers::dbg_output_of_test_in_child_process($test_name);
+            assert_eq!(output, $expected);
+        }
+    }};
+}
+
 #[allow(dead_code)]
 pub fn identity<T>(value: T) -> T {
     value
 }
 
+#[allow(unused_imports)]
+pub(crate) use assert_dbg_output;
 #[allow(unused_imports)]
 pub(crate) use assert_evals_to;
 #[allow(unused_imports)]
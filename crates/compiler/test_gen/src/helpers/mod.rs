@@ -92,3 +92,37 @@ pub enum RefCount {
     Deallocated,
     Constant,
 }
+
+/// Set in the environment of a test that [dbg_output_of_test_in_child_process] runs
+#[allow(dead_code)]
+pub(crate) const TEST_CHILD_PROCESS: &str = "ROC_TEST_GEN_CHILD_PROCESS";
+
+/// Runs the test `test_name` again in a child process, and returns the `[file:line] value` lines
+/// that its `dbg`s printed. The default `roc_dbg` prints to stderr directly, past the output
+/// capturing of the test harness, so what it prints can only be checked this way. Other lines,
+/// like warnings of the linker, are left out.
+#[allow(dead_code)]
+pub(crate) fn dbg_output_of_test_in_child_process(test_name: &str) -> String {
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([test_name, "--exact", "--test-threads=1"])
+        .env(TEST_CHILD_PROCESS, "1")
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(
+        output.status.success(),
+        "The test failed in its child process:\n{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        stderr
+    );
+
+    let mut dbg_output = String::new();
+    for line in stderr.lines().filter(|line| line.starts_with('[')) {
+        dbg_output.push_str(line);
+        dbg_output.push('\n');
+    }
+
+    dbg_output
+}
@@ -5,6 +5,7 @@ use roc_gen_wasm::wasm32_result::Wasm32Result;
 use roc_gen_wasm::wasm_module::{Export, ExportType};
 use roc_gen_wasm::DEBUG_SETTINGS;
 use roc_load::{ExecutionMode, LoadConfig, Threading};
+use roc_mono::ir::CrashTag;
 use std::marker::PhantomData;
 use std::path::PathBuf;
 use std::rc::Rc;
@@ -13,6 +14,7 @@ use wasm3::{Environment, Module, Runtime};
 
 const TEST_WRAPPER_NAME: &str = "test_wrapper";
 const INIT_REFCOUNT_NAME: &str = "init_refcount_test";
+const GET_DBG_OUTPUT_NAME: &str = "get_dbg_output";
 
 macro_rules! host_bytes_path {
     () => {
@@ -125,6 +127,7 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
         module_id,
         exposed_to_host,
         stack_bytes: roc_gen_wasm::Env::DEFAULT_STACK_BYTES,
+        debug_sources: None,
     };
 
     let host_module = roc_gen_wasm::parse_host(env.arena, host_bytes).unwrap_or_else(|e| {
@@ -141,20 +144,22 @@ fn compile_roc_to_wasm_bytes<'a, T: Wasm32Result>(
 
     T::insert_wrapper(arena, &mut module, TEST_WRAPPER_NAME, main_fn_index);
 
-    // Export the initialiser function for refcount tests
-    let init_refcount_idx = module
-        .names
-        .function_names
-        .iter()
-        .filter(|(_, name)| *name == INIT_REFCOUNT_NAME)
-        .map(|(i, _)| *i)
-        .next()
-        .unwrap();
-    module.export.append(Export {
-        name: INIT_REFCOUNT_NAME,
-        ty: ExportType::Func,
-        index: init_refcount_idx,
-    });
+    // Export the initialiser function for refcount tests, and the output of `dbg` for dbg tests
+    for host_fn_name in [INIT_REFCOUNT_NAME, GET_DBG_OUTPUT_NAME] {
+        let host_fn_idx = module
+            .names
+            .function_names
+            .iter()
+            .filter(|(_, name)| *name == host_fn_name)
+            .map(|(i, _)| *i)
+            .next()
+            .unwrap();
+        module.export.append(Export {
+            name: host_fn_name,
+            ty: ExportType::Func,
+            index: host_fn_idx,
+        });
+    }
 
     module.eliminate_dead_code(env.arena, called_preload_fns);
 
@@ -190,7 +195,7 @@ where
 
     let parsed = Module::parse(&env, &wasm_bytes[..]).expect("Unable to parse module");
     let mut module = rt.load_module(parsed).expect("Unable to load module");
-    let panic_msg: Rc<Mutex<Option<(i32, i32)>>> = Default::default();
+    let panic_msg: Rc<Mutex<Option<(i32, i32, i32)>>> = Default::default();
     link_module(&mut module, panic_msg.clone());
 
     let test_wrapper = module
@@ -199,12 +204,17 @@ where
 
     match test_wrapper.call() {
         Err(e) => {
-            if let Some((msg_ptr, msg_len)) = *panic_msg.lock().unwrap() {
+            if let Some((msg_ptr, msg_len, tag_id)) = *panic_msg.lock().unwrap() {
                 let memory: &[u8] = get_memory(&rt);
                 let msg_bytes = &memory[msg_ptr as usize..][..msg_len as usize];
                 let msg = std::str::from_utf8(msg_bytes).unwrap();
 
-                Err(format!("Roc failed with message: \"{}\"", msg))
+                match CrashTag::try_from(tag_id as u32) {
+                    Ok(CrashTag::User) => Err(format!("User crash with message: \"{}\"", msg)),
+                    Ok(CrashTag::Roc) | Err(_) => {
+                        Err(format!("Roc failed with message: \"{}\"", msg))
+                    }
+                }
             } else {
                 Err(format!("{}", e))
             }
@@ -230,6 +240,46 @@ where
     }
 }
 
+/// Runs `src`, and returns what its `dbg`s reported to the test host's `roc_dbg`
+#[allow(dead_code)]
+pub fn dbg_output_help<T>(src: &str, phantom: PhantomData<T>) -> Result<String, String>
+where
+    T: FromWasm32Memory + Wasm32Result,
+{
+    let arena = bumpalo::Bump::new();
+
+    let wasm_bytes = crate::helpers::wasm::compile_to_wasm_bytes(&arena, src, phantom);
+
+    let env = Environment::new().expect("Unable to create environment");
+    let rt = env
+        .create_runtime(1024 * 60)
+        .expect("Unable to create runtime");
+    let parsed = Module::parse(&env, wasm_bytes).expect("Unable to parse module");
+    let mut module = rt.load_module(parsed).expect("Unable to load module");
+
+    let panic_msg: Rc<Mutex<Option<(i32, i32, i32)>>> = Default::default();
+    link_module(&mut module, panic_msg);
+
+    let test_wrapper = module
+        .find_function::<(), i32>(TEST_WRAPPER_NAME)
+        .expect("Unable to find test wrapper function");
+    test_wrapper.call().map_err(|e| format!("{:?}", e))?;
+
+    let get_dbg_output = module
+        .find_function::<(), i32>(GET_DBG_OUTPUT_NAME)
+        .expect("Unable to find dbg output function");
+    let output_ptr = get_dbg_output.call().map_err(|e| format!("{:?}", e))? as usize;
+
+    let memory: &[u8] = get_memory(&rt);
+
+    // Read the bytes and length of the output in the C host
+    let bytes_ptr = read_i32(memory, output_ptr) as usize;
+    let length = read_i32(memory, output_ptr + 4) as usize;
+    let bytes = &memory[bytes_ptr..][..length];
+
+    String::from_utf8(bytes.to_vec()).map_err(|e| format!("{:?}", e))
+}
+
 #[allow(dead_code)]
 pub fn assert_wasm_refcounts_help<T>(
     src: &str,
@@ -250,7 +300,7 @@ where
     let parsed = Module::parse(&env, wasm_bytes).expect("Unable to parse module");
     let mut module = rt.load_module(parsed).expect("Unable to load module");
 
-    let panic_msg: Rc<Mutex<Option<(i32, i32)>>> = Default::default();
+    let panic_msg: Rc<Mutex<Option<(i32, i32, i32)>>> = Default::default();
     link_module(&mut module, panic_msg.clone());
 
     let expected_len = num_refcounts as i32;
@@ -313,11 +363,11 @@ fn read_i32(memory: &[u8], ptr: usize) -> i32 {
     i32::from_le_bytes(bytes)
 }
 
-fn link_module(module: &mut Module, panic_msg: Rc<Mutex<Option<(i32, i32)>>>) {
+fn link_module(module: &mut Module, panic_msg: Rc<Mutex<Option<(i32, i32, i32)>>>) {
     let try_link_panic = module.link_closure(
         "env",
         "send_panic_msg_to_rust",
-        move |_call_context, args: (i32, i32)| {
+        move |_call_context, args: (i32, i32, i32)| {
             let mut w = panic_msg.lock().unwrap();
             *w = Some(args);
             Ok(())
@@ -405,6 +455,20 @@ pub fn identity<T>(value: T) -> T {
     value
 }
 
+/// Checks the exact text that the `dbg`s in `$src` report through `roc_dbg`. The test name is only
+/// used by the other backends, which check it in a child process.
+#[allow(unused_macros)]
+macro_rules! assert_dbg_output {
+    ($test_name:expr, $src:expr, $ty:ty, $expected:expr) => {{
+        let phantom = std::marker::PhantomData;
+        let _ = $test_name;
+        match $crate::helpers::wasm::dbg_output_help::<$ty>($src, phantom) {
+            Err(msg) => panic!("{}", msg),
+            Ok(output) => assert_eq!(output, $expected),
+        }
+    }};
+}
+
 #[allow(unused_macros)]
 macro_rules! assert_refcounts {
     // We need the result type to generate the test_wrapper, even though we ignore the value!
@@ -432,3 +496,6 @@ pub(crate) use expect_runtime_error_panic;
 
 #[allow(unused_imports)]
 pub(crate) use assert_refcounts;
+
+#[allow(unused_imports)]
+pub(crate) use assert_dbg_output;
@@ -1,3 +1,4 @@
+#include <stdint.h>
 #include <stdio.h>
 #include <stdlib.h>
 #include <string.h>
@@ -123,17 +124,73 @@ void roc_dealloc(void *ptr, unsigned int alignment)
 
 //--------------------------
 
-extern void send_panic_msg_to_rust(char* msg, int len);
+extern void send_panic_msg_to_rust(char* msg, int len, unsigned int tag_id);
 
 void roc_panic(char *msg, unsigned int tag_id)
 {
     int len = strlen(msg);
-    send_panic_msg_to_rust(msg, len);
+    send_panic_msg_to_rust(msg, len, tag_id);
     exit(101);
 }
 
 //--------------------------
 
+typedef struct
+{
+    char *bytes;
+    size_t length;
+    size_t capacity;
+} RocStr;
+
+typedef struct
+{
+    char *bytes;
+    size_t length;
+} Output;
+
+// What the `dbg`s of a test reported, in the format of the default roc_dbg.
+// The rust test reads it once the test is done.
+Output dbg_output;
+
+Output *get_dbg_output()
+{
+    return &dbg_output;
+}
+
+void append_dbg_output(const char *bytes, size_t length)
+{
+    dbg_output.bytes = realloc(dbg_output.bytes, dbg_output.length + length);
+    memcpy(dbg_output.bytes + dbg_output.length, bytes, length);
+    dbg_output.length += length;
+}
+
+void append_roc_str_to_dbg_output(RocStr *str)
+{
+    int is_small_str = (intptr_t)str->capacity < 0;
+
+    if (is_small_str)
+    {
+        // a small string is stored in the RocStr itself, with its length in the last byte
+        size_t length = ((unsigned char *)str)[sizeof(RocStr) - 1] ^ 0x80;
+        append_dbg_output((char *)str, length);
+    }
+    else
+    {
+        append_dbg_output(str->bytes, str->length);
+    }
+}
+
+void roc_dbg(RocStr *location, RocStr *message)
+{
+    append_dbg_output("[", 1);
+    append_roc_str_to_dbg_output(location);
+    append_dbg_output("] ", 2);
+    append_roc_str_to_dbg_output(message);
+    append_dbg_output("\n", 1);
+}
+
+//--------------------------
+
 void roc_memcpy(void *dest, const void *src, size_t n)
 {
     memcpy(dest, src, n);
@@ -475,6 +475,24 @@ fn let_with_record_pattern_list() {
     "#
 }
 
+#[mono_test]
+fn let_with_tuple_pattern() {
+    r#"
+    (x, _) = ([1, 3, 4], 3.14)
+
+    x
+    "#
+}
+
+#[mono_test]
+fn when_on_tuple() {
+    r#"
+    when (1u8, "two") is
+        (1, s) -> s
+        (_, _) -> "other"
+    "#
+}
+
 #[mono_test]
 fn if_guard_bind_variable_false() {
     r#"
@@ -1338,6 +1356,75 @@ fn specialize_ability_call() {
     )
 }
 
+#[mono_test]
+fn specialize_ability_default_implementation() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        MEq has
+            eq : a, a -> Bool | a has MEq
+            neq : a, a -> Bool | a has MEq
+            neq = \x, y -> Bool.not (eq x y)
+
+        Id := U64 has [MEq {eq}]
+
+        eq = \@Id m, @Id n -> m == n
+
+        main = neq (@Id 1) (@Id 2)
+        "#
+    )
+}
+
+#[mono_test]
+fn specialize_ability_builtin_implementation() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Pretty has
+            pretty : a -> Str | a has Pretty
+
+        Str has [Pretty { pretty: prettyStr }]
+        List a has [Pretty { pretty: prettyList }]
+
+        prettyStr = \s -> Str.concat "\"" s
+        prettyList = \l -> Num.toStr (List.len l)
+
+        main = Str.concat (pretty "hi") (pretty [1, 2])
+        "#
+    )
+}
+
+#[mono_test]
+fn specialize_ability_number_and_alias_implementation() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Pretty has
+            pretty : a -> Str | a has Pretty
+
+        U8 has [Pretty { pretty: prettyU8 }]
+
+        Point : { x : U8, y : U8 }
+
+        Point has [Pretty { pretty: prettyPoint }]
+
+        prettyU8 : U8 -> Str
+        prettyU8 = \n -> Num.toStr n
+
+        prettyPoint : Point -> Str
+        prettyPoint = \{ x, y } -> Str.concat (pretty x) (pretty y)
+
+        origin : Point
+        origin = { x: 0, y: 0 }
+
+        main = pretty origin
+        "#
+    )
+}
+
 #[mono_test]
 fn opaque_assign_to_symbol() {
     indoc!(
@@ -1927,3 +2014,120 @@ fn issue_3669() {
         "#
     )
 }
+
+#[mono_test]
+fn list_pattern_with_rest() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        sumFirstAndLast : List I64 -> I64
+        sumFirstAndLast = \list ->
+            when list is
+                [] -> 0
+                [x] -> x
+                [first, .., last] -> first + last
+
+        dropFirst : List I64 -> List I64
+        dropFirst = \list ->
+            when list is
+                [_, .. as rest] -> rest
+                [] -> []
+
+        main = sumFirstAndLast (dropFirst [1, 2, 3])
+        "#
+    )
+}
+
+#[mono_test]
+fn as_pattern_in_when() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            when Ok (Pair 1 2) is
+                Ok (Pair x _ as pair) -> { x, pair }
+                Err {} -> { x: 0, pair: Pair 0 0 }
+        "#
+    )
+}
+
+#[mono_test]
+fn dbg_borrows_value() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            greeting = "Hello, a string that lives on the heap"
+
+            dbg greeting
+            Str.concat greeting "!"
+        "#
+    )
+}
+
+#[mono_test]
+fn dbg_renders_record_with_list_and_tag() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            value = { name: "Roc", scores: [1u8, 2], status: Ok (Pair 1u64 2u64) }
+
+            dbg value
+            value.name
+        "#
+    )
+}
+
+#[mono_test]
+fn dbg_renders_recursive_tag_union() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        LinkedList : [Nil, Cons U8 LinkedList]
+
+        main =
+            list : LinkedList
+            list = Cons 1 (Cons 2 Nil)
+
+            dbg list
+            1u8
+        "#
+    )
+}
+
+#[mono_test]
+fn dbg_renders_opaque() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Age := U8
+
+        main =
+            age = @Age 21
+
+            dbg (Ok age)
+            1u8
+        "#
+    )
+}
+
+#[mono_test]
+fn crash_in_when_branch() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            when List.first [1u8, 2] is
+                Ok n -> n
+                Err _ -> crash "the list is not empty"
+        "#
+    )
+}
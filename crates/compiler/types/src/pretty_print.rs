@@ -4,7 +4,7 @@ use crate::subs::{
 };
 use crate::types::{name_type_var, name_type_var_with_hint, RecordField, Uls};
 use roc_collections::all::MutMap;
-use roc_module::ident::{Lowercase, TagName};
+use roc_module::ident::{tuple_arity, Lowercase, TagName};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use std::fmt::Write;
 
@@ -1084,6 +1084,41 @@ fn write_flat_type<'a>(
                 .expect("Something ended up weird in this record type");
             let ext_var = ext;
 
+            let is_closed = matches!(
+                subs.get_content_without_compacting(ext_var),
+                Content::Structure(EmptyRecord)
+            );
+
+            if is_closed && tuple_arity(sorted_fields.iter().map(|(label, _)| label)).is_some() {
+                // A closed record whose fields are exactly "0", "1", ... is a tuple
+                let mut elems: Vec<_> = sorted_fields
+                    .iter()
+                    .map(|(label, field)| (label.as_tuple_elem(), *field.as_inner()))
+                    .collect();
+                elems.sort_by_key(|(index, _)| *index);
+
+                buf.push_str("( ");
+
+                for (i, (_, var)) in elems.into_iter().enumerate() {
+                    if i > 0 {
+                        buf.push_str(", ");
+                    }
+
+                    write_content(
+                        env,
+                        ctx,
+                        subs.get_content_without_compacting(var),
+                        subs,
+                        buf,
+                        Parens::Unnecessary,
+                    );
+                }
+
+                buf.push_str(" )");
+
+                return;
+            }
+
             if fields.is_empty() {
                 buf.push_str(EMPTY_RECORD)
             } else {
@@ -245,6 +245,7 @@ impl Subs {
                 tag_name_cache: Default::default(),
                 problems: Default::default(),
                 uls_of_var: Default::default(),
+                required_abilities: Default::default(),
             },
             exposed_vars_by_symbol,
         )
@@ -394,6 +395,9 @@ pub struct Subs {
     pub tag_name_cache: TagNameCache,
     pub problems: Vec<Problem>,
     pub uls_of_var: UlsOfVar,
+    /// Abilities that require other abilities, as in `Ord has Eq`. A type variable bound to `Ord`
+    /// may be used where one bound to `Eq` is expected.
+    pub required_abilities: VecMap<Symbol, Vec<Symbol>>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1713,6 +1717,7 @@ impl Subs {
             tag_name_cache: Default::default(),
             problems: Vec::new(),
             uls_of_var: Default::default(),
+            required_abilities: Default::default(),
         };
 
         subs.utable.reserve(capacity);
@@ -1806,6 +1811,29 @@ impl Subs {
         self.set(var, desc);
     }
 
+    /// Whether `ability` requires `required` of the types implementing it, directly or through
+    /// another ability it requires.
+    pub fn ability_requires(&self, ability: Symbol, required: Symbol) -> bool {
+        let mut stack = vec![ability];
+        let mut seen = VecSet::default();
+
+        while let Some(ability) = stack.pop() {
+            if seen.insert(ability) {
+                // Already visited
+                continue;
+            }
+
+            for &next in self.required_abilities.get(&ability).into_iter().flatten() {
+                if next == required {
+                    return true;
+                }
+                stack.push(next);
+            }
+        }
+
+        false
+    }
+
     /// Unions two keys without the possibility of failure.
     pub fn union(&mut self, left: Variable, right: Variable, desc: Descriptor) {
         let l_root = self.utable.root_key(left);
@@ -4067,6 +4095,16 @@ impl StorageSubs {
         copy_import_to(&self.subs, target, false, variable, Rank::import())
     }
 
+    /// Like [`Self::export_variable_to`], but the exported variable is generalized in the target,
+    /// so that it is instantiated anew at each of its uses.
+    pub fn export_generalized_variable_to(
+        &self,
+        target: &mut Subs,
+        variable: Variable,
+    ) -> CopiedImport {
+        copy_import_to(&self.subs, target, false, variable, Rank::NONE)
+    }
+
     /// Like [`Self::export_variable_to`], but with the expectation that the exported variable is
     /// going directly to a usage site, rather than to be generalized as a toplevel definition.
     ///
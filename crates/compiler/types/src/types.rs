@@ -1957,6 +1957,7 @@ pub enum Reason {
     },
     WhenGuard,
     ExpectCondition,
+    CrashArg,
     IfCondition,
     IfBranch {
         index: HumanIndex,
@@ -2014,6 +2015,7 @@ pub enum Category {
 
     // records
     Record,
+    Tuple,
     Accessor(Lowercase),
     Access(Lowercase),
     DefaultValue(Lowercase), // for setting optional fields
@@ -2021,12 +2023,15 @@ pub enum Category {
     AbilityMemberSpecialization(Symbol),
 
     Expect,
+    Crash,
     Unknown,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PatternCategory {
     Record,
+    Tuple,
+    List,
     EmptyRecord,
     PatternGuard,
     PatternDefault,
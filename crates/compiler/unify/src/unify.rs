@@ -830,6 +830,17 @@ fn unify_alias<M: MetaCollector>(
             merge(env, ctx, Alias(symbol, args, real_var, kind))
         }
         RecursionVar { structure, .. } => unify_pool(env, pool, real_var, *structure, ctx.mode),
+        FlexAbleVar(_, ability) if !is_alias_of_opaque(env.subs, real_var) => {
+            // Alias wins, so that abilities implemented for the alias apply
+            merge_flex_able_with_concrete(
+                env,
+                ctx,
+                ctx.second,
+                *ability,
+                Alias(symbol, args, real_var, kind),
+                Obligated::Adhoc(ctx.first),
+            )
+        }
         RigidVar(_) | RigidAbleVar(..) | FlexAbleVar(..) => {
             unify_pool(env, pool, real_var, ctx.second, ctx.mode)
         }
@@ -860,6 +871,17 @@ fn unify_alias<M: MetaCollector>(
     }
 }
 
+/// Whether `var` is an opaque type other than a number, possibly behind more type aliases.
+fn is_alias_of_opaque(subs: &Subs, mut var: Variable) -> bool {
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Alias(_, _, real_var, AliasKind::Structural) => var = *real_var,
+            Alias(opaque, _, _, AliasKind::Opaque) => return opaque.module_id() != ModuleId::NUM,
+            _ => return false,
+        }
+    }
+}
+
 #[inline(always)]
 fn opaque_obligation(opaque: Symbol, opaque_var: Variable) -> Obligated {
     match opaque.module_id() {
@@ -2763,12 +2785,10 @@ fn unify_rigid_able<M: MetaCollector>(
             merge(env, ctx, RigidVar(*name))
         }
         FlexAbleVar(_, other_ability) => {
-            if ability == *other_ability {
-                // The ability bounds are the same, so rigid wins!
+            if ability == *other_ability || env.subs.ability_requires(ability, *other_ability) {
+                // The rigid's ability bound covers the flex's, so rigid wins!
                 merge(env, ctx, RigidAbleVar(*name, ability))
             } else {
-                // Mismatch for now.
-                // TODO check ability hierarchies.
                 mismatch!(
                     %not_able, ctx.second, ability,
                     "RigidAble {:?} with ability {:?} not compatible with ability {:?}",
@@ -2853,11 +2873,12 @@ fn unify_flex_able<M: MetaCollector>(
             // Prefer the right's name when possible.
             let opt_name = (opt_other_name).or(*opt_name);
 
-            if ability == *other_ability {
+            if ability == *other_ability || env.subs.ability_requires(ability, *other_ability) {
                 merge(env, ctx, FlexAbleVar(opt_name, ability))
+            } else if env.subs.ability_requires(*other_ability, ability) {
+                merge(env, ctx, FlexAbleVar(opt_name, *other_ability))
             } else {
-                // Ability names differ; mismatch for now.
-                // TODO check ability hierarchies.
+                // Ability names differ, and neither requires the other.
                 mismatch!(
                     %not_able, ctx.second, ability,
                     "FlexAble {:?} with ability {:?} not compatible with ability {:?}",
@@ -2869,7 +2890,7 @@ fn unify_flex_able<M: MetaCollector>(
         }
 
         RigidAbleVar(_, other_ability) => {
-            if ability == *other_ability {
+            if ability == *other_ability || env.subs.ability_requires(*other_ability, ability) {
                 merge(env, ctx, *other)
             } else {
                 mismatch!(%not_able, ctx.second, ability, "RigidAble {:?} vs {:?}", ability, other_ability)
@@ -6,13 +6,16 @@ use html::mark_node_to_html;
 use roc_can::scope::Scope;
 use roc_code_markup::markup::nodes::MarkupNode;
 use roc_code_markup::slow_pool::SlowPool;
+use roc_collections::MutMap;
 use roc_highlight::highlight_parser::{highlight_defs, highlight_expr};
 use roc_load::docs::DocEntry::DocDef;
 use roc_load::docs::{DocEntry, TypeAnnotation};
 use roc_load::docs::{ModuleDocumentation, RecordField};
 use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
-use roc_module::symbol::{IdentIdsByModule, Interns, ModuleId};
+use roc_module::symbol::{Interns, ModuleId};
+use roc_parse::ast::{ExtractSpaces, Module};
 use roc_parse::ident::{parse_ident, Ident};
+use roc_parse::module::parse_header;
 use roc_parse::state::State;
 use roc_region::all::Region;
 use std::fs;
@@ -21,42 +24,44 @@ use std::path::{Path, PathBuf};
 mod docs_error;
 mod html;
 
-const BUILD_DIR: &str = "./generated-docs";
-
-pub fn generate_docs_html(filenames: Vec<PathBuf>) {
-    let build_dir = Path::new(BUILD_DIR);
-    let loaded_modules = load_modules_for_files(filenames);
+/// Generate HTML docs for the given .roc files, and write them to `build_dir`.
+///
+/// If one of the files has a `package` or `platform` header, the package's name and exposed
+/// modules are read from that header, and its README.md (if any) becomes the landing page. Otherwise, each of the given files gets documented as a standalone module.
+///
+/// `base_url` is where `build_dir` will be served from, e.g. "/packages/json/", so that several
+/// packages can be hosted on the same site. Types and values from builtin modules link to the
+/// builtins docs at `builtins_url` (unless it's the builtins themselves being documented), and
+/// those from other packages link to the URL given for that package's shorthand in
+/// `package_urls`, e.g. `("json", "/packages/json")`.
+pub fn generate_docs_html(
+    filenames: Vec<PathBuf>,
+    build_dir: &Path,
+    base_url: &str,
+    builtins_url: &str,
+    package_urls: &[(&str, &str)],
+) {
+    let base_url = normalize_base_url(base_url);
 
-    // TODO: get info from a package module; this is all hardcoded for now.
-    let mut package = roc_load::docs::Documentation {
-        name: "documentation".to_string(),
-        version: "".to_string(),
-        docs: "Package introduction or README.".to_string(),
-        modules: loaded_modules,
+    let (filenames, name, readme, packages) = match read_package_info(&filenames) {
+        Some(info) => (info.exposed_modules, info.name, info.readme, info.packages),
+        None => (filenames, "documentation".to_string(), None, Vec::new()),
     };
 
-    if !build_dir.exists() {
-        fs::create_dir_all(build_dir).expect("TODO gracefully handle unable to create build dir");
-    }
-
-    // Copy over the assets
-    fs::write(
-        build_dir.join("search.js"),
-        include_str!("./static/search.js"),
-    )
-    .expect("TODO gracefully handle failing to make the search javascript");
+    let packages: Vec<(&str, &str)> = packages
+        .iter()
+        .map(|(shorthand, package_path)| (shorthand.as_str(), package_path.as_str()))
+        .collect();
 
-    fs::write(
-        build_dir.join("styles.css"),
-        include_str!("./static/styles.css"),
-    )
-    .expect("TODO gracefully handle failing to make the stylesheet");
+    let loaded_modules = load_modules_for_files(filenames, &packages);
 
-    fs::write(
-        build_dir.join("favicon.svg"),
-        include_str!("./static/favicon.svg"),
-    )
-    .expect("TODO gracefully handle failing to make the favicon");
+    let package = roc_load::docs::Documentation {
+        name,
+        // Package headers don't have a version yet
+        version: "".to_string(),
+        docs: readme.unwrap_or_default(),
+        modules: loaded_modules,
+    };
 
     let module_pairs = package.modules.iter().flat_map(|loaded_module| {
         loaded_module
@@ -82,16 +87,49 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
             })
     });
 
+    if !build_dir.exists() {
+        fs::create_dir_all(build_dir).expect("TODO gracefully handle unable to create build dir");
+    }
+
+    // Copy over the assets
+    fs::write(
+        build_dir.join("search.js"),
+        include_str!("./static/search.js"),
+    )
+    .expect("TODO gracefully handle failing to make the search javascript");
+
+    fs::write(
+        build_dir.join("search-index.js"),
+        render_search_index(&base_url, module_pairs.clone()),
+    )
+    .expect("TODO gracefully handle failing to make the search index");
+
+    fs::write(
+        build_dir.join("styles.css"),
+        include_str!("./static/styles.css"),
+    )
+    .expect("TODO gracefully handle failing to make the stylesheet");
+
+    fs::write(
+        build_dir.join("favicon.svg"),
+        include_str!("./static/favicon.svg"),
+    )
+    .expect("TODO gracefully handle failing to make the favicon");
+
     let template_html = include_str!("./static/index.html")
-        .replace("<!-- search.js -->", "/search.js")
-        .replace("<!-- styles.css -->", "/styles.css")
-        .replace("<!-- favicon.svg -->", "/favicon.svg")
+        .replace("<!-- search.js -->", &format!("{}search.js", base_url))
+        .replace(
+            "<!-- search-index.js -->",
+            &format!("{}search-index.js", base_url),
+        )
+        .replace("<!-- styles.css -->", &format!("{}styles.css", base_url))
+        .replace("<!-- favicon.svg -->", &format!("{}favicon.svg", base_url))
         .replace(
             "<!-- Prefetch links -->",
             &module_pairs
                 .clone()
                 .map(|(module, _)| {
-                    let href = sidebar_link_url(module);
+                    let href = sidebar_link_url(&base_url, module);
 
                     format!(r#"<link rel="prefetch" href="{href}"/>"#)
                 })
@@ -100,11 +138,42 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
         )
         .replace(
             "<!-- Module links -->",
-            render_sidebar(module_pairs).as_str(),
+            render_sidebar(&base_url, module_pairs.clone()).as_str(),
+        )
+        .replace(
+            "<!-- Package Name and Version -->",
+            render_name_and_version(&base_url, package.name.as_str(), package.version.as_str())
+                .as_str(),
         );
 
+    // Write the package's landing page
+    let landing_page = template_html.replace(
+        "<!-- Module Docs -->",
+        render_package_index(
+            &base_url,
+            &package.docs,
+            module_pairs.clone().map(|(module, _)| module),
+        )
+        .as_str(),
+    );
+
+    fs::write(build_dir.join("index.html"), landing_page)
+        .expect("TODO gracefully handle failing to write the package's index.html");
+
+    let links = Links {
+        base_url: base_url.clone(),
+        builtins_url: normalize_base_url(builtins_url),
+        package_urls: package_urls
+            .iter()
+            .map(|(shorthand, url)| (shorthand.to_string(), normalize_base_url(url)))
+            .collect(),
+        module_names: module_pairs
+            .map(|(module, _)| module.name.clone())
+            .collect(),
+    };
+
     // Write each package's module docs html file
-    for loaded_module in package.modules.iter_mut() {
+    for loaded_module in package.modules.iter() {
         for (module_id, module_docs) in loaded_module.documentation.iter() {
             if *module_id == loaded_module.module_id {
                 let module_dir = build_dir.join(module_docs.name.replace('.', "/").as_str());
@@ -112,16 +181,10 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
                 fs::create_dir_all(&module_dir)
                     .expect("TODO gracefully handle not being able to create the module dir");
 
-                let rendered_module = template_html
-                    .replace(
-                        "<!-- Package Name and Version -->",
-                        render_name_and_version(package.name.as_str(), package.version.as_str())
-                            .as_str(),
-                    )
-                    .replace(
-                        "<!-- Module Docs -->",
-                        render_module_documentation(module_docs, loaded_module).as_str(),
-                    );
+                let rendered_module = template_html.replace(
+                    "<!-- Module Docs -->",
+                    render_module_documentation(&links, module_docs, loaded_module).as_str(),
+                );
 
                 fs::write(module_dir.join("index.html"), rendered_module).expect(
                     "TODO gracefully handle failing to write index.html inside module's dir",
@@ -133,8 +196,66 @@ pub fn generate_docs_html(filenames: Vec<PathBuf>) {
     println!("🎉 Docs generated in {}", build_dir.display());
 }
 
-fn sidebar_link_url(module: &ModuleDocumentation) -> String {
-    let mut href_buf = base_url();
+/// What the header of a package's root module says about that package.
+struct PackageInfo {
+    name: String,
+    exposed_modules: Vec<PathBuf>,
+    readme: Option<String>,
+    /// The packages it depends on, by shorthand, e.g. `("json", "json/main.roc")`
+    packages: Vec<(String, String)>,
+}
+
+/// Find the root module of a package among the given files (that is, a module with a `package`
+/// or `platform` header), and read the package's info from it.
+fn read_package_info(filenames: &[PathBuf]) -> Option<PackageInfo> {
+    filenames.iter().find_map(|filename| {
+        let src = fs::read(filename).ok()?;
+        let arena = Bump::new();
+
+        let (package_name, exposes, packages) = match parse_header(&arena, State::new(&src)) {
+            Ok((Module::Package { header }, _)) => {
+                (header.name.value, header.exposes, header.packages)
+            }
+            Ok((Module::Platform { header }, _)) => {
+                (header.name.value, header.exposes, header.packages)
+            }
+            _ => return None,
+        };
+
+        let package_dir = filename.parent().unwrap_or_else(|| Path::new(""));
+
+        let exposed_modules = exposes
+            .iter()
+            .map(|loc_module_name| {
+                let module_name = loc_module_name.value.extract_spaces().item;
+
+                package_dir
+                    .join(module_name.as_str().replace('.', "/"))
+                    .with_extension("roc")
+            })
+            .collect();
+
+        Some(PackageInfo {
+            name: package_name.as_str().to_string(),
+            exposed_modules,
+            readme: fs::read_to_string(package_dir.join("README.md")).ok(),
+            packages: packages
+                .iter()
+                .map(|loc_entry| {
+                    let entry = loc_entry.value.extract_spaces().item;
+
+                    (
+                        entry.shorthand.to_string(),
+                        entry.package_name.value.to_str().to_string(),
+                    )
+                })
+                .collect(),
+        })
+    })
+}
+
+fn sidebar_link_url(base_url: &str, module: &ModuleDocumentation) -> String {
+    let mut href_buf = base_url.to_string();
     href_buf.push_str(module.name.as_str());
 
     href_buf
@@ -184,16 +305,24 @@ pub fn syntax_highlight_top_level_defs(code_str: &str) -> DocsResult<String> {
 }
 
 fn render_module_documentation(
+    links: &Links,
     module: &ModuleDocumentation,
     loaded_module: &LoadedModule,
 ) -> String {
+    let base_url = links.base_url.as_str();
+    let module_links = ModuleLinks {
+        links,
+        scope: &module.scope,
+        interns: &loaded_module.interns,
+        package_shorthands: &loaded_module.package_shorthands,
+    };
     let mut buf = String::new();
 
     buf.push_str(
         html_to_string(
             "h2",
             vec![("class", "module-name")],
-            html_to_string("a", vec![("href", "/#")], module.name.as_str()).as_str(),
+            html_to_string("a", vec![("href", base_url)], module.name.as_str()).as_str(),
         )
         .as_str(),
     );
@@ -239,7 +368,7 @@ fn render_module_documentation(
                         }
                     }
 
-                    type_annotation_to_html(0, &mut content, type_ann, false);
+                    type_annotation_to_html(&module_links, 0, &mut content, type_ann, false);
 
                     buf.push_str(
                         html_to_string(
@@ -253,6 +382,7 @@ fn render_module_documentation(
                     if let Some(docs) = &doc_def.docs {
                         buf.push_str(
                             markdown_to_html(
+                                links,
                                 &exposed_values,
                                 &module.scope,
                                 docs.to_string(),
@@ -266,6 +396,7 @@ fn render_module_documentation(
                 }
                 DocEntry::DetachedDoc(docs) => {
                     let markdown = markdown_to_html(
+                        links,
                         &exposed_values,
                         &module.scope,
                         docs.to_string(),
@@ -309,77 +440,95 @@ fn html_to_string(tag_name: &str, attrs: Vec<(&str, &str)>, content: &str) -> St
     buf
 }
 
-fn base_url() -> String {
-    // e.g. "builtins/" in "https://roc-lang.org/builtins/Str"
-    //
-    // TODO make this a CLI flag to the `docs` subcommand instead of an env var
-    match std::env::var("ROC_DOCS_URL_ROOT") {
-        Ok(root_builtins_path) => {
-            let mut url_str = String::with_capacity(root_builtins_path.len() + 64);
+/// Make sure the base URL starts and ends with a slash, so that we can append paths to it.
+///
+/// e.g. "builtins" becomes "/builtins/", so that we link to "/builtins/Str"
+///
+/// URLs of other sites, like "https://www.roc-lang.org/builtins", only get the trailing slash.
+fn normalize_base_url(base_url: &str) -> String {
+    let mut url_str = String::with_capacity(base_url.len() + 64);
 
-            if !root_builtins_path.starts_with('/') {
-                url_str.push('/');
-            }
-
-            url_str.push_str(&root_builtins_path);
-
-            if !root_builtins_path.ends_with('/') {
-                url_str.push('/');
-            }
-
-            url_str
-        }
-        _ => {
-            let mut url_str = String::with_capacity(64);
+    if !base_url.starts_with('/') && !base_url.contains("://") {
+        url_str.push('/');
+    }
 
-            url_str.push('/');
+    url_str.push_str(base_url);
 
-            url_str
-        }
+    if !url_str.ends_with('/') {
+        url_str.push('/');
     }
+
+    url_str
 }
 
-fn render_name_and_version(name: &str, version: &str) -> String {
+fn render_name_and_version(base_url: &str, name: &str, version: &str) -> String {
     let mut buf = String::new();
-    let mut url_str = base_url();
-
-    url_str.push_str(name);
 
     buf.push_str(
         html_to_string(
             "h1",
             vec![("class", "pkg-full-name")],
-            html_to_string("a", vec![("href", url_str.as_str())], name).as_str(),
+            html_to_string("a", vec![("href", base_url)], name).as_str(),
         )
         .as_str(),
     );
 
-    let mut versions_url_str = base_url();
+    if !version.is_empty() {
+        buf.push_str(
+            html_to_string("a", vec![("class", "version"), ("href", base_url)], version).as_str(),
+        );
+    }
 
-    versions_url_str.push('/');
-    versions_url_str.push_str(name);
-    versions_url_str.push('/');
-    versions_url_str.push_str(version);
+    buf
+}
 
-    buf.push_str(
-        html_to_string(
-            "a",
-            vec![("class", "version"), ("href", versions_url_str.as_str())],
-            version,
-        )
-        .as_str(),
-    );
+/// The landing page of a package: its README if it has one, and otherwise a list of its modules.
+fn render_package_index<'a, I: Iterator<Item = &'a ModuleDocumentation>>(
+    base_url: &str,
+    readme: &str,
+    modules: I,
+) -> String {
+    if !readme.is_empty() {
+        let mut buf = String::new();
+
+        pulldown_cmark::html::push_html(
+            &mut buf,
+            pulldown_cmark::Parser::new_ext(readme, pulldown_cmark::Options::empty()),
+        );
+
+        return buf;
+    }
+
+    let mut module_links = String::new();
+
+    for module in modules {
+        let href = sidebar_link_url(base_url, module);
+
+        module_links.push_str(
+            html_to_string(
+                "li",
+                vec![],
+                html_to_string("a", vec![("href", href.as_str())], module.name.as_str()).as_str(),
+            )
+            .as_str(),
+        );
+    }
+
+    let mut buf = html_to_string("h2", vec![("class", "module-name")], "Modules");
+
+    buf.push_str(html_to_string("ul", vec![], module_links.as_str()).as_str());
 
     buf
 }
 
 fn render_sidebar<'a, I: Iterator<Item = (&'a ModuleDocumentation, Vec<String>)>>(
+    base_url: &str,
     modules: I,
 ) -> String {
     let mut buf = String::new();
 
     for (module, exposed_values) in modules {
-        let href = sidebar_link_url(module);
+        let href = sidebar_link_url(base_url, module);
         let mut sidebar_entry_content = String::new();
 
         sidebar_entry_content.push_str(
@@ -440,7 +589,71 @@ fn render_sidebar<'a, I: Iterator<Item = (&'a ModuleDocumentation, Vec<String>)>
     buf
 }
 
-pub fn load_modules_for_files(filenames: Vec<PathBuf>) -> Vec<LoadedModule> {
+/// The search index that search.js uses to find exposed values and types by their name or their
+/// type signature, e.g. `List a, (a -> b) -> List b`
+fn render_search_index<'a, I: Iterator<Item = (&'a ModuleDocumentation, Vec<String>)>>(
+    base_url: &str,
+    modules: I,
+) -> String {
+    let mut buf = String::from("window.rocSearchIndex = [\n");
+
+    for (module, exposed_values) in modules {
+        let href = sidebar_link_url(base_url, module);
+
+        for entry in &module.entries {
+            if let DocEntry::DocDef(doc_def) = entry {
+                if !exposed_values.contains(&doc_def.name) {
+                    continue;
+                }
+
+                let mut signature = String::new();
+
+                type_annotation_to_signature(&mut signature, &doc_def.type_annotation, false);
+
+                buf.push_str(
+                    format!(
+                        "  {{ module: {}, name: {}, signature: {}, url: {} }},\n",
+                        js_string(&module.name),
+                        js_string(&doc_def.name),
+                        js_string(&signature),
+                        js_string(&format!("{}#{}", href, doc_def.name)),
+                    )
+                    .as_str(),
+                );
+            }
+        }
+    }
+
+    buf.push_str("];\n");
+
+    buf
+}
+
+fn js_string(string: &str) -> String {
+    let mut buf = String::with_capacity(string.len() + 2);
+
+    buf.push('"');
+
+    for ch in string.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            _ => buf.push(ch),
+        }
+    }
+
+    buf.push('"');
+
+    buf
+}
+
+/// Load and type-check each of the given modules. `packages` are the shorthands that the
+/// modules can import other packages with, e.g. `("json", "json/main.roc")`.
+pub fn load_modules_for_files(
+    filenames: Vec<PathBuf>,
+    packages: &[(&str, &str)],
+) -> Vec<LoadedModule> {
     let arena = Bump::new();
     let mut modules = Vec::with_capacity(filenames.len());
 
@@ -451,7 +664,13 @@ pub fn load_modules_for_files(filenames: Vec<PathBuf>) -> Vec<LoadedModule> {
             threading: Threading::AllAvailable,
             exec_mode: ExecutionMode::Check,
         };
-        match roc_load::load_and_typecheck(&arena, filename, Default::default(), load_config) {
+        match roc_load::load_and_typecheck_in_package(
+            &arena,
+            filename,
+            packages,
+            Default::default(),
+            load_config,
+        ) {
             Ok(loaded) => modules.push(loaded),
             Err(LoadingProblem::FormattedReport(report)) => {
                 eprintln!("{}", report);
@@ -478,6 +697,7 @@ fn new_line(buf: &mut String) {
 
 // html is written to buf
 fn type_annotation_to_html(
+    links: &ModuleLinks,
     indent_level: usize,
     buf: &mut String,
     type_ann: &TypeAnnotation,
@@ -516,7 +736,7 @@ fn type_annotation_to_html(
 
                     for type_value in &tag.values {
                         buf.push(' ');
-                        type_annotation_to_html(next_indent_level, buf, type_value, true);
+                        type_annotation_to_html(links, next_indent_level, buf, type_value, true);
                     }
 
                     if is_multiline {
@@ -535,23 +755,23 @@ fn type_annotation_to_html(
                 buf.push(']');
             }
 
-            type_annotation_to_html(indent_level, buf, extension, true);
+            type_annotation_to_html(links, indent_level, buf, extension, true);
         }
         TypeAnnotation::BoundVariable(var_name) => {
             buf.push_str(var_name);
         }
         TypeAnnotation::Apply { name, parts } => {
             if parts.is_empty() {
-                buf.push_str(name);
+                push_type_name(links, buf, name);
             } else {
                 if needs_parens {
                     buf.push('(');
                 }
 
-                buf.push_str(name);
+                push_type_name(links, buf, name);
                 for part in parts {
                     buf.push(' ');
-                    type_annotation_to_html(indent_level, buf, part, true);
+                    type_annotation_to_html(links, indent_level, buf, part, true);
                 }
 
                 if needs_parens {
@@ -599,13 +819,25 @@ fn type_annotation_to_html(
                             type_annotation, ..
                         } => {
                             buf.push_str(" : ");
-                            type_annotation_to_html(next_indent_level, buf, type_annotation, false);
+                            type_annotation_to_html(
+                                links,
+                                next_indent_level,
+                                buf,
+                                type_annotation,
+                                false,
+                            );
                         }
                         RecordField::OptionalField {
                             type_annotation, ..
                         } => {
                             buf.push_str(" ? ");
-                            type_annotation_to_html(next_indent_level, buf, type_annotation, false);
+                            type_annotation_to_html(
+                                links,
+                                next_indent_level,
+                                buf,
+                                type_annotation,
+                                false,
+                            );
                         }
                         RecordField::LabelOnly { .. } => {}
                     }
@@ -628,9 +860,28 @@ fn type_annotation_to_html(
                 buf.push('}');
             }
 
-            type_annotation_to_html(indent_level, buf, extension, true);
+            type_annotation_to_html(links, indent_level, buf, extension, true);
+        }
+        TypeAnnotation::Tuple { elems } => {
+            buf.push('(');
+
+            for (index, elem) in elems.iter().enumerate() {
+                if index > 0 {
+                    buf.push_str(", ");
+                }
+
+                let elem_needs_parens = matches!(elem, TypeAnnotation::Function { .. });
+
+                type_annotation_to_html(links, indent_level, buf, elem, elem_needs_parens);
+            }
+
+            buf.push(')');
         }
         TypeAnnotation::Function { args, output } => {
+            if needs_parens {
+                buf.push('(');
+            }
+
             let mut peekable_args = args.iter().peekable();
             while let Some(arg) = peekable_args.next() {
                 if is_multiline {
@@ -640,7 +891,9 @@ fn type_annotation_to_html(
                     indent(buf, indent_level + 1);
                 }
 
-                type_annotation_to_html(indent_level, buf, arg, false);
+                let arg_needs_parens = matches!(arg, TypeAnnotation::Function { .. });
+
+                type_annotation_to_html(links, indent_level, buf, arg, arg_needs_parens);
 
                 if peekable_args.peek().is_some() {
                     buf.push_str(", ");
@@ -660,7 +913,143 @@ fn type_annotation_to_html(
                 next_indent_level += 1;
             }
 
-            type_annotation_to_html(next_indent_level, buf, output, false);
+            type_annotation_to_html(links, next_indent_level, buf, output, false);
+
+            if needs_parens {
+                buf.push(')');
+            }
+        }
+        TypeAnnotation::Ability { members: _ } => {
+            // TODO(abilities): fill me in
+        }
+        TypeAnnotation::ObscuredTagUnion => {
+            buf.push_str("[@..]");
+        }
+        TypeAnnotation::ObscuredRecord => {
+            buf.push_str("{ @.. }");
+        }
+        TypeAnnotation::NoTypeAnn => {}
+        TypeAnnotation::Wildcard => buf.push('*'),
+    }
+}
+
+/// A type annotation on a single line, as plain text, e.g. `List a, (a -> b) -> List b`
+fn type_annotation_to_signature(buf: &mut String, type_ann: &TypeAnnotation, needs_parens: bool) {
+    match type_ann {
+        TypeAnnotation::TagUnion { tags, extension } => {
+            buf.push('[');
+
+            for (index, tag) in tags.iter().enumerate() {
+                if index > 0 {
+                    buf.push_str(", ");
+                }
+
+                buf.push_str(tag.name.as_str());
+
+                for type_value in &tag.values {
+                    buf.push(' ');
+                    type_annotation_to_signature(buf, type_value, true);
+                }
+            }
+
+            buf.push(']');
+
+            type_annotation_to_signature(buf, extension, true);
+        }
+        TypeAnnotation::BoundVariable(var_name) => {
+            buf.push_str(var_name);
+        }
+        TypeAnnotation::Apply { name, parts } => {
+            let needs_parens = needs_parens && !parts.is_empty();
+
+            if needs_parens {
+                buf.push('(');
+            }
+
+            buf.push_str(name);
+
+            for part in parts {
+                buf.push(' ');
+                type_annotation_to_signature(buf, part, true);
+            }
+
+            if needs_parens {
+                buf.push(')');
+            }
+        }
+        TypeAnnotation::Record { fields, extension } => {
+            if fields.is_empty() {
+                buf.push_str("{}");
+            } else {
+                buf.push_str("{ ");
+
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        buf.push_str(", ");
+                    }
+
+                    match field {
+                        RecordField::RecordField {
+                            name,
+                            type_annotation,
+                        } => {
+                            buf.push_str(name);
+                            buf.push_str(" : ");
+                            type_annotation_to_signature(buf, type_annotation, false);
+                        }
+                        RecordField::OptionalField {
+                            name,
+                            type_annotation,
+                        } => {
+                            buf.push_str(name);
+                            buf.push_str(" ? ");
+                            type_annotation_to_signature(buf, type_annotation, false);
+                        }
+                        RecordField::LabelOnly { name } => buf.push_str(name),
+                    }
+                }
+
+                buf.push_str(" }");
+            }
+
+            type_annotation_to_signature(buf, extension, true);
+        }
+        TypeAnnotation::Tuple { elems } => {
+            buf.push('(');
+
+            for (index, elem) in elems.iter().enumerate() {
+                if index > 0 {
+                    buf.push_str(", ");
+                }
+
+                let elem_needs_parens = matches!(elem, TypeAnnotation::Function { .. });
+
+                type_annotation_to_signature(buf, elem, elem_needs_parens);
+            }
+
+            buf.push(')');
+        }
+        TypeAnnotation::Function { args, output } => {
+            if needs_parens {
+                buf.push('(');
+            }
+
+            for (index, arg) in args.iter().enumerate() {
+                if index > 0 {
+                    buf.push_str(", ");
+                }
+
+                let arg_needs_parens = matches!(arg, TypeAnnotation::Function { .. });
+
+                type_annotation_to_signature(buf, arg, arg_needs_parens);
+            }
+
+            buf.push_str(" -> ");
+            type_annotation_to_signature(buf, output, false);
+
+            if needs_parens {
+                buf.push(')');
+            }
         }
         TypeAnnotation::Ability { members: _ } => {
             // TODO(abilities): fill me in
@@ -741,26 +1130,110 @@ fn should_be_multiline(type_ann: &TypeAnnotation) -> bool {
 
             is_multiline
         }
+        TypeAnnotation::Tuple { elems } => elems.iter().any(should_be_multiline),
         TypeAnnotation::Ability { .. } => true,
         TypeAnnotation::Wildcard => false,
         TypeAnnotation::NoTypeAnn => false,
     }
 }
 
+/// Where the docs for the modules that a module's docs refer to can be found.
+struct Links {
+    base_url: String,
+    builtins_url: String,
+    /// Where the docs of other packages are served from, by the shorthand they're imported with
+    package_urls: MutMap<String, String>,
+    /// The modules whose docs are being generated, which are all served from `base_url`
+    module_names: Vec<String>,
+}
+
+impl Links {
+    /// The URL of the docs for the given module, if we know where they are.
+    ///
+    /// `package` is the shorthand of the package the module was imported from, if it's not part
+    /// of the package being documented. Modules that aren't being documented here, aren't
+    /// builtins, and don't come from a package with a known URL have no docs to link to,
+    /// e.g. the internal modules of a platform.
+    fn module_url(
+        &self,
+        package: Option<&str>,
+        module_id: ModuleId,
+        module_name: &str,
+    ) -> Option<String> {
+        let root = match package {
+            Some(shorthand) => self.package_urls.get(shorthand)?,
+            None if self.module_names.iter().any(|name| name == module_name) => &self.base_url,
+            None if module_id.is_builtin() => &self.builtins_url,
+            None => return None,
+        };
+
+        Some(format!("{}{}", root, module_name))
+    }
+}
+
+/// Resolves the names used in one module's type annotations to links.
+struct ModuleLinks<'a> {
+    links: &'a Links,
+    scope: &'a Scope,
+    interns: &'a Interns,
+    package_shorthands: &'a MutMap<ModuleId, String>,
+}
+
+impl<'a> ModuleLinks<'a> {
+    /// The URL of the docs for a type name, which may be qualified (e.g. `Dict.Dict`)
+    fn type_url(&self, name: &str) -> Option<String> {
+        let (module_id, module_name, ident) = match name.rsplit_once('.') {
+            Some((module_name, ident)) => {
+                let module_id = self.interns.module_ids.get_id(&module_name.into())?;
+
+                (module_id, module_name, ident)
+            }
+            None => {
+                let symbol = self.scope.lookup_str(name, Region::zero()).ok()?;
+
+                (
+                    symbol.module_id(),
+                    symbol.module_string(self.interns).as_str(),
+                    name,
+                )
+            }
+        };
+
+        let package = self.package_shorthands.get(&module_id).map(String::as_str);
+        let mut url = self.links.module_url(package, module_id, module_name)?;
+
+        url.push('#');
+        url.push_str(ident);
+
+        Some(url)
+    }
+}
+
+/// Write a type's name, linking to its docs if we can find them.
+fn push_type_name(links: &ModuleLinks, buf: &mut String, name: &str) {
+    match links.type_url(name) {
+        Some(url) => buf.push_str(html_to_string("a", vec![("href", url.as_str())], name).as_str()),
+        None => buf.push_str(name),
+    }
+}
+
 struct DocUrl {
     url: String,
     title: String,
 }
 
 fn doc_url<'a>(
-    home: ModuleId,
+    links: &Links,
+    loaded_module: &'a LoadedModule,
     exposed_values: &[&str],
-    dep_idents: &IdentIdsByModule,
     scope: &Scope,
-    interns: &'a Interns,
     mut module_name: &'a str,
     ident: &str,
 ) -> DocUrl {
+    let home = loaded_module.module_id;
+    let interns = &loaded_module.interns;
+    let module_id;
+
     if module_name.is_empty() {
         // This is an unqualified lookup, so look for the ident
         // in scope!
@@ -771,6 +1244,7 @@ fn doc_url<'a>(
                 // module - for example, if this is in scope from an
                 // unqualified import.
                 module_name = symbol.module_string(interns);
+                module_id = symbol.module_id();
             }
             Err(_) => {
                 // TODO return Err here
@@ -782,7 +1256,9 @@ fn doc_url<'a>(
         }
     } else {
         match interns.module_ids.get_id(&module_name.into()) {
-            Some(module_id) => {
+            Some(id) => {
+                module_id = id;
+
                 // You can do qualified lookups on your own module, e.g.
                 // if I'm in the Foo module, I can do a `Foo.bar` lookup.
                 if module_id == home {
@@ -796,16 +1272,16 @@ fn doc_url<'a>(
                     }
                 } else {
                     // This is not the home module
-                    match dep_idents
+                    match loaded_module
+                        .dep_idents
                         .get(&module_id)
                         .and_then(|exposed_ids| exposed_ids.get_id(ident))
                     {
                         Some(_) => {
                             // This is a valid symbol for this dependency,
                             // so proceed using the current module's name.
-                            //
-                            // TODO: In the future, this is where we'll
-                            // incorporate the package name into the link.
+                            // (If it's from another package, `module_url`
+                            // links to that package's docs.)
                         }
                         _ => {
                             // TODO return Err here
@@ -823,12 +1299,17 @@ fn doc_url<'a>(
         }
     }
 
-    let mut url = base_url();
-
     // Example:
     //
     // module_name: "Str", ident: "join" => "/Str#join"
-    url.push_str(module_name);
+    let package = loaded_module
+        .package_shorthands
+        .get(&module_id)
+        .map(String::as_str);
+    let mut url = links
+        .module_url(package, module_id, module_name)
+        .unwrap_or_else(|| format!("{}{}", links.base_url, module_name));
+
     url.push('#');
     url.push_str(ident);
 
@@ -839,6 +1320,7 @@ fn doc_url<'a>(
 }
 
 fn markdown_to_html(
+    links: &Links,
     exposed_values: &[&str],
     scope: &Scope,
     markdown: String,
@@ -869,11 +1351,10 @@ fn markdown_to_html(
                         match iter.next() {
                             Some(symbol_name) if iter.next().is_none() => {
                                 let DocUrl { url, title } = doc_url(
-                                    loaded_module.module_id,
+                                    links,
+                                    loaded_module,
                                     exposed_values,
-                                    &loaded_module.dep_idents,
                                     scope,
-                                    &loaded_module.interns,
                                     module_name,
                                     symbol_name,
                                 );
@@ -891,15 +1372,8 @@ fn markdown_to_html(
                     Ok((_, Ident::Tag(type_name), _)) => {
                         // This looks like a tag name, but it could
                         // be a type alias that's in scope, e.g. [I64]
-                        let DocUrl { url, title } = doc_url(
-                            loaded_module.module_id,
-                            exposed_values,
-                            &loaded_module.dep_idents,
-                            scope,
-                            &loaded_module.interns,
-                            "",
-                            type_name,
-                        );
+                        let DocUrl { url, title } =
+                            doc_url(links, loaded_module, exposed_values, scope, "", type_name);
 
                         Some((url.into(), title.into()))
                     }
@@ -1,7 +1,71 @@
+// Turn a type signature into a list of tokens, with its type variables renamed in order of
+// appearance, so that `List x, (x -> y) -> List y` matches `List a, (a -> b) -> List b`.
+function normalizeSignature(signature) {
+  let tokens = signature.match(/[A-Za-z_][A-Za-z0-9_.]*|->|[^\sA-Za-z0-9_]/g) || [];
+  let typeVars = new Map();
+
+  return tokens.map((token, index) => {
+    let isLowercase = /^[a-z_]/.test(token);
+    let isFieldName = tokens[index + 1] === ":" || tokens[index + 1] === "?";
+
+    if (!isLowercase || isFieldName) {
+      return token;
+    }
+
+    if (!typeVars.has(token)) {
+      typeVars.set(token, `'${typeVars.size}`);
+    }
+
+    return typeVars.get(token);
+  });
+}
+
+// A query is a type signature search if it has an arrow, or starts with a colon, e.g. `: Str`
+function isSignatureQuery(text) {
+  return text.includes("->") || text.trimStart().startsWith(":");
+}
+
+// The URLs of the entries in the search index whose type signature matches the query
+function signatureMatches(searchIndex, text) {
+  let query = normalizeSignature(text.trimStart().replace(/^:/, ""));
+  let hasTypeVars = query.some((token) => token.startsWith("'"));
+  let isFunction = query.includes("->");
+  let queryStr = query.join(" ");
+  let urls = new Set();
+
+  searchIndex.forEach((entry) => {
+    let signatureStr = entry.normalizedSignature.join(" ");
+
+    // Type variables only line up when comparing whole signatures, but a function query
+    // without any (e.g. `Str -> U64`) can also match part of a longer signature.
+    if (signatureStr === queryStr || (isFunction && !hasTypeVars && (" " + signatureStr + " ").includes(" " + queryStr + " "))) {
+      urls.add(entry.url);
+    }
+  });
+
+  return urls;
+}
+
+if (typeof module !== "undefined") {
+  // Running in Node.js, e.g. for search.test.js
+  module.exports = { normalizeSignature, isSignatureQuery, signatureMatches };
+}
+
 (() => {
+  // There's no page to search when running in Node.js
+  if (typeof document === "undefined") {
+    return;
+  }
+
   let sidebar = document.getElementById("sidebar-nav");
   let searchBox = document.getElementById("module-search");
 
+  // Every exposed value and type, with its type signature (see search-index.js)
+  let searchIndex = (window.rocSearchIndex || []).map((entry) => ({
+    ...entry,
+    normalizedSignature: normalizeSignature(entry.signature),
+  }));
+
   function search() {
     let text = searchBox.value.toLowerCase(); // Search is case-insensitive.
 
@@ -20,6 +84,26 @@
         };
         entry.querySelectorAll(".sidebar-sub-entries a").forEach((subEntry) => subEntry.classList.add("hidden"));
       })
+    } else if (isSignatureQuery(text)) {
+      // Type signatures are case-sensitive, since that's what tells type variables apart
+      let urls = signatureMatches(searchIndex, searchBox.value);
+
+      sidebar.querySelectorAll(".sidebar-sub-entries a").forEach((entry) => {
+        if (urls.has(entry.getAttribute("href"))) {
+          entry.classList.remove("hidden");
+        } else {
+          entry.classList.add("hidden");
+        }
+      });
+
+      // Only show the modules that have matches
+      sidebar.querySelectorAll(".sidebar-module-link").forEach((entry) => {
+        if (entry.parentNode.querySelectorAll(".sidebar-sub-entries a:not(.hidden)").length > 0) {
+          entry.classList.remove("hidden");
+        } else {
+          entry.classList.add("hidden");
+        }
+      });
     } else {
       // First, show/hide all the sub-entries within each module (top-level functions etc.)
       sidebar.querySelectorAll(".sidebar-sub-entries a").forEach((entry) => {
@@ -0,0 +1,54 @@
+/**
+ * Node.js tests for the type signature search in search.js
+ * We are not running this in CI currently, and Node.js is not a Roc dependency.
+ * But if you happen to have it, you can run this with `node search.test.js`.
+ */
+
+const assert = require("assert/strict");
+const { normalizeSignature, isSignatureQuery, signatureMatches } = require("./search");
+
+// Type variables are renamed in order of appearance
+assert.deepEqual(normalizeSignature("List a, (a -> b) -> List b"), [
+  "List", "'0", ",", "(", "'0", "->", "'1", ")", "->", "List", "'1",
+]);
+assert.deepEqual(
+  normalizeSignature("List x, (x -> y) -> List y"),
+  normalizeSignature("List a, (a -> b) -> List b")
+);
+assert.notDeepEqual(
+  normalizeSignature("List a, (a -> b) -> List b"),
+  normalizeSignature("List a, (b -> a) -> List b")
+);
+
+// Qualified type names and record field names aren't type variables
+assert.deepEqual(normalizeSignature("Dict.Dict k v -> { name : Str, age ? U8 }"), [
+  "Dict.Dict", "'0", "'1", "->", "{", "name", ":", "Str", ",", "age", "?", "U8", "}",
+]);
+
+// Only queries with an arrow or a leading colon search by signature
+assert.ok(isSignatureQuery("Str -> U64"));
+assert.ok(isSignatureQuery("  : Str"));
+assert.ok(!isSignatureQuery("split"));
+assert.ok(!isSignatureQuery("List.map"));
+
+const searchIndex = [
+  { signature: "List a, (a -> b) -> List b", url: "/List#map" },
+  { signature: "Str, Str -> List Str", url: "/Str#split" },
+  { signature: "Str -> U64", url: "/Str#countUtf8Bytes" },
+  { signature: "Str", url: "/Str#empty" },
+].map((entry) => ({ ...entry, normalizedSignature: normalizeSignature(entry.signature) }));
+
+const matches = (query) => [...signatureMatches(searchIndex, query)].sort();
+
+// Whole signatures match regardless of what their type variables are called
+assert.deepEqual(matches("List x, (x -> y) -> List y"), ["/List#map"]);
+assert.deepEqual(matches("List x, (y -> x) -> List y"), []);
+
+// A query without type variables also matches part of a longer signature
+assert.deepEqual(matches("Str -> List Str"), ["/Str#split"]);
+assert.deepEqual(matches("Str -> U64"), ["/Str#countUtf8Bytes"]);
+
+// A leading colon searches by the type of a value
+assert.deepEqual(matches(": Str"), ["/Str#empty"]);
+
+console.log("OK");
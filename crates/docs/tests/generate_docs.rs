@@ -0,0 +1,338 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate indoc;
+
+#[cfg(test)]
+mod generate_docs {
+    use roc_docs::generate_docs_html;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use tempfile::TempDir;
+
+    /// Write the given modules to a temporary directory, and generate docs for the ones in
+    /// `documented` into the `docs` directory next to them.
+    fn generate_docs(
+        modules: &[(&str, &str)],
+        documented: &[&str],
+        base_url: &str,
+        package_urls: &[(&str, &str)],
+    ) -> TempDir {
+        let dir = tempfile::tempdir().unwrap();
+
+        for (filename, source) in modules {
+            let path = dir.path().join(filename);
+
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, source).unwrap();
+        }
+
+        let filenames: Vec<PathBuf> = documented
+            .iter()
+            .map(|filename| dir.path().join(filename))
+            .collect();
+
+        generate_docs_html(
+            filenames,
+            &dir.path().join("docs"),
+            base_url,
+            "https://www.roc-lang.org/builtins",
+            package_urls,
+        );
+
+        dir
+    }
+
+    fn read_docs(dir: &Path, path: &str) -> String {
+        fs::read_to_string(dir.join("docs").join(path)).unwrap()
+    }
+
+    fn assert_contains(html: &str, expected: &str) {
+        assert!(
+            html.contains(expected),
+            "Expected to find `{}` in:\n\n{}",
+            expected,
+            html
+        );
+    }
+
+    const GREETING_PACKAGE: &str = indoc!(
+        r#"
+        package "test/greeting"
+            exposes [Greeting]
+            packages { colors: "colors/main.roc" }
+        "#
+    );
+
+    const GREETING: &str = indoc!(
+        r#"
+        interface Greeting
+            exposes [greet]
+            imports [colors.Color.{ Color }]
+
+        ## Says hello in a color, e.g. [Color.red], using [Str.concat]
+        greet : Color -> Str
+        greet = \color -> Str.concat "Hello in " (Color.toStr color)
+        "#
+    );
+
+    const COLORS_PACKAGE: &str = indoc!(
+        r#"
+        package "test/colors"
+            exposes [Color]
+            packages {}
+        "#
+    );
+
+    const COLOR: &str = indoc!(
+        r#"
+        interface Color
+            exposes [Color, red, toStr]
+            imports []
+
+        Color : [Red, Green]
+
+        red : Color
+        red = Red
+
+        ## The name of a color, e.g. [red]
+        toStr : Color -> Str
+        toStr = \color ->
+            when color is
+                Red -> "red"
+                Green -> "green"
+        "#
+    );
+
+    const GREETING_MODULES: &[(&str, &str)] = &[
+        ("main.roc", GREETING_PACKAGE),
+        ("Greeting.roc", GREETING),
+        ("colors/main.roc", COLORS_PACKAGE),
+        ("colors/Color.roc", COLOR),
+    ];
+
+    #[test]
+    fn links_to_other_packages() {
+        let dir = generate_docs(
+            GREETING_MODULES,
+            &["main.roc"],
+            "/packages/greeting",
+            &[("colors", "/packages/colors")],
+        );
+        let html = read_docs(dir.path(), "Greeting/index.html");
+
+        // in the type signature
+        assert_contains(
+            &html,
+            r#"<a href="/packages/colors/Color#Color" >Color</a> -> <a href="https://www.roc-lang.org/builtins/Str#Str" >Str</a>"#,
+        );
+
+        // in the doc comment
+        assert_contains(&html, r#"<a href="/packages/colors/Color#red""#);
+        assert_contains(
+            &html,
+            r#"<a href="https://www.roc-lang.org/builtins/Str#concat""#,
+        );
+    }
+
+    #[test]
+    fn no_links_to_packages_without_a_url() {
+        let dir = generate_docs(
+            GREETING_MODULES,
+            &["main.roc"],
+            "/packages/greeting",
+            &[("json", "/packages/json")],
+        );
+        let html = read_docs(dir.path(), "Greeting/index.html");
+
+        assert_contains(
+            &html,
+            r#"Color -> <a href="https://www.roc-lang.org/builtins/Str#Str" >Str</a>"#,
+        );
+        assert!(!html.contains("/packages/json"));
+    }
+
+    const PACKAGE: &str = indoc!(
+        r#"
+        package "test/pkg"
+            exposes [Color, Palette]
+            packages {}
+        "#
+    );
+
+    const PACKAGE_PALETTE: &str = indoc!(
+        r#"
+        interface Palette
+            exposes [colors, names]
+            imports [Color.{ Color }]
+
+        colors : List Color
+        colors = [Color.red]
+
+        names : List Color -> List Str
+        names = \list -> List.map list Color.toStr
+        "#
+    );
+
+    #[test]
+    fn links_to_modules_of_the_same_package() {
+        let dir = generate_docs(
+            &[
+                ("main.roc", PACKAGE),
+                ("Color.roc", COLOR),
+                ("Palette.roc", PACKAGE_PALETTE),
+            ],
+            &["main.roc"],
+            "packages/pkg",
+            &[],
+        );
+        let html = read_docs(dir.path(), "Palette/index.html");
+
+        assert_contains(
+            &html,
+            r#"<a href="https://www.roc-lang.org/builtins/List#List" >List</a> <a href="/packages/pkg/Color#Color" >Color</a>"#,
+        );
+
+        let html = read_docs(dir.path(), "Color/index.html");
+
+        assert_contains(&html, r#"<a href="/packages/pkg/Color#red""#);
+    }
+
+    #[test]
+    fn search_index() {
+        let dir = generate_docs(
+            &[
+                ("main.roc", PACKAGE),
+                ("Color.roc", COLOR),
+                ("Palette.roc", PACKAGE_PALETTE),
+            ],
+            &["main.roc"],
+            "packages/pkg",
+            &[],
+        );
+
+        assert_eq!(
+            read_docs(dir.path(), "search-index.js"),
+            indoc!(
+                r#"
+                window.rocSearchIndex = [
+                  { module: "Color", name: "red", signature: "Color", url: "/packages/pkg/Color#red" },
+                  { module: "Color", name: "toStr", signature: "Color -> Str", url: "/packages/pkg/Color#toStr" },
+                  { module: "Palette", name: "colors", signature: "List Color", url: "/packages/pkg/Palette#colors" },
+                  { module: "Palette", name: "names", signature: "List Color -> List Str", url: "/packages/pkg/Palette#names" },
+                ];
+                "#
+            )
+        );
+    }
+
+    fn docs_exist(dir: &Path, path: &str) -> bool {
+        dir.join("docs").join(path).exists()
+    }
+
+    #[test]
+    fn package_info_from_package_header() {
+        let dir = generate_docs(
+            &[
+                ("main.roc", PACKAGE),
+                ("README.md", "# Colors\n\nColors and palettes."),
+                ("Color.roc", COLOR),
+                ("Palette.roc", PACKAGE_PALETTE),
+                ("Unexposed.roc", GREETING),
+            ],
+            &["main.roc"],
+            "/packages/pkg",
+            &[],
+        );
+        let html = read_docs(dir.path(), "index.html");
+
+        assert_contains(
+            &html,
+            r#"<h1 class="pkg-full-name" ><a href="/packages/pkg/" >test/pkg</a></h1>"#,
+        );
+        // Package headers have no version, so we don't show one
+        assert!(!html.contains(r#"class="version""#));
+        // The README is the landing page
+        assert_contains(&html, "<p>Colors and palettes.</p>");
+
+        // Only the exposed modules get documented
+        assert!(docs_exist(dir.path(), "Color/index.html"));
+        assert!(docs_exist(dir.path(), "Palette/index.html"));
+        assert!(!docs_exist(dir.path(), "Unexposed/index.html"));
+    }
+
+    #[test]
+    fn package_info_from_platform_header() {
+        let platform = indoc!(
+            r#"
+            platform "test/platform"
+                requires {} { main : Str }
+                exposes [Color]
+                packages {}
+                imports []
+                provides [mainForHost]
+
+            mainForHost : Str
+            mainForHost = main
+            "#
+        );
+        let dir = generate_docs(
+            &[("main.roc", platform), ("Color.roc", COLOR)],
+            &["main.roc"],
+            "/",
+            &[],
+        );
+        let html = read_docs(dir.path(), "index.html");
+
+        assert_contains(
+            &html,
+            r#"<h1 class="pkg-full-name" ><a href="/" >test/platform</a></h1>"#,
+        );
+        // Without a README, the landing page lists the modules
+        assert_contains(&html, r#"<a href="/Color" >Color</a>"#);
+        assert!(docs_exist(dir.path(), "Color/index.html"));
+    }
+
+    #[test]
+    fn standalone_modules_without_a_package() {
+        let dir = generate_docs(
+            &[("Color.roc", COLOR), ("Palette.roc", PACKAGE_PALETTE)],
+            &["Color.roc"],
+            "/",
+            &[],
+        );
+        let html = read_docs(dir.path(), "index.html");
+
+        assert_contains(
+            &html,
+            r#"<h1 class="pkg-full-name" ><a href="/" >documentation</a></h1>"#,
+        );
+        assert!(docs_exist(dir.path(), "Color/index.html"));
+        assert!(!docs_exist(dir.path(), "Palette/index.html"));
+    }
+
+    #[test]
+    fn base_url_gets_leading_and_trailing_slashes() {
+        for base_url in ["packages/pkg", "/packages/pkg", "packages/pkg/"] {
+            let dir = generate_docs(&[("Color.roc", COLOR)], &["Color.roc"], base_url, &[]);
+            let html = read_docs(dir.path(), "Color/index.html");
+
+            assert_contains(&html, r#"<a href="/packages/pkg/Color#red""#);
+            assert_contains(&html, r#"src="/packages/pkg/search.js""#);
+        }
+    }
+
+    #[test]
+    fn base_url_of_another_site_only_gets_a_trailing_slash() {
+        let dir = generate_docs(
+            &[("Color.roc", COLOR)],
+            &["Color.roc"],
+            "https://example.com/pkg",
+            &[],
+        );
+        let html = read_docs(dir.path(), "Color/index.html");
+
+        assert_contains(&html, r#"<a href="https://example.com/pkg/Color#red""#);
+        assert_contains(&html, r#"src="https://example.com/pkg/search.js""#);
+    }
+}
@@ -5,6 +5,10 @@ use std::io;
 use std::path::{Path, PathBuf};
 
 pub const DIRECTORY_OR_FILES: &str = "DIRECTORY_OR_FILES";
+pub const FLAG_OUTPUT: &str = "output";
+pub const FLAG_BASE_URL: &str = "base-url";
+pub const FLAG_BUILTINS_URL: &str = "builtins-url";
+pub const FLAG_PACKAGE_URL: &str = "package-url";
 
 fn main() -> io::Result<()> {
     let matches = Command::new("roc-docs")
@@ -16,6 +20,38 @@ fn main() -> io::Result<()> {
                 .help("The directory or files to build documentation for")
                 .allow_invalid_utf8(true),
         )
+        .arg(
+            Arg::new(FLAG_OUTPUT)
+                .long(FLAG_OUTPUT)
+                .help("The directory to write the generated docs to")
+                .allow_invalid_utf8(true)
+                .default_value("generated-docs"),
+        )
+        .arg(
+            Arg::new(FLAG_BASE_URL)
+                .long(FLAG_BASE_URL)
+                .help("The URL path the docs will be served from, e.g. /builtins")
+                .takes_value(true)
+                .default_value("/"),
+        )
+        .arg(
+            Arg::new(FLAG_BUILTINS_URL)
+                .long(FLAG_BUILTINS_URL)
+                .help("Where the docs for the builtins are served from, e.g. /builtins")
+                .takes_value(true)
+                .default_value("https://www.roc-lang.org/builtins"),
+        )
+        .arg(
+            Arg::new(FLAG_PACKAGE_URL)
+                .long(FLAG_PACKAGE_URL)
+                .help("Where the docs for the package imported with the given shorthand are served from, e.g. json=/packages/json")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .validator(|value| match value.split_once('=') {
+                    Some(_) => Ok(()),
+                    None => Err("expected a package shorthand and a URL, e.g. json=/packages/json"),
+                }),
+        )
         .get_matches();
 
     let mut roc_files = Vec::new();
@@ -26,7 +62,17 @@ fn main() -> io::Result<()> {
         roc_files_recursive(os_str, metadata.file_type(), &mut roc_files)?;
     }
 
-    generate_docs_html(roc_files);
+    let output_dir = Path::new(matches.value_of_os(FLAG_OUTPUT).unwrap());
+    let base_url = matches.value_of(FLAG_BASE_URL).unwrap();
+    let builtins_url = matches.value_of(FLAG_BUILTINS_URL).unwrap();
+    let package_urls: Vec<(&str, &str)> = matches
+        .values_of(FLAG_PACKAGE_URL)
+        .into_iter()
+        .flatten()
+        .filter_map(|value| value.split_once('='))
+        .collect();
+
+    generate_docs_html(roc_files, output_dir, base_url, builtins_url, &package_urls);
 
     Ok(())
 }
@@ -71,8 +71,10 @@ RocType : [
     RecursivePointer TypeId,
     Function {
         name: Str,
+        externName: Str,
         args: List TypeId,
         ret: TypeId,
+        captures: TypeId,
     },
     # A zero-sized type, such as an empty record or a single-tag union with no payload
     Unit,
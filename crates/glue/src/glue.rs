@@ -616,7 +616,9 @@ impl core::fmt::Debug for RocNum {
 #[repr(C)]
 pub struct R1 {
     pub args: roc_std::RocList<u32>,
+    pub externName: roc_std::RocStr,
     pub name: roc_std::RocStr,
+    pub captures: u32,
     pub ret: u32,
 }
 
@@ -843,7 +845,9 @@ struct RocType_RocDict {
 #[repr(C)]
 pub struct R1 {
     pub args: roc_std::RocList<u64>,
+    pub externName: roc_std::RocStr,
     pub name: roc_std::RocStr,
+    pub captures: u64,
     pub ret: u64,
 }
 
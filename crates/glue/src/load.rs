@@ -120,7 +120,9 @@ pub fn load_types(
         use roc_can::expr::DeclarationTag::*;
 
         match decls.declarations[index] {
-            Value | Function(_) | Recursive(_) | TailRecursive(_) => Some(decls.variables[index]),
+            Value | Function(_) | Recursive(_) | TailRecursive(_) => {
+                Some((decls.symbols[index].value, decls.variables[index]))
+            }
             Destructure(_) => {
                 // figure out if we need to export non-identifier defs - when would that
                 // happen?
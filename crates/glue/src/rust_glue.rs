@@ -143,7 +143,7 @@ fn add_type(target_info: TargetInfo, id: TypeId, types: &Types, impls: &mut Impl
         RocType::Struct { name, fields } => {
             add_struct(name, target_info, fields, id, types, impls, false)
         }
-        RocType::TagUnionPayload { name, fields } => {
+        RocType::TagUnionPayload { name, fields } | RocType::Tuple { name, fields } => {
             add_struct(name, target_info, fields, id, types, impls, true)
         }
         RocType::TagUnion(tag_union) => {
@@ -294,12 +294,158 @@ fn add_type(target_info: TargetInfo, id: TypeId, types: &Types, impls: &mut Impl
             // This is recursively pointing to a type that should already have been added,
             // so no extra work needs to happen.
         }
-        RocType::Function { .. } => {
-            // TODO actually generate glue functions!
+        RocType::Function {
+            name,
+            extern_name: Some(extern_name),
+            args,
+            ret,
+            captures,
+        } => add_function(
+            name,
+            extern_name,
+            args,
+            *ret,
+            *captures,
+            target_info,
+            types,
+            impls,
+        ),
+        RocType::Function {
+            extern_name: None, ..
+        } => {
+            // Roc doesn't generate a caller for functions that weren't given a name
+            // with `as`, so there is no way for the host to call them.
         }
     }
 }
 
+/// Declares a wrapper type for a Roc function (e.g. a closure returned to the host),
+/// which owns the function's captured environment and calls it through the `_caller`
+/// symbol Roc generates for it. The captured environment is laid out the way Roc lays out
+/// the function's lambda set, so dropping the wrapper decrements the refcounts of its captures.
+#[allow(clippy::too_many_arguments)]
+fn add_function(
+    name: &str,
+    extern_name: &str,
+    args: &[TypeId],
+    ret: TypeId,
+    captures: TypeId,
+    target_info: TargetInfo,
+    types: &Types,
+    impls: &mut Impls,
+) {
+    let name = escape_kw(name.to_string());
+    let ret_type = type_name(ret, types);
+    let captures_type = type_name(captures, types);
+
+    add_decl(
+        impls,
+        None,
+        target_info,
+        format!(
+            r#"#[derive(Clone)]
+#[repr(transparent)]
+pub struct {name} {{
+    captures: {captures_type},
+}}"#
+        ),
+    );
+
+    let opt_impl = Some(format!("impl {name}"));
+
+    add_decl(
+        impls,
+        opt_impl.clone(),
+        target_info,
+        format!(
+            r#"/// Takes ownership of a captured environment that Roc wrote to the given address,
+    /// e.g. as part of the return value of the Roc function that returned this function.
+    ///
+    /// # Safety
+    ///
+    /// `closure_data` must point to a captured environment that Roc produced
+    /// for this function, which must not be used again afterwards.
+    pub unsafe fn from_closure_data(closure_data: *const u8) -> Self {{
+        extern "C" {{
+            #[link_name = "{extern_name}_size"]
+            fn size() -> i64;
+        }}
+
+        debug_assert_eq!(size() as usize, core::mem::size_of::<{captures_type}>());
+
+        Self {{
+            captures: core::ptr::read(closure_data as *const {captures_type}),
+        }}
+    }}"#
+        ),
+    );
+
+    {
+        let mut params = Vec::with_capacity(args.len());
+        let mut extern_params = Vec::with_capacity(args.len());
+        let mut arg_ptrs = Vec::with_capacity(args.len());
+        let mut forget_args = String::new();
+
+        for (index, arg_id) in args.iter().enumerate() {
+            let arg_type = type_name(*arg_id, types);
+
+            params.push(format!("arg{index}: {arg_type}"));
+            extern_params.push(format!("arg{index}: *const {arg_type}"));
+            arg_ptrs.push(format!("&arg{index}"));
+
+            // Roc takes ownership of the arguments, so their refcounts must not be decremented here.
+            write!(
+                forget_args,
+                "\n{INDENT}{INDENT}core::mem::forget(arg{index});"
+            )
+            .unwrap();
+        }
+
+        let params = params.join(", ");
+        let extern_params = extern_params.join(", ");
+        let arg_ptrs = arg_ptrs.join(", ");
+
+        add_decl(
+            impls,
+            opt_impl,
+            target_info,
+            format!(
+                r#"/// Calls the function, passing the captured environment along with the arguments.
+    pub fn call(&self, {params}) -> {ret_type} {{
+        extern "C" {{
+            #[link_name = "{extern_name}_caller"]
+            fn caller({extern_params}, captures: *const {captures_type}, output: *mut {ret_type});
+        }}
+
+        // Roc takes ownership of the captured environment too, so it gets its own copy;
+        // the one in `self` keeps its refcounts until `self` is dropped.
+        let captures = self.captures.clone();
+        let mut output = core::mem::MaybeUninit::uninit();
+
+        unsafe {{
+            caller({arg_ptrs}, &captures, output.as_mut_ptr());
+        }}
+
+        core::mem::forget(captures);{forget_args}
+
+        unsafe {{ output.assume_init() }}
+    }}"#
+            ),
+        );
+    }
+
+    add_decl(
+        impls,
+        Some(format!("impl core::fmt::Debug for {name}")),
+        target_info,
+        format!(
+            r#"fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
+        f.debug_struct("{name}").finish_non_exhaustive()
+    }}"#
+        ),
+    );
+}
+
 fn add_single_tag_struct(
     name: &str,
     tag_name: &str,
@@ -942,7 +1088,8 @@ pub struct {name} {{
                     | RocType::RocBox(_)
                     | RocType::TagUnion(_)
                     | RocType::RocResult(_, _)
-                    | RocType::RecursivePointer { .. } => {
+                    | RocType::RecursivePointer { .. }
+                    | RocType::Function { .. } => {
                         owned_ret_type = type_name(*payload_id, types);
                         borrowed_ret_type = format!("&{}", owned_ret_type);
                         owned_ret = "payload".to_string();
@@ -965,7 +1112,7 @@ pub struct {name} {{
                         payload_args = answer.payload_args;
                         args_to_payload = answer.args_to_payload;
                     }
-                    RocType::TagUnionPayload { fields, name } => {
+                    RocType::TagUnionPayload { fields, name } | RocType::Tuple { fields, name } => {
                         let answer =
                             tag_union_struct_help(name, fields.iter(), *payload_id, types, true);
 
@@ -976,7 +1123,6 @@ pub struct {name} {{
                         payload_args = answer.payload_args;
                         args_to_payload = answer.args_to_payload;
                     }
-                    RocType::Function { .. } => todo!(),
                 };
 
                 {
@@ -1565,10 +1711,12 @@ pub struct {name} {{
                             | RocType::TagUnion(_)
                             | RocType::RocResult(_, _)
                             | RocType::Struct { .. }
-                            | RocType::RecursivePointer { .. } => {
+                            | RocType::RecursivePointer { .. }
+                            | RocType::Function { .. } => {
                                 format!(".field({deref_str}{actual_self}.{tag_name})")
                             }
-                            RocType::TagUnionPayload { fields, .. } => {
+                            RocType::TagUnionPayload { fields, .. }
+                            | RocType::Tuple { fields, .. } => {
                                 let mut buf = Vec::new();
 
                                 for (label, _) in fields {
@@ -1580,7 +1728,6 @@ pub struct {name} {{
 
                                 buf.join("\n")
                             }
-                            RocType::Function { .. } => todo!(),
                         };
 
                         format!(
@@ -1680,11 +1827,15 @@ fn add_struct<S: Display>(
     struct_id: TypeId,
     types: &Types,
     impls: &mut Impls,
-    is_tag_union_payload: bool,
+    numbered_fields: bool,
 ) {
     let name = escape_kw(name.to_string());
     let derive = derive_str(types.get_type(struct_id), types, true);
-    let pub_str = if is_tag_union_payload { "" } else { "pub " };
+    let pub_str = if matches!(types.get_type(struct_id), RocType::TagUnionPayload { .. }) {
+        ""
+    } else {
+        "pub "
+    };
     let repr = if fields.len() == 1 {
         "transparent"
     } else {
@@ -1697,7 +1848,7 @@ fn add_struct<S: Display>(
 
         // Tag union payloads have numbered fields, so we prefix them
         // with an "f" because Rust doesn't allow struct fields to be numbers.
-        let label = if is_tag_union_payload {
+        let label = if numbered_fields {
             format!("f{label}")
         } else {
             escape_kw(label.to_string())
@@ -1748,6 +1899,7 @@ fn type_name(id: TypeId, types: &Types) -> String {
         }
         RocType::Struct { name, .. }
         | RocType::TagUnionPayload { name, .. }
+        | RocType::Tuple { name, .. }
         | RocType::TagUnion(RocTagUnion::NonRecursive { name, .. })
         | RocType::TagUnion(RocTagUnion::Recursive { name, .. })
         | RocType::TagUnion(RocTagUnion::Enumeration { name, .. })
@@ -1874,7 +2026,8 @@ pub struct {name} {{
             | RocType::RocBox(_)
             | RocType::RocResult(_, _)
             | RocType::TagUnion(_)
-            | RocType::RecursivePointer { .. } => {
+            | RocType::RecursivePointer { .. }
+            | RocType::Function { .. } => {
                 owned_ret_type = type_name(non_null_payload, types);
                 borrowed_ret_type = format!("&{}", owned_ret_type);
                 payload_args = format!("arg: {owned_ret_type}");
@@ -1893,7 +2046,7 @@ pub struct {name} {{
                 owned_ret_type = answer.owned_ret_type;
                 borrowed_ret_type = answer.borrowed_ret_type;
             }
-            RocType::TagUnionPayload { fields, name } => {
+            RocType::TagUnionPayload { fields, name } | RocType::Tuple { fields, name } => {
                 let answer =
                     tag_union_struct_help(name, fields.iter(), non_null_payload, types, true);
 
@@ -1904,7 +2057,6 @@ pub struct {name} {{
                 owned_ret_type = answer.owned_ret_type;
                 borrowed_ret_type = answer.borrowed_ret_type;
             }
-            RocType::Function { .. } => todo!(),
         };
 
         // Add a convenience constructor function for the tag with the payload, e.g.
@@ -2123,7 +2275,8 @@ pub struct {name} {{
             | RocType::RocBox(_)
             | RocType::RocResult(_, _)
             | RocType::TagUnion(_)
-            | RocType::RecursivePointer { .. } => {
+            | RocType::RecursivePointer { .. }
+            | RocType::Function { .. } => {
                 format!(
                     r#"f.debug_tuple("{non_null_tag}").field(&*{extra_deref}self.pointer).finish()"#
                 )
@@ -2137,7 +2290,7 @@ pub struct {name} {{
 
                 buf.join(&format!("\n{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}"))
             }
-            RocType::TagUnionPayload { fields, .. } => {
+            RocType::TagUnionPayload { fields, .. } | RocType::Tuple { fields, .. } => {
                 let mut buf = Vec::new();
 
                 for (label, _) in fields {
@@ -2147,7 +2300,6 @@ pub struct {name} {{
 
                 buf.join(&format!("\n{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}"))
             }
-            RocType::Function { .. } => todo!(),
         };
 
         let body = format!(
@@ -2366,7 +2518,7 @@ fn cannot_derive_default(roc_type: &RocType, types: &Types) -> bool {
         RocType::Struct { fields, .. } => fields
             .iter()
             .any(|(_, type_id)| cannot_derive_default(types.get_type(*type_id), types)),
-        RocType::TagUnionPayload { fields, .. } => fields
+        RocType::TagUnionPayload { fields, .. } | RocType::Tuple { fields, .. } => fields
             .iter()
             .any(|(_, type_id)| cannot_derive_default(types.get_type(*type_id), types)),
     }
@@ -2379,9 +2531,9 @@ fn cannot_derive_copy(roc_type: &RocType, types: &Types) -> bool {
         | RocType::EmptyTagUnion
         | RocType::Bool
         | RocType::Num(_)
-        | RocType::TagUnion(RocTagUnion::Enumeration { .. })
-        | RocType::Function { .. } => false,
+        | RocType::TagUnion(RocTagUnion::Enumeration { .. }) => false,
         RocType::RocStr
+        | RocType::Function { .. }
         | RocType::RocList(_)
         | RocType::RocDict(_, _)
         | RocType::RocSet(_)
@@ -2408,7 +2560,7 @@ fn cannot_derive_copy(roc_type: &RocType, types: &Types) -> bool {
         RocType::Struct { fields, .. } => fields
             .iter()
             .any(|(_, type_id)| cannot_derive_copy(types.get_type(*type_id), types)),
-        RocType::TagUnionPayload { fields, .. } => fields
+        RocType::TagUnionPayload { fields, .. } | RocType::Tuple { fields, .. } => fields
             .iter()
             .any(|(_, type_id)| cannot_derive_copy(types.get_type(*type_id), types)),
     }
@@ -2449,7 +2601,7 @@ fn has_float_help(roc_type: &RocType, types: &Types, do_not_recurse: &[TypeId])
         RocType::Struct { fields, .. } => fields
             .iter()
             .any(|(_, type_id)| has_float_help(types.get_type(*type_id), types, do_not_recurse)),
-        RocType::TagUnionPayload { fields, .. } => fields
+        RocType::TagUnionPayload { fields, .. } | RocType::Tuple { fields, .. } => fields
             .iter()
             .any(|(_, type_id)| has_float_help(types.get_type(*type_id), types, do_not_recurse)),
         RocType::TagUnion(RocTagUnion::SingleTagStruct { payload_fields, .. }) => payload_fields
@@ -8,16 +8,17 @@ use roc_builtins::bitcode::{
 };
 use roc_collections::VecMap;
 use roc_module::{
-    ident::TagName,
+    ident::{tuple_arity, TagName},
     symbol::{Interns, Symbol},
 };
+use roc_mono::ir::ProcLayout;
 use roc_mono::layout::{
-    cmp_fields, ext_var_is_empty_tag_union, round_up_to_alignment, Builtin, Discriminant, Layout,
-    LayoutCache, LayoutInterner, UnionLayout,
+    cmp_fields, ext_var_is_empty_tag_union, round_up_to_alignment, Builtin, CapturesNiche,
+    Discriminant, Layout, LayoutCache, LayoutIds, LayoutInterner, UnionLayout,
 };
 use roc_target::TargetInfo;
 use roc_types::{
-    subs::{Content, FlatType, GetSubsSlice, Subs, UnionLabels, UnionTags, Variable},
+    subs::{self, Content, FlatType, GetSubsSlice, Subs, UnionLabels, UnionTags, Variable},
     types::{AliasKind, RecordField},
 };
 use std::fmt::Display;
@@ -296,16 +297,42 @@ impl Types {
                     false
                 }
             }
+            (
+                Tuple {
+                    fields: fields_a, ..
+                },
+                Tuple {
+                    fields: fields_b, ..
+                },
+            ) => {
+                if fields_a.len() == fields_b.len() {
+                    fields_a.iter().zip(fields_b.iter()).all(
+                        |((index_a, id_a), (index_b, id_b))| {
+                            index_a == index_b
+                                && self.is_equivalent_help(
+                                    self.get_type_or_pending(*id_a),
+                                    self.get_type_or_pending(*id_b),
+                                )
+                        },
+                    )
+                } else {
+                    false
+                }
+            }
             (
                 Function {
                     name: name_a,
                     args: args_a,
                     ret: ret_a,
+                    captures: captures_a,
+                    ..
                 },
                 Function {
                     name: name_b,
                     args: args_b,
                     ret: ret_b,
+                    captures: captures_b,
+                    ..
                 },
             ) => {
                 // for functions, the name is actually important because two functions
@@ -316,6 +343,10 @@ impl Types {
                         self.get_type_or_pending(*ret_a),
                         self.get_type_or_pending(*ret_b),
                     )
+                    && self.is_equivalent_help(
+                        self.get_type_or_pending(*captures_a),
+                        self.get_type_or_pending(*captures_b),
+                    )
                 {
                     args_a.iter().zip(args_b.iter()).all(|(id_a, id_b)| {
                         self.is_equivalent_help(
@@ -353,6 +384,8 @@ impl Types {
             | (_, Struct { .. })
             | (TagUnionPayload { .. }, _)
             | (_, TagUnionPayload { .. })
+            | (Tuple { .. }, _)
+            | (_, Tuple { .. })
             | (RecursivePointer(_), _)
             | (_, RecursivePointer(_))
             | (Function { .. }, _)
@@ -517,14 +550,27 @@ pub enum RocType {
         name: String,
         fields: Vec<(usize, TypeId)>,
     },
+    /// A tuple, e.g. `(U8, Str)`. Like a tag union payload, its fields are numbered.
+    Tuple {
+        name: String,
+        fields: Vec<(usize, TypeId)>,
+    },
     /// A recursive pointer, e.g. in StrConsList : [Nil, Cons Str StrConsList],
     /// this would be the field of Cons containing the (recursive) StrConsList type,
     /// and the TypeId is the TypeId of StrConsList itself.
     RecursivePointer(TypeId),
+    /// A function the host can call, e.g. a closure returned by the Roc app.
     Function {
         name: String,
+        /// The prefix of the symbols Roc generates for calling this function,
+        /// e.g. `roc__mainForHost_1__Fx` for `roc__mainForHost_1__Fx_caller`,
+        /// `roc__mainForHost_1__Fx_size` and `roc__mainForHost_1__Fx_result_size`.
+        /// Roc only generates these for functions given a name with `as`, e.g. `as Fx`.
+        extern_name: Option<String>,
         args: Vec<TypeId>,
         ret: TypeId,
+        /// The function's captured environment, laid out the way Roc lays out its lambda set
+        captures: TypeId,
     },
     /// A zero-sized type, such as an empty record or a single-tag union with no payload
     Unit,
@@ -668,6 +714,10 @@ pub struct Env<'a> {
     enum_names: Enums,
     pending_recursive_types: VecMap<TypeId, Variable>,
     known_recursive_types: VecMap<Variable, TypeId>,
+    /// The name Roc gives the exposed top-level def whose type is currently being added,
+    /// if any, e.g. `mainForHost_1`.
+    /// Closure callers are named after it, e.g. `roc__mainForHost_1__Fx_caller`.
+    entry_point: Option<String>,
     target: TargetInfo,
 }
 
@@ -687,6 +737,7 @@ impl<'a> Env<'a> {
             enum_names: Default::default(),
             pending_recursive_types: Default::default(),
             known_recursive_types: Default::default(),
+            entry_point: None,
             layout_cache: LayoutCache::new(layout_interner, target),
             target,
         }
@@ -694,14 +745,35 @@ impl<'a> Env<'a> {
 
     pub fn vars_to_types<I>(&mut self, variables: I) -> Types
     where
-        I: Iterator<Item = Variable>,
+        I: Iterator<Item = (Symbol, Variable)>,
     {
         let mut types = Types::with_capacity(variables.size_hint().0, self.target);
+        let mut layout_ids = LayoutIds::default();
 
-        for var in variables {
+        for (symbol, var) in variables {
+            // Roc names the symbols it exposes after the layout of the def,
+            // the same way it does for the def itself (e.g. `roc__mainForHost_1_exposed`).
+            let layout_id = {
+                let raw_layout = self
+                    .layout_cache
+                    .raw_from_var(self.arena, var, self.subs)
+                    .expect("Something weird ended up in the content");
+                let proc_layout = ProcLayout::from_raw(
+                    self.arena,
+                    &self.layout_cache.interner,
+                    raw_layout,
+                    CapturesNiche::no_niche(),
+                );
+
+                layout_ids.get_toplevel(symbol, &proc_layout)
+            };
+
+            self.entry_point = Some(layout_id.to_exposed_alias_def_name(symbol, self.interns));
             self.add_type(var, &mut types);
         }
 
+        self.entry_point = None;
+
         self.resolve_pending_recursive_types(&mut types);
 
         types
@@ -762,6 +834,30 @@ fn add_type_help<'a>(
         | Content::RigidAbleVar(_, _) => {
             todo!("TODO give a nice error message for a non-concrete type being passed to the host")
         }
+        Content::Structure(FlatType::Record(fields, ext))
+            if tuple_arity(fields.iter_all().map(|(label, _, _)| &subs[label])).is_some() =>
+        {
+            let elems = fields
+                .unsorted_iterator(subs, *ext)
+                .expect("something weird in content")
+                .map(|(label, field)| (label.clone(), field.into_inner()));
+
+            let name = match opt_name {
+                Some(sym) => sym.as_str(env.interns).to_string(),
+                None => env.struct_names.get_name(var),
+            };
+
+            // Keep the labels as they are while laying out the struct, so that its fields are
+            // sorted the same way the record's are at runtime.
+            add_struct(env, name, elems, types, layout, |name, fields| {
+                let fields = fields
+                    .into_iter()
+                    .map(|(label, type_id)| (label.as_tuple_elem().unwrap(), type_id))
+                    .collect();
+
+                RocType::Tuple { name, fields }
+            })
+        }
         Content::Structure(FlatType::Record(fields, ext)) => {
             let it = fields
                 .unsorted_iterator(subs, *ext)
@@ -841,19 +937,40 @@ fn add_type_help<'a>(
                 add_type_help(env, ret_layout, *ret_var, None, types)
             };
 
-            let name = format!("TODO_roc_function_{:?}", closure_var);
+            // Functions exposed to the host through an `as` alias (e.g. `as Fx`) are named
+            // after that alias, which is also how Roc names the symbols for calling them.
+            let (name, extern_name) = match (opt_name, &env.entry_point) {
+                (Some(alias), Some(entry_point)) => {
+                    let extern_name = format!(
+                        "roc__{}_{}_{}",
+                        entry_point,
+                        alias.module_string(env.interns),
+                        alias.as_str(env.interns)
+                    );
+
+                    (alias.as_str(env.interns).to_string(), Some(extern_name))
+                }
+                (Some(alias), None) => (alias.as_str(env.interns).to_string(), None),
+                (None, _) => (format!("RocFunction_{}", closure_var.index()), None),
+            };
+
+            let captures_type_id = add_captures(env, &name, *closure_var, layout, types);
+
             let fn_type_id = types.add_named(
                 &env.layout_cache.interner,
                 name.clone(),
                 RocType::Function {
                     name,
+                    extern_name,
                     args: arg_type_ids.clone(),
                     ret: ret_type_id,
+                    captures: captures_type_id,
                 },
                 layout,
             );
 
             types.depends(fn_type_id, ret_type_id);
+            types.depends(fn_type_id, captures_type_id);
 
             for arg_type_id in arg_type_ids {
                 types.depends(fn_type_id, arg_type_id);
@@ -1446,6 +1563,115 @@ fn add_tag_union<'a>(
     type_id
 }
 
+/// Adds the type of a function's captured environment. Roc lays out a lambda set like a
+/// tag union with a tag for each lambda the function could be, whose payload is what
+/// that lambda captures.
+fn add_captures<'a>(
+    env: &mut Env<'a>,
+    fn_name: &str,
+    closure_var: Variable,
+    layout: Layout<'a>,
+    types: &mut Types,
+) -> TypeId {
+    let captures_layout = match layout {
+        Layout::LambdaSet(lambda_set) => {
+            lambda_set.runtime_representation(&env.layout_cache.interner)
+        }
+        _ => unreachable!("The layout of a function is always a lambda set, but got {layout:?}"),
+    };
+
+    let lambdas: Vec<Vec<Variable>> = match env.subs.get_content_without_compacting(closure_var) {
+        Content::LambdaSet(subs::LambdaSet {
+            solved,
+            recursion_var,
+            ..
+        }) => {
+            if recursion_var.is_some() {
+                todo!("Support recursive lambda sets in glue");
+            }
+
+            // These are sorted the same way Roc sorts the tags of the lambda set's layout.
+            solved
+                .iter_from_subs(env.subs)
+                .map(|(_, capture_vars)| capture_vars.to_vec())
+                .collect()
+        }
+        other => {
+            unreachable!("The closure of a function is always a lambda set, but got {other:?}")
+        }
+    };
+
+    let name = format!("{}_Captures", fn_name);
+
+    match lambdas.as_slice() {
+        [capture_vars] => {
+            // With just one lambda, there's no need for a tag; only its captures are stored.
+            match tag_to_type(
+                fn_name,
+                env,
+                "Captures",
+                capture_vars,
+                types,
+                captures_layout,
+                false,
+            ) {
+                (_, Some(payload_id)) => payload_id,
+                (_, None) => {
+                    types.add_anonymous(&env.layout_cache.interner, RocType::Unit, captures_layout)
+                }
+            }
+        }
+        _ => {
+            let tags: Vec<(String, Option<TypeId>)> = lambdas
+                .iter()
+                .enumerate()
+                .map(|(index, capture_vars)| {
+                    tag_to_type(
+                        &name,
+                        env,
+                        format!("Lambda{index}"),
+                        capture_vars,
+                        types,
+                        captures_layout,
+                        false,
+                    )
+                })
+                .collect();
+
+            let tag_union = match captures_layout {
+                Layout::Union(union_layout @ UnionLayout::NonRecursive(_)) => {
+                    RocTagUnion::NonRecursive {
+                        name: name.clone(),
+                        discriminant_size: Discriminant::from_number_of_tags(tags.len())
+                            .stack_size()
+                            .max(1),
+                        discriminant_offset: union_layout
+                            .tag_id_offset(&env.layout_cache.interner, env.target)
+                            .unwrap(),
+                        tags,
+                    }
+                }
+                // None of the lambdas capture anything, so only the tag is stored.
+                Layout::Builtin(Builtin::Bool | Builtin::Int(U8)) => RocTagUnion::Enumeration {
+                    name: name.clone(),
+                    tags: tags.into_iter().map(|(tag_name, _)| tag_name).collect(),
+                    size: U8.stack_size(),
+                },
+                _ => unreachable!(
+                    "A non-recursive lambda set with several lambdas is laid out like a tag union, but got {captures_layout:?}"
+                ),
+            };
+
+            types.add_named(
+                &env.layout_cache.interner,
+                name,
+                RocType::TagUnion(tag_union),
+                captures_layout,
+            )
+        }
+    }
+}
+
 fn add_int_enumeration(
     union_tags: &UnionLabels<TagName>,
     subs: &Subs,
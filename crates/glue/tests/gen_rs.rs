@@ -233,4 +233,233 @@ mod test_gen_rs {
             )
         );
     }
+
+    #[test]
+    fn closure_exposed_with_as() {
+        let module = indoc!(
+            r#"
+            main : I64 -> ({} -> I64) as Fx
+            main = \x -> \{} -> x
+        "#
+        );
+
+        assert_eq!(
+            generate_bindings(module)
+                .strip_prefix('\n')
+                .unwrap_or_default(),
+            indoc!(
+                r#"
+                #[cfg(any(
+                    target_arch = "arm",
+                    target_arch = "aarch64",
+                    target_arch = "wasm32",
+                    target_arch = "x86",
+                    target_arch = "x86_64"
+                ))]
+                #[derive(Clone)]
+                #[repr(transparent)]
+                pub struct Fx {
+                    captures: i64,
+                }
+
+                impl Fx {
+                    #[cfg(any(
+                        target_arch = "arm",
+                        target_arch = "aarch64",
+                        target_arch = "wasm32",
+                        target_arch = "x86",
+                        target_arch = "x86_64"
+                    ))]
+                    /// Takes ownership of a captured environment that Roc wrote to the given address,
+                    /// e.g. as part of the return value of the Roc function that returned this function.
+                    ///
+                    /// # Safety
+                    ///
+                    /// `closure_data` must point to a captured environment that Roc produced
+                    /// for this function, which must not be used again afterwards.
+                    pub unsafe fn from_closure_data(closure_data: *const u8) -> Self {
+                        extern "C" {
+                            #[link_name = "roc__main_1__Fx_size"]
+                            fn size() -> i64;
+                        }
+
+                        debug_assert_eq!(size() as usize, core::mem::size_of::<i64>());
+
+                        Self {
+                            captures: core::ptr::read(closure_data as *const i64),
+                        }
+                    }
+
+                    #[cfg(any(
+                        target_arch = "arm",
+                        target_arch = "aarch64",
+                        target_arch = "wasm32",
+                        target_arch = "x86",
+                        target_arch = "x86_64"
+                    ))]
+                    /// Calls the function, passing the captured environment along with the arguments.
+                    pub fn call(&self, arg0: ()) -> i64 {
+                        extern "C" {
+                            #[link_name = "roc__main_1__Fx_caller"]
+                            fn caller(arg0: *const (), captures: *const i64, output: *mut i64);
+                        }
+
+                        // Roc takes ownership of the captured environment too, so it gets its own copy;
+                        // the one in `self` keeps its refcounts until `self` is dropped.
+                        let captures = self.captures.clone();
+                        let mut output = core::mem::MaybeUninit::uninit();
+
+                        unsafe {
+                            caller(&arg0, &captures, output.as_mut_ptr());
+                        }
+
+                        core::mem::forget(captures);
+                        core::mem::forget(arg0);
+
+                        unsafe { output.assume_init() }
+                    }
+                }
+
+                impl core::fmt::Debug for Fx {
+                    #[cfg(any(
+                        target_arch = "arm",
+                        target_arch = "aarch64",
+                        target_arch = "wasm32",
+                        target_arch = "x86",
+                        target_arch = "x86_64"
+                    ))]
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        f.debug_struct("Fx").finish_non_exhaustive()
+                    }
+                }
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn closure_capturing_str_and_int() {
+        let module = indoc!(
+            r#"
+            main : Str, I64 -> ({} -> Str) as Fx
+            main = \str, n -> \{} -> Str.concat str (Num.toStr n)
+        "#
+        );
+
+        assert_eq!(
+            generate_bindings(module)
+                .strip_prefix('\n')
+                .unwrap_or_default(),
+            indoc!(
+                r#"
+                #[cfg(any(
+                    target_arch = "arm",
+                    target_arch = "wasm32"
+                ))]
+                #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+                #[repr(C)]
+                struct Fx_Captures {
+                    pub f1: i64,
+                    pub f0: roc_std::RocStr,
+                }
+
+                #[cfg(any(
+                    target_arch = "arm",
+                    target_arch = "aarch64",
+                    target_arch = "wasm32",
+                    target_arch = "x86",
+                    target_arch = "x86_64"
+                ))]
+                #[derive(Clone)]
+                #[repr(transparent)]
+                pub struct Fx {
+                    captures: Fx_Captures,
+                }
+
+                #[cfg(any(
+                    target_arch = "aarch64",
+                    target_arch = "x86",
+                    target_arch = "x86_64"
+                ))]
+                #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+                #[repr(C)]
+                struct Fx_Captures {
+                    pub f0: roc_std::RocStr,
+                    pub f1: i64,
+                }
+
+                impl Fx {
+                    #[cfg(any(
+                        target_arch = "arm",
+                        target_arch = "aarch64",
+                        target_arch = "wasm32",
+                        target_arch = "x86",
+                        target_arch = "x86_64"
+                    ))]
+                    /// Takes ownership of a captured environment that Roc wrote to the given address,
+                    /// e.g. as part of the return value of the Roc function that returned this function.
+                    ///
+                    /// # Safety
+                    ///
+                    /// `closure_data` must point to a captured environment that Roc produced
+                    /// for this function, which must not be used again afterwards.
+                    pub unsafe fn from_closure_data(closure_data: *const u8) -> Self {
+                        extern "C" {
+                            #[link_name = "roc__main_1__Fx_size"]
+                            fn size() -> i64;
+                        }
+
+                        debug_assert_eq!(size() as usize, core::mem::size_of::<Fx_Captures>());
+
+                        Self {
+                            captures: core::ptr::read(closure_data as *const Fx_Captures),
+                        }
+                    }
+
+                    #[cfg(any(
+                        target_arch = "arm",
+                        target_arch = "aarch64",
+                        target_arch = "wasm32",
+                        target_arch = "x86",
+                        target_arch = "x86_64"
+                    ))]
+                    /// Calls the function, passing the captured environment along with the arguments.
+                    pub fn call(&self, arg0: ()) -> roc_std::RocStr {
+                        extern "C" {
+                            #[link_name = "roc__main_1__Fx_caller"]
+                            fn caller(arg0: *const (), captures: *const Fx_Captures, output: *mut roc_std::RocStr);
+                        }
+
+                        // Roc takes ownership of the captured environment too, so it gets its own copy;
+                        // the one in `self` keeps its refcounts until `self` is dropped.
+                        let captures = self.captures.clone();
+                        let mut output = core::mem::MaybeUninit::uninit();
+
+                        unsafe {
+                            caller(&arg0, &captures, output.as_mut_ptr());
+                        }
+
+                        core::mem::forget(captures);
+                        core::mem::forget(arg0);
+
+                        unsafe { output.assume_init() }
+                    }
+                }
+
+                impl core::fmt::Debug for Fx {
+                    #[cfg(any(
+                        target_arch = "arm",
+                        target_arch = "aarch64",
+                        target_arch = "wasm32",
+                        target_arch = "x86",
+                        target_arch = "x86_64"
+                    ))]
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        f.debug_struct("Fx").finish_non_exhaustive()
+                    }
+                }
+            "#
+            )
+        );
+    }
 }
@@ -0,0 +1,188 @@
+use bumpalo::Bump;
+use roc_builtins::roc::module_source;
+use roc_module::symbol::ModuleId;
+use roc_parse::ast::{Module, Spaced};
+use roc_parse::header::ExposedName;
+use roc_parse::module::parse_header;
+use roc_parse::state::State;
+use rustyline::completion::{Completer, Pair};
+use rustyline::Context;
+
+const BUILTIN_MODULES: [(&str, ModuleId); 11] = [
+    ("Bool", ModuleId::BOOL),
+    ("Box", ModuleId::BOX),
+    ("Decode", ModuleId::DECODE),
+    ("Dict", ModuleId::DICT),
+    ("Encode", ModuleId::ENCODE),
+    ("Json", ModuleId::JSON),
+    ("List", ModuleId::LIST),
+    ("Num", ModuleId::NUM),
+    ("Result", ModuleId::RESULT),
+    ("Set", ModuleId::SET),
+    ("Str", ModuleId::STR),
+];
+
+/// Tab completion of builtin module names, the values they expose (after `List.` etc.),
+/// and the names defined in the session so far.
+pub struct ReplCompleter {
+    /// Each builtin module's name, along with the names it exposes
+    builtins: Vec<(&'static str, Vec<String>)>,
+    /// The names the session has defined or imported; kept up to date by the REPL loop
+    pub session_idents: Vec<String>,
+}
+
+impl ReplCompleter {
+    pub fn new() -> Self {
+        let builtins = BUILTIN_MODULES
+            .iter()
+            .map(|(name, module_id)| (*name, exposed_names(module_source(*module_id))))
+            .collect();
+
+        Self {
+            builtins,
+            session_idents: Vec::new(),
+        }
+    }
+
+    /// Everything that could complete `word`, which is the part of an identifier (possibly
+    /// qualified, like `List.ma`) before the cursor
+    fn candidates(&self, word: &str) -> Vec<String> {
+        let mut candidates: Vec<String> = match word.rsplit_once('.') {
+            Some((module_name, prefix)) => self
+                .builtins
+                .iter()
+                .filter(|(name, _)| *name == module_name)
+                .flat_map(|(_, exposed)| exposed.iter())
+                .filter(|exposed| exposed.starts_with(prefix))
+                .map(|exposed| format!("{}.{}", module_name, exposed))
+                .collect(),
+            None => self
+                .builtins
+                .iter()
+                .map(|(name, _)| name.to_string())
+                .chain(self.session_idents.iter().cloned())
+                .filter(|candidate| candidate.starts_with(word))
+                .collect(),
+        };
+
+        candidates.sort();
+        candidates.dedup();
+
+        candidates
+    }
+}
+
+impl Completer for ReplCompleter {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let before_cursor = &line[..pos];
+        let start = before_cursor
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+            .map_or(0, |index| index + 1);
+        let word = &before_cursor[start..];
+
+        if word.is_empty() {
+            return Ok((pos, Vec::new()));
+        }
+
+        let pairs = self
+            .candidates(word)
+            .into_iter()
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: candidate,
+            })
+            .collect();
+
+        Ok((start, pairs))
+    }
+}
+
+/// The names in the `exposes` list of a builtin module's header
+fn exposed_names(src: &str) -> Vec<String> {
+    let arena = Bump::new();
+
+    match parse_header(&arena, State::new(src.as_bytes())) {
+        Ok((Module::Interface { header }, _)) => header
+            .exposes
+            .items
+            .iter()
+            .map(|exposed| unspaced_name(&exposed.value).to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn unspaced_name<'a>(spaced: &Spaced<'a, ExposedName<'a>>) -> &'a str {
+    match spaced {
+        Spaced::Item(name) => (*name).into(),
+        Spaced::SpaceBefore(nested, _) | Spaced::SpaceAfter(nested, _) => unspaced_name(nested),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReplCompleter;
+
+    fn completer(session_idents: &[&str]) -> ReplCompleter {
+        let mut completer = ReplCompleter::new();
+
+        completer.session_idents = session_idents.iter().map(|s| s.to_string()).collect();
+
+        completer
+    }
+
+    #[test]
+    fn module_names() {
+        let completer = completer(&[]);
+
+        assert_eq!(completer.candidates("S"), vec!["Set", "Str"]);
+        assert_eq!(completer.candidates("Li"), vec!["List"]);
+        assert!(completer.candidates("Nope").is_empty());
+    }
+
+    #[test]
+    fn list_members() {
+        let completer = completer(&[]);
+
+        assert_eq!(
+            completer.candidates("List.ma"),
+            vec![
+                "List.map",
+                "List.map2",
+                "List.map3",
+                "List.map4",
+                "List.mapTry",
+                "List.mapWithIndex",
+                "List.max",
+            ]
+        );
+
+        let all_members = completer.candidates("List.");
+
+        assert!(all_members.contains(&"List.len".to_string()));
+        assert!(all_members.iter().all(|member| member.starts_with("List.")));
+    }
+
+    #[test]
+    fn members_of_unknown_module() {
+        let completer = completer(&["map"]);
+
+        assert!(completer.candidates("Foo.ma").is_empty());
+    }
+
+    #[test]
+    fn session_names() {
+        let completer = completer(&["listOfNums", "lastName", "listOfNums", "Lib"]);
+
+        assert_eq!(completer.candidates("li"), vec!["listOfNums"]);
+        assert_eq!(completer.candidates("l"), vec!["lastName", "listOfNums"]);
+        assert_eq!(completer.candidates("Li"), vec!["Lib", "List"]);
+    }
+}
@@ -0,0 +1,284 @@
+//! Runs REPL inputs compiled by the dev backend. The code is loaded straight into this process,
+//! and evaluated in a forked child, so that a crash in Roc code can be reported without taking
+//! the REPL down with it.
+
+use roc_gen_dev::jit::{JitObject, ReturnConvention};
+use roc_mono::ir::CrashTag;
+use roc_parse::ast::Expr;
+use roc_repl_eval::gen::ReplOutput;
+use roc_repl_eval::ReplApp;
+use std::ffi::CStr;
+use std::io::Read;
+use std::mem::MaybeUninit;
+use std::os::raw::{c_char, c_void};
+use std::os::unix::io::FromRawFd;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use crate::CliMemory;
+
+/// The child writes one of these before the value or crash message it reports
+const VALUE: u8 = 0;
+const CRASH: u8 = 1;
+
+/// Where `roc_panic` reports a crash in the child; only set in the child
+static CRASH_FD: AtomicI32 = AtomicI32::new(-1);
+
+/// The functions a platform would provide to code made by the dev backend
+pub(crate) fn host_symbols() -> [(&'static str, usize); 6] {
+    [
+        ("roc_alloc", roc_alloc as usize),
+        ("roc_realloc", roc_realloc as usize),
+        ("roc_dealloc", roc_dealloc as usize),
+        ("roc_memcpy", roc_memcpy as usize),
+        ("roc_memset", roc_memset as usize),
+        ("roc_panic", roc_panic as usize),
+    ]
+}
+
+extern "C" fn roc_alloc(size: usize, _alignment: u32) -> *mut c_void {
+    unsafe { libc::malloc(size) }
+}
+
+extern "C" fn roc_realloc(
+    ptr: *mut c_void,
+    new_size: usize,
+    _old_size: usize,
+    _alignment: u32,
+) -> *mut c_void {
+    unsafe { libc::realloc(ptr, new_size) }
+}
+
+extern "C" fn roc_dealloc(ptr: *mut c_void, _alignment: u32) {
+    unsafe { libc::free(ptr) }
+}
+
+extern "C" fn roc_memcpy(dst: *mut c_void, src: *mut c_void, size: usize) -> *mut c_void {
+    unsafe { libc::memcpy(dst, src, size) }
+}
+
+extern "C" fn roc_memset(dst: *mut c_void, value: i32, size: usize) -> *mut c_void {
+    unsafe { libc::memset(dst, value, size) }
+}
+
+/// Report the crash to the REPL, and end the child that was evaluating the input
+extern "C" fn roc_panic(message: *const c_char, tag: u32) {
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+
+    let report = match CrashTag::try_from(tag) {
+        Ok(CrashTag::User) => format!("This Roc code crashed with: \"{}\"", message),
+        _ => format!(
+            "The Roc compiler inserted a crash, which reported: {}",
+            message
+        ),
+    };
+
+    write_record(CRASH_FD.load(Ordering::SeqCst), CRASH, &report);
+
+    unsafe { libc::_exit(0) }
+}
+
+fn write_record(fd: i32, kind: u8, text: &str) {
+    let mut bytes = Vec::with_capacity(text.len() + 1);
+    bytes.push(kind);
+    bytes.extend_from_slice(text.as_bytes());
+
+    let mut written = 0;
+
+    while written < bytes.len() {
+        let result =
+            unsafe { libc::write(fd, bytes[written..].as_ptr().cast(), bytes.len() - written) };
+
+        if result <= 0 {
+            break;
+        }
+
+        written += result as usize;
+    }
+}
+
+/// Evaluate an input in a forked child, which sends back the formatted value, or the message
+/// of the crash that stopped it
+pub(crate) fn eval_in_child<F>(expr_type: String, eval: F) -> ReplOutput
+where
+    F: FnOnce() -> ReplOutput,
+{
+    let mut fds = [0; 2];
+
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        let error = std::io::Error::last_os_error();
+
+        return ReplOutput::Problems(vec![format!("Unable to evaluate this: {}", error)]);
+    }
+
+    let [read_fd, write_fd] = fds;
+
+    match unsafe { libc::fork() } {
+        0 => {
+            // we are the child
+            unsafe { libc::close(read_fd) };
+
+            CRASH_FD.store(write_fd, Ordering::SeqCst);
+
+            // a panic must not unwind into the child's copy of the REPL loop
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(eval)) {
+                Ok(ReplOutput::NoProblems { expr, .. }) => write_record(write_fd, VALUE, &expr),
+                Ok(ReplOutput::Problems(problems)) => {
+                    write_record(write_fd, CRASH, &problems.join("\n\n"))
+                }
+                Err(_) => write_record(
+                    write_fd,
+                    CRASH,
+                    "The REPL ran into a problem evaluating this input.",
+                ),
+            }
+
+            unsafe { libc::_exit(0) }
+        }
+        -1 => {
+            let error = std::io::Error::last_os_error();
+
+            unsafe {
+                libc::close(read_fd);
+                libc::close(write_fd);
+            }
+
+            ReplOutput::Problems(vec![format!("Unable to evaluate this: {}", error)])
+        }
+        child => {
+            unsafe { libc::close(write_fd) };
+
+            let mut bytes = Vec::new();
+            let mut reader = unsafe { std::fs::File::from_raw_fd(read_fd) };
+            let read = reader.read_to_end(&mut bytes);
+
+            let mut status = 0;
+            unsafe { libc::waitpid(child, &mut status, 0) };
+
+            match (read, bytes.split_first()) {
+                (Ok(_), Some((&VALUE, expr))) => ReplOutput::NoProblems {
+                    expr: String::from_utf8_lossy(expr).into_owned(),
+                    expr_type,
+                },
+                (Ok(_), Some((&CRASH, message))) => {
+                    ReplOutput::Problems(vec![String::from_utf8_lossy(message).into_owned()])
+                }
+                _ if libc::WIFSIGNALED(status) => ReplOutput::Problems(vec![format!(
+                    "The code generated for this input was stopped by signal {}.",
+                    libc::WTERMSIG(status)
+                )]),
+                _ => ReplOutput::Problems(vec![
+                    "The code generated for this input stopped without a result.".to_string(),
+                ]),
+            }
+        }
+    }
+}
+
+/// Runs code generated by the dev backend, whose main function returns its value directly
+/// rather than through a `RocCallResult`
+pub(crate) struct DevApp {
+    pub(crate) object: JitObject,
+    /// How the main function returns its value, which follows from the layout of that value
+    pub(crate) return_convention: ReturnConvention,
+}
+
+impl DevApp {
+    fn main_address(&self, main_fn_name: &str) -> usize {
+        self.object
+            .get(main_fn_name)
+            .unwrap_or_else(|| panic!("Unable to JIT compile `{}`", main_fn_name))
+    }
+}
+
+impl<'a> ReplApp<'a> for DevApp {
+    type Memory = CliMemory;
+
+    fn call_function<Return, F>(&mut self, main_fn_name: &str, mut transform: F) -> Expr<'a>
+    where
+        F: FnMut(&'a Self::Memory, Return) -> Expr<'a>,
+        Self::Memory: 'a,
+    {
+        let address = self.main_address(main_fn_name);
+
+        let value = unsafe {
+            match self.return_convention {
+                ReturnConvention::ArgPointer => {
+                    let main: extern "C" fn(*mut Return) = std::mem::transmute(address);
+                    let mut value = MaybeUninit::<Return>::uninit();
+
+                    main(value.as_mut_ptr());
+
+                    value.assume_init()
+                }
+                ReturnConvention::Nothing => {
+                    let main: extern "C" fn() = std::mem::transmute(address);
+
+                    main();
+
+                    MaybeUninit::<Return>::zeroed().assume_init()
+                }
+                ReturnConvention::FloatRegister | ReturnConvention::GeneralRegisters => {
+                    let main: extern "C" fn() -> Return = std::mem::transmute(address);
+
+                    main()
+                }
+            }
+        };
+
+        transform(&CliMemory, value)
+    }
+
+    fn call_function_dynamic_size<T, F>(
+        &mut self,
+        main_fn_name: &str,
+        ret_bytes: usize,
+        mut transform: F,
+    ) -> T
+    where
+        F: FnMut(&'a Self::Memory, usize) -> T,
+        Self::Memory: 'a,
+    {
+        /// Values of up to 16 bytes come back in two general-purpose registers
+        #[repr(C)]
+        struct TwoRegisters(u64, u64);
+
+        let address = self.main_address(main_fn_name);
+
+        // Like with LLVM, this memory is never freed, since the value may point into it
+        let layout = std::alloc::Layout::from_size_align(ret_bytes.max(16), 16).unwrap();
+
+        unsafe {
+            let result = std::alloc::alloc(layout);
+
+            match self.return_convention {
+                ReturnConvention::ArgPointer => {
+                    let main: extern "C" fn(*mut u8) = std::mem::transmute(address);
+
+                    main(result);
+                }
+                ReturnConvention::Nothing => {
+                    let main: extern "C" fn() = std::mem::transmute(address);
+
+                    main();
+                }
+                ReturnConvention::FloatRegister if ret_bytes == 4 => {
+                    let main: extern "C" fn() -> f32 = std::mem::transmute(address);
+
+                    (result as *mut f32).write(main());
+                }
+                ReturnConvention::FloatRegister => {
+                    let main: extern "C" fn() -> f64 = std::mem::transmute(address);
+
+                    (result as *mut f64).write(main());
+                }
+                ReturnConvention::GeneralRegisters => {
+                    let main: extern "C" fn() -> TwoRegisters = std::mem::transmute(address);
+
+                    (result as *mut TwoRegisters).write(main());
+                }
+            }
+
+            transform(&CliMemory, result as usize)
+        }
+    }
+}
@@ -1,16 +1,20 @@
 use bumpalo::Bump;
 use const_format::concatcp;
 use inkwell::context::Context;
+use inkwell::module::Module;
 use libloading::Library;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
 use roc_intern::SingleThreadedInterner;
-use roc_mono::layout::Layout;
+use roc_mono::layout::{Layout, LayoutIds};
 use roc_types::subs::Subs;
+use rustyline::completion::{Completer, Pair};
 use rustyline::highlight::{Highlighter, PromptInfo};
 use rustyline::validate::{self, ValidationContext, ValidationResult, Validator};
-use rustyline_derive::{Completer, Helper, Hinter};
+use rustyline_derive::{Helper, Hinter};
 use std::borrow::Cow;
 use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 use target_lexicon::Triple;
 
 use roc_build::link::llvm_module_to_dylib;
@@ -18,17 +22,28 @@ use roc_collections::all::MutSet;
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
 use roc_gen_llvm::{run_jit_function, run_jit_function_dynamic_type};
 use roc_load::{EntryPoint, MonomorphizedModule};
+use roc_module::symbol::{Interns, ModuleIds};
 use roc_mono::ir::OptLevel;
 use roc_parse::ast::Expr;
-use roc_parse::parser::{EExpr, ELambda, SyntaxError};
+use roc_parse::parser::{FileError, SourceError, SyntaxError};
+use roc_region::all::LineInfo;
 use roc_repl_eval::eval::jit_to_ast;
-use roc_repl_eval::gen::{compile_to_mono, format_answer, ReplOutput};
+use roc_repl_eval::gen::{compile_module_to_mono, format_answer, ReplOutput};
 use roc_repl_eval::{ReplApp, ReplAppMemory};
-use roc_reporting::report::DEFAULT_PALETTE;
+use roc_reporting::error::parse::parse_problem;
+use roc_reporting::report::{RocDocAllocator, DEFAULT_PALETTE};
 use roc_std::RocStr;
 use roc_target::TargetInfo;
 use roc_types::pretty_print::{name_and_print_var, DebugPrint};
 
+use crate::completion::ReplCompleter;
+use crate::repl_state::{parse_input, Input, ReplState};
+
+mod completion;
+#[cfg(unix)]
+mod dev;
+mod repl_state;
+
 const BLUE: &str = "\u{001b}[36m";
 const PINK: &str = "\u{001b}[35m";
 const END_COL: &str = "\u{001b}[0m";
@@ -45,12 +60,25 @@ pub const WELCOME_MESSAGE: &str = concatcp!(
     "\n\n"
 );
 pub const INSTRUCTIONS: &str = "Enter an expression, or :help, or :q to quit.\n";
+const HELP: &str = "
+Enter an expression to evaluate it, or a definition to add it to the session.
+
+  import Foo        Import the Foo.roc module in the current directory
+  :defs             Show the session's imports and definitions
+  :reset            Clear the session's imports and definitions
+  :load <file>      Add the definitions in a .roc file to the session
+  :type <expr>      Show the type of an expression, without evaluating it
+  :ir <expr>        Show the specialized mono IR of an expression
+  :llvm <expr>      Show the LLVM IR generated for an expression
+  :time             Turn showing how long evaluation takes on or off
+  :exit, :quit, :q  Exit the REPL";
 pub const PROMPT: &str = concatcp!("\n", BLUE, "»", END_COL, " ");
 pub const CONT_PROMPT: &str = concatcp!(BLUE, "…", END_COL, " ");
 
-#[derive(Completer, Helper, Hinter)]
+#[derive(Helper, Hinter)]
 struct ReplHelper {
     validator: InputValidator,
+    completer: ReplCompleter,
     pending_src: String,
 }
 
@@ -58,11 +86,25 @@ impl ReplHelper {
     pub(crate) fn new() -> ReplHelper {
         ReplHelper {
             validator: InputValidator::new(),
+            completer: ReplCompleter::new(),
             pending_src: String::new(),
         }
     }
 }
 
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        self.completer.complete(line, pos, ctx)
+    }
+}
+
 impl Highlighter for ReplHelper {
     fn has_continuation_prompt(&self) -> bool {
         true
@@ -104,25 +146,54 @@ impl InputValidator {
 
 impl Validator for InputValidator {
     fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
-        if ctx.input().is_empty() {
-            Ok(ValidationResult::Incomplete)
+        let input = ctx.input();
+
+        if input.trim().is_empty() {
+            return Ok(ValidationResult::Incomplete);
+        }
+
+        // A blank line always ends the input, so you can't get stuck in an unfinished one
+        if input.ends_with('\n') || input.trim_start().starts_with(':') {
+            return Ok(ValidationResult::Valid(None));
+        }
+
+        // An indented last line might be followed by more of the same block,
+        // like another `when` branch, so those wait for a blank line.
+        let ends_indented = input
+            .lines()
+            .last()
+            .map_or(false, |line| line.starts_with(char::is_whitespace));
+
+        let is_complete = match parse_input(input) {
+            Input::Import(_) => true,
+            Input::Expr => !ends_indented,
+            Input::Defs {
+                ends_with_annotation,
+                ..
+            } => !ends_indented && !ends_with_annotation,
+            Input::Unparsed => false,
+        };
+
+        if is_complete {
+            Ok(ValidationResult::Valid(None))
         } else {
-            let arena = bumpalo::Bump::new();
-            let state = roc_parse::state::State::new(ctx.input().trim().as_bytes());
-
-            match roc_parse::expr::parse_loc_expr(0, &arena, state) {
-                // Special case some syntax errors to allow for multi-line inputs
-                Err((_, EExpr::DefMissingFinalExpr(_), _))
-                | Err((_, EExpr::DefMissingFinalExpr2(_, _), _))
-                | Err((_, EExpr::Lambda(ELambda::Body(_, _), _), _)) => {
-                    Ok(ValidationResult::Incomplete)
-                }
-                _ => Ok(ValidationResult::Valid(None)),
-            }
+            Ok(ValidationResult::Incomplete)
         }
     }
 }
 
+/// Which backend generates the code for REPL inputs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// The dev backend, which compiles much faster than LLVM. Inputs that use something it
+    /// doesn't support yet fall back to LLVM.
+    Dev,
+    /// The dev backend without the fall back to LLVM, so that tests can tell which inputs it
+    /// doesn't support yet
+    DevOnly,
+    Llvm,
+}
+
 struct CliApp {
     lib: Library,
 }
@@ -217,7 +288,43 @@ pub fn mono_module_to_dylib<'a>(
     ),
     libloading::Error,
 > {
-    let target_info = TargetInfo::from(&target);
+    with_llvm_module(
+        arena,
+        &target,
+        loaded,
+        opt_level,
+        |module, main_fn_name, subs, layout_interner| {
+            llvm_module_to_dylib(module, &target, opt_level)
+                .map(|lib| (lib, main_fn_name, subs, layout_interner))
+        },
+    )
+}
+
+/// The optimized LLVM IR for a monomorphized REPL module, as `:llvm` prints it
+fn mono_module_to_llvm_ir<'a>(
+    arena: &'a Bump,
+    target: Triple,
+    loaded: MonomorphizedModule<'a>,
+    opt_level: OptLevel,
+) -> String {
+    with_llvm_module(arena, &target, loaded, opt_level, |module, _, _, _| {
+        module.print_to_string().to_string()
+    })
+}
+
+/// Generate and verify the LLVM module for a monomorphized REPL module, then give it to `f`
+/// along with the name of its main function.
+fn with_llvm_module<'a, T, F>(
+    arena: &'a Bump,
+    target: &Triple,
+    loaded: MonomorphizedModule<'a>,
+    opt_level: OptLevel,
+    f: F,
+) -> T
+where
+    F: FnOnce(&Module, &'a str, Subs, SingleThreadedInterner<'a, Layout<'a>>) -> T,
+{
+    let target_info = TargetInfo::from(target);
 
     let MonomorphizedModule {
         procedures,
@@ -231,7 +338,7 @@ pub fn mono_module_to_dylib<'a>(
     let context = Context::create();
     let builder = context.create_builder();
     let module = arena.alloc(roc_gen_llvm::llvm::build::module_from_builtins(
-        &target, &context, "",
+        target, &context, "",
     ));
 
     let module = arena.alloc(module);
@@ -303,24 +410,101 @@ pub fn mono_module_to_dylib<'a>(
         );
     }
 
-    llvm_module_to_dylib(env.module, &target, opt_level)
-        .map(|lib| (lib, main_fn_name, subs, layout_interner))
+    let module = env.module;
+
+    f(module, main_fn_name, subs, layout_interner)
 }
 
-fn gen_and_eval_llvm<'a>(
-    src: &str,
+/// Generate code for a monomorphized REPL module with the dev backend, and load it into this
+/// process. Returns `None` if the module uses something the dev backend doesn't support yet.
+#[cfg(unix)]
+fn mono_module_to_dev_object<'a>(
+    arena: &'a Bump,
+    target: &Triple,
+    loaded: MonomorphizedModule<'a>,
+) -> Option<(
+    roc_gen_dev::jit::JitObject,
+    String,
+    Subs,
+    SingleThreadedInterner<'a, Layout<'a>>,
+)> {
+    let MonomorphizedModule {
+        module_id,
+        procedures,
+        mut interns,
+        exposed_to_host,
+        entry_point,
+        subs,
+        layout_interner,
+        ..
+    } = loaded;
+
+    let (main_fn_symbol, main_fn_layout) = match entry_point {
+        EntryPoint::Executable { symbol, layout, .. } => (symbol, layout),
+        EntryPoint::Test => {
+            unreachable!()
+        }
+    };
+
+    let main_fn_name = LayoutIds::default()
+        .get_toplevel(main_fn_symbol, &main_fn_layout)
+        .to_exposed_symbol_string(main_fn_symbol, &interns);
+
+    let object_bytes = without_panic_output(|| {
+        let env = roc_gen_dev::Env {
+            arena,
+            layout_interner: &layout_interner,
+            module_id,
+            exposed_to_host: exposed_to_host.values.keys().copied().collect(),
+            lazy_literals: false,
+            // roc_alloc and friends are provided by the REPL when the object is loaded
+            generate_allocators: false,
+        };
+
+        roc_gen_dev::build_module(&env, &mut interns, target, procedures)
+            .write()
+            .ok()
+    })??;
+
+    let builtins = std::fs::read(roc_builtins::bitcode::get_builtins_host_obj_path()).ok()?;
+
+    let object =
+        roc_gen_dev::jit::JitObject::load(&[&object_bytes, &builtins], &dev::host_symbols())
+            .ok()?;
+
+    Some((object, main_fn_name, subs, layout_interner))
+}
+
+/// Run `f`, returning `None` instead of printing a message if it panics. The dev backend
+/// panics when it runs into something it doesn't support yet.
+fn without_panic_output<T, F: FnOnce() -> T>(f: F) -> Option<T> {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+
+    std::panic::set_hook(default_hook);
+
+    result.ok()
+}
+
+fn gen_and_eval(
+    module_src: &str,
     target: Triple,
     opt_level: OptLevel,
-) -> Result<ReplOutput, SyntaxError<'a>> {
+    backend: Backend,
+) -> ReplOutput {
     let arena = Bump::new();
     let target_info = TargetInfo::from(&target);
+    let module_src = arena.alloc_str(module_src);
 
-    let mut loaded = match compile_to_mono(&arena, src, target_info, DEFAULT_PALETTE) {
-        Ok(x) => x,
-        Err(prob_strings) => {
-            return Ok(ReplOutput::Problems(prob_strings));
-        }
-    };
+    let mut loaded =
+        match compile_module_to_mono(&arena, module_src, src_dir(), target_info, DEFAULT_PALETTE) {
+            Ok(x) => x,
+            Err(prob_strings) => {
+                return ReplOutput::Problems(prob_strings);
+            }
+        };
 
     debug_assert_eq!(loaded.exposed_to_host.values.len(), 1);
     let (main_fn_symbol, main_fn_var) = loaded.exposed_to_host.values.iter().next().unwrap();
@@ -340,52 +524,302 @@ fn gen_and_eval_llvm<'a>(
     let (_, main_fn_layout) = match loaded.procedures.keys().find(|(s, _)| *s == main_fn_symbol) {
         Some(layout) => *layout,
         None => {
-            return Ok(ReplOutput::NoProblems {
+            return ReplOutput::NoProblems {
                 expr: "<function>".to_string(),
                 expr_type: expr_type_str,
-            });
+            };
         }
     };
 
     let interns = loaded.interns.clone();
 
-    let (lib, main_fn_name, subs, layout_interner) =
-        mono_module_to_dylib(&arena, target, loaded, opt_level).expect("we produce a valid Dylib");
-
-    let mut app = CliApp { lib };
-
-    let res_answer = jit_to_ast(
-        &arena,
-        &mut app,
-        main_fn_name,
-        main_fn_layout,
-        &content,
-        &subs,
-        &interns,
-        layout_interner.into_global().fork(),
-        target_info,
-    );
+    let res_answer = match backend {
+        #[cfg(unix)]
+        Backend::Dev | Backend::DevOnly => {
+            match mono_module_to_dev_object(&arena, &target, loaded) {
+                Some((object, main_fn_name, subs, layout_interner)) => {
+                    let return_convention = roc_gen_dev::jit::ReturnConvention::of(
+                        &layout_interner,
+                        target_info,
+                        &main_fn_layout.result,
+                    );
+                    let mut app = dev::DevApp {
+                        object,
+                        return_convention,
+                    };
+
+                    // Roc code that crashes ends the child, which reports the crash instead
+                    return dev::eval_in_child(expr_type_str.clone(), || {
+                        let res_answer = jit_to_ast(
+                            &arena,
+                            &mut app,
+                            &main_fn_name,
+                            main_fn_layout,
+                            &content,
+                            &subs,
+                            &interns,
+                            layout_interner.into_global().fork(),
+                            target_info,
+                        );
+
+                        format_answer(&arena, res_answer, expr_type_str)
+                    });
+                }
+                None if backend == Backend::DevOnly => {
+                    return ReplOutput::Problems(vec![concat!(
+                        "The dev backend can't evaluate this input yet, ",
+                        "and falling back to LLVM is turned off."
+                    )
+                    .to_string()]);
+                }
+                None => return gen_and_eval(module_src, target, opt_level, Backend::Llvm),
+            }
+        }
+        #[cfg(not(unix))]
+        Backend::Dev | Backend::DevOnly => {
+            return gen_and_eval(module_src, target, opt_level, Backend::Llvm)
+        }
+        Backend::Llvm => {
+            let (lib, main_fn_name, subs, layout_interner) =
+                mono_module_to_dylib(&arena, target, loaded, opt_level)
+                    .expect("we produce a valid Dylib");
+
+            let mut app = CliApp { lib };
+
+            jit_to_ast(
+                &arena,
+                &mut app,
+                main_fn_name,
+                main_fn_layout,
+                &content,
+                &subs,
+                &interns,
+                layout_interner.into_global().fork(),
+                target_info,
+            )
+        }
+    };
 
-    let formatted = format_answer(&arena, res_answer, expr_type_str);
-    Ok(formatted)
+    format_answer(&arena, res_answer, expr_type_str)
 }
 
-fn eval_and_format<'a>(src: &str) -> Result<String, SyntaxError<'a>> {
-    let format_output = |output| match output {
+fn format_output(output: ReplOutput) -> String {
+    match output {
         ReplOutput::NoProblems { expr, expr_type } => {
             format!("\n{} {}:{} {}", expr, PINK, END_COL, expr_type)
         }
         ReplOutput::Problems(lines) => format!("\n{}\n", lines.join("\n\n")),
+    }
+}
+
+/// Evaluate an input in the session. Definitions and imports get added to the session
+/// (if they compile), and print nothing; expressions print their value and type.
+fn eval_and_format(state: &mut ReplState, src: &str, backend: Backend) -> String {
+    let mut next_state = state.clone();
+
+    match parse_input(src) {
+        Input::Import(import) => next_state.add_import(import),
+        Input::Defs { idents, .. } => next_state.add_def(idents, src),
+        Input::Expr => {
+            return format_output(gen_and_eval(
+                &state.module_src(src),
+                Triple::host(),
+                OptLevel::Normal,
+                backend,
+            ));
+        }
+        Input::Unparsed => {
+            let arena = Bump::new();
+            let src = src.trim();
+
+            // Report the syntax error against the input itself, rather than the module
+            // it would have been evaluated in
+            return match roc_parse::test_helpers::parse_expr_with(&arena, src) {
+                Ok(_) => format_output(gen_and_eval(
+                    &state.module_src(src),
+                    Triple::host(),
+                    OptLevel::Normal,
+                    backend,
+                )),
+                Err(problem) => {
+                    format!("\n{}\n", parse_problem_report(PathBuf::new(), src, problem))
+                }
+            };
+        }
+    }
+
+    match check(&next_state) {
+        Ok(()) => {
+            *state = next_state;
+
+            String::new()
+        }
+        Err(problems) => format_output(ReplOutput::Problems(problems)),
+    }
+}
+
+/// Render a syntax error in the REPL's input (or a `:load`ed file) the same way the compiler does
+pub(crate) fn parse_problem_report(filename: PathBuf, src: &str, problem: SyntaxError) -> String {
+    let src_lines: Vec<&str> = src.split('\n').collect();
+    let lines = LineInfo::new(src);
+
+    let mut module_ids = ModuleIds::default();
+    let home = module_ids.get_or_insert(&"REPL".into());
+    let interns = Interns {
+        module_ids,
+        ..Default::default()
+    };
+
+    let alloc = RocDocAllocator::new(&src_lines, home, &interns);
+    let file_error = FileError {
+        problem: SourceError {
+            problem,
+            bytes: src.as_bytes(),
+        },
+        filename: filename.clone(),
+    };
+    let report = parse_problem(&alloc, &lines, filename, 0, file_error);
+
+    let mut buf = String::new();
+    report.render_color_terminal(&mut buf, &alloc, &DEFAULT_PALETTE);
+
+    buf
+}
+
+/// Check that everything in the session compiles, without evaluating anything
+fn check(state: &ReplState) -> Result<(), Vec<String>> {
+    let arena = Bump::new();
+    let module_src = arena.alloc_str(&state.module_src("{}"));
+    let target_info = TargetInfo::from(&Triple::host());
+
+    compile_module_to_mono(&arena, module_src, src_dir(), target_info, DEFAULT_PALETTE).map(|_| ())
+}
+
+/// Compile an expression in the session, without evaluating it. Problems come back already
+/// formatted as REPL output.
+fn compile_expr<'a>(
+    arena: &'a Bump,
+    state: &ReplState,
+    src: &str,
+) -> Result<MonomorphizedModule<'a>, String> {
+    let module_src = arena.alloc_str(&state.module_src(src));
+    let target_info = TargetInfo::from(&Triple::host());
+
+    compile_module_to_mono(arena, module_src, src_dir(), target_info, DEFAULT_PALETTE)
+        .map_err(|problems| format_output(ReplOutput::Problems(problems)))
+}
+
+/// `:type <expr>`
+fn type_of(state: &ReplState, src: &str) -> String {
+    let arena = Bump::new();
+
+    match compile_expr(&arena, state, src) {
+        Ok(mut loaded) => {
+            let (_, main_fn_var) = loaded.exposed_to_host.values.iter().next().unwrap();
+            let main_fn_var = *main_fn_var;
+
+            let expr_type = name_and_print_var(
+                main_fn_var,
+                &mut loaded.subs,
+                loaded.module_id,
+                &loaded.interns,
+                DebugPrint::NOTHING,
+            );
+
+            format!("\n{} {}:{} {}", src, PINK, END_COL, expr_type)
+        }
+        Err(problems) => problems,
+    }
+}
+
+/// `:ir <expr>` prints the same IR as `ROC_PRINT_IR_AFTER_REFCOUNT`
+fn ir_of(state: &ReplState, src: &str) -> String {
+    let arena = Bump::new();
+
+    match compile_expr(&arena, state, src) {
+        Ok(loaded) => {
+            let mut procs: Vec<String> = loaded
+                .procedures
+                .values()
+                .map(|proc| proc.to_pretty(&loaded.layout_interner, 200))
+                .collect();
+
+            // The procedures are in a hash map, so sort them to print them in a stable order
+            procs.sort();
+
+            format!("\n{}", procs.join("\n"))
+        }
+        Err(problems) => problems,
+    }
+}
+
+/// `:llvm <expr>`
+fn llvm_ir_of(state: &ReplState, src: &str) -> String {
+    let arena = Bump::new();
+
+    match compile_expr(&arena, state, src) {
+        Ok(loaded) => {
+            let (main_fn_symbol, _) = loaded.exposed_to_host.values.iter().next().unwrap();
+
+            // Like evaluating it, there's no main function to generate for a function on its own
+            if !loaded.procedures.keys().any(|(s, _)| s == main_fn_symbol) {
+                return "\nThere is no LLVM IR for a function on its own; try calling it."
+                    .to_string();
+            }
+
+            format!(
+                "\n{}",
+                mono_module_to_llvm_ir(&arena, Triple::host(), loaded, OptLevel::Normal)
+            )
+        }
+        Err(problems) => problems,
+    }
+}
+
+/// `:load <file>` adds a file's definitions (and its imports, if it's an interface module)
+/// to the session.
+fn load_file(state: &mut ReplState, path: &str) -> String {
+    let src = match std::fs::read_to_string(path) {
+        Ok(src) => src,
+        Err(err) => return format!("\nI couldn't read {}: {}", path, err),
     };
 
-    gen_and_eval_llvm(src, Triple::host(), OptLevel::Normal).map(format_output)
+    let mut next_state = state.clone();
+
+    let def_count = match next_state.load(Path::new(path), &src) {
+        Ok(def_count) => def_count,
+        Err(problem) => return format!("\n{}\n", problem),
+    };
+
+    match check(&next_state) {
+        Ok(()) => {
+            *state = next_state;
+
+            format!("\nLoaded {} definitions from {}.", def_count, path)
+        }
+        Err(problems) => format_output(ReplOutput::Problems(problems)),
+    }
 }
 
-fn report_parse_error(fail: SyntaxError) {
-    println!("TODO Gracefully report parse error in repl: {:?}", fail);
+/// The argument of a command like `:type <expr>`
+fn command_arg(line: &str) -> &str {
+    line.split_once(char::is_whitespace)
+        .map_or("", |(_, arg)| arg.trim())
 }
 
-pub fn main() -> io::Result<()> {
+/// Where `import`ed modules are looked up
+fn src_dir() -> PathBuf {
+    std::env::current_dir().unwrap_or_default()
+}
+
+fn print_output(output: &str) {
+    // Definitions and imports don't print anything
+    if !output.is_empty() {
+        println!("{}", output);
+    }
+}
+
+pub fn main(backend: Backend) -> io::Result<()> {
     use rustyline::error::ReadlineError;
     use rustyline::Editor;
 
@@ -395,11 +829,19 @@ pub fn main() -> io::Result<()> {
     print!("{}{}", WELCOME_MESSAGE, INSTRUCTIONS);
 
     let mut prev_line_blank = false;
+    let mut state = ReplState::new();
+    let mut show_timing = false;
     let mut editor = Editor::<ReplHelper>::new();
     let repl_helper = ReplHelper::new();
     editor.set_helper(Some(repl_helper));
 
     loop {
+        editor
+            .helper_mut()
+            .expect("Editor helper was not set")
+            .completer
+            .session_idents = state.idents();
+
         let readline = editor.readline(PROMPT);
 
         match readline {
@@ -419,14 +861,11 @@ pub fn main() -> io::Result<()> {
                         } else if prev_line_blank {
                             // After two blank lines in a row, give up and try parsing it
                             // even though it's going to fail. This way you don't get stuck.
-                            match eval_and_format(pending_src.as_str()) {
-                                Ok(output) => {
-                                    println!("{}", output);
-                                }
-                                Err(fail) => {
-                                    report_parse_error(fail);
-                                }
-                            }
+                            print_output(&eval_and_format(
+                                &mut state,
+                                pending_src.as_str(),
+                                backend,
+                            ));
 
                             pending_src.clear();
                         } else {
@@ -437,34 +876,56 @@ pub fn main() -> io::Result<()> {
                         }
                     }
                     ":help" => {
-                        println!("Use :exit or :quit or :q to exit.");
+                        println!("{}", HELP);
+                    }
+                    ":defs" => {
+                        println!("\n{}", state.describe());
+                    }
+                    ":reset" => {
+                        state.reset();
+
+                        println!("\nCleared all definitions and imports.");
+                    }
+                    ":time" => {
+                        show_timing = !show_timing;
+
+                        if show_timing {
+                            println!("\nTiming is on.");
+                        } else {
+                            println!("\nTiming is off.");
+                        }
                     }
                     ":exit" | ":quit" | ":q" => {
                         break;
                     }
+                    command if command.starts_with(":type ") => {
+                        print_output(&type_of(&state, command_arg(trim_line)));
+                    }
+                    command if command.starts_with(":ir ") => {
+                        print_output(&ir_of(&state, command_arg(trim_line)));
+                    }
+                    command if command.starts_with(":llvm ") => {
+                        print_output(&llvm_ir_of(&state, command_arg(trim_line)));
+                    }
+                    command if command.starts_with(":load ") => {
+                        print_output(&load_file(&mut state, command_arg(trim_line)));
+                    }
                     _ => {
-                        let result = if pending_src.is_empty() {
-                            eval_and_format(trim_line)
+                        let start_time = Instant::now();
+                        let output = if pending_src.is_empty() {
+                            eval_and_format(&mut state, trim_line, backend)
                         } else {
                             pending_src.push('\n');
                             pending_src.push_str(trim_line);
 
-                            eval_and_format(pending_src.as_str())
+                            eval_and_format(&mut state, pending_src.as_str(), backend)
                         };
 
-                        match result {
-                            Ok(output) => {
-                                println!("{}", output);
-                                pending_src.clear();
-                            }
-                            //                            Err(Fail {
-                            //                                reason: FailReason::Eof(_),
-                            //                                ..
-                            //                            }) => {}
-                            Err(fail) => {
-                                report_parse_error(fail);
-                                pending_src.clear();
-                            }
+                        print_output(&output);
+                        pending_src.clear();
+
+                        if show_timing {
+                            println!("\n({} ms)", start_time.elapsed().as_millis());
                         }
                     }
                 }
@@ -0,0 +1,232 @@
+use bumpalo::Bump;
+use roc_parse::ast::{Module, Pattern, TypeDef, ValueDef};
+use roc_parse::module::parse_header;
+use roc_parse::parser::Parser;
+use roc_parse::state::State;
+use roc_region::all::Region;
+use roc_repl_eval::gen::repl_module_src;
+use std::path::Path;
+
+use crate::parse_problem_report;
+
+/// What an input to the REPL turned out to be
+pub enum Input<'a> {
+    /// `import Foo` or `import Foo.{ bar }`, with the part after `import`
+    Import(&'a str),
+    /// Top-level definitions, which introduce the given names
+    Defs {
+        idents: Vec<String>,
+        /// Whether the last def is an annotation, whose body may still be on its way
+        ends_with_annotation: bool,
+    },
+    Expr,
+    /// Input that doesn't parse; it may be unfinished, or have a syntax error
+    Unparsed,
+}
+
+pub fn parse_input(src: &str) -> Input<'_> {
+    let src = src.trim();
+
+    if let Some(import) = src.strip_prefix("import ") {
+        return Input::Import(import.trim());
+    }
+
+    let arena = Bump::new();
+
+    if roc_parse::expr::test_parse_expr(0, &arena, State::new(src.as_bytes())).is_ok() {
+        return Input::Expr;
+    }
+
+    let parsed = roc_parse::module::module_defs().parse(&arena, State::new(src.as_bytes()));
+
+    match parsed {
+        Ok((_, defs, _)) if !defs.is_empty() => Input::Defs {
+            idents: defs.defs().flat_map(def_idents).collect(),
+            ends_with_annotation: matches!(defs.last(), Some(Err(ValueDef::Annotation(..)))),
+        },
+        _ => Input::Unparsed,
+    }
+}
+
+/// The names that a top-level def introduces
+fn def_idents(def: Result<&TypeDef, &ValueDef>) -> Vec<String> {
+    let mut idents = Vec::new();
+
+    match def {
+        Ok(TypeDef::Alias { header, .. })
+        | Ok(TypeDef::Opaque { header, .. })
+        | Ok(TypeDef::Ability { header, .. }) => {
+            idents.push(header.name.value.to_string());
+        }
+        Ok(TypeDef::BuiltinImpls { .. }) => {}
+        Err(ValueDef::Annotation(pattern, _)) => {
+            pattern_idents(&pattern.value, &mut idents);
+        }
+        Err(ValueDef::Body(pattern, _)) => {
+            pattern_idents(&pattern.value, &mut idents);
+        }
+        Err(ValueDef::AnnotatedBody { body_pattern, .. }) => {
+            pattern_idents(&body_pattern.value, &mut idents);
+        }
+        Err(ValueDef::Expect { .. }) | Err(ValueDef::ExpectFx { .. }) => {}
+    }
+
+    idents
+}
+
+fn pattern_idents(pattern: &Pattern, idents: &mut Vec<String>) {
+    match pattern {
+        Pattern::Identifier(ident) => idents.push(ident.to_string()),
+        Pattern::Apply(_, args) => {
+            for arg in args.iter() {
+                pattern_idents(&arg.value, idents);
+            }
+        }
+        Pattern::RecordDestructure(fields) | Pattern::Tuple(fields) | Pattern::List(fields) => {
+            for field in fields.items.iter() {
+                pattern_idents(&field.value, idents);
+            }
+        }
+        Pattern::RequiredField(_, nested) => pattern_idents(&nested.value, idents),
+        Pattern::OptionalField(name, _) => idents.push(name.to_string()),
+        Pattern::ListRest(Some((_, name))) => idents.push(name.value.to_string()),
+        Pattern::As(nested, pattern_as) => {
+            pattern_idents(&nested.value, idents);
+            idents.push(pattern_as.identifier.value.to_string());
+        }
+        Pattern::SpaceBefore(nested, _) | Pattern::SpaceAfter(nested, _) => {
+            pattern_idents(nested, idents)
+        }
+        _ => {}
+    }
+}
+
+fn region_src(src: &str, region: Region) -> &str {
+    &src[region.start().offset as usize..region.end().offset as usize]
+}
+
+/// A top-level definition from an earlier input
+#[derive(Clone)]
+struct PastDef {
+    /// The names it introduces, so that redefining any of them replaces it
+    idents: Vec<String>,
+    src: String,
+}
+
+/// The imports and definitions that a REPL session has built up so far. Every input gets
+/// evaluated in a module that has all of them.
+#[derive(Clone, Default)]
+pub struct ReplState {
+    imports: Vec<String>,
+    past_defs: Vec<PastDef>,
+}
+
+impl ReplState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&mut self) {
+        self.imports.clear();
+        self.past_defs.clear();
+    }
+
+    pub fn add_import(&mut self, import: &str) {
+        if !self.imports.iter().any(|existing| existing == import) {
+            self.imports.push(import.to_string());
+        }
+    }
+
+    /// Add a definition to the session, replacing any earlier ones that defined the same names
+    pub fn add_def(&mut self, idents: Vec<String>, src: &str) {
+        self.past_defs
+            .retain(|past_def| !past_def.idents.iter().any(|ident| idents.contains(ident)));
+
+        self.past_defs.push(PastDef {
+            idents,
+            src: src.trim().to_string(),
+        });
+    }
+
+    /// Add the top-level definitions in a .roc file to the session, along with the imports in
+    /// its header (if it's an interface module). Returns how many definitions there were, or
+    /// the report of the file's syntax error.
+    pub fn load(&mut self, path: &Path, src: &str) -> Result<usize, String> {
+        let arena = Bump::new();
+        let state = State::new(src.as_bytes());
+
+        let state = match parse_header(&arena, state.clone()) {
+            Ok((module, after_header)) => {
+                if let Module::Interface { header } = module {
+                    for import in header.imports.items.iter() {
+                        self.add_import(region_src(src, import.region));
+                    }
+                }
+
+                after_header
+            }
+            // Files without a header are just definitions
+            Err(_) => state,
+        };
+
+        let parsed = roc_parse::module::module_defs().parse(&arena, state);
+
+        match parsed {
+            Ok((_, defs, _)) => {
+                for (def, region) in defs.defs().zip(defs.regions.iter()) {
+                    self.add_def(def_idents(def), region_src(src, *region));
+                }
+
+                Ok(defs.len())
+            }
+            Err((_, problem, _)) => Err(parse_problem_report(path.to_path_buf(), src, problem)),
+        }
+    }
+
+    /// The source of the module that evaluates `expr` in this session
+    pub fn module_src(&self, expr: &str) -> String {
+        let defs: Vec<&str> = self
+            .past_defs
+            .iter()
+            .map(|past_def| past_def.src.as_str())
+            .collect();
+
+        repl_module_src(&self.imports, &defs, expr)
+    }
+
+    /// The names the session has imported or defined, for tab completion
+    pub fn idents(&self) -> Vec<String> {
+        let imported_modules = self.imports.iter().map(|import| {
+            // `Foo.{ bar }` imports the module `Foo`
+            import
+                .split(|c: char| c == '.' || c.is_whitespace())
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        });
+
+        let defined = self
+            .past_defs
+            .iter()
+            .flat_map(|past_def| past_def.idents.iter().cloned());
+
+        imported_modules.chain(defined).collect()
+    }
+
+    /// The session's imports and definitions, as `:defs` prints them
+    pub fn describe(&self) -> String {
+        let mut lines: Vec<String> = self
+            .imports
+            .iter()
+            .map(|import| format!("import {}", import))
+            .collect();
+
+        lines.extend(self.past_defs.iter().map(|past_def| past_def.src.clone()));
+
+        if lines.is_empty() {
+            "No definitions or imports yet.".to_string()
+        } else {
+            lines.join("\n")
+        }
+    }
+}
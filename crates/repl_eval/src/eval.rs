@@ -6,7 +6,7 @@ use std::cmp::{max_by_key, min_by_key};
 use roc_builtins::bitcode::{FloatWidth, IntWidth};
 use roc_collections::all::MutMap;
 use roc_module::called_via::CalledVia;
-use roc_module::ident::TagName;
+use roc_module::ident::{tuple_arity, TagName};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::ir::ProcLayout;
 use roc_mono::layout::{
@@ -1036,25 +1036,38 @@ fn struct_to_ast<'a, 'env, M: ReplAppMemory>(
                 region: Region::zero(),
             });
 
-            let field_name = Loc {
-                value: &*arena.alloc_str(label.as_str()),
-                region: Region::zero(),
-            };
-            let loc_field = Loc {
-                value: AssignedField::RequiredValue(field_name, &[], loc_expr),
-                region: Region::zero(),
-            };
-
-            output.push(loc_field);
+            output.push((label, loc_expr));
 
             // Advance the field pointer to the next field.
             field_addr +=
                 field_layout.stack_size(&env.layout_cache.interner, env.target_info) as usize;
         }
 
-        let output = output.into_bump_slice();
+        if tuple_arity(output.iter().map(|(label, _)| label)).is_some() {
+            // Tuples are records with fields "0", "1", ...; print them in element order.
+            output.sort_by_key(|(label, _)| label.as_tuple_elem());
 
-        Expr::Record(Collection::with_items(output))
+            let elems = Vec::from_iter_in(output.into_iter().map(|(_, loc_expr)| loc_expr), arena);
+
+            return Expr::Tuple(Collection::with_items(elems.into_bump_slice()));
+        }
+
+        let output = Vec::from_iter_in(
+            output.into_iter().map(|(label, loc_expr)| {
+                let field_name = Loc {
+                    value: &*arena.alloc_str(label.as_str()),
+                    region: Region::zero(),
+                };
+
+                Loc {
+                    value: AssignedField::RequiredValue(field_name, &[], loc_expr),
+                    region: Region::zero(),
+                }
+            }),
+            arena,
+        );
+
+        Expr::Record(Collection::with_items(output.into_bump_slice()))
     }
 }
 
@@ -7,6 +7,7 @@ use roc_fmt::annotation::Formattable;
 use roc_fmt::annotation::{Newlines, Parens};
 use roc_load::{LoadingProblem, MonomorphizedModule};
 use roc_parse::ast::Expr;
+use roc_problem::can::Problem;
 use roc_region::all::LineInfo;
 use roc_reporting::report::{can_problem, type_problem, RocDocAllocator};
 use roc_target::TargetInfo;
@@ -47,11 +48,31 @@ pub fn compile_to_mono<'a>(
     src: &str,
     target_info: TargetInfo,
     palette: Palette,
+) -> Result<MonomorphizedModule<'a>, Vec<String>> {
+    let module_src = arena.alloc(promote_expr_to_module(src));
+
+    compile_module_to_mono(
+        arena,
+        module_src,
+        PathBuf::from("fake/test/path"),
+        target_info,
+        palette,
+    )
+}
+
+/// Like `compile_to_mono`, but for a module made by `repl_module_src`, whose imports are
+/// looked up in `src_dir`.
+pub fn compile_module_to_mono<'a>(
+    arena: &'a Bump,
+    module_src: &'a str,
+    src_dir: PathBuf,
+    target_info: TargetInfo,
+    palette: Palette,
 ) -> Result<MonomorphizedModule<'a>, Vec<String>> {
     let filename = PathBuf::from("");
-    let src_dir = PathBuf::from("fake/test/path");
 
-    let module_src = arena.alloc(promote_expr_to_module(src));
+    // The defs and imports of the session don't have to be used by every input
+    let repl_output_offset = module_src.find(REPL_OUTPUT_DEF).unwrap_or(module_src.len()) as u32;
 
     let exposed_types = Default::default();
     let loaded = roc_load::load_and_monomorphize_from_str(
@@ -89,7 +110,14 @@ pub fn compile_to_mono<'a>(
     let mut lines = Vec::new();
 
     for (home, (module_path, src)) in sources.iter() {
-        let can_probs = can_problems.remove(home).unwrap_or_default();
+        let mut can_probs = can_problems.remove(home).unwrap_or_default();
+
+        can_probs.retain(|problem| match problem {
+            Problem::UnusedImport(..) | Problem::UnusedExposedImport(..) => false,
+            Problem::UnusedDef(_, region) => region.start().offset >= repl_output_offset,
+            _ => true,
+        });
+
         let type_probs = type_problems.remove(home).unwrap_or_default();
 
         let error_count = can_probs.len() + type_probs.len();
@@ -131,11 +159,39 @@ pub fn compile_to_mono<'a>(
     }
 }
 
+const REPL_OUTPUT_DEF: &str = "replOutput =\n";
+
 fn promote_expr_to_module(src: &str) -> String {
-    let mut buffer =
-        String::from("app \"app\" provides [replOutput] to \"./platform\"\n\nreplOutput =\n");
+    repl_module_src::<&str, &str>(&[], &[], src)
+}
+
+/// The module that REPL input gets evaluated in: the given imports and top-level defs
+/// (from earlier inputs), and the expression to evaluate as `replOutput`.
+pub fn repl_module_src<I: AsRef<str>, D: AsRef<str>>(
+    imports: &[I],
+    defs: &[D],
+    expr: &str,
+) -> String {
+    let mut buffer = String::from("app \"app\" ");
+
+    if !imports.is_empty() {
+        let imports: Vec<&str> = imports.iter().map(|import| import.as_ref()).collect();
+
+        buffer.push_str("imports [");
+        buffer.push_str(&imports.join(", "));
+        buffer.push_str("] ");
+    }
+
+    buffer.push_str("provides [replOutput] to \"./platform\"\n\n");
+
+    for def in defs {
+        buffer.push_str(def.as_ref());
+        buffer.push_str("\n\n");
+    }
+
+    buffer.push_str(REPL_OUTPUT_DEF);
 
-    for line in src.lines() {
+    for line in expr.lines() {
         // indent the body!
         buffer.push_str("    ");
         buffer.push_str(line);
@@ -234,6 +234,38 @@ mod test {
         );
     }
 
+    #[test]
+    fn crash_in_expect() {
+        run_expect_test(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = 0
+
+                expect crash "boom"
+                "#
+            ),
+            indoc!(
+                r#"
+                This expectation crashed while running:
+
+                5│  expect crash "boom"
+                    ^^^^^^^^^^^^^^^^^^^
+
+                It ran into this crash:
+
+                5│  expect crash "boom"
+                                 ^^^^^^
+
+                which reported this message:
+
+                    boom
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn lookup_integer() {
         run_expect_test(
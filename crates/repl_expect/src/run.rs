@@ -13,9 +13,16 @@ use roc_gen_llvm::{
 use roc_intern::{GlobalInterner, SingleThreadedInterner};
 use roc_load::{EntryPoint, Expectations, MonomorphizedModule};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
-use roc_mono::{ir::OptLevel, layout::Layout};
-use roc_region::all::Region;
-use roc_reporting::{error::expect::Renderer, report::RenderTarget};
+use roc_mono::{
+    ir::{CrashTag, OptLevel},
+    layout::Layout,
+};
+use roc_region::all::{LineInfo, Region};
+use roc_reporting::{
+    error::expect::{CrashSource, Renderer},
+    report::RenderTarget,
+};
+use roc_std::RocStr;
 use roc_target::TargetInfo;
 use target_lexicon::Triple;
 
@@ -181,11 +188,12 @@ fn run_expect_pure<'a, W: std::io::Write>(
 
     let sequence = ExpectSequence::new(shared_memory.ptr.cast());
 
-    let result: Result<(), String> = try_run_jit_function!(lib, expect.name, (), |v: ()| v);
+    let result: Result<(), (String, CrashTag)> =
+        try_run_jit_function!(lib, expect.name, (), |v: ()| v);
 
     let shared_memory_ptr: *const u8 = shared_memory.ptr.cast();
 
-    if result.is_err() || sequence.count_failures() > 0 {
+    if result.is_err() || sequence.count_frames() > 0 {
         let module_id = expect.symbol.module_id();
         let data = expectations.get_mut(&module_id).unwrap();
 
@@ -195,24 +203,26 @@ fn run_expect_pure<'a, W: std::io::Write>(
 
         let renderer = Renderer::new(arena, interns, render_target, module_id, filename, &source);
 
-        if let Err(roc_panic_message) = result {
-            renderer.render_panic(writer, &roc_panic_message, expect.region)?;
-        } else {
-            let mut offset = ExpectSequence::START_OFFSET;
-
-            for _ in 0..sequence.count_failures() {
-                offset += render_expect_failure(
-                    writer,
-                    &renderer,
-                    arena,
-                    Some(expect),
-                    expectations,
-                    interns,
-                    layout_interner,
-                    shared_memory_ptr,
-                    offset,
-                )?;
-            }
+        let failures = render_frames(
+            writer,
+            &renderer,
+            arena,
+            Some(expect),
+            expectations,
+            interns,
+            layout_interner,
+            lib,
+            shared_memory_ptr,
+            sequence.count_frames(),
+        )?;
+
+        if let Err((roc_panic_message, crash_tag)) = result {
+            let crash = crash_source(lib, interns, expectations, module_id, crash_tag)?;
+
+            renderer.render_panic(writer, &roc_panic_message, crash, expect.region)?;
+        } else if failures == 0 {
+            // only `dbg` frames were written; the expectation itself passed
+            return Ok(true);
         }
 
         writeln!(writer)?;
@@ -251,13 +261,14 @@ fn run_expect_fx<'a, W: std::io::Write>(
 
             child_memory.set_shared_buffer(lib);
 
-            let result: Result<(), String> = try_run_jit_function!(lib, expect.name, (), |v: ()| v);
+            let result: Result<(), (String, CrashTag)> =
+                try_run_jit_function!(lib, expect.name, (), |v: ()| v);
 
-            if let Err(msg) = result {
+            if let Err((msg, _)) = result {
                 panic!("roc panic {}", msg);
             }
 
-            if sequence.count_failures() > 0 {
+            if sequence.count_frames() > 0 {
                 libc::kill(parent_id() as _, SIGUSR1);
             }
 
@@ -281,8 +292,10 @@ fn run_expect_fx<'a, W: std::io::Write>(
                         return Ok(has_succeeded);
                     }
                     SIGUSR1 => {
-                        // this is the signal we use for an expect failure. Let's see what the child told us
-                        has_succeeded = false;
+                        // this is the signal we use for an expect failure or a dbg. Let's see what the child told us
+                        let sequence = ExpectSequence {
+                            ptr: parent_memory.ptr,
+                        };
 
                         let frame =
                             ExpectFrame::at_offset(parent_memory.ptr, ExpectSequence::START_OFFSET);
@@ -301,7 +314,7 @@ fn run_expect_fx<'a, W: std::io::Write>(
                             &source,
                         );
 
-                        render_expect_failure(
+                        let failures = render_frames(
                             writer,
                             &renderer,
                             arena,
@@ -309,9 +322,14 @@ fn run_expect_fx<'a, W: std::io::Write>(
                             expectations,
                             interns,
                             layout_interner,
+                            lib,
                             parent_memory.ptr,
-                            ExpectSequence::START_OFFSET,
+                            sequence.count_frames(),
                         )?;
+
+                        if failures > 0 {
+                            has_succeeded = false;
+                        }
                     }
                     _ => println!("received signal {}", sig),
                 }
@@ -323,6 +341,53 @@ fn run_expect_fx<'a, W: std::io::Write>(
     }
 }
 
+/// Finds out what crashed: the program writes the region and module of a `crash` to the
+/// `roc_crash_location` global before it calls `roc_panic`
+fn crash_source(
+    lib: &libloading::Library,
+    interns: &Interns,
+    expectations: &VecMap<ModuleId, Expectations>,
+    expect_module_id: ModuleId,
+    crash_tag: CrashTag,
+) -> std::io::Result<CrashSource> {
+    if crash_tag != CrashTag::User {
+        return Ok(CrashSource::Compiler);
+    }
+
+    let location = unsafe {
+        let location = lib
+            .get::<*const [u32; 3]>(b"roc_crash_location")
+            .expect("a program that can crash records where it crashed");
+
+        **location
+    };
+
+    let [start, end, module_id] = location;
+    let region: Region = unsafe { std::mem::transmute([start, end]) };
+    let module_id: ModuleId = unsafe { std::mem::transmute(module_id) };
+
+    if module_id == expect_module_id {
+        return Ok(CrashSource::User(region));
+    }
+
+    let location = match expectations.get(&module_id) {
+        Some(data) => {
+            let source = std::fs::read_to_string(&data.path)?;
+            let position = LineInfo::new(&source).convert_pos(region.start());
+
+            format!(
+                "{}:{}:{}",
+                data.path.display(),
+                position.line + 1,
+                position.column + 1
+            )
+        }
+        None => format!("module {}", interns.module_name(module_id)),
+    };
+
+    Ok(CrashSource::UserElsewhere(location))
+}
+
 pub fn roc_dev_expect<'a>(
     writer: &mut impl std::io::Write,
     arena: &'a Bump,
@@ -360,6 +425,131 @@ pub fn roc_dev_expect<'a>(
     )
 }
 
+/// Renders all frames that the program wrote to shared memory. Frames written by
+/// a `dbg` are reported through the `roc_dbg` hook; the number of expect failures
+/// is returned.
+#[allow(clippy::too_many_arguments)]
+fn render_frames<'a>(
+    writer: &mut impl std::io::Write,
+    renderer: &Renderer,
+    arena: &'a Bump,
+    expect: Option<ToplevelExpect>,
+    expectations: &mut VecMap<ModuleId, Expectations>,
+    interns: &'a Interns,
+    layout_interner: &Arc<GlobalInterner<'a, Layout<'a>>>,
+    lib: &libloading::Library,
+    start: *const u8,
+    count: usize,
+) -> std::io::Result<usize> {
+    let mut offset = ExpectSequence::START_OFFSET;
+    let mut failures = 0;
+
+    for _ in 0..count {
+        let frame = ExpectFrame::at_offset(start, offset);
+
+        let is_dbg = match expectations.get(&frame.module_id) {
+            Some(data) => data.dbgs.contains_key(&frame.region),
+            None => false,
+        };
+
+        offset = if is_dbg {
+            render_dbg(
+                arena,
+                expectations,
+                interns,
+                layout_interner,
+                lib,
+                start,
+                offset,
+            )?
+        } else {
+            failures += 1;
+
+            render_expect_failure(
+                writer,
+                renderer,
+                arena,
+                expect,
+                expectations,
+                interns,
+                layout_interner,
+                start,
+                offset,
+            )?
+        };
+    }
+
+    Ok(failures)
+}
+
+fn render_dbg<'a>(
+    arena: &'a Bump,
+    expectations: &mut VecMap<ModuleId, Expectations>,
+    interns: &'a Interns,
+    layout_interner: &Arc<GlobalInterner<'a, Layout<'a>>>,
+    lib: &libloading::Library,
+    start: *const u8,
+    offset: usize,
+) -> std::io::Result<usize> {
+    // we always run programs as the host
+    let target_info = (&target_lexicon::Triple::host()).into();
+
+    let frame = ExpectFrame::at_offset(start, offset);
+    let module_id = frame.module_id;
+
+    let data = expectations.get_mut(&module_id).unwrap();
+
+    let variable = match data.dbgs.get(&frame.region) {
+        None => panic!("region not in list of dbgs"),
+        Some(variable) => *variable,
+    };
+
+    let (offset, expressions) = crate::get_values(
+        target_info,
+        arena,
+        &data.subs,
+        interns,
+        layout_interner,
+        start,
+        frame.start_offset,
+        &[variable],
+    )
+    .unwrap();
+
+    let filename = data.path.to_owned();
+    let source = arena.alloc(std::fs::read_to_string(&data.path)?);
+
+    let renderer = Renderer::new(
+        arena,
+        interns,
+        RenderTarget::Generic,
+        module_id,
+        filename,
+        source,
+    );
+
+    let (location, value) = renderer.render_dbg(frame.region, &expressions[0]);
+
+    report_dbg(lib, &location, &value);
+
+    Ok(offset)
+}
+
+/// Calls the `roc_dbg` hook, which prints to stderr unless the host overrides it
+fn report_dbg(lib: &libloading::Library, location: &str, value: &str) {
+    type RocDbg = unsafe extern "C" fn(*const RocStr, *const RocStr);
+
+    match unsafe { lib.get::<RocDbg>(b"roc_dbg") } {
+        Ok(roc_dbg) => {
+            let location = RocStr::from(location);
+            let value = RocStr::from(value);
+
+            unsafe { roc_dbg(&location, &value) }
+        }
+        Err(_) => eprintln!("[{}] {}", location, value),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn render_expect_failure<'a>(
     writer: &mut impl std::io::Write,
@@ -438,7 +628,7 @@ impl ExpectSequence {
         }
     }
 
-    fn count_failures(&self) -> usize {
+    fn count_frames(&self) -> usize {
         unsafe { *(self.ptr as *const usize).add(Self::COUNT_INDEX) }
     }
 }
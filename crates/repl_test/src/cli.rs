@@ -1,6 +1,6 @@
 use std::env;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus, Stdio};
 
 use roc_repl_cli::{INSTRUCTIONS, WELCOME_MESSAGE};
@@ -8,6 +8,14 @@ use roc_test_utils::assert_multiline_str_eq;
 
 const ERROR_MESSAGE_START: char = '─';
 
+/// Every test runs against each of the REPL's backends
+#[cfg(not(feature = "dev-only"))]
+const BACKENDS: [&str; 2] = ["dev", "llvm"];
+
+/// Only the dev backend, which reports a problem instead of falling back to LLVM
+#[cfg(feature = "dev-only")]
+const BACKENDS: [&str; 1] = ["dev-only"];
+
 #[derive(Debug)]
 struct Out {
     stdout: String,
@@ -39,10 +47,13 @@ fn path_to_roc_binary() -> PathBuf {
     path
 }
 
-fn repl_eval(input: &str) -> Out {
+fn repl_eval(input: &str, backend: &str, dir: &Path) -> Out {
     let mut cmd = Command::new(path_to_roc_binary());
 
-    cmd.arg("repl");
+    cmd.arg("repl")
+        .arg("--backend")
+        .arg(backend)
+        .current_dir(dir);
 
     let mut child = cmd
         .stdin(Stdio::piped())
@@ -124,31 +135,61 @@ fn repl_eval(input: &str) -> Out {
 }
 
 pub fn expect_success(input: &str, expected: &str) {
-    let out = repl_eval(input);
+    expect_success_in_dir(&env::current_dir().unwrap(), input, expected);
+}
+
+/// Run the REPL in the given directory, e.g. to import the modules in it
+pub fn expect_success_in_dir(dir: &Path, input: &str, expected: &str) {
+    for backend in BACKENDS {
+        let out = repl_eval(input, backend, dir);
 
-    assert_multiline_str_eq!("", out.stderr.as_str());
-    assert_multiline_str_eq!(expected, out.stdout.as_str());
-    assert!(out.status.success());
+        assert_multiline_str_eq!("", out.stderr.as_str());
+        assert_multiline_str_eq!(expected, out.stdout.as_str());
+        assert!(out.status.success(), "the {} backend failed", backend);
+    }
 }
 
-pub fn expect_failure(input: &str, expected: &str) {
-    let out = repl_eval(input);
-
-    // there may be some other stuff printed (e.g. unification errors)
-    // so skip till the header of the first error
-    match out.stdout.find(ERROR_MESSAGE_START) {
-        Some(index) => {
-            assert_multiline_str_eq!("", out.stderr.as_str());
-            assert_multiline_str_eq!(expected, &out.stdout[index..]);
-            assert!(out.status.success());
-        }
-        None => {
-            assert_multiline_str_eq!("", out.stderr.as_str());
-            assert!(out.status.success());
-            panic!(
-                "I expected a failure, but there is no error message in stdout:\n\n{}",
-                &out.stdout
+/// For output that we can't compare in full, because it changes between runs or platforms,
+/// like the LLVM IR or timings
+pub fn expect_output_containing(input: &str, expected_parts: &[&str]) {
+    for backend in BACKENDS {
+        let out = repl_eval(input, backend, &env::current_dir().unwrap());
+
+        assert_multiline_str_eq!("", out.stderr.as_str());
+        assert!(out.status.success(), "the {} backend failed", backend);
+
+        for part in expected_parts {
+            assert!(
+                out.stdout.contains(part),
+                "Expected the output of the {} backend to contain {:?}, but it was:\n\n{}",
+                backend,
+                part,
+                out.stdout
             );
         }
     }
 }
+
+pub fn expect_failure(input: &str, expected: &str) {
+    for backend in BACKENDS {
+        let out = repl_eval(input, backend, &env::current_dir().unwrap());
+
+        // there may be some other stuff printed (e.g. unification errors)
+        // so skip till the header of the first error
+        match out.stdout.find(ERROR_MESSAGE_START) {
+            Some(index) => {
+                assert_multiline_str_eq!("", out.stderr.as_str());
+                assert_multiline_str_eq!(expected, &out.stdout[index..]);
+                assert!(out.status.success(), "the {} backend failed", backend);
+            }
+            None => {
+                assert_multiline_str_eq!("", out.stderr.as_str());
+                assert!(out.status.success(), "the {} backend failed", backend);
+                panic!(
+                    "I expected a failure from the {} backend, but there is no error message in stdout:\n\n{}",
+                    backend, &out.stdout
+                );
+            }
+        }
+    }
+}
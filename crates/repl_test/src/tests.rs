@@ -2,7 +2,10 @@
 use indoc::indoc;
 
 #[cfg(not(feature = "wasm"))]
-use crate::cli::{expect_failure, expect_success};
+use crate::cli::{expect_failure, expect_output_containing, expect_success, expect_success_in_dir};
+
+#[cfg(not(feature = "wasm"))]
+use std::path::PathBuf;
 
 #[cfg(feature = "wasm")]
 #[allow(unused_imports)]
@@ -506,6 +509,32 @@ fn basic_3_field_record() {
     );
 }
 
+#[test]
+fn basic_2_element_tuple() {
+    expect_success(r#"(42, "hi")"#, r#"(42, "hi") : ( Num *, Str )"#);
+}
+
+#[test]
+fn tuple_element_access() {
+    expect_success(r#"(1u8, "two", 3.5).1"#, r#""two" : Str"#);
+}
+
+#[test]
+fn nested_tuple() {
+    expect_success(
+        "((1u8, 2u16), { foo: (3u32, 4u64) })",
+        "((1, 2), { foo: (3, 4) }) : ( ( U8, U16 ), { foo : ( U32, U64 ) } )",
+    );
+}
+
+#[test]
+fn list_of_tuples() {
+    expect_success(
+        r#"[(1u8, "a"), (2u8, "b")]"#,
+        r#"[(1, "a"), (2, "b")] : List ( U8, Str )"#,
+    );
+}
+
 #[test]
 fn list_of_1_field_records() {
     // Even though these get unwrapped at runtime, the repl should still
@@ -588,7 +617,7 @@ fn too_few_args() {
         "Num.add 2",
         indoc!(
             r#"
-                ── TOO FEW ARGS ────────────────────────────────────────────────────────────────
+                ── TOO FEW ARGS [E3006] ────────────────────────────────────────────────────────
 
                 The add function expects 2 arguments, but it got only 1:
 
@@ -609,7 +638,7 @@ fn type_problem() {
         "1 + \"\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [E3025] ───────────────────────────────────────────────────────
 
                 This 2nd argument to add has an unexpected type:
 
@@ -895,15 +924,12 @@ fn parse_problem() {
         "add m n = m + n",
         indoc!(
             r#"
-                ── ARGUMENTS BEFORE EQUALS ─────────────────────────────────────────────────────
+                ── ARGUMENTS BEFORE EQUALS [E1001] ─────────────────────────────────────────────
 
                 I am partway through parsing a definition, but I got stuck here:
 
-                1│  app "app" provides [replOutput] to "./platform"
-                2│
-                3│  replOutput =
-                4│      add m n = m + n
-                            ^^^
+                1│  add m n = m + n
+                        ^^^
 
                 Looks like you are trying to define a function. In roc, functions are
                 always written as a lambda, like increment = \n -> n + 1.
@@ -927,7 +953,7 @@ fn exhaustiveness_problem() {
         ),
         indoc!(
             r#"
-            ── UNSAFE PATTERN ──────────────────────────────────────────────────────────────
+            ── UNSAFE PATTERN [E3011] ──────────────────────────────────────────────────────
 
             This when does not cover all the possibilities:
 
@@ -961,16 +987,16 @@ fn issue_2343_complete_mono_with_shadowed_vars() {
         ),
         indoc!(
             r#"
-                ── DUPLICATE NAME ──────────────────────────────────────────────────────────────
+                ── DUPLICATE NAME [E2013] ──────────────────────────────────────────────────────
 
                 The b name is first defined here:
 
-                4│      b = False
-                        ^
+                3│  b = False
+                    ^
 
                 But then it's defined a second time here:
 
-                5│      f = \b ->
+                6│      f = \b ->
                              ^
 
                 Since these variables have the same name, it's easy to use the wrong
@@ -980,6 +1006,256 @@ fn issue_2343_complete_mono_with_shadowed_vars() {
     );
 }
 
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn defs_persist_across_inputs() {
+    expect_success(
+        indoc!(
+            r#"
+            x = 5
+            double = \n -> n * 2
+            double x
+            "#
+        ),
+        "10 : Num *",
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn redefinition_replaces_def() {
+    expect_success(
+        indoc!(
+            r#"
+            x = 5
+            y = x + 1
+            x = 10
+            y
+            "#
+        ),
+        "11 : Num *",
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn show_defs() {
+    expect_success(
+        indoc!(
+            r#"
+            x = 5
+            Pair a : [Pair a a]
+            x = "five"
+            :defs
+            "#
+        ),
+        indoc!(
+            r#"
+            Pair a : [Pair a a]
+            x = "five""#
+        ),
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn reset_defs() {
+    expect_success(
+        indoc!(
+            r#"
+            x = 5
+            :reset
+            :defs
+            "#
+        ),
+        indoc!(
+            r#"
+            Cleared all definitions and imports.
+
+            No definitions or imports yet."#
+        ),
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn type_without_evaluating() {
+    expect_success(
+        indoc!(
+            r#"
+            double = \n -> n * 2
+            :type double 21
+            "#
+        ),
+        "double 21 : Num *",
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn type_of_function() {
+    expect_success(":type Str.concat", "Str.concat : Str, Str -> Str");
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn ir_of_expr() {
+    expect_output_containing(":ir 1 + 2", &["procedure ", "lowlevel NumAdd"]);
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn ir_of_type_problem() {
+    expect_failure(
+        r#":ir 1 + """#,
+        indoc!(
+            r#"
+                ── TYPE MISMATCH [E3025] ───────────────────────────────────────────────────────
+
+                This 2nd argument to add has an unexpected type:
+
+                4│      1 + ""
+                            ^^
+
+                The argument is a string of type:
+
+                    Str
+
+                But add needs its 2nd argument to be:
+
+                    Num a
+                "#
+        ),
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn llvm_ir_of_expr() {
+    expect_output_containing(":llvm 1 + 2", &["define "]);
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn llvm_ir_of_function() {
+    expect_success(
+        ":llvm Num.add",
+        "There is no LLVM IR for a function on its own; try calling it.",
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn time_evaluation() {
+    expect_output_containing(
+        indoc!(
+            r#"
+            :time
+            1 + 2
+            "#
+        ),
+        &["Timing is on.", "3 : Num *", " ms)"],
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn time_toggled_off() {
+    expect_success(
+        indoc!(
+            r#"
+            :time
+            :time
+            1 + 2
+            "#
+        ),
+        "Timing is on.\n\nTiming is off.\n\n3 : Num *",
+    );
+}
+
+/// Write `files` to a fresh directory for a test, and return its path
+#[cfg(not(feature = "wasm"))]
+fn write_test_dir(test_name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join("roc_repl_test").join(test_name);
+
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for (name, contents) in files {
+        std::fs::write(dir.join(name), contents).unwrap();
+    }
+
+    dir
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn load_file() {
+    let dir = write_test_dir(
+        "load_file",
+        &[(
+            "Defs.roc",
+            indoc!(
+                r#"
+                double = \n -> n * 2
+
+                answer = double 21
+                "#
+            ),
+        )],
+    );
+    let path = dir.join("Defs.roc");
+    let path = path.display();
+
+    expect_success(
+        &format!(":load {}\nanswer", path),
+        &format!("Loaded 2 definitions from {}.\n\n42 : Num *", path),
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn import_local_module() {
+    let dir = write_test_dir(
+        "import_local_module",
+        &[(
+            "Greeting.roc",
+            indoc!(
+                r#"
+                interface Greeting
+                    exposes [greet]
+                    imports []
+
+                greet = \name -> Str.concat "Hello, " name
+                "#
+            ),
+        )],
+    );
+
+    expect_success_in_dir(
+        &dir,
+        indoc!(
+            r#"
+            import Greeting
+            Greeting.greet "Roc"
+            "#
+        ),
+        r#""Hello, Roc" : Str"#,
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn load_missing_file() {
+    let dir = write_test_dir("load_missing_file", &[]);
+    let path = dir.join("Missing.roc");
+
+    expect_output_containing(
+        &format!(":load {}", path.display()),
+        &[&format!("I couldn't read {}", path.display())],
+    );
+}
+
 #[test]
 fn record_with_type_behind_alias() {
     expect_success(
@@ -216,6 +216,7 @@ pub async fn entrypoint_from_js(src: String) -> Result<String, String> {
             layout_interner: &layout_interner,
             module_id,
             stack_bytes: roc_gen_wasm::Env::DEFAULT_STACK_BYTES,
+            debug_sources: None,
             exposed_to_host: exposed_to_host
                 .values
                 .keys()
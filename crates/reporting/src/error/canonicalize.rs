@@ -1,6 +1,6 @@
 use roc_collections::all::MutSet;
 use roc_module::ident::{Ident, Lowercase, ModuleName};
-use roc_module::symbol::DERIVABLE_ABILITIES;
+use roc_module::symbol::{Symbol, DERIVABLE_ABILITIES};
 use roc_problem::can::PrecedenceProblem::BothNonAssociative;
 use roc_problem::can::{
     BadPattern, ExtensionTypeKind, FloatErrorKind, IntErrorKind, Problem, RuntimeError, ShadowKind,
@@ -9,6 +9,7 @@ use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Loc, Region};
 use roc_types::types::AliasKind;
 use std::path::PathBuf;
 
+use crate::error::explain::ErrorCode;
 use crate::error::r#type::suggest;
 use crate::report::{Annotation, Report, RocDocAllocator, RocDocBuilder, Severity};
 use ven_pretty::DocAllocator;
@@ -57,6 +58,12 @@ const ABILITY_IMPLEMENTATION_NOT_IDENTIFIER: &str = "ABILITY IMPLEMENTATION NOT
 const DUPLICATE_IMPLEMENTATION: &str = "DUPLICATE IMPLEMENTATION";
 const UNNECESSARY_IMPLEMENTATIONS: &str = "UNNECESSARY IMPLEMENTATIONS";
 const INCOMPLETE_ABILITY_IMPLEMENTATION: &str = "INCOMPLETE ABILITY IMPLEMENTATION";
+const MISSING_REQUIRED_ABILITY: &str = "MISSING REQUIRED ABILITY";
+const ILLEGAL_BUILTIN_IMPLEMENTATION: &str = "ILLEGAL BUILTIN IMPLEMENTATION";
+const FOREIGN_BUILTIN_IMPLEMENTATION: &str = "FOREIGN BUILTIN IMPLEMENTATION";
+const IMPLEMENTATION_NOT_ON_TOPLEVEL: &str = "IMPLEMENTATION NOT ON TOP-LEVEL";
+const UNAPPLIED_CRASH: &str = "UNAPPLIED CRASH";
+const OVERAPPLIED_CRASH: &str = "OVERAPPLIED CRASH";
 
 pub fn can_problem<'b>(
     alloc: &'b RocDocAllocator<'b>,
@@ -66,6 +73,7 @@ pub fn can_problem<'b>(
 ) -> Report<'b> {
     let doc;
     let title;
+    let code;
     let severity;
 
     match problem {
@@ -85,6 +93,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_DEF.to_string();
+            code = ErrorCode::UnusedDefinition;
             severity = Severity::Warning;
         }
         Problem::UnusedImport(module_id, region) => {
@@ -103,6 +112,27 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_IMPORT.to_string();
+            code = ErrorCode::UnusedImport;
+            severity = Severity::Warning;
+        }
+        Problem::UnusedExposedImport(symbol, region) => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" is imported from "),
+                    alloc.module(symbol.module_id()),
+                    alloc.reflow(", but it isn't used in this module."),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("Since "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" isn't used, you can remove it from the import."),
+                ]),
+            ]);
+
+            title = UNUSED_IMPORT.to_string();
+            code = ErrorCode::UnusedExposedImport;
             severity = Severity::Warning;
         }
         Problem::ExposedButNotDefined(symbol) => {
@@ -119,6 +149,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = MISSING_DEFINITION.to_string();
+            code = ErrorCode::MissingDefinition;
             severity = Severity::RuntimeError;
         }
         Problem::UnknownGeneratesWith(loc_ident) => {
@@ -134,9 +165,10 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNKNOWN_GENERATES_WITH.to_string();
+            code = ErrorCode::UnknownGeneratesFunction;
             severity = Severity::RuntimeError;
         }
-        Problem::UnusedArgument(closure_symbol, is_anonymous, argument_symbol, region) => {
+        Problem::UnusedArgument(closure_symbol, is_anonymous, argument_symbol, region, _) => {
             let line = "\". Adding an underscore at the start of a variable name is a way of saying that the variable is not used.";
 
             doc = alloc.stack([
@@ -169,6 +201,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_ARG.to_string();
+            code = ErrorCode::UnusedArgument;
             severity = Severity::Warning;
         }
         Problem::UnusedBranchDef(symbol, region) => {
@@ -190,6 +223,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_DEF.to_string();
+            code = ErrorCode::UnusedDefinition;
             severity = Severity::Warning;
         }
         Problem::PrecedenceProblem(BothNonAssociative(region, left_bin_op, right_bin_op)) => {
@@ -219,6 +253,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            code = ErrorCode::NonAssociativeOperators;
             severity = Severity::RuntimeError;
         }
         Problem::UnsupportedPattern(BadPattern::Unsupported(pattern_type), region) => {
@@ -250,6 +285,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            code = ErrorCode::UnsupportedPattern;
             severity = Severity::RuntimeError;
         }
         Problem::Shadowing {
@@ -260,6 +296,7 @@ pub fn can_problem<'b>(
             doc = report_shadowing(alloc, lines, original_region, shadow, kind);
 
             title = DUPLICATE_NAME.to_string();
+            code = ErrorCode::DuplicateName;
             severity = Severity::RuntimeError;
         }
         Problem::CyclicAlias(symbol, region, others, alias_kind) => {
@@ -269,6 +306,7 @@ pub fn can_problem<'b>(
 
             doc = answer.0;
             title = answer.1;
+            code = ErrorCode::CyclicAlias;
             severity = Severity::RuntimeError;
         }
         Problem::PhantomTypeArgument {
@@ -297,6 +335,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_ALIAS_PARAM.to_string();
+            code = ErrorCode::UnusedTypeAliasParameter;
             severity = Severity::RuntimeError;
         }
         Problem::UnboundTypeVariable {
@@ -334,11 +373,13 @@ pub fn can_problem<'b>(
             doc = alloc.stack(stack);
 
             title = UNBOUND_TYPE_VARIABLE.to_string();
+            code = ErrorCode::UnboundTypeVariable;
             severity = Severity::RuntimeError;
         }
         Problem::BadRecursion(entries) => {
             doc = to_circular_def_doc(alloc, lines, &entries);
             title = CIRCULAR_DEF.to_string();
+            code = ErrorCode::CircularDefinition;
             severity = Severity::RuntimeError;
         }
         Problem::DuplicateRecordFieldValue {
@@ -374,6 +415,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_FIELD_NAME.to_string();
+            code = ErrorCode::DuplicateFieldName;
             severity = Severity::Warning;
         }
         Problem::InvalidOptionalValue {
@@ -423,6 +465,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_FIELD_NAME.to_string();
+            code = ErrorCode::DuplicateFieldNameInType;
             severity = Severity::Warning;
         }
         Problem::DuplicateTag {
@@ -458,6 +501,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_TAG_NAME.to_string();
+            code = ErrorCode::DuplicateTagName;
             severity = Severity::Warning;
         }
         Problem::SignatureDefMismatch {
@@ -475,6 +519,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = NAMING_PROBLEM.to_string();
+            code = ErrorCode::SignatureDefMismatch;
             severity = Severity::RuntimeError;
         }
         Problem::InvalidAliasRigid {
@@ -498,6 +543,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            code = ErrorCode::InvalidAliasRigid;
             severity = Severity::RuntimeError;
         }
         Problem::InvalidHexadecimal(region) => {
@@ -515,6 +561,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_UNICODE.to_string();
+            code = ErrorCode::InvalidHexadecimal;
             severity = Severity::RuntimeError;
         }
         Problem::InvalidUnicodeCodePt(region) => {
@@ -525,6 +572,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_UNICODE.to_string();
+            code = ErrorCode::InvalidUnicodeCodePoint;
             severity = Severity::RuntimeError;
         }
         Problem::InvalidInterpolation(region) => {
@@ -542,6 +590,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            code = ErrorCode::InvalidInterpolation;
             severity = Severity::RuntimeError;
         }
         Problem::RuntimeError(runtime_error) => {
@@ -549,6 +598,7 @@ pub fn can_problem<'b>(
 
             doc = answer.0;
             title = answer.1.to_string();
+            code = answer.2;
             severity = Severity::RuntimeError;
         }
         Problem::NestedDatatype {
@@ -577,6 +627,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = NESTED_DATATYPE.to_string();
+            code = ErrorCode::NestedDatatype;
             severity = Severity::RuntimeError;
         }
 
@@ -605,6 +656,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_EXTENSION_TYPE.to_string();
+            code = ErrorCode::InvalidExtensionType;
             severity = Severity::RuntimeError;
         }
 
@@ -624,6 +676,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = ABILITY_HAS_TYPE_VARIABLES.to_string();
+            code = ErrorCode::AbilityHasTypeVariables;
             severity = Severity::RuntimeError;
         }
 
@@ -635,6 +688,7 @@ pub fn can_problem<'b>(
                 alloc.region(lines.convert_region(clause_region)),
             ]);
             title = HAS_CLAUSE_IS_NOT_AN_ABILITY.to_string();
+            code = ErrorCode::HasClauseIsNotAnAbility;
             severity = Severity::RuntimeError;
         }
 
@@ -654,6 +708,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = ILLEGAL_HAS_CLAUSE.to_string();
+            code = ErrorCode::IllegalHasClause;
             severity = Severity::RuntimeError;
         }
 
@@ -689,6 +744,7 @@ pub fn can_problem<'b>(
                     .reflow("Otherwise, the function does not need to be part of the ability!")]),
             ]);
             title = ABILITY_MEMBER_MISSING_HAS_CLAUSE.to_string();
+            code = ErrorCode::AbilityMemberMissingHasClause;
             severity = Severity::RuntimeError;
         }
 
@@ -717,6 +773,7 @@ pub fn can_problem<'b>(
                 ])
             ]);
             title = ABILITY_MEMBER_BINDS_MULTIPLE_VARIABLES.to_string();
+            code = ErrorCode::AbilityMemberBindsMultipleVariables;
             severity = Severity::RuntimeError;
         }
 
@@ -729,6 +786,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Abilities can only be defined on the top-level of a Roc module."),
             ]);
             title = ABILITY_NOT_ON_TOPLEVEL.to_string();
+            code = ErrorCode::AbilityNotOnToplevel;
             severity = Severity::RuntimeError;
         }
 
@@ -757,6 +815,7 @@ pub fn can_problem<'b>(
                 ])),
             ]);
             title = ABILITY_USED_AS_TYPE.to_string();
+            code = ErrorCode::AbilityUsedAsType;
             severity = Severity::RuntimeError;
         }
         Problem::NestedSpecialization(member, region) => {
@@ -770,6 +829,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Specializations can only be defined on the top-level of a module."),
             ]);
             title = SPECIALIZATION_NOT_ON_TOPLEVEL.to_string();
+            code = ErrorCode::SpecializationNotOnToplevel;
             severity = Severity::Warning;
         }
         Problem::IllegalDerivedAbility(region) => {
@@ -782,6 +842,7 @@ pub fn can_problem<'b>(
                     .append(list_builtin_abilities(alloc)),
             ]);
             title = ILLEGAL_DERIVE.to_string();
+            code = ErrorCode::IllegalDerive;
             severity = Severity::Warning;
         }
         Problem::NotAnAbility(region) => {
@@ -791,6 +852,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Only abilities can be implemented."),
             ]);
             title = NOT_AN_ABILITY.to_string();
+            code = ErrorCode::NotAnAbility;
             severity = Severity::Warning;
         }
         Problem::NotAnAbilityMember {
@@ -806,6 +868,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Only implementations for members an ability has can be specified in this location.")
             ]);
             title = NOT_AN_ABILITY_MEMBER.to_string();
+            code = ErrorCode::NotAnAbilityMember;
             severity = Severity::RuntimeError;
         }
         Problem::ImplementationNotFound { member, region } => {
@@ -818,6 +881,7 @@ pub fn can_problem<'b>(
                 alloc.tip().append(alloc.concat([alloc.reflow("consider adding a value of name "), alloc.symbol_unqualified(member), alloc.reflow(" in this scope, or using another variable that implements this ability member, like "), alloc.type_str(&format!("{{ {}: my{} }}", member_str, member_str))]))
             ]);
             title = IMPLEMENTATION_NOT_FOUND.to_string();
+            code = ErrorCode::ImplementationNotFound;
             severity = Severity::RuntimeError;
         }
         Problem::OptionalAbilityImpl { ability, region } => {
@@ -837,6 +901,7 @@ pub fn can_problem<'b>(
                 hint,
             ]);
             title = OPTIONAL_ABILITY_IMPLEMENTATION.to_string();
+            code = ErrorCode::OptionalAbilityImplementation;
             severity = Severity::RuntimeError;
         }
         Problem::QualifiedAbilityImpl { region } => {
@@ -848,6 +913,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = QUALIFIED_ABILITY_IMPLEMENTATION.to_string();
+            code = ErrorCode::QualifiedAbilityImplementation;
             severity = Severity::RuntimeError;
         }
         Problem::AbilityImplNotIdent { region } => {
@@ -860,6 +926,7 @@ pub fn can_problem<'b>(
                 alloc.tip().append(alloc.reflow("consider defining this expression as a variable."))
             ]);
             title = ABILITY_IMPLEMENTATION_NOT_IDENTIFIER.to_string();
+            code = ErrorCode::AbilityImplementationNotIdentifier;
             severity = Severity::RuntimeError;
         }
         Problem::DuplicateImpl {
@@ -875,6 +942,7 @@ pub fn can_problem<'b>(
                     .reflow("Only one custom implementation can be defined for an ability member."),
             ]);
             title = DUPLICATE_IMPLEMENTATION.to_string();
+            code = ErrorCode::DuplicateImplementation;
             severity = Severity::RuntimeError;
         }
         Problem::ImplementsNonRequired {
@@ -900,6 +968,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = UNNECESSARY_IMPLEMENTATIONS.to_string();
+            code = ErrorCode::UnnecessaryImplementations;
             severity = Severity::Warning;
         }
         Problem::DoesNotImplementAbility {
@@ -925,6 +994,131 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = INCOMPLETE_ABILITY_IMPLEMENTATION.to_string();
+            code = ErrorCode::IncompleteAbilityImplementation;
+            severity = Severity::RuntimeError;
+        }
+        Problem::MissingRequiredAbilities {
+            region,
+            ability,
+            missing,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This type implements the "),
+                    alloc.symbol_unqualified(ability),
+                    alloc.reflow(" ability, but not all the abilities it requires:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("Types implementing "),
+                    alloc.symbol_unqualified(ability),
+                    alloc.reflow(" must also implement:"),
+                ]),
+                alloc.type_block(alloc.intersperse(
+                    missing.into_iter().map(|sym| alloc.symbol_unqualified(sym)),
+                    alloc.string(",".to_string()).append(alloc.space()),
+                )),
+            ]);
+            title = MISSING_REQUIRED_ABILITY.to_string();
+            code = ErrorCode::MissingRequiredAbility;
+            severity = Severity::RuntimeError;
+        }
+        Problem::IllegalBuiltinImplementation { typ, region } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This declares ability implementations for "),
+                    alloc.symbol_unqualified(typ),
+                    alloc.reflow(":"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("Only type aliases defined in this module, number types like "),
+                    alloc.symbol_unqualified(Symbol::NUM_U64),
+                    alloc.reflow(", and the builtin types "),
+                    alloc.intersperse(
+                        [
+                            Symbol::STR_STR,
+                            Symbol::LIST_LIST,
+                            Symbol::BOX_BOX_TYPE,
+                            Symbol::DICT_DICT,
+                            Symbol::SET_SET,
+                            Symbol::RESULT_RESULT,
+                        ]
+                        .into_iter()
+                        .map(|sym| alloc.symbol_unqualified(sym)),
+                        alloc.reflow(", "),
+                    ),
+                    alloc.reflow(" can implement abilities this way."),
+                ]),
+                alloc.tip().append(alloc.concat([
+                    alloc.reflow("Opaque types declare the abilities they implement with a "),
+                    alloc.keyword("has"),
+                    alloc.reflow(" clause in their definition."),
+                ])),
+            ]);
+            title = ILLEGAL_BUILTIN_IMPLEMENTATION.to_string();
+            code = ErrorCode::IllegalBuiltinImplementation;
+            severity = Severity::RuntimeError;
+        }
+        Problem::ForeignBuiltinImplementation {
+            typ,
+            ability,
+            region,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This implements the "),
+                    alloc.symbol_unqualified(ability),
+                    alloc.reflow(" ability for "),
+                    alloc.symbol_unqualified(typ),
+                    alloc.reflow(" outside of the module defining "),
+                    alloc.symbol_unqualified(ability),
+                    alloc.reflow(":"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow(
+                    "Builtin types can only implement abilities in the module defining the \
+                    ability, so that each type has at most one implementation of it.",
+                ),
+            ]);
+            title = FOREIGN_BUILTIN_IMPLEMENTATION.to_string();
+            code = ErrorCode::ForeignBuiltinImplementation;
+            severity = Severity::RuntimeError;
+        }
+        Problem::DuplicateBuiltinImplementation {
+            typ,
+            ability,
+            original,
+            duplicate,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This implements the "),
+                    alloc.symbol_unqualified(ability),
+                    alloc.reflow(" ability for "),
+                    alloc.symbol_unqualified(typ),
+                    alloc.reflow(" again:"),
+                ]),
+                alloc.region(lines.convert_region(duplicate)),
+                alloc.reflow("The first implementation was declared here:"),
+                alloc.region(lines.convert_region(original)),
+                alloc.reflow("A type can only have one implementation of an ability."),
+            ]);
+            title = DUPLICATE_IMPLEMENTATION.to_string();
+            code = ErrorCode::DuplicateBuiltinImplementation;
+            severity = Severity::RuntimeError;
+        }
+        Problem::BuiltinImplementationNotOnToplevel { region } => {
+            doc = alloc.stack([
+                alloc.reflow("This ability implementation is not on the top-level of a module:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow(
+                    "Abilities can only be implemented for builtin types on the top-level of a \
+                    Roc module.",
+                ),
+            ]);
+            title = IMPLEMENTATION_NOT_ON_TOPLEVEL.to_string();
+            code = ErrorCode::ImplementationNotOnToplevel;
             severity = Severity::RuntimeError;
         }
         Problem::NotBoundInAllPatterns {
@@ -946,6 +1140,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "NAME NOT BOUND IN ALL PATTERNS".to_string();
+            code = ErrorCode::NameNotBoundInAllPatterns;
             severity = Severity::RuntimeError;
         }
         Problem::NoIdentifiersIntroduced(region) => {
@@ -955,8 +1150,23 @@ pub fn can_problem<'b>(
                 alloc.reflow("If you don't need to use the value on the right-hand-side of this assignment, consider removing the assignment. Since Roc is purely functional, assignments that don't introduce variables cannot affect a program's behavior!"),
             ]);
             title = "UNNECESSARY DEFINITION".to_string();
+            code = ErrorCode::UnnecessaryDefinition;
             severity = Severity::Warning;
         }
+        Problem::MultipleListRestPattern { region } => {
+            doc = alloc.stack([
+                alloc.reflow("This list pattern matches multiple sublists:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("Each list pattern can only have one "),
+                    alloc.parser_suggestion(".."),
+                    alloc.reflow(", so I'm not sure how to match the elements around them."),
+                ]),
+            ]);
+            title = "MULTIPLE LIST REST PATTERNS".to_string();
+            code = ErrorCode::MultipleListRestPatterns;
+            severity = Severity::RuntimeError;
+        }
         Problem::OverloadedSpecialization {
             ability_member,
             overload,
@@ -975,12 +1185,46 @@ pub fn can_problem<'b>(
                 alloc.reflow("Ability specializations can only provide implementations for one opaque type, since all opaque types are different!"),
             ]);
             title = "OVERLOADED SPECIALIZATION".to_string();
+            code = ErrorCode::OverloadedSpecialization;
             severity = Severity::Warning;
         }
+        Problem::UnappliedCrash { region } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "), alloc.keyword("crash"), alloc.reflow(" doesn't have a message given to it:")
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.keyword("crash"), alloc.reflow(" must be passed a message to crash with at the exact place it's used. "),
+                    alloc.keyword("crash"), alloc.reflow(" can't be used as a value that's passed around, like functions can be - it must be applied immediately!"),
+                ])
+            ]);
+            title = UNAPPLIED_CRASH.to_string();
+            code = ErrorCode::UnappliedCrash;
+            severity = Severity::RuntimeError;
+        }
+        Problem::OverAppliedCrash { region } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword("crash"),
+                    alloc.reflow(" has too many values given to it:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.keyword("crash"),
+                    alloc.reflow(" must be given exactly one message to crash with."),
+                ]),
+            ]);
+            title = OVERAPPLIED_CRASH.to_string();
+            code = ErrorCode::OverappliedCrash;
+            severity = Severity::RuntimeError;
+        }
     };
 
     Report {
         title,
+        code: Some(code),
         filename,
         doc,
         severity,
@@ -1012,6 +1256,7 @@ fn to_invalid_optional_value_report<'b>(
 
     Report {
         title: "BAD OPTIONAL VALUE".to_string(),
+        code: Some(ErrorCode::BadOptionalValue),
         filename,
         doc,
         severity: Severity::RuntimeError,
@@ -1388,9 +1633,10 @@ fn pretty_runtime_error<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
     runtime_error: RuntimeError,
-) -> (RocDocBuilder<'b>, &'static str) {
+) -> (RocDocBuilder<'b>, &'static str, ErrorCode) {
     let doc;
     let title;
+    let code;
 
     match runtime_error {
         RuntimeError::VoidValue => {
@@ -1411,15 +1657,18 @@ fn pretty_runtime_error<'b>(
         } => {
             doc = report_shadowing(alloc, lines, original_region, shadow, kind);
             title = DUPLICATE_NAME;
+            code = ErrorCode::DuplicateName;
         }
 
         RuntimeError::LookupNotInScope(loc_name, options) => {
             doc = not_found(alloc, lines, loc_name.region, &loc_name.value, options);
             title = UNRECOGNIZED_NAME;
+            code = ErrorCode::UnrecognizedName;
         }
         RuntimeError::CircularDef(entries) => {
             doc = to_circular_def_doc(alloc, lines, &entries);
             title = CIRCULAR_DEF;
+            code = ErrorCode::CircularDefinition;
         }
         RuntimeError::MalformedPattern(problem, region) => {
             use roc_parse::ast::Base;
@@ -1434,9 +1683,10 @@ fn pretty_runtime_error<'b>(
                 MalformedBase(Base::Decimal) => " integer ",
                 BadIdent(bad_ident) => {
                     title = NAMING_PROBLEM;
+                    code = ErrorCode::MalformedPatternName;
                     doc = to_bad_ident_pattern_report(alloc, lines, bad_ident, region);
 
-                    return (doc, title);
+                    return (doc, title, code);
                 }
                 Unknown => " ",
                 QualifiedIdentifier => " qualified ",
@@ -1467,6 +1717,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::MalformedPattern;
         }
         RuntimeError::UnsupportedPattern(_) => {
             todo!("unsupported patterns are currently not parsed!")
@@ -1508,6 +1759,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = VALUE_NOT_EXPOSED;
+            code = ErrorCode::NotExposed;
         }
 
         RuntimeError::ModuleNotImported {
@@ -1526,6 +1778,7 @@ fn pretty_runtime_error<'b>(
             );
 
             title = MODULE_NOT_IMPORTED;
+            code = ErrorCode::ModuleNotImported;
         }
         RuntimeError::InvalidPrecedence(_, _) => {
             // do nothing, reported with PrecedenceProblem
@@ -1535,6 +1788,7 @@ fn pretty_runtime_error<'b>(
             doc = to_bad_ident_expr_report(alloc, lines, bad_ident, surroundings);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::MalformedIdentifier;
         }
         RuntimeError::MalformedTypeName(_box_str, surroundings) => {
             doc = alloc.stack([
@@ -1551,6 +1805,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::MalformedTypeName;
         }
         RuntimeError::MalformedClosure(_) => {
             todo!("");
@@ -1585,6 +1840,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::FloatOutOfRange;
         }
         RuntimeError::InvalidFloat(FloatErrorKind::Error, region, _raw_str) => {
             let tip = alloc
@@ -1603,6 +1859,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::InvalidFloatLiteral;
         }
         RuntimeError::InvalidFloat(FloatErrorKind::IntSuffix, region, _raw_str) => {
             doc = alloc.stack([
@@ -1613,6 +1870,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = CONFLICTING_NUMBER_SUFFIX;
+            code = ErrorCode::ConflictingNumberSuffix;
         }
         RuntimeError::InvalidInt(error @ IntErrorKind::InvalidDigit, base, region, _raw_str)
         | RuntimeError::InvalidInt(error @ IntErrorKind::Empty, base, region, _raw_str) => {
@@ -1674,6 +1932,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::InvalidIntLiteral;
         }
         RuntimeError::InvalidInt(error_kind @ IntErrorKind::Underflow, _base, region, _raw_str)
         | RuntimeError::InvalidInt(error_kind @ IntErrorKind::Overflow, _base, region, _raw_str) => {
@@ -1717,6 +1976,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::IntOutOfRange;
         }
         RuntimeError::InvalidInt(IntErrorKind::FloatSuffix, _base, region, _raw_str) => {
             doc = alloc.stack([
@@ -1727,6 +1987,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = CONFLICTING_NUMBER_SUFFIX;
+            code = ErrorCode::ConflictingNumberSuffix;
         }
         RuntimeError::InvalidInt(
             IntErrorKind::OverflowsSuffix {
@@ -1751,6 +2012,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = NUMBER_OVERFLOWS_SUFFIX;
+            code = ErrorCode::NumberOverflowsSuffix;
         }
         RuntimeError::InvalidInt(
             IntErrorKind::UnderflowsSuffix {
@@ -1775,6 +2037,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = NUMBER_UNDERFLOWS_SUFFIX;
+            code = ErrorCode::NumberUnderflowsSuffix;
         }
         RuntimeError::InvalidOptionalValue {
             field_name,
@@ -1790,6 +2053,7 @@ fn pretty_runtime_error<'b>(
             );
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::OptionalValueInRecord;
         }
         RuntimeError::InvalidRecordUpdate { region } => {
             doc = alloc.stack([
@@ -1802,6 +2066,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::InvalidRecordUpdate;
         }
         RuntimeError::InvalidHexadecimal(region) => {
             todo!(
@@ -1835,6 +2100,7 @@ fn pretty_runtime_error<'b>(
                 .append(alloc.reflow(", but it was not defined anywhere in that module."))]);
 
             title = MISSING_DEFINITION;
+            code = ErrorCode::MissingDefinition;
         }
         RuntimeError::EmptySingleQuote(region) => {
             let tip = alloc
@@ -1848,6 +2114,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::EmptyCharacterLiteral;
         }
         RuntimeError::MultipleCharsInSingleQuote(region) => {
             let tip = alloc
@@ -1864,6 +2131,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = ErrorCode::MultipleCharactersInLiteral;
         }
         RuntimeError::OpaqueNotDefined {
             usage:
@@ -1914,6 +2182,7 @@ fn pretty_runtime_error<'b>(
             doc = alloc.stack(stack);
 
             title = OPAQUE_NOT_DEFINED;
+            code = ErrorCode::OpaqueNotDefined;
         }
         RuntimeError::OpaqueOutsideScope {
             opaque,
@@ -1935,6 +2204,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_DECLARED_OUTSIDE_SCOPE;
+            code = ErrorCode::OpaqueDeclaredOutsideScope;
         }
         RuntimeError::OpaqueNotApplied(loc_ident) => {
             doc = alloc.stack([
@@ -1944,6 +2214,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_NOT_APPLIED;
+            code = ErrorCode::OpaqueNotApplied;
         }
         RuntimeError::OpaqueAppliedToMultipleArgs(region) => {
             doc = alloc.stack([
@@ -1953,6 +2224,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_OVER_APPLIED;
+            code = ErrorCode::OpaqueAppliedToTooManyArgs;
         }
         RuntimeError::DegenerateBranch(region) => {
             doc = alloc.stack([
@@ -1961,10 +2233,11 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "DEGENERATE BRANCH";
+            code = ErrorCode::DegenerateBranch;
         }
     }
 
-    (doc, title)
+    (doc, title, code)
 }
 
 pub fn to_circular_def_doc<'b>(
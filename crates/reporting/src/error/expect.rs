@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use bumpalo::Bump;
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_parse::ast::Expr;
-use roc_region::all::{LineColumnRegion, LineInfo, Region};
+use roc_region::all::{LineColumnRegion, LineInfo, Loc, Region};
 use roc_types::{
     subs::{Subs, Variable},
     types::ErrorType,
@@ -11,6 +11,16 @@ use roc_types::{
 
 use crate::report::{RenderTarget, RocDocAllocator, RocDocBuilder};
 
+/// What made an expectation crash
+pub enum CrashSource {
+    /// A `crash` in the module being rendered, at the given region
+    User(Region),
+    /// A `crash` in another module, at the given location
+    UserElsewhere(String),
+    /// A crash the compiler inserted, e.g. for code that did not compile
+    Compiler,
+}
+
 pub struct Renderer<'a> {
     arena: &'a Bump,
     alloc: RocDocAllocator<'a>,
@@ -145,6 +155,7 @@ impl<'a> Renderer<'a> {
 
         let report = Report {
             title: "EXPECT FAILED".into(),
+            code: None,
             doc,
             filename: self.filename.clone(),
             severity: crate::report::Severity::RuntimeError,
@@ -162,10 +173,25 @@ impl<'a> Renderer<'a> {
         write!(writer, "{}", buf)
     }
 
+    /// Renders the `file:line` location of a `dbg` and the value it reported. Strings are escaped
+    /// like compiled programs render them, so that a `dbg` reads the same in `roc test`.
+    pub fn render_dbg(&self, dbg_region: Region, expr: &Expr<'a>) -> (String, String) {
+        use roc_fmt::annotation::Formattable;
+
+        let line = self.line_info.convert_pos(dbg_region.start()).line + 1;
+        let location = format!("{}:{}", self.filename.display(), line);
+
+        let mut buf = roc_fmt::Buf::new_in(self.arena);
+        escape_strings(self.arena, expr).format(&mut buf, 0);
+
+        (location, buf.into_bump_str().to_string())
+    }
+
     pub fn render_panic<W>(
         &self,
         writer: &mut W,
         message: &str,
+        crash: CrashSource,
         expect_region: Region,
     ) -> std::io::Result<()>
     where
@@ -176,15 +202,39 @@ impl<'a> Renderer<'a> {
 
         let line_col_region = self.line_info.convert_region(expect_region);
 
+        let crash_doc = match crash {
+            CrashSource::User(crash_region) => self.alloc.stack([
+                self.alloc.concat([
+                    self.alloc.reflow("It ran into this "),
+                    self.alloc.keyword("crash"),
+                    self.alloc.reflow(":"),
+                ]),
+                self.alloc
+                    .region(self.line_info.convert_region(crash_region)),
+                self.alloc.reflow("which reported this message:"),
+            ]),
+            CrashSource::UserElsewhere(location) => self.alloc.concat([
+                self.alloc.reflow("It ran into a "),
+                self.alloc.keyword("crash"),
+                self.alloc.reflow(" at "),
+                self.alloc.text(location),
+                self.alloc.reflow(", which reported this message:"),
+            ]),
+            CrashSource::Compiler => self.alloc.reflow(
+                "It ran into a crash that the compiler inserted, which reported this message:",
+            ),
+        };
+
         let doc = self.alloc.stack([
             self.alloc.text("This expectation crashed while running:"),
             self.alloc.region(line_col_region),
-            self.alloc.text("The crash reported this message:"),
-            self.alloc.text(message),
+            crash_doc,
+            self.alloc.text(message).indent(4),
         ]);
 
         let report = Report {
             title: "EXPECT PANICKED".into(),
+            code: None,
             doc,
             filename: self.filename.clone(),
             severity: crate::report::Severity::RuntimeError,
@@ -202,3 +252,75 @@ impl<'a> Renderer<'a> {
         write!(writer, "{}", buf)
     }
 }
+
+/// Turns the strings in `expr` into one-line literals, where the characters that cannot appear in
+/// a string literal as such are escaped
+fn escape_strings<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Expr<'a> {
+    use roc_parse::ast::{AssignedField, Collection, EscapedChar, StrLiteral, StrSegment};
+
+    let escape_all = |items: &[&'a Loc<Expr<'a>>]| {
+        let escaped = items
+            .iter()
+            .map(|item| &*arena.alloc(Loc::at(item.region, escape_strings(arena, &item.value))));
+
+        bumpalo::collections::Vec::from_iter_in(escaped, arena).into_bump_slice()
+    };
+
+    match expr {
+        Expr::Str(StrLiteral::PlainLine(string)) => {
+            let mut segments = bumpalo::collections::Vec::new_in(arena);
+            let mut plain_start = 0;
+
+            for (index, character) in string.char_indices() {
+                let escaped = match character {
+                    '\\' => EscapedChar::Backslash,
+                    '"' => EscapedChar::Quote,
+                    '\n' => EscapedChar::Newline,
+                    '\r' => EscapedChar::CarriageReturn,
+                    '\t' => EscapedChar::Tab,
+                    _ => continue,
+                };
+
+                if plain_start < index {
+                    segments.push(StrSegment::Plaintext(&string[plain_start..index]));
+                }
+                segments.push(StrSegment::EscapedChar(escaped));
+                plain_start = index + 1;
+            }
+
+            if segments.is_empty() {
+                return *expr;
+            }
+
+            if plain_start < string.len() {
+                segments.push(StrSegment::Plaintext(&string[plain_start..]));
+            }
+
+            Expr::Str(StrLiteral::Line(segments.into_bump_slice()))
+        }
+        Expr::Apply(function, arguments, called_via) => {
+            Expr::Apply(function, escape_all(arguments), *called_via)
+        }
+        Expr::List(items) => Expr::List(Collection::with_items(escape_all(items.items))),
+        Expr::Tuple(items) => Expr::Tuple(Collection::with_items(escape_all(items.items))),
+        Expr::Record(fields) => {
+            let escaped = fields.items.iter().map(|field| match field.value {
+                AssignedField::RequiredValue(label, spaces, value) => {
+                    let value =
+                        arena.alloc(Loc::at(value.region, escape_strings(arena, &value.value)));
+
+                    Loc::at(
+                        field.region,
+                        AssignedField::RequiredValue(label, spaces, value),
+                    )
+                }
+                _ => *field,
+            });
+
+            let escaped = bumpalo::collections::Vec::from_iter_in(escaped, arena);
+
+            Expr::Record(Collection::with_items(escaped.into_bump_slice()))
+        }
+        _ => *expr,
+    }
+}
@@ -0,0 +1,1444 @@
+//! The error codes shown in report headers, and the longer explanations that
+//! `roc explain <code>` prints for them.
+//!
+//! Every kind of report gets its own code, which is attached where the report is built, so
+//! reports that share a title (like `TYPE MISMATCH`) can still be told apart.
+//!
+//! Codes are stable: once a code is handed out it keeps meaning the same kind of report, so
+//! new entries get the next free number in their group rather than being slotted in between.
+//! Codes starting with `E1` are for syntax errors, `E2` for naming and canonicalization
+//! problems, and `E3` for type problems.
+
+macro_rules! error_codes {
+    ($($variant:ident = $code:literal,)*) => {
+        /// The kind of problem a report is about.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ErrorCode {
+            $($variant,)*
+        }
+
+        impl ErrorCode {
+            /// Every code, in order.
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$variant,)*];
+
+            /// The code as shown in report headers, like `E3010`.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(ErrorCode::$variant => $code,)*
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    // Syntax errors
+    ArgumentsBeforeEquals = "E1001",
+    BadBackpassingArrow = "E1002",
+    BadRequires = "E1003",
+    BadRequiresRigids = "E1004",
+    BadTypeVariable = "E1005",
+    DoubleComma = "E1006",
+    DoubleDot = "E1007",
+    EmptyParentheses = "E1008",
+    UnfinishedQualifiedType = "E1009",
+    EndlessFormat = "E1010",
+    EndlessString = "E1011",
+    IfGuardNoCondition = "E1012",
+    IncompleteHeader = "E1013",
+    InsufficientIndentInMultilineString = "E1014",
+    InvalidNumberLiteral = "E1015",
+    LambdaMissingArrow = "E1016",
+    MissingExpression = "E1017",
+    MissingFinalExpression = "E1018",
+    MissingHeader = "E1019",
+    MissingPackages = "E1020",
+    MissingRequires = "E1021",
+    RecordTypeNeedsMoreIndentation = "E1022",
+    NotAnInlineAlias = "E1023",
+    NotEndOfFile = "E1024",
+    ParseProblem = "E1025",
+    ProblemInRecordPattern = "E1026",
+    ProblemInRecordType = "E1027",
+    QualifiedAliasName = "E1028",
+    RecordParseProblem = "E1029",
+    NonAssociativeOperators = "E1030",
+    TabCharacter = "E1031",
+    TrailingDot = "E1032",
+    TypeArgumentNotLowercase = "E1033",
+    UnexpectedArrow = "E1034",
+    UnfinishedAbility = "E1035",
+    UnfinishedArgumentList = "E1036",
+    UnfinishedFunction = "E1037",
+    UnfinishedIf = "E1038",
+    UnfinishedInlineAlias = "E1039",
+    UnfinishedList = "E1040",
+    UnfinishedParentheses = "E1041",
+    UnfinishedPattern = "E1042",
+    UnfinishedRecordPattern = "E1043",
+    UnfinishedRecordType = "E1044",
+    UnfinishedTagUnionType = "E1045",
+    UnfinishedType = "E1046",
+    UnfinishedWhen = "E1047",
+    UnknownOperator = "E1048",
+    WeirdAppName = "E1049",
+    WeirdArrow = "E1050",
+    WeirdCodePoint = "E1051",
+    WeirdEscape = "E1052",
+    WeirdExposes = "E1053",
+    WeirdGeneratedTypeName = "E1054",
+    WeirdGenerates = "E1055",
+    WeirdIdentifier = "E1056",
+    WeirdImports = "E1057",
+    WeirdModuleName = "E1058",
+    WeirdProvides = "E1059",
+    WeirdQualifiedName = "E1060",
+    WeirdTagName = "E1061",
+    WhenMissingArrow = "E1062",
+    UnexpectedTokensBeforeEquals = "E1063",
+    UnexpectedEndOfFile = "E1064",
+    OutdentedTooFar = "E1065",
+    UnfinishedPatternParentheses = "E1066",
+    UnfinishedTypeParentheses = "E1067",
+    UnfinishedAsPattern = "E1068",
+    RecordPatternNeedsMoreIndentation = "E1069",
+    PatternParenthesesNeedMoreIndentation = "E1070",
+    TagUnionTypeNeedsMoreIndentation = "E1071",
+    TypeParenthesesNeedMoreIndentation = "E1072",
+    WeirdPlatformName = "E1073",
+    WeirdPackageName = "E1074",
+    WeirdImportedModuleName = "E1075",
+    WeirdTagNameInParentheses = "E1076",
+    UnexpectedExpressionEnd = "E1077",
+
+    // Naming and canonicalization problems
+    AbilityHasTypeVariables = "E2001",
+    AbilityImplementationNotIdentifier = "E2002",
+    AbilityMemberBindsMultipleVariables = "E2003",
+    AbilityMemberMissingHasClause = "E2004",
+    AbilityNotOnToplevel = "E2005",
+    AbilityUsedAsType = "E2006",
+    BadOptionalValue = "E2007",
+    CircularDefinition = "E2008",
+    ConflictingNumberSuffix = "E2009",
+    DegenerateBranch = "E2010",
+    DuplicateFieldName = "E2011",
+    DuplicateImplementation = "E2012",
+    DuplicateName = "E2013",
+    DuplicateTagName = "E2014",
+    ForeignBuiltinImplementation = "E2015",
+    HasClauseIsNotAnAbility = "E2016",
+    IllegalBuiltinImplementation = "E2017",
+    IllegalDerive = "E2018",
+    IllegalHasClause = "E2019",
+    ImplementationNotFound = "E2020",
+    ImplementationNotOnToplevel = "E2021",
+    IncompleteAbilityImplementation = "E2022",
+    InvalidUnicodeCodePoint = "E2023",
+    InvalidExtensionType = "E2024",
+    MissingDefinition = "E2025",
+    MissingRequiredAbility = "E2026",
+    ModuleNotImported = "E2027",
+    MultipleListRestPatterns = "E2028",
+    NameNotBoundInAllPatterns = "E2029",
+    SignatureDefMismatch = "E2030",
+    NestedDatatype = "E2031",
+    NotAnAbility = "E2032",
+    NotAnAbilityMember = "E2033",
+    NotExposed = "E2034",
+    NumberOverflowsSuffix = "E2035",
+    NumberUnderflowsSuffix = "E2036",
+    OpaqueAppliedToTooManyArgs = "E2037",
+    OpaqueDeclaredOutsideScope = "E2038",
+    OpaqueNotApplied = "E2039",
+    OpaqueNotDefined = "E2040",
+    OptionalAbilityImplementation = "E2041",
+    OverappliedCrash = "E2042",
+    OverloadedSpecialization = "E2043",
+    QualifiedAbilityImplementation = "E2044",
+    SpecializationNotOnToplevel = "E2045",
+    UnappliedCrash = "E2046",
+    UnboundTypeVariable = "E2047",
+    UnknownGeneratesFunction = "E2048",
+    UnnecessaryDefinition = "E2049",
+    UnnecessaryImplementations = "E2050",
+    UnrecognizedName = "E2051",
+    UnusedArgument = "E2052",
+    UnusedDefinition = "E2053",
+    UnusedImport = "E2054",
+    UnusedTypeAliasParameter = "E2055",
+    UnusedExposedImport = "E2056",
+    UnsupportedPattern = "E2057",
+    DuplicateFieldNameInType = "E2058",
+    InvalidAliasRigid = "E2059",
+    InvalidHexadecimal = "E2060",
+    InvalidInterpolation = "E2061",
+    DuplicateBuiltinImplementation = "E2062",
+    MalformedPatternName = "E2063",
+    MalformedPattern = "E2064",
+    MalformedIdentifier = "E2065",
+    MalformedTypeName = "E2066",
+    FloatOutOfRange = "E2067",
+    InvalidFloatLiteral = "E2068",
+    InvalidIntLiteral = "E2069",
+    IntOutOfRange = "E2070",
+    OptionalValueInRecord = "E2071",
+    InvalidRecordUpdate = "E2072",
+    EmptyCharacterLiteral = "E2073",
+    MultipleCharactersInLiteral = "E2074",
+
+    // Type problems
+    CircularType = "E3001",
+    CyclicAlias = "E3002",
+    IllegalSpecialization = "E3003",
+    InferredHole = "E3004",
+    RedundantPattern = "E3005",
+    TooFewArgs = "E3006",
+    TooFewTypeArguments = "E3007",
+    TooManyArgs = "E3008",
+    TooManyTypeArguments = "E3009",
+    TypeMismatch = "E3010",
+    NonExhaustiveWhen = "E3011",
+    WrongSpecializationType = "E3012",
+    AnnotationMismatch = "E3013",
+    ExpectConditionNotBool = "E3014",
+    CrashMessageNotStr = "E3015",
+    IfConditionNotBool = "E3016",
+    WhenGuardNotBool = "E3017",
+    IfBranchMismatch = "E3018",
+    WhenBranchMismatch = "E3019",
+    ListElementMismatch = "E3020",
+    RecordUpdateValueMismatch = "E3021",
+    RecordUpdateMismatch = "E3022",
+    RecordUpdateNotRecord = "E3023",
+    CalledNonFunction = "E3024",
+    ArgumentMismatch = "E3025",
+    NumericSuffixMismatch = "E3026",
+    InvalidAbilityMemberSpecialization = "E3027",
+    GeneralizedAbilityMemberSpecialization = "E3028",
+    WhenConditionMismatch = "E3029",
+    TypedArgumentMismatch = "E3030",
+    ExpressionMissingAbility = "E3031",
+    PatternMissingAbility = "E3032",
+    UnfulfilledAbility = "E3033",
+    UnexposedName = "E3034",
+    PatternMismatch = "E3035",
+    ArgumentPatternMismatch = "E3036",
+    WhenPatternMismatch = "E3037",
+    MissingRecordField = "E3038",
+    RecordUpdateUnknownField = "E3039",
+    NonExhaustiveArgument = "E3040",
+    NonExhaustiveDestructure = "E3041",
+}
+
+/// The explanation of one kind of report.
+pub struct Explanation {
+    /// The title of the reports with this code, like `TYPE MISMATCH`.
+    pub title: &'static str,
+    /// What the problem is.
+    pub summary: &'static str,
+    /// A small program that has the problem.
+    pub example: &'static str,
+    /// How to make the problem go away.
+    pub fix: &'static str,
+}
+
+impl ErrorCode {
+    /// The code for a string like `E3010`, ignoring case, so that `e3010` is found too.
+    pub fn from_str_ignore_case(code: &str) -> Option<ErrorCode> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|error_code| error_code.as_str().eq_ignore_ascii_case(code))
+    }
+
+    /// What `roc explain` says about reports with this code.
+    pub fn explanation(self) -> Explanation {
+        use ErrorCode::*;
+
+        match self {
+            ArgumentsBeforeEquals => Explanation {
+                title: "ARGUMENTS BEFORE EQUALS",
+                summary: "A definition has arguments before its `=`, the way functions are defined in some other languages.",
+                example: "increment n = n + 1",
+                fix: "Functions in Roc are always written as lambdas. Write `increment = \\n -> n + 1` instead.",
+            },
+            BadBackpassingArrow => Explanation {
+                title: "BAD BACKPASSING ARROW",
+                summary: "A backpassing arrow `<-` is used somewhere it can't be, like after a pattern that isn't followed by a function call.",
+                example: "x <- 42\n\nx",
+                fix: "Backpassing needs a function call on the right of the `<-`, like `x <- Task.await getNumber`. If you meant to define a value, use `=` instead.",
+            },
+            BadRequires => Explanation {
+                title: "BAD REQUIRES",
+                summary: "The `requires` part of a platform header doesn't start with a list of type names.",
+                example: "platform \"example\"\n    requires { model } { main : Effect {} }",
+                fix: "List the type names the application must provide, like `requires { Model, Msg } { main : Effect {} }`.",
+            },
+            BadRequiresRigids => Explanation {
+                title: "BAD REQUIRES RIGIDS",
+                summary: "The list of rigid type variables in a platform's `requires` is malformed.",
+                example: "platform \"example\"\n    requires {model=>} { main : Effect {} }",
+                fix: "Write each rigid as `name=>Type`, like `requires {model=>Model, msg=>Msg} {main : Effect {}}`.",
+            },
+            BadTypeVariable => Explanation {
+                title: "BAD TYPE VARIABLE",
+                summary: "Something that should be a type variable in a type annotation isn't one.",
+                example: "Pair : [Pair A b]",
+                fix: "Type variables start with a lowercase letter, like `a` or `elem`.",
+            },
+            DoubleComma => Explanation {
+                title: "DOUBLE COMMA",
+                summary: "Two commas appear in a row, with nothing between them.",
+                example: "numbers = [1,, 2]",
+                fix: "Remove one of the commas, or put the missing element between them.",
+            },
+            DoubleDot => Explanation {
+                title: "DOUBLE DOT",
+                summary: "A qualified name has two dots in a row.",
+                example: "size : Num..I64",
+                fix: "Remove one of the dots, like `Num.I64`.",
+            },
+            EmptyParentheses => Explanation {
+                title: "EMPTY PARENTHESES",
+                summary: "A pair of parentheses has nothing in it. Roc has no `()` value.",
+                example: "nothing = ()",
+                fix: "If you need a value that holds nothing, use the empty record `{}` instead.",
+            },
+            UnfinishedQualifiedType => Explanation {
+                title: "END OF FILE",
+                summary: "The file ended while something was still being parsed.",
+                example: "Point : {",
+                fix: "Finish the last definition of the file, or remove it.",
+            },
+            EndlessFormat => Explanation {
+                title: "ENDLESS FORMAT",
+                summary: "A string interpolation `\\(` is never closed.",
+                example: "greeting = \"Hello, \\(name!\"",
+                fix: "Add a `)` at the end of the interpolated expression, like `\"Hello, \\(name)!\"`.",
+            },
+            EndlessString => Explanation {
+                title: "ENDLESS STRING",
+                summary: "A string literal is never closed.",
+                example: "greeting = \"Hello",
+                fix: "Add the closing `\"`. Strings that span several lines use triple quotes, like `\"\"\"`.",
+            },
+            IfGuardNoCondition => Explanation {
+                title: "IF GUARD NO CONDITION",
+                summary: "A `when` branch has an `if` guard without a condition after it.",
+                example: "when x is\n    n if -> n\n    _ -> 0",
+                fix: "Add the condition between the `if` and the `->`, like `n if n > 0 -> n`.",
+            },
+            IncompleteHeader => Explanation {
+                title: "INCOMPLETE HEADER",
+                summary: "A module header is missing some of its parts.",
+                example: "app \"example\"\n    packages { pf: \"platform/main.roc\" }",
+                fix: "Add the missing parts of the header. An application needs at least `packages`, `imports` and `provides`.",
+            },
+            InsufficientIndentInMultilineString => Explanation {
+                title: "INSUFFICIENT INDENT IN MULTI-LINE STRING",
+                summary: "A line of a multi-line string is indented less than the opening `\"\"\"`.",
+                example: "text =\n    \"\"\"\n  Hello\n    \"\"\"",
+                fix: "Indent every line of the string at least as much as the opening quotes. That indentation is removed from the string when it's compiled.",
+            },
+            InvalidNumberLiteral => Explanation {
+                title: "INVALID NUMBER LITERAL",
+                summary: "A number literal has characters that aren't allowed in it.",
+                example: "count = 12x4",
+                fix: "Use only digits, an optional decimal point, and a valid suffix like `u8` or `f64`.",
+            },
+            LambdaMissingArrow => Explanation {
+                title: "MISSING ARROW",
+                summary: "A lambda is missing the `->` between its arguments and its body.",
+                example: "increment = \\n n + 1",
+                fix: "Add the `->`, like `increment = \\n -> n + 1`.",
+            },
+            MissingExpression => Explanation {
+                title: "MISSING EXPRESSION",
+                summary: "An expression was expected, but something else was found.",
+                example: "x =\n\ny = 4",
+                fix: "Give the definition a body, like `x = 42`.",
+            },
+            MissingFinalExpression => Explanation {
+                title: "MISSING FINAL EXPRESSION",
+                summary: "A block of definitions isn't followed by the expression it should evaluate to.",
+                example: "main =\n    x = 5\n    y = 6",
+                fix: "End the block with an expression that uses the definitions, like `x + y`.",
+            },
+            MissingHeader => Explanation {
+                title: "MISSING HEADER",
+                summary: "A module doesn't start with a header.",
+                example: "main = \"Hello\"",
+                fix: "Start the file with a header, like `app`, `interface`, `platform` or `hosted`.",
+            },
+            MissingPackages => Explanation {
+                title: "MISSING PACKAGES",
+                summary: "A header is missing its `packages` section.",
+                example: "platform \"example\"\n    requires {} { main : Str }\n    exposes []\n    imports []",
+                fix: "Add a `packages` section, like `packages {}`.",
+            },
+            MissingRequires => Explanation {
+                title: "MISSING REQUIRES",
+                summary: "A platform header is missing its `requires` section.",
+                example: "platform \"example\"\n    exposes []",
+                fix: "Add a `requires` section, like `requires {} { main : Str }`.",
+            },
+            RecordTypeNeedsMoreIndentation => Explanation {
+                title: "NEED MORE INDENTATION",
+                summary: "The closing `}` of a record type isn't indented enough to belong to the type.",
+                example: "Point : {\nx : F64,\n}",
+                fix: "Indent the `}` more than the start of the definition it belongs to.",
+            },
+            NotAnInlineAlias => Explanation {
+                title: "NOT AN INLINE ALIAS",
+                summary: "The type after an `as` in an annotation isn't a type alias name.",
+                example: "f : [Leaf, Node t t] as t",
+                fix: "Use an uppercase name with optional type arguments after the `as`, like `as Tree`.",
+            },
+            NotEndOfFile => Explanation {
+                title: "NOT END OF FILE",
+                summary: "Something unexpected was found where the file should have ended, or where a new definition should start.",
+                example: "x = 4\n)",
+                fix: "Remove the unexpected text, or complete the definition it belongs to.",
+            },
+            ParseProblem => Explanation {
+                title: "PARSE PROBLEM",
+                summary: "Some syntax could not be parsed, and there's no more specific report for it.",
+                example: "x = { name, age: currentAge }, }",
+                fix: "Look at the text just before the highlighted region. It's often a missing or extra bracket, comma or keyword.",
+            },
+            ProblemInRecordPattern => Explanation {
+                title: "PROBLEM IN RECORD PATTERN",
+                summary: "A field in a record pattern is malformed.",
+                example: "\\{ User } -> 0",
+                fix: "Record field names start with a lowercase letter, like `userName`.",
+            },
+            ProblemInRecordType => Explanation {
+                title: "PROBLEM IN RECORD TYPE",
+                summary: "A field in a record type is malformed.",
+                example: "User : { Name : Str }",
+                fix: "Record field names start with a lowercase letter, like `name : Str`.",
+            },
+            QualifiedAliasName => Explanation {
+                title: "QUALIFIED ALIAS NAME",
+                summary: "An inline alias after an `as` has a qualified name, like `List.Tree`.",
+                example: "f : [Leaf, Node t t] as List.Tree",
+                fix: "An alias introduces a new name in the current scope, so it must be unqualified, like `as Tree`.",
+            },
+            RecordParseProblem => Explanation {
+                title: "RECORD PARSE PROBLEM",
+                summary: "A record expression is malformed.",
+                example: "user = { name: \"Ayla\" age: 30 }",
+                fix: "Check that fields are separated by commas and that each field is written as `name: value`.",
+            },
+            NonAssociativeOperators => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "Two operators that can't be chained, like two comparisons, are used in a row without parentheses.",
+                example: "isBetween = 1 < x < 5",
+                fix: "Add parentheses to make the order explicit, or split the expression up, like `1 < x && x < 5`.",
+            },
+            TabCharacter => Explanation {
+                title: "TAB CHARACTER",
+                summary: "The file contains a tab character. Roc only allows spaces for indentation.",
+                example: "main =\n\t\"Hello\"",
+                fix: "Replace the tabs with spaces. `roc format` does this for you.",
+            },
+            TrailingDot => Explanation {
+                title: "TRAILING DOT",
+                summary: "A qualified type name ends with a dot.",
+                example: "size : Num.",
+                fix: "Add the type name after the dot, like `Num.I64`.",
+            },
+            TypeArgumentNotLowercase => Explanation {
+                title: "TYPE ARGUMENT NOT LOWERCASE",
+                summary: "A type argument of an inline alias isn't a lowercase type variable.",
+                example: "f : [Leaf, Node a a] as Tree A",
+                fix: "Type arguments must be lowercase, like `as Tree a`.",
+            },
+            UnexpectedArrow => Explanation {
+                title: "UNEXPECTED ARROW",
+                summary: "An arrow `->` appears where one isn't expected.",
+                example: "when x is\n    1 -> \"one\" -> \"uno\"",
+                fix: "Remove the extra arrow. A `when` branch has exactly one arrow between the pattern and its body.",
+            },
+            UnfinishedAbility => Explanation {
+                title: "UNFINISHED ABILITY",
+                summary: "An ability definition is missing the type signature of a member.",
+                example: "Hash has\n    hash",
+                fix: "Annotate each member, like `hash : a -> U64 | a has Hash`.",
+            },
+            UnfinishedArgumentList => Explanation {
+                title: "UNFINISHED ARGUMENT LIST",
+                summary: "The argument list of a lambda is not finished.",
+                example: "add = \\x, -> x",
+                fix: "Add the missing argument, or remove the trailing comma.",
+            },
+            UnfinishedFunction => Explanation {
+                title: "UNFINISHED FUNCTION",
+                summary: "A lambda is missing its arrow or its body.",
+                example: "increment = \\n ->",
+                fix: "Add the body after the `->`, like `\\n -> n + 1`.",
+            },
+            UnfinishedIf => Explanation {
+                title: "UNFINISHED IF",
+                summary: "An `if` expression is missing its `then` or `else` branch.",
+                example: "max = if a > b then a",
+                fix: "Every `if` in Roc has both a `then` and an `else` branch, like `if a > b then a else b`.",
+            },
+            UnfinishedInlineAlias => Explanation {
+                title: "UNFINISHED INLINE ALIAS",
+                summary: "An `as` in a type annotation isn't followed by an alias name.",
+                example: "f : [Leaf, Node t t] as",
+                fix: "Add the name of the alias after the `as`, like `as Tree`.",
+            },
+            UnfinishedList => Explanation {
+                title: "UNFINISHED LIST",
+                summary: "A list literal or list pattern is never closed.",
+                example: "numbers = [1, 2, 3",
+                fix: "Add the closing `]`.",
+            },
+            UnfinishedParentheses => Explanation {
+                title: "UNFINISHED PARENTHESES",
+                summary: "A pair of parentheses is never closed.",
+                example: "total = (1 + 2",
+                fix: "Add the closing `)`.",
+            },
+            UnfinishedPattern => Explanation {
+                title: "UNFINISHED PATTERN",
+                summary: "A pattern is not finished, often because of indentation.",
+                example: "when x is\n    Ok ->\n1",
+                fix: "Finish the pattern, and indent its body more than the pattern itself.",
+            },
+            UnfinishedRecordPattern => Explanation {
+                title: "UNFINISHED RECORD PATTERN",
+                summary: "A record pattern is never closed, or one of its fields is a reserved word.",
+                example: "\\{ name, age -> name",
+                fix: "Add the closing `}`, and make sure no field is named after a keyword like `if` or `when`.",
+            },
+            UnfinishedRecordType => Explanation {
+                title: "UNFINISHED RECORD TYPE",
+                summary: "A record type is never closed, or one of its fields is a reserved word.",
+                example: "User : { name : Str, age : U8",
+                fix: "Add the closing `}`, and make sure no field is named after a keyword like `if` or `when`.",
+            },
+            UnfinishedTagUnionType => Explanation {
+                title: "UNFINISHED TAG UNION TYPE",
+                summary: "A tag union type is never closed, or one of its tags is not a valid tag name.",
+                example: "Color : [Red, Green, blue]",
+                fix: "Add the closing `]`, and start every tag name with an uppercase letter.",
+            },
+            UnfinishedType => Explanation {
+                title: "UNFINISHED TYPE",
+                summary: "A type annotation is not finished.",
+                example: "size :",
+                fix: "Add the type, like `size : U64`.",
+            },
+            UnfinishedWhen => Explanation {
+                title: "UNFINISHED WHEN",
+                summary: "A `when` expression is missing its `is`, its branches, or the body of a branch.",
+                example: "when color is\n    Red ->",
+                fix: "Give every branch a pattern, an `->`, and a body indented more than the pattern.",
+            },
+            UnknownOperator => Explanation {
+                title: "UNKNOWN OPERATOR",
+                summary: "An operator is used that Roc doesn't have, or is written in a way that's not allowed.",
+                example: "isEmpty = ! (List.isEmpty primes)",
+                fix: "Use one of Roc's operators. Unary operators like `!` and `-` are written right before their expression, without a space.",
+            },
+            WeirdAppName => Explanation {
+                title: "WEIRD APP NAME",
+                summary: "The name of an application is not a string.",
+                example: "app hello\n    packages { pf: \"platform/main.roc\" }",
+                fix: "App names are surrounded by quotation marks, like `app \"hello\"`.",
+            },
+            WeirdArrow => Explanation {
+                title: "WEIRD ARROW",
+                summary: "An arrow appears in a position where something else was expected.",
+                example: "f : Str, -> Str",
+                fix: "Check the arguments before the arrow. In a function type they're separated by commas, like `Str, Str -> Str`.",
+            },
+            WeirdCodePoint => Explanation {
+                title: "WEIRD CODE POINT",
+                summary: "A unicode escape in a string is malformed.",
+                example: "letter = \"\\u(zzzz)\"",
+                fix: "Write the code point in hexadecimal inside parentheses, like `\\u(00FF)`.",
+            },
+            WeirdEscape => Explanation {
+                title: "WEIRD ESCAPE",
+                summary: "A string has an escape sequence that Roc doesn't know.",
+                example: "text = \"\\q\"",
+                fix: "The escapes Roc knows are `\\\\`, `\\\"`, `\\n`, `\\r`, `\\t`, `\\$` and `\\u(...)`.",
+            },
+            WeirdExposes => Explanation {
+                title: "WEIRD EXPOSES",
+                summary: "The `exposes` part of a header is malformed.",
+                example: "interface Animal\n    exposes Animal, default\n    imports []",
+                fix: "List the exposed names in square brackets, like `exposes [Animal, default, tame]`.",
+            },
+            WeirdGeneratedTypeName => Explanation {
+                title: "WEIRD GENERATED TYPE NAME",
+                summary: "The type name after `with` in a hosted module's `generates` is not a type name.",
+                example: "hosted Effect\n    exposes []\n    imports []\n    generates effect with [after]",
+                fix: "Use an uppercase type name, like `generates Effect with [after, map]`.",
+            },
+            WeirdGenerates => Explanation {
+                title: "WEIRD GENERATES",
+                summary: "The `generates` part of a hosted module's header is malformed.",
+                example: "hosted Effect\n    exposes []\n    imports []\n    generates Effect after",
+                fix: "Write the generated functions in square brackets, like `generates Effect with [after, map]`.",
+            },
+            WeirdIdentifier => Explanation {
+                title: "WEIRD IDENTIFIER",
+                summary: "An identifier is malformed, for example a qualified name with a lowercase module part or a qualified tag.",
+                example: "x = json.Decode.string",
+                fix: "Module names start with an uppercase letter, like `Json.Decode.string`. Tags are never qualified.",
+            },
+            WeirdImports => Explanation {
+                title: "WEIRD IMPORTS",
+                summary: "The `imports` part of a header is malformed.",
+                example: "interface Animal\n    exposes []\n    imports Dict",
+                fix: "List the imported modules in square brackets, like `imports [Dict, pf.Stdout]`.",
+            },
+            WeirdModuleName => Explanation {
+                title: "WEIRD MODULE NAME",
+                summary: "The name of a module is not a valid module name.",
+                example: "interface animal\n    exposes []\n    imports []",
+                fix: "Module names start with an uppercase letter, like `Animal`.",
+            },
+            WeirdProvides => Explanation {
+                title: "WEIRD PROVIDES",
+                summary: "The `provides` part of a header is malformed.",
+                example: "app \"example\"\n    packages { pf: \"platform/main.roc\" }\n    imports []\n    provides main to pf",
+                fix: "List the provided values in square brackets, like `provides [main] to pf`.",
+            },
+            WeirdQualifiedName => Explanation {
+                title: "WEIRD QUALIFIED NAME",
+                summary: "Part of a qualified type name does not start with an uppercase letter.",
+                example: "size : Num.i64",
+                fix: "Every part of a qualified type name starts with an uppercase letter, like `Num.I64`.",
+            },
+            WeirdTagName => Explanation {
+                title: "WEIRD TAG NAME",
+                summary: "A tag name in a tag union type is not valid.",
+                example: "Color : [Red, when]",
+                fix: "Tag names start with an uppercase letter and can't be keywords.",
+            },
+            WhenMissingArrow => Explanation {
+                title: "MISSING ARROW",
+                summary: "A `when` branch is missing the `->` between its pattern and its body.",
+                example: "when color is\n    Red 1\n    _ -> 0",
+                fix: "Add the `->`, like `Red -> 1`.",
+            },
+            UnexpectedTokensBeforeEquals => Explanation {
+                title: "PARSE PROBLEM",
+                summary: "There are tokens in front of the `=` of a definition that can't be part of a pattern.",
+                example: "main 1 = 2",
+                fix: "Remove the extra tokens. If you meant to define a function, write it as a lambda, like `main = \\x -> x`.",
+            },
+            UnexpectedEndOfFile => Explanation {
+                title: "PARSE PROBLEM",
+                summary: "The file ended before a definition was complete.",
+                example: "main =",
+                fix: "Finish the last definition of the file, or remove it.",
+            },
+            OutdentedTooFar => Explanation {
+                title: "PARSE PROBLEM",
+                summary: "A line is indented less than the block it belongs to.",
+                example: "main =\n    x = 1\n  x",
+                fix: "Line up the lines of a block at the same indentation.",
+            },
+            UnfinishedPatternParentheses => Explanation {
+                title: "UNFINISHED PARENTHESES",
+                summary: "Parentheses in a pattern are never closed, or are closed before the pattern in them.",
+                example: "when x is\n    (Ok a -> a",
+                fix: "Finish the pattern in the parentheses and add the closing `)`.",
+            },
+            UnfinishedTypeParentheses => Explanation {
+                title: "UNFINISHED PARENTHESES",
+                summary: "Parentheses in a type annotation are never closed, or are closed before the type in them.",
+                example: "names : List (List Str",
+                fix: "Finish the type in the parentheses and add the closing `)`.",
+            },
+            UnfinishedAsPattern => Explanation {
+                title: "UNFINISHED PATTERN",
+                summary: "An `as` in a pattern is not followed by the name to bind the whole value to.",
+                example: "when result is\n    Ok x as -> x",
+                fix: "Put a name after the `as`, like `Ok x as result`.",
+            },
+            RecordPatternNeedsMoreIndentation => Explanation {
+                title: "NEED MORE INDENTATION",
+                summary: "The closing `}` of a record pattern isn't indented enough to belong to the pattern.",
+                example: "f = \\{ x,\ny\n} -> x",
+                fix: "Indent the `}` more than the start of the definition it belongs to.",
+            },
+            PatternParenthesesNeedMoreIndentation => Explanation {
+                title: "NEED MORE INDENTATION",
+                summary: "The closing `)` of a pattern in parentheses isn't indented enough to belong to the pattern.",
+                example: "f = \\(Pair a\nb\n) -> a",
+                fix: "Indent the `)` more than the start of the definition it belongs to.",
+            },
+            TagUnionTypeNeedsMoreIndentation => Explanation {
+                title: "NEED MORE INDENTATION",
+                summary: "The closing `]` of a tag union type isn't indented enough to belong to the type.",
+                example: "Color : [\nRed,\n]",
+                fix: "Indent the `]` more than the start of the definition it belongs to.",
+            },
+            TypeParenthesesNeedMoreIndentation => Explanation {
+                title: "NEED MORE INDENTATION",
+                summary: "The closing `)` of a type in parentheses isn't indented enough to belong to the type.",
+                example: "names : List (List\nStr\n)",
+                fix: "Indent the `)` more than the start of the definition it belongs to.",
+            },
+            WeirdPlatformName => Explanation {
+                title: "WEIRD MODULE NAME",
+                summary: "The name of a platform in a header is not a quoted string.",
+                example: "platform roc/core\n    requires {} { main : Str }",
+                fix: "Put the platform name in quotes, like `platform \"roc/core\"`.",
+            },
+            WeirdPackageName => Explanation {
+                title: "WEIRD MODULE NAME",
+                summary: "The name of a package in a header is not a quoted string.",
+                example: "package roc/json",
+                fix: "Put the package name in quotes, like `package \"roc/json\"`.",
+            },
+            WeirdImportedModuleName => Explanation {
+                title: "WEIRD MODULE NAME",
+                summary: "The name of an imported module is not a valid module name.",
+                example: "interface Animal\n    exposes []\n    imports [dict]",
+                fix: "Module names start with an uppercase letter, like `Dict`.",
+            },
+            WeirdTagNameInParentheses => Explanation {
+                title: "WEIRD TAG NAME",
+                summary: "A type in parentheses starts with something that isn't a valid type or tag name.",
+                example: "Result : (ok Str)",
+                fix: "Tag and type names start with an uppercase letter, like `(Ok Str)`.",
+            },
+            UnexpectedExpressionEnd => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "An expression is followed by something that can't continue it, and there's no more specific report for it.",
+                example: "x = 1 2 )",
+                fix: "Look at the text just before the highlighted region. It's often a missing or extra bracket, comma or operator.",
+            },
+            AbilityHasTypeVariables => Explanation {
+                title: "ABILITY HAS TYPE VARIABLES",
+                summary: "An ability is defined with type variables. Abilities can't be parameterized.",
+                example: "Hash a has\n    hash : a -> U64 | a has Hash",
+                fix: "Remove the type variables from the name of the ability, like `Hash has`.",
+            },
+            AbilityImplementationNotIdentifier => Explanation {
+                title: "ABILITY IMPLEMENTATION NOT IDENTIFIER",
+                summary: "The implementation given for an ability member is an expression rather than a name.",
+                example: "Id := U64 has [Eq { isEq: \\a, b -> a == b }]",
+                fix: "Define the implementation as a value, and refer to it by name, like `Eq { isEq: idEq }`.",
+            },
+            AbilityMemberBindsMultipleVariables => Explanation {
+                title: "ABILITY MEMBER BINDS MULTIPLE VARIABLES",
+                summary: "An ability member binds more than one type variable to its ability.",
+                example: "Eq has\n    isEq : a, b -> Bool | a has Eq, b has Eq",
+                fix: "Bind exactly one type variable to the ability, like `isEq : a, a -> Bool | a has Eq`.",
+            },
+            AbilityMemberMissingHasClause => Explanation {
+                title: "ABILITY MEMBER MISSING HAS CLAUSE",
+                summary: "An ability member doesn't bind a type variable to the ability it's part of.",
+                example: "Hash has\n    hash : a -> U64",
+                fix: "Add a `has` clause, like `hash : a -> U64 | a has Hash`.",
+            },
+            AbilityNotOnToplevel => Explanation {
+                title: "ABILITY NOT ON TOP-LEVEL",
+                summary: "An ability is defined inside another definition.",
+                example: "main =\n    Hash has hash : a -> U64 | a has Hash\n\n    1",
+                fix: "Move the ability to the top level of the module.",
+            },
+            AbilityUsedAsType => Explanation {
+                title: "ABILITY USED AS TYPE",
+                summary: "An ability is used as if it were a type.",
+                example: "hashAll : List Hash -> U64",
+                fix: "Use a type variable bound to the ability instead, like `hashAll : List a -> U64 | a has Hash`.",
+            },
+            BadOptionalValue => Explanation {
+                title: "BAD OPTIONAL VALUE",
+                summary: "A record uses `?` to give a field a default value somewhere that isn't a record pattern.",
+                example: "config = { width ? 80 }",
+                fix: "Default values can only be given in record patterns, like `\\{ width ? 80 } -> width`. Use `:` to give a field its value.",
+            },
+            CircularDefinition => Explanation {
+                title: "CIRCULAR DEFINITION",
+                summary: "A value is defined in terms of itself, so it can never be computed.",
+                example: "x = y + 1\ny = x + 1",
+                fix: "Break the cycle by defining one of the values without referring to the others. Only functions can refer to themselves.",
+            },
+            ConflictingNumberSuffix => Explanation {
+                title: "CONFLICTING NUMBER SUFFIX",
+                summary: "A number literal has a suffix that doesn't fit it, like a float literal with an integer suffix.",
+                example: "x = 1.5u8",
+                fix: "Use a suffix that fits the literal, like `1.5f64`, or remove the suffix.",
+            },
+            DegenerateBranch => Explanation {
+                title: "DEGENERATE BRANCH",
+                summary: "A `when` branch has alternative patterns that don't all bind the names its body uses.",
+                example: "when x is\n    Ok n | Err _ -> n",
+                fix: "Make every alternative bind the same names, or split them into separate branches.",
+            },
+            DuplicateFieldName => Explanation {
+                title: "DUPLICATE FIELD NAME",
+                summary: "A record has the same field more than once.",
+                example: "user = { name: \"Ayla\", name: \"Ray\" }",
+                fix: "Remove all but one of the fields with that name.",
+            },
+            DuplicateImplementation => Explanation {
+                title: "DUPLICATE IMPLEMENTATION",
+                summary: "An ability member, or a whole ability, is implemented more than once for the same type.",
+                example: "Id := U64 has [Eq { isEq: idEq, isEq: otherEq }]",
+                fix: "Keep only one implementation.",
+            },
+            DuplicateName => Explanation {
+                title: "DUPLICATE NAME",
+                summary: "A name is defined more than once in the same scope. Roc doesn't allow shadowing.",
+                example: "x = 1\nx = 2",
+                fix: "Give one of the definitions a different name.",
+            },
+            DuplicateTagName => Explanation {
+                title: "DUPLICATE TAG NAME",
+                summary: "A tag union type has the same tag more than once.",
+                example: "Color : [Red, Green, Red]",
+                fix: "Remove all but one of the tags with that name.",
+            },
+            ForeignBuiltinImplementation => Explanation {
+                title: "FOREIGN BUILTIN IMPLEMENTATION",
+                summary: "An ability is implemented for a builtin type outside of the module that defines the ability.",
+                example: "Str has [Encode.Encoding { toEncoder: strToEncoder }]",
+                fix: "Builtin types can only be given implementations of abilities defined in the same module. Wrap the builtin in an opaque type and implement the ability for that instead.",
+            },
+            HasClauseIsNotAnAbility => Explanation {
+                title: "HAS CLAUSE IS NOT AN ABILITY",
+                summary: "A `has` clause binds a type variable to something that isn't an ability.",
+                example: "show : a -> Str | a has Str",
+                fix: "Only abilities can appear in a `has` clause, like `a has Inspect`.",
+            },
+            IllegalBuiltinImplementation => Explanation {
+                title: "ILLEGAL BUILTIN IMPLEMENTATION",
+                summary: "Abilities are implemented for a type with the syntax only builtin types and type aliases can use.",
+                example: "Age := U8\n\nAge has [Pretty { pretty: prettyAge }]",
+                fix: "Opaque types declare the abilities they implement in their definition, like `Age := U8 has [Pretty { pretty: prettyAge }]`.",
+            },
+            IllegalDerive => Explanation {
+                title: "ILLEGAL DERIVE",
+                summary: "An ability that can't be derived is listed without implementations.",
+                example: "Id := U64 has [Hash]",
+                fix: "Only builtin abilities can be derived. Give the implementations of the members, like `Hash { hash: hashId }`.",
+            },
+            IllegalHasClause => Explanation {
+                title: "ILLEGAL HAS CLAUSE",
+                summary: "A `has` clause appears somewhere it isn't allowed.",
+                example: "Box a has Eq : [Box a]",
+                fix: "`has` clauses can only be used in annotations and ability definitions.",
+            },
+            ImplementationNotFound => Explanation {
+                title: "IMPLEMENTATION NOT FOUND",
+                summary: "The implementation named for an ability member isn't defined.",
+                example: "Id := U64 has [Eq { isEq }]",
+                fix: "Define a value with that name in the module, or name a value that's already defined, like `Eq { isEq: idEq }`.",
+            },
+            ImplementationNotOnToplevel => Explanation {
+                title: "IMPLEMENTATION NOT ON TOP-LEVEL",
+                summary: "Abilities are implemented for a builtin type inside another definition.",
+                example: "main =\n    Str has [Pretty { pretty: prettyStr }]\n\n    1",
+                fix: "Move the implementation to the top level of the module that defines the ability.",
+            },
+            IncompleteAbilityImplementation => Explanation {
+                title: "INCOMPLETE ABILITY IMPLEMENTATION",
+                summary: "A type claims to implement an ability, but some of the ability's members have no implementation.",
+                example: "Id := U64 has [Hash {}]",
+                fix: "Give an implementation for every member of the ability that doesn't have a default.",
+            },
+            InvalidUnicodeCodePoint => Explanation {
+                title: "INVALID UNICODE",
+                summary: "A unicode escape in a string refers to a code point that doesn't exist.",
+                example: "text = \"\\u(110000)\"",
+                fix: "Use a valid code point. The largest is `10FFFF`.",
+            },
+            InvalidExtensionType => Explanation {
+                title: "INVALID_EXTENSION_TYPE",
+                summary: "The extension of a record or tag union type isn't a record or tag union.",
+                example: "Named : { name : Str }Str",
+                fix: "Extend a record with a type variable or another record, and a tag union with a type variable or another tag union.",
+            },
+            MissingDefinition => Explanation {
+                title: "MISSING DEFINITION",
+                summary: "A module exposes a name it doesn't define.",
+                example: "interface Animal\n    exposes [tame]\n    imports []",
+                fix: "Add a definition for the name, or remove it from the `exposes` list.",
+            },
+            MissingRequiredAbility => Explanation {
+                title: "MISSING REQUIRED ABILITY",
+                summary: "A type implements an ability, but not the other abilities that ability requires.",
+                example: "Ord has Eq\n    compare : a, a -> [LT, EQ, GT] | a has Ord\n\nId := U64 has [Ord { compare: compareId }]",
+                fix: "Also implement or derive the required abilities, like `has [Eq, Ord { compare: compareId }]`.",
+            },
+            ModuleNotImported => Explanation {
+                title: "MODULE NOT IMPORTED",
+                summary: "A qualified name refers to a module that isn't imported.",
+                example: "x = Json.encode 5",
+                fix: "Add the module to the `imports` of the header, or check the spelling of the module name.",
+            },
+            MultipleListRestPatterns => Explanation {
+                title: "MULTIPLE LIST REST PATTERNS",
+                summary: "A list pattern has more than one `..`.",
+                example: "when list is\n    [.., x, ..] -> x\n    _ -> 0",
+                fix: "Use a single `..` per list pattern, so it's clear which elements the rest of the pattern matches.",
+            },
+            NameNotBoundInAllPatterns => Explanation {
+                title: "NAME NOT BOUND IN ALL PATTERNS",
+                summary: "A `when` branch has alternative patterns, and a name is bound in some of them but not others.",
+                example: "when x is\n    Ok n | Err _ -> n",
+                fix: "Bind the name in every alternative, or split them into separate branches.",
+            },
+            SignatureDefMismatch => Explanation {
+                title: "NAMING PROBLEM",
+                summary: "A type annotation is followed right away by a definition of a different name.",
+                example: "x : I64\ny = 4",
+                fix: "If the names should be the same, fix the typo. If not, put a newline or a comment between the annotation and the definition.",
+            },
+            NestedDatatype => Explanation {
+                title: "NESTED DATATYPE",
+                summary: "A recursive type refers to itself with different type arguments than it's defined with.",
+                example: "Nested a : [Chain a (Nested (List a)), Term]",
+                fix: "Refer to the recursive type with the same arguments it's defined with, like `Nested a`.",
+            },
+            NotAnAbility => Explanation {
+                title: "NOT AN ABILITY",
+                summary: "Something that isn't an ability is listed among the abilities a type implements.",
+                example: "Id := U64 has [Str]",
+                fix: "Only abilities can be implemented. Check the spelling, and that the ability is imported.",
+            },
+            NotAnAbilityMember => Explanation {
+                title: "NOT AN ABILITY MEMBER",
+                summary: "An implementation is given for a member the ability doesn't have.",
+                example: "Id := U64 has [Eq { isNotEq: idNotEq }]",
+                fix: "Only give implementations for the members the ability has.",
+            },
+            NotExposed => Explanation {
+                title: "NOT EXPOSED",
+                summary: "A module is asked for a name that it doesn't expose.",
+                example: "x = List.frist [1, 2]",
+                fix: "Check the spelling of the name. The report suggests the most similar names the module does expose.",
+            },
+            NumberOverflowsSuffix => Explanation {
+                title: "NUMBER OVERFLOWS SUFFIX",
+                summary: "An integer literal is larger than the type indicated by its suffix can hold.",
+                example: "x = 300u8",
+                fix: "Use a larger type, like `300u16`, or a smaller number.",
+            },
+            NumberUnderflowsSuffix => Explanation {
+                title: "NUMBER UNDERFLOWS SUFFIX",
+                summary: "An integer literal is smaller than the type indicated by its suffix can hold.",
+                example: "x = -200i8",
+                fix: "Use a larger type, like `-200i16`, or a larger number.",
+            },
+            OpaqueAppliedToTooManyArgs => Explanation {
+                title: "OPAQUE TYPE APPLIED TO TOO MANY ARGS",
+                summary: "An opaque type is wrapped around more than one value.",
+                example: "Age := U8\n\nage = @Age 1 2",
+                fix: "Opaque types always wrap exactly one value. Wrap a record or tuple if more values are needed.",
+            },
+            OpaqueDeclaredOutsideScope => Explanation {
+                title: "OPAQUE TYPE DECLARED OUTSIDE SCOPE",
+                summary: "An opaque type is wrapped or unwrapped outside of the module that defines it.",
+                example: "age = @Age 21",
+                fix: "Only the module defining an opaque type can wrap and unwrap it. Use the functions that module exposes instead.",
+            },
+            OpaqueNotApplied => Explanation {
+                title: "OPAQUE TYPE NOT APPLIED",
+                summary: "An opaque type wrapper is used without the value it should wrap.",
+                example: "Age := U8\n\nage = @Age",
+                fix: "Opaque types always wrap exactly one value, like `@Age 21`.",
+            },
+            OpaqueNotDefined => Explanation {
+                title: "OPAQUE TYPE NOT DEFINED",
+                summary: "A wrapper refers to an opaque type that isn't defined.",
+                example: "age = @Agee 21",
+                fix: "Check the spelling. If there's a type alias of the same name, turn it into an opaque type with `:=`.",
+            },
+            OptionalAbilityImplementation => Explanation {
+                title: "OPTIONAL ABILITY IMPLEMENTATION",
+                summary: "An ability member's implementation is marked as optional with `?`.",
+                example: "Id := U64 has [Eq { isEq ? idEq }]",
+                fix: "Implementations must be given in full, like `Eq { isEq: idEq }`. To derive the ability, leave out the record, like `has [Eq]`.",
+            },
+            OverappliedCrash => Explanation {
+                title: "OVERAPPLIED CRASH",
+                summary: "`crash` is given more than one value.",
+                example: "crash \"oops\" \"again\"",
+                fix: "`crash` takes exactly one message.",
+            },
+            OverloadedSpecialization => Explanation {
+                title: "OVERLOADED SPECIALIZATION",
+                summary: "The same value is used to implement an ability member for more than one opaque type.",
+                example: "A := U8 has [Eq { isEq: sameEq }]\nB := U8 has [Eq { isEq: sameEq }]",
+                fix: "Give each opaque type its own implementation.",
+            },
+            QualifiedAbilityImplementation => Explanation {
+                title: "QUALIFIED ABILITY IMPLEMENTATION",
+                summary: "An ability member's implementation is a qualified name from another module.",
+                example: "Id := U64 has [Eq { isEq: Num.isEq }]",
+                fix: "Define the implementation in the module with the opaque type, like `idEq = \\@Id a, @Id b -> a == b`.",
+            },
+            SpecializationNotOnToplevel => Explanation {
+                title: "SPECIALIZATION NOT ON TOP-LEVEL",
+                summary: "An ability member is specialized inside another definition.",
+                example: "main =\n    hash = \\@Id n -> n\n\n    1",
+                fix: "Move the specialization to the top level of the module.",
+            },
+            UnappliedCrash => Explanation {
+                title: "UNAPPLIED CRASH",
+                summary: "`crash` is used without a message, or passed around as a value.",
+                example: "fail = crash",
+                fix: "`crash` must be given its message right where it's used, like `crash \"unreachable\"`.",
+            },
+            UnboundTypeVariable => Explanation {
+                title: "UNBOUND TYPE VARIABLE",
+                summary: "A type alias uses type variables it doesn't declare as parameters.",
+                example: "Pair : [Pair a a]",
+                fix: "Add the type variable as a parameter of the alias, like `Pair a : [Pair a a]`.",
+            },
+            UnknownGeneratesFunction => Explanation {
+                title: "UNKNOWN GENERATES FUNCTION",
+                summary: "A hosted module asks for a function to be generated that can't be.",
+                example: "hosted Effect\n    exposes []\n    imports []\n    generates Effect with [after, flatten]",
+                fix: "Only specific functions like `after` and `map` can be generated.",
+            },
+            UnnecessaryDefinition => Explanation {
+                title: "UNNECESSARY DEFINITION",
+                summary: "A destructuring assignment doesn't introduce any names, so it has no effect.",
+                example: "{} = doSomething 1",
+                fix: "Remove the assignment. Since Roc is purely functional, it can't affect the program.",
+            },
+            UnnecessaryImplementations => Explanation {
+                title: "UNNECESSARY IMPLEMENTATIONS",
+                summary: "A type implements members that aren't part of the ability.",
+                example: "Id := U64 has [Eq { isEq: idEq, hash: hashId }]",
+                fix: "Remove the implementations that don't belong to the ability.",
+            },
+            UnrecognizedName => Explanation {
+                title: "UNRECOGNIZED NAME",
+                summary: "A name is used that isn't defined or imported.",
+                example: "main = lenght [1, 2]",
+                fix: "Check the spelling, or define or import the name. The report suggests similar names that are in scope.",
+            },
+            UnusedArgument => Explanation {
+                title: "UNUSED ARGUMENT",
+                summary: "A function has an argument it never uses.",
+                example: "always = \\x, y -> x",
+                fix: "Remove the argument, or prefix it with an underscore, like `_y`, if it's needed to fit a certain type.",
+            },
+            UnusedDefinition => Explanation {
+                title: "UNUSED DEFINITION",
+                summary: "A value is defined but never used.",
+                example: "main =\n    unused = 5\n\n    1",
+                fix: "Remove the definition, or use it. `roc fix` can remove some unused definitions for you.",
+            },
+            UnusedImport => Explanation {
+                title: "UNUSED IMPORT",
+                summary: "A module is imported, but nothing from it is used.",
+                example: "interface Animal\n    exposes []\n    imports [Dict]",
+                fix: "Remove the import. `roc fix` does this for you.",
+            },
+            UnusedTypeAliasParameter => Explanation {
+                title: "UNUSED TYPE ALIAS PARAMETER",
+                summary: "A type alias has a type parameter it never uses.",
+                example: "Name a : Str",
+                fix: "Remove the type parameter, or use it in the definition.",
+            },
+            UnusedExposedImport => Explanation {
+                title: "UNUSED IMPORT",
+                summary: "A name is listed in an import's exposed list, but never used.",
+                example: "interface Animal\n    exposes []\n    imports [Dict.{ Dict }]",
+                fix: "Remove the name from the exposed list. `roc fix` does this for you.",
+            },
+            UnsupportedPattern => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A pattern is used somewhere it can't be, like a literal in a definition or function argument.",
+                example: "f = \\1 -> 2",
+                fix: "Use a name or a destructuring pattern, and check for the literal with a `when` in the body.",
+            },
+            DuplicateFieldNameInType => Explanation {
+                title: "DUPLICATE FIELD NAME",
+                summary: "A record type has the same field more than once.",
+                example: "User : { name : Str, name : Str }",
+                fix: "Remove all but one of the fields with that name.",
+            },
+            InvalidAliasRigid => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "The left side of a type alias or opaque type has something other than type variables after its name.",
+                example: "Pair U64 : [Pair U64 U64]",
+                fix: "Only lowercase type variables can come after the name, like `Pair a : [Pair a a]`.",
+            },
+            InvalidHexadecimal => Explanation {
+                title: "INVALID UNICODE",
+                summary: "A unicode escape in a string doesn't contain a hexadecimal number.",
+                example: "text = \"\\u(zz)\"",
+                fix: "Write the code point in hexadecimal, like `\\u(00E9)`.",
+            },
+            InvalidInterpolation => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A string interpolation contains something other than a name.",
+                example: "greeting = \"Hi, \\(1 + 1)!\"",
+                fix: "Define the value first and interpolate its name, like `\"Hi, \\(name)!\"`.",
+            },
+            DuplicateBuiltinImplementation => Explanation {
+                title: "DUPLICATE IMPLEMENTATION",
+                summary: "A builtin type is given more than one implementation of the same ability member.",
+                example: "Str implements [Hash { hash: hashA }]\nStr implements [Hash { hash: hashB }]",
+                fix: "Keep only one implementation of each member.",
+            },
+            MalformedPatternName => Explanation {
+                title: "NAMING PROBLEM",
+                summary: "A pattern uses a name that can't be bound, like a qualified name or a field accessor.",
+                example: "f = \\Str.x -> x",
+                fix: "Bind a plain lowercase name in the pattern, like `\\x -> x`.",
+            },
+            MalformedPattern => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A literal in a pattern is malformed, like a number that isn't valid or a qualified name.",
+                example: "when x is\n    0x -> 1\n    _ -> 0",
+                fix: "Fix the literal, or use a tag or a plain name in the pattern.",
+            },
+            MalformedIdentifier => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "An expression uses a name that isn't well-formed, like a qualified name ending in a dot or a qualified tag.",
+                example: "x = Num.",
+                fix: "Complete the name, like `Num.abs`. Tags can't be qualified.",
+            },
+            MalformedTypeName => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A type annotation uses a type name that isn't well-formed.",
+                example: "x : Num.",
+                fix: "Complete the type name, like `Num.U64`.",
+            },
+            FloatOutOfRange => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A float literal is too large or too small to be represented.",
+                example: "x = 1e999",
+                fix: "Use a value inside the range of the float type.",
+            },
+            InvalidFloatLiteral => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A float literal is not well-formed.",
+                example: "x = 3.14abc",
+                fix: "Write the float as digits with a decimal point, like `3.14`.",
+            },
+            InvalidIntLiteral => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "An integer literal has digits that aren't allowed in its base, or has no digits at all.",
+                example: "x = 0b102",
+                fix: "Use only the digits of the literal's base, like `0b101` for binary.",
+            },
+            IntOutOfRange => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "An integer literal is too large or too small for any integer type.",
+                example: "x = 340282366920938463463374607431768211456",
+                fix: "Use a smaller number, or a float or `Dec` if you need a larger range.",
+            },
+            OptionalValueInRecord => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A record literal gives a field a default value with `?`, which is only allowed in record patterns and types.",
+                example: "config = { width ? 80 }",
+                fix: "Use `:` to give the field its value, like `{ width: 80 }`.",
+            },
+            InvalidRecordUpdate => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A record update is applied to something other than a plain name.",
+                example: "updated = { (getUser 1) & name: \"Ayla\" }",
+                fix: "Give the record a name first, then update it, like `{ user & name: \"Ayla\" }`.",
+            },
+            EmptyCharacterLiteral => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A single-quote character literal has nothing in it.",
+                example: "x = ''",
+                fix: "Put exactly one character between the quotes, like `'a'`.",
+            },
+            MultipleCharactersInLiteral => Explanation {
+                title: "SYNTAX PROBLEM",
+                summary: "A single-quote character literal has more than one character in it.",
+                example: "x = 'ab'",
+                fix: "Put exactly one character between the quotes. For longer text, use a string like `\"ab\"`.",
+            },
+            CircularType => Explanation {
+                title: "CIRCULAR TYPE",
+                summary: "A value's type would have to contain itself, which would make it infinitely large.",
+                example: "f = \\x -> f [x]",
+                fix: "Check the highlighted code for a value that's used both as a thing and as a container of that thing. A type annotation often makes the problem clearer.",
+            },
+            CyclicAlias => Explanation {
+                title: "CYCLIC ALIAS",
+                summary: "A type alias refers to itself in a way that isn't allowed, for example outside of a tag union.",
+                example: "Loop : { next : Loop }",
+                fix: "Recursive types must go through a tag union, like `List a : [Nil, Cons a (List a)]`. An opaque type can also break the cycle.",
+            },
+            IllegalSpecialization => Explanation {
+                title: "ILLEGAL SPECIALIZATION",
+                summary: "An ability member is specialized for a structural type, like a record. Only opaque types can implement abilities.",
+                example: "hash = \\{ x } -> x",
+                fix: "Wrap the type in an opaque type and implement the ability for that, like `Point := { x : U64 } has [Hash { hash: hashPoint }]`.",
+            },
+            InferredHole => Explanation {
+                title: "INFERRED HOLE",
+                summary: "A `_` in a type annotation was inferred to be a certain type. This is shown by `roc check --show-holes`.",
+                example: "double : _ -> U64\ndouble = \\n -> n * 2",
+                fix: "Nothing is wrong. If you want, replace the `_` with the inferred type shown in the report.",
+            },
+            RedundantPattern => Explanation {
+                title: "REDUNDANT PATTERN",
+                summary: "A pattern can never match, because the patterns before it already cover everything it would.",
+                example: "when color is\n    _ -> 0\n    Red -> 1",
+                fix: "Remove the pattern, or move it before the patterns that cover it.",
+            },
+            TooFewArgs => Explanation {
+                title: "TOO FEW ARGS",
+                summary: "A function is called with fewer arguments than it takes.",
+                example: "add = \\a, b -> a + b\n\nthree = add 1",
+                fix: "Pass all the arguments. Roc functions aren't curried, so they can't be partially applied.",
+            },
+            TooFewTypeArguments => Explanation {
+                title: "TOO FEW TYPE ARGUMENTS",
+                summary: "A type is given fewer type arguments than it takes.",
+                example: "pairs : Dict Str",
+                fix: "Pass all the type arguments, like `Dict Str U64`.",
+            },
+            TooManyArgs => Explanation {
+                title: "TOO MANY ARGS",
+                summary: "A function is called with more arguments than it takes.",
+                example: "increment = \\n -> n + 1\n\ntwo = increment 1 2",
+                fix: "Pass fewer arguments. Missing commas or parentheses can also make separate arguments look like one call.",
+            },
+            TooManyTypeArguments => Explanation {
+                title: "TOO MANY TYPE ARGUMENTS",
+                summary: "A type is given more type arguments than it takes.",
+                example: "name : Str U8",
+                fix: "Remove the extra type arguments, or add the missing parentheses, like `List (List U8)`.",
+            },
+            TypeMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A value is used in a way that disagrees with its type.",
+                example: "total = \"five\" + 1",
+                fix: "Compare the two types in the report. For large records and tag unions only the parts that differ are shown, and the tips point at the fields or tags to look at.",
+            },
+            NonExhaustiveWhen => Explanation {
+                title: "UNSAFE PATTERN",
+                summary: "A `when` does not cover all the possible values, so the program would crash on the ones it misses.",
+                example: "when color is\n    Red -> 1\n    Green -> 2",
+                fix: "Add branches for the missing possibilities listed in the report, or a catch-all `_` branch.",
+            },
+            WrongSpecializationType => Explanation {
+                title: "WRONG SPECIALIZATION TYPE",
+                summary: "An ability member's implementation is for a different type than the one it was claimed for.",
+                example: "Id := U64 has [Hash { hash: hashName }]\nName := Str\n\nhashName = \\@Name s -> Str.countUtf8Bytes s",
+                fix: "Make the implementation take the opaque type that claims it.",
+            },
+            AnnotationMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A definition's body, or a branch of it, disagrees with its type annotation.",
+                example: "total : U64\ntotal = \"five\"",
+                fix: "Change the definition or the annotation so that they agree.",
+            },
+            ExpectConditionNotBool => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The condition of an `expect` is not a `Bool`.",
+                example: "expect 1 + 1",
+                fix: "Make the condition a comparison, like `expect 1 + 1 == 2`.",
+            },
+            CrashMessageNotStr => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The message given to `crash` is not a `Str`.",
+                example: "x = crash 42",
+                fix: "Pass a string, like `crash \"unreachable\"`.",
+            },
+            IfConditionNotBool => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The condition of an `if` is not a `Bool`.",
+                example: "x = if 1 then \"yes\" else \"no\"",
+                fix: "Make the condition a `Bool`, for example with a comparison like `if n == 1 then ...`.",
+            },
+            WhenGuardNotBool => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The guard of a `when` branch, after `if`, is not a `Bool`.",
+                example: "when n is\n    x if x -> 1\n    _ -> 0",
+                fix: "Make the guard a `Bool`, for example with a comparison like `x if x > 0 -> 1`.",
+            },
+            IfBranchMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The branches of an `if` have different types.",
+                example: "x = if True then 1 else \"one\"",
+                fix: "Make every branch evaluate to the same type.",
+            },
+            WhenBranchMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The branches of a `when` have different types.",
+                example: "when n is\n    1 -> \"one\"\n    _ -> 0",
+                fix: "Make every branch evaluate to the same type.",
+            },
+            ListElementMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The elements of a list have different types.",
+                example: "items = [1, \"two\"]",
+                fix: "Make every element the same type. To mix kinds of values, wrap them in tags, like `[Num 1, Str \"two\"]`.",
+            },
+            RecordUpdateValueMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A record update gives a field a value of a different type than the field has.",
+                example: "user = { name: \"Ayla\" }\n\nrenamed = { user & name: 42 }",
+                fix: "Give the field a value of the type it already has.",
+            },
+            RecordUpdateMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A record update changes a record in a way its type doesn't allow.",
+                example: "point = { x: 1, y: 2 }\n\nmoved = { point & x: \"left\" }",
+                fix: "Make the updated fields keep the types they have in the record.",
+            },
+            RecordUpdateNotRecord => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A record update is applied to a value that isn't a record.",
+                example: "n = 5\n\nx = { n & value: 1 }",
+                fix: "Only records can be updated. Check that the name refers to the record you meant.",
+            },
+            CalledNonFunction => Explanation {
+                title: "TOO MANY ARGS",
+                summary: "A value that isn't a function is called with arguments.",
+                example: "x = 5\n\ny = x 1",
+                fix: "Check that the called name is the function you meant. Missing commas or operators can also make values look like a call.",
+            },
+            ArgumentMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "An argument passed to a function has a different type than the function takes.",
+                example: "x = Str.concat \"a\" 1",
+                fix: "Pass a value of the type the function expects, or convert the value first.",
+            },
+            NumericSuffixMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A number literal's suffix disagrees with how the number is used.",
+                example: "x : U8\nx = 5u16",
+                fix: "Use the suffix of the type the number is used as, or remove the suffix.",
+            },
+            InvalidAbilityMemberSpecialization => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "An implementation of an ability member has a type that doesn't match the member's signature.",
+                example: "Id := U64 has [Hash { hash: hashId }]\n\nhashId = \\@Id n -> n",
+                fix: "Give the implementation the type the ability member's signature asks for.",
+            },
+            GeneralizedAbilityMemberSpecialization => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "An implementation of an ability member is more general than the type it is for, so it can't be used for that type only.",
+                example: "Id := U64 has [Hash { hash: hashId }]\n\nhashId = \\_ -> 0",
+                fix: "Make the implementation take the opaque type it is for.",
+            },
+            WhenConditionMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The patterns of a `when` branch are for a different type than the value being matched.",
+                example: "when \"hi\" is\n    Ok x -> x\n    _ -> \"\"",
+                fix: "Match on patterns for the type of the `when` condition.",
+            },
+            TypedArgumentMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "An argument of a function disagrees with the function's type annotation.",
+                example: "f : Str -> Str\nf = \\1 -> \"one\"",
+                fix: "Make the argument pattern match the type in the annotation, or change the annotation.",
+            },
+            ExpressionMissingAbility => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "An expression has a type that doesn't implement an ability it needs to.",
+                example: "x = Hash.hash 0 (\\n -> n)",
+                fix: "Use a value whose type implements the ability. Functions never implement abilities.",
+            },
+            PatternMissingAbility => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A pattern has a type that doesn't implement an ability it needs to.",
+                example: "f = \\a -> a == (\\n -> n)",
+                fix: "Use a value whose type implements the ability. Functions never implement abilities.",
+            },
+            UnfulfilledAbility => Explanation {
+                title: "INCOMPLETE ABILITY IMPLEMENTATION",
+                summary: "A type is used with an ability that it doesn't implement.",
+                example: "Id := U64\n\nmain = Inspect.toStr (@Id 1)",
+                fix: "Add the ability to the type's `has` clause, either with `has [Inspect]` to derive it or with your own implementation.",
+            },
+            UnexposedName => Explanation {
+                title: "UNRECOGNIZED NAME",
+                summary: "A module is asked for a name that it doesn't expose.",
+                example: "main = Str.lenght \"hi\"",
+                fix: "Check the spelling, and check that the module exposes the name.",
+            },
+            PatternMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A pattern disagrees with the type of the value it matches.",
+                example: "{ x } = 5",
+                fix: "Change the pattern to match the type of the value.",
+            },
+            ArgumentPatternMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "The pattern of a function argument disagrees with the function's type annotation.",
+                example: "f : { x : U64 } -> U64\nf = \\{ y } -> y",
+                fix: "Make the pattern match the type in the annotation, or change the annotation.",
+            },
+            WhenPatternMismatch => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A pattern of a `when` is for a different type than the value or the other patterns.",
+                example: "when x is\n    1 -> 1\n    \"one\" -> 1\n    _ -> 0",
+                fix: "Make every pattern of the `when` match the type of the condition.",
+            },
+            MissingRecordField => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A record field is used that the record doesn't have, often because of a typo.",
+                example: "user = { name: \"Ayla\" }\n\nx = user.nmae",
+                fix: "Check the spelling. The report suggests fields that the record does have.",
+            },
+            RecordUpdateUnknownField => Explanation {
+                title: "TYPE MISMATCH",
+                summary: "A record update sets a field that the record doesn't have.",
+                example: "user = { name: \"Ayla\" }\n\nrenamed = { user & nmae: \"Ray\" }",
+                fix: "Record updates can only change existing fields. Check the spelling, or build a new record to add fields.",
+            },
+            NonExhaustiveArgument => Explanation {
+                title: "UNSAFE PATTERN",
+                summary: "A function argument pattern does not cover all the possible values of the argument.",
+                example: "f = \\Ok x -> x",
+                fix: "Take the argument as a name and match on it with a `when` in the function body.",
+            },
+            NonExhaustiveDestructure => Explanation {
+                title: "UNSAFE PATTERN",
+                summary: "A definition destructures a value with a pattern that does not cover all its possible values.",
+                example: "(Ok x) = Str.toU64 \"5\"",
+                fix: "Use a `when` to handle all the possibilities.",
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The text `roc explain <code>` prints for a code.
+pub fn explain(code: &str) -> Option<String> {
+    let error_code = ErrorCode::from_str_ignore_case(code)?;
+    let explanation = error_code.explanation();
+
+    let example = explanation
+        .example
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("    {}", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Some(format!(
+        "{} {}\n\n{}\n\nFor example:\n\n{}\n\n{}\n",
+        error_code, explanation.title, explanation.summary, example, explanation.fix
+    ))
+}
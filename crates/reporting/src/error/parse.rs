@@ -2,6 +2,7 @@ use roc_parse::parser::{ENumber, FileError, SyntaxError};
 use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Position, Region};
 use std::path::PathBuf;
 
+use crate::error::explain::ErrorCode;
 use crate::report::{Report, RocDocAllocator, RocDocBuilder, Severity};
 use ven_pretty::DocAllocator;
 
@@ -58,6 +59,7 @@ fn to_syntax_report<'a>(
         filename: filename.clone(),
         doc,
         title: "PARSE PROBLEM".to_string(),
+        code: Some(ErrorCode::ParseProblem),
         severity: Severity::RuntimeError,
     };
 
@@ -72,6 +74,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: Some(ErrorCode::UnexpectedTokensBeforeEquals),
                 severity: Severity::RuntimeError,
             }
         }
@@ -104,6 +107,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "NOT END OF FILE".to_string(),
+                code: Some(ErrorCode::NotEndOfFile),
                 severity: Severity::RuntimeError,
             }
         }
@@ -117,6 +121,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: Some(ErrorCode::UnexpectedEndOfFile),
                 severity: Severity::RuntimeError,
             }
         }
@@ -127,6 +132,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: Some(ErrorCode::OutdentedTooFar),
                 severity: Severity::RuntimeError,
             }
         }
@@ -206,6 +212,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
+                code: Some(ErrorCode::ArgumentsBeforeEquals),
                 severity: Severity::RuntimeError,
             }
         }
@@ -293,6 +300,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "UNKNOWN OPERATOR".to_string(),
+                code: Some(ErrorCode::UnknownOperator),
                 severity: Severity::RuntimeError,
             }
         }
@@ -319,14 +327,16 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IDENTIFIER".to_string(),
+                code: Some(ErrorCode::WeirdIdentifier),
                 severity: Severity::RuntimeError,
             }
         }
 
         EExpr::Start(pos) | EExpr::IndentStart(pos) => {
-            let (title, expecting) = match &context {
+            let (title, code, expecting) = match &context {
                 Context::InNode { .. } | Context::InDef { .. } => (
                     "MISSING EXPRESSION",
+                    ErrorCode::MissingExpression,
                     alloc.concat([
                         alloc.reflow("I was expecting to see an expression like "),
                         alloc.parser_suggestion("42"),
@@ -337,6 +347,7 @@ fn to_expr_report<'a>(
                 ),
                 Context::InDefFinalExpr { .. } => (
                     "MISSING FINAL EXPRESSION",
+                    ErrorCode::MissingFinalExpression,
                     alloc.stack([
                         alloc.concat([
                             alloc.reflow("This definition is missing a final expression."),
@@ -399,6 +410,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: title.to_string(),
+                code: Some(code),
                 severity: Severity::RuntimeError,
             }
         }
@@ -427,6 +439,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "MISSING FINAL EXPRESSION".to_string(),
+                code: Some(ErrorCode::MissingFinalExpression),
                 severity: Severity::RuntimeError,
             }
         }
@@ -458,6 +471,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "SYNTAX PROBLEM".to_string(),
+                code: Some(ErrorCode::UnexpectedExpressionEnd),
                 severity: Severity::RuntimeError,
             }
         }
@@ -481,6 +495,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
+                code: Some(ErrorCode::ArgumentsBeforeEquals),
                 severity: Severity::RuntimeError,
             }
         }
@@ -499,6 +514,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "BAD BACKPASSING ARROW".to_string(),
+                code: Some(ErrorCode::BadBackpassingArrow),
                 severity: Severity::RuntimeError,
             }
         }
@@ -517,6 +533,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "RECORD PARSE PROBLEM".to_string(),
+                code: Some(ErrorCode::RecordParseProblem),
                 severity: Severity::RuntimeError,
             }
         }
@@ -564,6 +581,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD ARROW".to_string(),
+                    code: Some(ErrorCode::WeirdArrow),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -586,6 +604,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: Some(ErrorCode::LambdaMissingArrow),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -611,6 +630,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD ARROW".to_string(),
+                    code: Some(ErrorCode::WeirdArrow),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -633,6 +653,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: Some(ErrorCode::LambdaMissingArrow),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -657,6 +678,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED ARGUMENT LIST".to_string(),
+                    code: Some(ErrorCode::UnfinishedArgumentList),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -678,6 +700,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: Some(ErrorCode::LambdaMissingArrow),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -759,6 +782,7 @@ fn to_unfinished_lambda_report<'a>(
         filename,
         doc,
         title: "UNFINISHED FUNCTION".to_string(),
+        code: Some(ErrorCode::UnfinishedFunction),
         severity: Severity::RuntimeError,
     }
 }
@@ -825,6 +849,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD ESCAPE".to_string(),
+                code: Some(ErrorCode::WeirdEscape),
                 severity: Severity::RuntimeError,
             }
         }
@@ -851,6 +876,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD CODE POINT".to_string(),
+                code: Some(ErrorCode::WeirdCodePoint),
                 severity: Severity::RuntimeError,
             }
         }
@@ -872,6 +898,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS FORMAT".to_string(),
+                code: Some(ErrorCode::EndlessFormat),
                 severity: Severity::RuntimeError,
             }
         }
@@ -895,6 +922,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS STRING".to_string(),
+                code: Some(ErrorCode::EndlessString),
                 severity: Severity::RuntimeError,
             }
         }
@@ -918,6 +946,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS STRING".to_string(),
+                code: Some(ErrorCode::EndlessString),
                 severity: Severity::RuntimeError,
             }
         }
@@ -939,6 +968,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "INSUFFICIENT INDENT IN MULTI-LINE STRING".to_string(),
+                code: Some(ErrorCode::InsufficientIndentInMultilineString),
                 severity: Severity::RuntimeError,
             }
         }
@@ -956,6 +986,9 @@ fn to_expr_in_parens_report<'a>(
 
     match *parse_problem {
         EInParens::Space(error, pos) => to_space_report(alloc, lines, filename, &error, pos),
+        EInParens::Empty(pos) => {
+            to_empty_parens_report(alloc, lines, filename, start, pos, "an expression")
+        }
         EInParens::Expr(expr, pos) => to_expr_report(
             alloc,
             lines,
@@ -985,6 +1018,7 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some(ErrorCode::UnfinishedParentheses),
                 severity: Severity::RuntimeError,
             }
         }
@@ -1010,12 +1044,51 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some(ErrorCode::UnfinishedParentheses),
                 severity: Severity::RuntimeError,
             }
         }
     }
 }
 
+/// `()`, which is neither something in parentheses nor a tuple; `what` is what we expected to
+/// find inside them, e.g. "an expression"
+fn to_empty_parens_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    start: Position,
+    pos: Position,
+    what: &'static str,
+) -> Report<'a> {
+    let surroundings = Region::new(start, pos.bump_column(2));
+    let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+    let doc = alloc.stack([
+        alloc.concat([
+            alloc.reflow("I was expecting to see "),
+            alloc.reflow(what),
+            alloc.reflow(" or a tuple inside these parentheses, but they are empty:"),
+        ]),
+        alloc.region_with_subregion(lines.convert_region(surroundings), region),
+        alloc.concat([
+            alloc.reflow("Roc has no "),
+            alloc.parser_suggestion("()"),
+            alloc.reflow(" value. If you need a value that holds nothing, use the empty record "),
+            alloc.parser_suggestion("{}"),
+            alloc.reflow(" instead."),
+        ]),
+    ]);
+
+    Report {
+        filename,
+        doc,
+        title: "EMPTY PARENTHESES".to_string(),
+        code: Some(ErrorCode::EmptyParentheses),
+        severity: Severity::RuntimeError,
+    }
+}
+
 fn to_list_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
@@ -1060,6 +1133,7 @@ fn to_list_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED LIST".to_string(),
+                        code: Some(ErrorCode::UnfinishedList),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1093,6 +1167,7 @@ fn to_list_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED LIST".to_string(),
+                        code: Some(ErrorCode::UnfinishedList),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1121,6 +1196,7 @@ fn to_list_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED LIST".to_string(),
+                code: Some(ErrorCode::UnfinishedList),
                 severity: Severity::RuntimeError,
             }
         }
@@ -1236,6 +1312,7 @@ fn to_unfinished_if_report<'a>(
         filename,
         doc,
         title: "UNFINISHED IF".to_string(),
+        code: Some(ErrorCode::UnfinishedIf),
         severity: Severity::RuntimeError,
     }
 }
@@ -1269,6 +1346,7 @@ fn to_when_report<'a>(
                         filename,
                         doc,
                         title: "IF GUARD NO CONDITION".to_string(),
+                        code: Some(ErrorCode::IfGuardNoCondition),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1301,6 +1379,7 @@ fn to_when_report<'a>(
                 filename,
                 doc,
                 title: "MISSING ARROW".to_string(),
+                code: Some(ErrorCode::WhenMissingArrow),
                 severity: Severity::RuntimeError,
             }
         }
@@ -1456,6 +1535,7 @@ fn to_unfinished_when_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED WHEN".to_string(),
+                code: Some(ErrorCode::UnfinishedWhen),
                 severity: Severity::RuntimeError,
             }
         }
@@ -1496,6 +1576,7 @@ fn to_unexpected_arrow_report<'a>(
         filename,
         doc,
         title: "UNEXPECTED ARROW".to_string(),
+        code: Some(ErrorCode::UnexpectedArrow),
         severity: Severity::RuntimeError,
     }
 }
@@ -1572,6 +1653,7 @@ fn to_pattern_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PATTERN".to_string(),
+                code: Some(ErrorCode::UnfinishedPattern),
                 severity: Severity::RuntimeError,
             }
         }
@@ -1582,6 +1664,36 @@ fn to_pattern_report<'a>(
         &EPattern::NumLiteral(ENumber::End, pos) => {
             to_malformed_number_literal_report(alloc, lines, filename, pos)
         }
+        EPattern::AsIdentifier(pos) => {
+            let surroundings = Region::new(start, *pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
+
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow(r"I was expecting to see a name after this "),
+                    alloc.keyword("as"),
+                    alloc.reflow(", but I got stuck here:"),
+                ]),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([
+                    alloc.reflow("The name after "),
+                    alloc.keyword("as"),
+                    alloc.reflow(" is bound to the whole value the pattern matches, like "),
+                    alloc.parser_suggestion("result"),
+                    alloc.reflow(" in "),
+                    alloc.parser_suggestion("Ok x as result"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "UNFINISHED PATTERN".to_string(),
+                code: Some(ErrorCode::UnfinishedAsPattern),
+                severity: Severity::RuntimeError,
+            }
+        }
         _ => todo!("unhandled parse error: {:?}", parse_problem),
     }
 }
@@ -1615,6 +1727,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: Some(ErrorCode::UnfinishedRecordPattern),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -1632,6 +1745,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: Some(ErrorCode::UnfinishedRecordPattern),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -1657,6 +1771,7 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        code: Some(ErrorCode::UnfinishedRecordPattern),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1677,6 +1792,7 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        code: Some(ErrorCode::UnfinishedRecordPattern),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1702,6 +1818,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: Some(ErrorCode::UnfinishedRecordPattern),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -1727,6 +1844,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "PROBLEM IN RECORD PATTERN".to_string(),
+                    code: Some(ErrorCode::ProblemInRecordPattern),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -1769,6 +1887,7 @@ fn to_precord_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED RECORD PATTERN".to_string(),
+                code: Some(ErrorCode::UnfinishedRecordPattern),
                 severity: Severity::RuntimeError,
             }
         }
@@ -1793,6 +1912,7 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: Some(ErrorCode::RecordPatternNeedsMoreIndentation),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1818,6 +1938,7 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        code: Some(ErrorCode::UnfinishedRecordPattern),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1869,6 +1990,7 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some(ErrorCode::UnfinishedPatternParentheses),
                 severity: Severity::RuntimeError,
             }
         }
@@ -1893,12 +2015,17 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some(ErrorCode::UnfinishedPatternParentheses),
                 severity: Severity::RuntimeError,
             }
         }
 
         PInParens::Pattern(pattern, pos) => to_pattern_report(alloc, lines, filename, pattern, pos),
 
+        PInParens::Empty(pos) => {
+            to_empty_parens_report(alloc, lines, filename, start, pos, "a pattern")
+        }
+
         PInParens::IndentOpen(pos) => {
             let surroundings = Region::new(start, pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
@@ -1916,6 +2043,7 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some(ErrorCode::UnfinishedPatternParentheses),
                 severity: Severity::RuntimeError,
             }
         }
@@ -1940,6 +2068,7 @@ fn to_pattern_in_parens_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: Some(ErrorCode::PatternParenthesesNeedMoreIndentation),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1965,6 +2094,7 @@ fn to_pattern_in_parens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some(ErrorCode::UnfinishedPatternParentheses),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -1993,6 +2123,7 @@ fn to_malformed_number_literal_report<'a>(
         filename,
         doc,
         title: "INVALID NUMBER LITERAL".to_string(),
+        code: Some(ErrorCode::InvalidNumberLiteral),
         severity: Severity::RuntimeError,
     }
 }
@@ -2033,6 +2164,7 @@ fn to_type_report<'a>(
                         filename,
                         doc,
                         title: "DOUBLE COMMA".to_string(),
+                        code: Some(ErrorCode::DoubleComma),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2060,6 +2192,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: Some(ErrorCode::UnfinishedType),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2078,6 +2211,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: Some(ErrorCode::UnfinishedType),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2096,6 +2230,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: Some(ErrorCode::UnfinishedType),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2114,6 +2249,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED INLINE ALIAS".to_string(),
+                code: Some(ErrorCode::UnfinishedInlineAlias),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2131,6 +2267,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "BAD TYPE VARIABLE".to_string(),
+                code: Some(ErrorCode::BadTypeVariable),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2168,6 +2305,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: Some(ErrorCode::UnfinishedRecordType),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2189,6 +2327,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: Some(ErrorCode::UnfinishedRecordType),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2214,6 +2353,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: Some(ErrorCode::UnfinishedRecordType),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2234,6 +2374,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: Some(ErrorCode::UnfinishedRecordType),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2259,6 +2400,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: Some(ErrorCode::UnfinishedRecordType),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2284,6 +2426,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "PROBLEM IN RECORD TYPE".to_string(),
+                    code: Some(ErrorCode::ProblemInRecordType),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2317,6 +2460,7 @@ fn to_trecord_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED RECORD TYPE".to_string(),
+                code: Some(ErrorCode::UnfinishedRecordType),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2341,6 +2485,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: Some(ErrorCode::RecordTypeNeedsMoreIndentation),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2366,6 +2511,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: Some(ErrorCode::UnfinishedRecordType),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2413,6 +2559,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
+                    code: Some(ErrorCode::UnfinishedTagUnionType),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2435,6 +2582,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD TAG NAME".to_string(),
+                    code: Some(ErrorCode::WeirdTagName),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2456,6 +2604,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
+                    code: Some(ErrorCode::UnfinishedTagUnionType),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -2482,6 +2631,7 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: Some(ErrorCode::WeirdTagName),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2502,6 +2652,7 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED TAG UNION TYPE".to_string(),
+                        code: Some(ErrorCode::UnfinishedTagUnionType),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2529,6 +2680,7 @@ fn to_ttag_union_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TAG UNION TYPE".to_string(),
+                code: Some(ErrorCode::UnfinishedTagUnionType),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2556,6 +2708,7 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: Some(ErrorCode::TagUnionTypeNeedsMoreIndentation),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2581,6 +2734,7 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED TAG UNION TYPE".to_string(),
+                        code: Some(ErrorCode::UnfinishedTagUnionType),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2622,6 +2776,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some(ErrorCode::UnfinishedTypeParentheses),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2644,6 +2799,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: Some(ErrorCode::WeirdTagNameInParentheses),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2667,6 +2823,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some(ErrorCode::UnfinishedTypeParentheses),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2695,6 +2852,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: Some(ErrorCode::WeirdTagNameInParentheses),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2715,6 +2873,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some(ErrorCode::UnfinishedTypeParentheses),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2723,6 +2882,10 @@ fn to_tinparens_report<'a>(
 
         ETypeInParens::Type(tipe, pos) => to_type_report(alloc, lines, filename, tipe, pos),
 
+        ETypeInParens::Empty(pos) => {
+            to_empty_parens_report(alloc, lines, filename, start, pos, "a type")
+        }
+
         ETypeInParens::IndentOpen(pos) => {
             let surroundings = Region::new(start, pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
@@ -2743,6 +2906,7 @@ fn to_tinparens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some(ErrorCode::UnfinishedTypeParentheses),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2767,6 +2931,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: Some(ErrorCode::TypeParenthesesNeedMoreIndentation),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2792,6 +2957,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some(ErrorCode::UnfinishedTypeParentheses),
                         severity: Severity::RuntimeError,
                     }
                 }
@@ -2825,6 +2991,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "DOUBLE DOT".to_string(),
+                code: Some(ErrorCode::DoubleDot),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2847,6 +3014,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "TRAILING DOT".to_string(),
+                code: Some(ErrorCode::TrailingDot),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2869,6 +3037,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
+                code: Some(ErrorCode::WeirdQualifiedName),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2891,6 +3060,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
+                code: Some(ErrorCode::WeirdQualifiedName),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2909,6 +3079,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "END OF FILE".to_string(),
+                code: Some(ErrorCode::UnfinishedQualifiedType),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2949,6 +3120,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "NOT AN INLINE ALIAS".to_string(),
+                code: Some(ErrorCode::NotAnInlineAlias),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2965,6 +3137,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "QUALIFIED ALIAS NAME".to_string(),
+                code: Some(ErrorCode::QualifiedAliasName),
                 severity: Severity::RuntimeError,
             }
         }
@@ -2981,6 +3154,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "TYPE ARGUMENT NOT LOWERCASE".to_string(),
+                code: Some(ErrorCode::TypeArgumentNotLowercase),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3027,6 +3201,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INCOMPLETE HEADER".to_string(),
+                code: Some(ErrorCode::IncompleteHeader),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3053,6 +3228,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "MISSING HEADER".to_string(),
+                code: Some(ErrorCode::MissingHeader),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3077,6 +3253,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: Some(ErrorCode::WeirdModuleName),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3101,6 +3278,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD APP NAME".to_string(),
+                code: Some(ErrorCode::WeirdAppName),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3123,6 +3301,30 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: Some(ErrorCode::WeirdPlatformName),
+                severity: Severity::RuntimeError,
+            }
+        }
+
+        EHeader::PackageName(_, pos) => {
+            let surroundings = Region::new(start, *pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(*pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"I am partway through parsing a header, but got stuck here:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([
+                    alloc.reflow("I am expecting a package name next, like "),
+                    alloc.parser_suggestion("\"roc/json\""),
+                    alloc.reflow(". Package names must be quoted."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "WEIRD MODULE NAME".to_string(),
+                code: Some(ErrorCode::WeirdPackageName),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3146,6 +3348,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATED TYPE NAME".to_string(),
+                code: Some(ErrorCode::WeirdGeneratedTypeName),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3186,6 +3389,7 @@ fn to_generates_with_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATES".to_string(),
+                code: Some(ErrorCode::WeirdGenerates),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3211,6 +3415,7 @@ fn to_generates_with_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATES".to_string(),
+                code: Some(ErrorCode::WeirdGenerates),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3252,6 +3457,7 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                code: Some(ErrorCode::WeirdProvides),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3277,6 +3483,7 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                code: Some(ErrorCode::WeirdProvides),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3317,6 +3524,7 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                code: Some(ErrorCode::WeirdExposes),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3342,6 +3550,7 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                code: Some(ErrorCode::WeirdExposes),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3381,6 +3590,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: Some(ErrorCode::WeirdImports),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3406,6 +3616,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: Some(ErrorCode::WeirdImports),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3432,6 +3643,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: Some(ErrorCode::WeirdImportedModuleName),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3451,6 +3663,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: Some(ErrorCode::WeirdImports),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3490,6 +3703,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "MISSING REQUIRES".to_string(),
+                code: Some(ErrorCode::MissingRequires),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3517,6 +3731,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "MISSING REQUIRES".to_string(),
+                code: Some(ErrorCode::MissingRequires),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3546,6 +3761,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "BAD REQUIRES RIGIDS".to_string(),
+                code: Some(ErrorCode::BadRequiresRigids),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3575,6 +3791,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "BAD REQUIRES".to_string(),
+                code: Some(ErrorCode::BadRequires),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3612,6 +3829,7 @@ fn to_packages_report<'a>(
                 filename,
                 doc,
                 title: "MISSING PACKAGES".to_string(),
+                code: Some(ErrorCode::MissingPackages),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3645,6 +3863,7 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "TAB CHARACTER".to_string(),
+                code: Some(ErrorCode::TabCharacter),
                 severity: Severity::RuntimeError,
             }
         }
@@ -3726,6 +3945,7 @@ fn to_unfinished_ability_report<'a>(
         filename,
         doc,
         title: "UNFINISHED ABILITY".to_string(),
+        code: Some(ErrorCode::UnfinishedAbility),
         severity: Severity::RuntimeError,
     }
 }
@@ -1,11 +1,12 @@
 use crate::error::canonicalize::{to_circular_def_doc, CIRCULAR_DEF};
+use crate::error::explain::ErrorCode;
 use crate::report::{Annotation, Report, RocDocAllocator, RocDocBuilder, Severity};
 use roc_can::expected::{Expected, PExpected};
 use roc_collections::all::{HumanIndex, MutSet, SendMap};
 use roc_error_macros::internal_error;
 use roc_exhaustive::CtorName;
 use roc_module::called_via::{BinOp, CalledVia};
-use roc_module::ident::{Ident, IdentStr, Lowercase, TagName};
+use roc_module::ident::{tuple_arity, Ident, IdentStr, Lowercase, TagName};
 use roc_module::symbol::Symbol;
 use roc_region::all::{LineInfo, Loc, Region};
 use roc_solve_problem::{
@@ -22,6 +23,10 @@ use ven_pretty::DocAllocator;
 const DUPLICATE_NAME: &str = "DUPLICATE NAME";
 const ADD_ANNOTATIONS: &str = r#"Can more type annotations be added? Type annotations always help me give more specific messages, and I think they could help a lot in this case"#;
 
+/// When comparing records or tag unions with more entries than this, only the entries that
+/// differ are shown; the rest are elided with `…`.
+const MAX_ENTRIES_BEFORE_ELIDING: usize = 4;
+
 const OPAQUE_NUM_SYMBOLS: &[Symbol] = &[
     Symbol::NUM_NUM,
     Symbol::NUM_INTEGER,
@@ -36,9 +41,15 @@ pub fn type_problem<'b>(
 ) -> Option<Report<'b>> {
     use TypeError::*;
 
-    fn report(title: String, doc: RocDocBuilder<'_>, filename: PathBuf) -> Option<Report<'_>> {
+    fn report(
+        title: String,
+        code: ErrorCode,
+        doc: RocDocBuilder<'_>,
+        filename: PathBuf,
+    ) -> Option<Report<'_>> {
         Some(Report {
             title,
+            code: Some(code),
             filename,
             doc,
             severity: Severity::RuntimeError,
@@ -70,7 +81,7 @@ pub fn type_problem<'b>(
                     .append(alloc.symbol_unqualified(symbol))])
                 .append(alloc.reflow("."));
 
-            report(title, doc, filename)
+            report(title, ErrorCode::UnexposedName, doc, filename)
         }
         BadType(type_problem) => {
             use roc_types::types::Problem::*;
@@ -108,19 +119,25 @@ pub fn type_problem<'b>(
                         alloc.reflow("Are there missing parentheses?"),
                     ]);
 
-                    let title = if type_got > alias_needs {
-                        "TOO MANY TYPE ARGUMENTS".to_string()
+                    let (title, code) = if type_got > alias_needs {
+                        (
+                            "TOO MANY TYPE ARGUMENTS".to_string(),
+                            ErrorCode::TooManyTypeArguments,
+                        )
                     } else {
-                        "TOO FEW TYPE ARGUMENTS".to_string()
+                        (
+                            "TOO FEW TYPE ARGUMENTS".to_string(),
+                            ErrorCode::TooFewTypeArguments,
+                        )
                     };
 
-                    report(title, doc, filename)
+                    report(title, code, doc, filename)
                 }
                 Shadowed(original_region, shadow) => {
                     let doc = report_shadowing(alloc, lines, original_region, shadow);
                     let title = DUPLICATE_NAME.to_string();
 
-                    report(title, doc, filename)
+                    report(title, ErrorCode::DuplicateName, doc, filename)
                 }
 
                 SolvedTypeError => None, // Don't re-report cascading errors - see https://github.com/roc-lang/roc/pull/1711
@@ -137,7 +154,7 @@ pub fn type_problem<'b>(
 
             let doc = report_unfulfilled_ability(alloc, lines, incomplete);
 
-            report(title, doc, filename)
+            report(title, ErrorCode::UnfulfilledAbility, doc, filename)
         }
         BadExprMissingAbility(region, _category, _found, incomplete) => {
             let incomplete = incomplete
@@ -155,6 +172,7 @@ pub fn type_problem<'b>(
 
             let report = Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: Some(ErrorCode::ExpressionMissingAbility),
                 filename,
                 doc: alloc.stack(stack),
                 severity: Severity::RuntimeError,
@@ -177,6 +195,7 @@ pub fn type_problem<'b>(
 
             let report = Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: Some(ErrorCode::PatternMissingAbility),
                 filename,
                 doc: alloc.stack(stack),
                 severity: Severity::RuntimeError,
@@ -191,6 +210,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title,
+                code: Some(ErrorCode::CircularDefinition),
                 filename,
                 doc,
                 severity,
@@ -221,6 +241,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "ILLEGAL SPECIALIZATION".to_string(),
+                code: Some(ErrorCode::IllegalSpecialization),
                 filename,
                 doc: alloc.stack(stack),
                 severity: Severity::RuntimeError,
@@ -250,11 +271,13 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "WRONG SPECIALIZATION TYPE".to_string(),
+                code: Some(ErrorCode::WrongSpecializationType),
                 filename,
                 doc: alloc.stack(stack),
                 severity: Severity::RuntimeError,
             })
         }
+        InferredHole { region, typ } => Some(inferred_hole(alloc, lines, filename, region, typ)),
     }
 }
 
@@ -456,6 +479,33 @@ fn report_shadowing<'b>(
     ])
 }
 
+/// The solved type of a `_` in an annotation, for `roc check --show-holes`
+fn inferred_hole<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    region: Region,
+    typ: String,
+) -> Report<'b> {
+    let doc = alloc.stack([
+        alloc.concat([
+            alloc.reflow("This "),
+            alloc.parser_suggestion("_"),
+            alloc.reflow(" in an annotation was inferred to be:"),
+        ]),
+        alloc.region(lines.convert_region(region)),
+        alloc.type_block(alloc.string(typ)),
+    ]);
+
+    Report {
+        title: "INFERRED HOLE".to_string(),
+        code: Some(ErrorCode::InferredHole),
+        filename,
+        doc,
+        severity: Severity::Warning,
+    }
+}
+
 pub fn cyclic_alias<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
@@ -518,6 +568,7 @@ fn report_mismatch<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
     filename: PathBuf,
+    code: ErrorCode,
     category: &Category,
     found: ErrorType,
     expected_type: ErrorType,
@@ -552,6 +603,7 @@ fn report_mismatch<'b>(
 
     Report {
         title: "TYPE MISMATCH".to_string(),
+        code: Some(code),
         filename,
         doc: alloc.stack(lines),
         severity: Severity::RuntimeError,
@@ -563,6 +615,7 @@ fn report_bad_type<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
     filename: PathBuf,
+    code: ErrorCode,
     category: &Category,
     found: ErrorType,
     expected_type: ErrorType,
@@ -595,6 +648,7 @@ fn report_bad_type<'b>(
 
     Report {
         title: "TYPE MISMATCH".to_string(),
+        code: Some(code),
         filename,
         doc: alloc.stack(lines),
         severity: Severity::RuntimeError,
@@ -652,6 +706,7 @@ fn to_expr_report<'b>(
                             alloc,
                             lines,
                             filename,
+                            ErrorCode::MissingRecordField,
                             opt_sym,
                             ".",
                             field,
@@ -677,6 +732,7 @@ fn to_expr_report<'b>(
             Report {
                 filename,
                 title: "TYPE MISMATCH".to_string(),
+                code: Some(ErrorCode::TypeMismatch),
                 doc: alloc.stack([
                     alloc.text("This expression is used in an unexpected way:"),
                     alloc.region(lines.convert_region(expr_region)),
@@ -791,6 +847,7 @@ fn to_expr_report<'b>(
 
             Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: Some(ErrorCode::AnnotationMismatch),
                 filename,
                 doc: alloc.stack([
                     alloc.text("Something is off with the ").append(thing),
@@ -822,6 +879,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::ExpectConditionNotBool,
                     &category,
                     found,
                     expected_type,
@@ -848,6 +906,34 @@ fn to_expr_report<'b>(
                     // they don't know. ("Wait, what's truthiness?")
                 )
             }
+            Reason::CrashArg => {
+                let problem = alloc.concat([
+                    alloc.text("This value passed to "),
+                    alloc.keyword("crash"),
+                    alloc.text(" is not a string:"),
+                ]);
+
+                report_bad_type(
+                    alloc,
+                    lines,
+                    filename,
+                    ErrorCode::CrashMessageNotStr,
+                    &category,
+                    found,
+                    expected_type,
+                    region,
+                    Some(expr_region),
+                    problem,
+                    alloc.text("The value is"),
+                    alloc.concat([
+                        alloc.reflow("But I can only "),
+                        alloc.keyword("crash"),
+                        alloc.reflow(" with messages of type "),
+                        alloc.type_str("Str"),
+                        alloc.reflow("."),
+                    ]),
+                )
+            }
             Reason::IfCondition => {
                 let problem = alloc.concat([
                     alloc.text("This "),
@@ -861,6 +947,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::IfConditionNotBool,
                     &category,
                     found,
                     expected_type,
@@ -899,6 +986,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::WhenGuardNotBool,
                     &category,
                     found,
                     expected_type,
@@ -927,6 +1015,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::IfBranchMismatch,
                     &category,
                     found,
                     expected_type,
@@ -961,6 +1050,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::IfBranchMismatch,
                     &category,
                     found,
                     expected_type,
@@ -986,6 +1076,7 @@ fn to_expr_report<'b>(
                 alloc,
                 lines,
                 filename,
+                ErrorCode::WhenBranchMismatch,
                 &category,
                 found,
                 expected_type,
@@ -1025,6 +1116,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::ListElementMismatch,
                     &category,
                     found,
                     expected_type,
@@ -1040,6 +1132,7 @@ fn to_expr_report<'b>(
                 alloc,
                 lines,
                 filename,
+                ErrorCode::RecordUpdateValueMismatch,
                 &category,
                 found,
                 expected_type,
@@ -1075,6 +1168,7 @@ fn to_expr_report<'b>(
                                 alloc,
                                 lines,
                                 filename,
+                                ErrorCode::RecordUpdateMismatch,
                                 &category,
                                 found,
                                 expected_type,
@@ -1093,6 +1187,7 @@ fn to_expr_report<'b>(
                                 alloc,
                                 lines,
                                 filename,
+                                ErrorCode::RecordUpdateUnknownField,
                                 Some(symbol),
                                 "",
                                 field,
@@ -1107,6 +1202,7 @@ fn to_expr_report<'b>(
                         alloc,
                         lines,
                         filename,
+                        ErrorCode::RecordUpdateNotRecord,
                         &category,
                         found,
                         expected_type,
@@ -1148,6 +1244,7 @@ fn to_expr_report<'b>(
                     Report {
                         filename,
                         title: "TOO MANY ARGS".to_string(),
+                        code: Some(ErrorCode::CalledNonFunction),
                         doc: alloc.stack(lines),
                         severity: Severity::RuntimeError,
                     }
@@ -1183,6 +1280,7 @@ fn to_expr_report<'b>(
                         Report {
                             filename,
                             title: "TOO MANY ARGS".to_string(),
+                            code: Some(ErrorCode::TooManyArgs),
                             doc: alloc.stack(lines),
                             severity: Severity::RuntimeError,
                         }
@@ -1210,6 +1308,7 @@ fn to_expr_report<'b>(
                         Report {
                             filename,
                             title: "TOO FEW ARGS".to_string(),
+                            code: Some(ErrorCode::TooFewArgs),
                             doc: alloc.stack(lines),
                             severity: Severity::RuntimeError,
                         }
@@ -1228,6 +1327,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::ArgumentMismatch,
                     &category,
                     found,
                     expected_type,
@@ -1252,6 +1352,7 @@ fn to_expr_report<'b>(
                 alloc,
                 lines,
                 filename,
+                ErrorCode::NumericSuffixMismatch,
                 &category,
                 found,
                 expected_type,
@@ -1303,6 +1404,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::InvalidAbilityMemberSpecialization,
                     &category,
                     found,
                     expected_type,
@@ -1343,6 +1445,7 @@ fn to_expr_report<'b>(
                     alloc,
                     lines,
                     filename,
+                    ErrorCode::GeneralizedAbilityMemberSpecialization,
                     &category,
                     found,
                     expected_type,
@@ -1394,6 +1497,7 @@ fn to_expr_report<'b>(
 
                 Report {
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some(ErrorCode::WhenConditionMismatch),
                     filename,
                     doc: alloc.stack(lines),
                     severity: Severity::RuntimeError,
@@ -1432,6 +1536,7 @@ fn to_expr_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some(ErrorCode::TypedArgumentMismatch),
                     doc,
                     severity: Severity::RuntimeError,
                 }
@@ -1690,6 +1795,11 @@ fn format_category<'b>(
             alloc.text(" of type:"),
         ),
 
+        Tuple => (
+            alloc.concat([this_is, alloc.text(" a tuple")]),
+            alloc.text(" of type:"),
+        ),
+
         Accessor(field) => (
             alloc.concat([
                 alloc.text(format!("{}his ", t)),
@@ -1762,6 +1872,10 @@ fn format_category<'b>(
             alloc.concat([this_is, alloc.text(" an expectation")]),
             alloc.text(" of type:"),
         ),
+        Crash => (
+            alloc.concat([this_is, alloc.text(" a "), alloc.keyword("crash")]),
+            alloc.text(" of type:"),
+        ),
     }
 }
 
@@ -1803,6 +1917,7 @@ fn to_pattern_report<'b>(
             Report {
                 filename,
                 title: "TYPE MISMATCH".to_string(),
+                code: Some(ErrorCode::PatternMismatch),
                 doc,
                 severity: Severity::RuntimeError,
             }
@@ -1846,6 +1961,7 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some(ErrorCode::ArgumentPatternMismatch),
                     doc,
                     severity: Severity::RuntimeError,
                 }
@@ -1926,6 +2042,7 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some(ErrorCode::WhenPatternMismatch),
                     doc,
                     severity: Severity::RuntimeError,
                 }
@@ -1973,6 +2090,8 @@ fn add_pattern_category<'b>(
 
     let rest = match category {
         Record => alloc.reflow(" record values of type:"),
+        Tuple => alloc.reflow(" tuples of type:"),
+        List => alloc.reflow(" lists of type:"),
         EmptyRecord => alloc.reflow(" an empty record:"),
         PatternGuard => alloc.reflow(" a pattern guard of type:"),
         PatternDefault => alloc.reflow(" an optional field of type:"),
@@ -2007,6 +2126,7 @@ fn to_circular_report<'b>(
 ) -> Report<'b> {
     Report {
         title: "CIRCULAR TYPE".to_string(),
+        code: Some(ErrorCode::CircularType),
         filename,
         doc: {
             alloc.stack([
@@ -2037,6 +2157,8 @@ pub enum Problem {
     FieldsMissing(Vec<Lowercase>),
     TagTypo(TagName, Vec<TagName>),
     TagsMissing(Vec<TagName>),
+    TagArityMismatch(TagName, usize, usize),
+    FieldTypesDiffer(Vec<Vec<Lowercase>>),
     BadRigidVar(Lowercase, ErrorType, Option<Symbol>),
     OptionalRequiredMismatch(Lowercase),
     OpaqueComparedToNonOpaque,
@@ -2280,6 +2402,14 @@ fn to_doc_help<'b>(
                 .collect(),
         ),
 
+        Record(fields_map, TypeExt::Closed) if is_tuple(&fields_map) => report_text::tuple(
+            alloc,
+            tuple_elems(fields_map)
+                .into_iter()
+                .map(|elem| to_doc_help(ctx, alloc, Parens::Unnecessary, elem))
+                .collect(),
+        ),
+
         Record(fields_map, ext) => {
             let mut fields = fields_map.into_iter().collect::<Vec<_>>();
             fields.sort_by(|(a, _), (b, _)| a.cmp(b));
@@ -2317,6 +2447,7 @@ fn to_doc_help<'b>(
                         )
                     })
                     .collect(),
+                false,
                 ext_to_doc(alloc, ext),
             )
         }
@@ -2340,6 +2471,7 @@ fn to_doc_help<'b>(
                 tags.into_iter()
                     .map(|(k, v)| (alloc.tag_name(k), v))
                     .collect(),
+                false,
                 ext_to_doc(alloc, ext),
             )
         }
@@ -2599,6 +2731,24 @@ fn to_diff<'b>(
             }
         }
 
+        (Alias(symbol, _, actual, AliasKind::Structural), other)
+        | (other, Alias(symbol, _, actual, AliasKind::Structural))
+            if symbol.module_id().is_builtin()
+                && compact_builtin_aliases((*actual).clone()) == other =>
+        {
+            // e.g. `U8` and `Int Unsigned8` are the same type, just written differently
+            let (left, left_able) = to_doc(alloc, parens, type1);
+            let (right, right_able) = to_doc(alloc, parens, type2);
+
+            Diff {
+                left,
+                right,
+                status: Status::Similar,
+                left_able,
+                right_able,
+            }
+        }
+
         (Alias(symbol, _, actual, AliasKind::Structural), other)
             if !symbol.module_id().is_builtin() =>
         {
@@ -2612,6 +2762,41 @@ fn to_diff<'b>(
             to_diff(alloc, parens, other, *actual)
         }
 
+        (Record(fields1, TypeExt::Closed), Record(fields2, TypeExt::Closed))
+            if is_tuple(&fields1) && fields1.len() == fields2.len() && is_tuple(&fields2) =>
+        {
+            let elems_diff = traverse(
+                alloc,
+                Parens::Unnecessary,
+                tuple_elems(fields1),
+                tuple_elems(fields2),
+            );
+
+            Diff {
+                left: report_text::tuple(alloc, elems_diff.left),
+                right: report_text::tuple(alloc, elems_diff.right),
+                status: elems_diff.status,
+                left_able: elems_diff.left_able,
+                right_able: elems_diff.right_able,
+            }
+        }
+
+        (Record(fields1, TypeExt::Closed), Record(fields2, TypeExt::Closed))
+            if is_tuple(&fields1) || is_tuple(&fields2) =>
+        {
+            // tuples of different lengths; there are no elements worth pointing out
+            let (left, left_able) = to_doc(alloc, Parens::Unnecessary, type1);
+            let (right, right_able) = to_doc(alloc, Parens::Unnecessary, type2);
+
+            Diff {
+                left,
+                right,
+                status: Status::Different(Vec::new()),
+                left_able,
+                right_able,
+            }
+        }
+
         (Record(fields1, ext1), Record(fields2, ext2)) => {
             diff_record(alloc, fields1, ext1, fields2, ext2)
         }
@@ -2735,6 +2920,41 @@ where
     }
 }
 
+/// Whether these are the fields of a tuple, i.e. exactly "0", "1", ... (there is no record
+/// syntax for numeric field names, so this is unambiguous for closed records).
+fn is_tuple(fields: &SendMap<Lowercase, RecordField<ErrorType>>) -> bool {
+    tuple_arity(fields.keys()).is_some()
+}
+
+/// The element types of a tuple, in order.
+fn tuple_elems(fields: SendMap<Lowercase, RecordField<ErrorType>>) -> Vec<ErrorType> {
+    let mut elems: Vec<_> = fields
+        .into_iter()
+        .map(|(label, field)| (label.as_tuple_elem(), field.into_inner()))
+        .collect();
+    elems.sort_by_key(|(index, _)| *index);
+
+    elems.into_iter().map(|(_, elem)| elem).collect()
+}
+
+fn is_record(tipe: &ErrorType) -> bool {
+    match tipe {
+        ErrorType::Record(..) => true,
+        ErrorType::Alias(_, _, actual, AliasKind::Structural) => is_record(actual),
+        _ => false,
+    }
+}
+
+/// The names to suggest for a typo: the ones the other side has that didn't match anything,
+/// or every name it has if they all matched.
+fn typo_candidates<K: Clone, V>(unmatched: &SendMap<K, V>, all: &SendMap<K, V>) -> Vec<K> {
+    if unmatched.is_empty() {
+        all.keys().cloned().collect()
+    } else {
+        unmatched.keys().cloned().collect()
+    }
+}
+
 fn ext_has_fixed_fields(ext: &TypeExt) -> bool {
     match ext {
         TypeExt::Closed => true,
@@ -2836,7 +3056,7 @@ fn diff_record<'b>(
         (true, true) => match left.peek() {
             Some((f, _, _)) => Status::Different(vec![Problem::FieldTypo(
                 f.clone(),
-                fields2.keys().cloned().collect(),
+                typo_candidates(&right_keys, &fields2),
             )]),
             None => {
                 if right.peek().is_none() {
@@ -2854,14 +3074,14 @@ fn diff_record<'b>(
         (false, true) => match left.peek() {
             Some((f, _, _)) => Status::Different(vec![Problem::FieldTypo(
                 f.clone(),
-                fields2.keys().cloned().collect(),
+                typo_candidates(&right_keys, &fields2),
             )]),
             None => Status::Similar,
         },
         (true, false) => match right.peek() {
             Some((f, _, _)) => Status::Different(vec![Problem::FieldTypo(
                 f.clone(),
-                fields1.keys().cloned().collect(),
+                typo_candidates(&left_keys, &fields1),
             )]),
             None => Status::Similar,
         },
@@ -2879,20 +3099,85 @@ fn diff_record<'b>(
             right_able: vec![],
         };
 
+    let is_large = fields1.len().max(fields2.len()) > MAX_ENTRIES_BEFORE_ELIDING;
+    let mut similar_fields = Vec::new();
+    let mut differing_paths = Vec::new();
+    let mut has_nested_paths = false;
+
     for diff in both {
+        let field = diff.left.0.clone();
+
+        let status = match diff.status {
+            Status::Similar => {
+                similar_fields.push(field);
+                Status::Similar
+            }
+            Status::Different(problems) => {
+                // Paths found in a nested record are extended with this field's name, so the
+                // final report can point at e.g. `.address.city` rather than `.address`.
+                let is_nested_record = |fields: &SendMap<Lowercase, RecordField<ErrorType>>| matches!(fields.get(&field), Some(tipe) if is_record(tipe.as_inner()));
+                let mut nested_paths = Vec::new();
+                let mut other_problems = Vec::with_capacity(problems.len());
+                for problem in problems {
+                    match problem {
+                        Problem::FieldTypesDiffer(paths)
+                            if is_nested_record(&fields1) && is_nested_record(&fields2) =>
+                        {
+                            nested_paths.extend(paths)
+                        }
+                        other => other_problems.push(other),
+                    }
+                }
+
+                if nested_paths.is_empty() {
+                    differing_paths.push(vec![field]);
+                } else {
+                    has_nested_paths = true;
+                    for mut path in nested_paths {
+                        path.insert(0, field.clone());
+                        differing_paths.push(path);
+                    }
+                }
+
+                Status::Different(other_problems)
+            }
+        };
+
         fields_diff.left.push(diff.left);
         fields_diff.right.push(diff.right);
-        fields_diff.status.merge(diff.status);
+        fields_diff.status.merge(status);
         fields_diff.left_able.extend(diff.left_able);
         fields_diff.right_able.extend(diff.right_able);
     }
 
+    if (is_large || has_nested_paths) && !differing_paths.is_empty() {
+        differing_paths.sort();
+
+        // Put this first, so that more specific problems found in the fields take precedence.
+        if let Status::Different(problems) = &mut fields_diff.status {
+            problems.insert(0, Problem::FieldTypesDiffer(differing_paths));
+        }
+    }
+
     if !all_fields_shared {
         fields_diff.left.extend(left);
         fields_diff.right.extend(right);
         fields_diff.status.merge(Status::Different(vec![]));
     }
 
+    // Only show the fields that differ in large records, so they don't get lost among the rest.
+    let elided = is_large
+        && !similar_fields.is_empty()
+        && matches!(fields_diff.status, Status::Different(_));
+    if elided {
+        fields_diff
+            .left
+            .retain(|(f, _, _)| !similar_fields.contains(f));
+        fields_diff
+            .right
+            .retain(|(f, _, _)| !similar_fields.contains(f));
+    }
+
     // sort fields for display
     fields_diff.left.sort_by(|a, b| a.0.cmp(&b.0));
     fields_diff.right.sort_by(|a, b| a.0.cmp(&b.0));
@@ -2904,6 +3189,7 @@ fn diff_record<'b>(
             .into_iter()
             .map(|(_, b, c)| (b, c))
             .collect(),
+        elided,
         ext_diff.left,
     );
     let doc2 = report_text::record(
@@ -2913,6 +3199,7 @@ fn diff_record<'b>(
             .into_iter()
             .map(|(_, b, c)| (b, c))
             .collect(),
+        elided,
         ext_diff.right,
     );
 
@@ -2944,6 +3231,8 @@ fn same_tag_name_overlap_diff<'b>(
             right_able: diff.right_able,
         }
     } else {
+        let problem = Problem::TagArityMismatch(field.clone(), args1.len(), args2.len());
+
         let (left_doc, left_able): (_, Vec<AbleVariables>) = args1
             .into_iter()
             .map(|arg| to_doc(alloc, Parens::InTypeParam, arg))
@@ -2956,7 +3245,7 @@ fn same_tag_name_overlap_diff<'b>(
         Diff {
             left: (field.clone(), alloc.tag_name(field.clone()), left_doc),
             right: (field.clone(), alloc.tag_name(field), right_doc),
-            status: Status::Similar,
+            status: Status::Different(vec![problem]),
             left_able: left_able.into_iter().flatten().collect(),
             right_able: right_able.into_iter().flatten().collect(),
         }
@@ -3008,7 +3297,7 @@ fn diff_tag_union<'b>(
         (true, true) => match (left.peek(), right.peek()) {
             (Some((f, _, _, _)), Some(_)) => Status::Different(vec![Problem::TagTypo(
                 f.clone(),
-                fields2.keys().cloned().collect(),
+                typo_candidates(&right_keys, fields2),
             )]),
             (Some(_), None) => {
                 let status =
@@ -3027,14 +3316,14 @@ fn diff_tag_union<'b>(
         (false, true) => match left.peek() {
             Some((f, _, _, _)) => Status::Different(vec![Problem::TagTypo(
                 f.clone(),
-                fields2.keys().cloned().collect(),
+                typo_candidates(&right_keys, fields2),
             )]),
             None => Status::Similar,
         },
         (true, false) => match right.peek() {
             Some((f, _, _, _)) => Status::Different(vec![Problem::TagTypo(
                 f.clone(),
-                fields1.keys().cloned().collect(),
+                typo_candidates(&left_keys, fields1),
             )]),
             None => Status::Similar,
         },
@@ -3051,7 +3340,14 @@ fn diff_tag_union<'b>(
         right_able: vec![],
     };
 
+    let is_large = fields1.len().max(fields2.len()) > MAX_ENTRIES_BEFORE_ELIDING;
+    let mut similar_tags = Vec::new();
+
     for diff in both {
+        if let Status::Similar = diff.status {
+            similar_tags.push(diff.left.0.clone());
+        }
+
         fields_diff.left.push(diff.left);
         fields_diff.right.push(diff.right);
         fields_diff.status.merge(diff.status);
@@ -3071,6 +3367,17 @@ fn diff_tag_union<'b>(
         fields_diff.status.merge(Status::Different(vec![]));
     }
 
+    let elided =
+        is_large && !similar_tags.is_empty() && matches!(fields_diff.status, Status::Different(_));
+    if elided {
+        fields_diff
+            .left
+            .retain(|(t, _, _)| !similar_tags.contains(t));
+        fields_diff
+            .right
+            .retain(|(t, _, _)| !similar_tags.contains(t));
+    }
+
     fields_diff.left.sort_by(|a, b| a.0.cmp(&b.0));
     fields_diff.right.sort_by(|a, b| a.0.cmp(&b.0));
 
@@ -3085,8 +3392,8 @@ fn diff_tag_union<'b>(
         .map(|(_, a, b)| (a, b))
         .collect();
 
-    let doc1 = report_text::tag_union(alloc, lefts, ext_diff.left);
-    let doc2 = report_text::tag_union(alloc, rights, ext_diff.right);
+    let doc1 = report_text::tag_union(alloc, lefts, elided, ext_diff.left);
+    let doc2 = report_text::tag_union(alloc, rights, elided, ext_diff.right);
 
     fields_diff.status.merge(status);
 
@@ -3206,9 +3513,12 @@ mod report_text {
         }
     }
 
+    /// Renders a record type. If `elided` is set, some of its fields were left out, which is
+    /// shown as `{ …, a : Str }`.
     pub fn record<'b>(
         alloc: &'b RocDocAllocator<'b>,
         entries: Vec<(RocDocBuilder<'b>, RecordField<RocDocBuilder<'b>>)>,
+        elided: bool,
         opt_ext: Option<RocDocBuilder<'b>>,
     ) -> RocDocBuilder<'b> {
         let ext_doc = if let Some(t) = opt_ext {
@@ -3217,7 +3527,9 @@ mod report_text {
             alloc.nil()
         };
 
-        if entries.is_empty() {
+        if elided && entries.is_empty() {
+            alloc.text("{ … }").append(ext_doc)
+        } else if entries.is_empty() {
             alloc.text("{}").append(ext_doc)
         } else {
             let entry_to_doc =
@@ -3235,8 +3547,9 @@ mod report_text {
                     }
                 };
 
+            let first = if elided { "{ …, " } else { "{ " };
             let starts =
-                std::iter::once(alloc.reflow("{ ")).chain(std::iter::repeat(alloc.reflow(", ")));
+                std::iter::once(alloc.reflow(first)).chain(std::iter::repeat(alloc.reflow(", ")));
 
             let entries_doc = alloc.concat(
                 entries
@@ -3249,6 +3562,16 @@ mod report_text {
         }
     }
 
+    pub fn tuple<'b>(
+        alloc: &'b RocDocAllocator<'b>,
+        elems: Vec<RocDocBuilder<'b>>,
+    ) -> RocDocBuilder<'b> {
+        alloc
+            .reflow("( ")
+            .append(alloc.intersperse(elems, alloc.reflow(", ")))
+            .append(alloc.reflow(" )"))
+    }
+
     pub fn to_suggestion_record<'b>(
         alloc: &'b RocDocAllocator<'b>,
         f: (Lowercase, RecordField<ErrorType>),
@@ -3319,9 +3642,12 @@ mod report_text {
         }
     }
 
+    /// Renders a tag union type. If `elided` is set, some of its tags were left out, which is
+    /// shown as `[…, Foo Str]`.
     pub fn tag_union<'b>(
         alloc: &'b RocDocAllocator<'b>,
         entries: Vec<(RocDocBuilder<'b>, Vec<RocDocBuilder<'b>>)>,
+        elided: bool,
         opt_ext: Option<RocDocBuilder<'b>>,
     ) -> RocDocBuilder<'b> {
         let ext_doc = if let Some(t) = opt_ext {
@@ -3330,7 +3656,9 @@ mod report_text {
             alloc.nil()
         };
 
-        if entries.is_empty() {
+        if elided && entries.is_empty() {
+            alloc.text("[…]").append(ext_doc)
+        } else if entries.is_empty() {
             alloc.text("[]")
         } else {
             let entry_to_doc = |(tag_name, arguments): (RocDocBuilder<'b>, Vec<_>)| {
@@ -3343,8 +3671,9 @@ mod report_text {
                 }
             };
 
+            let first = if elided { "[…, " } else { "[" };
             let starts =
-                std::iter::once(alloc.reflow("[")).chain(std::iter::repeat(alloc.reflow(", ")));
+                std::iter::once(alloc.reflow(first)).chain(std::iter::repeat(alloc.reflow(", ")));
 
             let entries_doc = alloc.concat(
                 entries
@@ -3689,6 +4018,57 @@ fn type_problem_to_pretty<'b>(
                 alloc.stack([tip1, tip2])
             }
         },
+        (TagArityMismatch(tag, found, expected), _) => {
+            let payloads = |n: usize| match n {
+                1 => "1 payload".to_string(),
+                n => format!("{} payloads", n),
+            };
+
+            alloc.tip().append(alloc.concat([
+                alloc.reflow("The "),
+                alloc.tag_name(tag),
+                alloc.reflow(" tag has "),
+                alloc.text(payloads(found)),
+                alloc.reflow(" in one type, but "),
+                alloc.text(payloads(expected)),
+                alloc.reflow(" in the other. Maybe a payload was added or left out by accident?"),
+            ]))
+        }
+
+        (FieldTypesDiffer(paths), _) => {
+            let path_to_doc = |path: Vec<Lowercase>| {
+                let path = path
+                    .iter()
+                    .map(|field| field.as_str())
+                    .collect::<Vec<_>>()
+                    .join(".");
+
+                alloc.record_field(path.into())
+            };
+
+            let mut paths = paths.into_iter().map(path_to_doc).collect::<Vec<_>>();
+
+            match paths.len() {
+                0 => alloc.nil(),
+                1 => alloc.tip().append(alloc.concat([
+                    alloc.reflow("The "),
+                    paths.remove(0),
+                    alloc.reflow(" field has a different type in each record."),
+                ])),
+                _ => {
+                    let last = paths.pop().unwrap();
+
+                    alloc.tip().append(alloc.concat([
+                        alloc.reflow("The "),
+                        alloc.intersperse(paths, alloc.reflow(", ")),
+                        alloc.reflow(" and "),
+                        last,
+                        alloc.reflow(" fields have a different type in each record."),
+                    ]))
+                }
+            }
+        }
+
         (OptionalRequiredMismatch(field), _) => alloc.tip().append(alloc.concat([
             alloc.reflow("To extract the "),
             alloc.record_field(field),
@@ -3717,6 +4097,7 @@ fn report_record_field_typo<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
     filename: PathBuf,
+    code: ErrorCode,
     opt_sym: Option<Symbol>,
     field_prefix: &str,
     field: &Lowercase,
@@ -3797,6 +4178,7 @@ fn report_record_field_typo<'b>(
     Report {
         filename,
         title: "TYPE MISMATCH".to_string(),
+        code: Some(code),
         doc,
         severity: Severity::RuntimeError,
     }
@@ -3832,6 +4214,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: Some(ErrorCode::NonExhaustiveArgument),
                     doc,
                     severity: Severity::RuntimeError,
                 }
@@ -3856,6 +4239,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: Some(ErrorCode::NonExhaustiveDestructure),
                     doc,
                     severity: Severity::RuntimeError,
                 }
@@ -3880,6 +4264,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: Some(ErrorCode::NonExhaustiveWhen),
                     doc,
                     severity: Severity::RuntimeError,
                 }
@@ -3909,6 +4294,7 @@ fn exhaustive_problem<'a>(
             Report {
                 filename,
                 title: "REDUNDANT PATTERN".to_string(),
+                code: Some(ErrorCode::RedundantPattern),
                 doc,
                 severity: Severity::Warning,
             }
@@ -3947,7 +4333,7 @@ fn pattern_to_doc_help<'b>(
     use roc_can::exhaustive::{GUARD_CTOR, NONEXHAUSIVE_CTOR};
     use roc_exhaustive::Literal::*;
     use roc_exhaustive::Pattern::*;
-    use roc_exhaustive::RenderAs;
+    use roc_exhaustive::{ListArity, RenderAs};
 
     match pattern {
         Anything => alloc.text("_"),
@@ -3961,6 +4347,21 @@ fn pattern_to_doc_help<'b>(
             Decimal(d) => alloc.text(RocDec::from_ne_bytes(d).to_string()),
             Str(s) => alloc.string(s.into()),
         },
+        List(arity, patterns) => {
+            let mut elem_docs: Vec<_> = patterns
+                .into_iter()
+                .map(|v| pattern_to_doc_help(alloc, v, false))
+                .collect();
+
+            if let ListArity::Slice(before, _) = arity {
+                elem_docs.insert(before, alloc.text(".."));
+            }
+
+            alloc
+                .text("[")
+                .append(alloc.intersperse(elem_docs, alloc.reflow(", ")))
+                .append("]")
+        }
         Ctor(union, tag_id, args) => {
             match union.render_as {
                 RenderAs::Guard => {
@@ -3986,7 +4387,7 @@ fn pattern_to_doc_help<'b>(
                             Anything => {
                                 arg_docs.push(alloc.text(label.to_string()));
                             }
-                            Literal(_) | Ctor(_, _, _) => {
+                            Literal(_) | Ctor(_, _, _) | List(..) => {
                                 arg_docs.push(
                                     alloc
                                         .text(label.to_string())
@@ -6,6 +6,8 @@ use std::fmt;
 use std::path::{Path, PathBuf};
 use ven_pretty::{BoxAllocator, DocAllocator, DocBuilder, Render, RenderAnnotated};
 
+use crate::error::explain::ErrorCode;
+
 pub use crate::error::canonicalize::can_problem;
 pub use crate::error::parse::parse_problem;
 pub use crate::error::r#type::type_problem;
@@ -120,6 +122,9 @@ pub enum RenderTarget {
 /// A textual report.
 pub struct Report<'b> {
     pub title: String,
+    /// The code of the kind of problem this report is about, shown next to the title.
+    /// Reports that aren't about a problem in the user's code, like a missing file, have none.
+    pub code: Option<ErrorCode>,
     pub filename: PathBuf,
     pub doc: RocDocBuilder<'b>,
     pub severity: Severity,
@@ -168,10 +173,15 @@ impl<'b> Report<'b> {
         if self.title.is_empty() {
             self.doc
         } else {
+            let title = match self.code {
+                Some(code) => format!("{} [{}]", self.title, code),
+                None => self.title,
+            };
+
             let header = if self.filename == PathBuf::from("") {
-                crate::report::pretty_header(&self.title)
+                crate::report::pretty_header(&title)
             } else {
-                crate::report::pretty_header_with_path(&self.title, &self.filename)
+                crate::report::pretty_header_with_path(&title, &self.filename)
             };
 
             alloc.stack([alloc.text(header).annotate(Annotation::Header), self.doc])
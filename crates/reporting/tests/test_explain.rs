@@ -0,0 +1,101 @@
+extern crate roc_reporting;
+
+#[cfg(test)]
+mod test_explain {
+    use roc_reporting::error::explain::{explain, ErrorCode};
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    #[test]
+    fn codes_are_unique() {
+        let mut codes = HashSet::new();
+
+        for code in ErrorCode::ALL {
+            assert!(codes.insert(code.as_str()), "duplicate code {}", code);
+        }
+    }
+
+    #[test]
+    fn codes_are_well_formed() {
+        for code in ErrorCode::ALL {
+            let code = code.as_str();
+
+            assert_eq!(code.len(), 5, "{}", code);
+            assert!(code.starts_with('E'), "{}", code);
+            assert!(code[1..].chars().all(|c| c.is_ascii_digit()), "{}", code);
+            assert!(matches!(&code[1..2], "1" | "2" | "3"), "{}", code);
+        }
+    }
+
+    #[test]
+    fn codes_are_in_order() {
+        for pair in ErrorCode::ALL.windows(2) {
+            assert!(pair[0].as_str() < pair[1].as_str(), "{:?}", pair);
+        }
+    }
+
+    #[test]
+    fn explanations_are_filled_in() {
+        for code in ErrorCode::ALL {
+            let explanation = code.explanation();
+
+            assert!(!explanation.title.is_empty(), "{}", code);
+            assert!(!explanation.summary.is_empty(), "{}", code);
+            assert!(!explanation.example.is_empty(), "{}", code);
+            assert!(!explanation.fix.is_empty(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn explain_every_code() {
+        for code in ErrorCode::ALL {
+            let explanation = code.explanation();
+            let text = explain(code.as_str()).unwrap();
+
+            assert!(text.starts_with(code.as_str()));
+            assert!(text.contains(explanation.title));
+            assert!(text.contains(explanation.fix));
+            assert_eq!(
+                explain(&code.as_str().to_lowercase()).as_deref(),
+                Some(text.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn explain_unknown_code() {
+        assert_eq!(explain("E0000"), None);
+        assert_eq!(explain("TYPE MISMATCH"), None);
+    }
+
+    /// The reports in the reporting tests show the code they were built with next to their
+    /// title, so check that each of those codes is explained under the same title.
+    #[test]
+    fn reported_codes_match_their_titles() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/test_reporting.rs");
+        let source = std::fs::read_to_string(path).unwrap();
+        let mut seen = 0;
+
+        for line in source.lines() {
+            let header = match line.trim().strip_prefix("── ") {
+                Some(header) => header,
+                None => continue,
+            };
+
+            let (title, rest) = match header.split_once(" [") {
+                Some(parts) => parts,
+                None => continue,
+            };
+
+            let code = &rest[..rest.find(']').unwrap()];
+            let error_code = ErrorCode::from_str_ignore_case(code)
+                .unwrap_or_else(|| panic!("unknown code {} in {:?}", code, line));
+
+            assert_eq!(error_code.explanation().title, title, "{}", line);
+
+            seen += 1;
+        }
+
+        assert!(seen > 0);
+    }
+}
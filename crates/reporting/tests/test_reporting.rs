@@ -35,6 +35,7 @@ mod test_reporting {
     fn to_simple_report(doc: RocDocBuilder) -> Report {
         Report {
             title: "".to_string(),
+            code: None,
             doc,
             filename: filename_from_string(r"/code/proj/Main.roc"),
             severity: Severity::RuntimeError,
@@ -425,7 +426,7 @@ mod test_reporting {
         "#
         ),
         @r###"
-    ── NOT EXPOSED ─────────────────────────────────────────── /code/proj/Main.roc ─
+    ── NOT EXPOSED [E2034] ─────────────────────────────────── /code/proj/Main.roc ─
 
     The List module does not expose `isempty`:
 
@@ -452,7 +453,7 @@ mod test_reporting {
         "#
         ),
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `y` is not used anywhere in your code.
 
@@ -477,7 +478,7 @@ mod test_reporting {
        "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `i` name is first defined here:
 
@@ -509,7 +510,7 @@ mod test_reporting {
        "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `Booly` name is first defined here:
 
@@ -541,7 +542,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E1030] ──────────────────────────────── /code/proj/Main.roc ─
 
     Using != and == together requires parentheses, to clarify how they
     should be grouped.
@@ -570,7 +571,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-        ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+        ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
         Nothing is named `bar` in this scope.
 
@@ -594,7 +595,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `true` in this scope.
 
@@ -626,7 +627,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E1030] ──────────────────────────────── /code/proj/Main.roc ─
 
     Using more than one == like this requires parentheses, to clarify how
     things should be grouped.
@@ -652,7 +653,7 @@ mod test_reporting {
          "#
         ),
         @r###"
-    ── UNUSED ARGUMENT ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED ARGUMENT [E2052] ─────────────────────────────── /code/proj/Main.roc ─
 
     `box` doesn't use `htmlChildren`.
 
@@ -665,7 +666,7 @@ mod test_reporting {
     at the start of a variable name is a way of saying that the variable
     is not used.
 
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `y` is not used anywhere in your code.
 
@@ -749,7 +750,7 @@ mod test_reporting {
             ),
             indoc!(
                 r#"
-                <cyan>── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─<reset>
+                <cyan>── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─<reset>
 
                 Nothing is named `theAdmin` in this scope.
 
@@ -775,7 +776,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3016] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` condition needs to be a Bool:
 
@@ -801,7 +802,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3017] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` guard condition needs to be a Bool:
 
@@ -826,7 +827,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3018] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -853,7 +854,7 @@ mod test_reporting {
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3018] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 3rd branch of this `if` does not match all the previous branches:
 
@@ -883,7 +884,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3019] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 2nd branch of this `when` does not match all the previous branches:
 
@@ -912,7 +913,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3020] ───────────────────────────────── /code/proj/Main.roc ─
 
     This list contains elements with different types:
 
@@ -939,7 +940,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3020] ───────────────────────────────── /code/proj/Main.roc ─
 
     This list contains elements with different types:
 
@@ -972,7 +973,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3021] ───────────────────────────────── /code/proj/Main.roc ─
 
     I cannot update the `.foo` field like this:
 
@@ -1002,7 +1003,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1027,7 +1028,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1053,7 +1054,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1066,7 +1067,7 @@ mod test_reporting {
 
         List ∞ -> a
 
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1093,7 +1094,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -1129,7 +1130,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -1163,7 +1164,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-        ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
         I'm inferring a weird self-referential type for `f`:
 
@@ -1189,7 +1190,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-        ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
         I'm inferring a weird self-referential type for `f`:
 
@@ -1227,7 +1228,7 @@ mod test_reporting {
         // against that extra variable, rather than possibly having to translate a `Type`
         // again.
         @r###"
-        ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
         I'm inferring a weird self-referential type for `f`:
 
@@ -1240,7 +1241,7 @@ mod test_reporting {
 
             List ∞ -> List a
 
-        ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
         I'm inferring a weird self-referential type for `g`:
 
@@ -1267,7 +1268,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-        ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
         I'm inferring a weird self-referential type for `f`:
 
@@ -1280,7 +1281,7 @@ mod test_reporting {
 
             List ∞ -> List a
 
-        ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
         I'm inferring a weird self-referential type for `g`:
 
@@ -1308,7 +1309,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1342,7 +1343,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1376,7 +1377,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1410,7 +1411,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the `then` branch of this `if` expression:
 
@@ -1444,7 +1445,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -1477,7 +1478,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -1509,7 +1510,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E3024] ───────────────────────────────── /code/proj/Main.roc ─
 
     The `x` value is not a function, but it was given 1 argument:
 
@@ -1531,7 +1532,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E3008] ───────────────────────────────── /code/proj/Main.roc ─
 
     The `f` function expects 1 argument, but it got 2 instead:
 
@@ -1553,7 +1554,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO FEW ARGS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO FEW ARGS [E3006] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `f` function expects 2 arguments, but it got only 1:
 
@@ -1574,7 +1575,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -1603,7 +1604,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3037] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern in this `when` does not match the previous ones:
 
@@ -1629,7 +1630,7 @@ mod test_reporting {
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -1657,7 +1658,7 @@ mod test_reporting {
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -1686,7 +1687,7 @@ mod test_reporting {
              "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `foo` in this scope.
 
@@ -1742,7 +1743,7 @@ mod test_reporting {
         ),
         // Just putting this here. We should probably handle or-patterns better
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3037] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern in this branch does not match the previous ones:
 
@@ -1770,7 +1771,7 @@ mod test_reporting {
         ),
         // Maybe this should specifically say the pattern doesn't work?
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -1798,7 +1799,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of this definition:
 
@@ -1829,7 +1830,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2064] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer pattern is malformed:
 
@@ -1850,7 +1851,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2064] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float pattern is malformed:
 
@@ -1871,7 +1872,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2064] ──────────────────────────────── /code/proj/Main.roc ─
 
     This hex integer pattern is malformed:
 
@@ -1892,7 +1893,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2064] ──────────────────────────────── /code/proj/Main.roc ─
 
     This octal integer pattern is malformed:
 
@@ -1913,7 +1914,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2064] ──────────────────────────────── /code/proj/Main.roc ─
 
     This binary integer pattern is malformed:
 
@@ -1935,7 +1936,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -1981,7 +1982,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -2015,7 +2016,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2050,7 +2051,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2086,7 +2087,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `ok` in this scope.
 
@@ -2117,7 +2118,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `ok` is not used anywhere in your code.
 
@@ -2127,7 +2128,7 @@ mod test_reporting {
     If you didn't intend on using `ok` then remove it so future readers of
     your code don't wonder why it is there.
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2159,7 +2160,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-        ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR DEFINITION [E2008] ─────────────────────────── /code/proj/Main.roc ─
 
         The `f` value is defined directly in terms of itself, causing an
         infinite loop.
@@ -2179,7 +2180,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR DEFINITION [E2008] ─────────────────────────── /code/proj/Main.roc ─
 
     The `foo` definition is causing a very tricky infinite loop:
 
@@ -2207,7 +2208,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3039] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2229,7 +2230,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3039] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2262,7 +2263,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3039] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `r` record doesn’t have a `foo` field:
 
@@ -2290,7 +2291,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3039] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2320,7 +2321,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `add` has an unexpected type:
 
@@ -2345,7 +2346,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `add` has an unexpected type:
 
@@ -2373,7 +2374,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `add` has an unexpected type:
 
@@ -2401,7 +2402,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2435,7 +2436,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -2474,7 +2475,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3040] ──────────────────────────────── /code/proj/Main.roc ─
 
     This pattern does not cover all the possibilities:
 
@@ -2505,7 +2506,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -2536,7 +2537,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2563,7 +2564,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2591,7 +2592,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2620,7 +2621,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2649,7 +2650,7 @@ mod test_reporting {
         ),
         // Tip: Looks like a record field guard is not exhaustive. Learn more about record pattern matches at TODO.
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2678,7 +2679,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2704,7 +2705,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -2731,7 +2732,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E3005] ───────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern is redundant:
 
@@ -2759,7 +2760,7 @@ mod test_reporting {
         ),
         // de-aliases the alias to give a better error message
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `f` has an unexpected type:
 
@@ -2797,7 +2798,7 @@ mod test_reporting {
         ),
         // should not report Bar as unused!
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `Foo` alias is self-recursive in an invalid way:
 
@@ -2823,7 +2824,7 @@ mod test_reporting {
         ),
         // should not report Bar as unused!
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `Foo` alias is self-recursive in an invalid way:
 
@@ -2843,7 +2844,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E2011] ────────────────────────── /code/proj/Main.roc ─
 
     This record defines the `.x` field twice!
 
@@ -2867,7 +2868,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E2011] ────────────────────────── /code/proj/Main.roc ─
 
     This record defines the `.x` field twice!
 
@@ -2895,7 +2896,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E2011] ────────────────────────── /code/proj/Main.roc ─
 
     This record defines the `.x` field twice!
 
@@ -2930,7 +2931,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E2011] ────────────────────────── /code/proj/Main.roc ─
 
     This record defines the `.x` field twice!
 
@@ -2963,7 +2964,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE FIELD NAME ────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE FIELD NAME [E2058] ────────────────────────── /code/proj/Main.roc ─
 
     This record type defines the `.foo` field twice!
 
@@ -2991,7 +2992,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DUPLICATE TAG NAME ──────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE TAG NAME [E2014] ──────────────────────────── /code/proj/Main.roc ─
 
     This tag union type defines the `Foo` tag twice!
 
@@ -3020,7 +3021,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── NAMING PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── NAMING PROBLEM [E2030] ──────────────────────────────── /code/proj/Main.roc ─
 
     This annotation does not match the definition immediately following
     it:
@@ -3056,7 +3057,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2059] ──────────────────────────────── /code/proj/Main.roc ─
 
     This definition of `MyAlias` has an unexpected pattern:
 
@@ -3065,7 +3066,7 @@ mod test_reporting {
 
     Only type variables like `a` or `value` can occur in this position.
 
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `MyAlias` is not used anywhere in your code.
 
@@ -3088,7 +3089,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2059] ──────────────────────────────── /code/proj/Main.roc ─
 
     This definition of `Age` has an unexpected pattern:
 
@@ -3110,7 +3111,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY TYPE ARGUMENTS ─────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY TYPE ARGUMENTS [E3009] ─────────────────────── /code/proj/Main.roc ─
 
     The `Num` opaque expects 1 type argument, but it got 2 instead:
 
@@ -3132,7 +3133,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY TYPE ARGUMENTS ─────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY TYPE ARGUMENTS [E3009] ─────────────────────── /code/proj/Main.roc ─
 
     The `Num` opaque expects 1 type argument, but it got 2 instead:
 
@@ -3156,7 +3157,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO FEW TYPE ARGUMENTS ──────────────────────────────── /code/proj/Main.roc ─
+    ── TOO FEW TYPE ARGUMENTS [E3007] ──────────────────────── /code/proj/Main.roc ─
 
     The `Pair` alias expects 2 type arguments, but it got 1 instead:
 
@@ -3180,7 +3181,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY TYPE ARGUMENTS ─────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY TYPE ARGUMENTS [E3009] ─────────────────────── /code/proj/Main.roc ─
 
     The `Pair` alias expects 2 type arguments, but it got 3 instead:
 
@@ -3203,7 +3204,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNUSED TYPE ALIAS PARAMETER ─────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED TYPE ALIAS PARAMETER [E2055] ─────────────────── /code/proj/Main.roc ─
 
     The `a` type parameter is not used in the `Foo` alias definition:
 
@@ -3225,7 +3226,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── ARGUMENTS BEFORE EQUALS ────────────────── tmp/elm_function_syntax/Test.roc ─
+    ── ARGUMENTS BEFORE EQUALS [E1001] ────────── tmp/elm_function_syntax/Test.roc ─
 
     I am partway through parsing a definition, but I got stuck here:
 
@@ -3253,7 +3254,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -3290,7 +3291,7 @@ mod test_reporting {
         // TODO render tag unions across multiple lines
         // TODO do not show recursion var if the recursion var does not render on the surface of a type
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -3327,7 +3328,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2070] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal is too big:
 
@@ -3339,7 +3340,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2070] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal is too small:
 
@@ -3351,7 +3352,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2070] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal is too big:
 
@@ -3363,7 +3364,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2070] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal is too small:
 
@@ -3375,7 +3376,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `add` has an unexpected type:
 
@@ -3404,7 +3405,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2067] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float literal is too big:
 
@@ -3416,7 +3417,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2067] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float literal is too small:
 
@@ -3449,7 +3450,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal contains an invalid digit:
 
@@ -3461,7 +3462,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This hex integer literal contains an invalid digit:
 
@@ -3473,7 +3474,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This octal integer literal contains an invalid digit:
 
@@ -3485,7 +3486,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This binary integer literal contains an invalid digit:
 
@@ -3515,7 +3516,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This hex integer literal contains no digits:
 
@@ -3527,7 +3528,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This octal integer literal contains no digits:
 
@@ -3539,7 +3540,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This binary integer literal contains no digits:
 
@@ -3563,7 +3564,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2068] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float literal contains an invalid digit:
 
@@ -3594,7 +3595,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2072] ──────────────────────────────── /code/proj/Main.roc ─
 
     This expression cannot be updated:
 
@@ -3603,7 +3604,7 @@ mod test_reporting {
 
     Only variables can be updated with record update syntax.
 
-    ── MODULE NOT IMPORTED ─────────────────────────────────── /code/proj/Main.roc ─
+    ── MODULE NOT IMPORTED [E2027] ─────────────────────────── /code/proj/Main.roc ─
 
     The `Test` module is not imported:
 
@@ -3618,7 +3619,7 @@ mod test_reporting {
         Dict
         Result
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2072] ──────────────────────────────── /code/proj/Main.roc ─
 
     This expression cannot be updated:
 
@@ -3637,7 +3638,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── MODULE NOT IMPORTED ─────────────────────────────────── /code/proj/Main.roc ─
+    ── MODULE NOT IMPORTED [E2027] ─────────────────────────── /code/proj/Main.roc ─
 
     The `Foo` module is not imported:
 
@@ -3662,7 +3663,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `add` has an unexpected type:
 
@@ -3690,7 +3691,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3036] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `f` is weird:
 
@@ -3719,7 +3720,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of this definition:
 
@@ -3750,7 +3751,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3036] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `f` is weird:
 
@@ -3783,7 +3784,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -3816,7 +3817,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -3847,7 +3848,7 @@ mod test_reporting {
                 "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to this function has an unexpected type:
 
@@ -3881,7 +3882,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -3915,7 +3916,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -3943,7 +3944,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── BAD OPTIONAL VALUE ──────────────────────────────────── /code/proj/Main.roc ─
+    ── BAD OPTIONAL VALUE [E2007] ──────────────────────────── /code/proj/Main.roc ─
 
     This record uses an optional value for the `.y` field in an incorrect
     context!
@@ -3980,7 +3981,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+    ── REDUNDANT PATTERN [E3005] ───────────────────────────── /code/proj/Main.roc ─
 
     The 3rd pattern is redundant:
 
@@ -4025,7 +4026,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNUSED ARGUMENT ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED ARGUMENT [E2052] ─────────────────────────────── /code/proj/Main.roc ─
 
     `f` doesn't use `foo`.
 
@@ -4047,7 +4048,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2065] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse a qualified name here:
 
@@ -4068,7 +4069,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2065] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse a qualified name here:
 
@@ -4088,7 +4089,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2065] ──────────────────────────────── /code/proj/Main.roc ─
 
     I trying to parse a record field access here:
 
@@ -4110,7 +4111,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ──────────────── tmp/type_annotation_double_colon/Test.roc ─
+    ── UNKNOWN OPERATOR [E1048] ──────── tmp/type_annotation_double_colon/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -4142,7 +4143,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E3024] ───────────────────────────────── /code/proj/Main.roc ─
 
     This value is not a function, but it was given 3 arguments:
 
@@ -4161,7 +4162,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TAG UNION TYPE ───────────────────── tmp/tag_union_open/Test.roc ─
+    ── UNFINISHED TAG UNION TYPE [E1045] ───────────── tmp/tag_union_open/Test.roc ─
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4183,7 +4184,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TAG UNION TYPE ────────────────────── tmp/tag_union_end/Test.roc ─
+    ── UNFINISHED TAG UNION TYPE [E1045] ────────────── tmp/tag_union_end/Test.roc ─
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4205,7 +4206,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── WEIRD TAG NAME ────────────────── tmp/tag_union_lowercase_tag_name/Test.roc ─
+    ── WEIRD TAG NAME [E1061] ────────── tmp/tag_union_lowercase_tag_name/Test.roc ─
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4226,7 +4227,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── WEIRD TAG NAME ─────────── tmp/tag_union_second_lowercase_tag_name/Test.roc ─
+    ── WEIRD TAG NAME [E1061] ─── tmp/tag_union_second_lowercase_tag_name/Test.roc ─
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4247,7 +4248,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ────────────────────── tmp/record_type_open/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E1044] ────────────── tmp/record_type_open/Test.roc ─
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4270,7 +4271,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ─────────────── tmp/record_type_open_indent/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E1044] ─────── tmp/record_type_open_indent/Test.roc ─
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4292,7 +4293,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ─────────────────────── tmp/record_type_end/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E1044] ─────────────── tmp/record_type_end/Test.roc ─
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4314,7 +4315,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ──────── tmp/record_type_keyword_field_name/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E1044] ─ ...record_type_keyword_field_name/Test.roc ─
 
     I just started parsing a record type, but I got stuck on this field
     name:
@@ -4336,7 +4337,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED RECORD TYPE ───────────── tmp/record_type_missing_comma/Test.roc ─
+    ── UNFINISHED RECORD TYPE [E1044] ───── tmp/record_type_missing_comma/Test.roc ─
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4353,7 +4354,7 @@ mod test_reporting {
         record_type_tab,
         "f : { foo \t }",
         @r###"
-    ── TAB CHARACTER ──────────────────────────────── tmp/record_type_tab/Test.roc ─
+    ── TAB CHARACTER [E1031] ──────────────────────── tmp/record_type_tab/Test.roc ─
 
     I encountered a tab character
 
@@ -4368,7 +4369,7 @@ mod test_reporting {
         comment_with_tab,
         "# comment with a \t\n4",
         @r###"
-    ── TAB CHARACTER ─────────────────────────────── tmp/comment_with_tab/Test.roc ─
+    ── TAB CHARACTER [E1031] ─────────────────────── tmp/comment_with_tab/Test.roc ─
 
     I encountered a tab character
 
@@ -4388,7 +4389,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ────────────────── tmp/type_in_parens_start/Test.roc ─
+    ── UNFINISHED PARENTHESES [E1067] ────────── tmp/type_in_parens_start/Test.roc ─
 
     I just started parsing a type in parentheses, but I got stuck here:
 
@@ -4410,7 +4411,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ──────────────────── tmp/type_in_parens_end/Test.roc ─
+    ── UNFINISHED PARENTHESES [E1067] ──────────── tmp/type_in_parens_end/Test.roc ─
 
     I am partway through parsing a type in parentheses, but I got stuck
     here:
@@ -4435,7 +4436,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2066] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am confused by this type name:
 
@@ -4446,7 +4447,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "###
     );
-    //                ── DOUBLE DOT ──────────────────────────────────────────────────────────────────
+    //                ── DOUBLE DOT [E1007] ──────────────────────────────────────────────────────────
     //
     //                I encountered two dots in a row:
     //
@@ -4465,7 +4466,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2066] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am confused by this type name:
 
@@ -4476,7 +4477,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "###
     );
-    //                ── TRAILING DOT ────────────────────────────────────────────────────────────────
+    //                ── TRAILING DOT [E1032] ────────────────────────────────────────────────────────
     //
     //                I encountered a dot with nothing after it:
     //
@@ -4494,7 +4495,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TYPE ───────────────────────── tmp/type_apply_stray_dot/Test.roc ─
+    ── UNFINISHED TYPE [E1046] ───────────────── tmp/type_apply_stray_dot/Test.roc ─
 
     I just started parsing a type, but I got stuck here:
 
@@ -4515,7 +4516,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2066] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am confused by this type name:
 
@@ -4526,7 +4527,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "###
     );
-    //                ── WEIRD QUALIFIED NAME ────────────────────────────────────────────────────────
+    //                ── WEIRD QUALIFIED NAME [E1060] ────────────────────────────────────────────────
     //
     //                I encountered a number at the start of a qualified name segment:
     //
@@ -4546,7 +4547,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2066] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am confused by this type name:
 
@@ -4566,7 +4567,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── MISSING FINAL EXPRESSION ──────── tmp/def_missing_final_expression/Test.roc ─
+    ── MISSING FINAL EXPRESSION [E1018] ─ ...def_missing_final_expression/Test.roc ─
 
     I am partway through parsing a definition, but I got stuck here:
 
@@ -4597,7 +4598,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED INLINE ALIAS ──────────────────── tmp/type_inline_alias/Test.roc ─
+    ── UNFINISHED INLINE ALIAS [E1039] ──────────── tmp/type_inline_alias/Test.roc ─
 
     I just started parsing an inline type alias, but I got stuck here:
 
@@ -4619,7 +4620,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── DOUBLE COMMA ─────────────────────────────── tmp/type_double_comma/Test.roc ─
+    ── DOUBLE COMMA [E1006] ─────────────────────── tmp/type_double_comma/Test.roc ─
 
     I just started parsing a function argument type, but I encountered two
     commas in a row:
@@ -4642,7 +4643,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TYPE ─────────────────────── tmp/type_argument_no_arrow/Test.roc ─
+    ── UNFINISHED TYPE [E1046] ─────────────── tmp/type_argument_no_arrow/Test.roc ─
 
     I am partway through parsing a type, but I got stuck here:
 
@@ -4665,7 +4666,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TYPE ───────────── tmp/type_argument_arrow_then_nothing/Test.roc ─
+    ── UNFINISHED TYPE [E1046] ───── tmp/type_argument_arrow_then_nothing/Test.roc ─
 
     I just started parsing a type, but I got stuck here:
 
@@ -4690,7 +4691,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `myDict` definition:
 
@@ -4725,7 +4726,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `myDict` definition:
 
@@ -4757,7 +4758,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── IF GUARD NO CONDITION ───────────── tmp/if_guard_without_condition/Test.roc ─
+    ── IF GUARD NO CONDITION [E1012] ───── tmp/if_guard_without_condition/Test.roc ─
 
     I just started parsing an if guard, but there is no guard condition:
 
@@ -4782,7 +4783,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED PATTERN ────────────────────────── tmp/empty_or_pattern/Test.roc ─
+    ── UNFINISHED PATTERN [E1042] ────────────────── tmp/empty_or_pattern/Test.roc ─
 
     I just started parsing a pattern, but I got stuck here:
 
@@ -4807,7 +4808,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── MISSING EXPRESSION ───────────────────── tmp/pattern_binds_keyword/Test.roc ─
+    ── MISSING EXPRESSION [E1017] ───────────── tmp/pattern_binds_keyword/Test.roc ─
 
     I am partway through parsing a `when` expression, but I got stuck here:
 
@@ -4829,7 +4830,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED WHEN ─────────────────────────── tmp/when_missing_arrow/Test.roc ─
+    ── UNFINISHED WHEN [E1047] ─────────────────── tmp/when_missing_arrow/Test.roc ─
 
     I was partway through parsing a `when` expression, but I got stuck here:
 
@@ -4860,7 +4861,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED ARGUMENT LIST ───────────────── tmp/lambda_double_comma/Test.roc ─
+    ── UNFINISHED ARGUMENT LIST [E1036] ───────── tmp/lambda_double_comma/Test.roc ─
 
     I am partway through parsing a function argument list, but I got stuck
     at this comma:
@@ -4881,7 +4882,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED ARGUMENT LIST ──────────────── tmp/lambda_leading_comma/Test.roc ─
+    ── UNFINISHED ARGUMENT LIST [E1036] ──────── tmp/lambda_leading_comma/Test.roc ─
 
     I am partway through parsing a function argument list, but I got stuck
     at this comma:
@@ -4897,7 +4898,7 @@ mod test_reporting {
     // this should get better with time
     // TODO this formerly gave
     //
-    //                ── UNFINISHED WHEN ─────────────────────────────────────────────────────────────
+    //                ── UNFINISHED WHEN [E1047] ─────────────────────────────────────────────────────
     //
     //                I was partway through parsing a `when` expression, but I got stuck here:
     //
@@ -4928,7 +4929,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── NOT END OF FILE ──────────────────────── tmp/when_outdented_branch/Test.roc ─
+    ── NOT END OF FILE [E1024] ──────────────── tmp/when_outdented_branch/Test.roc ─
 
     I expected to reach the end of the file, but got stuck here:
 
@@ -4947,7 +4948,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNEXPECTED ARROW ─────────────── tmp/when_over_indented_underscore/Test.roc ─
+    ── UNEXPECTED ARROW [E1034] ─────── tmp/when_over_indented_underscore/Test.roc ─
 
     I am parsing a `when` expression right now, but this arrow is confusing
     me:
@@ -4983,7 +4984,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNEXPECTED ARROW ────────────────────── tmp/when_over_indented_int/Test.roc ─
+    ── UNEXPECTED ARROW [E1034] ────────────── tmp/when_over_indented_int/Test.roc ─
 
     I am parsing a `when` expression right now, but this arrow is confusing
     me:
@@ -5022,7 +5023,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED IF ────────────────────────────── tmp/if_outdented_then/Test.roc ─
+    ── UNFINISHED IF [E1038] ────────────────────── tmp/if_outdented_then/Test.roc ─
 
     I was partway through parsing an `if` expression, but I got stuck here:
 
@@ -5042,7 +5043,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED IF ──────────────────────────────── tmp/if_missing_else/Test.roc ─
+    ── UNFINISHED IF [E1038] ──────────────────────── tmp/if_missing_else/Test.roc ─
 
     I was partway through parsing an `if` expression, but I got stuck here:
 
@@ -5061,7 +5062,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED LIST ──────────────────────────── tmp/list_double_comma/Test.roc ─
+    ── UNFINISHED LIST [E1040] ──────────────────── tmp/list_double_comma/Test.roc ─
 
     I am partway through started parsing a list, but I got stuck here:
 
@@ -5081,7 +5082,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED LIST ───────────────────────────── tmp/list_without_end/Test.roc ─
+    ── UNFINISHED LIST [E1040] ───────────────────── tmp/list_without_end/Test.roc ─
 
     I am partway through started parsing a list, but I got stuck here:
 
@@ -5107,7 +5108,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2068] ──────────────────────────────── /code/proj/Main.roc ─
 
     This float literal contains an invalid digit:
 
@@ -5125,7 +5126,7 @@ mod test_reporting {
         unicode_not_hex,
         r#""abc\u(zzzz)def""#,
         @r###"
-    ── WEIRD CODE POINT ───────────────────────────── tmp/unicode_not_hex/Test.roc ─
+    ── WEIRD CODE POINT [E1051] ───────────────────── tmp/unicode_not_hex/Test.roc ─
 
     I am partway through parsing a unicode code point, but I got stuck
     here:
@@ -5143,7 +5144,7 @@ mod test_reporting {
         interpolate_not_identifier,
         r#""abc\(32)def""#,
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2061] ──────────────────────────────── /code/proj/Main.roc ─
 
     This string interpolation is invalid:
 
@@ -5161,7 +5162,7 @@ mod test_reporting {
         unicode_too_large,
         r#""abc\u(110000)def""#,
         @r###"
-    ── INVALID UNICODE ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── INVALID UNICODE [E2023] ─────────────────────────────── /code/proj/Main.roc ─
 
     This unicode code point is invalid:
 
@@ -5176,7 +5177,7 @@ mod test_reporting {
         weird_escape,
         r#""abc\qdef""#,
         @r###"
-    ── WEIRD ESCAPE ──────────────────────────────────── tmp/weird_escape/Test.roc ─
+    ── WEIRD ESCAPE [E1052] ──────────────────────────── tmp/weird_escape/Test.roc ─
 
     I was partway through parsing a  string literal, but I got stuck here:
 
@@ -5200,7 +5201,7 @@ mod test_reporting {
         single_no_end,
         r#""there is no end"#,
         @r###"
-    ── ENDLESS STRING ───────────────────────────────── tmp/single_no_end/Test.roc ─
+    ── ENDLESS STRING [E1011] ───────────────────────── tmp/single_no_end/Test.roc ─
 
     I cannot find the end of this string:
 
@@ -5216,7 +5217,7 @@ mod test_reporting {
         multi_no_end,
         r#""""there is no end"#,
         @r###"
-    ── ENDLESS STRING ────────────────────────────────── tmp/multi_no_end/Test.roc ─
+    ── ENDLESS STRING [E1011] ────────────────────────── tmp/multi_no_end/Test.roc ─
 
     I cannot find the end of this block string:
 
@@ -5232,7 +5233,7 @@ mod test_reporting {
         multi_insufficient_indent,
         "    \"\"\"\n  testing\n    \"\"\"", // 4 space indent on the start, 2 space on the `testing` line
         @r###"
-    ── INSUFFICIENT INDENT IN MULTI-LINE STRING ─ ..._insufficient_indent/Test.roc ─
+    ── INSUFFICIENT INDENT IN MULTI-LINE STRING [E1014] ─ ...cient_indent/Test.roc ─
 
     This multiline string is not sufficiently indented:
 
@@ -5256,7 +5257,7 @@ mod test_reporting {
             "#,
             ),
             @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3018] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -5282,7 +5283,7 @@ mod test_reporting {
                 $name,
                 &format!(r#"if True then "abc" else 1 {} 2"#, $op),
                 |golden| assert_eq!(golden, format!(
-r#"── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+r#"── TYPE MISMATCH [E3018] ───────────────────────────────── /code/proj/Main.roc ─
 
 This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -5325,7 +5326,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3038] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `foo` record doesn’t have a `if` field:
 
@@ -5344,7 +5345,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NOT EXPOSED ─────────────────────────────────────────── /code/proj/Main.roc ─
+    ── NOT EXPOSED [E2034] ─────────────────────────────────── /code/proj/Main.roc ─
 
     The Num module does not expose `if`:
 
@@ -5368,7 +5369,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2065] ──────────────────────────────── /code/proj/Main.roc ─
 
     I trying to parse a record field access here:
 
@@ -5387,7 +5388,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2065] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am very confused by this field access:
 
@@ -5406,7 +5407,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2065] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am very confused by this field access
 
@@ -5427,14 +5428,19 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
-    I trying to parse a record field access here:
+    Nothing is named `foo` in this scope.
 
     4│      foo.100
-                ^
+            ^^^^^^^
 
-    So I expect to see a lowercase letter next, like .name or .height.
+    Did you mean one of these?
+
+        Box
+        Bool
+        U8
+        F64
     "###
     );
 
@@ -5446,7 +5452,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NAMING PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── NAMING PROBLEM [E2063] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse an identifier here:
 
@@ -5480,7 +5486,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ───────────────────────────────────── tmp/case_of/Test.roc ─
+    ── UNKNOWN OPERATOR [E1048] ───────────────────────────── tmp/case_of/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5507,7 +5513,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `bar` in this scope.
 
@@ -5532,7 +5538,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ──────────────────────────── tmp/invalid_operator/Test.roc ─
+    ── UNKNOWN OPERATOR [E1048] ──────────────────── tmp/invalid_operator/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5557,7 +5563,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ───────────────────────────────── tmp/double_plus/Test.roc ─
+    ── UNKNOWN OPERATOR [E1048] ───────────────────────── tmp/double_plus/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5584,7 +5590,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ────────────────────────────── tmp/inline_hastype/Test.roc ─
+    ── UNKNOWN OPERATOR [E1048] ────────────────────── tmp/inline_hastype/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5613,7 +5619,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNKNOWN OPERATOR ───────────────────────────── tmp/wild_case_arrow/Test.roc ─
+    ── UNKNOWN OPERATOR [E1048] ───────────────────── tmp/wild_case_arrow/Test.roc ─
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5644,7 +5650,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD PROVIDES ──────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD PROVIDES [E1059] ──────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a provides list, but I got stuck here:
 
@@ -5681,7 +5687,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── BAD REQUIRES ────────────────────────────────────────── /code/proj/Main.roc ─
+                ── BAD REQUIRES [E1003] ────────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -5709,7 +5715,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD IMPORTS ───────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD IMPORTS [E1057] ───────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -5736,7 +5742,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD EXPOSES ───────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD EXPOSES [E1053] ───────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing an `exposes` list, but I got stuck here:
 
@@ -5764,7 +5770,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD MODULE NAME ───────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD MODULE NAME [E1058] ───────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but got stuck here:
 
@@ -5790,7 +5796,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD APP NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+                ── WEIRD APP NAME [E1049] ──────────────────────────────── /code/proj/Main.roc ─
 
                 I am partway through parsing a header, but got stuck here:
 
@@ -5814,7 +5820,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E3024] ───────────────────────────────── /code/proj/Main.roc ─
 
     This value is not a function, but it was given 2 arguments:
 
@@ -5835,7 +5841,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY ARGS [E3024] ───────────────────────────────── /code/proj/Main.roc ─
 
     This value is not a function, but it was given 2 arguments:
 
@@ -5857,7 +5863,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -5883,7 +5889,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ──────────────── tmp/pattern_in_parens_open/Test.roc ─
+    ── UNFINISHED PARENTHESES [E1066] ──────── tmp/pattern_in_parens_open/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -5906,7 +5912,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ─────────── tmp/pattern_in_parens_end_comma/Test.roc ─
+    ── UNFINISHED PARENTHESES [E1066] ─── tmp/pattern_in_parens_end_comma/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -5927,7 +5933,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ───────────────── tmp/pattern_in_parens_end/Test.roc ─
+    ── UNFINISHED PARENTHESES [E1066] ───────── tmp/pattern_in_parens_end/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -5951,7 +5957,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NEED MORE INDENTATION ─────────── tmp/pattern_in_parens_indent_end/Test.roc ─
+    ── NEED MORE INDENTATION [E1070] ─── tmp/pattern_in_parens_indent_end/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -5973,7 +5979,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNFINISHED PARENTHESES ───────── tmp/pattern_in_parens_indent_open/Test.roc ─
+    ── UNFINISHED PARENTHESES [E1066] ─ tmp/pattern_in_parens_indent_open/Test.roc ─
 
     I just started parsing a pattern in parentheses, but I got stuck here:
 
@@ -5999,7 +6005,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NEED MORE INDENTATION ──────────────────── tmp/outdented_in_parens/Test.roc ─
+    ── NEED MORE INDENTATION [E1072] ──────────── tmp/outdented_in_parens/Test.roc ─
 
     I am partway through parsing a type in parentheses, but I got stuck
     here:
@@ -6024,7 +6030,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `map` has an unexpected type:
 
@@ -6052,7 +6058,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3014] ───────────────────────────────── /code/proj/Main.roc ─
 
     This `expect` condition needs to be a Bool:
 
@@ -6079,7 +6085,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `mul` has an unexpected type:
 
@@ -6094,7 +6100,7 @@ All branches in an `if` must have the same type!
 
         Num *
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `mult` definition:
 
@@ -6123,7 +6129,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `mul` has an unexpected type:
 
@@ -6138,7 +6144,7 @@ All branches in an `if` must have the same type!
 
         Num a
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `mult` definition:
 
@@ -6173,7 +6179,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `Result` name is first defined here:
 
@@ -6188,7 +6194,7 @@ All branches in an `if` must have the same type!
     Since these aliases have the same name, it's easy to use the wrong one
     on accident. Give one of them a new name.
 
-    ── TOO FEW TYPE ARGUMENTS ──────────────────────────────── /code/proj/Main.roc ─
+    ── TOO FEW TYPE ARGUMENTS [E3007] ──────────────────────── /code/proj/Main.roc ─
 
     The `Result` alias expects 2 type arguments, but it got 1 instead:
 
@@ -6216,7 +6222,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `Result` name is first defined here:
 
@@ -6231,7 +6237,7 @@ All branches in an `if` must have the same type!
     Since these aliases have the same name, it's easy to use the wrong one
     on accident. Give one of them a new name.
 
-    ── TOO MANY TYPE ARGUMENTS ─────────────────────────────── /code/proj/Main.roc ─
+    ── TOO MANY TYPE ARGUMENTS [E3009] ─────────────────────── /code/proj/Main.roc ─
 
     The `Result` alias expects 2 type arguments, but it got 3 instead:
 
@@ -6253,7 +6259,7 @@ All branches in an `if` must have the same type!
         ),
         // TODO: We should tell the user that we inferred `_` as `a`
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -6287,7 +6293,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -6319,7 +6325,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -6355,7 +6361,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `inner` definition:
 
@@ -6384,7 +6390,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NOT AN INLINE ALIAS ────────── tmp/error_inline_alias_not_an_alias/Test.roc ─
+    ── NOT AN INLINE ALIAS [E1023] ── tmp/error_inline_alias_not_an_alias/Test.roc ─
 
     The inline type after this `as` is not a type alias:
 
@@ -6404,7 +6410,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── QUALIFIED ALIAS NAME ──────────── tmp/error_inline_alias_qualified/Test.roc ─
+    ── QUALIFIED ALIAS NAME [E1028] ──── tmp/error_inline_alias_qualified/Test.roc ─
 
     This type alias has a qualified name:
 
@@ -6424,7 +6430,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE ARGUMENT NOT LOWERCASE ─ ...r_inline_alias_argument_uppercase/Test.roc ─
+    ── TYPE ARGUMENT NOT LOWERCASE [E1033] ─ ..._alias_argument_uppercase/Test.roc ─
 
     This alias type argument is not lowercase:
 
@@ -6448,7 +6454,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `isEmpty` has an unexpected type:
 
@@ -6484,7 +6490,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `c` has an unexpected type:
 
@@ -6517,7 +6523,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -6540,7 +6546,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -6562,7 +6568,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -6587,7 +6593,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3036] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `job` is weird:
 
@@ -6622,7 +6628,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `job` definition:
 
@@ -6653,7 +6659,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NESTED DATATYPE ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── NESTED DATATYPE [E2031] ─────────────────────────────── /code/proj/Main.roc ─
 
     `Nested` is a nested datatype. Here is one recursive usage of it:
 
@@ -6681,7 +6687,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NESTED DATATYPE ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── NESTED DATATYPE [E2031] ─────────────────────────────── /code/proj/Main.roc ─
 
     `Nested` is a nested datatype. Here is one recursive usage of it:
 
@@ -6729,7 +6735,7 @@ All branches in an `if` must have the same type!
 
                     let real = format!(indoc!(
                         r#"
-                        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+                        ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
                         This 1st argument to `use` has an unexpected type:
 
@@ -6796,7 +6802,7 @@ All branches in an `if` must have the same type!
 
                     let real = format!(indoc!(
                         r#"
-                        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+                        ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
                         The branches of this `when` expression don't match the condition:
 
@@ -6848,7 +6854,7 @@ All branches in an `if` must have the same type!
         ),
         // TODO: link to number suffixes
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal contains an invalid digit:
 
@@ -6871,7 +6877,7 @@ All branches in an `if` must have the same type!
         ),
         // TODO: link to number suffixes
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2069] ──────────────────────────────── /code/proj/Main.roc ─
 
     This integer literal contains an invalid digit:
 
@@ -6893,7 +6899,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CONFLICTING NUMBER SUFFIX ───────────────────────────── /code/proj/Main.roc ─
+    ── CONFLICTING NUMBER SUFFIX [E2009] ───────────────────── /code/proj/Main.roc ─
 
     This number literal is an integer, but it has a float suffix:
 
@@ -6910,7 +6916,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CONFLICTING NUMBER SUFFIX ───────────────────────────── /code/proj/Main.roc ─
+    ── CONFLICTING NUMBER SUFFIX [E2009] ───────────────────── /code/proj/Main.roc ─
 
     This number literal is a float, but it has an integer suffix:
 
@@ -6923,7 +6929,7 @@ All branches in an `if` must have the same type!
         u8_overflow,
         "256u8",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -6939,7 +6945,7 @@ All branches in an `if` must have the same type!
         negative_u8,
         "-1u8",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -6955,7 +6961,7 @@ All branches in an `if` must have the same type!
         u16_overflow,
         "65536u16",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -6971,7 +6977,7 @@ All branches in an `if` must have the same type!
         negative_u16,
         "-1u16",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -6987,7 +6993,7 @@ All branches in an `if` must have the same type!
         u32_overflow,
         "4_294_967_296u32",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7003,7 +7009,7 @@ All branches in an `if` must have the same type!
         negative_u32,
         "-1u32",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7019,7 +7025,7 @@ All branches in an `if` must have the same type!
         u64_overflow,
         "18_446_744_073_709_551_616u64",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7035,7 +7041,7 @@ All branches in an `if` must have the same type!
         negative_u64,
         "-1u64",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7051,7 +7057,7 @@ All branches in an `if` must have the same type!
         negative_u128,
         "-1u128",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7067,7 +7073,7 @@ All branches in an `if` must have the same type!
         i8_overflow,
         "128i8",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7083,7 +7089,7 @@ All branches in an `if` must have the same type!
         i8_underflow,
         "-129i8",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7099,7 +7105,7 @@ All branches in an `if` must have the same type!
         i16_overflow,
         "32768i16",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7115,7 +7121,7 @@ All branches in an `if` must have the same type!
         i16_underflow,
         "-32769i16",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7131,7 +7137,7 @@ All branches in an `if` must have the same type!
         i32_overflow,
         "2_147_483_648i32",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7147,7 +7153,7 @@ All branches in an `if` must have the same type!
         i32_underflow,
         "-2_147_483_649i32",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7163,7 +7169,7 @@ All branches in an `if` must have the same type!
         i64_overflow,
         "9_223_372_036_854_775_808i64",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7179,7 +7185,7 @@ All branches in an `if` must have the same type!
         i64_underflow,
         "-9_223_372_036_854_775_809i64",
         @r###"
-    ── NUMBER UNDERFLOWS SUFFIX ────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER UNDERFLOWS SUFFIX [E2036] ────────────────────── /code/proj/Main.roc ─
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7195,7 +7201,7 @@ All branches in an `if` must have the same type!
         i128_overflow,
         "170_141_183_460_469_231_731_687_303_715_884_105_728i128",
         @r###"
-    ── NUMBER OVERFLOWS SUFFIX ─────────────────────────────── /code/proj/Main.roc ─
+    ── NUMBER OVERFLOWS SUFFIX [E2035] ─────────────────────── /code/proj/Main.roc ─
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7217,7 +7223,7 @@ All branches in an `if` must have the same type!
         // TODO: this error message could be improved, e.g. something like "This argument can
         // be used as ... because of its literal value"
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7243,7 +7249,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7270,7 +7276,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7297,7 +7303,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -7328,7 +7334,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `R` alias is self-recursive in an invalid way:
 
@@ -7351,7 +7357,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `R` alias is self-recursive in an invalid way:
 
@@ -7375,7 +7381,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `Foo` alias is recursive in an invalid way:
 
@@ -7410,7 +7416,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `Result` name is first defined here:
 
@@ -7435,7 +7441,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── OPAQUE TYPE NOT DEFINED ─────────────────────────────── /code/proj/Main.roc ─
+    ── OPAQUE TYPE NOT DEFINED [E2040] ─────────────────────── /code/proj/Main.roc ─
 
     The opaque type Age referenced here is not defined:
 
@@ -7456,7 +7462,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── OPAQUE TYPE NOT DEFINED ─────────────────────────────── /code/proj/Main.roc ─
+    ── OPAQUE TYPE NOT DEFINED [E2040] ─────────────────────── /code/proj/Main.roc ─
 
     The opaque type Age referenced here is not defined:
 
@@ -7470,7 +7476,7 @@ All branches in an `if` must have the same type!
 
     Note: It looks like there are no opaque types declared in this scope yet!
 
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `Age` is not used anywhere in your code.
 
@@ -7493,7 +7499,7 @@ All branches in an `if` must have the same type!
         // and checking it during can. The reason the error appears is because it is parsed as
         // Apply(Error(OtherModule), [@Age, 21])
         @r###"
-    ── OPAQUE TYPE NOT DEFINED ─────────────────────────────── /code/proj/Main.roc ─
+    ── OPAQUE TYPE NOT DEFINED [E2040] ─────────────────────── /code/proj/Main.roc ─
 
     The opaque type Age referenced here is not defined:
 
@@ -7502,7 +7508,7 @@ All branches in an `if` must have the same type!
 
     Note: It looks like there are no opaque types declared in this scope yet!
 
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2065] ──────────────────────────────── /code/proj/Main.roc ─
 
     I am trying to parse a qualified name here:
 
@@ -7529,7 +7535,7 @@ All branches in an `if` must have the same type!
         // `@Age` can be linked to the declaration of `Age` inside `age`, and a suggestion to
         // raise that declaration to the outer scope.
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `Age` is not used anywhere in your code.
 
@@ -7539,7 +7545,7 @@ All branches in an `if` must have the same type!
     If you didn't intend on using `Age` then remove it so future readers of
     your code don't wonder why it is there.
 
-    ── OPAQUE TYPE NOT DEFINED ─────────────────────────────── /code/proj/Main.roc ─
+    ── OPAQUE TYPE NOT DEFINED [E2040] ─────────────────────── /code/proj/Main.roc ─
 
     The opaque type Age referenced here is not defined:
 
@@ -7561,7 +7567,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── MODULE NOT IMPORTED ─────────────────────────────────── /code/proj/Main.roc ─
+        ── MODULE NOT IMPORTED [E2027] ─────────────────────────── /code/proj/Main.roc ─
 
         The `Task` module is not imported:
 
@@ -7593,7 +7599,7 @@ All branches in an `if` must have the same type!
         // TODO(opaques): error could be improved by saying that the opaque definition demands
         // that the argument be a U8, and linking to the definitin!
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -7622,7 +7628,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression is used in an unexpected way:
 
@@ -7652,7 +7658,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `v` definition:
 
@@ -7690,7 +7696,7 @@ All branches in an `if` must have the same type!
         // TODO(opaques): error could be improved by saying that the user-provided pattern
         // probably wants to change "Age" to "@Age"!
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3036] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 1st argument to `f` is weird:
 
@@ -7725,7 +7731,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3037] ───────────────────────────────── /code/proj/Main.roc ─
 
     The 2nd pattern in this `when` does not match the previous ones:
 
@@ -7756,7 +7762,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -7794,7 +7800,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -7827,7 +7833,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `y` has an unexpected type:
 
@@ -7860,7 +7866,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -7884,7 +7890,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── INVALID_EXTENSION_TYPE ──────────────────────────────── /code/proj/Main.roc ─
+    ── INVALID_EXTENSION_TYPE [E2024] ──────────────────────── /code/proj/Main.roc ─
 
     This record extension type is invalid:
 
@@ -7905,7 +7911,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── INVALID_EXTENSION_TYPE ──────────────────────────────── /code/proj/Main.roc ─
+    ── INVALID_EXTENSION_TYPE [E2024] ──────────────────────── /code/proj/Main.roc ─
 
     This tag union extension type is invalid:
 
@@ -7932,7 +7938,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `UnknownType` in this scope.
 
@@ -7946,7 +7952,7 @@ All branches in an `if` must have the same type!
         Unsigned32
         Unsigned16
 
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `UnknownType` in this scope.
 
@@ -7973,7 +7979,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNFINISHED ABILITY ── tmp/ability_first_demand_not_indented_enough/Test.roc ─
+    ── UNFINISHED ABILITY [E1035] ─ ...y_first_demand_not_indented_enough/Test.roc ─
 
     I was partway through parsing an ability definition, but I got stuck
     here:
@@ -7998,7 +8004,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── UNFINISHED ABILITY ─── tmp/ability_demands_not_indented_with_first/Test.roc ─
+        ── UNFINISHED ABILITY [E1035] ─ ...ty_demands_not_indented_with_first/Test.roc ─
 
         I was partway through parsing an ability definition, but I got stuck
         here:
@@ -8021,7 +8027,7 @@ All branches in an `if` must have the same type!
                 "#
         ),
         @r#"
-        ── UNFINISHED ABILITY ───────────── tmp/ability_demand_value_has_args/Test.roc ─
+        ── UNFINISHED ABILITY [E1035] ───── tmp/ability_demand_value_has_args/Test.roc ─
 
         I was partway through parsing an ability definition, but I got stuck
         here:
@@ -8044,7 +8050,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNFINISHED ABILITY ────────── tmp/ability_non_signature_expression/Test.roc ─
+    ── UNFINISHED ABILITY [E1035] ── tmp/ability_non_signature_expression/Test.roc ─
 
     I was partway through parsing an ability definition, but I got stuck
     here:
@@ -8067,7 +8073,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E2047] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has an unbound type variable:
 
@@ -8089,7 +8095,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E2047] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has an unbound type variable:
 
@@ -8111,7 +8117,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E2047] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has 2 unbound type variables.
 
@@ -8135,7 +8141,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E2047] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has an unbound type variable:
 
@@ -8157,7 +8163,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNBOUND TYPE VARIABLE ───────────────────────────────── /code/proj/Main.roc ─
+    ── UNBOUND TYPE VARIABLE [E2047] ───────────────────────── /code/proj/Main.roc ─
 
     The definition of `I` has an unbound type variable:
 
@@ -8180,7 +8186,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── ABILITY HAS TYPE VARIABLES ──────────────────────────── /code/proj/Main.roc ─
+        ── ABILITY HAS TYPE VARIABLES [E2001] ──────────────────── /code/proj/Main.roc ─
 
         The definition of the `Hash` ability includes type variables:
 
@@ -8190,7 +8196,7 @@ All branches in an `if` must have the same type!
         Abilities cannot depend on type variables, but their member values
         can!
 
-        ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+        ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
         `Hash` is not used anywhere in your code.
 
@@ -8212,7 +8218,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── HAS CLAUSE IS NOT AN ABILITY ────────────────────────── /code/proj/Main.roc ─
+        ── HAS CLAUSE IS NOT AN ABILITY [E2016] ────────────────── /code/proj/Main.roc ─
 
         The type referenced in this "has" clause is not an ability:
 
@@ -8231,7 +8237,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+        ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
 
         The `a` name is first defined here:
 
@@ -8260,7 +8266,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+        ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
 
         The `Ability` name is first defined here:
 
@@ -8287,7 +8293,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── ABILITY MEMBER MISSING HAS CLAUSE ───────────────────── /code/proj/Main.roc ─
+        ── ABILITY MEMBER MISSING HAS CLAUSE [E2004] ───────────── /code/proj/Main.roc ─
 
         The definition of the ability member `ab` does not include a `has` clause
         binding a type variable to the ability `Ability`:
@@ -8302,7 +8308,7 @@ All branches in an `if` must have the same type!
 
         Otherwise, the function does not need to be part of the ability!
 
-        ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+        ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
         `Ability` is not used anywhere in your code.
 
@@ -8324,7 +8330,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── ABILITY MEMBER BINDS MULTIPLE VARIABLES ─────────────── /code/proj/Main.roc ─
+        ── ABILITY MEMBER BINDS MULTIPLE VARIABLES [E2003] ─────── /code/proj/Main.roc ─
 
         The definition of the ability member `eq` includes multiple variables
         bound to the `Eq`` ability:`
@@ -8352,7 +8358,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── ILLEGAL HAS CLAUSE ──────────────────────────────────── /code/proj/Main.roc ─
+        ── ILLEGAL HAS CLAUSE [E2019] ──────────────────────────── /code/proj/Main.roc ─
 
         A `has` clause is not allowed here:
 
@@ -8361,7 +8367,7 @@ All branches in an `if` must have the same type!
 
         `has` clauses can only be specified on the top-level type annotations.
 
-        ── ABILITY MEMBER MISSING HAS CLAUSE ───────────────────── /code/proj/Main.roc ─
+        ── ABILITY MEMBER MISSING HAS CLAUSE [E2004] ───────────── /code/proj/Main.roc ─
 
         The definition of the ability member `hash` does not include a `has`
         clause binding a type variable to the ability `Hash`:
@@ -8392,7 +8398,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3027] ───────────────────────────────── /code/proj/Main.roc ─
 
         Something is off with this specialization of `hash`:
 
@@ -8425,7 +8431,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E2022] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `Eq` ability:
 
@@ -8438,6 +8444,208 @@ All branches in an `if` must have the same type!
     "###
     );
 
+    test_report!(
+        ability_missing_required_ability,
+        indoc!(
+            r#"
+            app "test" provides [lt] to "./platform"
+
+            MEq has
+                eq : a, a -> Bool | a has MEq
+
+            MOrd has MEq
+                lt : a, a -> Bool | a has MOrd
+
+            Id := U64 has [MOrd {lt}]
+
+            lt = \@Id m, @Id n -> m < n
+            "#
+        ),
+        @r###"
+    ── MISSING REQUIRED ABILITY [E2026] ────────────────────── /code/proj/Main.roc ─
+
+    This type implements the `MOrd` ability, but not all the abilities it
+    requires:
+
+    9│  Id := U64 has [MOrd {lt}]
+                       ^^^^^^^^^
+
+    Types implementing `MOrd` must also implement:
+
+        MEq
+    "###
+    );
+
+    test_report!(
+        builtin_implementation_for_non_builtin_type,
+        indoc!(
+            r#"
+            app "test" provides [pretty, age] to "./platform"
+
+            Pretty has
+                pretty : a -> Str | a has Pretty
+
+            Age := U8
+
+            Age has [Pretty { pretty: prettyAge }]
+
+            prettyAge = \@Age n -> Num.toStr n
+
+            age = @Age 21
+            "#
+        ),
+        @r###"
+    ── ILLEGAL BUILTIN IMPLEMENTATION [E2017] ──────────────── /code/proj/Main.roc ─
+
+    This declares ability implementations for `Age`:
+
+    8│  Age has [Pretty { pretty: prettyAge }]
+        ^^^
+
+    Only type aliases defined in this module, number types like `U64`, and
+    the builtin types `Str`, `List`, `Box`, `Dict`, `Set`, `Result` can implement
+    abilities this way.
+
+    Tip: Opaque types declare the abilities they implement with a `has`
+    clause in their definition.
+
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
+
+    `prettyAge` is not used anywhere in your code.
+
+    10│  prettyAge = \@Age n -> Num.toStr n
+         ^^^^^^^^^
+
+    If you didn't intend on using `prettyAge` then remove it so future
+    readers of your code don't wonder why it is there.
+    "###
+    );
+
+    test_report!(
+        alias_implementation_needs_alias,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Pretty has
+                pretty : a -> Str | a has Pretty
+
+            Point : { x : I64, y : I64 }
+
+            Point has [Pretty { pretty: prettyPoint }]
+
+            prettyPoint : Point -> Str
+            prettyPoint = \{ x, y } -> Str.concat (Num.toStr x) (Num.toStr y)
+
+            main = pretty { x: 0, y: 0 }
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
+
+    This expression has a type that does not implement the abilities it's expected to:
+
+    13│  main = pretty { x: 0, y: 0 }
+                       ^^^^^^^^^^^^^^
+
+    Roc can't generate an implementation of the `#UserApp.Pretty` ability
+    for
+
+        { x : Num a, y : Num b }
+
+    Only builtin abilities can have generated implementations!
+    "###
+    );
+
+    test_report!(
+        builtin_implementation_of_foreign_ability,
+        indoc!(
+            r#"
+            app "test" imports [Encode] provides [myToEncoder] to "./platform"
+
+            Str has [Encode.Encoding { toEncoder: myToEncoder }]
+
+            myToEncoder = \s -> Encode.string s
+            "#
+        ),
+        @r###"
+    ── FOREIGN BUILTIN IMPLEMENTATION [E2015] ──────────────── /code/proj/Main.roc ─
+
+    This implements the `Encoding` ability for `Str` outside of the module
+    defining `Encoding`:
+
+    3│  Str has [Encode.Encoding { toEncoder: myToEncoder }]
+                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    Builtin types can only implement abilities in the module defining the
+    ability, so that each type has at most one implementation of it.
+    "###
+    );
+
+    test_report!(
+        builtin_implementation_is_duplicate,
+        indoc!(
+            r#"
+            app "test" provides [pretty] to "./platform"
+
+            Pretty has
+                pretty : a -> Str | a has Pretty
+
+            Str has [Pretty { pretty: prettyStr }]
+            Str has [Pretty { pretty: prettyStr2 }]
+
+            prettyStr = \s -> s
+            prettyStr2 = \s -> s
+            "#
+        ),
+        @r###"
+    ── DUPLICATE IMPLEMENTATION [E2062] ────────────────────── /code/proj/Main.roc ─
+
+    This implements the `Pretty` ability for `Str` again:
+
+    7│  Str has [Pretty { pretty: prettyStr2 }]
+                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    The first implementation was declared here:
+
+    6│  Str has [Pretty { pretty: prettyStr }]
+                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    A type can only have one implementation of an ability.
+
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
+
+    `prettyStr2` is not used anywhere in your code.
+
+    10│  prettyStr2 = \s -> s
+         ^^^^^^^^^^
+
+    If you didn't intend on using `prettyStr2` then remove it so future
+    readers of your code don't wonder why it is there.
+    "###
+    );
+
+    test_report!(
+        ability_default_implementation_is_not_unused,
+        indoc!(
+            r#"
+            app "test" provides [different] to "./platform"
+
+            MEq has
+                eq : a, a -> Bool | a has MEq
+                neq : a, a -> Bool | a has MEq
+                neq = \x, y -> Bool.not (eq x y)
+
+            Id := U64 has [MEq {eq}]
+
+            eq = \@Id m, @Id n -> m == n
+
+            different = neq (@Id 1) (@Id 2)
+            "#
+        ),
+        @"" // should give no error
+    );
+
     test_report!(
         ability_specialization_is_unused,
         indoc!(
@@ -8451,7 +8659,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `hash` is not used anywhere in your code.
 
@@ -8480,7 +8688,7 @@ All branches in an `if` must have the same type!
         ),
         // TODO: the error message here could be seriously improved!
         @r###"
-    ── OVERLOADED SPECIALIZATION ───────────────────────────── /code/proj/Main.roc ─
+    ── OVERLOADED SPECIALIZATION [E2043] ───────────────────── /code/proj/Main.roc ─
 
     This ability member specialization is already claimed to specialize
     another opaque type:
@@ -8493,7 +8701,7 @@ All branches in an `if` must have the same type!
     Ability specializations can only provide implementations for one
     opaque type, since all opaque types are different!
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3028] ───────────────────────────────── /code/proj/Main.roc ─
 
     This specialization of `hash` is overly general:
 
@@ -8533,7 +8741,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── OVERLOADED SPECIALIZATION ───────────────────────────── /code/proj/Main.roc ─
+    ── OVERLOADED SPECIALIZATION [E2043] ───────────────────── /code/proj/Main.roc ─
 
     This ability member specialization is already claimed to specialize
     another opaque type:
@@ -8564,7 +8772,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3027] ───────────────────────────────── /code/proj/Main.roc ─
 
         Something is off with this specialization of `eq`:
 
@@ -8602,7 +8810,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
         Something is off with the body of the `hash` definition:
 
@@ -8618,7 +8826,7 @@ All branches in an `if` must have the same type!
 
             U32
 
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3027] ───────────────────────────────── /code/proj/Main.roc ─
 
         Something is off with this specialization of `hash`:
 
@@ -8658,7 +8866,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -8671,7 +8879,7 @@ All branches in an `if` must have the same type!
 
     Only builtin abilities can have generated implementations!
 
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -8696,7 +8904,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── ABILITY NOT ON TOP-LEVEL ────────────────────────────── /code/proj/Main.roc ─
+        ── ABILITY NOT ON TOP-LEVEL [E2005] ────────────────────── /code/proj/Main.roc ─
 
         This ability definition is not on the top-level of a module:
 
@@ -8724,7 +8932,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
         Something is off with the body of the `hashable` definition:
 
@@ -8770,7 +8978,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── ABILITY USED AS TYPE ────────────────────────────────── /code/proj/Main.roc ─
+        ── ABILITY USED AS TYPE [E2006] ────────────────────────── /code/proj/Main.roc ─
 
         You are attempting to use the ability `Hash` as a type directly:
 
@@ -8784,7 +8992,7 @@ All branches in an `if` must have the same type!
 
             a has Hash
 
-        ── ABILITY USED AS TYPE ────────────────────────────────── /code/proj/Main.roc ─
+        ── ABILITY USED AS TYPE [E2006] ────────────────────────── /code/proj/Main.roc ─
 
         You are attempting to use the ability `Hash` as a type directly:
 
@@ -8814,7 +9022,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
         The branches of this `when` expression don't match the condition:
 
@@ -8863,7 +9071,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── WEIRD IMPORTS ────────────────────────── tmp/imports_missing_comma/Test.roc ─
+        ── WEIRD IMPORTS [E1057] ────────────────── tmp/imports_missing_comma/Test.roc ─
 
         I am partway through parsing a imports list, but I got stuck here:
 
@@ -8888,7 +9096,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+        ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
         This `when` does not cover all the possibilities:
 
@@ -8935,7 +9143,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── SPECIALIZATION NOT ON TOP-LEVEL ─────────────────────── /code/proj/Main.roc ─
+        ── SPECIALIZATION NOT ON TOP-LEVEL [E2045] ─────────────── /code/proj/Main.roc ─
 
         This specialization of the `default` ability member is in a nested
         scope:
@@ -8960,7 +9168,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `isEq` has an unexpected type:
 
@@ -8996,7 +9204,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `remove` has an unexpected type:
 
@@ -9017,7 +9225,7 @@ All branches in an `if` must have the same type!
     change the type annotation to be more specific? Maybe change the code
     to be more general?
 
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `new`:
 
@@ -9030,7 +9238,7 @@ All branches in an `if` must have the same type!
 
         { set : Set ∞ }
 
-    ── CIRCULAR TYPE ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── CIRCULAR TYPE [E3001] ───────────────────────────────── /code/proj/Main.roc ─
 
     I'm inferring a weird self-referential type for `goal`:
 
@@ -9060,7 +9268,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+        ── CIRCULAR DEFINITION [E2008] ─────────────────────────── /code/proj/Main.roc ─
 
         The `t1` definition is causing a very tricky infinite loop:
 
@@ -9088,7 +9296,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
 
         This expression has a type that does not implement the abilities it's expected to:
 
@@ -9117,7 +9325,7 @@ All branches in an `if` must have the same type!
         // TODO: this error message is quite unfortunate. We should remove the duplication, and
         // also support regions that point to things in other modules. See also https://github.com/roc-lang/roc/issues/3056.
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9155,7 +9363,7 @@ All branches in an `if` must have the same type!
                 "#
         ),
         @r#"
-            ── CIRCULAR DEFINITION ─────────────────────────────────── /code/proj/Main.roc ─
+            ── CIRCULAR DEFINITION [E2008] ─────────────────────────── /code/proj/Main.roc ─
 
             The `t1` definition is causing a very tricky infinite loop:
 
@@ -9185,7 +9393,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── IMPLEMENTATION NOT FOUND ────────────────────────────── /code/proj/Main.roc ─
+    ── IMPLEMENTATION NOT FOUND [E2020] ────────────────────── /code/proj/Main.roc ─
 
     An implementation of `eq` could not be found in this scope:
 
@@ -9196,7 +9404,7 @@ All branches in an `if` must have the same type!
     another variable that implements this ability member, like
     { eq: myeq }
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E2022] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `Eq` ability:
 
@@ -9223,7 +9431,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNRECOGNIZED NAME [E2051] ───────────────────────────── /code/proj/Main.roc ─
 
     Nothing is named `aEq` in this scope.
 
@@ -9237,7 +9445,7 @@ All branches in an `if` must have the same type!
         eq
         U8
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E2022] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `Eq` ability:
 
@@ -9264,7 +9472,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── OPTIONAL ABILITY IMPLEMENTATION ─────────────────────── /code/proj/Main.roc ─
+    ── OPTIONAL ABILITY IMPLEMENTATION [E2041] ─────────────── /code/proj/Main.roc ─
 
     Ability implementations cannot be optional:
 
@@ -9275,7 +9483,7 @@ All branches in an `if` must have the same type!
 
 
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E2022] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `Eq` ability:
 
@@ -9302,7 +9510,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── OPTIONAL ABILITY IMPLEMENTATION ─────────────────────── /code/proj/Main.roc ─
+    ── OPTIONAL ABILITY IMPLEMENTATION [E2041] ─────────────── /code/proj/Main.roc ─
 
     Ability implementations cannot be optional:
 
@@ -9315,7 +9523,7 @@ All branches in an `if` must have the same type!
     record of implementations. For example,    has [Encoding] will attempt
     to derive `Encoding`
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E2022] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `Encoding` ability:
 
@@ -9340,7 +9548,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── QUALIFIED ABILITY IMPLEMENTATION ────────────────────── /code/proj/Main.roc ─
+    ── QUALIFIED ABILITY IMPLEMENTATION [E2044] ────────────── /code/proj/Main.roc ─
 
     This ability implementation is qualified:
 
@@ -9350,7 +9558,7 @@ All branches in an `if` must have the same type!
     Custom implementations must be defined in the local scope, and
     unqualified.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E2022] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `Eq` ability:
 
@@ -9375,7 +9583,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── ABILITY IMPLEMENTATION NOT IDENTIFIER ───────────────── /code/proj/Main.roc ─
+    ── ABILITY IMPLEMENTATION NOT IDENTIFIER [E2002] ───────── /code/proj/Main.roc ─
 
     This ability implementation is not an identifier:
 
@@ -9387,7 +9595,7 @@ All branches in an `if` must have the same type!
 
     Tip: consider defining this expression as a variable.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E2022] ───────────── /code/proj/Main.roc ─
 
     This type does not fully implement the `Eq` ability:
 
@@ -9414,7 +9622,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── DUPLICATE IMPLEMENTATION ────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE IMPLEMENTATION [E2012] ────────────────────── /code/proj/Main.roc ─
 
     This ability member implementation is duplicate:
 
@@ -9442,7 +9650,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── NOT AN ABILITY ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── NOT AN ABILITY [E2032] ──────────────────────────────── /code/proj/Main.roc ─
 
     This identifier is not an ability in scope:
 
@@ -9465,7 +9673,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── ILLEGAL DERIVE ──────────────────────────────────────── /code/proj/Main.roc ─
+        ── ILLEGAL DERIVE [E2018] ──────────────────────────────── /code/proj/Main.roc ─
 
         This ability cannot be derived:
 
@@ -9488,7 +9696,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+        ── INCOMPLETE ABILITY IMPLEMENTATION [E3033] ───────────── /code/proj/Main.roc ─
 
         Roc can't derive an implementation of the `Encode.Encoding` for `A`:
 
@@ -9513,7 +9721,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+        ── INCOMPLETE ABILITY IMPLEMENTATION [E3033] ───────────── /code/proj/Main.roc ─
 
         Roc can't derive an implementation of the `Encode.Encoding` for `A`:
 
@@ -9565,7 +9773,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── DUPLICATE NAME ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
 
     The `main` name is first defined here:
 
@@ -9580,7 +9788,7 @@ All branches in an `if` must have the same type!
     Since these variables have the same name, it's easy to use the wrong
     one on accident. Give one of them a new name.
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -9614,7 +9822,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
         Something is off with the body of the `withOpen` definition:
 
@@ -9649,7 +9857,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+        ── TYPE MISMATCH [E3010] ───────────────────────────────── /code/proj/Main.roc ─
 
         This expression is used in an unexpected way:
 
@@ -9696,7 +9904,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 1st argument to `foo` has an unexpected type:
 
@@ -9741,7 +9949,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `isEq` has an unexpected type:
 
@@ -9766,7 +9974,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `isEq` has an unexpected type:
 
@@ -9803,7 +10011,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the 2nd branch of this `when` expression:
 
@@ -9837,7 +10045,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
 
     This 2nd argument to `map` has an unexpected type:
 
@@ -9863,7 +10071,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── NAME NOT BOUND IN ALL PATTERNS ──────────────────────── /code/proj/Main.roc ─
+        ── NAME NOT BOUND IN ALL PATTERNS [E2029] ──────────────── /code/proj/Main.roc ─
 
         `x` is not bound in all patterns of this `when` branch
 
@@ -9874,7 +10082,7 @@ All branches in an `if` must have the same type!
         of the branch. Otherwise, the program would crash when it tries to use
         an identifier that wasn't bound!
 
-        ── NAME NOT BOUND IN ALL PATTERNS ──────────────────────── /code/proj/Main.roc ─
+        ── NAME NOT BOUND IN ALL PATTERNS [E2029] ──────────────── /code/proj/Main.roc ─
 
         `y` is not bound in all patterns of this `when` branch
 
@@ -9885,7 +10093,7 @@ All branches in an `if` must have the same type!
         of the branch. Otherwise, the program would crash when it tries to use
         an identifier that wasn't bound!
 
-        ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+        ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
         `y` is not used in this `when` branch.
 
@@ -9907,7 +10115,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
 
     This `when` does not cover all the possibilities:
 
@@ -9933,7 +10141,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── SYNTAX PROBLEM [E2065] ──────────────────────────────── /code/proj/Main.roc ─
 
     Underscores are not allowed in identifier names:
 
@@ -9960,7 +10168,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -9972,7 +10180,7 @@ All branches in an `if` must have the same type!
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -9984,7 +10192,7 @@ All branches in an `if` must have the same type!
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -9996,7 +10204,7 @@ All branches in an `if` must have the same type!
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10026,7 +10234,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10038,7 +10246,7 @@ All branches in an `if` must have the same type!
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10050,7 +10258,7 @@ All branches in an `if` must have the same type!
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10062,7 +10270,7 @@ All branches in an `if` must have the same type!
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION ──────────────────────────────── /code/proj/Main.roc ─
+    ── UNNECESSARY DEFINITION [E2049] ──────────────────────── /code/proj/Main.roc ─
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10090,7 +10298,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `hash` is not used anywhere in your code.
 
@@ -10117,7 +10325,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── WRONG SPECIALIZATION TYPE ───────────────────────────── /code/proj/Main.roc ─
+    ── WRONG SPECIALIZATION TYPE [E3012] ───────────────────── /code/proj/Main.roc ─
 
     This specialization of `hash` is not for the expected type:
 
@@ -10140,7 +10348,7 @@ All branches in an `if` must have the same type!
                 "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `x` definition:
 
@@ -10170,7 +10378,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── CYCLIC ALIAS ────────────────────────────────────────── /code/proj/Main.roc ─
+    ── CYCLIC ALIAS [E3002] ────────────────────────────────── /code/proj/Main.roc ─
 
     The `Recursive` opaque is self-recursive in an invalid way:
 
@@ -10192,7 +10400,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E3033] ───────────── /code/proj/Main.roc ─
 
     Roc can't derive an implementation of the `Decode.Decoding` for `A`:
 
@@ -10217,7 +10425,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── INCOMPLETE ABILITY IMPLEMENTATION ───────────────────── /code/proj/Main.roc ─
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E3033] ───────────── /code/proj/Main.roc ─
 
     Roc can't derive an implementation of the `Decode.Decoding` for `A`:
 
@@ -10271,7 +10479,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -10303,7 +10511,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -10334,7 +10542,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r###"
-    ── UNUSED ARGUMENT ─────────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED ARGUMENT [E2052] ─────────────────────────────── /code/proj/Main.roc ─
 
     This function doesn't use `x`.
 
@@ -10366,7 +10574,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
 
     The branches of this `when` expression don't match the condition:
 
@@ -10386,6 +10594,112 @@ All branches in an `if` must have the same type!
         [Bad [DecodeProblem], Good (List U8) a]
 
     The branches must be cases of the `when` condition's type!
+
+    Tip: The `Good` tag has 1 payload in one type, but 2 payloads in the
+    other. Maybe a payload was added or left out by accident?
+    "###
+    );
+
+    test_report!(
+        large_record_mismatch_shows_only_differing_fields,
+        indoc!(
+            r#"
+            app "test" provides [user] to "./platform"
+
+            User : { name : Str, email : Str, age : U8, city : Str, score : F64 }
+
+            user : User
+            user = { name: "Ayla", email: "ayla@example.com", age: "thirty", city: "Oslo", score: 1.5 }
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
+
+    Something is off with the body of the `user` definition:
+
+    5│  user : User
+    6│  user = { name: "Ayla", email: "ayla@example.com", age: "thirty", city: "Oslo", score: 1.5 }
+               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    The body is a record of type:
+
+        { …, age : Str }
+
+    But the type annotation on `user` says it should be:
+
+        { …, age : U8 }
+
+    Tip: The `.age` field has a different type in each record.
+    "###
+    );
+
+    test_report!(
+        nested_record_mismatch_points_at_field_path,
+        indoc!(
+            r#"
+            app "test" provides [config] to "./platform"
+
+            Server : { host : Str, port : U16, path : Str, timeout : U32, retries : U8 }
+
+            config : { name : Str, server : Server }
+            config = {
+                name: "api",
+                server: { host: "localhost", port: "8080", path: "/", timeout: 30, retries: 3 },
+            }
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
+
+    Something is off with the body of the `config` definition:
+
+    5│   config : { name : Str, server : Server }
+    6│>  config = {
+    7│>      name: "api",
+    8│>      server: { host: "localhost", port: "8080", path: "/", timeout: 30, retries: 3 },
+    9│>  }
+
+    The body is a record of type:
+
+        { name : Str, server : { …, port : Str } }
+
+    But the type annotation on `config` says it should be:
+
+        { name : Str, server : { …, port : U16 } }
+
+    Tip: The `.server.port` field has a different type in each record.
+    "###
+    );
+
+    test_report!(
+        large_tag_union_mismatch_shows_only_differing_tags,
+        indoc!(
+            r#"
+            app "test" provides [color] to "./platform"
+
+            color : [Red, Green, Blue, Cyan, Magenta, Custom Str]
+            color = if 1 == 1 then Red else Custom 1 2
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
+
+    Something is off with the `else` branch of this `if` expression:
+
+    3│  color : [Red, Green, Blue, Cyan, Magenta, Custom Str]
+    4│  color = if 1 == 1 then Red else Custom 1 2
+                                        ^^^^^^^^^^
+
+    This `Custom` tag application has the type:
+
+        […, Custom (Num a) (Num b)]
+
+    But the type annotation on `color` says it should be:
+
+        […, Custom Str]
+
+    Tip: The `Custom` tag has 2 payloads in one type, but 1 payload in the
+    other. Maybe a payload was added or left out by accident?
     "###
     );
 
@@ -10399,7 +10713,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the body of the `f` definition:
 
@@ -10430,7 +10744,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
 
     Something is off with the `then` branch of this `if` expression:
 
@@ -10459,7 +10773,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r###"
-    ── UNUSED DEFINITION ───────────────────────────────────── /code/proj/Main.roc ─
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
 
     `foo` is not used in this `when` branch.
 
@@ -10485,7 +10799,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r###"
-    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -10515,7 +10829,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r###"
-     ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+     ── TYPE MISMATCH [E3031] ───────────────────────────────── /code/proj/Main.roc ─
 
      This expression has a type that does not implement the abilities it's expected to:
 
@@ -10534,4 +10848,343 @@ All branches in an `if` must have the same type!
      Maybe you wanted to use a `Result`?
      "###
     );
+
+    test_report!(
+        tuple_element_mismatch,
+        indoc!(
+            r#"
+            pair : (Str, U8)
+            pair = ("one", "two")
+
+            pair
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH [E3013] ───────────────────────────────── /code/proj/Main.roc ─
+
+    Something is off with the body of the `pair` definition:
+
+    4│      pair : (Str, U8)
+    5│      pair = ("one", "two")
+                   ^^^^^^^^^^^^^^
+
+    The body is a tuple of type:
+
+        ( Str, Str )
+
+    But the type annotation on `pair` says it should be:
+
+        ( Str, U8 )
+    "###
+    );
+
+    test_report!(
+        tuple_arity_mismatch,
+        indoc!(
+            r#"
+            first : (a, b) -> a
+            first = \(a, _) -> a
+
+            first (1, 2, 3)
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH [E3025] ───────────────────────────────── /code/proj/Main.roc ─
+
+    This 1st argument to `first` has an unexpected type:
+
+    7│      first (1, 2, 3)
+                  ^^^^^^^^^
+
+    The argument is a tuple of type:
+
+        ( Num a, Num b, Num c )
+
+    But `first` needs its 1st argument to be:
+
+        ( a, b )
+    "###
+    );
+
+    test_report!(
+        empty_parens_expr,
+        indoc!(
+            r#"
+            x = ()
+
+            x
+            "#
+        ),
+        @r###"
+    ── EMPTY PARENTHESES [E1008] ────────────────── tmp/empty_parens_expr/Test.roc ─
+
+    I was expecting to see an expression or a tuple inside these
+    parentheses, but they are empty:
+
+    4│      x = ()
+                ^
+
+    Roc has no () value. If you need a value that holds nothing, use the
+    empty record {} instead.
+    "###
+    );
+
+    test_report!(
+        list_pattern_not_exhaustive,
+        indoc!(
+            r#"
+            when [] is
+                [] -> ""
+                [_] -> ""
+                [_, "a"] -> ""
+                [_, _, _, ..] -> ""
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN [E3011] ──────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    4│>      when [] is
+    5│>          [] -> ""
+    6│>          [_] -> ""
+    7│>          [_, "a"] -> ""
+    8│>          [_, _, _, ..] -> ""
+
+    Other possibilities include:
+
+        [_, _]
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "###
+    );
+
+    test_report!(
+        list_pattern_redundant,
+        indoc!(
+            r#"
+            when [] is
+                [] -> ""
+                [_, ..] -> ""
+                [_, _] -> ""
+            "#
+        ),
+        @r###"
+    ── REDUNDANT PATTERN [E3005] ───────────────────────────── /code/proj/Main.roc ─
+
+    The 3rd pattern is redundant:
+
+    4│      when [] is
+    5│          [] -> ""
+    6│          [_, ..] -> ""
+    7│          [_, _] -> ""
+                ^^^^^^
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "###
+    );
+
+    test_report!(
+        list_pattern_multiple_rests,
+        indoc!(
+            r#"
+            when [] is
+                [_, .., _, ..] -> ""
+                _ -> ""
+            "#
+        ),
+        @r###"
+    ── MULTIPLE LIST REST PATTERNS [E2028] ─────────────────── /code/proj/Main.roc ─
+
+    This list pattern matches multiple sublists:
+
+    5│          [_, .., _, ..] -> ""
+                ^^^^^^^^^^^^^^
+
+    Each list pattern can only have one .., so I'm not sure how to match
+    the elements around them.
+    "###
+    );
+
+    test_report!(
+        list_pattern_element_mismatch,
+        indoc!(
+            r#"
+            when ["a"] is
+                [1, ..] -> ""
+                _ -> ""
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH [E3029] ───────────────────────────────── /code/proj/Main.roc ─
+
+    The branches of this `when` expression don't match the condition:
+
+    4│>      when ["a"] is
+    5│           [1, ..] -> ""
+    6│           _ -> ""
+
+    The `when` condition is a list of type:
+
+        List Str
+
+    But the branch patterns have type:
+
+        List (Num a)
+
+    The branches must be cases of the `when` condition's type!
+    "###
+    );
+
+    test_report!(
+        as_pattern_missing_name,
+        indoc!(
+            r#"
+            when Ok 4 is
+                Ok x as -> x
+                _ -> 0
+            "#
+        ),
+        @r###"
+    ── UNFINISHED PATTERN [E1068] ─────────── tmp/as_pattern_missing_name/Test.roc ─
+
+    I was expecting to see a name after this `as`, but I got stuck here:
+
+    5│          Ok x as -> x
+                        ^
+
+    The name after `as` is bound to the whole value the pattern matches,
+    like result in Ok x as result.
+    "###
+    );
+
+    test_report!(
+        as_pattern_shadows,
+        indoc!(
+            r#"
+            x = 4
+
+            when Ok 4 is
+                Ok _ as x -> x
+                _ -> x
+            "#
+        ),
+        @r###"
+    ── DUPLICATE NAME [E2013] ──────────────────────────────── /code/proj/Main.roc ─
+
+    The `x` name is first defined here:
+
+    4│      x = 4
+            ^
+
+    But then it's defined a second time here:
+
+    7│          Ok _ as x -> x
+                        ^
+
+    Since these variables have the same name, it's easy to use the wrong
+    one on accident. Give one of them a new name.
+
+    ── UNUSED DEFINITION [E2053] ───────────────────────────── /code/proj/Main.roc ─
+
+    `x` is not used in this `when` branch.
+
+    7│          Ok _ as x -> x
+                        ^
+
+    If you don't need to use `x`, prefix it with an underscore, like "_x",
+    or replace it with just an "_".
+    "###
+    );
+
+    test_report!(
+        as_pattern_unused,
+        indoc!(
+            r#"
+            f = \Pair x _ as pair -> x
+
+            f (Pair 1 2)
+            "#
+        ),
+        @r###"
+    ── UNUSED ARGUMENT [E2052] ─────────────────────────────── /code/proj/Main.roc ─
+
+    `f` doesn't use `pair`.
+
+    4│      f = \Pair x _ as pair -> x
+                             ^^^^
+
+    If you don't need `pair`, then you can just remove it. However, if you
+    really do need `pair` as an argument of `f`, prefix it with an underscore,
+    like this: "_`pair`". Adding an underscore at the start of a variable
+    name is a way of saying that the variable is not used.
+    "###
+    );
+
+    test_report!(
+        crash_given_non_str,
+        indoc!(
+            r#"
+            crash {}
+            "#
+        ),
+        @r###"
+    ── TYPE MISMATCH [E3015] ───────────────────────────────── /code/proj/Main.roc ─
+
+    This value passed to `crash` is not a string:
+
+    4│      crash {}
+                  ^^
+
+    The value is a record of type:
+
+        {}
+
+    But I can only `crash` with messages of type Str.
+    "###
+    );
+
+    test_report!(
+        crash_unapplied,
+        indoc!(
+            r#"
+            f : Str -> {}
+            f = crash
+
+            f ""
+            "#
+        ),
+        @r###"
+    ── UNAPPLIED CRASH [E2046] ─────────────────────────────── /code/proj/Main.roc ─
+
+    This `crash` doesn't have a message given to it:
+
+    5│      f = crash
+                ^^^^^
+
+    `crash` must be passed a message to crash with at the exact place it's
+    used. `crash` can't be used as a value that's passed around, like
+    functions can be - it must be applied immediately!
+    "###
+    );
+
+    test_report!(
+        crash_overapplied,
+        indoc!(
+            r#"
+            crash "" ""
+            "#
+        ),
+        @r###"
+    ── OVERAPPLIED CRASH [E2042] ───────────────────────────── /code/proj/Main.roc ─
+
+    This `crash` has too many values given to it:
+
+    4│      crash "" ""
+                     ^^
+
+    `crash` must be given exactly one message to crash with.
+    "###
+    );
 }
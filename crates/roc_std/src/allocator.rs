@@ -0,0 +1,277 @@
+#![deny(unsafe_op_in_unsafe_fn)]
+
+//! The allocator used by `RocList`, `RocStr` and `RocBox`.
+//!
+//! By default (with the `externs` feature), memory is managed through the
+//! `roc_alloc`, `roc_realloc` and `roc_dealloc` symbols that every platform host
+//! already defines for the Roc application. Hosts that can't or don't want to
+//! provide those symbols - for example embedded or WASM hosts, or unit tests of
+//! host code - can install their own [`RocAllocator`] with [`set_allocator`]
+//! before creating any Roc values.
+
+use core::{
+    ffi::c_void,
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A memory allocator for Roc values, with the same contract as the
+/// `roc_alloc`/`roc_realloc`/`roc_dealloc` functions a platform host provides.
+///
+/// Note that a value's memory is always released through whichever allocator is
+/// installed at the time, so a value allocated by the Roc application (which
+/// always calls the `roc_alloc` symbol) must only be freed by an allocator that
+/// is compatible with it.
+pub trait RocAllocator: Sync {
+    /// Allocates `size` bytes aligned to `alignment`, returning null on failure.
+    ///
+    /// # Safety
+    ///
+    /// `alignment` must be a power of two.
+    unsafe fn alloc(&self, size: usize, alignment: u32) -> *mut c_void;
+
+    /// Resizes an allocation to `new_size` bytes, returning null on failure.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by this allocator with the given `alignment`,
+    /// and `old_size` must be the size it was last allocated or reallocated with.
+    unsafe fn realloc(
+        &self,
+        ptr: *mut c_void,
+        new_size: usize,
+        old_size: usize,
+        alignment: u32,
+    ) -> *mut c_void;
+
+    /// Releases an allocation.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by this allocator with the given `alignment`,
+    /// and must not be used afterwards.
+    unsafe fn dealloc(&self, ptr: *mut c_void, alignment: u32);
+}
+
+/// Delegates to the `roc_alloc`, `roc_realloc` and `roc_dealloc` symbols
+/// defined by the platform host.
+#[cfg(feature = "externs")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExternAllocator;
+
+#[cfg(feature = "externs")]
+impl RocAllocator for ExternAllocator {
+    unsafe fn alloc(&self, size: usize, alignment: u32) -> *mut c_void {
+        unsafe { crate::roc_alloc(size, alignment) }
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut c_void,
+        new_size: usize,
+        old_size: usize,
+        alignment: u32,
+    ) -> *mut c_void {
+        unsafe { crate::roc_realloc(ptr, new_size, old_size, alignment) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut c_void, alignment: u32) {
+        unsafe { crate::roc_dealloc(ptr, alignment) }
+    }
+}
+
+/// Uses Rust's global allocator.
+///
+/// Since `dealloc` isn't told the size of the allocation, each allocation is
+/// prefixed with a header recording its size.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemAllocator;
+
+#[cfg(feature = "std")]
+impl SystemAllocator {
+    /// The number of bytes before the returned pointer that hold the header.
+    /// This is a multiple of the alignment, so the returned pointer stays aligned.
+    fn header_bytes(alignment: u32) -> usize {
+        (alignment as usize).max(core::mem::size_of::<usize>())
+    }
+
+    fn layout(size: usize, alignment: u32) -> std::alloc::Layout {
+        let header = Self::header_bytes(alignment);
+
+        std::alloc::Layout::from_size_align(size + header, header)
+            .expect("Invalid size or alignment passed to SystemAllocator")
+    }
+
+    /// Writes the size into the header and returns a pointer past it.
+    unsafe fn finish(base: *mut u8, size: usize, alignment: u32) -> *mut c_void {
+        if base.is_null() {
+            return core::ptr::null_mut();
+        }
+
+        unsafe {
+            let ptr = base.add(Self::header_bytes(alignment));
+
+            ptr.cast::<usize>().sub(1).write(size);
+
+            ptr.cast()
+        }
+    }
+
+    /// Returns the start of the allocation and the size recorded in its header.
+    unsafe fn base_and_size(ptr: *mut c_void, alignment: u32) -> (*mut u8, usize) {
+        unsafe {
+            let size = ptr.cast::<usize>().sub(1).read();
+            let base = ptr.cast::<u8>().sub(Self::header_bytes(alignment));
+
+            (base, size)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl RocAllocator for SystemAllocator {
+    unsafe fn alloc(&self, size: usize, alignment: u32) -> *mut c_void {
+        unsafe {
+            let base = std::alloc::alloc(Self::layout(size, alignment));
+
+            Self::finish(base, size, alignment)
+        }
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut c_void,
+        new_size: usize,
+        _old_size: usize,
+        alignment: u32,
+    ) -> *mut c_void {
+        unsafe {
+            let (base, size) = Self::base_and_size(ptr, alignment);
+            let new_base = std::alloc::realloc(
+                base,
+                Self::layout(size, alignment),
+                new_size + Self::header_bytes(alignment),
+            );
+
+            Self::finish(new_base, new_size, alignment)
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut c_void, alignment: u32) {
+        unsafe {
+            let (base, size) = Self::base_and_size(ptr, alignment);
+
+            std::alloc::dealloc(base, Self::layout(size, alignment));
+        }
+    }
+}
+
+/// Used when no allocator has been installed and there is no default to fall back on.
+struct MissingAllocator;
+
+impl RocAllocator for MissingAllocator {
+    unsafe fn alloc(&self, _size: usize, _alignment: u32) -> *mut c_void {
+        missing_allocator()
+    }
+
+    unsafe fn realloc(
+        &self,
+        _ptr: *mut c_void,
+        _new_size: usize,
+        _old_size: usize,
+        _alignment: u32,
+    ) -> *mut c_void {
+        missing_allocator()
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut c_void, _alignment: u32) {
+        missing_allocator()
+    }
+}
+
+fn missing_allocator() -> ! {
+    panic!("roc_std has no allocator: either enable the `externs` or `std` feature, or call `roc_std::set_allocator` before creating any Roc values.")
+}
+
+#[cfg(feature = "externs")]
+static DEFAULT_ALLOCATOR: &dyn RocAllocator = &ExternAllocator;
+
+#[cfg(all(not(feature = "externs"), feature = "std"))]
+static DEFAULT_ALLOCATOR: &dyn RocAllocator = &SystemAllocator;
+
+#[cfg(all(not(feature = "externs"), not(feature = "std")))]
+static DEFAULT_ALLOCATOR: &dyn RocAllocator = &MissingAllocator;
+
+// The installed allocator is written exactly once, guarded by STATE, the same
+// way the `log` crate installs its global logger. This works without `std`.
+static mut ALLOCATOR: &dyn RocAllocator = &MissingAllocator;
+static STATE: AtomicUsize = AtomicUsize::new(UNINITIALIZED);
+
+const UNINITIALIZED: usize = 0;
+const INITIALIZING: usize = 1;
+const INITIALIZED: usize = 2;
+
+/// Returned by [`set_allocator`] if an allocator has already been installed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SetAllocatorError;
+
+impl fmt::Display for SetAllocatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a Roc allocator has already been installed")
+    }
+}
+
+/// Installs the allocator used for every `RocList`, `RocStr` and `RocBox`
+/// allocation from now on. This can only be done once per process, and should
+/// happen before any Roc values are created: values allocated by the previous
+/// allocator would otherwise be released through the new one.
+pub fn set_allocator(allocator: &'static dyn RocAllocator) -> Result<(), SetAllocatorError> {
+    match STATE.compare_exchange(
+        UNINITIALIZED,
+        INITIALIZING,
+        Ordering::SeqCst,
+        Ordering::SeqCst,
+    ) {
+        Ok(_) => {
+            // Safety: only the thread that moved STATE to INITIALIZING gets here,
+            // and nobody reads ALLOCATOR until STATE is INITIALIZED.
+            unsafe {
+                ALLOCATOR = allocator;
+            }
+
+            STATE.store(INITIALIZED, Ordering::SeqCst);
+
+            Ok(())
+        }
+        Err(_) => Err(SetAllocatorError),
+    }
+}
+
+/// The allocator installed with [`set_allocator`], or the default one for the
+/// enabled features if none has been installed.
+pub fn allocator() -> &'static dyn RocAllocator {
+    if STATE.load(Ordering::SeqCst) == INITIALIZED {
+        // Safety: ALLOCATOR is never written again once STATE is INITIALIZED.
+        unsafe { ALLOCATOR }
+    } else {
+        DEFAULT_ALLOCATOR
+    }
+}
+
+pub(crate) unsafe fn alloc(size: usize, alignment: u32) -> *mut c_void {
+    unsafe { allocator().alloc(size, alignment) }
+}
+
+pub(crate) unsafe fn realloc(
+    ptr: *mut c_void,
+    new_size: usize,
+    old_size: usize,
+    alignment: u32,
+) -> *mut c_void {
+    unsafe { allocator().realloc(ptr, new_size, old_size, alignment) }
+}
+
+pub(crate) unsafe fn dealloc(ptr: *mut c_void, alignment: u32) {
+    unsafe { allocator().dealloc(ptr, alignment) }
+}
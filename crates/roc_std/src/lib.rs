@@ -1,8 +1,9 @@
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![crate_type = "lib"]
 
 use arrayvec::ArrayString;
 use core::cmp::Ordering;
+#[cfg(feature = "externs")]
 use core::ffi::c_void;
 use core::fmt::{self, Debug};
 use core::hash::{Hash, Hasher};
@@ -10,6 +11,7 @@ use core::mem::{ManuallyDrop, MaybeUninit};
 use core::ops::Drop;
 use core::str;
 
+mod allocator;
 mod roc_box;
 mod roc_dict;
 mod roc_list;
@@ -17,14 +19,20 @@ mod roc_set;
 mod roc_str;
 mod storage;
 
+#[cfg(feature = "externs")]
+pub use allocator::ExternAllocator;
+#[cfg(feature = "std")]
+pub use allocator::SystemAllocator;
+pub use allocator::{allocator, set_allocator, RocAllocator, SetAllocatorError};
 pub use roc_box::RocBox;
-pub use roc_dict::RocDict;
+pub use roc_dict::{Entry, OccupiedEntry, RocDict, RocDictItem, VacantEntry};
 pub use roc_list::RocList;
 pub use roc_set::RocSet;
 pub use roc_str::{InteriorNulError, RocStr};
 pub use storage::Storage;
 
 // A list of C functions that are being imported
+#[cfg(feature = "externs")]
 extern "C" {
     pub fn roc_alloc(size: usize, alignment: u32) -> *mut c_void;
     pub fn roc_realloc(
@@ -52,7 +60,7 @@ fn roc_alloc_refcounted_help(mut size: usize, mut align: usize) -> *mut u8 {
     align = align.max(core::mem::size_of::<crate::Storage>());
 
     unsafe {
-        let allocation_ptr = roc_alloc(size, align as _) as *mut u8;
+        let allocation_ptr = allocator::alloc(size, align as _) as *mut u8;
         let data_ptr = allocation_ptr.add(prefix);
         let storage_ptr = (data_ptr as *mut crate::Storage).sub(1);
 
@@ -1,50 +1,221 @@
 use crate::roc_list::{self, RocList};
 use core::{
+    cmp::Ordering,
     fmt::{self, Debug},
-    hash::Hash,
+    hash::{Hash, Hasher},
+    iter::Map,
+    mem::{self, ManuallyDrop},
+    slice,
 };
 
+/// A Rust representation of Roc's `Dict k v`.
+///
+/// `Dict.roc` defines a dictionary as `List [Pair k v]`, and this type has the
+/// same in-memory layout, so a `RocDict` can be passed across the host boundary
+/// in either direction. Its operations also have the same semantics as their
+/// `Dict.roc` counterparts: inserting an existing key replaces its value in-place,
+/// and removing a key moves the most recently inserted entry into the vacated slot.
 #[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct RocDict<K, V>(RocList<(K, V)>);
+#[repr(transparent)]
+pub struct RocDict<K, V>(RocList<RocDictItem<K, V>>);
 
 impl<K, V> RocDict<K, V> {
+    pub fn empty() -> Self {
+        Self(RocList::empty())
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     pub fn with_capacity(capacity: usize) -> Self {
         Self(RocList::with_capacity(capacity))
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &(K, V)> {
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
         self.into_iter()
     }
 
     pub fn iter_keys(&self) -> impl Iterator<Item = &K> {
-        self.0.iter().map(|(key, _)| key)
+        self.0.iter().map(RocDictItem::key)
     }
 
     pub fn iter_values(&self) -> impl Iterator<Item = &V> {
-        self.0.iter().map(|(_, val)| val)
+        self.0.iter().map(RocDictItem::value)
+    }
+}
+
+impl<K: PartialEq, V> RocDict<K, V> {
+    fn index_of(&self, key: &K) -> Option<usize> {
+        self.0.iter().position(|item| item.key() == key)
+    }
+
+    /// Returns a reference to the value associated with the key, like `Dict.get`.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.index_of(key).map(|index| self.0[index].value())
+    }
+
+    /// Returns `true` if the dictionary has a value for the key, like `Dict.contains`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.index_of(key).is_some()
     }
 }
 
-impl<K: Hash, V> RocDict<K, V> {
-    #[allow(unused)]
+impl<K: PartialEq + Clone, V: Clone> RocDict<K, V> {
     pub fn from_iter<I: Iterator<Item = (K, V)>>(src: I) -> Self {
         let mut ret = Self::with_capacity(src.size_hint().0);
 
         for (key, val) in src {
-            unsafe {
-                ret.insert_unchecked(key, val);
-            }
+            ret.insert(key, val);
         }
 
         ret
     }
 
-    unsafe fn insert_unchecked(&mut self, _key: K, _val: V) {
-        todo!();
+    /// Returns a mutable reference to the value associated with the key.
+    ///
+    /// If this dictionary shares its allocation with another one, the entries
+    /// are copied into a fresh allocation first.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.index_of(key)?;
+
+        Some(self.0.as_mut_slice()[index].value_mut())
+    }
+
+    /// Associates the value with the key, like `Dict.insert`, returning the
+    /// value that was previously associated with it (if any).
+    ///
+    /// An existing key keeps its position; a new key is appended at the end.
+    pub fn insert(&mut self, key: K, val: V) -> Option<V> {
+        match self.index_of(&key) {
+            Some(index) => {
+                let item = &mut self.0.as_mut_slice()[index];
+
+                *item.key_mut() = key;
+
+                Some(mem::replace(item.value_mut(), val))
+            }
+            None => {
+                self.0.push(RocDictItem::new(key, val));
+
+                None
+            }
+        }
+    }
+
+    /// Removes the key from the dictionary, like `Dict.remove`, returning the
+    /// value that was associated with it (if any).
+    ///
+    /// The most recently inserted entry is moved into the vacated slot.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.index_of(key)?;
+
+        Some(self.0.swap_remove(index).into_parts().1)
+    }
+
+    /// Gets the entry for the key, for in-place insertion or modification.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.index_of(&key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { dict: self, index }),
+            None => Entry::Vacant(VacantEntry { dict: self, key }),
+        }
+    }
+}
+
+/// A view into a single entry of a [`RocDict`], obtained via [`RocDict::entry`].
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    dict: &'a mut RocDict<K, V>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V> {
+    dict: &'a mut RocDict<K, V>,
+    key: K,
+}
+
+impl<'a, K: PartialEq + Clone, V: Clone> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K: PartialEq + Clone, V: Clone> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        self.dict.0[self.index].key()
+    }
+
+    pub fn get(&self) -> &V {
+        self.dict.0[self.index].value()
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        self.dict.0.as_mut_slice()[self.index].value_mut()
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        self.dict.0.as_mut_slice()[self.index].value_mut()
+    }
+
+    pub fn insert(&mut self, val: V) -> V {
+        mem::replace(self.get_mut(), val)
+    }
+
+    pub fn remove(self) -> V {
+        self.dict.0.swap_remove(self.index).into_parts().1
+    }
+}
+
+impl<'a, K: PartialEq + Clone, V: Clone> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn insert(self, val: V) -> &'a mut V {
+        let list = &mut self.dict.0;
+
+        list.push(RocDictItem::new(self.key, val));
+
+        let last = list.len() - 1;
+
+        list.as_mut_slice()[last].value_mut()
     }
 }
 
@@ -52,26 +223,196 @@ impl<K: Debug, V: Debug> Debug for RocDict<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("RocDict ")?;
 
-        f.debug_map()
-            .entries(self.iter().map(|(k, v)| (k, v)))
-            .finish()
+        f.debug_map().entries(self.iter()).finish()
     }
 }
 
 impl<K, V> IntoIterator for RocDict<K, V> {
     type Item = (K, V);
-    type IntoIter = roc_list::IntoIter<(K, V)>;
+    type IntoIter = Map<roc_list::IntoIter<RocDictItem<K, V>>, fn(RocDictItem<K, V>) -> (K, V)>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.0.into_iter().map(RocDictItem::into_parts)
     }
 }
 
 impl<'a, K, V> IntoIterator for &'a RocDict<K, V> {
-    type Item = &'a (K, V);
-    type IntoIter = core::slice::Iter<'a, (K, V)>;
+    type Item = (&'a K, &'a V);
+    type IntoIter =
+        Map<slice::Iter<'a, RocDictItem<K, V>>, fn(&'a RocDictItem<K, V>) -> (&'a K, &'a V)>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.as_slice().iter()
+        self.0.as_slice().iter().map(RocDictItem::parts)
+    }
+}
+
+impl<K: PartialEq + Clone, V: Clone> Extend<(K, V)> for RocDict<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, val) in iter {
+            self.insert(key, val);
+        }
+    }
+}
+
+/// An entry of a [`RocDict`], with the same in-memory layout as Roc's `Pair k v`.
+///
+/// Roc sorts a tag's payloads by alignment, keeping their order when it's the same, so the
+/// value only comes first when it's more aligned than the key. Both orders have the same size
+/// and alignment, so this is a union of the two, and the accessors use whichever one applies.
+#[repr(C)]
+pub union RocDictItem<K, V> {
+    key_first: ManuallyDrop<KeyFirst<K, V>>,
+    value_first: ManuallyDrop<ValueFirst<K, V>>,
+}
+
+#[repr(C)]
+struct KeyFirst<K, V> {
+    key: K,
+    value: V,
+}
+
+#[repr(C)]
+struct ValueFirst<K, V> {
+    value: V,
+    key: K,
+}
+
+impl<K, V> RocDictItem<K, V> {
+    const VALUE_FIRST: bool = mem::align_of::<V>() > mem::align_of::<K>();
+
+    pub fn new(key: K, value: V) -> Self {
+        if Self::VALUE_FIRST {
+            Self {
+                value_first: ManuallyDrop::new(ValueFirst { value, key }),
+            }
+        } else {
+            Self {
+                key_first: ManuallyDrop::new(KeyFirst { key, value }),
+            }
+        }
+    }
+
+    // Safety (for all the accessors): `new` initializes the field that `VALUE_FIRST` picks,
+    // and that's the only way to make a `RocDictItem` in Rust. Roc lays out the ones it makes
+    // the same way.
+
+    pub fn key(&self) -> &K {
+        unsafe {
+            if Self::VALUE_FIRST {
+                &self.value_first.key
+            } else {
+                &self.key_first.key
+            }
+        }
+    }
+
+    pub fn value(&self) -> &V {
+        unsafe {
+            if Self::VALUE_FIRST {
+                &self.value_first.value
+            } else {
+                &self.key_first.value
+            }
+        }
+    }
+
+    pub fn parts(&self) -> (&K, &V) {
+        (self.key(), self.value())
+    }
+
+    fn key_mut(&mut self) -> &mut K {
+        unsafe {
+            if Self::VALUE_FIRST {
+                &mut self.value_first.key
+            } else {
+                &mut self.key_first.key
+            }
+        }
+    }
+
+    pub fn value_mut(&mut self) -> &mut V {
+        unsafe {
+            if Self::VALUE_FIRST {
+                &mut self.value_first.value
+            } else {
+                &mut self.key_first.value
+            }
+        }
+    }
+
+    pub fn into_parts(self) -> (K, V) {
+        // Don't run our own destructor; the key and value get moved out instead.
+        let mut item = ManuallyDrop::new(self);
+
+        unsafe {
+            if Self::VALUE_FIRST {
+                let ValueFirst { value, key } = ManuallyDrop::take(&mut item.value_first);
+
+                (key, value)
+            } else {
+                let KeyFirst { key, value } = ManuallyDrop::take(&mut item.key_first);
+
+                (key, value)
+            }
+        }
+    }
+}
+
+impl<K, V> Drop for RocDictItem<K, V> {
+    fn drop(&mut self) {
+        unsafe {
+            if Self::VALUE_FIRST {
+                ManuallyDrop::drop(&mut self.value_first)
+            } else {
+                ManuallyDrop::drop(&mut self.key_first)
+            }
+        }
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for RocDictItem<K, V> {
+    fn clone(&self) -> Self {
+        Self::new(self.key().clone(), self.value().clone())
+    }
+}
+
+impl<K: Default, V: Default> Default for RocDictItem<K, V> {
+    fn default() -> Self {
+        Self::new(K::default(), V::default())
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for RocDictItem<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.parts() == other.parts()
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for RocDictItem<K, V> {}
+
+impl<K: PartialOrd, V: PartialOrd> PartialOrd for RocDictItem<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.parts().partial_cmp(&other.parts())
+    }
+}
+
+impl<K: Ord, V: Ord> Ord for RocDictItem<K, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.parts().cmp(&other.parts())
+    }
+}
+
+impl<K: Hash, V: Hash> Hash for RocDictItem<K, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parts().hash(state)
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for RocDictItem<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RocDictItem")
+            .field(self.key())
+            .field(self.value())
+            .finish()
     }
 }
@@ -13,7 +13,7 @@ use core::{
     ptr::{self, NonNull},
 };
 
-use crate::{roc_alloc, roc_dealloc, roc_realloc, storage::Storage};
+use crate::{allocator, storage::Storage};
 
 #[cfg(feature = "serde")]
 use core::marker::PhantomData;
@@ -67,7 +67,8 @@ impl<T> RocList<T> {
     }
 
     fn elems_with_capacity(num_elems: usize) -> NonNull<ManuallyDrop<T>> {
-        let alloc_ptr = unsafe { roc_alloc(Self::alloc_bytes(num_elems), Self::alloc_alignment()) };
+        let alloc_ptr =
+            unsafe { allocator::alloc(Self::alloc_bytes(num_elems), Self::alloc_alignment()) };
 
         Self::elems_from_allocation(NonNull::new(alloc_ptr).unwrap_or_else(|| {
             todo!("Call roc_panic with the info that an allocation failed.");
@@ -182,7 +183,7 @@ where
                     // There wasn't enough capacity, so we need a new allocation.
                     // Since this is a unique RocList, we can use realloc here.
                     let new_ptr = unsafe {
-                        roc_realloc(
+                        allocator::realloc(
                             storage.as_ptr().cast(),
                             Self::alloc_bytes(new_len),
                             Self::alloc_bytes(self.capacity),
@@ -257,7 +258,7 @@ impl<T> RocList<T> {
                         let old_alloc = self.ptr_to_allocation();
 
                         // Try to reallocate in-place.
-                        let new_alloc = roc_realloc(
+                        let new_alloc = allocator::realloc(
                             old_alloc,
                             Self::alloc_bytes(new_len),
                             Self::alloc_bytes(self.capacity),
@@ -266,6 +267,8 @@ impl<T> RocList<T> {
 
                         if new_alloc == old_alloc {
                             // We successfully reallocated in-place; we're done!
+                            self.capacity = new_len;
+
                             return;
                         } else {
                             // We got back a different allocation; copy the existing elements
@@ -280,7 +283,7 @@ impl<T> RocList<T> {
                         }
 
                         // Note that realloc automatically deallocates the old allocation,
-                        // so we don't need to call roc_dealloc here.
+                        // so we don't need to call dealloc here.
                     }
                 } else {
                     // Make a new allocation
@@ -303,7 +306,10 @@ impl<T> RocList<T> {
                             // The new allocation is referencing them, so instead of incrementing them all
                             // all just to decrement them again here, we neither increment nor decrement them.
                             unsafe {
-                                roc_dealloc(self.ptr_to_allocation(), Self::alloc_alignment());
+                                allocator::dealloc(
+                                    self.ptr_to_allocation(),
+                                    Self::alloc_alignment(),
+                                );
                             }
                         } else {
                             // Write the storage back.
@@ -327,6 +333,15 @@ impl<T> RocList<T> {
         });
     }
 
+    /// Whether this list is the only reference to its allocation, which means
+    /// its elements can be modified in-place. Empty lists are always unique.
+    pub fn is_unique(&self) -> bool {
+        match self.storage() {
+            Some(storage) => storage.is_unique(),
+            None => true,
+        }
+    }
+
     /// Replace self with a new version, without letting `drop` run in between.
     fn update_to(&mut self, mut updated: Self) {
         // We want to replace `self` with `updated` in a way that makes sure
@@ -338,6 +353,89 @@ impl<T> RocList<T> {
     }
 }
 
+impl<T> RocList<T>
+where
+    T: Clone,
+{
+    /// If this list shares its allocation with other lists (or is readonly),
+    /// replace it with a fresh copy that it owns exclusively.
+    fn make_unique(&mut self) {
+        if !self.is_unique() {
+            // Dropping the old list here decrements its reference count.
+            *self = self.iter().cloned().collect();
+        }
+    }
+
+    /// Returns a mutable slice of the elements, first copying them into a fresh
+    /// allocation if this list is not unique.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.make_unique();
+
+        match self.elements {
+            Some(elements) => unsafe {
+                core::slice::from_raw_parts_mut(elements.as_ptr().cast::<T>(), self.length)
+            },
+            None => &mut [],
+        }
+    }
+
+    /// Appends an element to the end of the list, growing it if necessary.
+    pub fn push(&mut self, value: T) {
+        self.make_unique();
+
+        if self.elements.is_none() || self.length == self.capacity {
+            self.reserve((self.capacity / 2).max(1));
+        }
+
+        let elements = self.elements.unwrap().as_ptr();
+
+        unsafe {
+            elements
+                .add(self.length)
+                .write(ptr::read(&ManuallyDrop::new(value)));
+        }
+
+        self.length += 1;
+    }
+
+    /// Removes the last element of the list and returns it, or `None` if the list is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.make_unique();
+
+        self.length -= 1;
+
+        let elements = self.elements?.as_ptr();
+
+        Some(unsafe { ManuallyDrop::into_inner(elements.add(self.length).read()) })
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element
+    /// into the vacated slot. This does not preserve ordering, but is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+
+        if index >= len {
+            panic!(
+                "swap_remove index (is {}) should be < len (is {})",
+                index, len
+            );
+        }
+
+        self.as_mut_slice().swap(index, len - 1);
+
+        // We just checked that the list is non-empty.
+        self.pop().unwrap()
+    }
+}
+
 impl<T> Deref for RocList<T> {
     type Target = [T];
 
@@ -463,7 +561,7 @@ impl<T> Drop for RocList<T> {
                         }
 
                         // Release the memory.
-                        roc_dealloc(self.ptr_to_allocation(), Self::alloc_alignment());
+                        allocator::dealloc(self.ptr_to_allocation(), Self::alloc_alignment());
                     }
                 } else {
                     // Write the storage back.
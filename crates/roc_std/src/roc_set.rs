@@ -1,17 +1,25 @@
 use crate::roc_dict::RocDict;
-use core::{
-    fmt::{self, Debug},
-    hash::Hash,
-};
+use core::fmt::{self, Debug};
 
+/// A Rust representation of Roc's `Set k`, which (like `Set.roc`) is a
+/// dictionary whose values are all the empty record.
 #[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct RocSet<T>(RocDict<T, ()>);
 
 impl<T> RocSet<T> {
+    pub fn empty() -> Self {
+        Self(RocDict::empty())
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     #[allow(unused)]
     pub fn with_capacity(capacity: usize) -> Self {
         Self(RocDict::with_capacity(capacity))
@@ -23,11 +31,37 @@ impl<T> RocSet<T> {
     }
 }
 
-impl<T: Hash> RocSet<T> {
+impl<T: PartialEq> RocSet<T> {
+    /// Returns `true` if the set contains the element, like `Set.contains`.
+    pub fn contains(&self, elem: &T) -> bool {
+        self.0.contains_key(elem)
+    }
+
+    /// Returns a reference to the element in the set that is equal to the given one.
+    pub fn get(&self, elem: &T) -> Option<&T> {
+        self.iter().find(|existing| *existing == elem)
+    }
+}
+
+impl<T: PartialEq + Clone> RocSet<T> {
     #[allow(unused)]
     pub fn from_iter<I: Iterator<Item = T>>(src: I) -> Self {
         Self(RocDict::from_iter(src.map(|elem| (elem, ()))))
     }
+
+    /// Adds the element to the set, like `Set.insert`.
+    ///
+    /// Returns `true` if the element was not already present.
+    pub fn insert(&mut self, elem: T) -> bool {
+        self.0.insert(elem, ()).is_none()
+    }
+
+    /// Removes the element from the set, like `Set.remove`.
+    ///
+    /// Returns `true` if the element was present.
+    pub fn remove(&mut self, elem: &T) -> bool {
+        self.0.remove(elem).is_some()
+    }
 }
 
 impl<T: Debug> Debug for RocSet<T> {
@@ -37,3 +71,11 @@ impl<T: Debug> Debug for RocSet<T> {
         f.debug_set().entries(self.iter()).finish()
     }
 }
+
+impl<T: PartialEq + Clone> Extend<T> for RocSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.insert(elem);
+        }
+    }
+}
@@ -18,7 +18,7 @@ use core::{
 };
 
 #[cfg(feature = "std")]
-use core::ffi::{CStr, CString};
+use std::ffi::{CStr, CString};
 
 use crate::RocList;
 
@@ -29,7 +29,7 @@ fn with_stack_bytes<F, E, T>(length: usize, closure: F) -> T
 where
     F: FnOnce(*mut E) -> T,
 {
-    use crate::{roc_alloc, roc_dealloc};
+    use crate::allocator;
     use core::mem::MaybeUninit;
 
     if length < RocStr::TEMP_STR_MAX_STACK_BYTES {
@@ -43,11 +43,11 @@ where
         let align = core::mem::align_of::<E>() as u32;
         // The string is too long to stack-allocate, so
         // do a heap allocation and then free it afterwards.
-        let ptr = unsafe { roc_alloc(length, align) } as *mut E;
+        let ptr = unsafe { allocator::alloc(length, align) } as *mut E;
         let answer = closure(ptr);
 
         // Free the heap allocation.
-        unsafe { roc_dealloc(ptr.cast(), align) };
+        unsafe { allocator::dealloc(ptr.cast(), align) };
 
         answer
     }
@@ -479,7 +479,7 @@ impl RocStr {
                                 // it tries to use the refcount - which we just overwrote
                                 // with string bytes.
                                 mem::forget(self);
-                                crate::roc_dealloc(ptr.cast(), mem::align_of::<E>() as u32);
+                                crate::allocator::dealloc(ptr.cast(), mem::align_of::<E>() as u32);
 
                                 answer
                             } else {
@@ -553,7 +553,6 @@ impl TryFrom<CString> for RocStr {
     }
 }
 
-#[cfg(not(feature = "no_std"))]
 /// Like https://doc.rust-lang.org/std/ffi/struct.NulError.html but
 /// only for interior nuls, not for missing nul terminators.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,90 @@
+extern crate roc_std;
+
+use core::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Once a custom allocator is installed, none of these should ever be called.
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_alloc(_size: usize, _alignment: u32) -> *mut c_void {
+    panic!("roc_alloc was called despite a custom allocator being installed")
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_realloc(
+    _c_ptr: *mut c_void,
+    _new_size: usize,
+    _old_size: usize,
+    _alignment: u32,
+) -> *mut c_void {
+    panic!("roc_realloc was called despite a custom allocator being installed")
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_dealloc(_c_ptr: *mut c_void, _alignment: u32) {
+    panic!("roc_dealloc was called despite a custom allocator being installed")
+}
+
+/// Delegates to libc, counting the allocations that are currently live.
+struct CountingAllocator {
+    live: AtomicUsize,
+}
+
+impl roc_std::RocAllocator for CountingAllocator {
+    unsafe fn alloc(&self, size: usize, _alignment: u32) -> *mut c_void {
+        self.live.fetch_add(1, Ordering::SeqCst);
+
+        libc::malloc(size)
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut c_void,
+        new_size: usize,
+        _old_size: usize,
+        _alignment: u32,
+    ) -> *mut c_void {
+        libc::realloc(ptr, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut c_void, _alignment: u32) {
+        self.live.fetch_sub(1, Ordering::SeqCst);
+
+        libc::free(ptr)
+    }
+}
+
+static ALLOCATOR: CountingAllocator = CountingAllocator {
+    live: AtomicUsize::new(0),
+};
+
+// There is only one test in this file, because the allocator can only be
+// installed once per process.
+#[test]
+fn custom_allocator() {
+    use roc_std::{RocBox, RocDict, RocList, RocStr};
+
+    roc_std::set_allocator(&ALLOCATOR).unwrap();
+
+    assert_eq!(
+        roc_std::set_allocator(&ALLOCATOR),
+        Err(roc_std::SetAllocatorError)
+    );
+
+    {
+        let mut list = RocList::from_slice(&[1, 2, 3]);
+        list.reserve(100);
+
+        let string = RocStr::from("a string that is too long to be stored inline");
+        let boxed = RocBox::new(42u64);
+
+        let mut dict = RocDict::empty();
+        dict.insert(string.clone(), list.clone());
+
+        assert_eq!(ALLOCATOR.live.load(Ordering::SeqCst), 4);
+        assert_eq!(*boxed, 42);
+        assert_eq!(dict.get(&string), Some(&list));
+    }
+
+    assert_eq!(ALLOCATOR.live.load(Ordering::SeqCst), 0);
+}
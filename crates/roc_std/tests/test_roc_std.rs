@@ -59,7 +59,7 @@ pub unsafe extern "C" fn roc_memset(dst: *mut c_void, c: i32, n: usize) -> *mut
 
 #[cfg(test)]
 mod test_roc_std {
-    use roc_std::{RocBox, RocDec, RocList, RocResult, RocStr};
+    use roc_std::{RocBox, RocDec, RocDict, RocDictItem, RocList, RocResult, RocSet, RocStr};
 
     fn roc_str_byte_representation(string: &RocStr) -> [u8; RocStr::SIZE] {
         unsafe { core::mem::transmute_copy(string) }
@@ -239,6 +239,175 @@ mod test_roc_std {
         assert_eq!(from_array.capacity(), from_slice.capacity());
     }
 
+    #[test]
+    fn list_push_pop() {
+        let mut list = RocList::empty();
+
+        for i in 0..100 {
+            list.push(i);
+        }
+
+        assert_eq!(list.len(), 100);
+        assert_eq!(list.pop(), Some(99));
+        assert_eq!(list.as_slice(), &(0..99).collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn list_mutation_copies_shared_allocation() {
+        let mut list = RocList::from_slice(&[1, 2, 3]);
+        let shared = list.clone();
+
+        list.as_mut_slice()[0] = 42;
+        list.push(4);
+
+        assert_eq!(list.as_slice(), &[42, 2, 3, 4]);
+        assert_eq!(shared.as_slice(), &[1, 2, 3]);
+        assert!(list.is_unique());
+        assert!(shared.is_unique());
+    }
+
+    #[test]
+    fn list_swap_remove() {
+        let mut list = RocList::from_slice(&[1, 2, 3, 4]);
+
+        assert_eq!(list.swap_remove(1), 2);
+        assert_eq!(list.as_slice(), &[1, 4, 3]);
+    }
+
+    #[test]
+    fn dict_insert_get() {
+        let mut dict = RocDict::empty();
+
+        assert_eq!(dict.insert(RocStr::from("London"), 8_961_989), None);
+        assert_eq!(dict.insert(RocStr::from("Delhi"), 16_787_941), None);
+        assert_eq!(
+            dict.insert(RocStr::from("London"), 9_000_000),
+            Some(8_961_989)
+        );
+
+        assert_eq!(dict.len(), 2);
+        assert_eq!(dict.get(&RocStr::from("London")), Some(&9_000_000));
+        assert_eq!(dict.get(&RocStr::from("Paris")), None);
+        assert!(dict.contains_key(&RocStr::from("Delhi")));
+
+        // Re-inserting a key keeps its original position, like Dict.insert
+        let keys: Vec<&str> = dict.iter_keys().map(|key| key.as_str()).collect();
+        assert_eq!(keys, ["London", "Delhi"]);
+    }
+
+    #[test]
+    fn dict_remove_moves_last_entry() {
+        let mut dict = RocDict::from_iter([(1, "a"), (2, "b"), (3, "c"), (4, "d")].into_iter());
+
+        assert_eq!(dict.remove(&2), Some("b"));
+        assert_eq!(dict.remove(&2), None);
+
+        // Like Dict.remove, the last entry moves into the vacated slot.
+        let keys: Vec<i32> = dict.iter_keys().copied().collect();
+        assert_eq!(keys, [1, 4, 3]);
+    }
+
+    #[test]
+    fn dict_entry() {
+        let mut dict: RocDict<u8, u64> = RocDict::empty();
+
+        for byte in b"mississippi" {
+            *dict.entry(*byte).or_insert(0) += 1;
+        }
+
+        assert_eq!(dict.get(&b's'), Some(&4));
+        assert_eq!(dict.get(&b'm'), Some(&1));
+
+        dict.entry(b'm')
+            .and_modify(|count| *count = 10)
+            .or_default();
+        dict.entry(b'z')
+            .and_modify(|count| *count = 10)
+            .or_default();
+
+        assert_eq!(dict.get(&b'm'), Some(&10));
+        assert_eq!(dict.get(&b'z'), Some(&0));
+    }
+
+    #[test]
+    fn dict_insert_does_not_affect_clones() {
+        let mut dict = RocDict::from_iter([(1, 1)].into_iter());
+        let shared = dict.clone();
+
+        dict.insert(1, 2);
+        dict.insert(3, 4);
+
+        assert_eq!(shared.get(&1), Some(&1));
+        assert_eq!(shared.len(), 1);
+        assert_eq!(dict.get(&1), Some(&2));
+    }
+
+    #[test]
+    fn dict_item_layout_matches_pair() {
+        fn offset_of<T, F>(item: &T, field: &F) -> usize {
+            field as *const F as usize - item as *const T as usize
+        }
+
+        // Roc puts the more aligned payload of `Pair k v` first...
+        let item = RocDictItem::new(1u8, 2u64);
+
+        assert_eq!(core::mem::size_of_val(&item), 16);
+        assert_eq!(offset_of(&item, item.value()), 0);
+        assert_eq!(offset_of(&item, item.key()), 8);
+
+        // ...and keeps the key first when they're equally aligned.
+        let item = RocDictItem::new(1u64, 2u8);
+
+        assert_eq!(core::mem::size_of_val(&item), 16);
+        assert_eq!(offset_of(&item, item.key()), 0);
+        assert_eq!(offset_of(&item, item.value()), 8);
+
+        let item = RocDictItem::new(RocStr::from("key"), 2u64);
+
+        assert_eq!(offset_of(&item, item.key()), 0);
+        assert_eq!(
+            offset_of(&item, item.value()),
+            core::mem::size_of::<RocStr>()
+        );
+    }
+
+    #[test]
+    fn dict_into_iter_moves_entries_out() {
+        let dict = RocDict::from_iter(
+            [
+                (RocStr::from("a"), RocList::from_slice(&[1u8])),
+                (RocStr::from("b"), RocList::empty()),
+            ]
+            .into_iter(),
+        );
+
+        let entries: Vec<(RocStr, RocList<u8>)> = dict.into_iter().collect();
+
+        assert_eq!(
+            entries,
+            [
+                (RocStr::from("a"), RocList::from_slice(&[1])),
+                (RocStr::from("b"), RocList::empty())
+            ]
+        );
+    }
+
+    #[test]
+    fn set_insert_remove() {
+        let mut set = RocSet::empty();
+
+        assert!(set.insert(RocStr::from("a")));
+        assert!(set.insert(RocStr::from("b")));
+        assert!(!set.insert(RocStr::from("a")));
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&RocStr::from("b")));
+
+        assert!(set.remove(&RocStr::from("a")));
+        assert!(!set.remove(&RocStr::from("a")));
+        assert!(!set.contains(&RocStr::from("a")));
+    }
+
     #[test]
     fn roc_result_to_rust_result() {
         let greeting = "Hello, World!";
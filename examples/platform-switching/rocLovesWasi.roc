@@ -0,0 +1,6 @@
+app "rocLovesWasi"
+    packages { pf: "wasi-platform/main.roc" }
+    imports []
+    provides [main] to pf
+
+main = "Roc <3 WASI!\n"
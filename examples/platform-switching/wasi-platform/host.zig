@@ -0,0 +1,85 @@
+const str = @import("str");
+const RocStr = str.RocStr;
+
+// This host only uses libc, so unlike the web-assembly-platform it doesn't
+// need any imports from JavaScript. The libc is the wasi-libc that Roc builds
+// in crates/wasi-libc-sys and links into every Wasm host, which in turn only
+// needs the WASI system calls that any WASI runtime provides.
+// Build it with `roc build --target=wasm32-wasi` and run the resulting
+// .wasm file with any WASI runtime, e.g. `wasmtime rocLovesWasi.wasm`.
+
+const Align = extern struct { a: usize, b: usize };
+extern fn malloc(size: usize) callconv(.C) ?*align(@alignOf(Align)) anyopaque;
+extern fn realloc(c_ptr: [*]align(@alignOf(Align)) u8, size: usize) callconv(.C) ?*anyopaque;
+extern fn free(c_ptr: [*]align(@alignOf(Align)) u8) callconv(.C) void;
+extern fn memcpy(dst: [*]u8, src: [*]u8, size: usize) callconv(.C) void;
+extern fn memset(dst: [*]u8, value: i32, size: usize) callconv(.C) void;
+extern fn strlen(str: [*:0]const u8) callconv(.C) usize;
+extern fn write(fd: i32, buf: [*]const u8, count: usize) callconv(.C) isize;
+extern fn exit(status: i32) callconv(.C) noreturn;
+
+const STDOUT_FILENO: i32 = 1;
+const STDERR_FILENO: i32 = 2;
+
+fn writeAll(fd: i32, bytes: []const u8) void {
+    var written: usize = 0;
+    while (written < bytes.len) {
+        const result = write(fd, bytes.ptr + written, bytes.len - written);
+        if (result <= 0) {
+            return;
+        }
+        written += @intCast(usize, result);
+    }
+}
+
+export fn roc_alloc(size: usize, alignment: u32) callconv(.C) ?*anyopaque {
+    _ = alignment;
+
+    return malloc(size);
+}
+
+export fn roc_realloc(c_ptr: *anyopaque, new_size: usize, old_size: usize, alignment: u32) callconv(.C) ?*anyopaque {
+    _ = old_size;
+    _ = alignment;
+
+    return realloc(@alignCast(@alignOf(Align), @ptrCast([*]u8, c_ptr)), new_size);
+}
+
+export fn roc_dealloc(c_ptr: *anyopaque, alignment: u32) callconv(.C) void {
+    _ = alignment;
+
+    free(@alignCast(@alignOf(Align), @ptrCast([*]u8, c_ptr)));
+}
+
+export fn roc_panic(c_ptr: *anyopaque, tag_id: u32) callconv(.C) void {
+    _ = tag_id;
+
+    const msg = @ptrCast([*:0]const u8, c_ptr);
+    writeAll(STDERR_FILENO, "Application crashed with message\n\n    ");
+    writeAll(STDERR_FILENO, msg[0..strlen(msg)]);
+    writeAll(STDERR_FILENO, "\n\nShutting down\n");
+    exit(1);
+}
+
+export fn roc_memcpy(dst: [*]u8, src: [*]u8, size: usize) callconv(.C) void {
+    return memcpy(dst, src, size);
+}
+
+export fn roc_memset(dst: [*]u8, value: i32, size: usize) callconv(.C) void {
+    return memset(dst, value, size);
+}
+
+extern fn roc__mainForHost_1_exposed(*RocStr) void;
+
+pub fn main() u8 {
+    // actually call roc to populate the callresult
+    var callresult = RocStr.empty();
+    roc__mainForHost_1_exposed(&callresult);
+
+    // stdout the result
+    writeAll(STDOUT_FILENO, callresult.asSlice());
+
+    callresult.deinit();
+
+    return 0;
+}
@@ -0,0 +1,9 @@
+platform "echo-in-wasi"
+    requires {} { main : Str }
+    exposes []
+    packages {}
+    imports []
+    provides [mainForHost]
+
+mainForHost : Str
+mainForHost = main